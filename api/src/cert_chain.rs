@@ -0,0 +1,551 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    cert_chain.rs
+
+Abstract:
+
+    Host-side helpers to fetch and validate the DICE certificate chain
+    returned by the GET_CERT_CHAIN mailbox command.
+
+--*/
+
+use std::vec::Vec;
+
+use crate::checksum::verify_checksum;
+use crate::mailbox::{
+    CommandId, GetCertChainReq, GetCertChainResp, MailboxReqHeader, Request, Response,
+};
+use zerocopy::AsBytes;
+
+#[derive(Debug)]
+pub enum CertChainError<E> {
+    /// The transport failed to execute the mailbox command
+    Transport(E),
+    /// The transport returned no response data
+    NoResponse,
+    /// The response checksum did not match its contents
+    InvalidChecksum,
+    /// A response was truncated, oversized or inconsistent with earlier chunks
+    InvalidResponse,
+    /// Certificate `index` could not be parsed
+    #[cfg(feature = "openssl")]
+    MalformedCert { index: usize },
+    /// Certificate `index` is not signed by, or not issued by, its predecessor
+    #[cfg(feature = "openssl")]
+    BrokenChain { index: usize },
+    #[cfg(feature = "openssl")]
+    OpenSsl(openssl::error::ErrorStack),
+}
+
+/// A DICE certificate chain reassembled from GET_CERT_CHAIN chunks
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CertChain {
+    /// The concatenated certificates, root first. Each is DER-encoded X.509,
+    /// or an untagged COSE_Sign1 when the device issues CBOR certificates.
+    pub der: Vec<u8>,
+    /// Offset of each certificate within `der`
+    pub offsets: Vec<usize>,
+}
+
+impl CertChain {
+    /// Fetch the full certificate chain by issuing GET_CERT_CHAIN until the
+    /// runtime reports the chain is complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - GET_CERT_CHAIN request flags (see `GetCertChainReq`)
+    /// * `exec` - Executes a mailbox command, returning the raw response bytes
+    pub fn fetch<E>(
+        flags: u32,
+        mut exec: impl FnMut(u32, &[u8]) -> Result<Option<Vec<u8>>, E>,
+    ) -> Result<Self, CertChainError<E>> {
+        let mut chain = CertChain::default();
+        let mut first: Option<GetCertChainResp> = None;
+
+        loop {
+            let mut req = GetCertChainReq {
+                hdr: MailboxReqHeader::default(),
+                offset: chain.der.len() as u32,
+                flags,
+            };
+            req.hdr.chksum = crate::checksum::calc_checksum(
+                GetCertChainReq::ID.into(),
+                &req.as_bytes()[core::mem::size_of::<MailboxReqHeader>()..],
+            );
+
+            let resp_bytes = exec(u32::from(CommandId::GET_CERT_CHAIN), req.as_bytes())
+                .map_err(CertChainError::Transport)?
+                .ok_or(CertChainError::NoResponse)?;
+            let (resp, data) = Self::parse_resp(&resp_bytes)?;
+
+            // Every chunk must describe the same chain and advance the cursor.
+            if resp.next_offset as usize != chain.der.len() + data.len() {
+                return Err(CertChainError::InvalidResponse);
+            }
+            if let Some(first) = &first {
+                if first.total_size != resp.total_size
+                    || first.cert_count != resp.cert_count
+                    || first.cert_offsets != resp.cert_offsets
+                {
+                    return Err(CertChainError::InvalidResponse);
+                }
+            }
+            if data.is_empty() && !resp.is_complete() {
+                return Err(CertChainError::InvalidResponse);
+            }
+
+            chain.der.extend_from_slice(data);
+            let complete = resp.is_complete();
+            first.get_or_insert(resp);
+            if complete {
+                break;
+            }
+        }
+
+        let first = first.ok_or(CertChainError::InvalidResponse)?;
+        if chain.der.len() != first.total_size as usize {
+            return Err(CertChainError::InvalidResponse);
+        }
+        chain.offsets = first
+            .cert_offsets
+            .get(..first.cert_count as usize)
+            .ok_or(CertChainError::InvalidResponse)?
+            .iter()
+            .map(|offset| *offset as usize)
+            .collect();
+        if chain
+            .offsets
+            .windows(2)
+            .any(|pair| pair[0] >= pair[1] || pair[1] > chain.der.len())
+        {
+            return Err(CertChainError::InvalidResponse);
+        }
+
+        Ok(chain)
+    }
+
+    /// Parse a GET_CERT_CHAIN response. A response carried by a chunked
+    /// transfer may hold more than `GetCertChainResp::DATA_MAX_SIZE` bytes of
+    /// data, so the data is returned separately rather than copied into the
+    /// struct.
+    fn parse_resp<E>(resp_bytes: &[u8]) -> Result<(GetCertChainResp, &[u8]), CertChainError<E>> {
+        if resp_bytes.len() < GetCertChainResp::MIN_SIZE {
+            return Err(CertChainError::InvalidResponse);
+        }
+        let (fixed, data) = resp_bytes.split_at(GetCertChainResp::MIN_SIZE);
+        let mut resp = GetCertChainResp::default();
+        resp.as_bytes_mut()[..fixed.len()].copy_from_slice(fixed);
+        if !verify_checksum(
+            resp.hdr.chksum,
+            0x0,
+            &resp_bytes[core::mem::size_of_val(&resp.hdr.chksum)..],
+        ) {
+            return Err(CertChainError::InvalidChecksum);
+        }
+        if resp.cert_count as usize > GetCertChainResp::MAX_CERT_COUNT
+            || data.len() != resp.data_size as usize
+        {
+            return Err(CertChainError::InvalidResponse);
+        }
+        Ok((resp, data))
+    }
+
+    /// Iterate over the encoding of each certificate, root first
+    pub fn certs(&self) -> impl Iterator<Item = &[u8]> {
+        self.offsets.iter().enumerate().map(|(i, start)| {
+            let end = self.offsets.get(i + 1).copied().unwrap_or(self.der.len());
+            &self.der[*start..end]
+        })
+    }
+
+    /// Parse every X.509 certificate and check that each one is issued and
+    /// signed by the certificate before it. The first certificate is treated
+    /// as the trust anchor and is not itself verified.
+    ///
+    /// Use [`CertChain::validate_cbor`] for chains of CBOR certificates.
+    #[cfg(feature = "openssl")]
+    pub fn validate<E>(&self) -> Result<Vec<openssl::x509::X509>, CertChainError<E>> {
+        let certs = self
+            .certs()
+            .enumerate()
+            .map(|(index, der)| {
+                openssl::x509::X509::from_der(der)
+                    .map_err(|_| CertChainError::MalformedCert { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (index, pair) in certs.windows(2).enumerate() {
+            let (issuer, subject) = (&pair[0], &pair[1]);
+            let issuer_key = issuer.public_key().map_err(CertChainError::OpenSsl)?;
+            let signed = subject
+                .verify(&issuer_key)
+                .map_err(CertChainError::OpenSsl)?;
+            let names_match = subject
+                .issuer_name()
+                .try_cmp(issuer.subject_name())
+                .map_err(CertChainError::OpenSsl)?
+                == core::cmp::Ordering::Equal;
+            if !signed || !names_match {
+                return Err(CertChainError::BrokenChain { index: index + 1 });
+            }
+        }
+
+        Ok(certs)
+    }
+
+    /// Parse every CBOR (COSE_Sign1) certificate and check that each one is
+    /// issued and signed by the certificate before it. The first certificate
+    /// is treated as the trust anchor and is not itself verified.
+    #[cfg(feature = "openssl")]
+    pub fn validate_cbor<E>(&self) -> Result<Vec<CborCert>, CertChainError<E>> {
+        let certs = self
+            .certs()
+            .enumerate()
+            .map(|(index, cert)| {
+                CborCert::parse(cert).ok_or(CertChainError::MalformedCert { index })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (index, pair) in certs.windows(2).enumerate() {
+            let (issuer, subject) = (&pair[0], &pair[1]);
+            let signed = subject
+                .verify(&issuer.public_key)
+                .map_err(CertChainError::OpenSsl)?;
+            if !signed || subject.issuer != issuer.subject {
+                return Err(CertChainError::BrokenChain { index: index + 1 });
+            }
+        }
+
+        Ok(certs)
+    }
+}
+
+/// An Open Profile for DICE certificate: CWT claims signed with ES384 in an
+/// untagged COSE_Sign1
+#[cfg(feature = "openssl")]
+pub struct CborCert {
+    /// Issuer claim
+    pub issuer: String,
+    /// Subject claim
+    pub subject: String,
+    /// Subject public key claim
+    pub public_key: openssl::ec::EcKey<openssl::pkey::Public>,
+    /// Encoded CWT claims
+    pub payload: Vec<u8>,
+    protected: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+#[cfg(feature = "openssl")]
+impl CborCert {
+    const CLAIM_ISSUER: i64 = 1;
+    const CLAIM_SUBJECT: i64 = 2;
+    const CLAIM_SUBJECT_PUBLIC_KEY: i64 = -4670552;
+    const COSE_KEY_X: i64 = -2;
+    const COSE_KEY_Y: i64 = -3;
+
+    /// COSE_Sign1 protected header: { alg: ES384 }
+    const PROTECTED_HEADER: [u8; 4] = [0xa1, 0x01, 0x38, 0x22];
+
+    fn parse(cert: &[u8]) -> Option<Self> {
+        let mut reader = CborReader::new(cert);
+        if reader.array()? != 4 {
+            return None;
+        }
+        let protected = reader.bytes()?;
+        if protected != Self::PROTECTED_HEADER || reader.map()? != 0 {
+            return None;
+        }
+        let payload = reader.bytes()?;
+        let signature = reader.bytes()?;
+        if !reader.is_empty() || signature.len() != 96 {
+            return None;
+        }
+
+        let issuer = CborReader::map_get(payload, Self::CLAIM_ISSUER)?.text()?;
+        let subject = CborReader::map_get(payload, Self::CLAIM_SUBJECT)?.text()?;
+        let cose_key = CborReader::map_get(payload, Self::CLAIM_SUBJECT_PUBLIC_KEY)?.bytes()?;
+        let x = CborReader::map_get(cose_key, Self::COSE_KEY_X)?.bytes()?;
+        let y = CborReader::map_get(cose_key, Self::COSE_KEY_Y)?.bytes()?;
+        let group = openssl::ec::EcGroup::from_curve_name(openssl::nid::Nid::SECP384R1).ok()?;
+        let public_key = openssl::ec::EcKey::from_public_key_affine_coordinates(
+            &group,
+            &openssl::bn::BigNum::from_slice(x).ok()?,
+            &openssl::bn::BigNum::from_slice(y).ok()?,
+        )
+        .ok()?;
+
+        Some(Self {
+            issuer: issuer.into(),
+            subject: subject.into(),
+            public_key,
+            payload: payload.into(),
+            protected: protected.into(),
+            signature: signature.into(),
+        })
+    }
+
+    /// Verify the signature over the Sig_structure
+    /// `["Signature1", protected, external_aad, payload]` with `key`
+    pub fn verify(
+        &self,
+        key: &openssl::ec::EcKeyRef<openssl::pkey::Public>,
+    ) -> Result<bool, openssl::error::ErrorStack> {
+        let mut sig_structure = vec![0x84];
+        cbor_push_head(&mut sig_structure, 3, b"Signature1".len());
+        sig_structure.extend_from_slice(b"Signature1");
+        cbor_push_head(&mut sig_structure, 2, self.protected.len());
+        sig_structure.extend_from_slice(&self.protected);
+        cbor_push_head(&mut sig_structure, 2, 0);
+        cbor_push_head(&mut sig_structure, 2, self.payload.len());
+        sig_structure.extend_from_slice(&self.payload);
+
+        let sig = openssl::ecdsa::EcdsaSig::from_private_components(
+            openssl::bn::BigNum::from_slice(&self.signature[..48])?,
+            openssl::bn::BigNum::from_slice(&self.signature[48..])?,
+        )?;
+        sig.verify(&openssl::sha::sha384(&sig_structure), key)
+    }
+}
+
+/// Append a CBOR item head with major type `major` and argument `arg`
+#[cfg(feature = "openssl")]
+fn cbor_push_head(buf: &mut Vec<u8>, major: u8, arg: usize) {
+    let major = major << 5;
+    match arg {
+        0..=23 => buf.push(major | arg as u8),
+        24..=0xff => buf.extend_from_slice(&[major | 24, arg as u8]),
+        0x100..=0xffff => {
+            buf.push(major | 25);
+            buf.extend_from_slice(&(arg as u16).to_be_bytes());
+        }
+        _ => {
+            buf.push(major | 26);
+            buf.extend_from_slice(&(arg as u32).to_be_bytes());
+        }
+    }
+}
+
+/// Reads the definite-length CBOR items found in DICE certificates
+#[cfg(feature = "openssl")]
+struct CborReader<'a> {
+    data: &'a [u8],
+}
+
+#[cfg(feature = "openssl")]
+impl<'a> CborReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn head(&mut self) -> Option<(u8, u64)> {
+        let (initial, rest) = self.data.split_first()?;
+        let arg_len = match initial & 0x1f {
+            0..=23 => 0,
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return None,
+        };
+        let arg = match arg_len {
+            0 => u64::from(initial & 0x1f),
+            _ => rest
+                .get(..arg_len)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b)),
+        };
+        self.data = &rest[arg_len..];
+        Some((initial >> 5, arg))
+    }
+
+    fn take(&mut self, len: u64) -> Option<&'a [u8]> {
+        let len = usize::try_from(len).ok()?;
+        if len > self.data.len() {
+            return None;
+        }
+        let (taken, rest) = self.data.split_at(len);
+        self.data = rest;
+        Some(taken)
+    }
+
+    fn expect(&mut self, major: u8) -> Option<u64> {
+        match self.head()? {
+            (m, arg) if m == major => Some(arg),
+            _ => None,
+        }
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.expect(2)?;
+        self.take(len)
+    }
+
+    fn text(&mut self) -> Option<&'a str> {
+        let len = self.expect(3)?;
+        std::str::from_utf8(self.take(len)?).ok()
+    }
+
+    fn array(&mut self) -> Option<u64> {
+        self.expect(4)
+    }
+
+    fn map(&mut self) -> Option<u64> {
+        self.expect(5)
+    }
+
+    /// Read an integer, or `None` if the next item is not one
+    fn int(&mut self) -> Option<i64> {
+        match self.head()? {
+            (0, arg) => i64::try_from(arg).ok(),
+            (1, arg) => Some(-1 - i64::try_from(arg).ok()?),
+            _ => None,
+        }
+    }
+
+    /// Skip over the next item, including any nested items
+    fn skip(&mut self) -> Option<()> {
+        let mut pending = 1u64;
+        while pending > 0 {
+            pending -= 1;
+            match self.head()? {
+                (2 | 3, len) => {
+                    self.take(len)?;
+                }
+                (4, len) => pending = pending.checked_add(len)?,
+                (5, len) => pending = pending.checked_add(len.checked_mul(2)?)?,
+                (6, _) => pending += 1,
+                _ => {}
+            }
+            if pending > self.data.len() as u64 {
+                return None;
+            }
+        }
+        Some(())
+    }
+
+    /// Find the value of integer `key` in the map encoded in `map`
+    fn map_get(map: &'a [u8], key: i64) -> Option<Self> {
+        let mut reader = Self::new(map);
+        for _ in 0..reader.map()? {
+            let mut key_reader = Self::new(reader.data);
+            let found = key_reader.int() == Some(key);
+            reader.skip()?;
+            if found {
+                return Some(reader);
+            }
+            reader.skip()?;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mailbox::{MailboxResp, MailboxRespHeader};
+    use zerocopy::FromBytes;
+
+    fn fake_runtime(chain: &[u8], offsets: &[u32], req: &[u8]) -> Vec<u8> {
+        let req = GetCertChainReq::read_from(req).unwrap();
+        let start = req.offset as usize;
+        let end = core::cmp::min(chain.len(), start + GetCertChainResp::DATA_MAX_SIZE);
+        let mut resp = GetCertChainResp {
+            hdr: MailboxRespHeader::default(),
+            total_size: chain.len() as u32,
+            cert_count: offsets.len() as u32,
+            next_offset: end as u32,
+            data_size: (end - start) as u32,
+            ..Default::default()
+        };
+        resp.cert_offsets[..offsets.len()].copy_from_slice(offsets);
+        resp.data[..end - start].copy_from_slice(&chain[start..end]);
+        let mut resp = MailboxResp::GetCertChain(resp);
+        resp.populate_chksum().unwrap();
+        resp.as_bytes().unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_fetch_reassembles_chunks() {
+        let chain: Vec<u8> = (0..5000u32).map(|i| i as u8).collect();
+        let offsets = [0, 1200, 2500, 4100];
+        let mut calls = 0;
+
+        let result = CertChain::fetch::<()>(0, |cmd, req| {
+            assert_eq!(cmd, u32::from(CommandId::GET_CERT_CHAIN));
+            calls += 1;
+            Ok(Some(fake_runtime(&chain, &offsets, req)))
+        })
+        .unwrap();
+
+        assert_eq!(calls, 3);
+        assert_eq!(result.der, chain);
+        assert_eq!(result.offsets, [0, 1200, 2500, 4100]);
+        let certs: Vec<&[u8]> = result.certs().collect();
+        assert_eq!(certs.len(), 4);
+        assert_eq!(certs[3], &chain[4100..]);
+    }
+
+    #[test]
+    fn test_fetch_accepts_whole_chain_in_one_response() {
+        // A response carried by a chunked transfer holds the whole chain
+        let chain: Vec<u8> = (0..3000u32).map(|i| i as u8).collect();
+        let offsets = [0u32, 1200, 2500];
+        let mut calls = 0;
+
+        let result = CertChain::fetch::<()>(0, |_, _| {
+            calls += 1;
+            let mut resp = GetCertChainResp {
+                total_size: chain.len() as u32,
+                cert_count: offsets.len() as u32,
+                next_offset: chain.len() as u32,
+                data_size: chain.len() as u32,
+                ..Default::default()
+            };
+            resp.cert_offsets[..offsets.len()].copy_from_slice(&offsets);
+            let mut resp_bytes = resp.as_bytes()[..GetCertChainResp::MIN_SIZE].to_vec();
+            resp_bytes.extend_from_slice(&chain);
+            let chksum = crate::checksum::calc_checksum(0, &resp_bytes[4..]);
+            resp_bytes[..4].copy_from_slice(&chksum.to_le_bytes());
+            Ok(Some(resp_bytes))
+        })
+        .unwrap();
+
+        assert_eq!(calls, 1);
+        assert_eq!(result.der, chain);
+        assert_eq!(result.offsets, [0, 1200, 2500]);
+    }
+
+    #[test]
+    fn test_fetch_rejects_bad_checksum() {
+        let chain = [0x30u8, 0x00];
+        let result = CertChain::fetch::<()>(0, |_, req| {
+            let mut resp = fake_runtime(&chain, &[0], req);
+            resp[0] ^= 1;
+            Ok(Some(resp))
+        });
+        assert!(matches!(result, Err(CertChainError::InvalidChecksum)));
+    }
+
+    #[test]
+    fn test_fetch_rejects_stalled_cursor() {
+        let result = CertChain::fetch::<()>(0, |_, _| {
+            let mut resp = MailboxResp::GetCertChain(GetCertChainResp {
+                total_size: 10,
+                cert_count: 1,
+                ..Default::default()
+            });
+            resp.populate_chksum().unwrap();
+            Ok(Some(resp.as_bytes().unwrap().to_vec()))
+        });
+        assert!(matches!(result, Err(CertChainError::InvalidResponse)));
+    }
+}
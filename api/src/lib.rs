@@ -1,8 +1,10 @@
 // Licensed under the Apache-2.0 license
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 mod capabilities;
+#[cfg(any(test, feature = "std"))]
+pub mod cert_chain;
 mod checksum;
 pub mod mailbox;
 
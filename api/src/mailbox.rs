@@ -14,6 +14,7 @@ impl CommandId {
     pub const GET_LDEV_CERT: Self = Self(0x4C444556); // "LDEV"
     pub const GET_FMC_ALIAS_CERT: Self = Self(0x43455246); // "CERF"
     pub const GET_RT_ALIAS_CERT: Self = Self(0x43455252); // "CERR"
+    pub const GET_CERT_CHAIN: Self = Self(0x43455243); // "CERC"
     pub const ECDSA384_VERIFY: Self = Self(0x53494756); // "SIGV"
     pub const STASH_MEASUREMENT: Self = Self(0x4D454153); // "MEAS"
     pub const INVOKE_DPE: Self = Self(0x44504543); // "DPEC"
@@ -24,6 +25,16 @@ impl CommandId {
     pub const INCREMENT_PCR_RESET_COUNTER: Self = Self(0x50435252); // "PCRR"
     pub const QUOTE_PCRS: Self = Self(0x50435251); // "PCRQ"
     pub const EXTEND_PCR: Self = Self(0x50435245); // "PCRE"
+    pub const GET_EAT: Self = Self(0x45415454); // "EATT"
+    pub const GET_CRASH_INFO: Self = Self(0x43525348); // "CRSH"
+    pub const CHUNKED_BEGIN: Self = Self(0x43484B42); // "CHKB"
+    pub const CHUNKED_CONTINUE: Self = Self(0x43484B43); // "CHKC"
+    pub const CHUNKED_END: Self = Self(0x43484B45); // "CHKE"
+    pub const CHUNKED_READ: Self = Self(0x43484B52); // "CHKR"
+    pub const SET_IDEV_CSR_NONCE: Self = Self(0x4944434E); // "IDCN"
+    pub const GET_IDEV_CSR: Self = Self(0x49444353); // "IDCS"
+    pub const GET_TIME_NONCE: Self = Self(0x544D4E43); // "TMNC"
+    pub const SET_TIME: Self = Self(0x53544D45); // "STME"
 
     pub const TEST_ONLY_HMAC384_VERIFY: Self = Self(0x484D4143); // "HMAC"
 
@@ -137,6 +148,13 @@ pub enum MailboxResp {
     GetTaggedTci(GetTaggedTciResp),
     GetRtAliasCert(GetRtAliasCertResp),
     QuotePcrs(QuotePcrsResp),
+    GetCertChain(GetCertChainResp),
+    GetEat(GetEatResp),
+    GetCrashInfo(GetCrashInfoResp),
+    Chunked(ChunkedResp),
+    GetIdevCsr(GetIdevCsrResp),
+    GetTimeNonce(GetTimeNonceResp),
+    SetTime(SetTimeResp),
 }
 
 impl MailboxResp {
@@ -155,6 +173,13 @@ impl MailboxResp {
             MailboxResp::GetFmcAliasCert(resp) => resp.as_bytes_partial(),
             MailboxResp::GetRtAliasCert(resp) => resp.as_bytes_partial(),
             MailboxResp::QuotePcrs(resp) => Ok(resp.as_bytes()),
+            MailboxResp::GetCertChain(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetEat(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetCrashInfo(resp) => Ok(resp.as_bytes()),
+            MailboxResp::Chunked(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetIdevCsr(resp) => resp.as_bytes_partial(),
+            MailboxResp::GetTimeNonce(resp) => Ok(resp.as_bytes()),
+            MailboxResp::SetTime(resp) => Ok(resp.as_bytes()),
         }
     }
 
@@ -173,6 +198,13 @@ impl MailboxResp {
             MailboxResp::GetFmcAliasCert(resp) => resp.as_bytes_partial_mut(),
             MailboxResp::GetRtAliasCert(resp) => resp.as_bytes_partial_mut(),
             MailboxResp::QuotePcrs(resp) => Ok(resp.as_bytes_mut()),
+            MailboxResp::GetCertChain(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetEat(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetCrashInfo(resp) => Ok(resp.as_bytes_mut()),
+            MailboxResp::Chunked(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetIdevCsr(resp) => resp.as_bytes_partial_mut(),
+            MailboxResp::GetTimeNonce(resp) => Ok(resp.as_bytes_mut()),
+            MailboxResp::SetTime(resp) => Ok(resp.as_bytes_mut()),
         }
     }
 
@@ -228,6 +260,16 @@ pub enum MailboxReq {
     IncrementPcrResetCounter(IncrementPcrResetCounterReq),
     QuotePcrs(QuotePcrsReq),
     ExtendPcr(ExtendPcrReq),
+    GetCertChain(GetCertChainReq),
+    GetEat(GetEatReq),
+    ChunkedBegin(ChunkedBeginReq),
+    ChunkedContinue(ChunkedDataReq),
+    ChunkedEnd(ChunkedDataReq),
+    ChunkedRead(ChunkedReadReq),
+    SetIdevCsrNonce(SetIdevCsrNonceReq),
+    GetIdevCsr(GetIdevCsrReq),
+    GetTimeNonce(GetTimeNonceReq),
+    SetTime(SetTimeReq),
 
     #[cfg(feature = "test_only_commands")]
     TestHmacVerify(HmacVerifyReq),
@@ -251,6 +293,16 @@ impl MailboxReq {
             MailboxReq::IncrementPcrResetCounter(req) => Ok(req.as_bytes()),
             MailboxReq::QuotePcrs(req) => Ok(req.as_bytes()),
             MailboxReq::ExtendPcr(req) => Ok(req.as_bytes()),
+            MailboxReq::GetCertChain(req) => Ok(req.as_bytes()),
+            MailboxReq::GetEat(req) => Ok(req.as_bytes()),
+            MailboxReq::ChunkedBegin(req) => req.as_bytes_partial(),
+            MailboxReq::ChunkedContinue(req) => req.as_bytes_partial(),
+            MailboxReq::ChunkedEnd(req) => req.as_bytes_partial(),
+            MailboxReq::ChunkedRead(req) => Ok(req.as_bytes()),
+            MailboxReq::SetIdevCsrNonce(req) => Ok(req.as_bytes()),
+            MailboxReq::GetIdevCsr(req) => Ok(req.as_bytes()),
+            MailboxReq::GetTimeNonce(req) => Ok(req.as_bytes()),
+            MailboxReq::SetTime(req) => Ok(req.as_bytes()),
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestHmacVerify(req) => Ok(req.as_bytes()),
@@ -274,6 +326,16 @@ impl MailboxReq {
             MailboxReq::IncrementPcrResetCounter(req) => Ok(req.as_bytes_mut()),
             MailboxReq::QuotePcrs(req) => Ok(req.as_bytes_mut()),
             MailboxReq::ExtendPcr(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetCertChain(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetEat(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::ChunkedBegin(req) => req.as_bytes_partial_mut(),
+            MailboxReq::ChunkedContinue(req) => req.as_bytes_partial_mut(),
+            MailboxReq::ChunkedEnd(req) => req.as_bytes_partial_mut(),
+            MailboxReq::ChunkedRead(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::SetIdevCsrNonce(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetIdevCsr(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::GetTimeNonce(req) => Ok(req.as_bytes_mut()),
+            MailboxReq::SetTime(req) => Ok(req.as_bytes_mut()),
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestHmacVerify(req) => Ok(req.as_bytes_mut()),
@@ -297,6 +359,16 @@ impl MailboxReq {
             MailboxReq::IncrementPcrResetCounter(_) => CommandId::INCREMENT_PCR_RESET_COUNTER,
             MailboxReq::QuotePcrs(_) => CommandId::QUOTE_PCRS,
             MailboxReq::ExtendPcr(_) => CommandId::EXTEND_PCR,
+            MailboxReq::GetCertChain(_) => CommandId::GET_CERT_CHAIN,
+            MailboxReq::GetEat(_) => CommandId::GET_EAT,
+            MailboxReq::ChunkedBegin(_) => CommandId::CHUNKED_BEGIN,
+            MailboxReq::ChunkedContinue(_) => CommandId::CHUNKED_CONTINUE,
+            MailboxReq::ChunkedEnd(_) => CommandId::CHUNKED_END,
+            MailboxReq::ChunkedRead(_) => CommandId::CHUNKED_READ,
+            MailboxReq::SetIdevCsrNonce(_) => CommandId::SET_IDEV_CSR_NONCE,
+            MailboxReq::GetIdevCsr(_) => CommandId::GET_IDEV_CSR,
+            MailboxReq::GetTimeNonce(_) => CommandId::GET_TIME_NONCE,
+            MailboxReq::SetTime(_) => CommandId::SET_TIME,
 
             #[cfg(feature = "test_only_commands")]
             MailboxReq::TestHmacVerify(_) => CommandId::TEST_ONLY_HMAC384_VERIFY,
@@ -496,6 +568,85 @@ impl Default for GetRtAliasCertResp {
     }
 }
 
+// GET_CERT_CHAIN
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCertChainReq {
+    pub hdr: MailboxReqHeader,
+    /// Offset into the concatenated chain at which to start this chunk
+    pub offset: u32,
+    pub flags: u32,
+}
+impl GetCertChainReq {
+    /// Append the DPE leaf certificate of the caller's default context
+    pub const FLAG_INCLUDE_DPE_LEAF: u32 = 1 << 0;
+}
+impl Request for GetCertChainReq {
+    const ID: CommandId = CommandId::GET_CERT_CHAIN;
+    type Resp = GetCertChainResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCertChainResp {
+    pub hdr: MailboxRespHeader,
+    /// Size in bytes of the complete concatenated chain
+    pub total_size: u32,
+    /// Number of valid entries in `cert_offsets`
+    pub cert_count: u32,
+    /// Offset of each certificate within the complete chain, root first
+    pub cert_offsets: [u32; GetCertChainResp::MAX_CERT_COUNT],
+    /// Offset to request next; equal to `total_size` once the chain is complete
+    pub next_offset: u32,
+    pub data_size: u32,
+    pub data: [u8; GetCertChainResp::DATA_MAX_SIZE], // variable length
+}
+impl GetCertChainResp {
+    pub const MAX_CERT_COUNT: usize = 5;
+    pub const DATA_MAX_SIZE: usize = 2048;
+
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.get(..self.data_size as usize)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_offset >= self.total_size
+    }
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Response for GetCertChainResp {
+    const MIN_SIZE: usize = size_of::<Self>() - Self::DATA_MAX_SIZE;
+}
+
+impl Default for GetCertChainResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            total_size: 0,
+            cert_count: 0,
+            cert_offsets: [0u32; GetCertChainResp::MAX_CERT_COUNT],
+            next_offset: 0,
+            data_size: 0,
+            data: [0u8; GetCertChainResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
 // ECDSA384_SIGNATURE_VERIFY
 #[repr(C)]
 #[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
@@ -834,6 +985,366 @@ impl Request for QuotePcrsReq {
     type Resp = QuotePcrsResp;
 }
 
+// GET_EAT
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetEatReq {
+    pub hdr: MailboxReqHeader,
+    pub nonce: [u8; 32],
+    pub flags: u32,
+}
+impl GetEatReq {
+    /// Sign with the DPE key certified by the GET_CERT_CHAIN leaf instead
+    /// of the RT alias key
+    pub const FLAG_SIGN_WITH_DPE_KEY: u32 = 1 << 0;
+}
+impl Request for GetEatReq {
+    const ID: CommandId = CommandId::GET_EAT;
+    type Resp = GetEatResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetEatResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetEatResp::DATA_MAX_SIZE], // variable length
+}
+impl GetEatResp {
+    pub const DATA_MAX_SIZE: usize = 2200;
+}
+impl ResponseVarSize for GetEatResp {}
+
+impl Default for GetEatResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetEatResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+/// Claim keys used in the GET_EAT token. Standard claims use their IANA CWT
+/// keys; Caliptra-specific claims use keys from the private-use range.
+pub struct EatClaim;
+impl EatClaim {
+    pub const NONCE: i64 = 10;
+    pub const UEID: i64 = 256;
+    pub const PCRS: i64 = -70000;
+    pub const PCR_RESET_COUNTERS: i64 = -70001;
+    pub const FMC_SVN: i64 = -70002;
+    pub const RT_SVN: i64 = -70003;
+    pub const RT_MIN_SVN: i64 = -70004;
+    pub const FMC_FWID: i64 = -70005;
+    pub const RT_FWID: i64 = -70006;
+}
+
+// GET_CRASH_INFO
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCrashInfoReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetCrashInfoReq {
+    const ID: CommandId = CommandId::GET_CRASH_INFO;
+    type Resp = GetCrashInfoResp;
+}
+
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetCrashInfoResp {
+    pub hdr: MailboxRespHeader,
+    /// Non-zero if a crash was recorded since the last cold reset
+    pub valid: u32,
+    pub fw_error_fatal: u32,
+    pub boot_status: u32,
+    pub last_mbox_cmd: u32,
+    pub mcause: u32,
+    pub mscause: u32,
+    pub mepc: u32,
+    pub mtval: u32,
+    pub mstatus: u32,
+    pub err_interrupt_status: u32,
+    /// ra, sp, a0-a7 and t0-t6, in that order
+    pub regs: [u32; GetCrashInfoResp::REG_COUNT],
+    pub stack: [u32; GetCrashInfoResp::STACK_WORDS],
+    pub cfi_state: [u32; GetCrashInfoResp::CFI_STATE_WORDS],
+}
+impl GetCrashInfoResp {
+    pub const REG_COUNT: usize = 17;
+    pub const STACK_WORDS: usize = 16;
+    pub const CFI_STATE_WORDS: usize = 6;
+}
+impl Response for GetCrashInfoResp {}
+
+// CHUNKED_BEGIN
+// No command-specific output args
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ChunkedBeginReq {
+    pub hdr: MailboxReqHeader,
+    /// Command carried by the transfer
+    pub cmd: u32,
+    /// Size in bytes of the complete request, including its header
+    pub total_size: u32,
+    /// Checksum over the complete request, computed with `cmd`
+    pub payload_chksum: u32,
+    pub data_size: u32,
+    pub data: [u8; ChunkedBeginReq::DATA_MAX_SIZE], // variable length
+}
+impl ChunkedBeginReq {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Default for ChunkedBeginReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            cmd: 0,
+            total_size: 0,
+            payload_chksum: 0,
+            data_size: 0,
+            data: [0u8; ChunkedBeginReq::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// CHUNKED_CONTINUE and CHUNKED_END
+// CHUNKED_CONTINUE has no command-specific output args; CHUNKED_END returns
+// the first chunk of the response as a ChunkedResp.
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ChunkedDataReq {
+    pub hdr: MailboxReqHeader,
+    /// Offset of this chunk within the complete request
+    pub offset: u32,
+    pub data_size: u32,
+    pub data: [u8; ChunkedDataReq::DATA_MAX_SIZE], // variable length
+}
+impl ChunkedDataReq {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Default for ChunkedDataReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            offset: 0,
+            data_size: 0,
+            data: [0u8; ChunkedDataReq::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// CHUNKED_READ
+#[repr(C)]
+#[derive(Debug, Default, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ChunkedReadReq {
+    pub hdr: MailboxReqHeader,
+    /// Offset within the complete response at which to start this chunk
+    pub offset: u32,
+}
+impl Request for ChunkedReadReq {
+    const ID: CommandId = CommandId::CHUNKED_READ;
+    type Resp = ChunkedResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct ChunkedResp {
+    pub hdr: MailboxRespHeader,
+    /// Size in bytes of the complete response, including its header
+    pub total_size: u32,
+    /// Checksum over the complete response, computed with command 0
+    pub payload_chksum: u32,
+    /// Offset of this chunk within the complete response
+    pub offset: u32,
+    pub data_size: u32,
+    pub data: [u8; ChunkedResp::DATA_MAX_SIZE], // variable length
+}
+impl ChunkedResp {
+    pub const DATA_MAX_SIZE: usize = 1024;
+
+    pub fn data(&self) -> Option<&[u8]> {
+        self.data.get(..self.data_size as usize)
+    }
+
+    pub fn as_bytes_partial(&self) -> CaliptraResult<&[u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&self.as_bytes()[..size_of::<Self>() - unused_byte_count])
+    }
+
+    pub fn as_bytes_partial_mut(&mut self) -> CaliptraResult<&mut [u8]> {
+        if self.data_size as usize > Self::DATA_MAX_SIZE {
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        let unused_byte_count = Self::DATA_MAX_SIZE - self.data_size as usize;
+        Ok(&mut self.as_bytes_mut()[..size_of::<Self>() - unused_byte_count])
+    }
+}
+impl Response for ChunkedResp {
+    const MIN_SIZE: usize = size_of::<Self>() - Self::DATA_MAX_SIZE;
+}
+
+impl Default for ChunkedResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            total_size: 0,
+            payload_chksum: 0,
+            offset: 0,
+            data_size: 0,
+            data: [0u8; ChunkedResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// SET_IDEV_CSR_NONCE (ROM only)
+// No command-specific output args
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SetIdevCsrNonceReq {
+    pub hdr: MailboxReqHeader,
+    /// Nonce embedded in the challengePassword attribute of the IDevID CSR
+    pub nonce: [u8; 32],
+}
+impl Request for SetIdevCsrNonceReq {
+    const ID: CommandId = CommandId::SET_IDEV_CSR_NONCE;
+    type Resp = MailboxRespHeader;
+}
+
+// GET_IDEV_CSR
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetIdevCsrReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetIdevCsrReq {
+    const ID: CommandId = CommandId::GET_IDEV_CSR;
+    type Resp = GetIdevCsrResp;
+}
+
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetIdevCsrResp {
+    pub hdr: MailboxRespHeader,
+    pub data_size: u32,
+    pub data: [u8; GetIdevCsrResp::DATA_MAX_SIZE], // variable length
+}
+impl GetIdevCsrResp {
+    pub const DATA_MAX_SIZE: usize = 1020;
+}
+impl ResponseVarSize for GetIdevCsrResp {}
+
+impl Default for GetIdevCsrResp {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxRespHeader::default(),
+            data_size: 0,
+            data: [0u8; GetIdevCsrResp::DATA_MAX_SIZE],
+        }
+    }
+}
+
+// GET_TIME_NONCE
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetTimeNonceReq {
+    pub hdr: MailboxReqHeader,
+}
+impl Request for GetTimeNonceReq {
+    const ID: CommandId = CommandId::GET_TIME_NONCE;
+    type Resp = GetTimeNonceResp;
+}
+
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct GetTimeNonceResp {
+    pub hdr: MailboxRespHeader,
+    /// Nonce the next SET_TIME token must be bound to
+    pub nonce: [u8; 32],
+}
+impl Response for GetTimeNonceResp {}
+
+// SET_TIME
+#[repr(C)]
+#[derive(Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SetTimeReq {
+    pub hdr: MailboxReqHeader,
+    /// Current time as an ASN.1 GeneralizedTime, "YYYYMMDDHHMMSSZ"
+    pub time: [u8; 15],
+    pub reserved: u8,
+    /// Owner ECDSA-384 signature over the SHA-384 digest of the nonce
+    /// returned by GET_TIME_NONCE followed by `time`
+    pub signature_r: [u8; 48],
+    pub signature_s: [u8; 48],
+}
+impl Request for SetTimeReq {
+    const ID: CommandId = CommandId::SET_TIME;
+    type Resp = SetTimeResp;
+}
+
+impl Default for SetTimeReq {
+    fn default() -> Self {
+        Self {
+            hdr: MailboxReqHeader::default(),
+            time: [0u8; 15],
+            reserved: 0,
+            signature_r: [0u8; 48],
+            signature_s: [0u8; 48],
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Default, Debug, AsBytes, FromBytes, PartialEq, Eq)]
+pub struct SetTimeResp {
+    pub hdr: MailboxRespHeader,
+    /// Whether the time is within the validity window of the firmware
+    /// certificates. One of the `CERT_*` values.
+    pub cert_validity: u32,
+}
+impl SetTimeResp {
+    pub const CERT_VALID: u32 = 0;
+    pub const CERT_NOT_YET_VALID: u32 = 1;
+    pub const CERT_EXPIRED: u32 = 2;
+}
+impl Response for SetTimeResp {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,10 @@
 // Licensed under the Apache-2.0 license
 
 use caliptra_builder::firmware;
-use caliptra_builder::ImageOptions;
-use clap::{arg, value_parser, Command};
+use caliptra_builder::reproducible::{self, Artifact, ArtifactKind};
+use clap::{arg, value_parser, ArgAction, Command};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn main() {
     let args = Command::new("image-gen")
@@ -24,42 +24,51 @@ fn main() {
         )
         .arg(arg!(--"fake-rom" [FILE] "Fake ROM").value_parser(value_parser!(PathBuf)))
         .arg(arg!(--"fake-fw" [FILE] "Fake FW bundle image").value_parser(value_parser!(PathBuf)))
+        .arg(
+            arg!(--"reproducible" "Build bit-reproducible firmware")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"provenance" [DIR] "Write SLSA provenance and SPDX SBOMs of the built images")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"verify-provenance" [FILE] "Rebuild the images in a provenance file and compare digests")
+                .value_parser(value_parser!(PathBuf)),
+        )
         .get_matches();
 
-    if let Some(path) = args.get_one::<PathBuf>("rom-no-log") {
-        let rom = caliptra_builder::build_firmware_rom(&firmware::ROM).unwrap();
-        std::fs::write(path, rom).unwrap();
+    if args.get_flag("reproducible")
+        || args.contains_id("provenance")
+        || args.contains_id("verify-provenance")
+    {
+        std::env::set_var(reproducible::REPRODUCIBLE_BUILD_ENV, "1");
     }
 
-    if let Some(path) = args.get_one::<PathBuf>("rom-with-log") {
-        let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART).unwrap();
-        std::fs::write(path, rom).unwrap();
-    }
-    if let Some(path) = args.get_one::<PathBuf>("fake-rom") {
-        let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_FAKE_WITH_UART).unwrap();
-        std::fs::write(path, rom).unwrap();
+    let mut artifacts = vec![];
+    for kind in ArtifactKind::ALL {
+        if let Some(path) = args.get_one::<PathBuf>(kind.arg_name()) {
+            let artifact = kind.build().unwrap();
+            std::fs::write(path, &artifact.contents).unwrap();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            artifacts.push((name, artifact));
+        }
     }
 
-    if let Some(path) = args.get_one::<PathBuf>("fw") {
-        // Generate Image Bundle
-        let image = caliptra_builder::build_and_sign_image(
-            &firmware::FMC_WITH_UART,
-            &firmware::APP_WITH_UART,
-            ImageOptions::default(),
-        )
-        .unwrap();
-        std::fs::write(path, image.to_bytes().unwrap()).unwrap();
+    if let Some(dir) = args.get_one::<PathBuf>("provenance") {
+        write_provenance(dir, &artifacts);
     }
 
-    if let Some(path) = args.get_one::<PathBuf>("fake-fw") {
-        // Generate Image Bundle
-        let image = caliptra_builder::build_and_sign_image(
-            &firmware::FMC_FAKE_WITH_UART,
-            &firmware::APP_WITH_UART,
-            ImageOptions::default(),
-        )
-        .unwrap();
-        std::fs::write(path, image.to_bytes().unwrap()).unwrap();
+    if let Some(path) = args.get_one::<PathBuf>("verify-provenance") {
+        let provenance = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+        let mismatches = reproducible::verify_provenance(&provenance).unwrap();
+        for mismatch in mismatches.iter() {
+            eprintln!("Mismatch: {mismatch}");
+        }
+        if !mismatches.is_empty() {
+            std::process::exit(1);
+        }
+        println!("All images in {} reproduced", path.display());
     }
 
     let mut used_filenames = HashSet::new();
@@ -76,6 +85,33 @@ fn main() {
     }
 }
 
+/// Write an SBOM for each firmware in `artifacts` and a provenance statement
+/// covering all of them to `dir`
+fn write_provenance(dir: &Path, artifacts: &[(String, Artifact)]) {
+    std::fs::create_dir_all(dir).unwrap();
+
+    let mut sboms: Vec<(String, Vec<u8>)> = vec![];
+    for (_, artifact) in artifacts {
+        for fwid in artifact.kind.fwids() {
+            let name = reproducible::sbom_filename(fwid);
+            if sboms.iter().any(|(n, _)| *n == name) {
+                continue;
+            }
+            let sbom = reproducible::sbom(fwid).unwrap();
+            let contents = serde_json::to_vec_pretty(&sbom).unwrap();
+            std::fs::write(dir.join(&name), &contents).unwrap();
+            sboms.push((name, contents));
+        }
+    }
+
+    let provenance = reproducible::provenance(artifacts, &sboms).unwrap();
+    std::fs::write(
+        dir.join("provenance.json"),
+        serde_json::to_vec_pretty(&provenance).unwrap(),
+    )
+    .unwrap();
+}
+
 #[test]
 #[cfg_attr(not(feature = "slow_tests"), ignore)]
 fn test_binaries_are_identical() {
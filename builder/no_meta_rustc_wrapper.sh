@@ -43,4 +43,13 @@ for arg in "$@"; do
     fi
 done
 
+# For reproducible builds, caliptra-builder passes newline-separated FROM=TO
+# path prefixes to strip host-specific paths (workspace, cargo home, target
+# dir) from panic locations and debug info.
+if [[ -n "${CALIPTRA_REMAP_PATH_PREFIX}" ]]; then
+    while IFS= read -r remap; do
+        args+=("--remap-path-prefix=${remap}")
+    done <<< "${CALIPTRA_REMAP_PATH_PREFIX}"
+fi
+
 exec "${args[@]}"
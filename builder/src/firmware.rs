@@ -10,6 +10,7 @@ pub fn rom_from_env() -> &'static FwId<'static> {
         Ok("ROM") => &ROM,
         Ok("ROM_WITHOUT_UART") => &ROM,
         Ok("ROM_WITH_UART") => &ROM_WITH_UART,
+        Ok("ROM_WITH_UART_EXTENDED") => &ROM_WITH_UART_EXTENDED,
         Ok(s) => panic!("unexpected CPRTA_TEST_ROM env-var value: {s:?}"),
         Err(_) => &ROM_WITH_UART,
     }
@@ -27,10 +28,34 @@ pub const ROM_WITH_UART: FwId = FwId {
     features: &["emu"],
 };
 
+/// ROM with the optional features that are left out of the frozen ROM
+pub const ROM_WITH_UART_EXTENDED: FwId = FwId {
+    crate_name: "caliptra-rom",
+    bin_name: "caliptra-rom",
+    features: &[
+        "emu",
+        "mldsa",
+        "lms-hss",
+        "components",
+        "crash-record",
+        "idevid-csr",
+        "cbor-certs",
+    ],
+};
+
 pub const ROM_FAKE_WITH_UART: FwId = FwId {
     crate_name: "caliptra-rom",
     bin_name: "caliptra-rom",
-    features: &["emu", "fake-rom"],
+    features: &[
+        "emu",
+        "fake-rom",
+        "mldsa",
+        "lms-hss",
+        "components",
+        "crash-record",
+        "idevid-csr",
+        "cbor-certs",
+    ],
 };
 
 pub const FMC_WITH_UART: FwId = FwId {
@@ -57,6 +82,17 @@ pub const APP_WITH_UART: FwId = FwId {
     features: &["emu", "test_only_commands", "fips_self_test"],
 };
 
+pub const APP_WITH_UART_IDEVID_ISSUER: FwId = FwId {
+    crate_name: "caliptra-runtime",
+    bin_name: "caliptra-runtime",
+    features: &[
+        "emu",
+        "test_only_commands",
+        "fips_self_test",
+        "test_idevid_issuer",
+    ],
+};
+
 pub const APP_WITH_UART_FPGA: FwId = FwId {
     crate_name: "caliptra-runtime",
     bin_name: "caliptra-runtime",
@@ -253,6 +289,11 @@ pub mod driver_tests {
         ..BASE_FWID
     };
 
+    pub const TEST_MLDSA87: FwId = FwId {
+        bin_name: "test_mldsa87",
+        ..BASE_FWID
+    };
+
     pub const TEST_UART: FwId = FwId {
         bin_name: "test_uart",
         ..BASE_FWID
@@ -382,11 +423,13 @@ pub mod runtime_tests {
 pub const REGISTERED_FW: &[&FwId] = &[
     &ROM,
     &ROM_WITH_UART,
+    &ROM_WITH_UART_EXTENDED,
     &ROM_FAKE_WITH_UART,
     &FMC_WITH_UART,
     &FMC_FAKE_WITH_UART,
     &APP,
     &APP_WITH_UART,
+    &APP_WITH_UART_IDEVID_ISSUER,
     &APP_WITH_UART_FPGA,
     &caliptra_builder_tests::FWID,
     &hw_model_tests::MAILBOX_RESPONDER,
@@ -420,6 +463,7 @@ pub const REGISTERED_FW: &[&FwId] = &[
     &driver_tests::TEST_LMS_24,
     &driver_tests::TEST_LMS_32,
     &driver_tests::TEST_NEGATIVE_LMS,
+    &driver_tests::TEST_MLDSA87,
     &driver_tests::TEST_UART,
     &driver_tests::CSRNG,
     &driver_tests::CSRNG2,
@@ -13,23 +13,26 @@ use std::sync::{Arc, Mutex};
 
 use caliptra_image_elf::ElfExecutable;
 use caliptra_image_gen::{
-    ImageGenerator, ImageGeneratorConfig, ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig,
+    ImageGenerator, ImageGeneratorComponent, ImageGeneratorConfig, ImageGeneratorOwnerConfig,
+    ImageGeneratorVendorConfig,
 };
-use caliptra_image_openssl::OsslCrypto;
-use caliptra_image_types::{ImageBundle, ImageRevision, RomInfo};
+use caliptra_image_openssl::{LmsKeyState, OsslCrypto};
+use caliptra_image_types::{ImageBundle, ImageLmsPrivKey, ImageRevision, RomInfo};
 use elf::endian::LittleEndian;
 use nix::fcntl::FlockArg;
 use zerocopy::AsBytes;
 
-mod elf_symbols;
 pub mod firmware;
+pub mod reproducible;
 mod sha256;
 
-pub use elf_symbols::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
+pub use caliptra_image_elf::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
 use once_cell::sync::Lazy;
 
 pub const THIS_WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
 
+const FIRMWARE_TARGET: &str = "riscv32imc-unknown-none-elf";
+
 fn other_err(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
     io::Error::new(ErrorKind::Other, e)
 }
@@ -127,7 +130,6 @@ pub fn build_firmware_elfs_uncached<'a>(
     workspace_dir: Option<&Path>,
     fwids: &'a [&'a FwId<'a>],
 ) -> io::Result<Vec<(&'a FwId<'a>, Vec<u8>)>> {
-    const TARGET: &str = "riscv32imc-unknown-none-elf";
     const PROFILE: &str = "firmware";
 
     let cargo_invocations = cargo_invocations_from_fwids(fwids)?;
@@ -135,14 +137,6 @@ pub fn build_firmware_elfs_uncached<'a>(
     let mut result_map = HashMap::new();
 
     for invocation in cargo_invocations {
-        let mut features_csv = invocation.features.join(",");
-        if !invocation.features.contains(&"riscv") {
-            if !features_csv.is_empty() {
-                features_csv.push(',');
-            }
-            features_csv.push_str("riscv");
-        }
-
         let workspace_dir = workspace_dir.unwrap_or_else(|| Path::new(THIS_WORKSPACE_DIR));
 
         // To prevent a race condition with concurrent calls to caliptra-builder
@@ -161,6 +155,17 @@ pub fn build_firmware_elfs_uncached<'a>(
             workspace_dir.join("builder/no_meta_rustc_wrapper.sh"),
         );
 
+        let target_dir = if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
+            PathBuf::from(dir)
+        } else {
+            Path::new(workspace_dir).join("target")
+        };
+
+        if reproducible::enabled() {
+            fs::create_dir_all(&target_dir)?;
+            reproducible::configure_cargo(&mut cmd, workspace_dir, &target_dir)?;
+        }
+
         if option_env!("GITHUB_ACTIONS").is_some() {
             // In continuous integration, warnings are always errors.
             cmd.arg("--config")
@@ -170,9 +175,9 @@ pub fn build_firmware_elfs_uncached<'a>(
             .arg("--quiet")
             .arg("--locked")
             .arg("--target")
-            .arg(TARGET)
+            .arg(FIRMWARE_TARGET)
             .arg("--features")
-            .arg(features_csv)
+            .arg(features_csv(invocation.features))
             .arg("--no-default-features")
             .arg("--profile")
             .arg(PROFILE);
@@ -183,16 +188,15 @@ pub fn build_firmware_elfs_uncached<'a>(
         }
         run_cmd(&mut cmd)?;
 
-        let target_dir = if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
-            PathBuf::from(dir)
-        } else {
-            Path::new(workspace_dir).join("target")
-        };
-
         for &fwid in invocation.fwids.iter() {
             result_map.insert(
                 fwid,
-                fs::read(target_dir.join(TARGET).join(PROFILE).join(fwid.bin_name))?,
+                fs::read(
+                    target_dir
+                        .join(FIRMWARE_TARGET)
+                        .join(PROFILE)
+                        .join(fwid.bin_name),
+                )?,
             );
         }
     }
@@ -209,6 +213,18 @@ pub fn build_firmware_elfs_uncached<'a>(
         .collect())
 }
 
+/// The cargo features to build a firmware binary with
+fn features_csv(features: &[&str]) -> String {
+    let mut features_csv = features.join(",");
+    if !features.contains(&"riscv") {
+        if !features_csv.is_empty() {
+            features_csv.push(',');
+        }
+        features_csv.push_str("riscv");
+    }
+    features_csv
+}
+
 /// Compute the minimum number of cargo invocations to build all the specified
 /// fwids.
 fn cargo_invocations_from_fwids<'a>(
@@ -413,6 +429,13 @@ pub struct ImageOptions {
     pub app_svn: u32,
     pub vendor_config: ImageGeneratorVendorConfig,
     pub owner_config: Option<ImageGeneratorOwnerConfig>,
+    /// LMS key state file tracking the leaves used by the LMS private keys.
+    /// Must be set when signing with real keys; the fake keys only support
+    /// the fixed leaf used when this is `None`, and signing with any other
+    /// LMS key fails without it.
+    pub lms_key_state: Option<PathBuf>,
+    /// Additional components to place in the image after the runtime.
+    pub components: Vec<ImageGeneratorComponent>,
 }
 impl Default for ImageOptions {
     fn default() -> Self {
@@ -423,6 +446,8 @@ impl Default for ImageOptions {
             app_svn: Default::default(),
             vendor_config: caliptra_image_fake_keys::VENDOR_CONFIG_KEY_0,
             owner_config: Some(caliptra_image_fake_keys::OWNER_CONFIG),
+            lms_key_state: None,
+            components: Vec::new(),
         }
     }
 }
@@ -432,18 +457,58 @@ pub fn build_and_sign_image(
     app: &FwId<'static>,
     opts: ImageOptions,
 ) -> anyhow::Result<ImageBundle> {
+    let crypto = match &opts.lms_key_state {
+        Some(path) => OsslCrypto::with_lms_state(LmsKeyState::open(path)?),
+        None if signs_only_with_fake_lms_keys(&opts) => OsslCrypto::default(),
+        None => anyhow::bail!(
+            "lms_key_state must be set to sign with LMS keys other than the fake keys"
+        ),
+    };
     let fmc_elf = build_firmware_elf(fmc)?;
     let app_elf = build_firmware_elf(app)?;
-    let gen = ImageGenerator::new(OsslCrypto::default());
+    let gen = ImageGenerator::new(crypto);
     let image = gen.generate(&ImageGeneratorConfig {
         fmc: ElfExecutable::new(&fmc_elf, opts.fmc_version, opts.fmc_svn, image_revision()?)?,
         runtime: ElfExecutable::new(&app_elf, opts.app_version, opts.app_svn, image_revision()?)?,
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        components: opts.components,
     })?;
     Ok(image)
 }
 
+/// Returns true if every LMS private key in `opts` is one of the fake keys,
+/// which may be reused with the fixed leaf used without an LMS state file
+fn signs_only_with_fake_lms_keys(opts: &ImageOptions) -> bool {
+    use caliptra_image_fake_keys::*;
+    const FAKE_KEYS: [ImageLmsPrivKey; 5] = [
+        VENDOR_LMS_KEY_0_PRIVATE,
+        VENDOR_LMS_KEY_1_PRIVATE,
+        VENDOR_LMS_KEY_2_PRIVATE,
+        VENDOR_LMS_KEY_3_PRIVATE,
+        OWNER_LMS_KEY_PRIVATE,
+    ];
+
+    let vendor = &opts.vendor_config;
+    let owner = opts.owner_config.as_ref();
+    let vendor_keys = vendor.priv_keys.iter().flat_map(|k| k.lms_priv_keys.iter());
+    let owner_keys = owner
+        .and_then(|o| o.priv_keys.as_ref())
+        .map(|k| &k.lms_priv_key);
+    let hss_keys = [
+        vendor.lms_hss.as_ref(),
+        owner.and_then(|o| o.lms_hss.as_ref()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|hss| hss.priv_key.as_ref());
+
+    vendor_keys
+        .chain(owner_keys)
+        .chain(hss_keys)
+        .all(|key| FAKE_KEYS.contains(key))
+}
+
 fn image_revision() -> io::Result<ImageRevision> {
     if std::env::var_os("CALIPTRA_IMAGE_NO_GIT_REVISION").is_some() {
         // Sometimes needed to build a consistent ROM image from different
@@ -457,6 +522,11 @@ fn image_revision_from_git_repo() -> io::Result<ImageRevision> {
     let commit_id = run_cmd_stdout(Command::new("git").arg("rev-parse").arg("HEAD"), None)?;
     let rtl_git_status =
         run_cmd_stdout(Command::new("git").arg("status").arg("--porcelain"), None)?;
+    if reproducible::enabled() && !rtl_git_status.is_empty() {
+        return Err(other_err(
+            "Reproducible builds require a clean git tree; commit or stash local changes",
+        ));
+    }
     image_revision_from_str(&commit_id, rtl_git_status.is_empty())
 }
 
@@ -503,6 +573,31 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_real_lms_keys_require_lms_key_state() {
+        assert!(signs_only_with_fake_lms_keys(&ImageOptions::default()));
+
+        let mut opts = ImageOptions::default();
+        if let Some(priv_keys) = opts.vendor_config.priv_keys.as_mut() {
+            priv_keys.lms_priv_keys[0].id[0] ^= 1;
+        }
+        assert!(!signs_only_with_fake_lms_keys(&opts));
+
+        let mut opts = ImageOptions::default();
+        if let Some(priv_keys) = opts
+            .owner_config
+            .as_mut()
+            .and_then(|o| o.priv_keys.as_mut())
+        {
+            priv_keys.lms_priv_key.id[0] ^= 1;
+        }
+        assert!(!signs_only_with_fake_lms_keys(&opts));
+
+        let err = build_and_sign_image(&firmware::FMC_WITH_UART, &firmware::APP_WITH_UART, opts)
+            .unwrap_err();
+        assert!(err.to_string().contains("lms_key_state must be set"));
+    }
+
     #[test]
     fn test_elf2rom_golden() {
         let rom_bytes = elf2rom(include_bytes!("testdata/example.elf")).unwrap();
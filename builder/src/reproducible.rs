@@ -0,0 +1,632 @@
+// Licensed under the Apache-2.0 license
+
+//! Reproducible firmware builds, with SPDX SBOMs and in-toto/SLSA provenance.
+//!
+//! Reproducible mode is enabled by setting the `CALIPTRA_REPRODUCIBLE_BUILD`
+//! environment variable. In this mode caliptra-builder:
+//!
+//! * remaps the workspace, cargo home and target directories out of the
+//!   firmware binaries (see no_meta_rustc_wrapper.sh),
+//! * sets `SOURCE_DATE_EPOCH` to the commit time of HEAD (unless already set),
+//! * refuses to stamp the fmc/rt/ROM revision from a dirty git tree.
+//!
+//! ECDSA signatures are randomized and LMS signatures depend on the leaf taken
+//! from the LMS key state, so firmware bundles are compared using a digest
+//! that excludes the ECC and LMS signatures of the preamble and the HSS
+//! extension.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use caliptra_image_types::{ImageBundle, ImageHssExtension, ImageManifest};
+use serde_json::{json, Value};
+use zerocopy::AsBytes;
+
+use crate::firmware;
+use crate::{
+    build_and_sign_image, build_firmware_rom, features_csv, other_err, run_cmd_stdout, FwId,
+    ImageOptions, THIS_WORKSPACE_DIR,
+};
+
+/// Set to enable reproducible builds
+pub const REPRODUCIBLE_BUILD_ENV: &str = "CALIPTRA_REPRODUCIBLE_BUILD";
+
+/// Newline-separated `FROM=TO` path prefixes for no_meta_rustc_wrapper.sh to
+/// remap in firmware builds
+const REMAP_PATH_PREFIX_ENV: &str = "CALIPTRA_REMAP_PATH_PREFIX";
+
+const REMAPPED_WORKSPACE_DIR: &str = "/caliptra-sw";
+const REMAPPED_CARGO_HOME: &str = "/cargo";
+const REMAPPED_TARGET_DIR: &str = "/target";
+
+const SOURCE_URI: &str = "git+https://github.com/chipsalliance/caliptra-sw";
+const BUILD_TYPE: &str =
+    "https://github.com/chipsalliance/caliptra-sw/tree/main/builder#reproducible-builds";
+const BUILDER_ID: &str = "https://github.com/chipsalliance/caliptra-sw/tree/main/builder";
+
+/// Returns true if reproducible builds are enabled
+pub fn enabled() -> bool {
+    std::env::var_os(REPRODUCIBLE_BUILD_ENV).is_some()
+}
+
+/// Configure a firmware `cargo build` command for a reproducible build
+pub(crate) fn configure_cargo(
+    cmd: &mut Command,
+    workspace_dir: &Path,
+    target_dir: &Path,
+) -> io::Result<()> {
+    let mut remaps = vec![
+        (workspace_dir.canonicalize()?, REMAPPED_WORKSPACE_DIR),
+        (target_dir.canonicalize()?, REMAPPED_TARGET_DIR),
+    ];
+    if let Some(cargo_home) = cargo_home() {
+        remaps.push((cargo_home, REMAPPED_CARGO_HOME));
+    }
+    // rustc applies the last matching prefix, so remap the longest paths last
+    // in case one directory is nested within another.
+    remaps.sort_by_key(|(from, _)| from.as_os_str().len());
+    let remaps: Vec<String> = remaps
+        .iter()
+        .map(|(from, to)| format!("{}={to}", from.display()))
+        .collect();
+
+    cmd.env(REMAP_PATH_PREFIX_ENV, remaps.join("\n"));
+    cmd.env("SOURCE_DATE_EPOCH", source_date_epoch()?.to_string());
+    cmd.env("CARGO_INCREMENTAL", "0");
+    Ok(())
+}
+
+fn cargo_home() -> Option<PathBuf> {
+    let path = match std::env::var_os("CARGO_HOME") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".cargo"),
+    };
+    path.canonicalize().ok()
+}
+
+/// The `SOURCE_DATE_EPOCH` of the build; defaults to the commit time of HEAD
+pub fn source_date_epoch() -> io::Result<u64> {
+    let epoch = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => epoch,
+        Err(_) => run_cmd_stdout(
+            Command::new("git")
+                .current_dir(THIS_WORKSPACE_DIR)
+                .args(["log", "-1", "--format=%ct"]),
+            None,
+        )?,
+    };
+    epoch
+        .trim()
+        .parse()
+        .map_err(|e| other_err(format!("Invalid SOURCE_DATE_EPOCH {epoch:?}: {e}")))
+}
+
+fn git_commit() -> io::Result<String> {
+    Ok(run_cmd_stdout(
+        Command::new("git")
+            .current_dir(THIS_WORKSPACE_DIR)
+            .args(["rev-parse", "HEAD"]),
+        None,
+    )?
+    .trim()
+    .into())
+}
+
+fn rustc_version() -> io::Result<String> {
+    // Run from the workspace so the pinned toolchain is reported
+    Ok(run_cmd_stdout(
+        Command::new("rustc")
+            .current_dir(THIS_WORKSPACE_DIR)
+            .arg("--version"),
+        None,
+    )?
+    .trim()
+    .into())
+}
+
+/// Release artifacts built by the caliptra-builder binary
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArtifactKind {
+    RomNoLog,
+    RomWithLog,
+    FakeRom,
+    Fw,
+    FakeFw,
+}
+
+impl ArtifactKind {
+    pub const ALL: [ArtifactKind; 5] = [
+        ArtifactKind::RomNoLog,
+        ArtifactKind::RomWithLog,
+        ArtifactKind::FakeRom,
+        ArtifactKind::Fw,
+        ArtifactKind::FakeFw,
+    ];
+
+    /// Name of the caliptra-builder argument that builds the artifact
+    pub fn arg_name(self) -> &'static str {
+        match self {
+            ArtifactKind::RomNoLog => "rom-no-log",
+            ArtifactKind::RomWithLog => "rom-with-log",
+            ArtifactKind::FakeRom => "fake-rom",
+            ArtifactKind::Fw => "fw",
+            ArtifactKind::FakeFw => "fake-fw",
+        }
+    }
+
+    pub fn from_arg_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.arg_name() == name)
+    }
+
+    /// Firmware linked into the artifact
+    pub fn fwids(self) -> &'static [&'static FwId<'static>] {
+        match self {
+            ArtifactKind::RomNoLog => &[&firmware::ROM],
+            ArtifactKind::RomWithLog => &[&firmware::ROM_WITH_UART],
+            ArtifactKind::FakeRom => &[&firmware::ROM_FAKE_WITH_UART],
+            ArtifactKind::Fw => &[&firmware::FMC_WITH_UART, &firmware::APP_WITH_UART],
+            ArtifactKind::FakeFw => &[&firmware::FMC_FAKE_WITH_UART, &firmware::APP_WITH_UART],
+        }
+    }
+
+    pub fn build(self) -> anyhow::Result<Artifact> {
+        let fwids = self.fwids();
+        match self {
+            ArtifactKind::RomNoLog | ArtifactKind::RomWithLog | ArtifactKind::FakeRom => {
+                let rom = build_firmware_rom(fwids[0])?;
+                Ok(Artifact {
+                    kind: self,
+                    reproducible_digest: openssl::sha::sha384(&rom),
+                    contents: rom,
+                })
+            }
+            ArtifactKind::Fw | ArtifactKind::FakeFw => {
+                let image = build_and_sign_image(fwids[0], fwids[1], ImageOptions::default())?;
+                Ok(Artifact {
+                    kind: self,
+                    reproducible_digest: reproducible_image_digest(&image)?,
+                    contents: image.to_bytes()?,
+                })
+            }
+        }
+    }
+}
+
+/// A built release artifact
+pub struct Artifact {
+    pub kind: ArtifactKind,
+    pub contents: Vec<u8>,
+
+    /// SHA-384 of the contents, excluding any randomized signatures
+    pub reproducible_digest: [u8; 48],
+}
+
+/// SHA-384 of an image bundle with the ECC and LMS signatures zeroed
+fn reproducible_image_digest(image: &ImageBundle) -> io::Result<[u8; 48]> {
+    let mut manifest = image.manifest;
+    for sigs in [
+        &mut manifest.preamble.vendor_sigs,
+        &mut manifest.preamble.owner_sigs,
+    ] {
+        sigs.ecc_sig = Default::default();
+        sigs.lms_sig = Default::default();
+    }
+
+    let mut bytes = image.to_bytes()?;
+    bytes[..size_of::<ImageManifest>()].copy_from_slice(manifest.as_bytes());
+
+    // The HSS extension occupies the last bytes of the bundle
+    if let Some(mut hss_extension) = image.hss_extension {
+        for signed_pub_key in hss_extension
+            .vendor_signed_pub_keys
+            .iter_mut()
+            .chain(hss_extension.owner_signed_pub_keys.iter_mut())
+        {
+            signed_pub_key.sig = Default::default();
+        }
+        let offset = bytes.len() - size_of::<ImageHssExtension>();
+        bytes[offset..].copy_from_slice(hss_extension.as_bytes());
+    }
+    Ok(openssl::sha::sha384(&bytes))
+}
+
+/// A crate linked into a firmware binary
+#[derive(Debug, Eq, PartialEq)]
+struct Package {
+    name: String,
+    version: String,
+    source: PackageSource,
+    license: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum PackageSource {
+    /// Path relative to the workspace
+    Workspace(String),
+    Git(String),
+    Registry,
+}
+
+impl Package {
+    fn spdx_id(&self) -> String {
+        let id: String = format!("{}-{}", self.name, self.version)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!("SPDXRef-Package-{id}")
+    }
+
+    fn to_spdx(&self) -> Value {
+        let download_location = match &self.source {
+            PackageSource::Workspace(path) => format!("{SOURCE_URI}#{path}"),
+            PackageSource::Git(url) => format!("git+{url}"),
+            PackageSource::Registry => {
+                format!("https://crates.io/crates/{}/{}", self.name, self.version)
+            }
+        };
+        json!({
+            "name": self.name,
+            "SPDXID": self.spdx_id(),
+            "versionInfo": self.version,
+            "downloadLocation": download_location,
+            "filesAnalyzed": false,
+            "licenseConcluded": "NOASSERTION",
+            "licenseDeclared": self.license.as_deref().unwrap_or("NOASSERTION"),
+        })
+    }
+}
+
+/// Parse a line of `cargo tree --prefix none --format {p}|{l}` output
+fn parse_cargo_tree_line(line: &str, workspace_dir: &Path) -> Option<Package> {
+    let line = line.trim().trim_end_matches(" (*)");
+    let (package, license) = line.split_once('|')?;
+    let mut parts = package.splitn(3, ' ');
+    let name = parts.next()?;
+    let version = parts.next()?.strip_prefix('v')?;
+
+    let mut source = PackageSource::Registry;
+    for annotation in parts.next().unwrap_or("").split('(') {
+        let Some(annotation) = annotation.trim().strip_suffix(')') else {
+            continue;
+        };
+        if annotation.starts_with('/') {
+            let path = Path::new(annotation);
+            let path = path.strip_prefix(workspace_dir).unwrap_or(path);
+            source = PackageSource::Workspace(path.display().to_string());
+        } else if annotation.contains("://") {
+            source = PackageSource::Git(annotation.into());
+        }
+    }
+    Some(Package {
+        name: name.into(),
+        version: version.into(),
+        source,
+        license: Some(license.trim())
+            .filter(|l| !l.is_empty())
+            .map(Into::into),
+    })
+}
+
+/// Generate an SPDX SBOM of the crates linked into a firmware binary
+pub fn sbom(fwid: &FwId) -> io::Result<Value> {
+    let workspace_dir = Path::new(THIS_WORKSPACE_DIR).canonicalize()?;
+    let tree = run_cmd_stdout(
+        Command::new(env!("CARGO"))
+            .current_dir(&workspace_dir)
+            .args(["tree", "--quiet", "--locked"])
+            .args(["--target", crate::FIRMWARE_TARGET])
+            .args(["--edges", "normal"])
+            .args(["--prefix", "none"])
+            .args(["--format", "{p}|{l}"])
+            .arg("--no-default-features")
+            .args(["--features", &features_csv(fwid.features)])
+            .args(["-p", fwid.crate_name]),
+        None,
+    )?;
+
+    // Sort the packages so the SBOM is reproducible
+    let mut packages = BTreeMap::new();
+    for package in tree
+        .lines()
+        .filter_map(|line| parse_cargo_tree_line(line, &workspace_dir))
+    {
+        packages
+            .entry((package.name.clone(), package.version.clone()))
+            .or_insert(package);
+    }
+    let root = packages
+        .values()
+        .find(|p| p.name == fwid.crate_name)
+        .ok_or_else(|| other_err(format!("cargo tree did not list {}", fwid.crate_name)))?
+        .spdx_id();
+
+    let name = fwid.elf_filename().trim_end_matches(".elf").to_string();
+    let mut relationships = vec![json!({
+        "spdxElementId": "SPDXRef-DOCUMENT",
+        "relationshipType": "DESCRIBES",
+        "relatedSpdxElement": root,
+    })];
+    for package in packages.values().filter(|p| p.spdx_id() != root) {
+        relationships.push(json!({
+            "spdxElementId": root,
+            "relationshipType": "DEPENDS_ON",
+            "relatedSpdxElement": package.spdx_id(),
+        }));
+    }
+
+    Ok(json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": name,
+        "documentNamespace": format!("{BUILDER_ID}/spdx/{name}-{}", git_commit()?),
+        "creationInfo": {
+            "created": rfc3339(source_date_epoch()?),
+            "creators": ["Tool: caliptra-builder"],
+        },
+        "packages": packages.values().map(Package::to_spdx).collect::<Vec<_>>(),
+        "relationships": relationships,
+    }))
+}
+
+/// File name of the SBOM of a firmware binary
+pub fn sbom_filename(fwid: &FwId) -> String {
+    format!("{}.spdx.json", fwid.elf_filename().trim_end_matches(".elf"))
+}
+
+/// Generate an in-toto statement with SLSA provenance for `artifacts`, given
+/// as (file name, artifact) pairs. `sboms` are the (file name, contents) of
+/// the SBOMs written alongside the artifacts.
+pub fn provenance(
+    artifacts: &[(String, Artifact)],
+    sboms: &[(String, Vec<u8>)],
+) -> io::Result<Value> {
+    let subjects: Vec<Value> = artifacts
+        .iter()
+        .map(|(name, artifact)| {
+            json!({
+                "name": name,
+                "digest": { "sha384": hex::encode(openssl::sha::sha384(&artifact.contents)) },
+                "annotations": {
+                    "kind": artifact.kind.arg_name(),
+                    "reproducibleSha384": hex::encode(artifact.reproducible_digest),
+                },
+            })
+        })
+        .collect();
+    let parameters: Vec<Value> = artifacts
+        .iter()
+        .map(|(name, artifact)| {
+            json!({
+                "name": name,
+                "kind": artifact.kind.arg_name(),
+                "firmware": artifact
+                    .kind
+                    .fwids()
+                    .iter()
+                    .map(|fwid| fwid.elf_filename())
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    let byproducts: Vec<Value> = sboms
+        .iter()
+        .map(|(name, contents)| {
+            json!({
+                "name": name,
+                "digest": { "sha384": hex::encode(openssl::sha::sha384(contents)) },
+                "mediaType": "application/spdx+json",
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "_type": "https://in-toto.io/Statement/v1",
+        "subject": subjects,
+        "predicateType": "https://slsa.dev/provenance/v1",
+        "predicate": {
+            "buildDefinition": {
+                "buildType": BUILD_TYPE,
+                "externalParameters": { "artifacts": parameters },
+                "internalParameters": {
+                    "sourceDateEpoch": source_date_epoch()?,
+                    "rustc": rustc_version()?,
+                },
+                "resolvedDependencies": [{
+                    "uri": SOURCE_URI,
+                    "digest": { "gitCommit": git_commit()? },
+                }],
+            },
+            "runDetails": {
+                "builder": { "id": BUILDER_ID },
+                "byproducts": byproducts,
+            },
+        },
+    }))
+}
+
+/// Rebuild the artifacts listed in `provenance` and compare their digests.
+/// Returns a description of each mismatch.
+pub fn verify_provenance(provenance: &Value) -> anyhow::Result<Vec<String>> {
+    let build = &provenance["predicate"]["buildDefinition"];
+    let expected_commit = build["resolvedDependencies"][0]["digest"]["gitCommit"]
+        .as_str()
+        .ok_or_else(|| other_err("Provenance does not record the git commit"))?;
+    let commit = git_commit()?;
+    if expected_commit != commit {
+        anyhow::bail!(
+            "Provenance was generated from commit {expected_commit}, but HEAD is {commit}"
+        );
+    }
+
+    let mut mismatches = vec![];
+    let expected_rustc = build["internalParameters"]["rustc"].as_str().unwrap_or("");
+    let rustc = rustc_version()?;
+    if expected_rustc != rustc {
+        mismatches.push(format!(
+            "toolchain: provenance used {expected_rustc:?}, rebuilt with {rustc:?}"
+        ));
+    }
+
+    let subjects = provenance["subject"]
+        .as_array()
+        .ok_or_else(|| other_err("Provenance has no subjects"))?;
+    for subject in subjects {
+        let name = subject["name"].as_str().unwrap_or("<unnamed>");
+        let kind = subject["annotations"]["kind"]
+            .as_str()
+            .and_then(ArtifactKind::from_arg_name)
+            .ok_or_else(|| other_err(format!("{name}: unknown artifact kind")))?;
+        let expected = subject["annotations"]["reproducibleSha384"]
+            .as_str()
+            .ok_or_else(|| other_err(format!("{name}: no reproducible digest")))?;
+
+        let digest = hex::encode(kind.build()?.reproducible_digest);
+        if digest != expected {
+            mismatches.push(format!("{name}: expected {expected}, rebuilt {digest}"));
+        }
+    }
+    Ok(mismatches)
+}
+
+/// Format a UNIX timestamp as an RFC 3339 UTC date-time
+fn rfc3339(epoch: u64) -> String {
+    let days = (epoch / 86400) as i64;
+    let secs = epoch % 86400;
+
+    // Convert days since 1970-01-01 to a civil date
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reproducible_image_digest_excludes_signatures() {
+        let mut image = ImageBundle {
+            hss_extension: Some(ImageHssExtension::default()),
+            ..Default::default()
+        };
+        image.manifest.fmc.offset = size_of::<ImageManifest>() as u32;
+        image.manifest.runtime.offset = size_of::<ImageManifest>() as u32;
+        let digest = reproducible_image_digest(&image).unwrap();
+
+        let preamble = &mut image.manifest.preamble;
+        preamble.vendor_sigs.ecc_sig.r[0] = 1;
+        preamble.vendor_sigs.lms_sig.q = 2.into();
+        preamble.owner_sigs.ecc_sig.s[0] = 3;
+        preamble.owner_sigs.lms_sig.q = 4.into();
+        let hss_extension = image.hss_extension.as_mut().unwrap();
+        hss_extension.vendor_signed_pub_keys[0].sig.q = 5.into();
+        hss_extension.owner_signed_pub_keys[0].sig.q = 6.into();
+        assert_eq!(reproducible_image_digest(&image).unwrap(), digest);
+
+        let hss_extension = image.hss_extension.as_mut().unwrap();
+        hss_extension.vendor_signed_pub_keys[0].pub_key.id[0] = 7;
+        assert_ne!(reproducible_image_digest(&image).unwrap(), digest);
+    }
+
+    #[test]
+    fn test_reproducible_build_twice() {
+        // The ECDSA signatures differ between the two builds, but nothing else
+        // should.
+        let first = ArtifactKind::FakeFw.build().unwrap();
+        let second = ArtifactKind::FakeFw.build().unwrap();
+        assert_ne!(first.contents, second.contents);
+        assert_eq!(first.reproducible_digest, second.reproducible_digest);
+    }
+
+    #[test]
+    fn test_rfc3339() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339(951782400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339(1689954123), "2023-07-21T15:42:03Z");
+    }
+
+    #[test]
+    fn test_parse_cargo_tree_line() {
+        let workspace = Path::new("/src/caliptra-sw");
+        assert_eq!(
+            parse_cargo_tree_line(
+                "caliptra-drivers v0.1.0 (/src/caliptra-sw/drivers)|Apache-2.0",
+                workspace
+            ),
+            Some(Package {
+                name: "caliptra-drivers".into(),
+                version: "0.1.0".into(),
+                source: PackageSource::Workspace("drivers".into()),
+                license: Some("Apache-2.0".into()),
+            })
+        );
+        assert_eq!(
+            parse_cargo_tree_line(
+                "zerocopy-derive v0.3.2 (proc-macro)|BSD-3-Clause (*)",
+                workspace
+            ),
+            Some(Package {
+                name: "zerocopy-derive".into(),
+                version: "0.3.2".into(),
+                source: PackageSource::Registry,
+                license: Some("BSD-3-Clause".into()),
+            })
+        );
+        assert_eq!(
+            parse_cargo_tree_line(
+                "ufmt v0.2.0 (https://github.com/korran/ufmt.git?rev=1d0743c1#1d0743c1)|",
+                workspace
+            ),
+            Some(Package {
+                name: "ufmt".into(),
+                version: "0.2.0".into(),
+                source: PackageSource::Git(
+                    "https://github.com/korran/ufmt.git?rev=1d0743c1#1d0743c1".into()
+                ),
+                license: None,
+            })
+        );
+        assert_eq!(parse_cargo_tree_line("", workspace), None);
+    }
+
+    #[test]
+    fn test_spdx_id() {
+        let package = Package {
+            name: "caliptra_x509".into(),
+            version: "1.0.0+build".into(),
+            source: PackageSource::Registry,
+            license: None,
+        };
+        assert_eq!(
+            package.spdx_id(),
+            "SPDXRef-Package-caliptra-x509-1.0.0-build"
+        );
+        assert_eq!(
+            package.to_spdx()["downloadLocation"],
+            "https://crates.io/crates/caliptra_x509/1.0.0+build"
+        );
+    }
+}
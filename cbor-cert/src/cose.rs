@@ -0,0 +1,248 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    cose.rs
+
+Abstract:
+
+    COSE API to construct a COSE_Sign1 certificate from the CWT claims and
+    ECDSA-384 Signature.
+
+--*/
+
+use crate::encoder::CborEncoder;
+use caliptra_x509::Ecdsa384Signature;
+
+/// COSE_Sign1 protected header: { alg: ES384 }
+const PROTECTED_HEADER: [u8; 4] = [0xa1, 0x01, 0x38, 0x22];
+
+/// Signature context of COSE_Sign1
+const SIGNATURE1_CONTEXT: &[u8] = b"Signature1";
+
+/// Length of a raw ES384 signature (r || s)
+const ES384_SIG_LEN: usize = 2 * Ecdsa384Signature::ECDSA_COORD_LEN;
+
+/// Untagged COSE_Sign1 Builder
+pub struct CoseSign1Builder<'a> {
+    /// Payload
+    payload: &'a [u8],
+
+    /// Signature
+    sig: &'a Ecdsa384Signature,
+
+    /// Length of the COSE_Sign1
+    len: usize,
+}
+
+impl<'a> CoseSign1Builder<'a> {
+    /// Overhead of the Sig_structure over a payload of 256 bytes or more
+    pub const SIG_STRUCTURE_OVERHEAD: usize =
+        1 + (1 + SIGNATURE1_CONTEXT.len()) + (1 + PROTECTED_HEADER.len()) + 1 + 3;
+
+    /// Length of the COSE_Sign1 items preceding a payload of 256 bytes or more
+    pub const HEADER_LEN: usize = 1 + (1 + PROTECTED_HEADER.len()) + 1 + 3;
+
+    /// Create an instance of `CoseSign1Builder`
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - CWT claims
+    /// * `sig`     - ECDSA-384 Signature over the Sig_structure of `payload`
+    pub fn new(payload: &'a [u8], sig: &'a Ecdsa384Signature) -> Option<Self> {
+        if payload.is_empty() || payload.len() > u16::MAX as usize {
+            return None;
+        }
+
+        let len = 1
+            + (1 + PROTECTED_HEADER.len())
+            + 1
+            + Self::bstr_header_len(payload.len())
+            + payload.len()
+            + Self::bstr_header_len(ES384_SIG_LEN)
+            + ES384_SIG_LEN;
+
+        Some(Self { payload, sig, len })
+    }
+
+    /// Return the length of the COSE_Sign1
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Build the COSE_Sign1
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - Output buffer
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Number of bytes written to `buf`
+    pub fn build(&self, buf: &mut [u8]) -> Option<usize> {
+        if buf.len() < self.len {
+            return None;
+        }
+
+        let mut enc = CborEncoder::new(buf);
+        Self::encode_header(&mut enc, self.payload.len())?;
+        enc.raw(self.payload)?;
+        Self::encode_signature(&mut enc, self.sig)?;
+
+        Some(enc.encoded_len())
+    }
+
+    /// Encode the COSE_Sign1 items preceding the payload, ending with the
+    /// header of the payload byte string
+    ///
+    /// # Arguments
+    ///
+    /// * `enc`         - Encoder
+    /// * `payload_len` - Length of the payload
+    pub fn encode_header(enc: &mut CborEncoder, payload_len: usize) -> Option<()> {
+        enc.array(4)?;
+        enc.bytes(&PROTECTED_HEADER)?;
+        enc.map(0)?;
+        enc.bytes_header(payload_len)
+    }
+
+    /// Encode the signature, the COSE_Sign1 item following the payload
+    ///
+    /// # Arguments
+    ///
+    /// * `enc` - Encoder
+    /// * `sig` - ECDSA-384 Signature
+    pub fn encode_signature(enc: &mut CborEncoder, sig: &Ecdsa384Signature) -> Option<()> {
+        enc.bytes_header(ES384_SIG_LEN)?;
+        enc.raw(&sig.r)?;
+        enc.raw(&sig.s)
+    }
+
+    /// Build the Sig_structure `["Signature1", protected, external_aad, payload]`
+    /// of a payload, the data signed by the issuer
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - CWT claims
+    /// * `buf`     - Output buffer
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Number of bytes written to `buf`
+    pub fn sig_structure(payload: &[u8], buf: &mut [u8]) -> Option<usize> {
+        let mut enc = CborEncoder::new(buf);
+        Self::encode_sig_structure_header(&mut enc, payload.len())?;
+        enc.raw(payload)?;
+
+        Some(enc.encoded_len())
+    }
+
+    /// Encode the Sig_structure items preceding the payload, ending with the
+    /// header of the payload byte string. Lets callers digest a payload that
+    /// is not contiguous with the header.
+    ///
+    /// # Arguments
+    ///
+    /// * `enc`         - Encoder
+    /// * `payload_len` - Length of the payload
+    pub fn encode_sig_structure_header(enc: &mut CborEncoder, payload_len: usize) -> Option<()> {
+        if payload_len > u16::MAX as usize {
+            return None;
+        }
+
+        enc.array(4)?;
+        enc.text(SIGNATURE1_CONTEXT)?;
+        enc.bytes(&PROTECTED_HEADER)?;
+        enc.bytes(&[])?;
+        enc.bytes_header(payload_len)
+    }
+
+    fn bstr_header_len(len: usize) -> usize {
+        match len {
+            0..=23 => 1,
+            24..=255 => 2,
+            _ => 3,
+        }
+    }
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+    use crate::cwt::{DiceCwt, DiceCwtParams, DiceMode};
+    use openssl::bn::BigNum;
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::ecdsa::EcdsaSig;
+    use openssl::nid::Nid;
+    use openssl::sha::sha384;
+
+    #[test]
+    fn test_sig_structure() {
+        let mut buf = [0u8; 64];
+        let len = CoseSign1Builder::sig_structure(&[0xa0], &mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x84, 0x6a, b'S', b'i', b'g', b'n', b'a', b't', b'u', b'r', b'e', b'1', 0x44, 0xa1,
+                0x01, 0x38, 0x22, 0x40, 0x41, 0xa0
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+        let key = EcKey::generate(&group).unwrap();
+        let mut ctx = openssl::bn::BigNumContext::new().unwrap();
+        let mut x = BigNum::new().unwrap();
+        let mut y = BigNum::new().unwrap();
+        key.public_key()
+            .affine_coordinates(&group, &mut x, &mut y, &mut ctx)
+            .unwrap();
+
+        let params = DiceCwtParams {
+            issuer: &[b'0'; 64],
+            subject: &[b'1'; 64],
+            public_key_x: &x.to_vec_padded(48).unwrap().try_into().unwrap(),
+            public_key_y: &y.to_vec_padded(48).unwrap().try_into().unwrap(),
+            mode: DiceMode::Debug,
+            code_hash: None,
+            authority_hash: None,
+            config_desc: None,
+        };
+        let mut payload = [0u8; DiceCwt::MAX_SIZE];
+        let payload_len = DiceCwt::encode(&params, &mut payload).unwrap();
+        let payload = &payload[..payload_len];
+
+        let mut tbs = [0u8; DiceCwt::MAX_SIZE + CoseSign1Builder::SIG_STRUCTURE_OVERHEAD];
+        let tbs_len = CoseSign1Builder::sig_structure(payload, &mut tbs).unwrap();
+        assert_eq!(
+            tbs_len,
+            payload_len + CoseSign1Builder::SIG_STRUCTURE_OVERHEAD
+        );
+        let sig = EcdsaSig::sign(&sha384(&tbs[..tbs_len]), &key).unwrap();
+        let ecdsa_sig = Ecdsa384Signature {
+            r: sig.r().to_vec_padded(48).unwrap().try_into().unwrap(),
+            s: sig.s().to_vec_padded(48).unwrap().try_into().unwrap(),
+        };
+
+        let builder = CoseSign1Builder::new(payload, &ecdsa_sig).unwrap();
+        let mut cert = vec![0u8; builder.len()];
+        assert_eq!(builder.build(&mut cert), Some(builder.len()));
+        assert_eq!(builder.build(&mut cert[..builder.len() - 1]), None);
+
+        // [protected, unprotected, payload, signature]
+        let payload_start = 10;
+        assert_eq!(&cert[..7], &[0x84, 0x44, 0xa1, 0x01, 0x38, 0x22, 0xa0]);
+        assert_eq!(&cert[7..payload_start], &[0x59, 0x01, payload_len as u8]);
+        assert_eq!(&cert[payload_start..payload_start + payload_len], payload);
+        let sig_bytes = &cert[payload_start + payload_len + 2..];
+        let r = BigNum::from_slice(&sig_bytes[..48]).unwrap();
+        let s = BigNum::from_slice(&sig_bytes[48..]).unwrap();
+        let sig = EcdsaSig::from_private_components(r, s).unwrap();
+        assert!(sig.verify(&sha384(&tbs[..tbs_len]), &key).unwrap());
+    }
+}
@@ -0,0 +1,340 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    cwt.rs
+
+Abstract:
+
+    File contains the CBOR Web Token (CWT) claims of Open Profile for DICE
+    certificates.
+
+--*/
+
+use crate::cose::CoseSign1Builder;
+use crate::encoder::CborEncoder;
+
+/// CWT claim keys (RFC 8392 and Open Profile for DICE)
+struct Claim;
+impl Claim {
+    const ISSUER: i64 = 1;
+    const SUBJECT: i64 = 2;
+    const CODE_HASH: i64 = -4670545;
+    const CONFIG_DESC: i64 = -4670548;
+    const AUTHORITY_HASH: i64 = -4670549;
+    const MODE: i64 = -4670551;
+    const SUBJECT_PUBLIC_KEY: i64 = -4670552;
+    const KEY_USAGE: i64 = -4670553;
+}
+
+/// Configuration descriptor keys (Android profile for DICE)
+struct ConfigDescKey;
+impl ConfigDescKey {
+    const COMPONENT_NAME: i64 = -70002;
+    const SECURITY_VERSION: i64 = -70005;
+}
+
+/// COSE_Key labels and values (RFC 9053)
+struct CoseKey;
+impl CoseKey {
+    const KTY: i64 = 1;
+    const ALG: i64 = 3;
+    const CRV: i64 = -1;
+    const X: i64 = -2;
+    const Y: i64 = -3;
+    const KTY_EC2: i64 = 2;
+    const ALG_ES384: i64 = -35;
+    const CRV_P384: i64 = 2;
+}
+
+/// keyCertSign, as a little endian bit string of X.509 key usage bits
+const KEY_USAGE_CERT_SIGN: [u8; 1] = [0x20];
+
+/// Open Profile for DICE mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceMode {
+    NotConfigured = 0,
+    Normal = 1,
+    Debug = 2,
+    Recovery = 3,
+}
+
+impl DiceMode {
+    /// Mode of a device in the given security state
+    ///
+    /// # Arguments
+    ///
+    /// * `production`   - Device is in the production lifecycle state
+    /// * `debug_locked` - Debug is locked
+    pub fn from_security_state(production: bool, debug_locked: bool) -> Self {
+        match (production, debug_locked) {
+            (_, false) => Self::Debug,
+            (true, true) => Self::Normal,
+            (false, true) => Self::NotConfigured,
+        }
+    }
+}
+
+/// Configuration descriptor of the certified layer
+pub struct ConfigDescriptor<'a> {
+    /// Name of the certified component
+    pub component_name: &'a str,
+
+    /// Security version number of the certified component
+    pub security_version: u32,
+}
+
+/// Parameters of the CWT claims of a DICE certificate
+pub struct DiceCwtParams<'a> {
+    /// Hex encoded identifier of the issuer key
+    pub issuer: &'a [u8; 64],
+
+    /// Hex encoded identifier of the subject key
+    pub subject: &'a [u8; 64],
+
+    /// Subject public key X-Coordinate
+    pub public_key_x: &'a [u8; 48],
+
+    /// Subject public key Y-Coordinate
+    pub public_key_y: &'a [u8; 48],
+
+    /// Mode of the device
+    pub mode: DiceMode,
+
+    /// Measurement of the certified code
+    pub code_hash: Option<&'a [u8; 48]>,
+
+    /// Digest of the state used to authorize the certified code
+    pub authority_hash: Option<&'a [u8; 48]>,
+
+    /// Configuration descriptor of the certified component
+    pub config_desc: Option<ConfigDescriptor<'a>>,
+}
+
+/// CWT claims of a DICE certificate, the payload of its COSE_Sign1
+pub enum DiceCwt {}
+
+impl DiceCwt {
+    /// Maximum size of the encoded claims
+    pub const MAX_SIZE: usize = 512;
+
+    /// Encode the claims
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Claim parameters
+    /// * `buf`    - Output buffer
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - Number of bytes written to `buf`, `None` if `buf` is too small
+    pub fn encode(params: &DiceCwtParams, buf: &mut [u8]) -> Option<usize> {
+        let claim_count = 5
+            + params.code_hash.is_some() as usize
+            + params.authority_hash.is_some() as usize
+            + params.config_desc.is_some() as usize;
+
+        // Claims are in the canonical order of their keys
+        let mut enc = CborEncoder::new(buf);
+        enc.map(claim_count)?;
+
+        enc.int(Claim::ISSUER)?;
+        enc.text(params.issuer)?;
+
+        enc.int(Claim::SUBJECT)?;
+        enc.text(params.subject)?;
+
+        if let Some(code_hash) = params.code_hash {
+            enc.int(Claim::CODE_HASH)?;
+            enc.bytes(code_hash)?;
+        }
+
+        if let Some(config_desc) = &params.config_desc {
+            let mut desc = [0u8; 64];
+            let desc_len = Self::encode_config_desc(config_desc, &mut desc)?;
+            enc.int(Claim::CONFIG_DESC)?;
+            enc.bytes(desc.get(..desc_len)?)?;
+        }
+
+        if let Some(authority_hash) = params.authority_hash {
+            enc.int(Claim::AUTHORITY_HASH)?;
+            enc.bytes(authority_hash)?;
+        }
+
+        enc.int(Claim::MODE)?;
+        enc.bytes(&[params.mode as u8])?;
+
+        let mut key = [0u8; 128];
+        let key_len = Self::encode_public_key(params, &mut key)?;
+        enc.int(Claim::SUBJECT_PUBLIC_KEY)?;
+        enc.bytes(key.get(..key_len)?)?;
+
+        enc.int(Claim::KEY_USAGE)?;
+        enc.bytes(&KEY_USAGE_CERT_SIGN)?;
+
+        Some(enc.encoded_len())
+    }
+
+    /// Encode the subject public key as a COSE_Key
+    fn encode_public_key(params: &DiceCwtParams, buf: &mut [u8]) -> Option<usize> {
+        let mut enc = CborEncoder::new(buf);
+        enc.map(5)?;
+        enc.int(CoseKey::KTY)?;
+        enc.int(CoseKey::KTY_EC2)?;
+        enc.int(CoseKey::ALG)?;
+        enc.int(CoseKey::ALG_ES384)?;
+        enc.int(CoseKey::CRV)?;
+        enc.int(CoseKey::CRV_P384)?;
+        enc.int(CoseKey::X)?;
+        enc.bytes(params.public_key_x)?;
+        enc.int(CoseKey::Y)?;
+        enc.bytes(params.public_key_y)?;
+        Some(enc.encoded_len())
+    }
+
+    /// Encode the configuration descriptor
+    fn encode_config_desc(desc: &ConfigDescriptor, buf: &mut [u8]) -> Option<usize> {
+        let mut enc = CborEncoder::new(buf);
+        enc.map(2)?;
+        enc.int(ConfigDescKey::COMPONENT_NAME)?;
+        enc.text(desc.component_name.as_bytes())?;
+        enc.int(ConfigDescKey::SECURITY_VERSION)?;
+        enc.uint(desc.security_version.into())?;
+        Some(enc.encoded_len())
+    }
+}
+
+/// CWT claims of a DICE certificate along with the Sig_structure signed
+/// over them
+pub struct DiceCwtTbs {
+    cwt: [u8; DiceCwt::MAX_SIZE],
+    cwt_len: usize,
+    sig_structure: [u8; DiceCwt::MAX_SIZE + CoseSign1Builder::SIG_STRUCTURE_OVERHEAD],
+    sig_structure_len: usize,
+}
+
+impl DiceCwtTbs {
+    /// Create an instance of `DiceCwtTbs`
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Claim parameters
+    pub fn new(params: &DiceCwtParams) -> Option<Self> {
+        let mut tbs = Self {
+            cwt: [0u8; DiceCwt::MAX_SIZE],
+            cwt_len: 0,
+            sig_structure: [0u8; DiceCwt::MAX_SIZE + CoseSign1Builder::SIG_STRUCTURE_OVERHEAD],
+            sig_structure_len: 0,
+        };
+        tbs.cwt_len = DiceCwt::encode(params, &mut tbs.cwt)?;
+        tbs.sig_structure_len = CoseSign1Builder::sig_structure(tbs.tbs(), &mut tbs.sig_structure)?;
+        Some(tbs)
+    }
+
+    /// CWT claims, the payload of the COSE_Sign1
+    pub fn tbs(&self) -> &[u8] {
+        &self.cwt[..self.cwt_len]
+    }
+
+    /// Sig_structure of the claims, the data signed by the issuer
+    pub fn sig_structure(&self) -> &[u8] {
+        &self.sig_structure[..self.sig_structure_len]
+    }
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+
+    const ISSUER: [u8; 64] = [b'A'; 64];
+    const SUBJECT: [u8; 64] = [b'B'; 64];
+
+    fn params() -> DiceCwtParams<'static> {
+        DiceCwtParams {
+            issuer: &ISSUER,
+            subject: &SUBJECT,
+            public_key_x: &[0x11; 48],
+            public_key_y: &[0x22; 48],
+            mode: DiceMode::Normal,
+            code_hash: None,
+            authority_hash: None,
+            config_desc: None,
+        }
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(DiceMode::from_security_state(true, true), DiceMode::Normal);
+        assert_eq!(DiceMode::from_security_state(true, false), DiceMode::Debug);
+        assert_eq!(DiceMode::from_security_state(false, false), DiceMode::Debug);
+        assert_eq!(
+            DiceMode::from_security_state(false, true),
+            DiceMode::NotConfigured
+        );
+    }
+
+    #[test]
+    fn test_minimal_claims() {
+        let mut buf = [0u8; DiceCwt::MAX_SIZE];
+        let len = DiceCwt::encode(&params(), &mut buf).unwrap();
+        let cwt = &buf[..len];
+
+        // map(5), 1: tstr(64)
+        assert_eq!(&cwt[..4], &[0xa5, 0x01, 0x78, 0x40]);
+        assert_eq!(&cwt[4..68], &ISSUER);
+        // 2: tstr(64)
+        assert_eq!(&cwt[68..71], &[0x02, 0x78, 0x40]);
+        assert_eq!(&cwt[71..135], &SUBJECT);
+        // mode: bstr(1)
+        assert_eq!(&cwt[135..142], &[0x3a, 0x00, 0x47, 0x44, 0x56, 0x41, 0x01]);
+        // key usage is last
+        assert_eq!(&cwt[len - 7..], &[0x3a, 0x00, 0x47, 0x44, 0x58, 0x41, 0x20]);
+    }
+
+    #[test]
+    fn test_public_key() {
+        let mut buf = [0u8; 128];
+        let len = DiceCwt::encode_public_key(&params(), &mut buf).unwrap();
+        let mut expected = vec![
+            0xa5, 0x01, 0x02, 0x03, 0x38, 0x22, 0x20, 0x02, 0x21, 0x58, 0x30,
+        ];
+        expected.extend([0x11; 48]);
+        expected.extend([0x22, 0x58, 0x30]);
+        expected.extend([0x22; 48]);
+        assert_eq!(&buf[..len], &expected);
+    }
+
+    #[test]
+    fn test_all_claims() {
+        let mut params = params();
+        params.code_hash = Some(&[0x33; 48]);
+        params.authority_hash = Some(&[0x44; 48]);
+        params.config_desc = Some(ConfigDescriptor {
+            component_name: "caliptra-fmc",
+            security_version: 3,
+        });
+
+        let mut buf = [0u8; DiceCwt::MAX_SIZE];
+        let len = DiceCwt::encode(&params, &mut buf).unwrap();
+        assert_eq!(buf[0], 0xa8);
+
+        // Code hash follows the subject
+        assert_eq!(&buf[135..142], &[0x3a, 0x00, 0x47, 0x44, 0x50, 0x58, 0x30]);
+        assert_eq!(&buf[142..190], &[0x33; 48]);
+
+        // Configuration descriptor
+        let desc = [
+            0x3a, 0x00, 0x47, 0x44, 0x53, 0x58, 0x19, 0xa2, 0x3a, 0x00, 0x01, 0x11, 0x71, 0x6c,
+        ];
+        assert_eq!(&buf[190..190 + desc.len()], &desc);
+
+        assert!(len < DiceCwt::MAX_SIZE);
+        assert_eq!(DiceCwt::encode(&params, &mut buf[..len - 1]), None);
+
+        let tbs = DiceCwtTbs::new(&params).unwrap();
+        assert_eq!(tbs.tbs(), &buf[..len]);
+        assert!(tbs.sig_structure().ends_with(tbs.tbs()));
+    }
+}
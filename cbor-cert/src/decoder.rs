@@ -0,0 +1,129 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    decoder.rs
+
+Abstract:
+
+    File contains a minimal CBOR (RFC 8949) walker used to find the extent of
+    encoded certificates.
+
+--*/
+
+const MAJOR_BSTR: u8 = 2;
+const MAJOR_TSTR: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+/// Compute the encoded length of the CBOR item at the start of `buf`
+///
+/// Only definite-length items are supported. Nested items are walked
+/// iteratively, so the nesting depth is not bounded by the stack.
+///
+/// # Arguments
+///
+/// * `buf` - Buffer starting with a CBOR item
+///
+/// # Returns
+///
+/// * `usize` - Length of the item, `None` if it is malformed or truncated
+pub fn cbor_item_len(buf: &[u8]) -> Option<usize> {
+    let mut offset = 0usize;
+
+    // Number of items still to be walked
+    let mut pending = 1u64;
+    while pending > 0 {
+        pending -= 1;
+
+        let initial = *buf.get(offset)?;
+        offset += 1;
+        let major = initial >> 5;
+        let arg_len = match initial & 0x1f {
+            0..=23 => 0,
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return None,
+        };
+        let arg = match arg_len {
+            0 => u64::from(initial & 0x1f),
+            _ => buf
+                .get(offset..offset.checked_add(arg_len)?)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b)),
+        };
+        offset += arg_len;
+
+        match major {
+            MAJOR_BSTR | MAJOR_TSTR => {
+                offset = offset.checked_add(usize::try_from(arg).ok()?)?;
+            }
+            MAJOR_ARRAY => pending = pending.checked_add(arg)?,
+            MAJOR_MAP => pending = pending.checked_add(arg.checked_mul(2)?)?,
+            MAJOR_TAG => pending += 1,
+            MAJOR_SIMPLE if arg_len == 1 && arg < 32 => return None,
+            _ => {}
+        }
+
+        // Every pending item takes at least one byte
+        if offset > buf.len() || pending > (buf.len() - offset) as u64 {
+            return None;
+        }
+    }
+
+    Some(offset)
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalars() {
+        // RFC 8949 Appendix A
+        assert_eq!(cbor_item_len(&[0x0a]), Some(1));
+        assert_eq!(cbor_item_len(&[0x19, 0x03, 0xe8]), Some(3));
+        assert_eq!(cbor_item_len(&[0x3a, 0x00, 0x47, 0x44, 0x50]), Some(5));
+        assert_eq!(cbor_item_len(&[0xf5]), Some(1));
+        assert_eq!(
+            cbor_item_len(&[0xfb, 0x3f, 0xf1, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9a]),
+            Some(9)
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        // [1, [2, 3], {"a": h'01'}] followed by a trailing byte
+        let item = [
+            0x83, 0x01, 0x82, 0x02, 0x03, 0xa1, 0x61, b'a', 0x41, 0x01, 0xff,
+        ];
+        assert_eq!(cbor_item_len(&item), Some(item.len() - 1));
+
+        // Tag 18 wrapping an empty array
+        assert_eq!(cbor_item_len(&[0xd2, 0x80]), Some(2));
+    }
+
+    #[test]
+    fn test_truncated() {
+        assert_eq!(cbor_item_len(&[]), None);
+        assert_eq!(cbor_item_len(&[0x19, 0x03]), None);
+        assert_eq!(cbor_item_len(&[0x43, 0x01, 0x02]), None);
+        assert_eq!(cbor_item_len(&[0x82, 0x01]), None);
+        assert_eq!(
+            cbor_item_len(&[0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_indefinite_length() {
+        assert_eq!(cbor_item_len(&[0x9f, 0x01, 0xff]), None);
+        assert_eq!(cbor_item_len(&[0x5f, 0x41, 0x01, 0xff]), None);
+    }
+}
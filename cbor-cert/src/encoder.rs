@@ -0,0 +1,160 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    encoder.rs
+
+Abstract:
+
+    File contains a minimal CBOR (RFC 8949) encoder for DICE certificates.
+
+--*/
+
+const MAJOR_UINT: u8 = 0;
+const MAJOR_NINT: u8 = 1;
+const MAJOR_BSTR: u8 = 2;
+const MAJOR_TSTR: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+/// Writes definite-length CBOR items into a caller-provided buffer.
+pub struct CborEncoder<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> CborEncoder<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Number of bytes encoded so far
+    pub fn encoded_len(&self) -> usize {
+        self.len
+    }
+
+    /// Write `data` verbatim
+    pub fn raw(&mut self, data: &[u8]) -> Option<()> {
+        let end = self.len.checked_add(data.len())?;
+        self.buf.get_mut(self.len..end)?.copy_from_slice(data);
+        self.len = end;
+        Some(())
+    }
+
+    fn head(&mut self, major: u8, arg: u64) -> Option<()> {
+        let major = major << 5;
+        if arg < 24 {
+            self.raw(&[major | arg as u8])
+        } else if arg <= u8::MAX as u64 {
+            self.raw(&[major | 24, arg as u8])
+        } else if arg <= u16::MAX as u64 {
+            self.raw(&[major | 25])?;
+            self.raw(&(arg as u16).to_be_bytes())
+        } else if arg <= u32::MAX as u64 {
+            self.raw(&[major | 26])?;
+            self.raw(&(arg as u32).to_be_bytes())
+        } else {
+            self.raw(&[major | 27])?;
+            self.raw(&arg.to_be_bytes())
+        }
+    }
+
+    pub fn uint(&mut self, value: u64) -> Option<()> {
+        self.head(MAJOR_UINT, value)
+    }
+
+    pub fn int(&mut self, value: i64) -> Option<()> {
+        if value >= 0 {
+            self.head(MAJOR_UINT, value as u64)
+        } else {
+            // Negative integers are encoded as -1 - n
+            self.head(MAJOR_NINT, !value as u64)
+        }
+    }
+
+    pub fn bytes(&mut self, value: &[u8]) -> Option<()> {
+        self.head(MAJOR_BSTR, value.len() as u64)?;
+        self.raw(value)
+    }
+
+    /// Write only the header of a byte string of `len` bytes; the caller
+    /// writes the contents next
+    pub fn bytes_header(&mut self, len: usize) -> Option<()> {
+        self.head(MAJOR_BSTR, len as u64)
+    }
+
+    /// Write a text string; `value` must be valid UTF-8
+    pub fn text(&mut self, value: &[u8]) -> Option<()> {
+        core::str::from_utf8(value).ok()?;
+        self.head(MAJOR_TSTR, value.len() as u64)?;
+        self.raw(value)
+    }
+
+    /// Start an array of `len` items; the caller encodes the items next
+    pub fn array(&mut self, len: usize) -> Option<()> {
+        self.head(MAJOR_ARRAY, len as u64)
+    }
+
+    /// Start a map of `len` pairs; the caller encodes keys and values next
+    pub fn map(&mut self, len: usize) -> Option<()> {
+        self.head(MAJOR_MAP, len as u64)
+    }
+
+    /// Write a tag; the caller encodes the tagged item next
+    pub fn tag(&mut self, tag: u64) -> Option<()> {
+        self.head(MAJOR_TAG, tag)
+    }
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+
+    fn encode(f: impl FnOnce(&mut CborEncoder) -> Option<()>) -> Vec<u8> {
+        let mut buf = [0u8; 64];
+        let mut enc = CborEncoder::new(&mut buf);
+        f(&mut enc).unwrap();
+        let len = enc.encoded_len();
+        buf[..len].to_vec()
+    }
+
+    #[test]
+    fn test_int() {
+        // RFC 8949 Appendix A
+        assert_eq!(encode(|e| e.int(10)), [0x0a]);
+        assert_eq!(encode(|e| e.int(24)), [0x18, 0x18]);
+        assert_eq!(encode(|e| e.int(1000)), [0x19, 0x03, 0xe8]);
+        assert_eq!(encode(|e| e.int(-1)), [0x20]);
+        assert_eq!(encode(|e| e.int(-100)), [0x38, 0x63]);
+        assert_eq!(encode(|e| e.int(-4670545)), [0x3a, 0x00, 0x47, 0x44, 0x50]);
+    }
+
+    #[test]
+    fn test_tag() {
+        assert_eq!(encode(|e| e.tag(18)), [0xd2]);
+        assert_eq!(encode(|e| e.tag(61)), [0xd8, 0x3d]);
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(encode(|e| e.bytes(&[1, 2, 3])), [0x43, 1, 2, 3]);
+        assert_eq!(encode(|e| e.text(b"IETF")), [0x64, b'I', b'E', b'T', b'F']);
+    }
+
+    #[test]
+    fn test_invalid_text() {
+        let mut buf = [0u8; 8];
+        let mut enc = CborEncoder::new(&mut buf);
+        assert_eq!(enc.text(&[0xff]), None);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut buf = [0u8; 2];
+        let mut enc = CborEncoder::new(&mut buf);
+        assert_eq!(enc.bytes(&[1, 2]), None);
+    }
+}
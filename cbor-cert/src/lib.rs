@@ -0,0 +1,26 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    lib.rs
+
+Abstract:
+
+    Main entry point for Caliptra CBOR (Open Profile for DICE) certificate
+    functionality
+
+--*/
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod cose;
+mod cwt;
+mod decoder;
+mod encoder;
+
+pub use cose::CoseSign1Builder;
+pub use cwt::{ConfigDescriptor, DiceCwt, DiceCwtParams, DiceCwtTbs, DiceMode};
+pub use decoder::cbor_item_len;
+pub use encoder::CborEncoder;
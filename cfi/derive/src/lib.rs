@@ -55,10 +55,49 @@ fn cfi_fn(mod_fn: bool, input: TokenStream) -> TokenStream {
         })
         .collect();
 
-    let fn_call = if mod_fn {
-        quote!(#fn_name( #(#param_names,)* ))
+    let fn_path = if mod_fn {
+        quote!(#fn_name)
     } else {
-        quote!(Self::#fn_name( #(#param_names,)* ))
+        quote!(Self::#fn_name)
+    };
+
+    // A parameter compiled out with #[cfg] must not be passed on either.
+    let cfg_params: Vec<(usize, &syn::Attribute)> = orig_fn
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, input)| match input {
+            FnArg::Typed(p) => p
+                .attrs
+                .iter()
+                .find(|a| a.path.is_ident("cfg"))
+                .map(|a| (i, a)),
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    assert!(
+        cfg_params.len() <= 1,
+        "CFI functions support at most one #[cfg] parameter"
+    );
+
+    let fn_call = match cfg_params.first() {
+        Some((idx, cfg)) => {
+            let cfg_args = &cfg.tokens;
+            let other_params = param_names
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i != idx)
+                .map(|(_, p)| p);
+            quote!({
+                #[cfg #cfg_args]
+                let ret = #fn_path( #(#param_names,)* );
+                #[cfg(not #cfg_args)]
+                let ret = #fn_path( #(#other_params,)* );
+                ret
+            })
+        }
+        None => quote!(#fn_path( #(#param_names,)* )),
     };
 
     wrapper_fn.block.stmts.clear();
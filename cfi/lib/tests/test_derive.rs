@@ -22,6 +22,16 @@ fn test2<T>(val: T) -> T {
     val
 }
 
+#[cfi_mod_fn]
+fn test_cfg_param(val: u32, #[cfg(all())] add: u32) -> u32 {
+    val + add
+}
+
+#[cfi_mod_fn]
+fn test_cfg_param_removed(val: u32, #[cfg(any())] add: u32) -> u32 {
+    val
+}
+
 struct Test;
 
 impl Test {
@@ -69,6 +79,14 @@ fn test_with_initialized_counter() {
     assert_eq!(test.test_self1(10), 10);
 }
 
+#[test]
+#[serial]
+fn test_cfg_params() {
+    CfiCounter::reset_for_test();
+    assert_eq!(test_cfg_param(10, 1), 11);
+    assert_eq!(test_cfg_param_removed(10), 10);
+}
+
 #[test]
 fn test_rand() {
     // Expected random numbers generated from a modified implementation of:
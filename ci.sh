@@ -118,6 +118,18 @@ if task_enabled "check_frozen_images"; then
         false
     )
 fi
+if optional_task_enabled "check_reproducible_images"; then
+    echo Checking reproducible images
+    rm -rf "${CARGO_TARGET_DIR}/riscv32imc-unknown-none-elf"
+    cargo run -p caliptra-builder -- \
+        --rom-no-log "${WORK_DIR}/caliptra-rom-no-log.bin" \
+        --rom-with-log "${WORK_DIR}/caliptra-rom-with-log.bin" \
+        --fw "${WORK_DIR}/image-bundle.bin" \
+        --provenance "${WORK_DIR}/provenance"
+    rm -rf "${CARGO_TARGET_DIR}/riscv32imc-unknown-none-elf"
+    cargo run -p caliptra-builder -- \
+        --verify-provenance "${WORK_DIR}/provenance/provenance.json"
+fi
 if optional_task_enabled "update_frozen_images"; then
     echo "Updating frozen images"
     build_rom_images
@@ -0,0 +1,30 @@
+// Licensed under the Apache-2.0 license
+
+use crate::mailbox_api::{GetCrashInfoResp, MailboxRespHeader};
+use caliptra_drivers::CrashRecord;
+
+pub struct GetCrashInfoCmd;
+impl GetCrashInfoCmd {
+    pub fn execute(record: &CrashRecord) -> GetCrashInfoResp {
+        if !record.is_valid() {
+            return GetCrashInfoResp::default();
+        }
+
+        GetCrashInfoResp {
+            hdr: MailboxRespHeader::default(),
+            valid: 1,
+            fw_error_fatal: record.fw_error_fatal,
+            boot_status: record.boot_status,
+            last_mbox_cmd: record.last_mbox_cmd,
+            mcause: record.mcause,
+            mscause: record.mscause,
+            mepc: record.mepc,
+            mtval: record.mtval,
+            mstatus: record.mstatus,
+            err_interrupt_status: record.err_interrupt_status,
+            regs: record.regs,
+            stack: record.stack,
+            cfi_state: record.cfi_state,
+        }
+    }
+}
@@ -9,6 +9,7 @@ pub mod capabilities {
 pub mod checksum {
     pub use caliptra_api::{calc_checksum, verify_checksum};
 }
+pub mod crash_info;
 pub mod crypto;
 pub mod dice;
 pub mod error_handler;
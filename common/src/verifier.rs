@@ -14,8 +14,9 @@ Abstract:
 
 use caliptra_drivers::*;
 use caliptra_image_types::*;
-use caliptra_image_verify::ImageVerificationEnv;
+use caliptra_image_verify::{ImageVerificationEnv, LmsHssPubKeys, Mldsa87PubKeyDigests};
 use core::ops::Range;
+use zerocopy::FromBytes;
 
 use caliptra_drivers::memory_layout::ICCM_RANGE;
 
@@ -28,6 +29,17 @@ pub struct FirmwareImageVerificationEnv<'a, 'b> {
     pub data_vault: &'a mut DataVault,
     pub pcr_bank: &'a mut PcrBank,
     pub image: &'b [u8],
+    /// The image bundle carries the HSS and PQC extensions, if they were
+    /// provided by the SoC. Images rebuilt from ICCM have no extensions.
+    pub image_extensions: bool,
+    /// ML-DSA-87 public key digests verified on cold boot, which bind images
+    /// without the PQC extension to the same ML-DSA keys
+    #[cfg(feature = "mldsa")]
+    pub mldsa87_pub_key_digests: Option<Mldsa87PubKeyDigests>,
+    /// LMS public keys at the bottom of the HSS chains verified on cold boot,
+    /// which verify images without the HSS extension
+    #[cfg(feature = "lms-hss")]
+    pub lms_hss_pub_keys: Option<LmsHssPubKeys>,
 }
 
 impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b> {
@@ -43,6 +55,29 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         Ok(self.sha384.digest(data)?.0)
     }
 
+    /// Calculate Digest of the image data followed by `suffix` using SHA-384
+    /// Accelerator
+    fn sha384_digest_with_suffix(
+        &mut self,
+        offset: u32,
+        len: u32,
+        suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        let mut digest = Array4x12::default();
+        let mut op = self.sha384.digest_init()?;
+        op.update(data)?;
+        op.update(suffix)?;
+        op.finalize(&mut digest)?;
+        Ok(digest.0)
+    }
+
     /// ECC-384 Verification routine
     fn ecc384_verify(
         &mut self,
@@ -78,6 +113,40 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         Lms::default().verify_lms_signature_cfi(self.sha256, &message, pub_key, sig)
     }
 
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key_offset: u32,
+        sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let pub_key = self
+            .image
+            .get(pub_key_offset as usize..)
+            .and_then(|b| b.get(..MLDSA87_PUB_KEY_BYTE_SIZE))
+            .and_then(|b| <&[u8; MLDSA87_PUB_KEY_BYTE_SIZE]>::try_from(b).ok())
+            .ok_or(err)?;
+        let sig = self
+            .image
+            .get(sig_offset as usize..)
+            .and_then(|b| b.get(..MLDSA87_SIGNATURE_BYTE_SIZE))
+            .and_then(|b| <&[u8; MLDSA87_SIGNATURE_BYTE_SIZE]>::try_from(b).ok())
+            .ok_or(err)?;
+
+        let mut message = [0u8; SHA384_DIGEST_BYTE_SIZE];
+        for i in 0..digest.len() {
+            message[i * 4..][..4].copy_from_slice(&digest[i].to_be_bytes());
+        }
+        Mldsa87::default().verify(pub_key, &message, sig)
+    }
+
+    fn lms_hss_signed_pub_key(&self, offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+        self.image
+            .get(offset as usize..)
+            .and_then(ImageLmsSignedPubKey::read_from_prefix)
+            .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS)
+    }
+
     /// Retrieve Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().vendor_pub_key_hash().into()
@@ -123,6 +192,25 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         self.data_vault.owner_pk_hash().into()
     }
 
+    /// Get the ML-DSA-87 public key digests verified on cold boot
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+        #[cfg(feature = "mldsa")]
+        if self.soc_ifc.fuse_bank().mldsa_verify() == MldsaVerifyConfig::Enabled {
+            return self.mldsa87_pub_key_digests;
+        }
+        None
+    }
+
+    /// Get the LMS public keys at the bottom of the HSS chains verified on
+    /// cold boot
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+        #[cfg(feature = "lms-hss")]
+        if self.lms_verify_enabled() {
+            return self.lms_hss_pub_keys;
+        }
+        None
+    }
+
     // Get the fmc digest from the data vault on cold boot
     fn get_fmc_digest_dv(&self) -> ImageDigest {
         self.data_vault.fmc_tci().into()
@@ -146,6 +234,15 @@ impl<'a, 'b> ImageVerificationEnv for &mut FirmwareImageVerificationEnv<'a, 'b>
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
 
+    fn lms_hss_verify_enabled(&self) -> bool {
+        self.image_extensions && self.lms_verify_enabled()
+    }
+
+    fn mldsa87_verify_enabled(&self) -> bool {
+        self.image_extensions
+            && self.soc_ifc.fuse_bank().mldsa_verify() == MldsaVerifyConfig::Enabled
+    }
+
     fn set_fw_extended_error(&mut self, err: u32) {
         self.soc_ifc.set_fw_extended_error(err);
     }
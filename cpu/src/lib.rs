@@ -12,9 +12,12 @@ core::arch::global_asm!(include_str!("trap.S"));
 pub mod csr;
 pub mod trap;
 
+use caliptra_drivers::CrashRecord;
 use caliptra_registers::soc_ifc::SocIfcReg;
 pub use trap::{Exception, Interrupt, Trap, TrapRecord};
 
+/// Log the trap record to the extended error info registers and start a crash
+/// record. The crash record is completed when the fatal error is reported.
 pub fn log_trap_record(trap_record: &TrapRecord, err_interrupt_status: Option<u32>) {
     let mut soc_ifc = unsafe { SocIfcReg::new() };
     let soc_ifc = soc_ifc.regs_mut();
@@ -28,4 +31,13 @@ pub fn log_trap_record(trap_record: &TrapRecord, err_interrupt_status: Option<u3
     if let Some(err_interrupt_status) = err_interrupt_status {
         ext_info.at(4).write(|_| err_interrupt_status);
     }
+
+    let crash_record = unsafe { CrashRecord::get_mut() };
+    crash_record.begin_trap(&trap_record.regs());
+    crash_record.mcause = trap_record.mcause;
+    crash_record.mscause = trap_record.mscause;
+    crash_record.mepc = trap_record.mepc;
+    crash_record.mtval = trap_record.mtval;
+    crash_record.mstatus = trap_record.mstatus;
+    crash_record.err_interrupt_status = err_interrupt_status.unwrap_or_default();
 }
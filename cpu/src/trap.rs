@@ -12,6 +12,8 @@ Abstract:
 
 --*/
 
+use caliptra_drivers::CRASH_RECORD_REG_COUNT;
+
 /// Exception Record
 #[repr(C)]
 pub struct TrapRecord {
@@ -39,6 +41,16 @@ pub struct TrapRecord {
     pub mtval: u32,
 }
 
+impl TrapRecord {
+    /// Returns ra, sp, a0-a7 and t0-t6, in that order
+    pub fn regs(&self) -> [u32; CRASH_RECORD_REG_COUNT] {
+        [
+            self.ra, self.sp, self.a0, self.a1, self.a2, self.a3, self.a4, self.a5, self.a6,
+            self.a7, self.t0, self.t1, self.t2, self.t3, self.t4, self.t5, self.t6,
+        ]
+    }
+}
+
 pub enum Trap {
     Synchronous(Exception),
     Asynchronous(Interrupt),
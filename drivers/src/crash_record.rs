@@ -0,0 +1,151 @@
+/*++
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    crash_record.rs
+
+Abstract:
+
+    Crash record kept in DCCM across warm resets.
+
+--*/
+
+#[cfg(feature = "crash-record")]
+use crate::memory_layout::{BOOT_STATUS_ORG, CFI_STATE_ORG};
+use crate::memory_layout::{CRASH_RECORD_ORG, DCCM_ORG, DCCM_SIZE};
+#[cfg(feature = "crash-record")]
+use caliptra_registers::mbox::MboxCsr;
+#[cfg(feature = "crash-record")]
+use core::ptr::addr_of;
+use zerocopy::{AsBytes, FromBytes};
+use zeroize::Zeroize;
+
+/// Number of general purpose registers saved by the trap handlers
+/// (ra, sp, a0-a7, t0-t6)
+pub const CRASH_RECORD_REG_COUNT: usize = 17;
+
+/// Number of words captured from the top of the stack
+pub const CRASH_RECORD_STACK_WORDS: usize = 16;
+
+/// Number of words of CFI counter state (value, mask and PRNG state)
+pub const CRASH_RECORD_CFI_STATE_WORDS: usize = 6;
+
+/// State captured when the firmware hits a fatal error.
+///
+/// The record lives in DCCM, which is zeroed by the ROM on cold reset only,
+/// so it can be retrieved with GET_CRASH_INFO after a warm reset.
+#[repr(C, align(4))]
+#[derive(AsBytes, FromBytes, Zeroize, Default, Clone, Copy)]
+pub struct CrashRecord {
+    /// One of the `MARKER_*` constants
+    pub marker: u32,
+
+    /// Value written to CPTRA_FW_ERROR_FATAL
+    pub fw_error_fatal: u32,
+
+    /// Boot status at the time of the crash
+    pub boot_status: u32,
+
+    /// Mailbox command register at the time of the crash
+    pub last_mbox_cmd: u32,
+
+    pub mcause: u32,
+    pub mscause: u32,
+    pub mepc: u32,
+    pub mtval: u32,
+    pub mstatus: u32,
+
+    /// error_internal_intr_r, only captured on NMI
+    pub err_interrupt_status: u32,
+
+    /// ra, sp, a0-a7 and t0-t6, in that order. Only captured on traps.
+    pub regs: [u32; CRASH_RECORD_REG_COUNT],
+
+    /// Words at the top of the stack, starting at the stack pointer
+    pub stack: [u32; CRASH_RECORD_STACK_WORDS],
+
+    /// CFI counter value, mask and PRNG state
+    pub cfi_state: [u32; CRASH_RECORD_CFI_STATE_WORDS],
+}
+
+impl CrashRecord {
+    /// No crash was recorded since the last cold reset
+    pub const MARKER_EMPTY: u32 = 0;
+
+    /// Trap state was captured, the fatal error has not been reported yet
+    pub const MARKER_TRAP: u32 = 0x5452_4150; // "TRAP"
+
+    /// The record is complete
+    pub const MARKER_VALID: u32 = 0x4352_5348; // "CRSH"
+
+    /// Returns true if the record describes a crash
+    pub fn is_valid(&self) -> bool {
+        self.marker == Self::MARKER_VALID
+    }
+
+    /// Start a new record from the state saved by a trap handler. The record
+    /// is completed by `report_fw_error_fatal()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `regs` - ra, sp, a0-a7 and t0-t6
+    pub fn begin_trap(&mut self, regs: &[u32; CRASH_RECORD_REG_COUNT]) {
+        self.zeroize();
+        self.regs = *regs;
+        self.capture_stack(regs[1]);
+        self.marker = Self::MARKER_TRAP;
+    }
+
+    /// Copy the words at the top of the stack, stopping at the end of DCCM
+    fn capture_stack(&mut self, sp: u32) {
+        let dccm = DCCM_ORG..DCCM_ORG + DCCM_SIZE;
+        if sp % 4 != 0 {
+            return;
+        }
+        for (i, word) in self.stack.iter_mut().enumerate() {
+            let addr = sp.wrapping_add((i * 4) as u32);
+            if !dccm.contains(&addr) {
+                break;
+            }
+            *word = unsafe { core::ptr::read_volatile(addr as *const u32) };
+        }
+    }
+
+    /// Complete the record for a fatal error. If no trap state was captured
+    /// the previous record is discarded.
+    #[cfg(feature = "crash-record")]
+    fn finish(&mut self, fw_error_fatal: u32) {
+        if self.marker != Self::MARKER_TRAP {
+            self.zeroize();
+            // Approximate the stack pointer with the address of a local
+            let frame = 0u32;
+            self.capture_stack(addr_of!(frame) as u32);
+        }
+        self.fw_error_fatal = fw_error_fatal;
+        unsafe {
+            self.boot_status = core::ptr::read_volatile(BOOT_STATUS_ORG as *const u32);
+            for (i, word) in self.cfi_state.iter_mut().enumerate() {
+                *word = core::ptr::read_volatile((CFI_STATE_ORG as *const u32).add(i));
+            }
+            self.last_mbox_cmd = MboxCsr::new().regs().cmd().read();
+        }
+        self.marker = Self::MARKER_VALID;
+    }
+
+    /// Returns the crash record in DCCM
+    ///
+    /// # Safety
+    ///
+    /// Only to be called from trap handlers and fatal error paths, where no
+    /// other reference to the persistent data is used again.
+    pub unsafe fn get_mut() -> &'static mut CrashRecord {
+        &mut *(CRASH_RECORD_ORG as *mut CrashRecord)
+    }
+}
+
+/// Complete the crash record for fatal error `code`
+#[cfg(feature = "crash-record")]
+pub(crate) fn record_fatal_error(code: u32) {
+    unsafe { CrashRecord::get_mut() }.finish(code);
+}
@@ -144,6 +144,31 @@ pub struct Ecc384Signature {
     pub s: Ecc384Scalar,
 }
 
+pub trait Ecc384Alg {
+    fn key_pair(
+        &mut self,
+        seed: &Ecc384Seed,
+        nonce: &Array4x12,
+        trng: &mut Trng,
+        priv_key: Ecc384PrivKeyOut,
+    ) -> CaliptraResult<Ecc384PubKey>;
+
+    fn sign(
+        &mut self,
+        priv_key: &Ecc384PrivKeyIn,
+        pub_key: &Ecc384PubKey,
+        data: &Ecc384Scalar,
+        trng: &mut Trng,
+    ) -> CaliptraResult<Ecc384Signature>;
+
+    fn verify(
+        &mut self,
+        pub_key: &Ecc384PubKey,
+        digest: &Ecc384Scalar,
+        signature: &Ecc384Signature,
+    ) -> CaliptraResult<Ecc384Result>;
+}
+
 /// Elliptic Curve P-384 API
 pub struct Ecc384 {
     ecc: EccReg,
@@ -497,6 +522,37 @@ impl Ecc384 {
     }
 }
 
+impl Ecc384Alg for Ecc384 {
+    fn key_pair(
+        &mut self,
+        seed: &Ecc384Seed,
+        nonce: &Array4x12,
+        trng: &mut Trng,
+        priv_key: Ecc384PrivKeyOut,
+    ) -> CaliptraResult<Ecc384PubKey> {
+        Ecc384::key_pair(self, seed, nonce, trng, priv_key)
+    }
+
+    fn sign(
+        &mut self,
+        priv_key: &Ecc384PrivKeyIn,
+        pub_key: &Ecc384PubKey,
+        data: &Ecc384Scalar,
+        trng: &mut Trng,
+    ) -> CaliptraResult<Ecc384Signature> {
+        Ecc384::sign(self, priv_key, pub_key, data, trng)
+    }
+
+    fn verify(
+        &mut self,
+        pub_key: &Ecc384PubKey,
+        digest: &Ecc384Scalar,
+        signature: &Ecc384Signature,
+    ) -> CaliptraResult<Ecc384Result> {
+        Ecc384::verify(self, pub_key, digest, signature)
+    }
+}
+
 /// ECC-384 key access error trait
 trait Ecc384KeyAccessErr {
     /// Convert to read seed operation error
@@ -11,6 +11,8 @@ Abstract:
     File contains API for Error Reporting via Soc Iface.
 
 --*/
+#[cfg(feature = "crash-record")]
+use crate::crash_record::record_fatal_error;
 use crate::memory_layout::BOOT_STATUS_ORG;
 use caliptra_registers::soc_ifc::SocIfcReg;
 
@@ -26,7 +28,7 @@ pub fn report_fw_error_non_fatal(val: u32) {
     update_boot_status(&mut soc_ifc);
 }
 
-/// Report fatal F/W error
+/// Report fatal F/W error and complete the crash record, if enabled
 ///
 /// # Arguments
 ///
@@ -36,6 +38,8 @@ pub fn report_fw_error_fatal(val: u32) {
     soc_ifc.regs_mut().cptra_fw_error_fatal().write(|_| val);
 
     update_boot_status(&mut soc_ifc);
+    #[cfg(feature = "crash-record")]
+    record_fatal_error(val);
 }
 
 fn update_boot_status(soc_ifc: &mut SocIfcReg) {
@@ -63,12 +63,24 @@ impl From<IdevidCertAttr> for usize {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceCertFormat {
+    X509 = 0,
+    Cbor = 1,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RomVerifyConfig {
     EcdsaOnly = 0,
     EcdsaAndLms = 1,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MldsaVerifyConfig {
+    Disabled = 0,
+    Enabled = 1,
+}
+
 impl FuseBank<'_> {
     /// Get the key id crypto algorithm.
     ///
@@ -95,6 +107,29 @@ impl FuseBank<'_> {
         }
     }
 
+    /// Get the format of the DICE certificates.
+    ///
+    /// # Arguments
+    /// * None
+    ///
+    /// # Returns
+    ///     DICE certificate format
+    ///
+    pub fn dice_cert_format(&self) -> DiceCertFormat {
+        let soc_ifc_regs = self.soc_ifc.regs();
+
+        let flags = soc_ifc_regs
+            .fuse_idevid_cert_attr()
+            .at(IdevidCertAttr::Flags.into())
+            .read();
+
+        if flags & 0x4 != 0 {
+            DiceCertFormat::Cbor
+        } else {
+            DiceCertFormat::X509
+        }
+    }
+
     /// Get the manufacturer serial number.
     ///
     /// # Arguments
@@ -306,6 +341,34 @@ impl FuseBank<'_> {
             RomVerifyConfig::EcdsaAndLms
         }
     }
+
+    /// Get the ML-DSA verification config.
+    ///
+    /// ML-DSA verification is enabled by bit 3 of the IDevID certificate
+    /// attribute flags word.
+    ///
+    /// # Arguments
+    /// * None
+    ///
+    /// # Returns
+    ///     MldsaVerifyConfig
+    ///         Disabled: Verify Caliptra firmware images without ML-DSA
+    ///         Enabled: Verify Caliptra firmware images with ML-DSA-87 as well
+    ///
+    pub fn mldsa_verify(&self) -> MldsaVerifyConfig {
+        let soc_ifc_regs = self.soc_ifc.regs();
+
+        let flags = soc_ifc_regs
+            .fuse_idevid_cert_attr()
+            .at(IdevidCertAttr::Flags.into())
+            .read();
+
+        if flags & 0x8 == 0 {
+            MldsaVerifyConfig::Disabled
+        } else {
+            MldsaVerifyConfig::Enabled
+        }
+    }
 }
 
 #[cfg(test)]
@@ -8,7 +8,7 @@ use crate::{
 };
 use bitfield::{bitfield_bitrange, bitfield_fields};
 use caliptra_error::CaliptraError;
-use caliptra_image_types::RomInfo;
+use caliptra_image_types::{ImageTocEntry, RomInfo, MAX_COMPONENT_COUNT};
 use core::mem::size_of;
 use zerocopy::{AsBytes, FromBytes};
 use zeroize::Zeroize;
@@ -299,10 +299,32 @@ pub struct FirmwareHandoffTable {
 
     /// Reserved for future use.
     #[cfg(any(feature = "fmc", feature = "runtime"))]
-    pub reserved: [u8; 1636],
+    pub reserved: [u8; 1024],
 
     #[cfg(not(any(feature = "fmc", feature = "runtime")))]
-    pub reserved: [u8; 1642],
+    pub reserved: [u8; 1030],
+
+    /// Number of valid entries in `components`.
+    pub component_count: u32,
+
+    /// TOC entries of the additional image components loaded by ROM.
+    pub components: [ImageTocEntry; MAX_COMPONENT_COUNT as usize],
+
+    /// Digest of the vendor ML-DSA-87 public key verified by ROM, or zero if
+    /// the fuses do not require ML-DSA verification.
+    pub vendor_mldsa_pub_key_digest: [u32; 12],
+
+    /// Digest of the owner ML-DSA-87 public key verified by ROM, or zero if
+    /// the fuses do not require ML-DSA verification.
+    pub owner_mldsa_pub_key_digest: [u32; 12],
+
+    /// Vendor LMS public key at the bottom of the HSS chain verified by ROM.
+    /// Only valid if the vendor or owner uses more than one HSS level.
+    pub vendor_lms_hss_pub_key: [u32; 12],
+
+    /// Owner LMS public key at the bottom of the HSS chain verified by ROM.
+    /// Only valid if the vendor or owner uses more than one HSS level.
+    pub owner_lms_hss_pub_key: [u32; 12],
 }
 
 impl Default for FirmwareHandoffTable {
@@ -349,10 +371,17 @@ impl Default for FirmwareHandoffTable {
             #[cfg(any(feature = "fmc", feature = "runtime"))]
             rt_hash_chain_kv_hdl: HandOffDataHandle(0),
             #[cfg(any(feature = "fmc", feature = "runtime"))]
-            reserved: [0u8; 1636],
+            reserved: [0u8; 1024],
 
             #[cfg(not(any(feature = "fmc", feature = "runtime")))]
-            reserved: [0u8; 1642],
+            reserved: [0u8; 1030],
+
+            component_count: 0,
+            components: [ImageTocEntry::default(); MAX_COMPONENT_COUNT as usize],
+            vendor_mldsa_pub_key_digest: [0u32; 12],
+            owner_mldsa_pub_key_digest: [0u32; 12],
+            vendor_lms_hss_pub_key: [0u32; 12],
+            owner_lms_hss_pub_key: [0u32; 12],
         }
     }
 }
@@ -423,9 +452,16 @@ pub fn print_fht(fht: &FirmwareHandoffTable) {
     crate::cprintln!("Measurement log Address: {}", fht.meas_log_addr);
     crate::cprintln!("Measurement log Index: {}", fht.meas_log_index);
     crate::cprintln!("Fuse log Address: 0x{:08x}", fht.fuse_log_addr);
+    crate::cprintln!("Component Count: {}", fht.component_count);
 }
 
 impl FirmwareHandoffTable {
+    /// TOC entries of the additional image components loaded by ROM.
+    pub fn components(&self) -> &[ImageTocEntry] {
+        let count = (self.component_count as usize).min(self.components.len());
+        &self.components[..count]
+    }
+
     /// Perform validity check of the table's data.
     /// The fields below should have been populated by ROM with
     /// valid data before it transfers control to mutable code.
@@ -110,6 +110,32 @@ impl From<KeyReadArgs> for Hmac384Key<'_> {
     }
 }
 
+pub trait Hmac384OpAlg<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()>;
+    fn finalize(&mut self) -> CaliptraResult<()>;
+}
+
+pub trait Hmac384Alg {
+    type Op<'a>: Hmac384OpAlg<'a>
+    where
+        Self: 'a;
+
+    fn hmac_init<'a>(
+        &'a mut self,
+        key: &Hmac384Key,
+        trng: &mut Trng,
+        tag: Hmac384Tag<'a>,
+    ) -> CaliptraResult<Self::Op<'a>>;
+
+    fn hmac(
+        &mut self,
+        key: &Hmac384Key,
+        data: &Hmac384Data,
+        trng: &mut Trng,
+        tag: Hmac384Tag,
+    ) -> CaliptraResult<()>;
+}
+
 pub struct Hmac384 {
     hmac: HmacReg,
 }
@@ -131,7 +157,7 @@ impl Hmac384 {
         key: &Hmac384Key,
         trng: &mut Trng,
         mut tag: Hmac384Tag<'a>,
-    ) -> CaliptraResult<Hmac384Op> {
+    ) -> CaliptraResult<Hmac384Op<'a>> {
         let hmac = self.hmac.regs_mut();
 
         // Configure the hardware so that the output tag is stored at a location specified by the
@@ -407,6 +433,29 @@ impl Hmac384 {
     }
 }
 
+impl Hmac384Alg for Hmac384 {
+    type Op<'a> = Hmac384Op<'a>;
+
+    fn hmac_init<'a>(
+        &'a mut self,
+        key: &Hmac384Key,
+        trng: &mut Trng,
+        tag: Hmac384Tag<'a>,
+    ) -> CaliptraResult<Hmac384Op<'a>> {
+        Hmac384::hmac_init(self, key, trng, tag)
+    }
+
+    fn hmac(
+        &mut self,
+        key: &Hmac384Key,
+        data: &Hmac384Data,
+        trng: &mut Trng,
+        tag: Hmac384Tag,
+    ) -> CaliptraResult<()> {
+        Hmac384::hmac(self, key, data, trng, tag)
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Hmac384OpState {
     /// Initial state
@@ -521,6 +570,16 @@ impl<'a> Hmac384Op<'a> {
     }
 }
 
+impl<'a> Hmac384OpAlg<'a> for Hmac384Op<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        Hmac384Op::update(self, data)
+    }
+
+    fn finalize(&mut self) -> CaliptraResult<()> {
+        Hmac384Op::finalize(self)
+    }
+}
+
 /// HMAC-384 key access error trait
 trait Hmac384KeyAccessErr {
     /// Convert to read key operation error
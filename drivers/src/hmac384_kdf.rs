@@ -12,7 +12,7 @@ Abstract:
 
 --*/
 
-use crate::{Hmac384, Hmac384Key, Hmac384Tag, Trng};
+use crate::{Hmac384, Hmac384Alg, Hmac384Key, Hmac384OpAlg, Hmac384Tag, Trng};
 #[cfg(not(feature = "no-cfi"))]
 use caliptra_cfi_derive::cfi_mod_fn;
 use caliptra_error::CaliptraResult;
@@ -53,3 +53,37 @@ pub fn hmac384_kdf(
 
     hmac_op.finalize()
 }
+
+/// Calculate HMAC-384-KDF with any HMAC384 implementation, such as the
+/// software driver used by host unit tests. Same as `hmac384_kdf`.
+///
+/// # Arguments
+///
+/// * `hmac` - HMAC384 implementation
+/// * `key` - HMAC384 key
+/// * `label` - Label for the KDF. If `context` is omitted, this is considered
+///             the fixed input data.
+/// * `context` - Context for KDF. If present, a NULL byte is included between
+///               the label and context.
+/// * `trng` - TRNG driver instance
+/// * `output` - Location to store the output
+pub fn hmac384_kdf_alg(
+    hmac: &mut impl Hmac384Alg,
+    key: Hmac384Key,
+    label: &[u8],
+    context: Option<&[u8]>,
+    trng: &mut Trng,
+    output: Hmac384Tag,
+) -> CaliptraResult<()> {
+    let mut hmac_op = hmac.hmac_init(&key, trng, output)?;
+
+    hmac_op.update(&1_u32.to_be_bytes())?;
+    hmac_op.update(label)?;
+
+    if let Some(context) = context {
+        hmac_op.update(&[0x00])?;
+        hmac_op.update(context)?;
+    }
+
+    hmac_op.finalize()
+}
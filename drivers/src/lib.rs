@@ -19,6 +19,7 @@ mod array_concat;
 mod wait;
 
 mod bounded_address;
+mod crash_record;
 mod csrng;
 mod data_vault;
 mod doe;
@@ -35,6 +36,7 @@ mod kv_access;
 mod lms;
 mod mailbox;
 pub mod memory_layout;
+mod mldsa87;
 mod okref;
 mod pcr_bank;
 pub mod pcr_log;
@@ -54,23 +56,29 @@ pub use array::{Array4x12, Array4x4, Array4x5, Array4x8, Array4xN};
 pub use array_concat::array_concat3;
 pub use bounded_address::{BoundedAddr, MemBounds, RomAddr};
 pub use caliptra_error::{CaliptraError, CaliptraResult};
+pub use crash_record::{
+    CrashRecord, CRASH_RECORD_CFI_STATE_WORDS, CRASH_RECORD_REG_COUNT, CRASH_RECORD_STACK_WORDS,
+};
 pub use csrng::{Csrng, HealthFailCounts as CsrngHealthFailCounts, Seed as CsrngSeed};
 pub use data_vault::{
     ColdResetEntry4, ColdResetEntry48, DataVault, WarmResetEntry4, WarmResetEntry48,
 };
 pub use doe::DeobfuscationEngine;
 pub use ecc384::{
-    Ecc384, Ecc384PrivKeyIn, Ecc384PrivKeyOut, Ecc384PubKey, Ecc384Result, Ecc384Scalar,
+    Ecc384, Ecc384Alg, Ecc384PrivKeyIn, Ecc384PrivKeyOut, Ecc384PubKey, Ecc384Result, Ecc384Scalar,
     Ecc384Seed, Ecc384Signature,
 };
 pub use error_reporter::{report_fw_error_fatal, report_fw_error_non_fatal};
 pub use exit_ctrl::ExitCtrl;
 pub use fuse_bank::{
-    FuseBank, IdevidCertAttr, RomVerifyConfig, VendorPubKeyRevocation, X509KeyIdAlgo,
+    DiceCertFormat, FuseBank, IdevidCertAttr, MldsaVerifyConfig, RomVerifyConfig,
+    VendorPubKeyRevocation, X509KeyIdAlgo,
 };
 pub use hand_off::FirmwareHandoffTable;
-pub use hmac384::{Hmac384, Hmac384Data, Hmac384Key, Hmac384Op, Hmac384Tag};
-pub use hmac384_kdf::hmac384_kdf;
+pub use hmac384::{
+    Hmac384, Hmac384Alg, Hmac384Data, Hmac384Key, Hmac384Op, Hmac384OpAlg, Hmac384Tag,
+};
+pub use hmac384_kdf::{hmac384_kdf, hmac384_kdf_alg};
 pub use key_vault::{KeyId, KeyUsage, KeyVault};
 pub use kv_access::{KeyReadArgs, KeyWriteArgs};
 pub use lms::{
@@ -78,18 +86,20 @@ pub use lms::{
     Sha256Digest, D_INTR, D_LEAF, D_MESG, D_PBLC,
 };
 pub use mailbox::{Mailbox, MailboxRecvTxn, MailboxSendTxn};
+pub use mldsa87::{Mldsa87, Mldsa87Result};
 pub use okref::okmutref;
 pub use okref::okref;
 pub use pcr_bank::{PcrBank, PcrId};
 pub use pcr_reset::PcrResetCounter;
 pub use persistent::{
-    FuseLogArray, PcrLogArray, PersistentData, PersistentDataAccessor, StashMeasurementArray,
-    FUSE_LOG_MAX_COUNT, MEASUREMENT_MAX_COUNT, PCR_LOG_MAX_COUNT,
+    FuseLogArray, IdevIdCsr, PcrLogArray, PersistentData, PersistentDataAccessor,
+    StashMeasurementArray, FUSE_LOG_MAX_COUNT, IDEVID_CSR_MAX_SIZE, MEASUREMENT_MAX_COUNT,
+    PCR_LOG_MAX_COUNT,
 };
 pub use pic::{IntSource, Pic};
 pub use sha1::{Sha1, Sha1Digest, Sha1DigestOp};
 pub use sha256::{Sha256, Sha256Alg, Sha256DigestOp};
-pub use sha384::{Sha384, Sha384Digest, Sha384DigestOp};
+pub use sha384::{Sha384, Sha384Alg, Sha384Digest, Sha384DigestOp, Sha384DigestOpAlg};
 pub use sha384acc::{Sha384Acc, Sha384AccOp, ShaAccLockState};
 pub use soc_ifc::{report_boot_status, Lifecycle, MfgFlags, ResetReason, SocIfc};
 pub use trng::Trng;
@@ -453,11 +453,23 @@ impl Lms {
         }
 
         let q_str = <[u8; 4]>::from(lms_sig.q);
+        #[cfg(feature = "lms-tree-checks")]
+        let (hash_width, tree_height) = get_lms_parameters(lms_sig.tree_type)?;
+        #[cfg(not(feature = "lms-tree-checks"))]
         let (_, tree_height) = get_lms_parameters(lms_sig.tree_type)?;
+        // Make sure the hash width of the tree matches the value of N this was compiled with
+        #[cfg(feature = "lms-tree-checks")]
+        if hash_width as usize != N * 4 {
+            return Err(CaliptraError::DRIVER_LMS_INVALID_HASH_WIDTH);
+        }
         // Make sure the height of the tree matches the value of H this was compiled with
         if tree_height as usize != H {
             return Err(CaliptraError::DRIVER_LMS_INVALID_TREE_HEIGHT);
         }
+        #[cfg(feature = "lms-tree-checks")]
+        if lms_sig.tree_type != lms_public_key.tree_type {
+            return Err(CaliptraError::DRIVER_LMS_SIGNATURE_LMS_DOESNT_MATCH_PUBKEY_LMS);
+        }
         // Make sure the value of Q is valid for the tree height
         if lms_sig.q.get() >= 1 << H {
             return Err(CaliptraError::DRIVER_LMS_INVALID_Q_VALUE);
@@ -15,6 +15,12 @@ Abstract:
 #[cfg(test)]
 use crate::FirmwareHandoffTable;
 
+#[cfg(test)]
+use crate::CrashRecord;
+
+#[cfg(test)]
+use crate::IdevIdCsr;
+
 #[cfg(test)]
 use caliptra_image_types::ImageManifest;
 
@@ -39,7 +45,9 @@ pub const MEASUREMENT_LOG_ORG: u32 = 0x50004C00;
 pub const FUSE_LOG_ORG: u32 = 0x50005000;
 pub const DPE_ORG: u32 = 0x50005400;
 pub const PCR_RESET_COUNTER_ORG: u32 = 0x50006400;
-pub const DATA_ORG: u32 = 0x50006800;
+pub const CRASH_RECORD_ORG: u32 = 0x50006800;
+pub const IDEVID_CSR_ORG: u32 = 0x50006A00;
+pub const DATA_ORG: u32 = 0x50006E00;
 pub const STACK_ORG: u32 = 0x5001A000;
 pub const ROM_STACK_ORG: u32 = 0x5001C000;
 pub const ESTACK_ORG: u32 = 0x5001F800;
@@ -65,7 +73,9 @@ pub const MEASUREMENT_LOG_SIZE: u32 = 1024;
 pub const FUSE_LOG_SIZE: u32 = 1024;
 pub const DPE_SIZE: u32 = 4 * 1024;
 pub const PCR_RESET_COUNTER_SIZE: u32 = 1024;
-pub const DATA_SIZE: u32 = 78 * 1024;
+pub const CRASH_RECORD_SIZE: u32 = 512;
+pub const IDEVID_CSR_SIZE: u32 = 1024;
+pub const DATA_SIZE: u32 = 76 * 1024 + 512;
 pub const STACK_SIZE: u32 = 22 * 1024;
 pub const ROM_STACK_SIZE: u32 = 14 * 1024;
 pub const ESTACK_SIZE: u32 = 1024;
@@ -138,7 +148,24 @@ fn mem_layout_test_dpe() {
 #[test]
 #[allow(clippy::assertions_on_constants)]
 fn mem_layout_test_pcr_reset_counter() {
-    assert_eq!((DATA_ORG - PCR_RESET_COUNTER_ORG), PCR_RESET_COUNTER_SIZE);
+    assert_eq!(
+        (CRASH_RECORD_ORG - PCR_RESET_COUNTER_ORG),
+        PCR_RESET_COUNTER_SIZE
+    );
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_crash_record() {
+    assert!(CRASH_RECORD_SIZE as usize >= core::mem::size_of::<CrashRecord>());
+    assert_eq!((IDEVID_CSR_ORG - CRASH_RECORD_ORG), CRASH_RECORD_SIZE);
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn mem_layout_test_idevid_csr() {
+    assert_eq!(IDEVID_CSR_SIZE as usize, core::mem::size_of::<IdevIdCsr>());
+    assert_eq!((DATA_ORG - IDEVID_CSR_ORG), IDEVID_CSR_SIZE);
 }
 
 #[test]
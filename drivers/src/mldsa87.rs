@@ -0,0 +1,532 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    mldsa87.rs
+
+Abstract:
+
+    File contains API for ML-DSA-87 signature verification.
+    Implementation follows the ML-DSA specification and pseudocode from FIPS 204
+    https://csrc.nist.gov/pubs/fips/204/final
+
+    There is no ML-DSA or SHA-3 hardware, so both are implemented in
+    software. Only one row of the matrix A is expanded at a time to keep
+    the stack usage within a few kilobytes.
+
+--*/
+
+use crate::CaliptraResult;
+use caliptra_image_types::{MLDSA87_PUB_KEY_BYTE_SIZE, MLDSA87_SIGNATURE_BYTE_SIZE};
+
+/// Modulus
+const Q: u32 = 8380417;
+
+/// Polynomial degree
+const N: usize = 256;
+
+/// Rows of the matrix A
+const K: usize = 8;
+
+/// Columns of the matrix A
+const L: usize = 7;
+
+/// Dropped bits of t
+const D: u32 = 13;
+
+/// Number of +/-1 coefficients of the challenge polynomial
+const TAU: usize = 60;
+
+/// Coefficient range of the mask y
+const GAMMA1: i32 = 1 << 19;
+
+/// Low-order rounding range
+const GAMMA2: u32 = (Q - 1) / 32;
+
+/// Infinity norm bound of c * s1
+const BETA: i32 = 120;
+
+/// Maximum number of hints
+const OMEGA: usize = 75;
+
+const RHO_BYTE_SIZE: usize = 32;
+const TR_BYTE_SIZE: usize = 64;
+const MU_BYTE_SIZE: usize = 64;
+const C_TILDE_BYTE_SIZE: usize = 64;
+const T1_BITS: u32 = 10;
+const Z_BITS: u32 = 20;
+const T1_POLY_BYTE_SIZE: usize = N * T1_BITS as usize / 8;
+const Z_POLY_BYTE_SIZE: usize = N * Z_BITS as usize / 8;
+const W1_POLY_BYTE_SIZE: usize = N * 4 / 8;
+
+/// 256^-1 mod q
+const N_INV: u32 = 8347681;
+
+/// 512th root of unity mod q
+const ZETA: u32 = 1753;
+
+/// ZETA^BitRev8(k) mod q
+const ZETAS: [u32; N] = zetas();
+
+type Poly = [u32; N];
+
+#[must_use]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mldsa87Result {
+    Success = 0xDDDDDDDD,
+    SigVerifyFailed = 0x22222222,
+}
+
+#[derive(Default, Debug)]
+pub struct Mldsa87 {}
+
+impl Mldsa87 {
+    /// Verify an ML-DSA-87 signature (FIPS 204 Algorithm 3) with an empty
+    /// context string.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - Encoded public key
+    /// * `msg`     - Message that was signed
+    /// * `sig`     - Encoded signature
+    ///
+    /// # Returns
+    ///
+    /// * `Mldsa87Result` - Success if the signature is valid
+    #[inline(never)]
+    pub fn verify(
+        &mut self,
+        pub_key: &[u8; MLDSA87_PUB_KEY_BYTE_SIZE],
+        msg: &[u8],
+        sig: &[u8; MLDSA87_SIGNATURE_BYTE_SIZE],
+    ) -> CaliptraResult<Mldsa87Result> {
+        let (rho, t1) = pub_key.split_at(RHO_BYTE_SIZE);
+        let (c_tilde, sig_rest) = sig.split_at(C_TILDE_BYTE_SIZE);
+        let (z, hint) = sig_rest.split_at(L * Z_POLY_BYTE_SIZE);
+
+        if !hint_valid(hint) || !z_norm_valid(z) {
+            return Ok(Mldsa87Result::SigVerifyFailed);
+        }
+
+        let mut tr = [0u8; TR_BYTE_SIZE];
+        let mut shake = Shake::shake256();
+        shake.absorb(pub_key);
+        shake.finalize();
+        shake.squeeze(&mut tr);
+
+        // M' = 0 || |ctx| || ctx || M with an empty ctx
+        let mut mu = [0u8; MU_BYTE_SIZE];
+        let mut shake = Shake::shake256();
+        shake.absorb(&tr);
+        shake.absorb(&[0, 0]);
+        shake.absorb(msg);
+        shake.finalize();
+        shake.squeeze(&mut mu);
+
+        let mut c = [0u32; N];
+        sample_in_ball(c_tilde, &mut c);
+        ntt(&mut c);
+
+        // c~' = H(mu || w1Encode(UseHint(h, A * z - c * t1 * 2^d)))
+        let mut w1_shake = Shake::shake256();
+        w1_shake.absorb(&mu);
+
+        let mut w = [0u32; N];
+        let mut a = [0u32; N];
+        let mut tmp = [0u32; N];
+        let mut hint_start = 0;
+        for i in 0..K {
+            w.fill(0);
+            for j in 0..L {
+                rej_ntt_poly(rho, j as u8, i as u8, &mut a);
+                unpack_z(&z[j * Z_POLY_BYTE_SIZE..][..Z_POLY_BYTE_SIZE], &mut tmp);
+                ntt(&mut tmp);
+                for n in 0..N {
+                    w[n] = add(w[n], mul(a[n], tmp[n]));
+                }
+            }
+
+            unpack(
+                &t1[i * T1_POLY_BYTE_SIZE..][..T1_POLY_BYTE_SIZE],
+                T1_BITS,
+                &mut tmp,
+            );
+            for coeff in tmp.iter_mut() {
+                *coeff <<= D;
+            }
+            ntt(&mut tmp);
+            for n in 0..N {
+                w[n] = sub(w[n], mul(c[n], tmp[n]));
+            }
+            inv_ntt(&mut w);
+
+            // Hint positions of row i are stored in ascending order
+            let hint_end = usize::from(hint[OMEGA + i]);
+            let mut hints = hint[hint_start..hint_end].iter().peekable();
+            hint_start = hint_end;
+
+            let mut w1 = [0u8; W1_POLY_BYTE_SIZE];
+            for (n, coeff) in w.iter().enumerate() {
+                let h = hints.next_if(|&&pos| usize::from(pos) == n).is_some();
+                w1[n / 2] |= (use_hint(h, *coeff) as u8) << (4 * (n % 2));
+            }
+            w1_shake.absorb(&w1);
+        }
+        w1_shake.finalize();
+
+        let mut c_tilde_prime = [0u8; C_TILDE_BYTE_SIZE];
+        w1_shake.squeeze(&mut c_tilde_prime);
+
+        if c_tilde_prime[..] == *c_tilde {
+            Ok(Mldsa87Result::Success)
+        } else {
+            Ok(Mldsa87Result::SigVerifyFailed)
+        }
+    }
+}
+
+/// Compute the NTT twiddle factors
+const fn zetas() -> [u32; N] {
+    let mut zetas = [0u32; N];
+    let mut k = 0;
+    while k < N {
+        let mut exp = (k as u8).reverse_bits();
+        let mut base = ZETA as u64;
+        let mut acc = 1u64;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base % Q as u64;
+            }
+            base = base * base % Q as u64;
+            exp >>= 1;
+        }
+        zetas[k] = acc as u32;
+        k += 1;
+    }
+    zetas
+}
+
+fn add(a: u32, b: u32) -> u32 {
+    (a + b) % Q
+}
+
+fn sub(a: u32, b: u32) -> u32 {
+    (a + Q - b) % Q
+}
+
+fn mul(a: u32, b: u32) -> u32 {
+    (a as u64 * b as u64 % Q as u64) as u32
+}
+
+/// Number theoretic transform (FIPS 204 Algorithm 41)
+fn ntt(w: &mut Poly) {
+    let mut m = 0;
+    let mut len = N / 2;
+    while len >= 1 {
+        let mut start = 0;
+        while start < N {
+            m += 1;
+            let zeta = ZETAS[m];
+            for j in start..start + len {
+                let t = mul(zeta, w[j + len]);
+                w[j + len] = sub(w[j], t);
+                w[j] = add(w[j], t);
+            }
+            start += 2 * len;
+        }
+        len /= 2;
+    }
+}
+
+/// Inverse number theoretic transform (FIPS 204 Algorithm 42)
+fn inv_ntt(w: &mut Poly) {
+    let mut m = N;
+    let mut len = 1;
+    while len < N {
+        let mut start = 0;
+        while start < N {
+            m -= 1;
+            let zeta = Q - ZETAS[m];
+            for j in start..start + len {
+                let t = w[j];
+                w[j] = add(t, w[j + len]);
+                w[j + len] = mul(zeta, sub(t, w[j + len]));
+            }
+            start += 2 * len;
+        }
+        len *= 2;
+    }
+    for coeff in w.iter_mut() {
+        *coeff = mul(*coeff, N_INV);
+    }
+}
+
+/// Sample the NTT of entry A[r][s] from rho (FIPS 204 Algorithm 30)
+fn rej_ntt_poly(rho: &[u8], s: u8, r: u8, a: &mut Poly) {
+    let mut shake = Shake::shake128();
+    shake.absorb(rho);
+    shake.absorb(&[s, r]);
+    shake.finalize();
+
+    let mut j = 0;
+    let mut bytes = [0u8; 3];
+    while j < N {
+        shake.squeeze(&mut bytes);
+        let coeff =
+            u32::from(bytes[0]) | (u32::from(bytes[1]) << 8) | (u32::from(bytes[2] & 0x7f) << 16);
+        if coeff < Q {
+            a[j] = coeff;
+            j += 1;
+        }
+    }
+}
+
+/// Sample the challenge polynomial from c~ (FIPS 204 Algorithm 29)
+fn sample_in_ball(c_tilde: &[u8], c: &mut Poly) {
+    let mut shake = Shake::shake256();
+    shake.absorb(c_tilde);
+    shake.finalize();
+
+    let mut signs = [0u8; 8];
+    shake.squeeze(&mut signs);
+    let signs = u64::from_le_bytes(signs);
+
+    c.fill(0);
+    for (k, i) in (N - TAU..N).enumerate() {
+        let mut j = [0u8];
+        loop {
+            shake.squeeze(&mut j);
+            if usize::from(j[0]) <= i {
+                break;
+            }
+        }
+        let j = usize::from(j[0]);
+        c[i] = c[j];
+        c[j] = if (signs >> k) & 1 == 1 { Q - 1 } else { 1 };
+    }
+}
+
+/// Unpack little-endian `bits` wide coefficients
+fn unpack(bytes: &[u8], bits: u32, out: &mut Poly) {
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    let mut bytes = bytes.iter();
+    for coeff in out.iter_mut() {
+        while acc_bits < bits {
+            acc |= u32::from(*bytes.next().unwrap_or(&0)) << acc_bits;
+            acc_bits += 8;
+        }
+        *coeff = acc & ((1 << bits) - 1);
+        acc >>= bits;
+        acc_bits -= bits;
+    }
+}
+
+/// Unpack a polynomial of z, reduced mod q
+fn unpack_z(bytes: &[u8], out: &mut Poly) {
+    unpack(bytes, Z_BITS, out);
+    for coeff in out.iter_mut() {
+        let z = GAMMA1 - *coeff as i32;
+        *coeff = if z < 0 {
+            (z + Q as i32) as u32
+        } else {
+            z as u32
+        };
+    }
+}
+
+/// Check that all coefficients of z are below gamma1 - beta
+fn z_norm_valid(z: &[u8]) -> bool {
+    let mut poly = [0u32; N];
+    for bytes in z.chunks_exact(Z_POLY_BYTE_SIZE) {
+        unpack(bytes, Z_BITS, &mut poly);
+        if poly
+            .iter()
+            .any(|coeff| (GAMMA1 - *coeff as i32).abs() >= GAMMA1 - BETA)
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Check the hint encoding (FIPS 204 Algorithm 21)
+fn hint_valid(hint: &[u8]) -> bool {
+    let mut index = 0;
+    for i in 0..K {
+        let end = usize::from(hint[OMEGA + i]);
+        if end < index || end > OMEGA {
+            return false;
+        }
+        for n in index + 1..end {
+            if hint[n - 1] >= hint[n] {
+                return false;
+            }
+        }
+        index = end;
+    }
+    hint[index..OMEGA].iter().all(|&b| b == 0)
+}
+
+/// Split r into high and low bits (FIPS 204 Algorithm 36)
+fn decompose(r: u32) -> (u32, i32) {
+    let mut r0 = (r % (2 * GAMMA2)) as i32;
+    if r0 > GAMMA2 as i32 {
+        r0 -= 2 * GAMMA2 as i32;
+    }
+    let high = (r as i32 - r0) as u32;
+    if high == Q - 1 {
+        (0, r0 - 1)
+    } else {
+        (high / (2 * GAMMA2), r0)
+    }
+}
+
+/// Recover the high bits of r with a hint (FIPS 204 Algorithm 40)
+fn use_hint(hint: bool, r: u32) -> u32 {
+    const M: u32 = (Q - 1) / (2 * GAMMA2);
+    let (r1, r0) = decompose(r);
+    if !hint {
+        r1
+    } else if r0 > 0 {
+        (r1 + 1) % M
+    } else {
+        (r1 + M - 1) % M
+    }
+}
+
+/// SHAKE128 and SHAKE256 (FIPS 202)
+struct Shake {
+    state: [u64; 25],
+    rate: usize,
+    pos: usize,
+}
+
+impl Shake {
+    const SHAKE128_RATE: usize = 168;
+    const SHAKE256_RATE: usize = 136;
+
+    fn shake128() -> Self {
+        Self {
+            state: [0; 25],
+            rate: Self::SHAKE128_RATE,
+            pos: 0,
+        }
+    }
+
+    fn shake256() -> Self {
+        Self {
+            state: [0; 25],
+            rate: Self::SHAKE256_RATE,
+            pos: 0,
+        }
+    }
+
+    fn xor_byte(&mut self, pos: usize, byte: u8) {
+        self.state[pos / 8] ^= u64::from(byte) << (8 * (pos % 8));
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for byte in data {
+            self.xor_byte(self.pos, *byte);
+            self.pos += 1;
+            if self.pos == self.rate {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    fn finalize(&mut self) {
+        self.xor_byte(self.pos, 0x1f);
+        self.xor_byte(self.rate - 1, 0x80);
+        keccak_f1600(&mut self.state);
+        self.pos = 0;
+    }
+
+    fn squeeze(&mut self, out: &mut [u8]) {
+        for byte in out.iter_mut() {
+            if self.pos == self.rate {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+            *byte = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+}
+
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const KECCAK_ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const KECCAK_PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-f[1600] permutation
+fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in KECCAK_ROUND_CONSTANTS {
+        // Theta
+        let mut c = [0u64; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in (0..25).step_by(5) {
+                state[y + x] ^= d;
+            }
+        }
+
+        // Rho and Pi
+        let mut lane = state[1];
+        for (rotation, pi) in KECCAK_ROTATIONS.iter().zip(KECCAK_PI_LANES) {
+            let next = state[pi];
+            state[pi] = lane.rotate_left(*rotation);
+            lane = next;
+        }
+
+        // Chi
+        for y in (0..25).step_by(5) {
+            let mut row = [0u64; 5];
+            row.copy_from_slice(&state[y..y + 5]);
+            for x in 0..5 {
+                state[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota
+        state[0] ^= round_constant;
+    }
+}
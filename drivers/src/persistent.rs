@@ -9,6 +9,7 @@ use zerocopy::{AsBytes, FromBytes};
 use zeroize::Zeroize;
 
 use crate::{
+    crash_record::CrashRecord,
     fuse_log::FuseLogEntry,
     memory_layout,
     pcr_log::{MeasurementLogEntry, PcrLogEntry},
@@ -21,11 +22,45 @@ use crate::pcr_reset::PcrResetCounter;
 pub const PCR_LOG_MAX_COUNT: usize = 17;
 pub const FUSE_LOG_MAX_COUNT: usize = 62;
 pub const MEASUREMENT_MAX_COUNT: usize = 8;
+pub const IDEVID_CSR_MAX_SIZE: usize = memory_layout::IDEVID_CSR_SIZE as usize - size_of::<u32>();
 
 pub type PcrLogArray = [PcrLogEntry; PCR_LOG_MAX_COUNT];
 pub type FuseLogArray = [FuseLogEntry; FUSE_LOG_MAX_COUNT];
 pub type StashMeasurementArray = [MeasurementLogEntry; MEASUREMENT_MAX_COUNT];
 
+/// Initial Device ID CSR generated by ROM
+#[derive(FromBytes, AsBytes, Zeroize)]
+#[repr(C)]
+pub struct IdevIdCsr {
+    csr_len: u32,
+    csr: [u8; IDEVID_CSR_MAX_SIZE],
+}
+
+impl IdevIdCsr {
+    /// Store a CSR
+    ///
+    /// # Arguments
+    ///
+    /// * `csr` - DER encoded CSR
+    ///
+    /// # Returns
+    ///
+    /// * `None` if the CSR does not fit
+    pub fn set(&mut self, csr: &[u8]) -> Option<()> {
+        self.csr.get_mut(..csr.len())?.copy_from_slice(csr);
+        self.csr_len = csr.len() as u32;
+        Some(())
+    }
+
+    /// Retrieve the CSR, or `None` if no CSR was generated
+    pub fn get(&self) -> Option<&[u8]> {
+        match self.csr_len {
+            0 => None,
+            len => self.csr.get(..len as usize),
+        }
+    }
+}
+
 #[derive(FromBytes, AsBytes, Zeroize)]
 #[repr(C)]
 pub struct PersistentData {
@@ -77,6 +112,11 @@ pub struct PersistentData {
 
     #[cfg(not(feature = "runtime"))]
     pcr_reset: [u8; memory_layout::PCR_RESET_COUNTER_SIZE as usize],
+
+    pub crash_record: CrashRecord,
+    reserved8: [u8; memory_layout::CRASH_RECORD_SIZE as usize - size_of::<CrashRecord>()],
+
+    pub idevid_csr: IdevIdCsr,
 }
 impl PersistentData {
     pub fn assert_matches_layout() {
@@ -100,9 +140,17 @@ impl PersistentData {
                 addr_of!((*P).pcr_reset) as u32,
                 memory_layout::PCR_RESET_COUNTER_ORG
             );
+            assert_eq!(
+                addr_of!((*P).crash_record) as u32,
+                memory_layout::CRASH_RECORD_ORG
+            );
+            assert_eq!(
+                addr_of!((*P).idevid_csr) as u32,
+                memory_layout::IDEVID_CSR_ORG
+            );
             assert_eq!(
                 P.add(1) as u32,
-                memory_layout::PCR_RESET_COUNTER_ORG + memory_layout::PCR_RESET_COUNTER_SIZE
+                memory_layout::IDEVID_CSR_ORG + memory_layout::IDEVID_CSR_SIZE
             );
         }
     }
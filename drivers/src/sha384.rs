@@ -30,6 +30,20 @@ const SHA384_HASH_SIZE: usize = 48;
 /// SHA-384 Digest
 pub type Sha384Digest<'a> = &'a mut Array4x12;
 
+pub trait Sha384DigestOpAlg<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()>;
+    fn finalize(self, digest: &mut Array4x12) -> CaliptraResult<()>;
+}
+
+pub trait Sha384Alg {
+    type DigestOp<'a>: Sha384DigestOpAlg<'a>
+    where
+        Self: 'a;
+
+    fn digest_init(&mut self) -> CaliptraResult<Self::DigestOp<'_>>;
+    fn digest(&mut self, buf: &[u8]) -> CaliptraResult<Array4x12>;
+}
+
 pub struct Sha384 {
     sha512: Sha512Reg,
 }
@@ -312,6 +326,18 @@ impl Sha384 {
     }
 }
 
+impl Sha384Alg for Sha384 {
+    type DigestOp<'a> = Sha384DigestOp<'a>;
+
+    fn digest_init(&mut self) -> CaliptraResult<Sha384DigestOp<'_>> {
+        Sha384::digest_init(self)
+    }
+
+    fn digest(&mut self, buf: &[u8]) -> CaliptraResult<Array4x12> {
+        Sha384::digest(self, buf)
+    }
+}
+
 /// SHA-384 Digest state
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum Sha384DigestState {
@@ -418,6 +444,16 @@ impl<'a> Sha384DigestOp<'a> {
     }
 }
 
+impl<'a> Sha384DigestOpAlg<'a> for Sha384DigestOp<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        Sha384DigestOp::update(self, data)
+    }
+
+    fn finalize(self, digest: &mut Array4x12) -> CaliptraResult<()> {
+        Sha384DigestOp::finalize(self, digest)
+    }
+}
+
 /// SHA-384 key access error trait
 trait Sha384KeyAccessErr {
     /// Convert to read data operation error
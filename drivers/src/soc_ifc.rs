@@ -153,6 +153,15 @@ impl SocIfc {
         flags.contains(MfgFlags::GENERATE_IDEVID_CSR)
     }
 
+    /// Returns the flag indicating whether to store a nonce-bearing Initial
+    /// Device ID Certificate Signing Request (CSR) for runtime retrieval
+    pub fn mfg_flag_store_idev_id_csr(&self) -> bool {
+        let soc_ifc_regs = self.soc_ifc.regs();
+        // Lower 16 bits are for mfg flags
+        let flags: MfgFlags = (soc_ifc_regs.cptra_dbg_manuf_service_reg().read() & 0xffff).into();
+        flags.contains(MfgFlags::STORE_IDEVID_CSR)
+    }
+
     /// Returns the flag indicating whether random number generation is unavailable.
     pub fn mfg_flag_rng_unavailable(&self) -> bool {
         let soc_ifc_regs = self.soc_ifc.regs();
@@ -321,6 +330,15 @@ impl SocIfc {
             .write(|w| w.notif_cmd_avail_en(true));
         intr_block.global_intr_en_r().write(|w| w.notif_en(true));
     }
+
+    pub fn clear_mbox_notif_status(&mut self) {
+        let soc_ifc_regs = self.soc_ifc.regs_mut();
+        let intr_block = soc_ifc_regs.intr_block_rf();
+
+        intr_block
+            .notif_internal_intr_r()
+            .write(|w| w.notif_cmd_avail_sts(true));
+    }
 }
 
 bitflags::bitflags! {
@@ -330,6 +348,8 @@ bitflags::bitflags! {
        const GENERATE_IDEVID_CSR = 0x01;
        /// RNG functionality unavailable
        const RNG_SUPPORT_UNAVAILABLE = 0x2;
+       /// Store a nonce-bearing Initial Device Id CSR for runtime retrieval
+       const STORE_IDEVID_CSR = 0x4;
     }
 }
 
@@ -0,0 +1,289 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    ecc384.rs
+
+Abstract:
+
+    File contains a software implementation of the ECC-384 driver traits.
+
+--*/
+
+use crate::KeyVaultSw;
+use caliptra_drivers::{
+    Array4x12, CaliptraError, CaliptraResult, Ecc384Alg, Ecc384PrivKeyIn, Ecc384PrivKeyOut,
+    Ecc384PubKey, Ecc384Result, Ecc384Scalar, Ecc384Seed, Ecc384Signature, Trng,
+};
+use p384::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+use p384::ecdsa::{Signature, SigningKey, VerifyingKey};
+use p384::{EncodedPoint, FieldBytes};
+use rfc6979::HmacDrbg;
+
+/// Software ECC-384
+///
+/// Private keys are derived from the seed and nonce with the same
+/// HMAC-DRBG construction as the hardware, so key pairs match the ones
+/// generated on the device. Key vault slots are served from `KeyVaultSw`
+/// and the `trng` arguments are ignored.
+pub struct Ecc384Sw {
+    kv: KeyVaultSw,
+}
+
+impl Ecc384Sw {
+    pub fn new(kv: KeyVaultSw) -> Self {
+        Self { kv }
+    }
+
+    fn signing_key(priv_key: &Array4x12) -> CaliptraResult<SigningKey> {
+        SigningKey::from_bytes(&<[u8; 48]>::from(priv_key))
+            .map_err(|_| CaliptraError::DRIVER_ECC384_SCALAR_RANGE_CHECK_FAILED)
+    }
+
+    fn pub_key(signing_key: &SigningKey) -> Ecc384PubKey {
+        let point = signing_key.verifying_key().to_encoded_point(false);
+        Ecc384PubKey {
+            x: Array4x12::from(<[u8; 48]>::try_from(point.x().unwrap().as_slice()).unwrap()),
+            y: Array4x12::from(<[u8; 48]>::try_from(point.y().unwrap().as_slice()).unwrap()),
+        }
+    }
+}
+
+impl Ecc384Alg for Ecc384Sw {
+    fn key_pair(
+        &mut self,
+        seed: &Ecc384Seed,
+        nonce: &Array4x12,
+        _trng: &mut Trng,
+        priv_key: Ecc384PrivKeyOut,
+    ) -> CaliptraResult<Ecc384PubKey> {
+        if let Ecc384PrivKeyOut::Key(key) = &priv_key {
+            if !key.usage.ecc_private_key() {
+                return Err(CaliptraError::DRIVER_ECC384_KEYGEN_BAD_USAGE);
+            }
+        }
+
+        let seed = match seed {
+            Ecc384Seed::Array4x12(arr) => **arr,
+            Ecc384Seed::Key(key) => self.kv.read(
+                key.id,
+                |usage| usage.ecc_key_gen_seed(),
+                CaliptraError::DRIVER_ECC384_READ_SEED_KV_READ,
+            )?,
+        };
+
+        let mut key_bytes = [0u8; 48];
+        let mut drbg =
+            HmacDrbg::<sha2::Sha384>::new(&<[u8; 48]>::from(seed), &<[u8; 48]>::from(nonce), &[]);
+        drbg.fill_bytes(&mut key_bytes);
+        let key = Array4x12::from(key_bytes);
+        let pub_key = Self::pub_key(&Self::signing_key(&key)?);
+
+        match priv_key {
+            Ecc384PrivKeyOut::Array4x12(arr) => *arr = key,
+            Ecc384PrivKeyOut::Key(out) => self.kv.write(out.id, key, out.usage),
+        }
+        Ok(pub_key)
+    }
+
+    fn sign(
+        &mut self,
+        priv_key: &Ecc384PrivKeyIn,
+        pub_key: &Ecc384PubKey,
+        data: &Ecc384Scalar,
+        _trng: &mut Trng,
+    ) -> CaliptraResult<Ecc384Signature> {
+        let priv_key = match priv_key {
+            Ecc384PrivKeyIn::Array4x12(arr) => **arr,
+            Ecc384PrivKeyIn::Key(key) => self.kv.read(
+                key.id,
+                |usage| usage.ecc_private_key(),
+                CaliptraError::DRIVER_ECC384_READ_PRIV_KEY_KV_READ,
+            )?,
+        };
+
+        let signing_key = Self::signing_key(&priv_key)?;
+        let sig: Signature = signing_key
+            .sign_prehash(&<[u8; 48]>::from(data))
+            .map_err(|_| CaliptraError::DRIVER_ECC384_SIGN_VALIDATION_FAILED)?;
+        let sig = Ecc384Signature {
+            r: Array4x12::from(<[u8; 48]>::try_from(sig.r().to_be_bytes().as_slice()).unwrap()),
+            s: Array4x12::from(<[u8; 48]>::try_from(sig.s().to_be_bytes().as_slice()).unwrap()),
+        };
+
+        // The hardware driver verifies every signature it generates
+        if self.verify(pub_key, data, &sig)? != Ecc384Result::Success {
+            return Err(CaliptraError::DRIVER_ECC384_SIGN_VALIDATION_FAILED);
+        }
+        Ok(sig)
+    }
+
+    fn verify(
+        &mut self,
+        pub_key: &Ecc384PubKey,
+        digest: &Ecc384Scalar,
+        signature: &Ecc384Signature,
+    ) -> CaliptraResult<Ecc384Result> {
+        let sig = Signature::from_scalars(
+            FieldBytes::clone_from_slice(&<[u8; 48]>::from(signature.r)),
+            FieldBytes::clone_from_slice(&<[u8; 48]>::from(signature.s)),
+        )
+        .map_err(|_| CaliptraError::DRIVER_ECC384_SCALAR_RANGE_CHECK_FAILED)?;
+
+        let x = <[u8; 48]>::from(pub_key.x);
+        let y = <[u8; 48]>::from(pub_key.y);
+        let point = EncodedPoint::from_affine_coordinates(
+            FieldBytes::from_slice(&x),
+            FieldBytes::from_slice(&y),
+            false,
+        );
+        let result = VerifyingKey::from_encoded_point(&point)
+            .and_then(|key| key.verify_prehash(&<[u8; 48]>::from(digest), &sig));
+        Ok(match result {
+            Ok(()) => Ecc384Result::Success,
+            Err(_) => Ecc384Result::SigVerifyFailed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hmac384Sw;
+    use caliptra_drivers::{
+        hmac384_kdf_alg, Hmac384Alg, KeyId, KeyReadArgs, KeyUsage, KeyWriteArgs,
+    };
+
+    // Same vector as `test_kdf0` in the driver hardware tests.
+    const KDF_KEY: [u8; 48] = [
+        0x9e, 0x2c, 0xce, 0xc7, 0x00, 0x16, 0x1e, 0x42, 0xff, 0x0e, 0x13, 0x8c, 0x48, 0x89, 0xe4,
+        0xd6, 0xa0, 0x88, 0x8d, 0x13, 0x1d, 0x58, 0xcb, 0x44, 0xf5, 0xe2, 0x92, 0x47, 0x59, 0x64,
+        0xac, 0x6a, 0x8c, 0x63, 0xff, 0x7c, 0x0c, 0x95, 0xe7, 0xda, 0x0b, 0x4e, 0x17, 0xdf, 0x67,
+        0xa5, 0x5c, 0xb6,
+    ];
+    const KDF_MSG: [u8; 48] = [
+        0x2c, 0x60, 0xda, 0x7c, 0xd6, 0xfc, 0x88, 0x99, 0x58, 0x3e, 0xf7, 0xa8, 0x10, 0xe7, 0x4b,
+        0xb1, 0x37, 0x7b, 0xaa, 0x72, 0x66, 0x38, 0xb4, 0x15, 0x7c, 0x72, 0x41, 0x61, 0x06, 0x93,
+        0xcb, 0xc9, 0xb1, 0x78, 0xa7, 0x85, 0x61, 0xeb, 0xa7, 0x5d, 0x0e, 0x65, 0x99, 0x10, 0x49,
+        0xd9, 0x57, 0x93,
+    ];
+    const KDF_LABEL: [u8; 4] = [0x2d, 0xd2, 0x38, 0x86];
+    const KDF_CONTEXT: [u8; 48] = [
+        0x9a, 0x81, 0x9e, 0xf0, 0xc9, 0x67, 0xb2, 0x13, 0x88, 0x41, 0x72, 0x1e, 0xd9, 0xde, 0x2f,
+        0xd4, 0x1c, 0xb9, 0xa7, 0x7e, 0x78, 0x4e, 0x38, 0x5b, 0x90, 0x36, 0x26, 0x2a, 0xe2, 0x81,
+        0xf6, 0x21, 0x93, 0x55, 0x85, 0xf6, 0xf7, 0x59, 0xeb, 0x16, 0xcc, 0xed, 0x7f, 0x65, 0x13,
+        0x04, 0xd7, 0x9d,
+    ];
+    const KDF_PUB_X: [u8; 48] = [
+        0x67, 0x3e, 0x4d, 0x0d, 0x4a, 0x7b, 0x15, 0x17, 0x8a, 0x87, 0x5a, 0x28, 0x3a, 0xa4, 0x98,
+        0x80, 0x84, 0x99, 0xf6, 0x91, 0x76, 0xde, 0xaa, 0x52, 0x9f, 0x44, 0xb2, 0xdb, 0x6c, 0x2c,
+        0xac, 0xd3, 0x68, 0xe6, 0x8f, 0xdc, 0xdc, 0x7a, 0xfd, 0xef, 0x3f, 0x7f, 0x96, 0xef, 0x95,
+        0x0e, 0x08, 0x0a,
+    ];
+    const KDF_PUB_Y: [u8; 48] = [
+        0x34, 0x28, 0x5e, 0x58, 0xb9, 0x4a, 0x3a, 0xcc, 0x1c, 0x4b, 0xb3, 0x8f, 0xca, 0xf4, 0xf9,
+        0xc5, 0x91, 0x7c, 0xd7, 0x41, 0xd7, 0x0f, 0x72, 0xae, 0x29, 0x3d, 0xf7, 0x81, 0x76, 0xb4,
+        0x6f, 0xfd, 0xc3, 0xf8, 0xf1, 0x99, 0xd6, 0x97, 0x6a, 0x58, 0x63, 0x80, 0xcc, 0x80, 0x76,
+        0xcb, 0x13, 0x18,
+    ];
+
+    #[test]
+    fn test_kdf_key_pair() {
+        let kv = KeyVaultSw::new();
+        let mut hmac = Hmac384Sw::new(kv.clone());
+        let mut ecc = Ecc384Sw::new(kv.clone());
+        let mut trng = Trng::MfgMode();
+
+        hmac.hmac(
+            &(&Array4x12::from(KDF_KEY)).into(),
+            &(&KDF_MSG).into(),
+            &mut trng,
+            KeyWriteArgs::new(
+                KeyId::KeyId0,
+                KeyUsage::default().set_hmac_key_en().set_hmac_data_en(),
+            )
+            .into(),
+        )
+        .unwrap();
+
+        hmac384_kdf_alg(
+            &mut hmac,
+            KeyReadArgs::new(KeyId::KeyId0).into(),
+            &KDF_LABEL,
+            Some(&KDF_CONTEXT),
+            &mut trng,
+            KeyWriteArgs::new(KeyId::KeyId1, KeyUsage::default().set_ecc_key_gen_seed_en()).into(),
+        )
+        .unwrap();
+
+        let pub_key = ecc
+            .key_pair(
+                &KeyReadArgs::new(KeyId::KeyId1).into(),
+                &Array4x12::default(),
+                &mut trng,
+                KeyWriteArgs::new(KeyId::KeyId2, KeyUsage::default().set_ecc_private_key_en())
+                    .into(),
+            )
+            .unwrap();
+
+        assert_eq!(pub_key.x, Array4x12::from(KDF_PUB_X));
+        assert_eq!(pub_key.y, Array4x12::from(KDF_PUB_Y));
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let kv = KeyVaultSw::new();
+        let mut ecc = Ecc384Sw::new(kv.clone());
+        let mut trng = Trng::MfgMode();
+        let seed = Array4x12::from([0x5a; 48]);
+        let digest = Array4x12::from([0xa5; 48]);
+
+        let pub_key = ecc
+            .key_pair(
+                &(&seed).into(),
+                &Array4x12::default(),
+                &mut trng,
+                KeyWriteArgs::new(KeyId::KeyId3, KeyUsage::default().set_ecc_private_key_en())
+                    .into(),
+            )
+            .unwrap();
+        let sig = ecc
+            .sign(
+                &KeyReadArgs::new(KeyId::KeyId3).into(),
+                &pub_key,
+                &digest,
+                &mut trng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            ecc.verify(&pub_key, &digest, &sig).unwrap(),
+            Ecc384Result::Success
+        );
+        assert_eq!(
+            ecc.verify(&pub_key, &Array4x12::default(), &sig).unwrap(),
+            Ecc384Result::SigVerifyFailed
+        );
+        assert_eq!(
+            ecc.verify(&pub_key, &digest, &Ecc384Signature::default()),
+            Err(CaliptraError::DRIVER_ECC384_SCALAR_RANGE_CHECK_FAILED)
+        );
+    }
+
+    #[test]
+    fn test_key_pair_bad_usage() {
+        let mut ecc = Ecc384Sw::new(KeyVaultSw::new());
+        assert_eq!(
+            ecc.key_pair(
+                &(&Array4x12::default()).into(),
+                &Array4x12::default(),
+                &mut Trng::MfgMode(),
+                KeyWriteArgs::new(KeyId::KeyId0, KeyUsage::default().set_ecc_key_gen_seed_en())
+                    .into(),
+            ),
+            Err(CaliptraError::DRIVER_ECC384_KEYGEN_BAD_USAGE)
+        );
+    }
+}
@@ -0,0 +1,257 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    hmac384.rs
+
+Abstract:
+
+    File contains a software implementation of the HMAC-384 driver traits.
+
+--*/
+
+use crate::KeyVaultSw;
+use caliptra_drivers::{
+    Array4x12, CaliptraError, CaliptraResult, Hmac384Alg, Hmac384Data, Hmac384Key, Hmac384OpAlg,
+    Hmac384Tag, Trng,
+};
+use hmac::{Hmac, Mac};
+
+type HmacSha384 = Hmac<sha2::Sha384>;
+
+/// Software HMAC-384
+///
+/// Key vault slots are served from `KeyVaultSw`. The `trng` arguments are
+/// only needed by the hardware for its LFSR seed and are ignored here.
+pub struct Hmac384Sw {
+    kv: KeyVaultSw,
+}
+
+impl Hmac384Sw {
+    pub fn new(kv: KeyVaultSw) -> Self {
+        Self { kv }
+    }
+
+    fn key_bytes(&self, key: &Hmac384Key) -> CaliptraResult<[u8; 48]> {
+        let key = match key {
+            Hmac384Key::Array4x12(arr) => **arr,
+            Hmac384Key::Key(key) => self.kv.read(
+                key.id,
+                |usage| usage.hmac_key(),
+                CaliptraError::DRIVER_HMAC384_READ_KEY_KV_READ,
+            )?,
+        };
+        Ok(key.into())
+    }
+}
+
+/// Software HMAC-384 multi step operation
+pub struct Hmac384OpSw<'a> {
+    mac: Option<HmacSha384>,
+    kv: KeyVaultSw,
+    tag: Hmac384Tag<'a>,
+}
+
+impl<'a> Hmac384OpAlg<'a> for Hmac384OpSw<'a> {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        let mac = self
+            .mac
+            .as_mut()
+            .ok_or(CaliptraError::DRIVER_HMAC384_INVALID_STATE)?;
+        mac.update(data);
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> CaliptraResult<()> {
+        let mac = self
+            .mac
+            .take()
+            .ok_or(CaliptraError::DRIVER_HMAC384_INVALID_STATE)?;
+        write_tag(&self.kv, &mut self.tag, mac);
+        Ok(())
+    }
+}
+
+impl Hmac384Alg for Hmac384Sw {
+    type Op<'a> = Hmac384OpSw<'a>;
+
+    fn hmac_init<'a>(
+        &'a mut self,
+        key: &Hmac384Key,
+        _trng: &mut Trng,
+        tag: Hmac384Tag<'a>,
+    ) -> CaliptraResult<Hmac384OpSw<'a>> {
+        let key = self.key_bytes(key)?;
+        Ok(Hmac384OpSw {
+            mac: Some(HmacSha384::new_from_slice(&key).unwrap()),
+            kv: self.kv.clone(),
+            tag,
+        })
+    }
+
+    fn hmac(
+        &mut self,
+        key: &Hmac384Key,
+        data: &Hmac384Data,
+        _trng: &mut Trng,
+        mut tag: Hmac384Tag,
+    ) -> CaliptraResult<()> {
+        let key = self.key_bytes(key)?;
+        let mut mac = HmacSha384::new_from_slice(&key).unwrap();
+        match data {
+            Hmac384Data::Slice(buf) => mac.update(buf),
+            Hmac384Data::Key(key) => {
+                let data = self.kv.read(
+                    key.id,
+                    |usage| usage.hmac_data(),
+                    CaliptraError::DRIVER_HMAC384_READ_DATA_KV_READ,
+                )?;
+                mac.update(&<[u8; 48]>::from(data));
+            }
+        }
+        write_tag(&self.kv, &mut tag, mac);
+        Ok(())
+    }
+}
+
+fn write_tag(kv: &KeyVaultSw, tag: &mut Hmac384Tag, mac: HmacSha384) {
+    let result = mac.finalize().into_bytes();
+    let result = Array4x12::from(<[u8; 48]>::try_from(result.as_slice()).unwrap());
+    match tag {
+        Hmac384Tag::Array4x12(arr) => **arr = result,
+        Hmac384Tag::Key(key) => kv.write(key.id, result, key.usage),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use caliptra_drivers::{hmac384_kdf_alg, KeyId, KeyReadArgs, KeyUsage, KeyWriteArgs};
+
+    // Same vector as `test_hmac0` in the driver hardware tests.
+    const KEY: [u8; 48] = [0x0b; 48];
+    const DATA: [u8; 8] = [0x48, 0x69, 0x20, 0x54, 0x68, 0x65, 0x72, 0x65];
+    const TAG: [u8; 48] = [
+        0xb6, 0xa8, 0xd5, 0x63, 0x6f, 0x5c, 0x6a, 0x72, 0x24, 0xf9, 0x97, 0x7d, 0xcf, 0x7e, 0xe6,
+        0xc7, 0xfb, 0x6d, 0x0c, 0x48, 0xcb, 0xde, 0xe9, 0x73, 0x7a, 0x95, 0x97, 0x96, 0x48, 0x9b,
+        0xdd, 0xbc, 0x4c, 0x5d, 0xf6, 0x1d, 0x5b, 0x32, 0x97, 0xb4, 0xfb, 0x68, 0xda, 0xb9, 0xf1,
+        0xb5, 0x82, 0xc2,
+    ];
+
+    #[test]
+    fn test_hmac() {
+        let mut hmac = Hmac384Sw::new(KeyVaultSw::new());
+        let mut tag = Array4x12::default();
+        hmac.hmac(
+            &(&Array4x12::from(KEY)).into(),
+            &(&DATA).into(),
+            &mut Trng::MfgMode(),
+            (&mut tag).into(),
+        )
+        .unwrap();
+        assert_eq!(tag, Array4x12::from(TAG));
+    }
+
+    #[test]
+    fn test_hmac_multi_step() {
+        let mut hmac = Hmac384Sw::new(KeyVaultSw::new());
+        let key = Array4x12::from(KEY);
+        let mut tag = Array4x12::default();
+        let mut op = hmac
+            .hmac_init(&(&key).into(), &mut Trng::MfgMode(), (&mut tag).into())
+            .unwrap();
+        op.update(&DATA[..3]).unwrap();
+        op.update(&DATA[3..]).unwrap();
+        op.finalize().unwrap();
+        assert_eq!(
+            op.finalize(),
+            Err(CaliptraError::DRIVER_HMAC384_INVALID_STATE)
+        );
+        drop(op);
+        assert_eq!(tag, Array4x12::from(TAG));
+    }
+
+    #[test]
+    fn test_hmac_key_usage() {
+        let kv = KeyVaultSw::new();
+        let mut hmac = Hmac384Sw::new(kv.clone());
+        let mut trng = Trng::MfgMode();
+
+        hmac.hmac(
+            &(&Array4x12::from(KEY)).into(),
+            &(&DATA).into(),
+            &mut trng,
+            KeyWriteArgs::new(KeyId::KeyId0, KeyUsage::default().set_hmac_data_en()).into(),
+        )
+        .unwrap();
+        assert_eq!(
+            kv.key_usage(KeyId::KeyId0),
+            Some(KeyUsage::default().set_hmac_data_en())
+        );
+
+        let mut tag = Array4x12::default();
+        assert_eq!(
+            hmac.hmac(
+                &KeyReadArgs::new(KeyId::KeyId0).into(),
+                &(&DATA).into(),
+                &mut trng,
+                (&mut tag).into(),
+            ),
+            Err(CaliptraError::DRIVER_HMAC384_READ_KEY_KV_READ)
+        );
+        assert_eq!(
+            hmac.hmac(
+                &KeyReadArgs::new(KeyId::KeyId1).into(),
+                &(&DATA).into(),
+                &mut trng,
+                (&mut tag).into(),
+            ),
+            Err(CaliptraError::DRIVER_HMAC384_READ_KEY_KV_READ)
+        );
+    }
+
+    #[test]
+    fn test_hmac384_kdf() {
+        let kv = KeyVaultSw::new();
+        let mut hmac = Hmac384Sw::new(kv.clone());
+        let mut trng = Trng::MfgMode();
+        let label = b"label";
+        let context = b"context";
+
+        hmac384_kdf_alg(
+            &mut hmac,
+            (&Array4x12::from(KEY)).into(),
+            label,
+            Some(context),
+            &mut trng,
+            KeyWriteArgs::new(KeyId::KeyId1, KeyUsage::default().set_hmac_data_en()).into(),
+        )
+        .unwrap();
+
+        // The KDF output is HMAC(key, 1 || label || 0x00 || context)
+        let mut fixed_input = vec![];
+        fixed_input.extend_from_slice(&1_u32.to_be_bytes());
+        fixed_input.extend_from_slice(label);
+        fixed_input.push(0x00);
+        fixed_input.extend_from_slice(context);
+        let mut expected = Array4x12::default();
+        hmac.hmac(
+            &(&Array4x12::from(KEY)).into(),
+            &fixed_input.as_slice().into(),
+            &mut trng,
+            (&mut expected).into(),
+        )
+        .unwrap();
+
+        let actual = kv
+            .read(
+                KeyId::KeyId1,
+                |usage| usage.hmac_data(),
+                CaliptraError::DRIVER_HMAC384_READ_DATA_KV_READ,
+            )
+            .unwrap();
+        assert_eq!(actual, expected);
+    }
+}
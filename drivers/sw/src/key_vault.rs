@@ -0,0 +1,136 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    key_vault.rs
+
+Abstract:
+
+    File contains an in-memory key vault shared by the software drivers.
+
+--*/
+
+use caliptra_drivers::{Array4x12, CaliptraError, CaliptraResult, KeyId, KeyUsage};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const KEY_COUNT: usize = 32;
+
+#[derive(Clone, Copy)]
+struct KeySlot {
+    key: Array4x12,
+    usage: KeyUsage,
+}
+
+/// In-memory key vault
+///
+/// Clones share the same slots, so a single vault can be handed to each
+/// software driver that reads or writes keys.
+#[derive(Clone, Default)]
+pub struct KeyVaultSw {
+    slots: Rc<RefCell<[Option<KeySlot>; KEY_COUNT]>>,
+}
+
+impl KeyVaultSw {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Erase the specified key
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Key ID to erase
+    pub fn erase_key(&mut self, id: KeyId) {
+        self.slots.borrow_mut()[usize::from(id)] = None;
+    }
+
+    /// Retrieve the usage flags of the specified key, if the slot is written
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Key ID
+    pub fn key_usage(&self, id: KeyId) -> Option<KeyUsage> {
+        self.slots.borrow()[usize::from(id)].map(|slot| slot.usage)
+    }
+
+    /// Write a key to the vault
+    pub(crate) fn write(&self, id: KeyId, key: Array4x12, usage: KeyUsage) {
+        self.slots.borrow_mut()[usize::from(id)] = Some(KeySlot { key, usage });
+    }
+
+    /// Read a key from the vault
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Key ID
+    /// * `allowed` - Predicate over the key's usage flags
+    /// * `err` - Error to return if the slot is empty or the usage is not allowed
+    pub(crate) fn read(
+        &self,
+        id: KeyId,
+        allowed: impl Fn(&KeyUsage) -> bool,
+        err: CaliptraError,
+    ) -> CaliptraResult<Array4x12> {
+        match self.slots.borrow()[usize::from(id)] {
+            Some(slot) if allowed(&slot.usage) => Ok(slot.key),
+            _ => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ERR: CaliptraError = CaliptraError::DRIVER_HMAC384_READ_KEY_KV_READ;
+
+    #[test]
+    fn test_write_read() {
+        let kv = KeyVaultSw::new();
+        let key = Array4x12::from([0x11; 48]);
+        let usage = KeyUsage::default().set_hmac_key_en();
+
+        assert_eq!(kv.key_usage(KeyId::KeyId5), None);
+        assert_eq!(kv.read(KeyId::KeyId5, |_| true, ERR), Err(ERR));
+
+        kv.write(KeyId::KeyId5, key, usage);
+        assert_eq!(kv.key_usage(KeyId::KeyId5), Some(usage));
+        assert_eq!(kv.read(KeyId::KeyId5, |u| u.hmac_key(), ERR), Ok(key));
+
+        // A key cannot be read for a usage it was not written with
+        assert_eq!(kv.read(KeyId::KeyId5, |u| u.hmac_data(), ERR), Err(ERR));
+        assert_eq!(kv.read(KeyId::KeyId6, |_| true, ERR), Err(ERR));
+    }
+
+    #[test]
+    fn test_erase_key() {
+        let mut kv = KeyVaultSw::new();
+        let usage = KeyUsage::default().set_ecc_private_key_en();
+        kv.write(KeyId::KeyId0, Array4x12::from([0x22; 48]), usage);
+        kv.write(KeyId::KeyId1, Array4x12::from([0x33; 48]), usage);
+
+        kv.erase_key(KeyId::KeyId0);
+        assert_eq!(kv.key_usage(KeyId::KeyId0), None);
+        assert_eq!(kv.key_usage(KeyId::KeyId1), Some(usage));
+    }
+
+    #[test]
+    fn test_clones_share_slots() {
+        let kv = KeyVaultSw::new();
+        let mut other = kv.clone();
+        let key = Array4x12::from([0x44; 48]);
+        let usage = KeyUsage::default().set_ecc_key_gen_seed_en();
+
+        other.write(KeyId::KeyId7, key, usage);
+        assert_eq!(
+            kv.read(KeyId::KeyId7, |u| u.ecc_key_gen_seed(), ERR),
+            Ok(key)
+        );
+
+        other.erase_key(KeyId::KeyId7);
+        assert_eq!(kv.key_usage(KeyId::KeyId7), None);
+    }
+}
@@ -0,0 +1,26 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    lib.rs
+
+Abstract:
+
+    Software implementations of the Caliptra crypto driver traits, used to
+    run ROM, FMC and runtime flow logic natively on the host.
+
+--*/
+
+mod ecc384;
+mod hmac384;
+mod key_vault;
+mod sha256;
+mod sha384;
+
+pub use ecc384::Ecc384Sw;
+pub use hmac384::{Hmac384OpSw, Hmac384Sw};
+pub use key_vault::KeyVaultSw;
+pub use sha256::{Sha256DigestOpSw, Sha256Sw};
+pub use sha384::{Sha384DigestOpSw, Sha384Sw};
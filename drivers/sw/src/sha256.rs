@@ -0,0 +1,99 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    sha256.rs
+
+Abstract:
+
+    File contains a software implementation of the SHA-256 driver traits.
+
+--*/
+
+use caliptra_drivers::{Array4x8, CaliptraResult, Sha256Alg, Sha256DigestOp};
+use sha2::Digest;
+
+#[derive(Default)]
+pub struct Sha256Sw {}
+
+impl Sha256Sw {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+pub struct Sha256DigestOpSw {
+    digest: sha2::Sha256,
+}
+
+impl<'a> Sha256DigestOp<'a> for Sha256DigestOpSw {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        self.digest.update(data);
+        Ok(())
+    }
+
+    fn finalize(self, digest: &mut Array4x8) -> CaliptraResult<()> {
+        let result = self.digest.finalize();
+        *digest = Array4x8::from(<[u8; 32]>::try_from(result.as_slice()).unwrap());
+        Ok(())
+    }
+}
+
+impl Sha256Alg for Sha256Sw {
+    type DigestOp<'a> = Sha256DigestOpSw;
+
+    fn digest_init(&mut self) -> CaliptraResult<Sha256DigestOpSw> {
+        Ok(Sha256DigestOpSw {
+            digest: sha2::Sha256::new(),
+        })
+    }
+
+    fn digest(&mut self, buf: &[u8]) -> CaliptraResult<Array4x8> {
+        let result = sha2::Sha256::digest(buf);
+        Ok(Array4x8::from(
+            <[u8; 32]>::try_from(result.as_slice()).unwrap(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // FIPS 180-2 Appendix B.1 and B.2
+    const EXPECTED_ABC: [u8; 32] = [
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+    const TWO_BLOCK: &[u8] = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+    const EXPECTED_TWO_BLOCK: [u8; 32] = [
+        0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e, 0x60,
+        0x39, 0xa3, 0x3c, 0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4, 0x19, 0xdb,
+        0x06, 0xc1,
+    ];
+
+    #[test]
+    fn test_digest() {
+        let mut sha = Sha256Sw::new();
+        assert_eq!(sha.digest(b"abc").unwrap(), Array4x8::from(EXPECTED_ABC));
+        assert_eq!(
+            sha.digest(TWO_BLOCK).unwrap(),
+            Array4x8::from(EXPECTED_TWO_BLOCK)
+        );
+    }
+
+    #[test]
+    fn test_digest_multi_step() {
+        let mut sha = Sha256Sw::new();
+        let mut op = sha.digest_init().unwrap();
+        for chunk in TWO_BLOCK.chunks(7) {
+            op.update(chunk).unwrap();
+        }
+        let mut digest = Array4x8::default();
+        op.finalize(&mut digest).unwrap();
+        assert_eq!(digest, Array4x8::from(EXPECTED_TWO_BLOCK));
+    }
+}
@@ -0,0 +1,88 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    sha384.rs
+
+Abstract:
+
+    File contains a software implementation of the SHA-384 driver traits.
+
+--*/
+
+use caliptra_drivers::{Array4x12, CaliptraResult, Sha384Alg, Sha384DigestOpAlg};
+use sha2::Digest;
+
+#[derive(Default)]
+pub struct Sha384Sw {}
+
+impl Sha384Sw {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+pub struct Sha384DigestOpSw {
+    digest: sha2::Sha384,
+}
+
+impl<'a> Sha384DigestOpAlg<'a> for Sha384DigestOpSw {
+    fn update(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        self.digest.update(data);
+        Ok(())
+    }
+
+    fn finalize(self, digest: &mut Array4x12) -> CaliptraResult<()> {
+        let result = self.digest.finalize();
+        *digest = Array4x12::from(<[u8; 48]>::try_from(result.as_slice()).unwrap());
+        Ok(())
+    }
+}
+
+impl Sha384Alg for Sha384Sw {
+    type DigestOp<'a> = Sha384DigestOpSw;
+
+    fn digest_init(&mut self) -> CaliptraResult<Sha384DigestOpSw> {
+        Ok(Sha384DigestOpSw {
+            digest: sha2::Sha384::new(),
+        })
+    }
+
+    fn digest(&mut self, buf: &[u8]) -> CaliptraResult<Array4x12> {
+        let result = sha2::Sha384::digest(buf);
+        Ok(Array4x12::from(
+            <[u8; 48]>::try_from(result.as_slice()).unwrap(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED_ABC: [u8; 48] = [
+        0xcb, 0x00, 0x75, 0x3f, 0x45, 0xa3, 0x5e, 0x8b, 0xb5, 0xa0, 0x3d, 0x69, 0x9a, 0xc6, 0x50,
+        0x07, 0x27, 0x2c, 0x32, 0xab, 0x0e, 0xde, 0xd1, 0x63, 0x1a, 0x8b, 0x60, 0x5a, 0x43, 0xff,
+        0x5b, 0xed, 0x80, 0x86, 0x07, 0x2b, 0xa1, 0xe7, 0xcc, 0x23, 0x58, 0xba, 0xec, 0xa1, 0x34,
+        0xc8, 0x25, 0xa7,
+    ];
+
+    #[test]
+    fn test_digest() {
+        let mut sha = Sha384Sw::new();
+        assert_eq!(sha.digest(b"abc").unwrap(), Array4x12::from(EXPECTED_ABC));
+    }
+
+    #[test]
+    fn test_digest_multi_step() {
+        let mut sha = Sha384Sw::new();
+        let mut op = sha.digest_init().unwrap();
+        op.update(b"a").unwrap();
+        op.update(b"bc").unwrap();
+        let mut digest = Array4x12::default();
+        op.finalize(&mut digest).unwrap();
+        assert_eq!(digest, Array4x12::from(EXPECTED_ABC));
+    }
+}
@@ -0,0 +1,1048 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    mldsa87_tests.rs
+
+Abstract:
+
+    File contains test cases for ML-DSA-87 signature verification.
+
+--*/
+
+#![no_std]
+#![no_main]
+
+use caliptra_drivers::{Mldsa87, Mldsa87Result};
+use caliptra_test_harness::test_suite;
+
+// Known answer vectors. Vector 1 was generated with OpenSSL 3.5 from the
+// seed 0x40..=0x5f (`-pkeyopt hexseed:`); vector 2 is sigVer tgId 1 of
+// image/openssl/testdata/mldsa87_kat.toml. Both sign a 48-byte message
+// deterministically with an empty context string.
+
+const PUB_KEY_1: [u8; 2592] = [
+    0xcf, 0xa8, 0x45, 0x57, 0x8d, 0xd5, 0x35, 0x33, 0xdb, 0xaa, 0xfe, 0xb7, 0xe8, 0xe5, 0xdd, 0x91,
+    0x40, 0xeb, 0x93, 0x35, 0xf1, 0xbe, 0xa9, 0x72, 0xf6, 0x36, 0x92, 0x9d, 0xb7, 0x88, 0x2a, 0x63,
+    0xd8, 0x26, 0x5c, 0x01, 0x89, 0x35, 0xcf, 0x68, 0xdf, 0x99, 0xd4, 0xf7, 0xf1, 0xce, 0x2d, 0x3d,
+    0x33, 0xd8, 0x42, 0xd7, 0x43, 0x44, 0xa5, 0x72, 0xe6, 0xd5, 0x4e, 0xda, 0x0e, 0x9a, 0xa5, 0xa8,
+    0x98, 0x43, 0x8e, 0x15, 0xef, 0x30, 0x0f, 0x81, 0x34, 0x9b, 0x46, 0xd9, 0x23, 0x54, 0x92, 0x3f,
+    0xbb, 0xc0, 0xc4, 0xc2, 0x02, 0x49, 0xf9, 0xb2, 0xba, 0x2d, 0x06, 0xf7, 0x0a, 0x8e, 0x61, 0xed,
+    0x3c, 0x77, 0xf2, 0x8c, 0x26, 0xb7, 0x16, 0x71, 0x87, 0x76, 0xa3, 0xeb, 0x23, 0x3c, 0x12, 0x31,
+    0x4b, 0xb6, 0xb9, 0x4b, 0x8a, 0x0f, 0x9c, 0x40, 0xdb, 0x28, 0xff, 0x8c, 0xb5, 0x73, 0xce, 0x4d,
+    0x49, 0x2a, 0xcf, 0x98, 0x15, 0x95, 0x4d, 0x06, 0x55, 0x7d, 0x20, 0x15, 0xbb, 0x17, 0x55, 0x33,
+    0xe2, 0x5a, 0x38, 0x5f, 0xc7, 0x07, 0xb8, 0xc6, 0xce, 0x1c, 0x83, 0xee, 0xed, 0xe1, 0x29, 0x91,
+    0xe4, 0xaf, 0xfd, 0x93, 0xaa, 0xc2, 0xef, 0x12, 0x58, 0x9a, 0x27, 0xe3, 0xbb, 0x3e, 0x00, 0x18,
+    0x4e, 0x15, 0x55, 0x90, 0x24, 0x59, 0xed, 0x2d, 0x14, 0x62, 0xf9, 0x39, 0xee, 0x4a, 0x67, 0x2f,
+    0xe0, 0xc0, 0xf5, 0x64, 0x5b, 0x69, 0xcd, 0xff, 0x19, 0xed, 0xd9, 0xb1, 0xa8, 0x26, 0x05, 0xcc,
+    0x93, 0x61, 0x5b, 0x39, 0x6a, 0x96, 0x53, 0x98, 0xdb, 0x41, 0x65, 0x0c, 0x0f, 0xaf, 0xcd, 0xde,
+    0xbf, 0xd7, 0x5b, 0x2c, 0xad, 0x64, 0x43, 0xca, 0xa1, 0xdc, 0x73, 0x56, 0xcc, 0x76, 0x43, 0xd0,
+    0xc1, 0x09, 0x99, 0xc7, 0xcd, 0x0f, 0x92, 0xbf, 0x60, 0x9d, 0x26, 0xf2, 0x5e, 0xb8, 0x10, 0xfa,
+    0x38, 0x96, 0x8e, 0xc4, 0xa7, 0x39, 0x59, 0xa5, 0x44, 0xd6, 0x7e, 0x5f, 0x67, 0xa9, 0xd7, 0x62,
+    0xa1, 0x3e, 0xa1, 0xc3, 0xf8, 0x13, 0xd7, 0xb4, 0xf0, 0x92, 0x10, 0xe1, 0xaf, 0x7f, 0x12, 0x15,
+    0xb8, 0xf2, 0x97, 0xd7, 0xeb, 0x5f, 0x8c, 0x66, 0x47, 0xee, 0x27, 0x40, 0xab, 0xd4, 0x22, 0x62,
+    0x65, 0xa3, 0x9f, 0x60, 0xfe, 0x3a, 0xab, 0xaa, 0x94, 0x9f, 0xa0, 0xf1, 0x54, 0xbe, 0x5d, 0x7f,
+    0x6b, 0xea, 0x4c, 0x50, 0xa9, 0x13, 0x54, 0x7f, 0x1e, 0x20, 0x49, 0xea, 0xd8, 0x39, 0x75, 0x93,
+    0x78, 0xa7, 0x1d, 0xf9, 0xd7, 0x06, 0x08, 0x36, 0x01, 0x74, 0xea, 0x98, 0x77, 0x27, 0xb3, 0x9a,
+    0xba, 0xf5, 0x9a, 0xd4, 0xcb, 0xba, 0xf8, 0x2b, 0xcc, 0xaa, 0x91, 0xa7, 0x24, 0x42, 0xeb, 0xfa,
+    0xc4, 0xe5, 0x0b, 0x66, 0xc8, 0xe3, 0xc4, 0xda, 0x0d, 0xb2, 0xca, 0xbc, 0x62, 0x21, 0x44, 0x5a,
+    0x2e, 0xc4, 0xbd, 0x11, 0xf2, 0xf2, 0x9c, 0x62, 0xb6, 0xe2, 0x3b, 0xe5, 0xa7, 0xbd, 0x32, 0xda,
+    0x22, 0xa7, 0xdb, 0x0b, 0x74, 0x91, 0xc1, 0x02, 0x1d, 0x80, 0xe3, 0x9d, 0xeb, 0x0d, 0x09, 0x1b,
+    0xb3, 0xad, 0xc5, 0xc4, 0x07, 0x6b, 0x03, 0x94, 0x12, 0x16, 0xa6, 0x32, 0xf7, 0x30, 0x55, 0x8f,
+    0x1e, 0x0e, 0x72, 0x44, 0x36, 0x12, 0x1b, 0x08, 0xa5, 0xbd, 0xa4, 0xcb, 0x34, 0xf7, 0xa9, 0x0e,
+    0xd8, 0x1d, 0x32, 0xd8, 0x77, 0x76, 0xfd, 0x6e, 0x27, 0x10, 0x5f, 0x95, 0x52, 0xf8, 0x52, 0x43,
+    0xfa, 0x49, 0x70, 0x1a, 0x6b, 0x0b, 0x2f, 0xe3, 0x95, 0x94, 0x56, 0xb5, 0x5b, 0x48, 0x56, 0x81,
+    0x0d, 0x79, 0xd4, 0x9e, 0x0c, 0x64, 0x47, 0xdc, 0xd6, 0xd4, 0xc3, 0xfd, 0x32, 0x9f, 0xed, 0x95,
+    0x42, 0x49, 0x23, 0x99, 0x39, 0x7a, 0x3b, 0xed, 0x81, 0xc1, 0xda, 0xde, 0x8e, 0xe3, 0xc8, 0x9e,
+    0x53, 0x94, 0x45, 0x43, 0x32, 0x15, 0xde, 0xbe, 0x98, 0xa9, 0x33, 0x89, 0x57, 0xd4, 0x10, 0xd5,
+    0x17, 0x7c, 0x52, 0x9e, 0x55, 0x05, 0xa9, 0x2e, 0xb3, 0x49, 0xc5, 0x73, 0x6c, 0xd2, 0x8f, 0x59,
+    0xe7, 0xc9, 0x6c, 0x03, 0x20, 0x87, 0x41, 0x8f, 0x84, 0xf4, 0x56, 0x79, 0x80, 0x57, 0xea, 0xcb,
+    0xc1, 0xbb, 0x31, 0x4c, 0x0b, 0x76, 0xb3, 0x21, 0xd8, 0x6b, 0xa9, 0x15, 0x17, 0x5c, 0xac, 0x5d,
+    0x83, 0x29, 0xc2, 0xb8, 0x8f, 0xdd, 0xd0, 0x8f, 0xbe, 0x5f, 0x0e, 0xe6, 0xa0, 0x86, 0xb8, 0x64,
+    0xbb, 0xb8, 0x9e, 0x71, 0x60, 0xf0, 0xcf, 0x46, 0x85, 0xf5, 0xfc, 0x23, 0x2c, 0x9a, 0xb5, 0xdc,
+    0xf8, 0x2d, 0x11, 0x36, 0xd4, 0xf5, 0xeb, 0xf5, 0x97, 0x8b, 0xcf, 0x2d, 0xee, 0xbd, 0xbc, 0x9c,
+    0x2c, 0x6d, 0xa8, 0x55, 0xce, 0xd6, 0xbf, 0xad, 0x81, 0x84, 0xc1, 0x9c, 0x1b, 0xa5, 0x5e, 0x0a,
+    0xe5, 0x90, 0x45, 0x3f, 0xa9, 0xa8, 0x38, 0xd6, 0x2a, 0x9d, 0xb5, 0xe4, 0x15, 0x2e, 0xaa, 0xed,
+    0x44, 0x6c, 0x20, 0x87, 0x83, 0xe3, 0xea, 0xf6, 0x83, 0x7a, 0xd9, 0x73, 0x54, 0x4f, 0x51, 0xf0,
+    0x24, 0x4f, 0xdf, 0x18, 0xee, 0x57, 0x31, 0xfa, 0x42, 0xd6, 0xf9, 0x63, 0xd2, 0xf3, 0xba, 0xe8,
+    0x2d, 0xa3, 0x9f, 0x02, 0x76, 0x8b, 0xd9, 0x2f, 0x13, 0xf2, 0x01, 0xe1, 0x0d, 0x63, 0x63, 0xc6,
+    0x32, 0xb0, 0x6d, 0xdd, 0x44, 0x49, 0xd2, 0x28, 0x7a, 0x6e, 0x6e, 0x80, 0x4b, 0xae, 0xc3, 0xb7,
+    0x9c, 0xd6, 0xdc, 0x0a, 0x79, 0xe3, 0x03, 0xc3, 0xe1, 0xf9, 0xfa, 0xa1, 0x47, 0x84, 0x5f, 0x12,
+    0xb5, 0xd7, 0xf0, 0x15, 0x30, 0xa5, 0x9a, 0x2f, 0x80, 0xa5, 0x96, 0x26, 0x9f, 0x36, 0x55, 0xe5,
+    0x42, 0x24, 0x2d, 0xa6, 0x5a, 0xa8, 0x31, 0xfc, 0xf4, 0x9c, 0x13, 0x36, 0xce, 0x3d, 0xc7, 0x66,
+    0xe3, 0x0f, 0xf9, 0x6e, 0x76, 0x71, 0xf8, 0x11, 0xdc, 0x99, 0x2f, 0xd9, 0xdb, 0x69, 0x56, 0x51,
+    0x82, 0xaa, 0x35, 0x3a, 0x25, 0xc1, 0x47, 0x82, 0x27, 0x2e, 0x63, 0xcd, 0xe2, 0xed, 0x89, 0x63,
+    0x26, 0x1b, 0x99, 0x2b, 0x3f, 0xc3, 0x9c, 0x1a, 0x17, 0xeb, 0xbf, 0x4d, 0xe0, 0x63, 0xe0, 0x7b,
+    0x08, 0x39, 0xfd, 0x59, 0xf9, 0x9d, 0xeb, 0x7c, 0xbc, 0x02, 0xe2, 0x5d, 0xcc, 0x18, 0xa4, 0x31,
+    0xcc, 0xba, 0xbe, 0xf4, 0x85, 0x38, 0xf8, 0xd9, 0xd9, 0x62, 0x81, 0x65, 0x49, 0xb7, 0x49, 0x37,
+    0x7d, 0x87, 0x1d, 0xf7, 0xcf, 0xf2, 0x9b, 0x5e, 0x20, 0x0d, 0x8a, 0x55, 0x3d, 0xf6, 0xa6, 0x69,
+    0x08, 0xa0, 0x1e, 0xf8, 0x7d, 0x4b, 0xe7, 0x81, 0x80, 0x84, 0xaf, 0x45, 0xab, 0xfa, 0x05, 0x1c,
+    0x40, 0x77, 0x95, 0xa8, 0x05, 0xd7, 0x5e, 0x8b, 0x05, 0xd2, 0xfb, 0x6f, 0x57, 0x51, 0xd7, 0xe7,
+    0xba, 0x4f, 0x89, 0x99, 0xce, 0x23, 0xc6, 0x11, 0x56, 0x0d, 0x22, 0x88, 0x10, 0x5f, 0x55, 0x8c,
+    0xa6, 0x84, 0x96, 0xd7, 0xe0, 0xbf, 0x6c, 0xa9, 0xe5, 0x01, 0x6e, 0x14, 0xc4, 0x88, 0xda, 0x07,
+    0xd9, 0xa3, 0xa7, 0x3c, 0x01, 0xa9, 0x62, 0x98, 0xa1, 0x73, 0x82, 0x75, 0x1d, 0xaf, 0x8f, 0x17,
+    0xfd, 0x8d, 0xd4, 0x95, 0x08, 0x7c, 0x9e, 0xc4, 0x27, 0xbb, 0x97, 0x31, 0x93, 0xba, 0x54, 0x74,
+    0xaa, 0x3a, 0xf4, 0xb0, 0x0e, 0x8b, 0x50, 0x52, 0xd4, 0x75, 0xeb, 0x01, 0x7a, 0xa2, 0x5d, 0x2a,
+    0xc1, 0xc5, 0xba, 0xf8, 0x80, 0x0a, 0x27, 0xc1, 0x4d, 0x2a, 0xa4, 0xe8, 0x57, 0xd1, 0x97, 0xdf,
+    0x40, 0x57, 0x03, 0x83, 0xb6, 0x39, 0x16, 0x8e, 0xb0, 0x98, 0x19, 0x1e, 0x1f, 0x7c, 0xde, 0x40,
+    0x4d, 0x27, 0x09, 0x87, 0x96, 0xfa, 0xf7, 0x77, 0x9e, 0x69, 0x51, 0xed, 0xf8, 0x99, 0x92, 0xd0,
+    0x5b, 0x25, 0x2f, 0xd5, 0x67, 0xe3, 0x1e, 0x17, 0xdd, 0x12, 0xa7, 0x0e, 0x6c, 0x8c, 0xb7, 0x77,
+    0x17, 0x43, 0xf6, 0x8f, 0x08, 0x63, 0xe7, 0x3b, 0x4d, 0x2a, 0xab, 0x4b, 0x1e, 0xe7, 0xbc, 0x62,
+    0xe3, 0x7c, 0xa3, 0x6b, 0x1f, 0x7e, 0x57, 0x74, 0x30, 0x62, 0xed, 0x05, 0x64, 0x58, 0xb7, 0xa6,
+    0x68, 0x2f, 0x5f, 0x56, 0xc9, 0x56, 0x00, 0x37, 0xec, 0xa2, 0x51, 0x99, 0x61, 0x67, 0x9b, 0xe1,
+    0x43, 0xd9, 0xb2, 0xea, 0x52, 0xe9, 0x1c, 0x54, 0x76, 0x46, 0x58, 0x36, 0x95, 0xd5, 0xa2, 0x66,
+    0x63, 0xe0, 0xda, 0xb7, 0x02, 0x2f, 0x75, 0x76, 0x00, 0x63, 0xef, 0x75, 0x54, 0xd1, 0xfd, 0x7c,
+    0x7a, 0x34, 0x74, 0x6e, 0xba, 0xa9, 0x27, 0xd6, 0xd2, 0xe3, 0x9f, 0x0e, 0x68, 0x9c, 0x60, 0x01,
+    0xe3, 0xfc, 0x0a, 0xda, 0xd6, 0x62, 0xab, 0xcf, 0xcb, 0x7d, 0x97, 0xad, 0xc3, 0x72, 0x88, 0x62,
+    0x43, 0x2c, 0xfb, 0xcc, 0x3b, 0x2f, 0x6a, 0x5b, 0x86, 0x81, 0x73, 0x4f, 0x21, 0x14, 0x8a, 0xf2,
+    0xa1, 0xdc, 0x96, 0xb3, 0x2e, 0x50, 0xbd, 0xd0, 0x7a, 0x2f, 0x1c, 0x4f, 0x2c, 0xcd, 0x8a, 0xe3,
+    0x31, 0x87, 0xc8, 0xd4, 0xea, 0x38, 0xf8, 0x54, 0x39, 0xc4, 0xbe, 0xac, 0x23, 0x89, 0x76, 0x21,
+    0xd0, 0xb0, 0xa3, 0x4c, 0xb4, 0x87, 0x5c, 0x59, 0x6f, 0x35, 0xab, 0xfc, 0x83, 0xfb, 0x56, 0x5e,
+    0x5a, 0xf7, 0xe2, 0x53, 0x78, 0xcf, 0x63, 0xd8, 0x57, 0xd8, 0x88, 0x28, 0x79, 0x71, 0xf4, 0x01,
+    0x2c, 0x63, 0x74, 0xec, 0x73, 0x9e, 0x65, 0x04, 0x51, 0xba, 0xd6, 0xf9, 0xb5, 0x09, 0xee, 0xae,
+    0xf0, 0xe6, 0x9f, 0xa9, 0x25, 0xe8, 0x8d, 0x6e, 0x78, 0xde, 0x4d, 0xa7, 0xb2, 0xba, 0xba, 0x2b,
+    0x24, 0x39, 0x3f, 0xfb, 0xfb, 0x26, 0x83, 0xa5, 0x29, 0x3e, 0x2c, 0x9c, 0xe8, 0x42, 0xd5, 0x6c,
+    0x1d, 0x90, 0x0b, 0xfc, 0xa4, 0xc6, 0xb2, 0x68, 0xdf, 0x86, 0x6e, 0x9f, 0x31, 0xcb, 0x99, 0xa3,
+    0x39, 0xeb, 0x0a, 0xdc, 0xea, 0xab, 0x43, 0xdf, 0xc3, 0x54, 0x63, 0xd0, 0x26, 0xa3, 0x8e, 0xe5,
+    0xda, 0xe5, 0xcc, 0x07, 0x3a, 0x81, 0xcd, 0xf2, 0x85, 0x21, 0xaf, 0x0f, 0x7f, 0x3e, 0x86, 0x46,
+    0x9a, 0x3c, 0x8b, 0x0a, 0x5a, 0x07, 0x4c, 0x2c, 0x88, 0x5e, 0x2e, 0x57, 0x7f, 0x13, 0xf7, 0x0b,
+    0x8c, 0x45, 0x54, 0x36, 0xf1, 0xe8, 0x22, 0x67, 0x72, 0x92, 0x5c, 0x7d, 0x14, 0x99, 0x7e, 0x04,
+    0xb6, 0x96, 0x00, 0x3d, 0x52, 0x31, 0x55, 0x40, 0xf8, 0x6e, 0x4a, 0xc8, 0xf6, 0x2f, 0xdc, 0x04,
+    0x33, 0x0b, 0x9d, 0xc9, 0x4a, 0x85, 0x60, 0x6c, 0xb8, 0xe7, 0xa2, 0x7d, 0xa5, 0x27, 0x82, 0xbc,
+    0x5c, 0x11, 0xf2, 0x06, 0xaa, 0x93, 0x22, 0x88, 0x51, 0xf2, 0x83, 0x4d, 0xc4, 0x3f, 0xdc, 0x48,
+    0x3a, 0xef, 0x8e, 0xd8, 0xbb, 0x8b, 0xe7, 0x69, 0x9e, 0xe1, 0x22, 0xcc, 0x02, 0x42, 0x42, 0x41,
+    0x79, 0x10, 0x92, 0xaf, 0xe3, 0x41, 0xa6, 0x16, 0x14, 0x13, 0x85, 0x25, 0x8c, 0xc9, 0xd9, 0x57,
+    0x0c, 0x47, 0x3f, 0x1a, 0x0e, 0x17, 0x9b, 0x78, 0xcc, 0xe3, 0xd7, 0x3c, 0x9f, 0x39, 0xda, 0xf9,
+    0xd2, 0xa8, 0x6f, 0xb9, 0xf8, 0xc8, 0x1b, 0x73, 0x38, 0xd4, 0x2b, 0x98, 0xc0, 0x97, 0xd1, 0xb7,
+    0x74, 0xb6, 0xd8, 0xad, 0xca, 0xf0, 0xd4, 0x66, 0x2d, 0x9e, 0x30, 0xed, 0x63, 0x83, 0xc6, 0xc5,
+    0xa0, 0x9e, 0x8d, 0x68, 0x6a, 0xf2, 0x9d, 0x2a, 0x36, 0x63, 0xc8, 0xe5, 0x4c, 0x45, 0x2e, 0x8a,
+    0x74, 0xda, 0xdf, 0xc9, 0x38, 0x7a, 0x00, 0xee, 0x49, 0xde, 0xad, 0xe8, 0x6a, 0x52, 0x13, 0xec,
+    0x8c, 0xb2, 0x0f, 0x85, 0x98, 0x81, 0xc4, 0xc6, 0x72, 0x14, 0xb0, 0x2d, 0xad, 0x0a, 0x1d, 0x6d,
+    0x72, 0x5c, 0xec, 0xeb, 0x4a, 0x45, 0x8c, 0xd1, 0x48, 0x6e, 0x07, 0xc8, 0x75, 0x77, 0x4a, 0x70,
+    0x16, 0x24, 0x79, 0xca, 0x07, 0xfa, 0xa6, 0x70, 0xd0, 0x5d, 0xef, 0x84, 0xb1, 0x30, 0x93, 0x74,
+    0xbd, 0x48, 0x40, 0x85, 0xf6, 0xb7, 0xe6, 0xef, 0x95, 0x27, 0x73, 0xf6, 0x37, 0xd7, 0xf7, 0x75,
+    0x2a, 0xfa, 0xb3, 0xe3, 0x85, 0x6f, 0x12, 0x94, 0x41, 0x32, 0xd4, 0x45, 0x72, 0xad, 0x0a, 0xa3,
+    0x96, 0x30, 0x3a, 0x06, 0xde, 0x66, 0x2a, 0xf3, 0x38, 0xac, 0x78, 0xc8, 0xae, 0xf8, 0xf0, 0x80,
+    0xb0, 0x84, 0x3c, 0x68, 0x1a, 0xc7, 0xd0, 0x23, 0x36, 0x41, 0xc5, 0xa6, 0xef, 0x82, 0x44, 0xb4,
+    0x19, 0xa1, 0xc3, 0x14, 0x89, 0xa3, 0x6f, 0x4c, 0x0d, 0xcc, 0xcb, 0x05, 0xbd, 0x8f, 0x0f, 0xbf,
+    0xf2, 0x20, 0x0e, 0x17, 0x7a, 0xe9, 0xf1, 0xd3, 0x69, 0x93, 0x73, 0x43, 0x13, 0x15, 0xb4, 0x1c,
+    0x4c, 0x41, 0x58, 0xec, 0x60, 0x87, 0xfb, 0x8b, 0x37, 0x50, 0x58, 0x1a, 0xf4, 0xf8, 0x98, 0xb0,
+    0x1e, 0x7d, 0xbb, 0x60, 0x43, 0x8a, 0xf4, 0x03, 0x3a, 0xef, 0x52, 0xb4, 0x96, 0xcb, 0xfd, 0xc2,
+    0x0b, 0x31, 0x8d, 0xfe, 0x98, 0xab, 0x87, 0xf3, 0xf0, 0x19, 0x17, 0x50, 0xa0, 0xd6, 0x9d, 0xf7,
+    0x66, 0x20, 0x6d, 0x71, 0x8e, 0x94, 0xbc, 0xb1, 0x36, 0xfe, 0xcc, 0x61, 0xd6, 0x80, 0x7f, 0x1e,
+    0x75, 0x61, 0xcd, 0xae, 0x2c, 0x19, 0xaf, 0xfc, 0xad, 0xcd, 0x2b, 0x84, 0xb1, 0xad, 0xbb, 0x56,
+    0x02, 0x61, 0xfd, 0xc6, 0x73, 0xae, 0xe6, 0x98, 0x42, 0x93, 0xc3, 0x1a, 0x99, 0x6d, 0xfc, 0x8b,
+    0x80, 0x6d, 0x28, 0x2e, 0x0f, 0xe6, 0x22, 0x34, 0xaa, 0xd4, 0x97, 0xd0, 0xba, 0x2a, 0x1e, 0xf0,
+    0xde, 0x7c, 0x04, 0xe8, 0x18, 0x95, 0xfc, 0x71, 0x7e, 0x1b, 0xd8, 0x1a, 0xa6, 0x1f, 0x41, 0x9c,
+    0x06, 0x9d, 0xbf, 0xf2, 0x0d, 0x0a, 0xcd, 0x31, 0xd8, 0x3b, 0x83, 0x56, 0x4e, 0xfb, 0xd9, 0x14,
+    0xf9, 0x2e, 0x62, 0x87, 0x8c, 0xf6, 0xd0, 0xb2, 0x1c, 0xa1, 0x11, 0x82, 0x71, 0xef, 0xcb, 0xda,
+    0x2d, 0xef, 0x9e, 0x0f, 0xc4, 0x0c, 0x67, 0xb1, 0x9a, 0xfc, 0xf7, 0x7a, 0x0c, 0x9c, 0xc6, 0x57,
+    0xdb, 0xb8, 0x9b, 0xa0, 0x97, 0x54, 0xc0, 0x03, 0x6c, 0xc2, 0xd8, 0x57, 0x17, 0xc1, 0xd0, 0x46,
+    0x52, 0x48, 0x2f, 0x21, 0x48, 0x03, 0x0b, 0x80, 0x59, 0x47, 0x02, 0x33, 0x5e, 0x86, 0x8c, 0x9b,
+    0x7c, 0x26, 0x22, 0x28, 0xe7, 0x7a, 0x12, 0xee, 0x43, 0xa1, 0x7e, 0xdf, 0xac, 0x26, 0xfa, 0xaa,
+    0x29, 0x6c, 0x83, 0x11, 0xb6, 0x0b, 0x03, 0x55, 0x72, 0x34, 0xce, 0x2c, 0x8d, 0xf2, 0xcc, 0x05,
+    0xb4, 0x1f, 0x8c, 0x36, 0x9d, 0x83, 0x08, 0x18, 0x2f, 0x49, 0xfe, 0xe5, 0xcc, 0xeb, 0xd6, 0x35,
+    0x1e, 0x0a, 0xc2, 0xa0, 0x4c, 0x5b, 0xf9, 0xbd, 0x1d, 0x3d, 0x51, 0x48, 0x6c, 0x60, 0xdd, 0x9c,
+    0x66, 0x24, 0xcb, 0x0a, 0xea, 0xf9, 0xcd, 0xeb, 0x8b, 0x56, 0x95, 0x2f, 0xea, 0xd0, 0x0f, 0x48,
+    0xe1, 0xe5, 0xae, 0x52, 0xad, 0x00, 0x2b, 0x19, 0xf4, 0x43, 0xb4, 0xbd, 0xe2, 0x42, 0xda, 0x7d,
+    0xfe, 0x35, 0x41, 0xd5, 0xff, 0x80, 0x1f, 0xd4, 0x85, 0x7c, 0x57, 0xc1, 0xbe, 0xa4, 0x1c, 0x1d,
+    0xcd, 0x5d, 0x8d, 0x1c, 0x6c, 0x66, 0x80, 0x81, 0x9d, 0x2a, 0x77, 0x3d, 0x3c, 0x2a, 0x42, 0xab,
+    0x39, 0xd6, 0xf7, 0xc8, 0xc5, 0x25, 0x44, 0xb5, 0x06, 0x79, 0x83, 0x6a, 0xa0, 0xe9, 0xff, 0x3c,
+    0x19, 0x5b, 0x4c, 0x13, 0xba, 0xff, 0xf9, 0x15, 0x32, 0x11, 0xa2, 0x4d, 0xc9, 0xc8, 0xfb, 0xab,
+    0x06, 0x40, 0x1f, 0x31, 0x4c, 0xdb, 0xd7, 0x41, 0xab, 0x67, 0x9e, 0x93, 0xc0, 0x24, 0x03, 0x83,
+    0x83, 0x87, 0xc0, 0x4a, 0xc5, 0x5e, 0xf0, 0x17, 0xb3, 0x59, 0xe6, 0xb6, 0xf1, 0x99, 0x3f, 0x57,
+    0xea, 0x9c, 0xb5, 0x18, 0x07, 0xa2, 0xb2, 0x88, 0xc5, 0x1c, 0x96, 0x4c, 0x6d, 0x05, 0x33, 0xd3,
+    0xae, 0x69, 0x24, 0x9c, 0x6f, 0xf1, 0x35, 0x91, 0x82, 0xbc, 0xd9, 0xb7, 0x0d, 0x62, 0x4c, 0x51,
+    0xbd, 0x4b, 0x19, 0x3a, 0xbd, 0x0d, 0x7f, 0xf4, 0x51, 0x8f, 0xcf, 0x68, 0xb8, 0x4f, 0xdc, 0x9e,
+    0x72, 0x8e, 0xc1, 0x3e, 0x72, 0x33, 0x5e, 0x6e, 0xe7, 0x82, 0x75, 0x9a, 0xe6, 0x9c, 0xdf, 0xd3,
+    0x1d, 0x9d, 0xb1, 0x8d, 0xcd, 0x30, 0x27, 0x0d, 0xcd, 0x2d, 0x93, 0xaa, 0x70, 0xf9, 0x6d, 0x81,
+    0x31, 0x7e, 0x71, 0x2a, 0x96, 0x94, 0x4d, 0x31, 0x12, 0x1d, 0x98, 0x82, 0x81, 0x23, 0x2e, 0x05,
+    0xc1, 0xce, 0x42, 0xd9, 0x87, 0x5e, 0xcf, 0x59, 0xe2, 0x14, 0x98, 0x3a, 0x31, 0xcf, 0x5e, 0xcd,
+    0xf1, 0x13, 0xa9, 0x58, 0xb4, 0xc5, 0x66, 0x18, 0xb6, 0x23, 0xe3, 0xe8, 0x5c, 0xe0, 0xb2, 0xaa,
+    0xba, 0x3c, 0x2a, 0x4e, 0x1a, 0x98, 0xdb, 0xb5, 0x92, 0xb1, 0xab, 0xa9, 0x74, 0x33, 0xfe, 0xd6,
+    0xd0, 0xcc, 0x5a, 0x6b, 0x95, 0xea, 0x10, 0x44, 0xfa, 0xfd, 0x2d, 0xe1, 0x0c, 0xd4, 0xea, 0x48,
+    0x88, 0x01, 0xb9, 0x81, 0xc9, 0x64, 0x2f, 0x84, 0x92, 0x56, 0xde, 0xb8, 0x61, 0x0d, 0xe1, 0xba,
+    0xfe, 0x09, 0xff, 0xe4, 0xe9, 0x91, 0x4e, 0x06, 0xb0, 0x91, 0x9b, 0xfd, 0x0b, 0x16, 0xbd, 0xce,
+    0xf5, 0x13, 0x38, 0x31, 0x46, 0xd4, 0x89, 0xa4, 0xeb, 0x35, 0x38, 0x65, 0xad, 0x01, 0x1c, 0x37,
+    0x01, 0xf7, 0x26, 0xf7, 0x48, 0xac, 0x55, 0xbb, 0x4b, 0xb8, 0xef, 0x34, 0x83, 0x64, 0x0a, 0x83,
+    0x61, 0x07, 0x69, 0x44, 0xf0, 0xbc, 0xe0, 0xc4, 0x53, 0xc8, 0xe1, 0x3b, 0x6c, 0x99, 0xa3, 0x86,
+    0x75, 0x03, 0x24, 0xca, 0x71, 0x81, 0xf6, 0xe1, 0xf1, 0xe3, 0xa7, 0x45, 0x22, 0x3e, 0x44, 0x6a,
+    0x83, 0xa4, 0x1b, 0xb6, 0xa9, 0xde, 0x8f, 0x72, 0xdd, 0xdc, 0xbd, 0x72, 0x57, 0xcb, 0x0a, 0x7f,
+    0x32, 0xf4, 0x29, 0x25, 0xa7, 0x63, 0x96, 0x65, 0xa7, 0xcb, 0x59, 0x63, 0x82, 0x5f, 0x18, 0x9a,
+    0x67, 0x86, 0x15, 0xc0, 0x8b, 0x43, 0xed, 0x07, 0xb8, 0xef, 0x1c, 0x7d, 0x16, 0xad, 0x5e, 0xef,
+    0xd5, 0x10, 0x97, 0x28, 0x08, 0xc0, 0x7c, 0x48, 0x7e, 0xc2, 0x8a, 0x96, 0xff, 0xcd, 0x16, 0xd4,
+    0x94, 0x59, 0x2b, 0xf1, 0x7c, 0x8b, 0xeb, 0x6e, 0x1a, 0xd7, 0xbf, 0xb0, 0xa2, 0x9c, 0xba, 0x5f,
+    0x5f, 0x98, 0x7a, 0x76, 0x09, 0x87, 0xa3, 0x61, 0x1b, 0x58, 0x9a, 0x5c, 0xa0, 0xb2, 0xe9, 0xfa,
+    0xa8, 0xf1, 0x4a, 0x21, 0x62, 0xce, 0x9a, 0xaf, 0xcb, 0xf5, 0x39, 0xd4, 0x3b, 0xeb, 0x59, 0xf0,
+    0x09, 0x67, 0xe6, 0x70, 0xbd, 0x33, 0xc7, 0xfc, 0xcf, 0x59, 0xeb, 0xac, 0x9e, 0x67, 0x0b, 0xfc,
+    0x79, 0x1a, 0xd7, 0x3a, 0x31, 0xa3, 0xcd, 0xe5, 0xcb, 0x8a, 0xfd, 0x94, 0xa2, 0x03, 0xd5, 0x24,
+    0x5c, 0xf5, 0x27, 0x3d, 0x26, 0xde, 0x81, 0xcd, 0x74, 0xe8, 0x5e, 0xc5, 0xac, 0xeb, 0xb6, 0xf2,
+    0xb0, 0x57, 0x3d, 0xf0, 0x19, 0x3f, 0x1e, 0xce, 0x50, 0x9a, 0x7c, 0x90, 0x35, 0xc9, 0x92, 0x24,
+    0xf1, 0xc4, 0x21, 0x1b, 0x98, 0x4d, 0x75, 0xf1, 0x7d, 0x56, 0xde, 0x86, 0x2e, 0x1d, 0x71, 0x2a,
+    0xd9, 0xe6, 0x73, 0x04, 0x12, 0x48, 0xa3, 0xf8, 0x28, 0xf4, 0x9c, 0x1d, 0xea, 0xcf, 0x3e, 0x47,
+    0x11, 0x48, 0x31, 0x54, 0x15, 0x71, 0x16, 0x26, 0x14, 0x2d, 0x68, 0xe8, 0x7b, 0x84, 0x6a, 0x82,
+    0xb4, 0x66, 0x0b, 0xe7, 0x9b, 0x85, 0xca, 0xa2, 0x5a, 0x34, 0x54, 0x68, 0x94, 0x12, 0x2f, 0x28,
+    0x9d, 0x44, 0x65, 0x41, 0x56, 0xdd, 0xa3, 0xdd, 0x63, 0x2e, 0x2b, 0x6d, 0x27, 0x81, 0xea, 0x82,
+    0x73, 0xf8, 0xc4, 0xf9, 0xf6, 0xa7, 0xe5, 0x09, 0xae, 0x33, 0xda, 0xd0, 0x96, 0xa0, 0xf6, 0x04,
+];
+
+const MESSAGE_1: [u8; 48] = [
+    0xab, 0x1c, 0xd5, 0x20, 0xa2, 0x1d, 0x17, 0xf0, 0x6c, 0x40, 0x4f, 0xba, 0x3e, 0xa8, 0x5c, 0x5e,
+    0x14, 0x2b, 0x55, 0x7d, 0xfa, 0x66, 0x36, 0x3d, 0x3c, 0x5b, 0x42, 0xd0, 0x6d, 0xec, 0xa7, 0x62,
+    0x78, 0xd6, 0x05, 0xe2, 0xce, 0x37, 0x2b, 0xaa, 0xbe, 0xf7, 0x2c, 0x6e, 0x9c, 0xc7, 0xe5, 0xe3,
+];
+
+const SIGNATURE_1: [u8; 4627] = [
+    0xa4, 0xf7, 0x11, 0xc2, 0x91, 0xc0, 0xc7, 0x53, 0xe2, 0x83, 0x68, 0x75, 0x5f, 0x23, 0xd9, 0xfc,
+    0xdb, 0x94, 0x6c, 0x9b, 0x70, 0x14, 0xbe, 0x7c, 0xb4, 0xa5, 0x5f, 0x27, 0x02, 0x39, 0x59, 0xb7,
+    0x60, 0xfb, 0x5e, 0x26, 0xd0, 0xf1, 0x62, 0x50, 0x8a, 0xc8, 0xec, 0xa8, 0x12, 0x27, 0xad, 0x24,
+    0x77, 0x9b, 0x6d, 0xe0, 0x49, 0x88, 0x9f, 0xb4, 0x9c, 0x3f, 0x03, 0xcb, 0x75, 0xa3, 0xcb, 0x3c,
+    0x4d, 0xca, 0xfb, 0xb7, 0x2f, 0x65, 0xd0, 0x11, 0xb9, 0xfd, 0x2e, 0x9e, 0x10, 0x1f, 0xbc, 0x6c,
+    0x8d, 0xbc, 0xaf, 0x01, 0x73, 0xbd, 0x92, 0xfa, 0x4e, 0x95, 0xd1, 0xb2, 0x82, 0x93, 0x3c, 0xc2,
+    0x88, 0x08, 0x6d, 0x0a, 0x04, 0xf3, 0x7d, 0xa2, 0xe3, 0x06, 0x8c, 0xfc, 0x95, 0xda, 0x95, 0x23,
+    0xf2, 0x74, 0xd3, 0x4b, 0x38, 0x95, 0xfe, 0x6a, 0xb2, 0x9c, 0x83, 0xb5, 0x87, 0x6c, 0x72, 0x61,
+    0x54, 0x55, 0xa9, 0x46, 0x37, 0x09, 0x93, 0xc2, 0xf1, 0x5a, 0x57, 0xf9, 0x0e, 0xa2, 0x2f, 0x1c,
+    0x8b, 0x74, 0x8a, 0x43, 0xd1, 0xe7, 0x9e, 0xcc, 0x29, 0xea, 0x87, 0x27, 0x05, 0x3a, 0xfa, 0xba,
+    0x67, 0x83, 0x6f, 0x04, 0x13, 0xb9, 0x9d, 0x77, 0x05, 0x73, 0x36, 0xc8, 0x41, 0xe0, 0xa6, 0x7b,
+    0x2f, 0xa7, 0x48, 0xb6, 0xad, 0xa4, 0xfb, 0xbe, 0xa5, 0x5b, 0xee, 0x93, 0x04, 0xa1, 0xd5, 0xe4,
+    0x38, 0x7a, 0x0d, 0x4b, 0x6f, 0xb2, 0xd2, 0x07, 0x85, 0x1e, 0xae, 0xaf, 0x14, 0x56, 0x94, 0x7f,
+    0x3c, 0x61, 0xf2, 0xa1, 0xbd, 0xf7, 0x22, 0x85, 0xe9, 0xb9, 0xfe, 0x62, 0x36, 0xf3, 0x9a, 0xd6,
+    0x3a, 0xe4, 0x30, 0x3b, 0xc9, 0xc9, 0xf2, 0xfe, 0x64, 0x3f, 0xf3, 0x2b, 0x6e, 0x18, 0xb3, 0xe1,
+    0x0a, 0xa9, 0xc9, 0xf9, 0x26, 0x4d, 0x1d, 0xfa, 0xd4, 0x43, 0xdb, 0x39, 0x11, 0xbe, 0x34, 0xfe,
+    0x18, 0x6d, 0x86, 0x69, 0xca, 0x66, 0x12, 0xc8, 0x7d, 0x09, 0x3e, 0xe0, 0x87, 0x51, 0x3d, 0x19,
+    0x91, 0xed, 0xa0, 0xe1, 0x6f, 0x8b, 0x5f, 0x6b, 0xdd, 0x89, 0x29, 0x0c, 0xf1, 0xbd, 0x72, 0x77,
+    0x4d, 0x43, 0xf6, 0xcd, 0x58, 0x5e, 0x6e, 0x01, 0xa3, 0x13, 0xb8, 0xab, 0x13, 0x59, 0x7c, 0xa0,
+    0x20, 0x93, 0x54, 0xb3, 0x9b, 0x7d, 0x15, 0xff, 0xbd, 0x63, 0x02, 0xf4, 0xcb, 0x33, 0x6b, 0xb8,
+    0x77, 0xc4, 0x2b, 0xd3, 0x97, 0x16, 0x13, 0x6e, 0x1d, 0x9e, 0xe9, 0x5d, 0xc4, 0xbb, 0x70, 0x8d,
+    0x33, 0xb1, 0xfd, 0x4d, 0x52, 0x97, 0x44, 0xea, 0x89, 0x63, 0x26, 0x7e, 0xcb, 0x9f, 0x31, 0xcd,
+    0xb9, 0xb8, 0xb5, 0xd5, 0x25, 0x6e, 0x87, 0x4e, 0x49, 0x79, 0x82, 0x00, 0x5a, 0xbb, 0xe8, 0x88,
+    0x52, 0x3e, 0x87, 0x32, 0xdf, 0x11, 0xb7, 0x1b, 0xfc, 0x75, 0xbf, 0x58, 0x41, 0x50, 0x5b, 0x28,
+    0x36, 0x4c, 0x9b, 0xdc, 0x4d, 0xaa, 0x70, 0x0f, 0xd3, 0x60, 0x96, 0x31, 0x36, 0x23, 0x83, 0x0b,
+    0x44, 0xea, 0xef, 0x1e, 0x53, 0xa4, 0xac, 0x60, 0xed, 0x40, 0xe0, 0xff, 0x1d, 0xde, 0x66, 0x0c,
+    0xa0, 0xac, 0xce, 0x64, 0x0c, 0x16, 0xeb, 0x69, 0x4d, 0x96, 0xe5, 0x19, 0x2a, 0xcd, 0x26, 0xc2,
+    0x6e, 0x48, 0x27, 0x82, 0x9b, 0x64, 0xd5, 0x5f, 0x39, 0x97, 0x46, 0x7b, 0x05, 0x02, 0x4f, 0x3b,
+    0x47, 0x76, 0x26, 0x05, 0xe1, 0x25, 0x12, 0x55, 0xfb, 0x75, 0x6c, 0xda, 0x7e, 0x8c, 0x13, 0x72,
+    0x3e, 0x2c, 0x35, 0xd6, 0xc3, 0xb3, 0xb9, 0xad, 0x88, 0xd4, 0x37, 0xd7, 0xa7, 0x37, 0x3d, 0xde,
+    0xf3, 0x33, 0x23, 0x6f, 0xc8, 0x85, 0x12, 0xb6, 0x6a, 0x88, 0xe1, 0xc0, 0x07, 0xb1, 0xcc, 0x06,
+    0x95, 0xc2, 0x02, 0x2e, 0xc1, 0xe1, 0xa9, 0x23, 0x6b, 0x75, 0xce, 0x11, 0xc6, 0x3e, 0xd1, 0x3b,
+    0x8f, 0x06, 0xa2, 0xd4, 0x8c, 0x0c, 0xe9, 0xac, 0x0a, 0x0e, 0xb0, 0xf2, 0x2a, 0xc9, 0xed, 0x94,
+    0xa7, 0x53, 0x33, 0xc5, 0x01, 0xa8, 0xc0, 0x4a, 0xe2, 0x19, 0x7d, 0x4e, 0xda, 0xb8, 0xa7, 0xc8,
+    0xf1, 0x7b, 0x9d, 0x67, 0x2e, 0xd1, 0x46, 0x5b, 0x9b, 0x55, 0x33, 0xb8, 0x57, 0xb6, 0x9d, 0x55,
+    0xce, 0x33, 0xf8, 0x22, 0x32, 0xb6, 0x28, 0xd6, 0x95, 0x52, 0x1b, 0x1c, 0x27, 0x66, 0x6c, 0xfd,
+    0x54, 0xf0, 0x4b, 0x4b, 0x44, 0x4f, 0xa3, 0x60, 0x00, 0x32, 0x4d, 0xb4, 0x70, 0x4a, 0x52, 0x05,
+    0xeb, 0xd8, 0x27, 0x2a, 0xc7, 0xbc, 0x46, 0x02, 0xf6, 0xc8, 0x1a, 0x21, 0xec, 0xf8, 0x9f, 0x40,
+    0xed, 0xc7, 0x43, 0xa2, 0x9d, 0x90, 0x1a, 0xbe, 0xff, 0x45, 0x20, 0xac, 0x93, 0x34, 0xa0, 0x4b,
+    0x1f, 0x89, 0x80, 0x7b, 0x6a, 0xb3, 0xd6, 0x4c, 0xba, 0xaf, 0xff, 0x39, 0x8b, 0xc6, 0xf8, 0x67,
+    0xf8, 0x7c, 0xef, 0x56, 0x4a, 0xa3, 0x95, 0xe1, 0xc5, 0xbb, 0x37, 0xf1, 0xb5, 0x82, 0xd3, 0x78,
+    0xa1, 0x58, 0xd7, 0x40, 0x2a, 0x93, 0x5c, 0x2d, 0x2a, 0x99, 0x44, 0x2c, 0x9a, 0x93, 0x80, 0x47,
+    0x83, 0xa7, 0x6f, 0x8c, 0x14, 0xbc, 0xa7, 0x06, 0x52, 0x4c, 0xa4, 0x16, 0xbe, 0xcd, 0xe3, 0xdf,
+    0x8c, 0x80, 0xfa, 0x26, 0x14, 0x1c, 0xc4, 0x06, 0x9e, 0xcd, 0x04, 0xa1, 0x8f, 0xf3, 0x09, 0xf4,
+    0x76, 0x7e, 0x30, 0x7a, 0x51, 0x02, 0xa1, 0xd7, 0x3c, 0x95, 0x9c, 0xfc, 0x32, 0xbe, 0x88, 0xc8,
+    0x18, 0xf9, 0xb7, 0x2d, 0x63, 0x23, 0x54, 0xd5, 0x99, 0xe6, 0xac, 0xb7, 0xa7, 0xd1, 0xb9, 0xf5,
+    0xda, 0xd0, 0xc3, 0xcc, 0xff, 0xd6, 0x7b, 0x8f, 0xe0, 0xe3, 0x49, 0xbe, 0x21, 0x43, 0x80, 0x67,
+    0x33, 0x40, 0x5f, 0x46, 0x74, 0x6d, 0x52, 0x2d, 0xe2, 0x4b, 0x98, 0xec, 0xb2, 0x6a, 0xdf, 0xce,
+    0x02, 0xd5, 0xc6, 0x03, 0x85, 0x5b, 0x6d, 0xeb, 0xa0, 0x17, 0x49, 0x0d, 0x3e, 0x4e, 0x5b, 0x78,
+    0xfd, 0xf5, 0xb9, 0xd0, 0x5d, 0xca, 0xa2, 0x68, 0x82, 0xee, 0xd2, 0x7b, 0x84, 0xb0, 0xb7, 0xfa,
+    0x81, 0xd2, 0x4d, 0x4e, 0xea, 0x35, 0xcd, 0xea, 0xf0, 0x4d, 0xce, 0x8b, 0xdc, 0xdf, 0x88, 0x97,
+    0x0f, 0xf8, 0x14, 0xdd, 0xa3, 0xab, 0xe8, 0xb8, 0x7a, 0xed, 0x08, 0x1a, 0x91, 0x9c, 0x7b, 0x95,
+    0x28, 0x84, 0x63, 0xb8, 0x53, 0xe1, 0xcf, 0x50, 0x34, 0x11, 0xb8, 0x32, 0x72, 0x82, 0xe3, 0x31,
+    0x94, 0xca, 0xf2, 0xcf, 0x74, 0xe0, 0x11, 0x35, 0x02, 0x9b, 0x73, 0x07, 0x05, 0x75, 0xd7, 0xc7,
+    0xc2, 0x88, 0x80, 0xc0, 0xa9, 0x04, 0xce, 0x66, 0x03, 0xd7, 0xcb, 0xb5, 0x42, 0xa1, 0x55, 0x62,
+    0x39, 0x0f, 0x4c, 0x4c, 0x87, 0x2c, 0xd0, 0xaa, 0x4c, 0xcf, 0xd3, 0x68, 0xd2, 0xed, 0x9d, 0xb8,
+    0xd2, 0x99, 0x65, 0x39, 0x2e, 0xcf, 0x91, 0xb4, 0x25, 0x19, 0xd8, 0xae, 0x0c, 0x25, 0x34, 0x58,
+    0x38, 0x76, 0x0e, 0x0b, 0x4b, 0xad, 0x57, 0x45, 0xc9, 0x2d, 0x0d, 0x99, 0x5a, 0xf3, 0xd3, 0x8e,
+    0x15, 0x27, 0x13, 0xf6, 0x73, 0xc5, 0x7f, 0x67, 0xb7, 0x40, 0xb1, 0x19, 0xb3, 0x14, 0x63, 0xb4,
+    0x4b, 0xf8, 0x2b, 0xdb, 0x3a, 0x06, 0xd5, 0x31, 0xa8, 0x4e, 0x65, 0x18, 0x57, 0xe1, 0xd2, 0xa5,
+    0xe5, 0xcb, 0x6a, 0xea, 0x40, 0xcf, 0x35, 0x44, 0x70, 0x4f, 0x0f, 0x07, 0x2a, 0x86, 0xc7, 0x42,
+    0xa3, 0xfa, 0x56, 0x17, 0x3d, 0x44, 0xf4, 0xce, 0x55, 0xd3, 0xea, 0x69, 0xa4, 0x6e, 0x22, 0xea,
+    0xbe, 0x05, 0xd0, 0x06, 0xbb, 0xda, 0x65, 0x6c, 0xa3, 0x22, 0x95, 0xc7, 0x27, 0x47, 0xaf, 0x21,
+    0xc7, 0xd6, 0x8a, 0x21, 0xc1, 0x97, 0x8b, 0x6f, 0xb1, 0x8c, 0xa3, 0xad, 0xa0, 0x5a, 0x9d, 0xf4,
+    0xd2, 0xb3, 0xfc, 0x77, 0x07, 0xb9, 0x6b, 0x6e, 0x29, 0x3d, 0x66, 0xe0, 0x98, 0xd4, 0x31, 0x8d,
+    0xa1, 0x25, 0xc0, 0xc5, 0x58, 0x37, 0xb1, 0xb4, 0x49, 0xab, 0x96, 0xd9, 0x28, 0xd3, 0xbd, 0xb5,
+    0xbb, 0xcc, 0x27, 0x0c, 0x4a, 0xc6, 0xf9, 0x22, 0x9e, 0xab, 0x55, 0x5e, 0x28, 0x94, 0xc3, 0xad,
+    0x71, 0xc5, 0x70, 0x63, 0xe4, 0xa5, 0x30, 0x94, 0x12, 0x16, 0xd2, 0xf2, 0x38, 0x95, 0xfb, 0xdd,
+    0xd2, 0x19, 0xac, 0xfd, 0x93, 0x85, 0x60, 0xc3, 0x4b, 0x83, 0x2f, 0x0d, 0x60, 0xab, 0x4a, 0x9b,
+    0x16, 0xae, 0x62, 0x2c, 0xfb, 0x5f, 0x37, 0x49, 0x3e, 0x07, 0x31, 0x7f, 0xd9, 0xa9, 0x2d, 0x2b,
+    0x94, 0xdf, 0x5a, 0x7b, 0xd6, 0x00, 0x17, 0x77, 0x2b, 0x89, 0x85, 0x8e, 0x7d, 0x68, 0x3e, 0x4f,
+    0x59, 0x2c, 0xf9, 0xc3, 0xa1, 0x9a, 0xf3, 0x4b, 0xbc, 0xa7, 0x83, 0x81, 0x22, 0x29, 0xda, 0xd9,
+    0x7c, 0x03, 0xe1, 0xd8, 0x1f, 0x56, 0xb7, 0xcf, 0x83, 0x78, 0x6c, 0x2e, 0xbc, 0xa4, 0x9f, 0xe7,
+    0x00, 0x8a, 0xb6, 0x81, 0x37, 0x51, 0xc7, 0x6f, 0x84, 0x59, 0x81, 0x10, 0xb0, 0x4c, 0xb7, 0xec,
+    0xc2, 0x10, 0x7d, 0x50, 0x17, 0x0c, 0x72, 0x9c, 0x2c, 0x5e, 0x56, 0x0b, 0xd8, 0xa2, 0x05, 0xcd,
+    0x5e, 0x2f, 0xc5, 0x19, 0x27, 0xb8, 0x3a, 0xeb, 0x9c, 0xf5, 0xee, 0x92, 0x29, 0x1d, 0x12, 0xcc,
+    0x20, 0x8b, 0x33, 0xe0, 0xe6, 0xb5, 0x2d, 0x93, 0xd4, 0x5a, 0x75, 0x04, 0x89, 0x27, 0xba, 0x8b,
+    0xc8, 0x4a, 0xf0, 0x6a, 0x40, 0xed, 0xb5, 0x41, 0xc2, 0xaf, 0x69, 0xec, 0x6b, 0x26, 0xd9, 0x8e,
+    0x39, 0x7e, 0x55, 0x9e, 0x73, 0x73, 0x65, 0x8f, 0x25, 0xcd, 0xa7, 0x64, 0xda, 0xe6, 0xf1, 0xa4,
+    0xc5, 0x33, 0x01, 0xde, 0xc0, 0x19, 0xb0, 0xf1, 0x39, 0x5c, 0x4c, 0x78, 0x94, 0x92, 0x36, 0x30,
+    0x18, 0x31, 0x60, 0x61, 0x70, 0x70, 0x1d, 0xc9, 0x23, 0x30, 0xdd, 0xa9, 0xe9, 0x58, 0x3f, 0xd9,
+    0xe6, 0xa5, 0x6c, 0xc7, 0x04, 0x5e, 0x88, 0x4b, 0xb8, 0x40, 0x12, 0x38, 0xce, 0xc8, 0x6a, 0xec,
+    0xc9, 0xab, 0xdd, 0xb4, 0x9d, 0xe6, 0x6f, 0xc4, 0xe3, 0xee, 0x74, 0xe0, 0x97, 0x43, 0x3b, 0xce,
+    0x80, 0xe4, 0x15, 0x3d, 0x80, 0x9b, 0xd0, 0xf1, 0xbe, 0xfc, 0xcc, 0xe6, 0x68, 0xd8, 0x48, 0x03,
+    0xe5, 0x7b, 0xa1, 0x03, 0x08, 0x3c, 0x43, 0x4d, 0xd0, 0x52, 0x07, 0x7f, 0xd8, 0x99, 0x79, 0x05,
+    0xf4, 0xfa, 0x02, 0xa2, 0x93, 0x28, 0xf3, 0x47, 0xf1, 0x31, 0x8e, 0xd8, 0x59, 0x67, 0x4d, 0x9e,
+    0x92, 0xe9, 0xec, 0x8a, 0x8f, 0x64, 0x3a, 0x19, 0x8a, 0xcc, 0xcc, 0x2f, 0x5c, 0x64, 0x4d, 0x37,
+    0xaf, 0x16, 0x3e, 0xca, 0xc8, 0x7f, 0xdb, 0x4a, 0xb9, 0x51, 0x59, 0x82, 0xb9, 0xc0, 0xd5, 0xfd,
+    0xf2, 0x59, 0x9e, 0x51, 0x3f, 0xe8, 0x27, 0x5f, 0xac, 0xec, 0xd5, 0x81, 0x8a, 0x57, 0x23, 0x4b,
+    0x43, 0x6a, 0x96, 0x65, 0x4a, 0x86, 0x7e, 0xa3, 0x13, 0xb9, 0x89, 0x7c, 0xa9, 0x74, 0x31, 0xf6,
+    0x9e, 0x80, 0xb3, 0x7b, 0xce, 0x9e, 0x87, 0xe1, 0x74, 0x59, 0x6d, 0x33, 0x1e, 0xfd, 0x2d, 0x10,
+    0xa2, 0xfa, 0x00, 0x71, 0xa3, 0x16, 0x13, 0xc3, 0xe1, 0x6d, 0x8d, 0x1b, 0x60, 0xf2, 0xa0, 0x0b,
+    0x44, 0xbf, 0x4e, 0x35, 0x00, 0x83, 0x70, 0xcc, 0x2c, 0xd5, 0xf9, 0xfa, 0xf2, 0xd2, 0x76, 0xe2,
+    0x6a, 0x63, 0xac, 0xda, 0x12, 0x78, 0x77, 0xdb, 0xdc, 0x94, 0x53, 0x32, 0x46, 0x7a, 0x34, 0x51,
+    0x1e, 0x46, 0xa1, 0x21, 0x09, 0xde, 0x99, 0xfb, 0x01, 0x85, 0x6e, 0x05, 0x16, 0x69, 0xe3, 0xe1,
+    0x81, 0x66, 0x62, 0x15, 0x47, 0xec, 0x4f, 0xc4, 0x04, 0x6e, 0xfd, 0x78, 0x9a, 0x6f, 0xd8, 0xb5,
+    0xeb, 0xd9, 0x17, 0x92, 0xeb, 0x93, 0xd4, 0x82, 0x62, 0xba, 0x78, 0x45, 0x6c, 0xd0, 0xa0, 0xc3,
+    0xd6, 0x71, 0x22, 0x80, 0x58, 0x0f, 0x14, 0x27, 0x1f, 0xb3, 0x1e, 0x15, 0x24, 0xd2, 0x73, 0x7a,
+    0x1b, 0x79, 0x97, 0x1c, 0xe8, 0x71, 0xfe, 0xe3, 0xfa, 0x26, 0xfb, 0xb1, 0xdf, 0xf4, 0x28, 0x5e,
+    0x06, 0x9e, 0x6b, 0x1b, 0xd8, 0x3b, 0x62, 0xd7, 0x73, 0xae, 0x85, 0x3e, 0x27, 0xb6, 0xd1, 0x1d,
+    0x16, 0xee, 0xce, 0x53, 0x02, 0x8c, 0x57, 0x49, 0xbb, 0xa1, 0xe2, 0x6e, 0x37, 0x5f, 0x31, 0x61,
+    0xbc, 0xa2, 0x24, 0xde, 0xfb, 0xc9, 0xbb, 0x3a, 0x0a, 0x63, 0x66, 0x0c, 0x04, 0x3f, 0x7a, 0xcf,
+    0x3e, 0xe5, 0xf2, 0xf7, 0x19, 0x46, 0xd6, 0x13, 0x4a, 0xf5, 0x3c, 0xea, 0x86, 0xb4, 0x1c, 0xbd,
+    0xbe, 0xb2, 0x05, 0xfc, 0x74, 0x65, 0x52, 0xa8, 0x80, 0xa7, 0xd5, 0xaa, 0xea, 0x43, 0x65, 0xc4,
+    0xc9, 0x59, 0x2d, 0x98, 0x6f, 0x81, 0xc2, 0xac, 0x7e, 0x31, 0x90, 0xef, 0x62, 0x46, 0xff, 0xd5,
+    0x9e, 0xfb, 0x9f, 0x24, 0x25, 0xe2, 0x76, 0xdf, 0x09, 0x38, 0x84, 0x50, 0x0c, 0xb8, 0x8c, 0x04,
+    0x44, 0x3e, 0x29, 0xcd, 0xa6, 0xb7, 0x16, 0x5f, 0x30, 0x8f, 0x45, 0x58, 0xbc, 0x51, 0xf4, 0x86,
+    0x2b, 0x9a, 0x86, 0x04, 0xe8, 0x93, 0x84, 0x76, 0x01, 0xd9, 0xd9, 0xc0, 0xb3, 0xea, 0xc6, 0x4d,
+    0x85, 0x60, 0x85, 0x50, 0xb0, 0x9c, 0x80, 0xba, 0xf3, 0xdd, 0x1b, 0xb8, 0x46, 0x60, 0x49, 0xa4,
+    0x3d, 0x05, 0x7f, 0x10, 0x69, 0x1d, 0x50, 0x50, 0xa3, 0xe6, 0x31, 0xc0, 0x53, 0x4f, 0xce, 0xd5,
+    0xae, 0x57, 0xd6, 0x24, 0xbd, 0xf5, 0x2e, 0x67, 0x42, 0x81, 0x30, 0x11, 0x27, 0x27, 0x9a, 0x54,
+    0x0d, 0xf1, 0xd2, 0xae, 0x37, 0x6e, 0xec, 0x51, 0xca, 0x96, 0x6c, 0x5d, 0x9c, 0x08, 0x97, 0xa4,
+    0x22, 0x4e, 0x68, 0xd2, 0x6a, 0xcc, 0x3a, 0x0c, 0xee, 0xd8, 0xfa, 0xf9, 0x60, 0xff, 0xe1, 0x69,
+    0x2e, 0xbd, 0x79, 0x52, 0xad, 0x68, 0x7e, 0x1b, 0x77, 0x0e, 0xcf, 0xa2, 0x0e, 0x9e, 0x2c, 0x68,
+    0x46, 0xc5, 0xf3, 0xb1, 0x9f, 0xfd, 0x36, 0x33, 0x32, 0x6c, 0xf2, 0x32, 0x1b, 0x22, 0x14, 0x47,
+    0x9e, 0x84, 0x92, 0x0d, 0xf1, 0x5d, 0x8a, 0xd0, 0xb1, 0x0f, 0x9c, 0x4d, 0x40, 0x05, 0xe9, 0x62,
+    0x60, 0x17, 0xa2, 0x1b, 0x64, 0x88, 0x0d, 0x45, 0x68, 0xa1, 0x5f, 0xdb, 0xdd, 0x71, 0x27, 0x92,
+    0xed, 0xce, 0xc1, 0x3c, 0xcf, 0x9f, 0xc8, 0xcb, 0x80, 0xe8, 0x63, 0x64, 0xd5, 0x5d, 0x60, 0xf2,
+    0x6a, 0x84, 0x3c, 0xc0, 0x9a, 0xd5, 0x9d, 0xe0, 0x49, 0x9a, 0x19, 0x68, 0x6f, 0x47, 0xb3, 0x19,
+    0xec, 0xec, 0x8c, 0x68, 0x54, 0xde, 0x0c, 0x7e, 0x0a, 0xc3, 0xef, 0x69, 0x69, 0xe7, 0x02, 0xb2,
+    0xc3, 0xf8, 0x77, 0x9d, 0x51, 0xdf, 0x80, 0x40, 0x39, 0xde, 0xf3, 0xc4, 0x70, 0xa8, 0x1e, 0xcf,
+    0x90, 0x7b, 0x5d, 0xe3, 0x9b, 0xee, 0x0c, 0x71, 0xf3, 0x06, 0x76, 0xe7, 0xa3, 0x07, 0x2d, 0x85,
+    0x3d, 0xf4, 0xe1, 0x00, 0x41, 0x3d, 0xf1, 0xfd, 0xe5, 0x61, 0x89, 0x0a, 0x9d, 0x40, 0x06, 0x99,
+    0x0a, 0xa1, 0xc0, 0x29, 0x15, 0x2e, 0xfb, 0xa7, 0x79, 0x8c, 0x13, 0xff, 0xda, 0x57, 0xe1, 0x18,
+    0xad, 0xaf, 0xa4, 0x75, 0xd0, 0xfa, 0xc9, 0x9c, 0xb2, 0x94, 0x4a, 0xf5, 0x54, 0xed, 0x25, 0xf4,
+    0x39, 0x1f, 0x35, 0x47, 0x13, 0xef, 0x9f, 0x67, 0xe3, 0xad, 0xf6, 0x75, 0x64, 0x1d, 0x75, 0xab,
+    0x3f, 0xca, 0x28, 0xfc, 0x59, 0x1a, 0x50, 0xfb, 0x87, 0xf1, 0x07, 0x55, 0xd1, 0x10, 0xba, 0x93,
+    0x6f, 0x5d, 0xfa, 0xba, 0xbf, 0x1b, 0x13, 0xed, 0x78, 0x5b, 0x21, 0x9b, 0x1f, 0xa1, 0x84, 0xc0,
+    0xa9, 0x2d, 0x23, 0x69, 0x27, 0x9f, 0x02, 0xa8, 0x9e, 0x8b, 0x86, 0xaf, 0x5d, 0xf2, 0xc8, 0x8f,
+    0x89, 0x11, 0x06, 0xeb, 0xb2, 0xfd, 0x9d, 0x90, 0x2b, 0xee, 0x9d, 0x62, 0xd7, 0x0e, 0x9a, 0x05,
+    0x7a, 0x81, 0xf5, 0x95, 0xb9, 0xd9, 0x28, 0x64, 0x1b, 0x93, 0xb2, 0x73, 0x96, 0x62, 0xb9, 0x52,
+    0xc6, 0x74, 0x32, 0x54, 0x12, 0x2b, 0x3c, 0x28, 0x38, 0xa1, 0x58, 0xf9, 0x87, 0xc6, 0x8a, 0xab,
+    0x98, 0x64, 0xa0, 0x43, 0x62, 0x3a, 0x4f, 0xec, 0x31, 0xad, 0x22, 0x0c, 0x21, 0xb2, 0xf4, 0x0d,
+    0xaa, 0x6b, 0xf3, 0x64, 0xb1, 0x1a, 0x24, 0x94, 0x54, 0x22, 0xe1, 0x64, 0x03, 0x13, 0x87, 0xde,
+    0x17, 0xb8, 0x0a, 0x8e, 0xc1, 0x86, 0x33, 0x7d, 0x86, 0xb7, 0x06, 0x3d, 0x13, 0x01, 0x95, 0x4c,
+    0x17, 0xf1, 0x6f, 0xa3, 0x9e, 0xc4, 0xcd, 0xd4, 0x08, 0x60, 0x42, 0x92, 0x19, 0x6c, 0x39, 0x69,
+    0xde, 0x7a, 0xbd, 0x2e, 0x38, 0x2a, 0x4f, 0xef, 0xd6, 0x2e, 0x84, 0xe6, 0x89, 0x41, 0x7e, 0x33,
+    0x82, 0x62, 0xe5, 0xa1, 0xda, 0x58, 0xfc, 0x93, 0x1b, 0xbb, 0xd1, 0x61, 0x8a, 0x4c, 0x4e, 0xf1,
+    0x58, 0x92, 0x31, 0x04, 0x1f, 0x99, 0x09, 0xd0, 0x4f, 0xcf, 0x20, 0x7d, 0x97, 0x65, 0x00, 0xbd,
+    0x86, 0x5c, 0xd7, 0x56, 0x44, 0x92, 0x84, 0x6e, 0xc3, 0x27, 0xbf, 0x00, 0x14, 0xe5, 0xfd, 0x80,
+    0xbb, 0x10, 0xe1, 0x82, 0xb6, 0x25, 0x05, 0x25, 0x94, 0x89, 0xf5, 0x28, 0x73, 0x24, 0x1c, 0x6e,
+    0x96, 0x2a, 0x8a, 0x32, 0x56, 0x24, 0xba, 0xf2, 0x6f, 0x42, 0xa5, 0x68, 0x9e, 0x67, 0xe3, 0x3f,
+    0x13, 0xc4, 0xda, 0x6f, 0xc9, 0x54, 0x79, 0xb6, 0x51, 0x7f, 0x1c, 0x88, 0xd4, 0xfb, 0xc6, 0x02,
+    0xaa, 0x41, 0x06, 0xbe, 0xce, 0x0e, 0xfc, 0x21, 0xbc, 0x28, 0x06, 0xf7, 0x07, 0x5b, 0x4a, 0x9e,
+    0xba, 0x3d, 0x85, 0xbd, 0xe6, 0x57, 0xd5, 0xa1, 0xac, 0x1f, 0x27, 0xd2, 0xd4, 0x85, 0x36, 0x83,
+    0x0b, 0x25, 0x1d, 0xbc, 0xdf, 0xcf, 0x45, 0x7b, 0x8e, 0xb3, 0x7c, 0xd3, 0x41, 0xa4, 0xda, 0xac,
+    0xe3, 0x23, 0x57, 0xda, 0x45, 0x41, 0xed, 0x67, 0xaa, 0x4d, 0xd0, 0x74, 0x37, 0xee, 0xe0, 0xcd,
+    0x5e, 0x69, 0x2e, 0x44, 0xd9, 0xb9, 0x16, 0x2f, 0x6c, 0x34, 0x98, 0x50, 0x76, 0x5b, 0xb4, 0x05,
+    0x81, 0x38, 0x5d, 0xbd, 0x5d, 0xb8, 0xf5, 0x55, 0xc1, 0xfc, 0x6c, 0xce, 0x96, 0x62, 0x24, 0xf2,
+    0x78, 0x37, 0x2b, 0xa0, 0xeb, 0x87, 0x67, 0x45, 0x84, 0x22, 0x01, 0xdb, 0x53, 0xca, 0x7f, 0x9e,
+    0xdf, 0x8c, 0x83, 0x0a, 0xd6, 0x93, 0x77, 0x22, 0x84, 0x42, 0x6e, 0xe0, 0xc7, 0x2a, 0x1a, 0xff,
+    0x2e, 0x8f, 0x5c, 0x24, 0x55, 0x3f, 0xad, 0x9b, 0x26, 0xc2, 0x6b, 0x20, 0x4a, 0x9e, 0x14, 0x94,
+    0x13, 0xdc, 0x4d, 0xb9, 0xd4, 0xda, 0xd6, 0x45, 0xb7, 0x3a, 0x88, 0xf7, 0x54, 0xb9, 0xd4, 0x3b,
+    0xac, 0x2f, 0x6f, 0xa5, 0xa4, 0x21, 0xc4, 0x8b, 0xbd, 0xb6, 0x07, 0xdc, 0xe1, 0xed, 0x9c, 0xc2,
+    0xee, 0xca, 0x65, 0x02, 0x3a, 0x69, 0xa8, 0xe3, 0x0b, 0x9f, 0x82, 0xbc, 0x97, 0xe2, 0x88, 0x41,
+    0x34, 0x6b, 0xc3, 0xeb, 0x8e, 0xcd, 0x34, 0x8c, 0x13, 0xec, 0x47, 0x59, 0x51, 0x1e, 0xe9, 0xb4,
+    0xd0, 0x89, 0xd9, 0x38, 0xca, 0x90, 0x2c, 0x20, 0xf1, 0x32, 0x0a, 0x9c, 0x36, 0xa6, 0x14, 0xaf,
+    0x37, 0x8d, 0xb6, 0xec, 0x01, 0x3e, 0x6f, 0xb0, 0x3e, 0x17, 0xd1, 0xad, 0x0f, 0xa6, 0x44, 0xaa,
+    0xd0, 0xc5, 0xc3, 0x17, 0x7f, 0xeb, 0x09, 0xf1, 0x0a, 0x88, 0xc9, 0x3e, 0xcb, 0xb3, 0xe0, 0x09,
+    0xcc, 0x2e, 0x6f, 0x6d, 0x2e, 0x0e, 0x01, 0xa9, 0xe4, 0x34, 0x0d, 0x85, 0xbd, 0x71, 0x5f, 0x08,
+    0x46, 0x8d, 0x46, 0x6c, 0xb3, 0xee, 0x46, 0xba, 0x8e, 0xe5, 0xef, 0xe0, 0x65, 0xc6, 0x94, 0xc7,
+    0xca, 0x0f, 0x45, 0x3d, 0xc8, 0xf9, 0xb5, 0x69, 0x5d, 0x27, 0xa3, 0xe4, 0x34, 0xf7, 0xce, 0x89,
+    0x96, 0x60, 0x31, 0xc0, 0xf6, 0x79, 0x61, 0xd0, 0xf2, 0x9d, 0xdf, 0xf9, 0x71, 0xd7, 0x9f, 0x72,
+    0xd1, 0xa6, 0x4a, 0x16, 0x27, 0xeb, 0xc7, 0xb5, 0x14, 0xf8, 0xf1, 0x62, 0xc3, 0x28, 0x92, 0x44,
+    0x92, 0x32, 0xec, 0x22, 0x77, 0x32, 0xf2, 0x64, 0x8b, 0xd6, 0xc4, 0x43, 0xee, 0x6c, 0x4e, 0x90,
+    0xbb, 0x6b, 0xe4, 0x3c, 0xc3, 0x76, 0xff, 0xf5, 0xff, 0x64, 0xcd, 0xcb, 0xd5, 0x59, 0x85, 0x94,
+    0xb5, 0xbf, 0x42, 0xc5, 0x0a, 0x63, 0x89, 0x8d, 0xc4, 0x08, 0x84, 0xc2, 0x2f, 0xc4, 0xeb, 0x6b,
+    0x6f, 0xa7, 0x65, 0x8f, 0xe8, 0x8e, 0x48, 0x21, 0x9c, 0xf3, 0x7d, 0xc6, 0x44, 0x8c, 0x3e, 0x1b,
+    0xcd, 0x7d, 0x38, 0x60, 0x6c, 0x36, 0x27, 0xec, 0xdc, 0xb5, 0x30, 0xfe, 0xe1, 0xf4, 0xe8, 0x21,
+    0x9b, 0x51, 0x77, 0xe5, 0x44, 0xaf, 0x5f, 0x59, 0x17, 0xa2, 0xa4, 0x39, 0x4a, 0x10, 0x4c, 0x53,
+    0x32, 0x31, 0x81, 0x6c, 0x99, 0x97, 0x74, 0x41, 0xcd, 0x66, 0x54, 0xfe, 0xab, 0xcf, 0xe5, 0x84,
+    0xa8, 0x1b, 0x05, 0x42, 0xc5, 0x6d, 0x4d, 0x66, 0x65, 0x0e, 0x93, 0xdf, 0x01, 0xae, 0xd7, 0xbc,
+    0xa5, 0xda, 0x88, 0x9e, 0x42, 0xe5, 0x3d, 0xbb, 0x2a, 0x59, 0x78, 0x0e, 0x9b, 0xfc, 0xcc, 0xcd,
+    0x00, 0x71, 0x01, 0x68, 0x35, 0x01, 0x1d, 0x32, 0xc1, 0x7a, 0xbf, 0xe3, 0x7f, 0x0c, 0x3a, 0x73,
+    0xed, 0x3a, 0xc7, 0x18, 0xb5, 0x61, 0xd1, 0xa2, 0xd5, 0x83, 0x30, 0xc3, 0xce, 0x3f, 0xfd, 0x1c,
+    0xd0, 0x87, 0xbe, 0xe7, 0x68, 0x50, 0xd5, 0xb7, 0x47, 0x80, 0x20, 0x7d, 0xfa, 0x98, 0xac, 0x55,
+    0x35, 0xc6, 0x57, 0x1b, 0xf3, 0xd6, 0x1f, 0xe7, 0x7e, 0x1b, 0xe5, 0xcc, 0xd8, 0x51, 0x22, 0xb9,
+    0xb8, 0x23, 0x13, 0x57, 0x07, 0x53, 0x23, 0x56, 0x4b, 0x40, 0xed, 0xf4, 0x95, 0x7e, 0xb8, 0x9b,
+    0x34, 0xdb, 0xe6, 0x56, 0x20, 0xc3, 0x7e, 0x3c, 0xf6, 0x9f, 0xe4, 0x42, 0xa1, 0x46, 0x2d, 0x2f,
+    0x28, 0x38, 0xd4, 0x3a, 0x6e, 0xc6, 0x3e, 0x1f, 0x64, 0xac, 0x4e, 0xdd, 0xf1, 0xec, 0xe4, 0x71,
+    0xbd, 0xce, 0x55, 0x57, 0xe8, 0xff, 0x20, 0x18, 0x80, 0xd8, 0xe0, 0x99, 0x5c, 0xc4, 0x96, 0x08,
+    0xf1, 0x4f, 0x95, 0x77, 0x96, 0xba, 0x39, 0xe3, 0x05, 0x95, 0x96, 0xb1, 0xe8, 0x55, 0x75, 0x9b,
+    0xce, 0x3e, 0x5c, 0x23, 0x18, 0x3d, 0x13, 0xbd, 0xc6, 0x81, 0x9b, 0x0d, 0x3e, 0x20, 0xc8, 0x01,
+    0x63, 0xe1, 0xc2, 0x01, 0x8d, 0xed, 0xfd, 0x87, 0x46, 0xb3, 0x6b, 0x0a, 0x3f, 0x03, 0x7f, 0x75,
+    0xcc, 0x4e, 0x37, 0xde, 0x86, 0x34, 0xcb, 0x4e, 0xdf, 0xc1, 0x6c, 0x8c, 0x19, 0xc9, 0x63, 0xca,
+    0xff, 0x0c, 0x77, 0xc8, 0x11, 0x1f, 0xac, 0x4a, 0x94, 0x1a, 0x1b, 0x9d, 0xd3, 0xcb, 0xd1, 0x49,
+    0xe9, 0x71, 0x1a, 0x7b, 0x97, 0x35, 0x1f, 0x22, 0x5e, 0xc5, 0xde, 0xc5, 0x03, 0x19, 0xa0, 0x89,
+    0x8b, 0x54, 0x5a, 0xf2, 0xcc, 0x29, 0x94, 0x29, 0x28, 0xca, 0x3a, 0xe4, 0xad, 0x65, 0x23, 0x56,
+    0x18, 0x80, 0xba, 0x0f, 0x97, 0x27, 0x42, 0xf5, 0xfa, 0xf1, 0x1a, 0x21, 0x8c, 0x2e, 0x1e, 0x03,
+    0xf8, 0xca, 0xd9, 0xf8, 0x78, 0x42, 0xa1, 0x69, 0x9b, 0xaa, 0x61, 0x37, 0xe7, 0x8f, 0xd9, 0x57,
+    0x14, 0xf0, 0x88, 0x90, 0xc5, 0xac, 0x92, 0x26, 0x70, 0x8f, 0xe2, 0xc7, 0xeb, 0xc7, 0xd5, 0x32,
+    0x1f, 0xdf, 0xe9, 0xc0, 0x62, 0x20, 0x4f, 0xc0, 0x92, 0xe4, 0x8a, 0x53, 0x29, 0xc4, 0x4a, 0x48,
+    0x6d, 0x25, 0x01, 0x6c, 0xe7, 0x7c, 0xdb, 0xc4, 0x5a, 0x62, 0x9a, 0x63, 0xfb, 0x0d, 0x8e, 0x54,
+    0x9a, 0x8f, 0x6a, 0xf1, 0xb8, 0xfd, 0x89, 0x6b, 0x06, 0xcb, 0xf9, 0x2c, 0x94, 0x13, 0xe7, 0xa3,
+    0x12, 0x60, 0x18, 0x35, 0x3c, 0x9f, 0xa6, 0x98, 0x3a, 0x89, 0xf3, 0x63, 0x84, 0x9f, 0xbf, 0xcb,
+    0xcb, 0xd7, 0xc2, 0x39, 0x9c, 0x94, 0xe1, 0x0d, 0x9b, 0x46, 0x52, 0xf5, 0x2d, 0x46, 0x7e, 0x2f,
+    0xfb, 0x38, 0xa5, 0x97, 0xe4, 0x1e, 0x3e, 0x48, 0x3e, 0x6b, 0x1c, 0xc3, 0xcc, 0xd5, 0x03, 0xa1,
+    0x4f, 0xbc, 0x4d, 0x96, 0x8b, 0xf8, 0x37, 0xf6, 0xd9, 0x44, 0x39, 0x68, 0x4b, 0x61, 0x7b, 0xeb,
+    0x93, 0x56, 0x63, 0x33, 0xbb, 0x72, 0x7e, 0xb3, 0xaf, 0x86, 0xc6, 0xa5, 0xc5, 0xbb, 0xd6, 0xb5,
+    0xa4, 0x2d, 0x4f, 0xe4, 0x02, 0xda, 0xa5, 0x23, 0x39, 0x55, 0xa5, 0x68, 0x5d, 0x94, 0x5a, 0x95,
+    0x0d, 0x31, 0x9d, 0x7d, 0xcf, 0x30, 0xbc, 0x3f, 0x29, 0x7c, 0xa9, 0xb4, 0x8c, 0x18, 0xad, 0xed,
+    0xda, 0xbb, 0x30, 0xab, 0xd6, 0xce, 0x9e, 0x20, 0x4c, 0x36, 0x26, 0x99, 0x34, 0x37, 0x24, 0x48,
+    0x2c, 0x25, 0x38, 0x6f, 0xcc, 0x00, 0x6d, 0xfa, 0x96, 0x9b, 0x28, 0xaf, 0xb4, 0x61, 0xcf, 0xde,
+    0x4d, 0x1e, 0x30, 0x22, 0x56, 0x9c, 0xe7, 0x31, 0x3e, 0x31, 0x0e, 0x36, 0xf7, 0xb9, 0xac, 0x4a,
+    0x8a, 0x2f, 0x2a, 0x4c, 0xce, 0x30, 0xf2, 0x39, 0x81, 0xa5, 0xbd, 0x8f, 0x43, 0x79, 0xf8, 0x86,
+    0x22, 0x8c, 0x7e, 0x32, 0x4f, 0x53, 0x77, 0xd4, 0x32, 0x4c, 0x02, 0xd7, 0x9d, 0x47, 0x8a, 0xe0,
+    0x8d, 0xa8, 0x2e, 0x2e, 0x0e, 0x7d, 0xea, 0xe1, 0x84, 0xc4, 0x97, 0x4c, 0x25, 0x9a, 0x24, 0xac,
+    0x5a, 0xa4, 0x36, 0xa5, 0x91, 0x9f, 0xbe, 0xf1, 0x73, 0xdf, 0xb4, 0x2d, 0x2e, 0x9f, 0x5a, 0x28,
+    0xbe, 0xb8, 0x6c, 0x17, 0x76, 0x8d, 0x55, 0x82, 0xbe, 0xd6, 0xa8, 0xc7, 0x72, 0xda, 0xf2, 0x3e,
+    0x10, 0xf4, 0xca, 0x72, 0x53, 0xd5, 0x27, 0xe7, 0x98, 0x1b, 0x41, 0x36, 0xce, 0xc0, 0xf0, 0x6b,
+    0xa1, 0x5c, 0x59, 0x09, 0x2b, 0x13, 0x7c, 0xe8, 0xb7, 0xe6, 0xa2, 0x8f, 0x87, 0x06, 0xb8, 0x53,
+    0x4d, 0xb9, 0xc5, 0x0f, 0x68, 0x67, 0x61, 0x78, 0xf2, 0xfe, 0xe3, 0x52, 0x2b, 0x32, 0x9f, 0x23,
+    0xa9, 0x01, 0xab, 0x9d, 0xcf, 0x7c, 0x56, 0xea, 0x03, 0x5e, 0x8d, 0x48, 0xf5, 0x39, 0x88, 0xb6,
+    0x83, 0xef, 0xc2, 0xf8, 0x1c, 0xb2, 0x1d, 0x47, 0x88, 0xad, 0xbf, 0x0a, 0xc6, 0x79, 0xd4, 0x7f,
+    0xa6, 0xa5, 0xf6, 0x7e, 0x9d, 0xd9, 0xfa, 0x33, 0x40, 0xa3, 0xe9, 0x58, 0xff, 0xd3, 0x9c, 0x06,
+    0x92, 0xd3, 0x6d, 0xef, 0x8f, 0x17, 0x8a, 0x65, 0xfe, 0xb6, 0x86, 0x71, 0xb3, 0xc2, 0xd7, 0x53,
+    0x69, 0xf6, 0xc7, 0xaa, 0x26, 0x39, 0x27, 0x53, 0xf9, 0xb8, 0x9a, 0x82, 0x8b, 0xf4, 0xaf, 0x44,
+    0x77, 0xd1, 0x4b, 0x38, 0x3a, 0x8b, 0x4f, 0x2c, 0xbc, 0x83, 0xb6, 0x7f, 0xa2, 0xe3, 0x7e, 0x0e,
+    0xba, 0x05, 0xd3, 0xfd, 0x5f, 0x4e, 0xab, 0xf5, 0x91, 0x6e, 0xe0, 0xb9, 0x6f, 0x06, 0x0b, 0x55,
+    0xec, 0x01, 0xcd, 0x02, 0xd3, 0xbf, 0x49, 0xd0, 0x09, 0xeb, 0xc6, 0x8d, 0x66, 0x48, 0x88, 0xa7,
+    0x08, 0x32, 0x00, 0x2c, 0xfc, 0xed, 0xe1, 0x75, 0x4d, 0x27, 0xf0, 0x3f, 0x07, 0x7c, 0x41, 0x1c,
+    0x5e, 0xeb, 0xf3, 0x39, 0xc8, 0x4e, 0x1d, 0x8c, 0xb5, 0x32, 0xcf, 0xe3, 0x0a, 0x45, 0x2c, 0x28,
+    0x2c, 0xf1, 0x92, 0xda, 0xea, 0xf9, 0x0c, 0xf5, 0x7e, 0x13, 0x5d, 0x41, 0xf9, 0x1a, 0x74, 0xae,
+    0x5e, 0x32, 0xfb, 0x42, 0xd6, 0xc4, 0xbc, 0x61, 0x4f, 0x3a, 0x08, 0x96, 0xd8, 0xa5, 0x7b, 0x27,
+    0x31, 0xac, 0x11, 0xcd, 0x33, 0xf8, 0xbe, 0x21, 0xee, 0xef, 0x1c, 0xc4, 0x64, 0x32, 0x85, 0x7d,
+    0x75, 0x47, 0x4a, 0xe7, 0xec, 0x1a, 0xb0, 0xdc, 0x14, 0xc3, 0xba, 0x49, 0xb2, 0xe4, 0x16, 0xc0,
+    0x89, 0x5e, 0x3f, 0xbf, 0x8c, 0xbf, 0xf5, 0xc7, 0x29, 0x6b, 0x90, 0x8f, 0x84, 0xa5, 0x7d, 0x48,
+    0xb7, 0x84, 0xc8, 0xff, 0xa9, 0x99, 0x8f, 0x32, 0xa3, 0xd9, 0x20, 0xb9, 0x1d, 0xa3, 0xb3, 0xae,
+    0x46, 0x83, 0xde, 0xd5, 0xc0, 0x0e, 0x1d, 0x1d, 0xab, 0x88, 0x2a, 0x3d, 0x6e, 0xe8, 0x39, 0xfb,
+    0xdf, 0x4e, 0x90, 0x2a, 0xf8, 0xf5, 0x6d, 0x32, 0x0d, 0x88, 0x36, 0xf4, 0x30, 0xba, 0x64, 0xf5,
+    0xd1, 0x46, 0x75, 0x1c, 0xac, 0xe9, 0xb8, 0xb8, 0xa1, 0x14, 0xc3, 0xea, 0x3e, 0x28, 0xf8, 0xa2,
+    0x19, 0xf4, 0x73, 0x59, 0x36, 0x7e, 0xd4, 0x6c, 0x88, 0x63, 0x11, 0x5f, 0x5f, 0xbd, 0x8f, 0xe9,
+    0x74, 0xcd, 0x19, 0x44, 0x0d, 0xa4, 0xd6, 0x6d, 0x89, 0xd0, 0x6c, 0xde, 0x5b, 0xe1, 0x0e, 0xef,
+    0xa2, 0x03, 0xd3, 0xb1, 0xe7, 0x87, 0xc1, 0xe0, 0x39, 0xca, 0xa4, 0x46, 0x5c, 0x2e, 0x82, 0x95,
+    0xe0, 0xc2, 0xec, 0xf8, 0x91, 0xcf, 0x8f, 0xba, 0xe1, 0x01, 0xc1, 0x69, 0xfe, 0x34, 0x48, 0x79,
+    0x38, 0xa8, 0xe1, 0xa7, 0x36, 0x73, 0x98, 0xc2, 0xd0, 0xd2, 0x81, 0x4f, 0xb7, 0x39, 0xd8, 0x6b,
+    0xf9, 0xe2, 0xc7, 0x1e, 0x1c, 0x51, 0x04, 0xc2, 0xdd, 0x92, 0x02, 0x6a, 0x84, 0x93, 0xab, 0x97,
+    0xcf, 0x02, 0x1b, 0xdf, 0x2d, 0x88, 0xb4, 0x36, 0x5c, 0x4e, 0x47, 0x2d, 0x0a, 0x73, 0xff, 0xc9,
+    0x52, 0x98, 0x40, 0x1d, 0xf7, 0x0d, 0x10, 0x38, 0x8e, 0xac, 0x55, 0x95, 0x8a, 0xd2, 0x06, 0x80,
+    0x61, 0x53, 0x10, 0xbc, 0x23, 0xa7, 0xa1, 0x8f, 0xfd, 0xc6, 0xc5, 0x10, 0x08, 0xf2, 0x08, 0x8b,
+    0x8d, 0xd8, 0xe2, 0x60, 0x9f, 0x81, 0x4b, 0x3c, 0xf4, 0x3f, 0x05, 0xa4, 0x70, 0x10, 0x47, 0x22,
+    0x6e, 0xb1, 0xd5, 0x90, 0x98, 0x89, 0xe9, 0x50, 0x8b, 0xa2, 0x5d, 0x3b, 0x72, 0x0f, 0x8b, 0xc8,
+    0xd7, 0x8c, 0xdd, 0x65, 0x29, 0xc9, 0xc3, 0x6b, 0xb1, 0x1f, 0xc6, 0xd9, 0x51, 0xab, 0x7c, 0xc9,
+    0x52, 0xd0, 0x39, 0x62, 0x7f, 0x7b, 0x27, 0xa8, 0x7b, 0x57, 0xdd, 0x1d, 0x53, 0x4d, 0xe7, 0xd7,
+    0x5d, 0x0b, 0x66, 0xff, 0xcd, 0x71, 0x75, 0xae, 0x1b, 0xf4, 0x26, 0xd7, 0x79, 0xc9, 0xac, 0x09,
+    0x23, 0x4d, 0x33, 0x02, 0xb7, 0x8f, 0x64, 0x10, 0xd7, 0xdf, 0x21, 0x11, 0xcd, 0x35, 0x16, 0x57,
+    0x56, 0x23, 0x93, 0xdc, 0x07, 0xb2, 0xe7, 0x89, 0x4f, 0xf4, 0xd1, 0x80, 0xcc, 0xa8, 0x7a, 0xb6,
+    0x04, 0xef, 0xe0, 0xc8, 0x0c, 0xc1, 0xb3, 0x27, 0xdc, 0x5e, 0x85, 0xf2, 0x28, 0xd2, 0xe8, 0x09,
+    0xb7, 0x60, 0x3b, 0xca, 0xab, 0xc5, 0xae, 0xe4, 0xe7, 0x56, 0xa8, 0x31, 0xa2, 0xd8, 0xb9, 0x64,
+    0x3b, 0x72, 0xa7, 0x66, 0xa3, 0xdd, 0x5e, 0x61, 0xec, 0xfd, 0xea, 0x94, 0xaf, 0x1b, 0xfe, 0x61,
+    0x43, 0x5a, 0x4b, 0x48, 0xd2, 0x36, 0xe1, 0x47, 0xe3, 0x9f, 0xed, 0x2b, 0xe9, 0x13, 0xa5, 0x80,
+    0x3d, 0xf1, 0x0e, 0x4b, 0xb5, 0xe7, 0xb3, 0xbe, 0xaf, 0x82, 0xff, 0x4f, 0x92, 0x9d, 0x8c, 0x6e,
+    0x93, 0xda, 0xfc, 0x12, 0x22, 0xa8, 0xc3, 0xda, 0x10, 0xf5, 0xbf, 0x39, 0x7a, 0xd7, 0xea, 0x9f,
+    0xa9, 0x03, 0x25, 0x8a, 0x5c, 0x71, 0x0e, 0x6f, 0x07, 0x20, 0x6f, 0x0b, 0x1b, 0x40, 0x77, 0x5d,
+    0xa0, 0x2c, 0x10, 0x97, 0xf5, 0xe0, 0x16, 0xd6, 0x45, 0x2e, 0x5a, 0x2d, 0xae, 0xc7, 0x21, 0xac,
+    0xf9, 0xc6, 0x11, 0xb6, 0x9e, 0xfa, 0x0f, 0x8a, 0x2c, 0xb4, 0xa4, 0xc5, 0x50, 0x8c, 0xf1, 0x58,
+    0x5c, 0x60, 0xd6, 0x9f, 0xbe, 0x95, 0x14, 0x44, 0x66, 0x12, 0xb9, 0x1c, 0x0d, 0x1b, 0xc4, 0x8f,
+    0x73, 0xe7, 0x1b, 0x8a, 0x3c, 0x66, 0x80, 0xa0, 0x57, 0x70, 0xf2, 0x83, 0xfa, 0x39, 0xcd, 0xda,
+    0x1f, 0x61, 0xdb, 0xb0, 0xe7, 0xd0, 0x3b, 0xdf, 0xb8, 0x27, 0x89, 0xd5, 0xa3, 0xf3, 0xdb, 0x4c,
+    0x0a, 0x4e, 0xbf, 0x42, 0x95, 0xa3, 0x4c, 0x5b, 0x4b, 0x3d, 0x0b, 0x91, 0x27, 0xbc, 0x47, 0xed,
+    0xfa, 0x2e, 0x4c, 0x2e, 0x33, 0x2e, 0x2b, 0x0a, 0xc9, 0x43, 0xa7, 0x01, 0xd9, 0x06, 0xbf, 0xce,
+    0x92, 0xc9, 0x39, 0xd2, 0xe6, 0x75, 0x90, 0x72, 0x07, 0x28, 0xa9, 0xc3, 0x97, 0x2f, 0x03, 0xce,
+    0x34, 0x52, 0x81, 0x01, 0xa0, 0xdf, 0x74, 0x92, 0x17, 0x8b, 0x89, 0x9b, 0x26, 0x45, 0x07, 0xa6,
+    0xc9, 0xc8, 0x5c, 0xf2, 0x54, 0xc1, 0x43, 0x2c, 0x5a, 0x9e, 0xef, 0x53, 0xff, 0xe8, 0x66, 0x8a,
+    0x99, 0x18, 0x86, 0x77, 0x82, 0xb9, 0xf5, 0x24, 0x87, 0x54, 0xe2, 0x40, 0xc2, 0xa0, 0xc7, 0x06,
+    0x56, 0x42, 0x58, 0x99, 0x20, 0x41, 0x54, 0xb7, 0x51, 0xcd, 0xe4, 0x7b, 0x28, 0x43, 0x68, 0x76,
+    0x45, 0x7e, 0xbd, 0xa6, 0x7c, 0x7f, 0x59, 0x5d, 0xd8, 0xfe, 0x3d, 0x2e, 0xff, 0xc3, 0xf6, 0x19,
+    0xa1, 0xf8, 0xba, 0xda, 0x79, 0x25, 0x2d, 0x3f, 0x3c, 0xc0, 0xf4, 0x77, 0x92, 0x52, 0xca, 0xe8,
+    0x61, 0xd1, 0xe8, 0xb1, 0x21, 0xd6, 0xfd, 0xe2, 0x02, 0x75, 0x32, 0xda, 0x41, 0x98, 0xf5, 0x5c,
+    0xe0, 0xd3, 0xbc, 0xd8, 0xab, 0x13, 0x20, 0x91, 0xee, 0x4c, 0xca, 0x27, 0x8c, 0x18, 0x11, 0x51,
+    0x43, 0x07, 0xd4, 0x66, 0x6a, 0x33, 0x9a, 0x52, 0xb7, 0xde, 0x76, 0xc7, 0x26, 0xc7, 0x9b, 0x37,
+    0xb2, 0x77, 0x6a, 0xb3, 0x0f, 0xc7, 0xe8, 0xad, 0x7e, 0x36, 0x38, 0xad, 0x78, 0xf1, 0xba, 0x1f,
+    0x31, 0x2d, 0x6a, 0x1f, 0x05, 0xa7, 0x51, 0x79, 0x6a, 0x51, 0xb3, 0x1c, 0xc8, 0xda, 0x86, 0x6c,
+    0xb7, 0xfc, 0xe8, 0x21, 0x8f, 0x68, 0xb5, 0x84, 0x97, 0x49, 0xd9, 0x78, 0xb3, 0x52, 0xef, 0xa4,
+    0x19, 0x76, 0xab, 0x48, 0xe0, 0x28, 0x27, 0xb9, 0x5c, 0x86, 0x6d, 0x62, 0x3c, 0xa1, 0x9d, 0x23,
+    0x7f, 0x75, 0xef, 0x39, 0x01, 0x2d, 0xa3, 0x3a, 0x1e, 0xee, 0x79, 0x1f, 0xa7, 0xd9, 0x3a, 0x74,
+    0x62, 0x87, 0x89, 0xf9, 0x79, 0x60, 0x1b, 0xd4, 0xea, 0x44, 0x5d, 0x35, 0x28, 0x98, 0x96, 0x40,
+    0xb6, 0xfb, 0xea, 0x26, 0x51, 0x44, 0x8b, 0x90, 0xfd, 0xe9, 0xc9, 0x26, 0x2a, 0xb3, 0xf8, 0x0e,
+    0x5f, 0x8c, 0x28, 0x65, 0x5d, 0x94, 0x5a, 0x07, 0x35, 0x46, 0x00, 0x47, 0xa2, 0x4c, 0xd7, 0xb3,
+    0xbd, 0x03, 0xa2, 0xe5, 0x81, 0xc0, 0xb0, 0xaa, 0x2d, 0xb5, 0xa8, 0x3a, 0x03, 0x2b, 0xa3, 0x3c,
+    0x9b, 0x69, 0xde, 0x56, 0x53, 0x22, 0xd9, 0x39, 0x80, 0x19, 0xfe, 0x89, 0x9b, 0xe0, 0xaf, 0xdc,
+    0x83, 0x39, 0xdd, 0x6c, 0xea, 0xc0, 0x4d, 0x94, 0x1f, 0xb2, 0xf5, 0xba, 0x7c, 0xf4, 0xa1, 0x83,
+    0x36, 0x3d, 0xb4, 0xaa, 0xe2, 0x45, 0xd2, 0xf1, 0xef, 0xf4, 0xa1, 0x95, 0xec, 0x3f, 0x59, 0xfc,
+    0x66, 0x51, 0xe1, 0x86, 0x73, 0x20, 0xfc, 0xa4, 0xa3, 0xe7, 0xcc, 0xbd, 0x67, 0x5a, 0x8e, 0xee,
+    0x22, 0x2f, 0x43, 0x50, 0x78, 0x96, 0xb0, 0xfb, 0x2a, 0x7f, 0x81, 0x88, 0x8d, 0xa0, 0xc5, 0xda,
+    0x14, 0x15, 0x1e, 0x20, 0x2e, 0x60, 0x80, 0xbc, 0x06, 0x2d, 0x87, 0xbf, 0xd4, 0xde, 0x00, 0x42,
+    0x65, 0x67, 0x6e, 0xe0, 0xe7, 0xea, 0x0a, 0x29, 0x68, 0x88, 0x8e, 0xd2, 0x05, 0x06, 0x1e, 0x2a,
+    0x2c, 0x53, 0xea, 0x3a, 0x64, 0x6e, 0x93, 0xdd, 0xea, 0xfa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x10, 0x18, 0x1e, 0x26,
+    0x2c, 0x33, 0x3a,
+];
+
+const PUB_KEY_2: [u8; 2592] = [
+    0x81, 0x7c, 0x29, 0x72, 0xda, 0x4d, 0x8f, 0xf6, 0x04, 0x3c, 0x4b, 0x44, 0x99, 0x20, 0x52, 0x69,
+    0xa0, 0xdb, 0x1d, 0x08, 0xd2, 0xb4, 0x65, 0x34, 0x0c, 0xac, 0x07, 0x1a, 0x89, 0x26, 0xfd, 0x47,
+    0x8e, 0x34, 0x20, 0x7b, 0x60, 0xdb, 0x87, 0xbf, 0x9b, 0x21, 0xb5, 0x9c, 0x4b, 0xe1, 0x22, 0x55,
+    0x3d, 0x24, 0x92, 0x80, 0x6e, 0xec, 0xa6, 0xc9, 0x31, 0x10, 0x39, 0x96, 0xbc, 0x21, 0xa2, 0x09,
+    0xc6, 0x66, 0xf2, 0xae, 0x09, 0x22, 0x28, 0xa0, 0x67, 0x35, 0x6f, 0x50, 0xb6, 0x02, 0x49, 0x79,
+    0xd3, 0x22, 0x06, 0x48, 0xdd, 0x7f, 0x46, 0x4a, 0x9b, 0xd4, 0x87, 0xf0, 0x3f, 0x1b, 0x3c, 0xa8,
+    0x32, 0x08, 0xfb, 0xe5, 0x3a, 0xe1, 0x31, 0x57, 0x78, 0x98, 0x53, 0xa3, 0x46, 0x90, 0x60, 0xa6,
+    0x8f, 0x0c, 0x31, 0x90, 0x58, 0x49, 0x1e, 0x3f, 0x5d, 0x37, 0xfb, 0xe5, 0x2c, 0x71, 0x1f, 0xfc,
+    0xcf, 0x70, 0x8a, 0x67, 0xb1, 0x40, 0x9a, 0xc8, 0x5d, 0x73, 0x14, 0x64, 0x30, 0xb3, 0xce, 0x49,
+    0x59, 0x95, 0x6f, 0x46, 0x1f, 0x51, 0x3b, 0x62, 0x03, 0x97, 0xb2, 0xde, 0x18, 0x2e, 0xbd, 0x34,
+    0xf8, 0xd1, 0xe3, 0xcb, 0xfb, 0x7f, 0x46, 0x12, 0xc7, 0x04, 0x8a, 0x53, 0x75, 0x0c, 0xa0, 0x34,
+    0x95, 0xb9, 0xdb, 0x60, 0x95, 0x90, 0x1d, 0x62, 0x7a, 0x11, 0x6d, 0xc4, 0xbc, 0xa7, 0xcf, 0xe8,
+    0xa3, 0x44, 0xd5, 0xf0, 0x87, 0x4c, 0x01, 0x9f, 0xd8, 0x57, 0x3a, 0x65, 0xb7, 0xbb, 0x21, 0x2d,
+    0x51, 0x7b, 0x42, 0xa9, 0xec, 0x16, 0x79, 0x45, 0x8c, 0x4f, 0x20, 0x1c, 0xae, 0xd2, 0x0e, 0x84,
+    0x6f, 0x8a, 0xa3, 0xc1, 0xba, 0xcc, 0xdd, 0x61, 0x4a, 0xb3, 0x6c, 0x2b, 0x15, 0x9d, 0x58, 0xe8,
+    0x6f, 0xe6, 0x81, 0xaf, 0x5d, 0x96, 0x5d, 0x49, 0xb4, 0x77, 0xc3, 0x4c, 0x1b, 0x14, 0xaa, 0x09,
+    0x4a, 0x5b, 0xa1, 0xad, 0x6b, 0xcc, 0x18, 0xff, 0x62, 0xfb, 0xb9, 0x22, 0x06, 0x89, 0x07, 0x57,
+    0x21, 0xec, 0xbb, 0x8d, 0x83, 0x66, 0x46, 0x57, 0xdc, 0x0e, 0x55, 0xe6, 0x12, 0xb1, 0xf4, 0x46,
+    0xee, 0x2d, 0x98, 0xe3, 0xd7, 0xbf, 0xe6, 0x5b, 0x41, 0xba, 0x50, 0x3e, 0xe6, 0xbe, 0x8c, 0x77,
+    0xf8, 0x58, 0xb3, 0x28, 0xbc, 0x2a, 0x9e, 0x6c, 0xa9, 0xcb, 0xa0, 0x89, 0x52, 0xc4, 0x1f, 0x04,
+    0x4e, 0x10, 0xe2, 0x77, 0x3d, 0xf9, 0x13, 0x15, 0xbc, 0x53, 0xa0, 0x4f, 0x97, 0x18, 0xe4, 0x6b,
+    0xef, 0x15, 0xe8, 0x8a, 0x06, 0x3c, 0x02, 0xce, 0x62, 0x38, 0x7d, 0x85, 0x18, 0x80, 0xb8, 0x43,
+    0x73, 0xca, 0xa3, 0xdf, 0x08, 0xf5, 0xe1, 0x08, 0x51, 0xbf, 0x0b, 0xb6, 0x19, 0xf3, 0x55, 0x26,
+    0xf2, 0xda, 0x67, 0x12, 0xbf, 0x34, 0xc8, 0xa2, 0xbf, 0xe1, 0xf4, 0xbb, 0xa7, 0x88, 0x3e, 0xc8,
+    0x2a, 0xa7, 0xee, 0xf1, 0x6f, 0x92, 0xce, 0x72, 0x5f, 0x02, 0xe8, 0x5b, 0x08, 0x8e, 0x6c, 0xf2,
+    0x4a, 0xdf, 0x77, 0x6a, 0x65, 0x61, 0x2e, 0xcc, 0x97, 0xe1, 0x19, 0x08, 0xe5, 0xd3, 0x18, 0xf4,
+    0x92, 0x47, 0xac, 0x7d, 0xeb, 0xe2, 0x62, 0x91, 0x7d, 0x78, 0xad, 0x2e, 0x90, 0x91, 0xa3, 0xd0,
+    0x90, 0x6d, 0xcd, 0x42, 0x05, 0x12, 0x46, 0xcd, 0x24, 0x62, 0xfb, 0x80, 0x68, 0x1c, 0xd6, 0x31,
+    0xa6, 0x86, 0x27, 0x7f, 0xef, 0x5c, 0x52, 0xbe, 0xd4, 0x9e, 0x2a, 0xf6, 0xcb, 0x33, 0x5d, 0x5b,
+    0xd7, 0x19, 0x36, 0xdc, 0x97, 0xae, 0x5b, 0x50, 0xac, 0x97, 0x1a, 0x16, 0x26, 0x14, 0xd6, 0x7c,
+    0xd1, 0x90, 0xb2, 0x45, 0xa8, 0x34, 0x93, 0x32, 0x16, 0x50, 0x2f, 0x5d, 0xa1, 0x01, 0xd4, 0x6e,
+    0x06, 0x8b, 0x66, 0xb3, 0xcb, 0x46, 0xaa, 0xe8, 0x94, 0x35, 0xd6, 0x61, 0xbb, 0xe6, 0x3d, 0xbf,
+    0x54, 0xe6, 0x4a, 0x7d, 0xd1, 0xcd, 0xd0, 0xa5, 0x83, 0x11, 0x37, 0x59, 0x2a, 0x58, 0x55, 0xc3,
+    0x0b, 0x5a, 0x66, 0xb2, 0xed, 0x44, 0xe8, 0x02, 0xb7, 0x94, 0x15, 0x54, 0x97, 0x66, 0x31, 0x82,
+    0xe6, 0xb4, 0x7c, 0xce, 0xaf, 0x43, 0x60, 0x69, 0x91, 0xc4, 0x53, 0xab, 0x89, 0x79, 0x2c, 0xeb,
+    0xb8, 0x52, 0x54, 0xca, 0xb0, 0xa7, 0x49, 0xea, 0x33, 0x64, 0x99, 0x1a, 0xd6, 0xeb, 0x1c, 0x5e,
+    0x0d, 0x24, 0xa9, 0x63, 0xfd, 0x95, 0x22, 0x4b, 0x9c, 0x1d, 0x5f, 0x36, 0x8b, 0x6f, 0x54, 0x41,
+    0x95, 0x34, 0x26, 0x8c, 0x79, 0xa1, 0x67, 0x0f, 0xe8, 0xaa, 0x95, 0xd8, 0x90, 0x08, 0x04, 0x33,
+    0x72, 0x2a, 0xd9, 0xd8, 0x4a, 0x16, 0xe7, 0x92, 0x49, 0x5b, 0x65, 0xf2, 0x1f, 0x4e, 0x68, 0xa4,
+    0xbb, 0x21, 0xda, 0xf8, 0x2c, 0x54, 0x17, 0x1a, 0xa4, 0x42, 0x2c, 0x9c, 0xec, 0xee, 0x60, 0x3c,
+    0x61, 0x77, 0xf4, 0xe0, 0xd9, 0x83, 0x57, 0xd7, 0xcb, 0x05, 0xd2, 0xb9, 0xc6, 0x1d, 0xc4, 0xff,
+    0xe1, 0x97, 0x83, 0xce, 0xde, 0x43, 0x26, 0xb4, 0x9a, 0x7b, 0x71, 0xe8, 0x9d, 0x61, 0x38, 0xc2,
+    0xb2, 0xd1, 0x56, 0xf5, 0xe8, 0xcc, 0x27, 0x20, 0xcb, 0xc6, 0xb4, 0xab, 0x97, 0x57, 0xd8, 0xec,
+    0xe1, 0xa4, 0x84, 0xb1, 0xa8, 0xa7, 0x1c, 0x4a, 0xda, 0x76, 0xa6, 0xe6, 0x01, 0xda, 0xbb, 0x01,
+    0x96, 0x12, 0x95, 0x90, 0xaf, 0x6c, 0x56, 0x4f, 0xcf, 0x0f, 0x27, 0x77, 0x2b, 0x2e, 0xe0, 0xec,
+    0x7e, 0x68, 0xa5, 0xab, 0x57, 0xf8, 0x89, 0x2c, 0xdc, 0x9a, 0xd5, 0xd1, 0x06, 0x42, 0x9b, 0x25,
+    0x80, 0x5a, 0xa4, 0xc5, 0x7f, 0xc4, 0xd1, 0x9e, 0x6f, 0x5d, 0xd0, 0xe3, 0xef, 0x1a, 0xed, 0x84,
+    0x79, 0xab, 0x68, 0xe7, 0xbb, 0x1b, 0x3a, 0xff, 0x99, 0x48, 0x7e, 0x76, 0x69, 0x8b, 0x5b, 0x05,
+    0x28, 0x6f, 0x96, 0x0c, 0x1d, 0x78, 0xf5, 0xf7, 0xbf, 0x2e, 0x75, 0x78, 0xf3, 0xbc, 0x03, 0x87,
+    0x72, 0x5a, 0x72, 0x01, 0xea, 0x68, 0x18, 0xc3, 0x12, 0x14, 0x69, 0xfe, 0xf5, 0x2d, 0xdb, 0xe5,
+    0xf6, 0x1b, 0xe8, 0x8a, 0x07, 0x82, 0x68, 0xb1, 0x12, 0x78, 0xe8, 0xbc, 0x11, 0xce, 0x09, 0xf4,
+    0x71, 0x2a, 0x20, 0xe0, 0x3f, 0xa2, 0x9d, 0x70, 0x24, 0xea, 0x9c, 0xff, 0x16, 0x80, 0xe9, 0xa0,
+    0xc6, 0xe9, 0xc5, 0xf6, 0x1e, 0x1f, 0x87, 0xf6, 0xd7, 0xf9, 0xb0, 0x10, 0x54, 0xf3, 0xd2, 0xa4,
+    0xf8, 0xc0, 0xbf, 0x91, 0x47, 0xa1, 0xdc, 0x3e, 0x45, 0x6b, 0xce, 0x62, 0xd9, 0xef, 0x87, 0xd8,
+    0x1a, 0xef, 0xee, 0xa5, 0x24, 0xe8, 0x1f, 0x82, 0xe0, 0xcf, 0xf4, 0x76, 0xb1, 0x9b, 0x29, 0x60,
+    0xde, 0xf5, 0xd9, 0xae, 0xfd, 0xd0, 0xa6, 0x0f, 0xf3, 0x2a, 0x34, 0x07, 0xfc, 0x2f, 0x07, 0xd6,
+    0x5c, 0x32, 0xe5, 0x51, 0x8f, 0xf5, 0x6f, 0x49, 0x55, 0x42, 0x55, 0x53, 0x43, 0xa8, 0xe1, 0x0a,
+    0xff, 0x4c, 0x5f, 0x4e, 0x0b, 0xd7, 0x94, 0x53, 0xa0, 0x06, 0xb8, 0xc9, 0xc8, 0x2f, 0x85, 0x97,
+    0x45, 0x74, 0x95, 0xd1, 0x8c, 0xaa, 0x9a, 0x56, 0x45, 0xae, 0xab, 0x30, 0x38, 0xba, 0x50, 0x36,
+    0xc5, 0xd4, 0x70, 0x88, 0x0d, 0x22, 0x22, 0x36, 0x46, 0x4a, 0xda, 0x7e, 0xf3, 0xd7, 0x1c, 0x16,
+    0x55, 0x72, 0xf3, 0x87, 0x33, 0x01, 0xf4, 0x94, 0x9b, 0x7e, 0xeb, 0x72, 0x7c, 0xce, 0x43, 0xf4,
+    0x63, 0x57, 0xbc, 0x64, 0xc8, 0x99, 0x65, 0x96, 0x95, 0xf6, 0x81, 0xd1, 0x00, 0x16, 0xea, 0x04,
+    0x01, 0x59, 0x51, 0xfd, 0xf0, 0xeb, 0xe2, 0x10, 0x3b, 0xaf, 0x4f, 0xf4, 0xbc, 0x2d, 0x2c, 0xb7,
+    0xb3, 0xd2, 0x6b, 0x11, 0xb9, 0x7c, 0xcf, 0x50, 0xb6, 0xca, 0xd9, 0x1d, 0xa9, 0x52, 0x61, 0x76,
+    0x72, 0x09, 0x9b, 0x9f, 0x95, 0x01, 0x37, 0x0e, 0xbc, 0x81, 0x79, 0xae, 0x07, 0x94, 0x9e, 0xb0,
+    0x0b, 0x0e, 0x50, 0x7a, 0xe9, 0x58, 0xe0, 0x2b, 0x66, 0x96, 0x1f, 0x98, 0x4b, 0xfa, 0xb1, 0xfc,
+    0x1d, 0x3c, 0x8f, 0xe3, 0x9a, 0xc4, 0x8e, 0xa7, 0x9d, 0x19, 0x98, 0xf3, 0x18, 0xf5, 0x7f, 0x1b,
+    0xc0, 0xe6, 0xef, 0x7a, 0xc0, 0xad, 0x5d, 0xc3, 0xfc, 0xe9, 0xd0, 0xbf, 0x2a, 0xc8, 0xe2, 0xee,
+    0x7e, 0xc7, 0xb6, 0x42, 0xcb, 0x0f, 0xda, 0x5e, 0xc2, 0xff, 0xe2, 0x1b, 0x00, 0x35, 0xa3, 0x25,
+    0xed, 0x46, 0x41, 0x0e, 0x78, 0x55, 0x3b, 0x1a, 0x81, 0xab, 0x9c, 0xaa, 0xd7, 0x8a, 0xa0, 0xfc,
+    0xf0, 0x97, 0x26, 0x05, 0xc2, 0x62, 0xbf, 0xc0, 0xec, 0xf2, 0x79, 0x0c, 0x80, 0x53, 0xab, 0x51,
+    0x3b, 0x96, 0xb4, 0xc5, 0xfe, 0x7b, 0xb9, 0x71, 0x5d, 0x2b, 0x29, 0x16, 0x73, 0x8f, 0xb2, 0xf8,
+    0x31, 0x29, 0xe1, 0x80, 0x8c, 0xf2, 0xac, 0xee, 0x63, 0xee, 0x4c, 0xc2, 0xda, 0xdb, 0x98, 0x16,
+    0x68, 0xb0, 0x20, 0x4f, 0x1e, 0x7e, 0xce, 0x05, 0x9a, 0x5a, 0xfb, 0x51, 0xae, 0x1e, 0xf2, 0x45,
+    0xa5, 0x33, 0x9f, 0xd7, 0xb9, 0x98, 0x73, 0xa0, 0xab, 0x87, 0x27, 0x6b, 0x36, 0xd6, 0xfb, 0x82,
+    0x27, 0xfc, 0x81, 0x2f, 0x3f, 0x81, 0x9f, 0x76, 0xac, 0xfc, 0x2f, 0x9a, 0x4d, 0xd9, 0x73, 0x22,
+    0xf9, 0xd2, 0xe3, 0x45, 0x4a, 0x10, 0xb1, 0x33, 0xf0, 0x59, 0xbf, 0x83, 0x78, 0x9c, 0x27, 0xa7,
+    0x7c, 0x95, 0xcd, 0xd8, 0xf8, 0x62, 0x23, 0x13, 0x56, 0xa6, 0x07, 0x0c, 0x89, 0x78, 0x0b, 0x54,
+    0x70, 0x97, 0x45, 0x7c, 0xd0, 0x7e, 0xff, 0x5e, 0x47, 0x10, 0x28, 0x86, 0xfb, 0x72, 0x49, 0x8c,
+    0x2d, 0xfb, 0xcc, 0xbe, 0xa2, 0x32, 0x21, 0x93, 0xa7, 0xf2, 0x07, 0x9d, 0x45, 0x62, 0x84, 0x4e,
+    0x96, 0x3c, 0x21, 0x65, 0x24, 0x34, 0xf1, 0x40, 0xbb, 0x8f, 0xef, 0xa8, 0xab, 0xb8, 0x92, 0x60,
+    0x4a, 0x07, 0x2e, 0xea, 0x9b, 0xc4, 0xd7, 0x7f, 0xb7, 0xfa, 0x91, 0x1b, 0xc5, 0x39, 0xfd, 0xfe,
+    0xbf, 0xdd, 0xe9, 0xb4, 0xde, 0x6f, 0xfe, 0x07, 0xed, 0x47, 0x8d, 0x52, 0x5c, 0x4e, 0xd7, 0x71,
+    0x55, 0xc6, 0xf0, 0xc6, 0xe6, 0x4f, 0xc6, 0xef, 0xb2, 0xd3, 0x59, 0xe6, 0x79, 0x28, 0x48, 0x10,
+    0xfb, 0x9c, 0xeb, 0xe5, 0x1a, 0xa5, 0x86, 0x8d, 0x94, 0xe6, 0xee, 0x57, 0x5b, 0xdc, 0x6e, 0x00,
+    0x50, 0x4d, 0xe7, 0x0f, 0x39, 0x3d, 0x01, 0xea, 0xbb, 0xe0, 0x90, 0xa7, 0x2c, 0x92, 0x25, 0xbe,
+    0x22, 0x18, 0x22, 0x2e, 0x7e, 0x83, 0x6b, 0x31, 0xba, 0xa9, 0xa0, 0xf2, 0x33, 0x13, 0x2f, 0xbb,
+    0xf2, 0xc9, 0x8d, 0x9c, 0x43, 0x0c, 0x35, 0xbb, 0xf3, 0xdd, 0x6c, 0x9e, 0x89, 0x77, 0x29, 0x68,
+    0x0f, 0x88, 0x3d, 0xa3, 0xdb, 0xd1, 0x9c, 0x57, 0x2c, 0x0e, 0x88, 0x71, 0x7e, 0x2c, 0x7d, 0x44,
+    0x51, 0xf0, 0xf1, 0xd6, 0x5f, 0x87, 0x08, 0xc3, 0xc5, 0x80, 0xb1, 0xe1, 0x39, 0x5e, 0x46, 0x80,
+    0x01, 0xfd, 0xcc, 0x4f, 0x83, 0x80, 0x30, 0xf9, 0x77, 0x34, 0x12, 0x9e, 0x78, 0xb3, 0xe5, 0x87,
+    0xa4, 0xd8, 0x84, 0x63, 0x0a, 0xcc, 0xbf, 0xca, 0x1a, 0x79, 0x87, 0xc1, 0xaa, 0x05, 0xf1, 0x71,
+    0xba, 0x09, 0x34, 0x74, 0x52, 0x39, 0x0f, 0xc8, 0xfe, 0xfa, 0x91, 0x5e, 0xeb, 0xb9, 0x5c, 0xba,
+    0x7c, 0xd5, 0x41, 0x33, 0x78, 0x9c, 0x21, 0xd4, 0x27, 0x20, 0xa7, 0x75, 0xfa, 0x38, 0x4d, 0x99,
+    0x89, 0x3e, 0xa1, 0x22, 0x7b, 0xf3, 0xae, 0xd5, 0x25, 0x8c, 0xc9, 0xc5, 0x11, 0x2b, 0x17, 0x05,
+    0x87, 0xa2, 0x63, 0xa5, 0xbb, 0x47, 0x75, 0x61, 0x9d, 0xe0, 0x43, 0x4d, 0x8a, 0x37, 0x82, 0x30,
+    0xa6, 0xa1, 0x08, 0xa6, 0xb0, 0x43, 0xd0, 0xa9, 0xd9, 0x5f, 0x13, 0x79, 0x1d, 0x66, 0x19, 0xc1,
+    0x5a, 0x15, 0x73, 0x43, 0x65, 0x17, 0x8c, 0x35, 0xe6, 0xa7, 0xdc, 0x69, 0xc2, 0xc7, 0xfd, 0x2c,
+    0xda, 0xc0, 0x48, 0x46, 0x94, 0x9f, 0x23, 0x47, 0xd9, 0xc1, 0x67, 0xe8, 0xb2, 0x15, 0x84, 0x94,
+    0x08, 0x98, 0xb4, 0xe4, 0x9f, 0x7e, 0x4f, 0xe8, 0x4e, 0xe3, 0x41, 0xb1, 0x39, 0x28, 0xd9, 0xe8,
+    0x85, 0xeb, 0xa3, 0xf7, 0x6d, 0x8f, 0x6b, 0x9c, 0x27, 0xc0, 0x10, 0x06, 0x0c, 0x32, 0x9f, 0x90,
+    0x56, 0x52, 0x1d, 0xa7, 0x70, 0xc9, 0xe1, 0xb8, 0x55, 0x64, 0xbc, 0xbc, 0x33, 0xa7, 0x16, 0x75,
+    0x8f, 0x5f, 0x75, 0xfa, 0xb1, 0x3f, 0xe9, 0xeb, 0xc2, 0xac, 0xb3, 0x8e, 0x91, 0x28, 0xf5, 0x95,
+    0x88, 0x17, 0xd0, 0xa1, 0x2d, 0xc3, 0xfb, 0x60, 0x6a, 0xd9, 0x8e, 0x4e, 0xfc, 0x5c, 0xf8, 0x96,
+    0xa8, 0xe5, 0xf1, 0x32, 0x70, 0x96, 0xb4, 0x69, 0xbc, 0x35, 0xa2, 0xbc, 0x08, 0x25, 0x0c, 0x09,
+    0xde, 0x3f, 0x79, 0x2e, 0x80, 0x89, 0x10, 0x45, 0x96, 0x0a, 0x9d, 0x0b, 0xae, 0xf4, 0xfd, 0x25,
+    0xb3, 0xdd, 0x5e, 0x5c, 0x25, 0x17, 0x2d, 0x9a, 0x6c, 0x5f, 0x35, 0xa8, 0xf2, 0xf6, 0xfa, 0xb6,
+    0x34, 0xbb, 0xbf, 0xfb, 0xfd, 0xf7, 0xd9, 0x6c, 0x50, 0xd9, 0x22, 0xfb, 0x11, 0x9a, 0x85, 0x52,
+    0xf2, 0xda, 0x78, 0x17, 0x02, 0xbe, 0xd5, 0xad, 0x8a, 0x70, 0xa2, 0xad, 0x78, 0x18, 0x5b, 0x40,
+    0x76, 0x5b, 0x13, 0xd3, 0x25, 0xa1, 0x71, 0x1e, 0x7e, 0x70, 0x63, 0x0e, 0x89, 0x2a, 0x01, 0xf1,
+    0x43, 0x2b, 0x67, 0xc5, 0x79, 0x17, 0x0a, 0x34, 0x6d, 0x8f, 0x02, 0x01, 0x3d, 0xa8, 0xf3, 0x22,
+    0xa2, 0x7e, 0x50, 0xdc, 0xac, 0x35, 0x8a, 0xcc, 0x3f, 0xc1, 0xb5, 0x79, 0x7b, 0x1f, 0xad, 0x0c,
+    0xc1, 0xe5, 0x40, 0xd3, 0xd8, 0xf0, 0x85, 0x9e, 0x3b, 0xc9, 0x1e, 0xd8, 0xd0, 0x96, 0xf3, 0x37,
+    0xa4, 0x2d, 0xcd, 0x84, 0xc3, 0xa2, 0x14, 0x4d, 0x9a, 0xa6, 0xdc, 0x8e, 0x9e, 0xde, 0xa5, 0xe3,
+    0x49, 0xed, 0xc9, 0xda, 0x2e, 0xf2, 0xd8, 0xac, 0x1c, 0x2e, 0x7b, 0x77, 0x9e, 0x9d, 0x46, 0x7b,
+    0x5d, 0x25, 0xdb, 0xcb, 0x2f, 0xee, 0x6c, 0x4d, 0x4e, 0x21, 0x9c, 0xc2, 0xd3, 0x5f, 0xaf, 0x70,
+    0x51, 0xea, 0x5d, 0x97, 0xe7, 0xe6, 0x17, 0x04, 0xa0, 0x4d, 0x1e, 0x17, 0xec, 0x24, 0xa0, 0xfb,
+    0x0a, 0xe0, 0x4d, 0x7f, 0xec, 0xa0, 0xdb, 0x85, 0x75, 0x31, 0xfa, 0xfd, 0xc9, 0x19, 0x0e, 0xa2,
+    0x88, 0x32, 0x39, 0xeb, 0x51, 0x68, 0x33, 0x93, 0xb4, 0x0d, 0x18, 0xd2, 0x6d, 0xd3, 0xae, 0xc4,
+    0xcc, 0xb1, 0xa6, 0xc8, 0x66, 0xe9, 0x48, 0xa7, 0x4f, 0xda, 0xac, 0xfe, 0x1e, 0x29, 0x5d, 0xa7,
+    0x38, 0x0f, 0x1f, 0x70, 0xc6, 0xf2, 0xe6, 0x54, 0x99, 0xfd, 0x64, 0x99, 0x28, 0xc4, 0xb1, 0x51,
+    0xf0, 0x90, 0x56, 0xdf, 0xe8, 0x62, 0xe7, 0x87, 0xc1, 0xcd, 0x78, 0x6d, 0xba, 0x29, 0x5c, 0xa7,
+    0xc6, 0xe7, 0xe0, 0xca, 0xcf, 0xc7, 0x46, 0xb9, 0xe2, 0x10, 0xbc, 0xc1, 0x13, 0x08, 0xfd, 0xff,
+    0xbc, 0x72, 0xc7, 0xb3, 0xd9, 0x04, 0x74, 0x29, 0x45, 0xdb, 0x27, 0x83, 0x00, 0x74, 0x81, 0x19,
+    0xf9, 0xe5, 0x40, 0x3b, 0x8e, 0x8a, 0x63, 0x21, 0x04, 0x28, 0x2d, 0xa4, 0x8e, 0xa5, 0x17, 0x89,
+    0x6f, 0xba, 0x6c, 0x6b, 0x61, 0x76, 0x35, 0x4c, 0x9f, 0x79, 0x1f, 0xd4, 0x77, 0x02, 0x62, 0xa0,
+    0x77, 0x45, 0x42, 0x1f, 0x5b, 0xf5, 0x8f, 0xc6, 0x1e, 0x14, 0x6a, 0xc6, 0x59, 0x34, 0x73, 0x89,
+    0x21, 0x11, 0x72, 0x4e, 0xb0, 0x0a, 0x45, 0xc7, 0x42, 0xfb, 0x60, 0xae, 0xa1, 0x8e, 0x35, 0xd6,
+    0x20, 0xc3, 0xd7, 0x1f, 0xc9, 0x76, 0x7f, 0xcf, 0x2e, 0xf4, 0x37, 0xc0, 0x8e, 0x1d, 0x34, 0x2d,
+    0x3e, 0xd3, 0xe0, 0xb3, 0xb7, 0x3f, 0x59, 0x90, 0x6f, 0x9c, 0xfc, 0x13, 0x2d, 0x46, 0x4d, 0x3b,
+    0xb3, 0xe3, 0xbc, 0x59, 0x4a, 0x9f, 0x9c, 0x44, 0xe8, 0x33, 0xfc, 0xe5, 0x8f, 0x99, 0xee, 0x12,
+    0x8b, 0xe7, 0xf0, 0xc7, 0x94, 0x7b, 0xbd, 0x5b, 0x2e, 0xb8, 0xe9, 0x88, 0x02, 0x4e, 0x4e, 0xfc,
+    0xde, 0x3b, 0x56, 0x88, 0x9e, 0xd8, 0x26, 0x58, 0xf3, 0x09, 0x30, 0x26, 0x65, 0x3e, 0x16, 0x31,
+    0x25, 0xe3, 0x2a, 0xc4, 0x54, 0x60, 0x22, 0x8a, 0x76, 0xdc, 0xe9, 0x0b, 0x37, 0x30, 0xa4, 0x72,
+    0xb3, 0x41, 0xc3, 0x06, 0xd4, 0x92, 0xd0, 0x5a, 0x1c, 0x94, 0x24, 0xff, 0xc0, 0xe3, 0x20, 0x93,
+    0xc8, 0xa3, 0xe8, 0x76, 0xc6, 0xb3, 0x77, 0x43, 0x76, 0x2f, 0x3d, 0xc9, 0xef, 0x8a, 0x11, 0x4c,
+    0xd9, 0x9f, 0x6e, 0xd0, 0x9c, 0x44, 0x15, 0xe4, 0x5b, 0xad, 0x0d, 0x98, 0x89, 0xd4, 0x44, 0x03,
+    0x60, 0x49, 0x7c, 0xcc, 0x66, 0xe9, 0xae, 0x9b, 0x22, 0x8d, 0xa2, 0x7f, 0x54, 0xee, 0x1d, 0x04,
+    0x6d, 0xa5, 0xb2, 0xea, 0x84, 0xad, 0x33, 0xc8, 0xe1, 0xbd, 0xdb, 0x04, 0x50, 0xcc, 0x41, 0xa0,
+    0xc5, 0xdb, 0xd5, 0x12, 0xce, 0xf1, 0x8e, 0x3e, 0xae, 0xb5, 0x82, 0xc4, 0xbe, 0x70, 0xce, 0x3a,
+    0x1e, 0x22, 0x6a, 0xf0, 0x9a, 0x80, 0x3e, 0xc6, 0xe1, 0x6b, 0x91, 0xbd, 0xf9, 0xa4, 0xe1, 0x40,
+    0x2e, 0xff, 0x47, 0x96, 0x91, 0xf8, 0xe0, 0x49, 0x0f, 0x81, 0x8a, 0x08, 0xbb, 0x13, 0xa9, 0x53,
+    0xcf, 0x97, 0xcf, 0x45, 0xe4, 0x33, 0x50, 0x82, 0x2a, 0x44, 0xa2, 0x5a, 0x99, 0x61, 0x89, 0xa3,
+    0x83, 0xed, 0x12, 0x62, 0xf8, 0x8b, 0xab, 0xb6, 0x01, 0xd2, 0xa9, 0x8b, 0x1a, 0x78, 0xef, 0x36,
+    0x32, 0x24, 0xfc, 0x8c, 0x79, 0x71, 0xac, 0xb3, 0x0f, 0x42, 0xa0, 0x1a, 0xa8, 0xe5, 0x82, 0x7e,
+    0x87, 0x53, 0x11, 0xc2, 0x12, 0x79, 0x72, 0xa9, 0xab, 0xb1, 0xa1, 0x64, 0x9a, 0x67, 0x9f, 0xaa,
+    0x33, 0x8d, 0xaa, 0x91, 0x14, 0xe8, 0xaa, 0x93, 0xb6, 0x2a, 0x13, 0x85, 0x2b, 0x33, 0xdb, 0x8c,
+    0x10, 0xc5, 0x19, 0x38, 0xff, 0xc3, 0x8a, 0x7c, 0x20, 0xdc, 0xcc, 0xef, 0xe9, 0x51, 0x76, 0x2d,
+    0x15, 0x46, 0x71, 0xc1, 0x9e, 0xc3, 0x95, 0x2a, 0xef, 0x58, 0x8a, 0xfa, 0x77, 0xd6, 0xad, 0x1f,
+    0x3f, 0x56, 0x1c, 0xda, 0x1e, 0xb8, 0xb9, 0xf1, 0xc9, 0x10, 0x5a, 0x7b, 0x5e, 0x8e, 0xf5, 0x08,
+    0x66, 0x99, 0xa6, 0x1a, 0x8d, 0xa8, 0x6c, 0xa0, 0x5b, 0xda, 0x1c, 0xd6, 0x97, 0xac, 0x6d, 0x1b,
+    0x77, 0x4a, 0x21, 0x32, 0x17, 0x30, 0xd9, 0xf4, 0x13, 0x8f, 0x84, 0xbe, 0x9e, 0x7a, 0xe2, 0x13,
+    0x1a, 0xac, 0x39, 0xa3, 0xda, 0x13, 0x40, 0x23, 0x6f, 0x0c, 0xbe, 0xe4, 0xf6, 0x02, 0x99, 0xa6,
+    0x6e, 0x49, 0xa3, 0x8e, 0x5c, 0xac, 0xa2, 0xaf, 0x1b, 0xb9, 0xa6, 0xb7, 0xb3, 0xd1, 0x3f, 0x90,
+    0xcd, 0x38, 0xa1, 0x30, 0xe6, 0xf9, 0xde, 0x99, 0x04, 0x82, 0xdf, 0x3c, 0xd1, 0xa3, 0xde, 0xa1,
+    0x69, 0x27, 0x7d, 0x10, 0x55, 0xa3, 0x0c, 0x90, 0x64, 0xbc, 0xb8, 0xaf, 0x26, 0x10, 0xae, 0xde,
+    0x3f, 0x16, 0xb6, 0x61, 0xda, 0x46, 0xab, 0xc7, 0xa9, 0xf2, 0x2e, 0xa4, 0xb9, 0xe9, 0xa6, 0x13,
+    0xe7, 0xfa, 0x7b, 0x6d, 0x89, 0xb6, 0xfe, 0xb6, 0xfd, 0xb9, 0xa1, 0x9f, 0x2f, 0xf2, 0xe2, 0xf6,
+    0x0c, 0xfa, 0xa9, 0x15, 0x60, 0x09, 0xd7, 0x40, 0x1b, 0x6f, 0x43, 0xb1, 0xf5, 0x20, 0x32, 0x59,
+    0xbb, 0x4d, 0x4d, 0x53, 0xb8, 0x9f, 0xa9, 0x48, 0xa6, 0x2b, 0x17, 0x0b, 0xd9, 0x56, 0xaa, 0x9e,
+    0x68, 0x74, 0x24, 0xde, 0xa9, 0xd4, 0xb4, 0x53, 0xb3, 0x05, 0xcd, 0xe2, 0x48, 0x6d, 0xb3, 0x7b,
+    0x81, 0xd6, 0x98, 0x4a, 0x58, 0x7c, 0x73, 0x7a, 0x0c, 0x3e, 0x53, 0x42, 0x68, 0x2f, 0x62, 0x47,
+];
+
+const MESSAGE_2: [u8; 48] = [
+    0x66, 0x53, 0x24, 0xdd, 0x74, 0xc5, 0x6a, 0xf0, 0x8a, 0x99, 0x10, 0x35, 0x20, 0x0b, 0x46, 0xa2,
+    0x18, 0x48, 0x58, 0xd0, 0xe9, 0x22, 0x95, 0x82, 0x81, 0x03, 0x7a, 0x1c, 0x52, 0x05, 0x11, 0xa6,
+    0x22, 0x79, 0x52, 0x6a, 0xda, 0x76, 0xb4, 0x5f, 0xed, 0xf2, 0x90, 0xdc, 0xb9, 0xe2, 0x66, 0xcc,
+];
+
+const SIGNATURE_2: [u8; 4627] = [
+    0x72, 0xc8, 0x97, 0xa7, 0x0d, 0x10, 0x48, 0xdc, 0x40, 0x87, 0xe9, 0x44, 0x3c, 0x80, 0x1b, 0xcf,
+    0xc9, 0xa8, 0xd8, 0x99, 0x61, 0x50, 0xd1, 0x9a, 0x46, 0x36, 0xf1, 0xbe, 0x53, 0xb0, 0xfd, 0x9a,
+    0xa7, 0x14, 0x24, 0x0d, 0xdc, 0x43, 0x09, 0x39, 0xce, 0x97, 0xc7, 0x1d, 0x87, 0x0d, 0x23, 0x62,
+    0xba, 0x93, 0x3f, 0xf1, 0x7e, 0x62, 0x7b, 0xc9, 0xfd, 0x01, 0xc0, 0x58, 0x53, 0x49, 0xee, 0x36,
+    0x33, 0x38, 0x55, 0xb3, 0x53, 0xa3, 0x5d, 0x5c, 0x8f, 0xa2, 0x53, 0xf5, 0x69, 0xae, 0x80, 0xf6,
+    0x63, 0xdb, 0x1d, 0x5e, 0x1d, 0x8e, 0x37, 0x0d, 0x07, 0xe7, 0xad, 0x16, 0x3e, 0x39, 0x77, 0xd6,
+    0x8f, 0x03, 0x3c, 0x90, 0x32, 0x0f, 0xa5, 0x86, 0x37, 0xa0, 0x75, 0x94, 0xbe, 0x92, 0x0e, 0x27,
+    0x8e, 0x5f, 0x76, 0x95, 0x95, 0x89, 0xd9, 0x32, 0xab, 0xbe, 0x4a, 0x6e, 0xf2, 0x6a, 0xa9, 0x08,
+    0x5a, 0x73, 0xdb, 0x0c, 0xa1, 0x3e, 0x58, 0x9e, 0x12, 0xa7, 0xc8, 0x4a, 0x45, 0xcd, 0xcf, 0xba,
+    0xc4, 0x73, 0x24, 0x5c, 0x7a, 0x1f, 0x75, 0x5a, 0x94, 0x69, 0x0b, 0x7f, 0xc8, 0xe0, 0x55, 0x97,
+    0x39, 0xb8, 0xea, 0x6d, 0x54, 0x1e, 0x2f, 0x0d, 0x29, 0xb7, 0xf3, 0xff, 0xac, 0x97, 0x87, 0x7f,
+    0xbc, 0xfb, 0x63, 0x3d, 0xf9, 0xa6, 0xe7, 0x47, 0xf7, 0x53, 0x01, 0x32, 0xfa, 0x92, 0x65, 0x79,
+    0x1e, 0x56, 0x7e, 0x09, 0x76, 0x60, 0x79, 0xcf, 0x19, 0xa2, 0x94, 0x70, 0x61, 0xb4, 0x30, 0xac,
+    0xfe, 0xd5, 0x4a, 0x8f, 0x91, 0x15, 0xd1, 0xa4, 0xec, 0x24, 0xc6, 0x1c, 0x79, 0x20, 0x5a, 0xb4,
+    0x02, 0x1d, 0xa3, 0xc6, 0x39, 0xd7, 0x9d, 0x2b, 0x99, 0x0d, 0x3c, 0x14, 0x79, 0xd9, 0xea, 0x4a,
+    0x01, 0xae, 0xee, 0x39, 0xec, 0x8e, 0x8b, 0x34, 0xea, 0xcf, 0x54, 0x68, 0x46, 0x1c, 0xb6, 0x87,
+    0x44, 0xea, 0xf1, 0xa9, 0xf4, 0x74, 0x5b, 0x9d, 0x1c, 0xc3, 0x90, 0xba, 0x5b, 0x41, 0x6e, 0x74,
+    0x8d, 0x8a, 0x5f, 0x72, 0x66, 0x61, 0xec, 0x97, 0x14, 0x6d, 0xb2, 0x7f, 0x45, 0x97, 0x59, 0x61,
+    0x4e, 0x37, 0x81, 0x36, 0x7d, 0x7e, 0x10, 0x6c, 0xa0, 0xc8, 0x60, 0x5c, 0xc9, 0x9b, 0x64, 0x5b,
+    0x8c, 0xba, 0x03, 0x63, 0xd3, 0xc6, 0x55, 0x17, 0xb9, 0x64, 0xbb, 0x44, 0xb9, 0x80, 0x60, 0x37,
+    0x54, 0xab, 0xa7, 0x69, 0x5b, 0xee, 0xe8, 0xd9, 0xe4, 0x6c, 0xd2, 0xa6, 0x2b, 0x76, 0xc1, 0xb2,
+    0xc5, 0x2a, 0xd6, 0x09, 0xcb, 0xd3, 0xe4, 0xac, 0x83, 0xb4, 0xba, 0x92, 0x85, 0x4c, 0x84, 0x0e,
+    0xa5, 0x2b, 0x41, 0x0f, 0x44, 0x99, 0xf8, 0x91, 0x82, 0xf9, 0x85, 0x6d, 0x2d, 0x6e, 0x3d, 0xe8,
+    0x32, 0x63, 0x0f, 0x66, 0x25, 0x09, 0x20, 0x4f, 0xb6, 0xcd, 0x5a, 0xab, 0xdc, 0x95, 0x36, 0xa1,
+    0x7d, 0x2b, 0x9f, 0xbb, 0x41, 0xeb, 0x87, 0x32, 0xcb, 0x7b, 0x3d, 0xa2, 0x4a, 0x58, 0x1e, 0xdd,
+    0xed, 0xd0, 0xdc, 0x69, 0xf5, 0xf5, 0x17, 0x24, 0xf2, 0xfe, 0xb3, 0xe8, 0x4d, 0xb6, 0x67, 0x57,
+    0x49, 0xd7, 0xbb, 0xfc, 0x31, 0x13, 0x70, 0xc7, 0x92, 0x50, 0xca, 0x09, 0xff, 0x9f, 0x3c, 0x5c,
+    0x71, 0x7d, 0x63, 0x9d, 0x3c, 0xd8, 0xb4, 0x1a, 0xde, 0x8f, 0x98, 0x9d, 0x8e, 0x05, 0x0a, 0x55,
+    0x11, 0x38, 0xae, 0x03, 0xda, 0x6e, 0xcb, 0xe5, 0x3a, 0xc2, 0x99, 0xbb, 0x6c, 0x24, 0xdc, 0x14,
+    0x3a, 0x51, 0x61, 0x88, 0x24, 0x4e, 0x01, 0x89, 0x8b, 0xbb, 0x7b, 0xa8, 0x87, 0x64, 0x25, 0x51,
+    0x69, 0x19, 0xb2, 0xf1, 0xeb, 0xa6, 0x51, 0x41, 0x4d, 0x1e, 0x0e, 0xa2, 0x1c, 0xa4, 0xbb, 0x64,
+    0x55, 0x23, 0xe0, 0x9b, 0x41, 0x77, 0xb2, 0xc2, 0x00, 0x03, 0xd0, 0x24, 0x6b, 0x6e, 0x18, 0x26,
+    0x23, 0xeb, 0x56, 0x28, 0x4f, 0x54, 0xb2, 0x78, 0xd8, 0x92, 0x2d, 0xbb, 0x79, 0x5d, 0x31, 0x25,
+    0x5c, 0x64, 0x25, 0xf3, 0x28, 0x27, 0xe1, 0x11, 0x0a, 0x67, 0x7f, 0x9c, 0xe7, 0xba, 0x71, 0x6b,
+    0xf3, 0x7c, 0x71, 0x39, 0xbc, 0xda, 0x87, 0xa0, 0x5d, 0x24, 0xc7, 0xcf, 0x24, 0x60, 0x14, 0xc3,
+    0xa9, 0xb3, 0xbf, 0xe3, 0xd7, 0xf2, 0xfc, 0xe8, 0xc3, 0xf4, 0x7d, 0x19, 0x61, 0xa3, 0x4b, 0x94,
+    0xd1, 0xa9, 0xd0, 0xb2, 0x3a, 0x7e, 0x8f, 0x7a, 0x72, 0x08, 0x2d, 0xd1, 0x40, 0x93, 0x3e, 0xe1,
+    0xb1, 0xde, 0xb7, 0xce, 0xac, 0x4d, 0x59, 0x2c, 0x95, 0x7f, 0x2f, 0xef, 0x92, 0x03, 0xe7, 0xba,
+    0x33, 0x09, 0x06, 0xb4, 0xa9, 0xa9, 0x13, 0x05, 0xf5, 0x85, 0x04, 0xdb, 0xaf, 0xda, 0x94, 0x7d,
+    0xc8, 0x12, 0x72, 0x02, 0x76, 0x30, 0xcc, 0x55, 0xea, 0x24, 0x63, 0x9e, 0x5c, 0x87, 0xe8, 0x59,
+    0xb3, 0xb6, 0xf0, 0xbb, 0xb2, 0xf7, 0x82, 0x7e, 0x04, 0x11, 0xca, 0x3d, 0x50, 0x99, 0x77, 0xc9,
+    0x81, 0x23, 0xbb, 0x3e, 0x2c, 0x9d, 0x34, 0x55, 0x76, 0x9c, 0x64, 0xb2, 0xa0, 0x9b, 0x1b, 0x2d,
+    0x06, 0x84, 0x97, 0x1a, 0x7f, 0xf5, 0x7b, 0x11, 0xd1, 0x4e, 0xa3, 0x38, 0x34, 0xda, 0x36, 0x95,
+    0x99, 0x95, 0x18, 0x19, 0x7a, 0x4d, 0x3e, 0xbc, 0x85, 0x3b, 0xc6, 0x11, 0x02, 0x42, 0x7a, 0xb1,
+    0x9e, 0xb0, 0x92, 0x42, 0x94, 0x47, 0x2b, 0x9d, 0x6e, 0xdc, 0x77, 0x26, 0xaf, 0xb3, 0xcb, 0x8b,
+    0xc5, 0xcc, 0x1e, 0x7b, 0x08, 0x06, 0xe4, 0xe5, 0x55, 0x72, 0x0a, 0x3f, 0xad, 0x18, 0x1d, 0x0b,
+    0x53, 0x4d, 0xf8, 0x9b, 0x27, 0xc5, 0xbe, 0x85, 0x1c, 0x4c, 0xab, 0xf1, 0x62, 0x3d, 0xca, 0x4b,
+    0x91, 0x62, 0x22, 0x20, 0x18, 0xef, 0x0b, 0x7d, 0x0d, 0x88, 0xf2, 0xb7, 0x82, 0x3c, 0x8f, 0x87,
+    0xec, 0x39, 0x81, 0x19, 0x30, 0xe2, 0x9f, 0x15, 0x05, 0x12, 0x22, 0x7b, 0x8e, 0x74, 0xa9, 0x6e,
+    0x7c, 0xb0, 0xbe, 0x74, 0x82, 0x47, 0x8f, 0x08, 0x9f, 0x23, 0xeb, 0xc2, 0x55, 0xd6, 0x54, 0xd1,
+    0x02, 0x6f, 0x19, 0x75, 0xe0, 0xd5, 0x7a, 0x58, 0x84, 0x20, 0xf5, 0xc2, 0x6b, 0x0d, 0x9a, 0xbe,
+    0xd9, 0xef, 0xc1, 0xb7, 0xfa, 0xd6, 0xcd, 0x35, 0xcd, 0x80, 0x0b, 0xf9, 0x0c, 0x95, 0x96, 0x93,
+    0xda, 0xc3, 0xcf, 0xb7, 0x75, 0xb5, 0xe2, 0xe0, 0x02, 0x32, 0xb3, 0xf6, 0x21, 0x66, 0x0d, 0x4b,
+    0xec, 0x4d, 0xc1, 0x7e, 0xd3, 0xc7, 0x7c, 0xeb, 0x32, 0x4b, 0xf8, 0xd9, 0x7f, 0x25, 0x50, 0xcd,
+    0x42, 0x86, 0xab, 0x01, 0x13, 0xde, 0xe8, 0x80, 0xed, 0xad, 0xbb, 0x18, 0x75, 0xaf, 0x7e, 0xc1,
+    0xcf, 0x58, 0xa1, 0x4f, 0xd8, 0x0c, 0xb0, 0x47, 0xde, 0x57, 0x0f, 0xfd, 0x27, 0x42, 0x95, 0xfc,
+    0x0a, 0x1b, 0x72, 0x18, 0xed, 0x3c, 0xaa, 0x66, 0x71, 0x5b, 0x06, 0x8b, 0xb7, 0xf5, 0x2f, 0x3f,
+    0xad, 0xdd, 0xbc, 0xc9, 0xef, 0xd8, 0xaf, 0x2f, 0x99, 0x9b, 0xf6, 0xa3, 0x5f, 0xb8, 0xab, 0xbd,
+    0x1c, 0xb9, 0x82, 0xe0, 0x68, 0x8e, 0x4c, 0x38, 0x60, 0x05, 0x04, 0x8d, 0x50, 0x13, 0xc4, 0xc3,
+    0xc1, 0x91, 0x79, 0xa6, 0xb1, 0x05, 0x04, 0x2c, 0xd0, 0xcd, 0xc0, 0xd8, 0x69, 0x2f, 0x01, 0x55,
+    0xf3, 0xd2, 0x74, 0x43, 0x1f, 0xbe, 0xc7, 0xd2, 0x20, 0x0a, 0xde, 0x22, 0xea, 0x2e, 0x37, 0x97,
+    0xf1, 0xb7, 0x9d, 0x9e, 0x29, 0x31, 0x77, 0xc1, 0x23, 0x45, 0x9e, 0xea, 0x16, 0xb6, 0xfc, 0x70,
+    0xd0, 0x17, 0x99, 0xbb, 0xd7, 0xeb, 0x0e, 0xfc, 0x9c, 0x92, 0x9a, 0xa7, 0x64, 0x73, 0x7e, 0x77,
+    0x10, 0x25, 0x26, 0x57, 0x24, 0x42, 0x0d, 0x89, 0x1b, 0x1c, 0x98, 0x1b, 0x20, 0x59, 0xee, 0x83,
+    0xb7, 0x14, 0x72, 0x8c, 0xd9, 0x6e, 0x02, 0xb1, 0x7b, 0xe9, 0x22, 0x21, 0xa1, 0x2a, 0x1f, 0x9e,
+    0xc6, 0xc6, 0x8a, 0x70, 0xb9, 0x9a, 0x61, 0x34, 0x71, 0x36, 0x1d, 0x26, 0xb0, 0xcc, 0x1a, 0x7c,
+    0x36, 0xcb, 0x5a, 0x35, 0xe6, 0x14, 0x84, 0xbc, 0x79, 0xbe, 0x4c, 0x5e, 0x04, 0x55, 0x58, 0x0e,
+    0x41, 0x23, 0xef, 0xf7, 0x29, 0x44, 0x5c, 0x6c, 0x70, 0x6f, 0x80, 0xfe, 0x0a, 0x24, 0x01, 0x89,
+    0x31, 0xc5, 0xd4, 0xc3, 0xff, 0x1a, 0xcd, 0x1e, 0x45, 0x5b, 0x57, 0x78, 0x25, 0xbc, 0x3f, 0x86,
+    0x41, 0x35, 0x11, 0x8a, 0x22, 0xa7, 0x9d, 0x04, 0x34, 0x7f, 0x9b, 0x5d, 0x71, 0x3a, 0x7f, 0x2c,
+    0x71, 0xfd, 0xe6, 0xb2, 0xa4, 0x6d, 0x13, 0x4f, 0x24, 0x9f, 0x33, 0x26, 0x19, 0x8b, 0x2e, 0x8e,
+    0xc7, 0x93, 0x37, 0xe8, 0x05, 0xa0, 0x77, 0x8b, 0xd8, 0xb7, 0xd7, 0x3c, 0x93, 0xa0, 0xfb, 0xc1,
+    0x23, 0xc1, 0xae, 0x8f, 0xbe, 0xaf, 0x65, 0x24, 0x35, 0xc5, 0x85, 0xaa, 0x1a, 0xd6, 0x70, 0x02,
+    0xc4, 0xd3, 0x8f, 0x5f, 0x22, 0xb1, 0x40, 0x0c, 0xcc, 0x2e, 0xd0, 0xcd, 0x14, 0x43, 0xf1, 0xc0,
+    0xde, 0x18, 0x9a, 0x61, 0xdd, 0x06, 0xb6, 0x34, 0x6d, 0xdb, 0xc9, 0x39, 0x43, 0xf2, 0x2b, 0xde,
+    0x78, 0x70, 0xaa, 0xab, 0xf2, 0xc7, 0x1a, 0xfa, 0x37, 0xbe, 0x50, 0x93, 0x27, 0xda, 0x2e, 0x56,
+    0x47, 0x46, 0x79, 0x1b, 0x2e, 0xfd, 0x18, 0x7f, 0x6e, 0x10, 0x5a, 0x04, 0x0c, 0xf3, 0xd8, 0x93,
+    0x3a, 0xc8, 0xdb, 0xa6, 0x27, 0x08, 0x52, 0xc4, 0x2a, 0x3a, 0x2e, 0x82, 0xbf, 0xe9, 0x9c, 0x8f,
+    0xcb, 0x3f, 0xa6, 0xd5, 0x53, 0x13, 0x21, 0xb2, 0x34, 0xc8, 0x54, 0xef, 0x93, 0x26, 0xd8, 0x3a,
+    0x16, 0xdf, 0x21, 0x0b, 0x13, 0xa4, 0x35, 0x39, 0x61, 0x3f, 0xa3, 0x14, 0x82, 0xf9, 0xbb, 0x1f,
+    0xca, 0x25, 0xe2, 0xa9, 0x9b, 0x4c, 0x20, 0x10, 0x80, 0x0b, 0x76, 0x19, 0x4d, 0xdb, 0x2b, 0xbf,
+    0x86, 0x87, 0x2a, 0x73, 0xb1, 0x33, 0xcd, 0xde, 0x7b, 0xa7, 0x27, 0x3b, 0x4e, 0x30, 0x84, 0xc8,
+    0x2c, 0x05, 0x57, 0x1a, 0x61, 0xb5, 0xdd, 0xde, 0xaf, 0xd7, 0x2d, 0x3a, 0xe1, 0xee, 0x3f, 0xa9,
+    0x01, 0x6a, 0x1a, 0x78, 0x5a, 0xa6, 0xdc, 0x50, 0x52, 0xd3, 0x38, 0xd9, 0x80, 0x69, 0x92, 0xd9,
+    0xb5, 0x41, 0x73, 0x0c, 0x1b, 0x41, 0x21, 0x7a, 0xed, 0xf5, 0x0c, 0x1a, 0x9b, 0xa9, 0xbe, 0xcd,
+    0x3f, 0x8c, 0x2a, 0x2e, 0xcf, 0x49, 0x3d, 0xbb, 0x46, 0x20, 0x42, 0x33, 0xbe, 0x06, 0xa1, 0xa6,
+    0x9a, 0xbf, 0x8c, 0x30, 0xa3, 0x98, 0xa5, 0xe0, 0xa2, 0x30, 0x88, 0xcf, 0x6b, 0xe8, 0x15, 0xc0,
+    0x60, 0xda, 0x53, 0xeb, 0x9e, 0xfb, 0x96, 0xb8, 0x42, 0x30, 0x89, 0x2e, 0x05, 0x21, 0xe1, 0x9c,
+    0xcd, 0x6a, 0x6d, 0x10, 0xdb, 0x86, 0xc3, 0xc9, 0xf7, 0x48, 0x37, 0xf7, 0x8d, 0xcd, 0x12, 0x28,
+    0x88, 0xd2, 0xba, 0xc9, 0x0c, 0x7f, 0xab, 0x33, 0x41, 0x07, 0x5a, 0x84, 0x7f, 0xfb, 0x9e, 0x0b,
+    0x3b, 0x4f, 0x64, 0xa4, 0xd5, 0xb1, 0x1a, 0x95, 0xb2, 0x63, 0x87, 0x2c, 0x12, 0xb4, 0xbe, 0xc6,
+    0x42, 0xfe, 0x4c, 0x36, 0xcd, 0xc3, 0x65, 0x7a, 0xe3, 0x73, 0xd1, 0xed, 0xaf, 0xd8, 0x1b, 0x9e,
+    0xa6, 0x80, 0x0d, 0xba, 0xb4, 0x0f, 0x11, 0xc3, 0xe6, 0xb4, 0xd0, 0x13, 0x16, 0x4f, 0x6e, 0x0b,
+    0x34, 0xa0, 0x92, 0xad, 0xe1, 0xab, 0x0e, 0x4f, 0x2d, 0x47, 0x64, 0x5f, 0x11, 0xa9, 0x10, 0xd2,
+    0x25, 0x2a, 0x7c, 0x6b, 0x6a, 0xdd, 0x58, 0x30, 0xdf, 0x9e, 0x9d, 0xff, 0x60, 0x88, 0xb7, 0xf4,
+    0x90, 0x26, 0xa2, 0x84, 0x42, 0xf6, 0x67, 0x7a, 0x52, 0x60, 0x27, 0x5c, 0xe5, 0xc0, 0x44, 0x95,
+    0x25, 0x58, 0xcb, 0x6e, 0xcb, 0x18, 0xe9, 0xeb, 0x34, 0x10, 0xdc, 0x9f, 0x28, 0xb6, 0x68, 0x18,
+    0xc1, 0x42, 0xb1, 0xb7, 0x79, 0xa4, 0x70, 0x91, 0xf0, 0xbd, 0xd2, 0xb8, 0xca, 0xb6, 0x73, 0xdd,
+    0xc2, 0x2a, 0xea, 0x33, 0x76, 0x3a, 0x1f, 0x80, 0xe8, 0x1e, 0x1e, 0xce, 0xaf, 0xac, 0x07, 0x8d,
+    0x48, 0x0d, 0x9a, 0x2e, 0x36, 0x79, 0xe3, 0x1e, 0x68, 0x3e, 0x26, 0x09, 0x05, 0x7f, 0x17, 0xa7,
+    0x6f, 0x5c, 0x05, 0x2f, 0xf7, 0x15, 0x87, 0xcd, 0xe9, 0xd2, 0xfa, 0x09, 0x53, 0x37, 0x58, 0x86,
+    0x23, 0x33, 0x00, 0xc3, 0x4e, 0xdc, 0x12, 0x7b, 0x46, 0x3c, 0xf8, 0x4a, 0x28, 0xb2, 0x46, 0xe1,
+    0x59, 0xc1, 0xd2, 0x0d, 0x5b, 0xcc, 0x6a, 0x38, 0x3c, 0xb6, 0xf7, 0x15, 0xe7, 0x2e, 0xdc, 0xce,
+    0xf0, 0xc0, 0x23, 0xde, 0xdc, 0x09, 0x20, 0xf7, 0xb7, 0xb2, 0x35, 0x9a, 0xa5, 0xcb, 0x45, 0xbb,
+    0x6f, 0x85, 0x8b, 0x21, 0x57, 0x33, 0x39, 0x6f, 0x64, 0x5c, 0x86, 0xc1, 0x55, 0x5d, 0x0a, 0x55,
+    0xc1, 0xf5, 0x84, 0xc7, 0x27, 0x11, 0x80, 0xd8, 0x82, 0x77, 0x5a, 0x47, 0x6d, 0x7e, 0xc5, 0x8f,
+    0x34, 0xd6, 0x01, 0x53, 0x50, 0x72, 0x32, 0xa0, 0x37, 0x85, 0x6c, 0x9b, 0xe8, 0xde, 0x80, 0x35,
+    0x2b, 0xd8, 0xf4, 0x12, 0xc0, 0xf6, 0x5a, 0x92, 0x03, 0x9b, 0xb6, 0xf3, 0x38, 0x2b, 0xf2, 0x5c,
+    0x4e, 0xe7, 0x7a, 0xd3, 0xf8, 0x65, 0xd9, 0x74, 0x9e, 0x9c, 0xd5, 0x9e, 0x7c, 0x3a, 0xed, 0xba,
+    0xfb, 0xf9, 0x41, 0xb6, 0x1a, 0x98, 0x8d, 0xa9, 0x9c, 0x5c, 0xc7, 0xde, 0x89, 0xed, 0x02, 0x61,
+    0xc9, 0xc3, 0x1f, 0x8a, 0x1d, 0x3b, 0x78, 0x26, 0xf0, 0xa0, 0x3e, 0xb9, 0xe6, 0xc4, 0xb4, 0xe2,
+    0x13, 0x37, 0x2f, 0x56, 0x30, 0xed, 0x86, 0x3d, 0x7f, 0x2d, 0x98, 0x3d, 0x2c, 0x80, 0x49, 0xf6,
+    0x75, 0x08, 0xe7, 0x19, 0x0e, 0x9e, 0x6e, 0x19, 0xd0, 0x58, 0x7d, 0xee, 0x8b, 0xdf, 0x4e, 0x7d,
+    0xe5, 0xe8, 0x8b, 0x0b, 0x90, 0x6a, 0x07, 0x2b, 0x9b, 0x20, 0x9a, 0xa6, 0xa5, 0xbb, 0xde, 0x6b,
+    0x7d, 0x69, 0xa5, 0x57, 0x99, 0x27, 0x14, 0x4e, 0x9f, 0x26, 0xf7, 0xb9, 0x35, 0x01, 0xb0, 0xc4,
+    0x26, 0xc3, 0x9a, 0x06, 0x9d, 0x52, 0xc6, 0xcf, 0x2d, 0x4b, 0xe1, 0xb2, 0xc8, 0x0c, 0xb3, 0x30,
+    0xb6, 0xe6, 0x30, 0xbb, 0xde, 0xb8, 0x04, 0xe4, 0xf5, 0x04, 0x7f, 0x76, 0x36, 0xc3, 0x59, 0x90,
+    0x2d, 0xf7, 0xf1, 0xa5, 0xff, 0x92, 0xf2, 0x8b, 0xfb, 0x97, 0x45, 0xde, 0x6f, 0x85, 0x34, 0xb0,
+    0xfd, 0x01, 0x9d, 0x7a, 0x01, 0xda, 0xcb, 0x0d, 0xb2, 0xf7, 0x83, 0x8d, 0xa3, 0x2b, 0xbe, 0x7b,
+    0xd7, 0x07, 0x69, 0x3c, 0x75, 0x76, 0x94, 0x8b, 0x2d, 0x7d, 0xa3, 0x3a, 0xe2, 0x15, 0x99, 0x56,
+    0x56, 0x72, 0x94, 0xf6, 0x73, 0x6a, 0xdf, 0x84, 0xaf, 0xd4, 0xc6, 0xe7, 0xac, 0x53, 0xe0, 0xa7,
+    0xae, 0xdd, 0x1d, 0x0f, 0x7f, 0x6a, 0x70, 0x3a, 0xe4, 0xc4, 0xe3, 0x9f, 0x5d, 0xc9, 0x37, 0x0a,
+    0x35, 0x16, 0xae, 0xec, 0x41, 0x35, 0xef, 0x33, 0xf8, 0x7a, 0xc8, 0xe2, 0x9a, 0x13, 0x5e, 0x4a,
+    0x47, 0x9a, 0xf8, 0x1b, 0x4e, 0xbf, 0x81, 0x14, 0x2a, 0xd9, 0xc5, 0x96, 0x4c, 0xb8, 0x37, 0xe2,
+    0x87, 0x36, 0x00, 0xb7, 0x65, 0x86, 0xd7, 0x1d, 0x48, 0x84, 0xa7, 0x62, 0x99, 0x7b, 0x93, 0x71,
+    0x15, 0xff, 0xb6, 0x7c, 0xbc, 0xc2, 0xd3, 0x79, 0xd4, 0x05, 0xd9, 0xbf, 0x7e, 0x45, 0x67, 0x81,
+    0xff, 0x12, 0xec, 0xda, 0xb6, 0xde, 0xa0, 0x99, 0xbd, 0x60, 0xdd, 0x99, 0xa1, 0x64, 0x99, 0xdf,
+    0xcc, 0x2c, 0x1d, 0x2f, 0xd2, 0x70, 0x83, 0x25, 0xd9, 0x30, 0xfc, 0x15, 0x00, 0xcf, 0x27, 0xb3,
+    0x46, 0xd2, 0x2f, 0x13, 0x37, 0x64, 0x37, 0xc5, 0x50, 0x11, 0x16, 0x1e, 0xe5, 0x2e, 0x51, 0x3c,
+    0x69, 0x84, 0x85, 0x96, 0x1a, 0xfe, 0x37, 0x56, 0xd5, 0x21, 0x99, 0xe5, 0x16, 0x92, 0x90, 0xf4,
+    0xc0, 0x82, 0xe8, 0xe7, 0x83, 0xc3, 0xee, 0xe3, 0x3b, 0x9e, 0x57, 0xea, 0x81, 0x96, 0xf5, 0x0c,
+    0x9e, 0x24, 0xa7, 0x50, 0x9c, 0x62, 0xd3, 0x8c, 0x42, 0x98, 0xaf, 0x7e, 0xc1, 0xa8, 0x1c, 0xf6,
+    0xbc, 0x26, 0xca, 0x21, 0x1a, 0x03, 0x4d, 0x8a, 0x4c, 0x3a, 0x16, 0xbe, 0xa6, 0x4d, 0x12, 0x72,
+    0xbc, 0xed, 0xf4, 0x31, 0x96, 0x39, 0xc9, 0x01, 0x7d, 0x0a, 0x69, 0xc1, 0x43, 0xd3, 0x53, 0xa7,
+    0x8f, 0xac, 0xf0, 0x4e, 0xba, 0xdf, 0xf1, 0xbe, 0x79, 0x56, 0x4a, 0xef, 0xa8, 0x52, 0x52, 0x88,
+    0x26, 0x27, 0xc1, 0xfe, 0x48, 0x95, 0x72, 0x21, 0x57, 0xea, 0x51, 0x01, 0x64, 0x12, 0x22, 0x2a,
+    0xe2, 0x6f, 0xe2, 0xab, 0xe3, 0x0a, 0x6d, 0xf5, 0xa7, 0x73, 0x60, 0xde, 0x37, 0x9e, 0x13, 0x18,
+    0xa5, 0x23, 0x41, 0xba, 0x27, 0xdd, 0x47, 0x40, 0x44, 0x63, 0x2d, 0x1c, 0xee, 0x25, 0x27, 0x4d,
+    0xae, 0x9c, 0xfd, 0x07, 0xd8, 0x03, 0xf6, 0x91, 0xd0, 0xdb, 0x04, 0xe5, 0x8e, 0x25, 0x0b, 0x56,
+    0x74, 0x5e, 0x14, 0x8e, 0xb9, 0xd8, 0xf7, 0x4a, 0xae, 0x40, 0x5a, 0xeb, 0xea, 0xe3, 0x3c, 0xae,
+    0xb3, 0x65, 0xb9, 0xd3, 0xfe, 0x43, 0x63, 0x6e, 0x3c, 0xc0, 0xae, 0x5f, 0xb6, 0xf1, 0xab, 0xd7,
+    0xb8, 0x2c, 0x14, 0xf6, 0xf6, 0xbe, 0xbc, 0x35, 0x61, 0x58, 0xf7, 0xa8, 0x05, 0x4c, 0x77, 0xe8,
+    0x67, 0x14, 0x13, 0x5c, 0x53, 0x92, 0xf1, 0x69, 0x45, 0xae, 0x68, 0x14, 0x5f, 0xf5, 0x23, 0xc8,
+    0xca, 0x9e, 0xf4, 0x76, 0x8e, 0x3a, 0xd0, 0x2b, 0x15, 0x80, 0x3b, 0x9f, 0xeb, 0xb2, 0xe8, 0x74,
+    0xf7, 0x8f, 0x87, 0x39, 0x2c, 0x2b, 0x78, 0x43, 0xf5, 0xd6, 0x73, 0x59, 0xb4, 0x02, 0x11, 0xb7,
+    0x41, 0x35, 0xd3, 0x19, 0x17, 0x4f, 0x3b, 0x71, 0x30, 0xd9, 0x4d, 0x15, 0xb9, 0x2b, 0xe2, 0xbc,
+    0x18, 0x08, 0x5e, 0x6a, 0x38, 0x75, 0x83, 0xa8, 0x9d, 0x3a, 0x2c, 0xdc, 0x09, 0xd4, 0x07, 0x84,
+    0xf4, 0xe6, 0xe3, 0xe4, 0xf9, 0xc4, 0xc9, 0x76, 0xa9, 0x55, 0xd7, 0x8a, 0x28, 0x26, 0xc5, 0xfa,
+    0xb7, 0x40, 0x15, 0xdf, 0x90, 0xa2, 0x8f, 0xd0, 0x70, 0xc9, 0x97, 0xf3, 0xb3, 0x5a, 0x3b, 0xa9,
+    0x95, 0x78, 0xab, 0x1c, 0xc2, 0x03, 0xe2, 0x86, 0x12, 0x33, 0x9d, 0xec, 0xdf, 0xad, 0x79, 0x5f,
+    0xf9, 0xf7, 0x42, 0x35, 0x25, 0xa2, 0x55, 0xa5, 0x33, 0x9d, 0xd9, 0x0e, 0xe9, 0x77, 0x93, 0xaf,
+    0xa2, 0x85, 0xb5, 0xa5, 0xf1, 0x9a, 0x9c, 0x96, 0x26, 0xed, 0xa4, 0x29, 0xee, 0xc8, 0xa2, 0x2d,
+    0x18, 0xb8, 0x6c, 0xfd, 0xac, 0x62, 0x42, 0xd3, 0xc4, 0x0c, 0xa0, 0x79, 0x95, 0xfc, 0x92, 0xa9,
+    0xb5, 0x36, 0xf9, 0x06, 0x0f, 0x6b, 0xcc, 0x0a, 0x96, 0x57, 0xa3, 0x2e, 0xbf, 0xbd, 0x79, 0x44,
+    0x48, 0x70, 0x65, 0xb6, 0xd3, 0x33, 0x9d, 0x92, 0x76, 0x6a, 0x71, 0x02, 0x8d, 0x2e, 0x26, 0xe9,
+    0x41, 0xe1, 0xac, 0x73, 0x34, 0xa1, 0xc5, 0xb1, 0xf6, 0x99, 0x37, 0x5d, 0xf2, 0xc8, 0x89, 0xd5,
+    0x16, 0x6f, 0x18, 0xa6, 0xd7, 0x9c, 0x4c, 0x35, 0x19, 0x91, 0xf4, 0x6a, 0xa0, 0xdc, 0xdf, 0x4c,
+    0x57, 0x6b, 0x8e, 0x83, 0x42, 0xa4, 0x61, 0x94, 0x41, 0xa5, 0x8c, 0x96, 0xf4, 0xd2, 0x96, 0xbc,
+    0x0d, 0xfe, 0xc6, 0x45, 0xec, 0xe5, 0x24, 0xca, 0x43, 0x50, 0x67, 0xc5, 0x70, 0x3c, 0x49, 0x2a,
+    0x3f, 0xfd, 0xe3, 0x2d, 0x46, 0xe1, 0x0d, 0x51, 0xa1, 0xb2, 0xe8, 0xbd, 0x82, 0xe8, 0xef, 0x7b,
+    0x63, 0xfc, 0xd0, 0x31, 0xe2, 0x81, 0xf1, 0x10, 0xed, 0xf2, 0xa0, 0x9b, 0xfd, 0x60, 0xd0, 0xbc,
+    0x64, 0x54, 0xc0, 0xf6, 0xc7, 0x7f, 0xf4, 0xac, 0xc1, 0x1a, 0x33, 0xe8, 0x75, 0xc0, 0x57, 0x8a,
+    0xa5, 0xf6, 0x20, 0x5b, 0x48, 0x88, 0x27, 0xda, 0xe4, 0x63, 0x65, 0x32, 0x69, 0xc7, 0xb6, 0x24,
+    0xb6, 0x94, 0x08, 0x70, 0xe9, 0xb5, 0xe9, 0x14, 0x5a, 0x64, 0xda, 0xa6, 0x4c, 0x8a, 0xcc, 0x51,
+    0x4b, 0x08, 0xd1, 0x9e, 0x69, 0x96, 0x90, 0x5c, 0x92, 0xc4, 0x5f, 0x10, 0x2b, 0x9a, 0xcb, 0x79,
+    0xa7, 0x74, 0xa2, 0x97, 0x47, 0xbc, 0x90, 0x0f, 0x52, 0x79, 0x38, 0x6c, 0x99, 0x40, 0x32, 0xb2,
+    0xf6, 0x78, 0x6c, 0xae, 0x3b, 0xbe, 0xcc, 0xf0, 0x26, 0xc8, 0x4a, 0xcd, 0xb1, 0x92, 0x71, 0x29,
+    0xc6, 0x77, 0x29, 0x8d, 0x73, 0x15, 0x62, 0xaf, 0x05, 0x9c, 0x23, 0x2e, 0xcd, 0xb5, 0x1c, 0xa8,
+    0x62, 0x3a, 0x3a, 0x1c, 0xf8, 0x5b, 0x71, 0x76, 0xd1, 0xa0, 0xac, 0x4b, 0x5e, 0x7e, 0xca, 0x1d,
+    0x5a, 0xb6, 0xee, 0x20, 0x13, 0xa9, 0x21, 0x53, 0x5c, 0x08, 0x8e, 0xb3, 0x2e, 0xa1, 0xfd, 0x6c,
+    0xf6, 0xda, 0x3b, 0x00, 0x19, 0xe9, 0xb1, 0xca, 0x9c, 0x96, 0x18, 0x85, 0xe5, 0x03, 0x77, 0x47,
+    0x77, 0x49, 0x0b, 0x0c, 0xc5, 0x93, 0xa2, 0x44, 0xf3, 0x37, 0x92, 0x3a, 0x99, 0x90, 0xac, 0x60,
+    0xd5, 0xce, 0x6e, 0x30, 0x88, 0x63, 0x57, 0x73, 0x44, 0x1e, 0xcc, 0x44, 0x5f, 0xbe, 0x78, 0x3a,
+    0x7a, 0x16, 0x10, 0x54, 0x5d, 0xba, 0x8a, 0x07, 0xba, 0xb0, 0x35, 0x00, 0x8e, 0xcd, 0x64, 0x66,
+    0xb2, 0x8e, 0xa9, 0x3e, 0xfd, 0x3b, 0x45, 0x69, 0xfc, 0x7b, 0x3c, 0x76, 0xe7, 0x4d, 0x56, 0xb3,
+    0xa4, 0x58, 0x02, 0xca, 0x97, 0x54, 0x2e, 0xfe, 0x55, 0x79, 0xdd, 0x25, 0x67, 0x13, 0x2a, 0x88,
+    0x15, 0xdf, 0xa5, 0xb3, 0xce, 0xe3, 0x10, 0x55, 0x81, 0xa0, 0x3e, 0x8a, 0x8a, 0xe7, 0xdf, 0x82,
+    0x3b, 0xcf, 0x18, 0x3a, 0x35, 0x7b, 0x31, 0x9c, 0xa2, 0x6b, 0x10, 0xae, 0x98, 0xa2, 0x23, 0x8e,
+    0xa7, 0x80, 0xfb, 0xd9, 0x6c, 0xee, 0xb7, 0x51, 0xbb, 0xc7, 0xb1, 0x01, 0x20, 0x2c, 0x67, 0x24,
+    0xac, 0xf2, 0xbb, 0x2f, 0x20, 0xc3, 0x62, 0xea, 0x30, 0x22, 0xa5, 0x21, 0xd0, 0xc3, 0xb5, 0x3d,
+    0x94, 0x14, 0x60, 0x91, 0xb0, 0x12, 0x04, 0x8a, 0xcb, 0x5c, 0xc1, 0x17, 0x89, 0x18, 0xe7, 0x6d,
+    0xa3, 0x27, 0x00, 0x7a, 0xb0, 0x08, 0x1d, 0xbc, 0xe9, 0xaa, 0xff, 0xed, 0x15, 0x1a, 0xb5, 0x98,
+    0xbb, 0xbd, 0xd9, 0x94, 0x0b, 0x28, 0x73, 0xb2, 0x48, 0xf0, 0xe9, 0x4d, 0x07, 0xe5, 0xb9, 0x1e,
+    0x81, 0xc4, 0xe0, 0x21, 0x3d, 0xdc, 0x3d, 0x8a, 0x37, 0x80, 0xc5, 0xb1, 0xfb, 0xec, 0x98, 0x4e,
+    0x18, 0x87, 0x39, 0x95, 0xbb, 0x70, 0xbd, 0xbd, 0x9a, 0x5b, 0x77, 0xc2, 0xc1, 0x7d, 0xea, 0x85,
+    0x8b, 0x12, 0x08, 0x77, 0x68, 0xb0, 0x0d, 0x78, 0x56, 0x49, 0x07, 0x8e, 0x27, 0x28, 0x5c, 0x34,
+    0xbf, 0x26, 0x3e, 0x17, 0x77, 0xa8, 0x61, 0xd0, 0x2f, 0x90, 0x34, 0xcc, 0x3b, 0x79, 0x95, 0x11,
+    0xd8, 0x9f, 0xc0, 0xb9, 0x62, 0x6f, 0x11, 0x1a, 0xb8, 0xbf, 0x5a, 0x1c, 0x9e, 0x4a, 0x26, 0x05,
+    0xad, 0x70, 0x9c, 0xde, 0x4f, 0xfa, 0xae, 0x81, 0x0d, 0x8c, 0x93, 0xab, 0x01, 0x34, 0x54, 0x8d,
+    0x8a, 0xc4, 0x6f, 0xf0, 0xea, 0xb4, 0x53, 0x45, 0x4c, 0x61, 0xb0, 0x1d, 0xf3, 0x32, 0xaa, 0x3a,
+    0x04, 0x03, 0x7e, 0xa3, 0xb4, 0xdf, 0xc3, 0x31, 0x26, 0x9d, 0x0d, 0xc9, 0xb2, 0xa9, 0xa4, 0x39,
+    0x56, 0xe6, 0xa1, 0x0d, 0xc8, 0x6d, 0x1c, 0x32, 0x88, 0xe8, 0x5e, 0x1a, 0x2d, 0x1e, 0x00, 0x8b,
+    0x6a, 0x3b, 0x67, 0xa8, 0x27, 0x4b, 0x62, 0xfd, 0xc0, 0xfb, 0x63, 0x75, 0x53, 0x9f, 0x92, 0x5f,
+    0x41, 0x14, 0x7c, 0x75, 0x5a, 0x02, 0x13, 0x21, 0xcd, 0x99, 0x0a, 0x50, 0x48, 0x01, 0x2f, 0x68,
+    0xa0, 0x0f, 0x28, 0x31, 0xb5, 0x68, 0xa5, 0xc1, 0x05, 0xd9, 0xe0, 0xd3, 0x41, 0xd6, 0x4a, 0x0f,
+    0xee, 0x66, 0xd0, 0x1c, 0xa7, 0xd5, 0xf7, 0xa6, 0xbf, 0xaf, 0x71, 0xff, 0xdb, 0x2a, 0xe2, 0x49,
+    0xc9, 0xc3, 0x9a, 0x34, 0xd0, 0xee, 0xfe, 0xcb, 0x39, 0x3c, 0xe8, 0xbe, 0xda, 0x24, 0x52, 0xc7,
+    0x32, 0xfa, 0xa8, 0xfd, 0x59, 0xe3, 0x04, 0x2d, 0xb1, 0x3b, 0x00, 0x53, 0xb8, 0x36, 0x95, 0x74,
+    0xd1, 0xe0, 0xb8, 0x22, 0x4f, 0xfe, 0x3e, 0x85, 0x43, 0xf9, 0x14, 0xda, 0x7e, 0x3f, 0x7e, 0xbe,
+    0x45, 0xb4, 0xfe, 0xe6, 0x52, 0x60, 0xb6, 0x62, 0xcb, 0xd3, 0x2f, 0xd6, 0x1d, 0x26, 0xc2, 0x5f,
+    0x5e, 0x70, 0xfd, 0xcd, 0xaa, 0x04, 0x64, 0x5e, 0x2d, 0xd4, 0xbe, 0x76, 0x0b, 0x9e, 0x72, 0x95,
+    0x74, 0x6c, 0x4d, 0x08, 0x9d, 0xb4, 0x9a, 0xf9, 0x08, 0xdf, 0x7f, 0x9b, 0x33, 0xad, 0x2a, 0x3d,
+    0xbb, 0x5f, 0x47, 0x3e, 0x16, 0xa1, 0xc1, 0x8f, 0x29, 0x2d, 0x81, 0xd8, 0x05, 0x32, 0xc4, 0x0c,
+    0x9e, 0x6b, 0x5c, 0xef, 0xe1, 0x9a, 0xa1, 0xe4, 0x49, 0x06, 0x3b, 0x0f, 0xb7, 0x00, 0xc4, 0x97,
+    0x91, 0x84, 0xbc, 0xcd, 0x0b, 0xc2, 0x5f, 0xa6, 0xd6, 0xdb, 0x47, 0xa1, 0x98, 0x3b, 0x66, 0x3c,
+    0x55, 0x2d, 0x67, 0x09, 0x74, 0xa9, 0x9d, 0x09, 0x63, 0x86, 0x93, 0x46, 0x97, 0xf6, 0x9f, 0x7e,
+    0x66, 0x77, 0x0e, 0xf6, 0x82, 0xcf, 0x5b, 0xa5, 0x5f, 0xf2, 0xb9, 0x9c, 0x0a, 0x75, 0x05, 0x1c,
+    0xad, 0x80, 0x45, 0x9d, 0xff, 0x3f, 0x2b, 0xaf, 0x44, 0xea, 0x66, 0x82, 0x0d, 0xda, 0xc5, 0x8c,
+    0xac, 0x55, 0x02, 0xb5, 0x89, 0x50, 0xb9, 0x23, 0xc8, 0x89, 0x52, 0x41, 0xf5, 0xba, 0x85, 0xe8,
+    0xd1, 0x3e, 0xc3, 0xef, 0xcb, 0xaa, 0xa8, 0x49, 0x79, 0xcf, 0x7c, 0x2f, 0xc0, 0x92, 0x8f, 0x15,
+    0xae, 0x1b, 0x44, 0x08, 0x23, 0xc8, 0x8b, 0x5f, 0x9b, 0x27, 0x93, 0x20, 0x20, 0x02, 0x5b, 0xc6,
+    0x4f, 0xea, 0x52, 0xa8, 0xab, 0x90, 0x1b, 0x07, 0xa4, 0x0a, 0x2b, 0x58, 0xb4, 0x9e, 0x04, 0x81,
+    0x9b, 0x73, 0x06, 0x7c, 0x31, 0xd6, 0x4d, 0xb1, 0xa7, 0x51, 0x64, 0xd3, 0x0a, 0x7a, 0xaa, 0x50,
+    0xc6, 0xc5, 0x35, 0xb4, 0x3e, 0x50, 0xc1, 0x9c, 0xfb, 0x5e, 0xfe, 0xe1, 0x08, 0x28, 0xad, 0xc1,
+    0x53, 0x5c, 0xd4, 0x58, 0x1d, 0x12, 0x5d, 0xc2, 0x7a, 0xe1, 0x78, 0xea, 0x60, 0xbe, 0x3c, 0x43,
+    0x3d, 0xfd, 0x60, 0xc7, 0x40, 0xa8, 0x35, 0xcf, 0xf3, 0x45, 0xa8, 0xd9, 0x0c, 0xbf, 0xff, 0x52,
+    0xb6, 0xec, 0xca, 0x97, 0x45, 0x96, 0x9a, 0xb2, 0x26, 0xab, 0x89, 0xfd, 0xe2, 0x29, 0xb0, 0x65,
+    0x6b, 0x7d, 0x14, 0x53, 0xc4, 0x41, 0x89, 0x07, 0xff, 0xf1, 0x27, 0xd5, 0x0d, 0x4a, 0x8b, 0x59,
+    0x9e, 0x49, 0x12, 0x55, 0x14, 0x9a, 0xfd, 0x74, 0x02, 0x56, 0x5c, 0x2e, 0xbe, 0xe1, 0x50, 0x1b,
+    0x7d, 0xda, 0x1f, 0x7f, 0x82, 0xcf, 0x88, 0x68, 0xb4, 0xc8, 0x87, 0x82, 0x6f, 0xa6, 0x8a, 0xc0,
+    0xf4, 0xeb, 0x20, 0x13, 0xe2, 0x8e, 0x7e, 0x69, 0xd1, 0x28, 0xc5, 0x79, 0x83, 0x2f, 0x6c, 0x9a,
+    0x12, 0x73, 0x7f, 0xb7, 0xd8, 0xb9, 0xb3, 0xf0, 0x17, 0x64, 0x59, 0x7e, 0x1d, 0x02, 0x46, 0xa6,
+    0x41, 0x46, 0xab, 0x19, 0xc7, 0xd0, 0x15, 0x47, 0xb1, 0x89, 0x5c, 0xcf, 0x14, 0xfc, 0x7d, 0x0c,
+    0x79, 0x70, 0xcf, 0x0f, 0x3b, 0x94, 0x81, 0xe4, 0xee, 0x6e, 0x03, 0x79, 0x15, 0xea, 0x9e, 0xcd,
+    0xcb, 0x25, 0x2d, 0x6a, 0x48, 0xe2, 0x5d, 0x90, 0x61, 0x9f, 0xc5, 0xc4, 0xf0, 0xf0, 0xcf, 0xf3,
+    0x4a, 0x9a, 0x94, 0xcf, 0x45, 0xc2, 0xfd, 0x80, 0xf1, 0xc1, 0x8a, 0x47, 0x7f, 0xf9, 0x63, 0x8f,
+    0x51, 0xc7, 0xaf, 0x0c, 0x69, 0x7c, 0xb9, 0xaf, 0x89, 0x1d, 0xb2, 0xbf, 0x36, 0x98, 0xd3, 0x7e,
+    0x3e, 0xe4, 0x6f, 0xe5, 0xc1, 0x0e, 0xa0, 0x82, 0xdf, 0x05, 0xbf, 0x15, 0x91, 0xd8, 0x05, 0xd1,
+    0x1d, 0x2f, 0xd5, 0xd1, 0xfb, 0x13, 0xd6, 0x99, 0xd7, 0x2d, 0x59, 0x16, 0x20, 0xf3, 0x07, 0xcb,
+    0x28, 0x40, 0x15, 0x78, 0x78, 0x3b, 0x7e, 0xb9, 0x83, 0x20, 0xc3, 0x2d, 0xe4, 0xbc, 0x3c, 0x14,
+    0x88, 0xb7, 0x05, 0x4b, 0x20, 0x6d, 0xe0, 0x83, 0xce, 0x09, 0xc8, 0xdc, 0xab, 0x8d, 0x21, 0x99,
+    0x74, 0x3a, 0xe7, 0x9d, 0xf1, 0x47, 0x1e, 0xce, 0x44, 0xaa, 0x75, 0xa7, 0x83, 0x80, 0x9f, 0x97,
+    0x37, 0x92, 0x12, 0xbe, 0x96, 0x54, 0x50, 0x7b, 0x9e, 0xe2, 0x40, 0xca, 0x55, 0x19, 0x94, 0xcd,
+    0xfc, 0xa7, 0x69, 0x11, 0x7f, 0x53, 0x53, 0x74, 0x9d, 0x85, 0x05, 0xcd, 0x24, 0x51, 0xb1, 0x89,
+    0x06, 0xaf, 0xbc, 0xf0, 0x22, 0x9a, 0x89, 0x45, 0x82, 0x28, 0x40, 0xcc, 0xdd, 0xe0, 0x51, 0x5d,
+    0x35, 0x7d, 0xb6, 0xc2, 0x7d, 0xb5, 0x80, 0x4d, 0xb2, 0xdb, 0x73, 0xc7, 0x09, 0x91, 0x0d, 0x67,
+    0xff, 0xc6, 0xe5, 0x8f, 0xe7, 0x93, 0x82, 0x97, 0xb2, 0xbd, 0x95, 0x2f, 0x1c, 0x90, 0x47, 0x3c,
+    0x4c, 0x65, 0xa5, 0x3d, 0x01, 0x2d, 0xfd, 0xf3, 0x9f, 0x6b, 0x9e, 0xa2, 0xe3, 0x5b, 0x58, 0x32,
+    0x3c, 0x8d, 0x48, 0xa0, 0x8b, 0x02, 0x71, 0x6e, 0xd4, 0x36, 0xe2, 0x1a, 0xcc, 0x4d, 0x28, 0x6f,
+    0x3f, 0x3f, 0xf3, 0xf6, 0x72, 0xb0, 0xd5, 0xad, 0x56, 0xed, 0x58, 0xb0, 0xed, 0x96, 0xbe, 0x6f,
+    0xfd, 0xb4, 0xe6, 0x61, 0xf5, 0x3b, 0xab, 0x61, 0xca, 0x81, 0x6d, 0x38, 0x63, 0x71, 0x64, 0x6d,
+    0x83, 0x96, 0xf3, 0xe0, 0x64, 0x94, 0xfa, 0x51, 0x33, 0xde, 0x44, 0xb7, 0x7d, 0xa5, 0x89, 0x0a,
+    0x8d, 0xac, 0x9c, 0x91, 0x6b, 0x00, 0xd4, 0xdb, 0xac, 0xc2, 0x36, 0x38, 0x41, 0x2d, 0xa5, 0xa3,
+    0x86, 0x4d, 0x29, 0x7a, 0xd6, 0xe1, 0x18, 0xb1, 0x7f, 0x3a, 0xb3, 0x76, 0x91, 0x16, 0x98, 0x13,
+    0x56, 0x65, 0xf2, 0x31, 0xac, 0x43, 0x80, 0xee, 0xfb, 0x32, 0xae, 0xd1, 0x6d, 0x57, 0x81, 0xde,
+    0x6f, 0xa0, 0xa6, 0xd2, 0x1b, 0x69, 0xe1, 0xf5, 0x92, 0x68, 0x39, 0x1d, 0xae, 0x50, 0xfb, 0xcb,
+    0x6b, 0x63, 0x8c, 0xce, 0xfc, 0x26, 0x7c, 0x9a, 0xbc, 0x45, 0x88, 0x8c, 0xa5, 0xcb, 0x4b, 0xfc,
+    0xe0, 0xa1, 0xee, 0x82, 0x70, 0xd5, 0x89, 0x81, 0x7a, 0x16, 0xa9, 0xbe, 0x3f, 0x9e, 0xdd, 0x3d,
+    0x1d, 0x9b, 0x0c, 0x06, 0xfd, 0x34, 0x52, 0xc5, 0xa6, 0x64, 0x68, 0x2c, 0x7a, 0x48, 0x53, 0xca,
+    0x55, 0x23, 0xff, 0x92, 0x2e, 0x9c, 0xec, 0x9b, 0xbf, 0xeb, 0x04, 0xa4, 0xe3, 0x01, 0x1d, 0x90,
+    0x38, 0xc0, 0xe8, 0xe1, 0xce, 0xaf, 0xb1, 0x56, 0x6f, 0xaf, 0xa5, 0x4f, 0x1b, 0xb3, 0x04, 0x75,
+    0x9f, 0x4e, 0xd9, 0x92, 0xc4, 0x00, 0x21, 0xc0, 0x49, 0x1f, 0x1c, 0xa0, 0x52, 0xbf, 0x3c, 0x34,
+    0xf3, 0xe5, 0xb3, 0xf1, 0x35, 0xd3, 0xdd, 0xf5, 0x78, 0xbc, 0x11, 0x74, 0xff, 0xb2, 0xd6, 0xc6,
+    0xf6, 0x91, 0x1c, 0xb7, 0x6d, 0x80, 0xb2, 0x29, 0x3c, 0xde, 0x05, 0x85, 0x0c, 0x4e, 0x1f, 0xd2,
+    0x1d, 0x5f, 0x46, 0xc2, 0x26, 0x48, 0x70, 0x67, 0xcb, 0x45, 0x55, 0x7a, 0xa2, 0x0a, 0x36, 0xe3,
+    0x70, 0x9f, 0xee, 0x5c, 0xbf, 0x4c, 0xf9, 0x6b, 0x1d, 0x44, 0x85, 0x0f, 0x04, 0x40, 0x2f, 0x44,
+    0x99, 0xbf, 0x71, 0x45, 0x18, 0xa2, 0xd3, 0xb1, 0x53, 0x00, 0xc5, 0x1a, 0xdb, 0x04, 0x0e, 0x74,
+    0x4c, 0xc0, 0x02, 0x2f, 0xc5, 0x03, 0xf4, 0x04, 0x68, 0x70, 0x2a, 0xf7, 0x14, 0x87, 0x14, 0x72,
+    0xd8, 0x52, 0xed, 0x70, 0x31, 0x6f, 0xec, 0xa8, 0xde, 0x24, 0x09, 0x03, 0x3f, 0x62, 0x3c, 0x12,
+    0xe3, 0x91, 0xb8, 0x7c, 0x46, 0xe7, 0x67, 0x33, 0x3b, 0x96, 0xe5, 0xe8, 0x40, 0x8f, 0x51, 0x2a,
+    0x98, 0x82, 0xec, 0x78, 0x16, 0xba, 0x7a, 0xad, 0xf8, 0x4b, 0x2d, 0x50, 0x3a, 0xf2, 0xed, 0x2e,
+    0xcd, 0x09, 0x4c, 0x47, 0x19, 0x54, 0x74, 0x2b, 0x82, 0x0e, 0x48, 0xe8, 0x32, 0xd5, 0xf6, 0x69,
+    0x32, 0x11, 0xdb, 0x77, 0x82, 0xce, 0x60, 0x4e, 0xae, 0x12, 0x76, 0xbb, 0xdf, 0x53, 0xbf, 0x0f,
+    0x36, 0x4a, 0x5c, 0x3c, 0x7c, 0xdd, 0x63, 0x2c, 0xb6, 0xf6, 0x10, 0xb0, 0x49, 0x32, 0xea, 0xa4,
+    0xab, 0xc8, 0x5b, 0xc3, 0xbc, 0x15, 0x8b, 0x43, 0x6e, 0x61, 0x3c, 0xc2, 0x14, 0xe3, 0x9b, 0xfa,
+    0x24, 0xd0, 0x10, 0x6e, 0x54, 0x2d, 0xcd, 0xfe, 0xd0, 0x3c, 0x4b, 0x90, 0x2a, 0xba, 0x2a, 0x6c,
+    0x49, 0x0d, 0x18, 0x29, 0xfb, 0x29, 0x86, 0xae, 0xbe, 0xdc, 0xad, 0x4c, 0x61, 0xb1, 0x1a, 0xbe,
+    0x20, 0x4f, 0x79, 0xe6, 0xee, 0x3f, 0x1b, 0x84, 0x27, 0x70, 0x65, 0xc2, 0x8b, 0x3c, 0x44, 0x59,
+    0x36, 0x76, 0x38, 0x36, 0xe0, 0x79, 0xe6, 0x7f, 0x42, 0x8a, 0x28, 0xa8, 0x96, 0x75, 0xef, 0xdf,
+    0xc3, 0x54, 0xf7, 0x35, 0xdc, 0x94, 0x9b, 0xd0, 0x41, 0x11, 0xcb, 0x42, 0x4e, 0x68, 0x9e, 0x4d,
+    0xa6, 0x64, 0xe3, 0xb3, 0x80, 0xdf, 0x72, 0x65, 0x19, 0x2d, 0xd9, 0x6a, 0x9f, 0x10, 0x4f, 0x3b,
+    0x8c, 0x16, 0x84, 0x0e, 0xd5, 0x3c, 0xaf, 0x1e, 0xa2, 0x04, 0xe7, 0x40, 0x26, 0x9c, 0x75, 0xbb,
+    0x66, 0x3a, 0x7a, 0xf5, 0x4b, 0x30, 0x77, 0x7e, 0xa0, 0x16, 0xd7, 0xe7, 0xc5, 0x3a, 0x1c, 0x92,
+    0x67, 0xec, 0x60, 0xfd, 0x73, 0xac, 0x6d, 0x1f, 0xe1, 0x81, 0x7e, 0xe4, 0x97, 0x98, 0xdc, 0xec,
+    0xa0, 0xf6, 0x4c, 0xa2, 0x94, 0xfe, 0x74, 0x55, 0x83, 0x13, 0xa7, 0xbe, 0x16, 0xf3, 0x72, 0x79,
+    0x3c, 0x35, 0xfe, 0x2b, 0xc2, 0xad, 0xf5, 0x07, 0x24, 0x52, 0x85, 0x99, 0xf6, 0x2a, 0xc7, 0xf6,
+    0x21, 0xe5, 0xfd, 0xa6, 0x14, 0x3b, 0x21, 0xec, 0x16, 0x19, 0x8f, 0x1c, 0x61, 0xa3, 0x04, 0xa5,
+    0x92, 0xd6, 0x28, 0x10, 0x2a, 0x74, 0x7e, 0x29, 0xb0, 0xdd, 0x68, 0xb6, 0xb7, 0x8f, 0x38, 0x82,
+    0xcb, 0x3b, 0x99, 0x1a, 0x42, 0x63, 0x59, 0x83, 0xce, 0x8d, 0x53, 0x42, 0xde, 0x1b, 0xf8, 0x37,
+    0x74, 0x17, 0x64, 0x7a, 0xb6, 0x5e, 0x0a, 0xa8, 0x78, 0xe9, 0x1c, 0xaa, 0x04, 0x15, 0xee, 0x33,
+    0x2b, 0x0d, 0xa2, 0x01, 0xa0, 0x89, 0xfb, 0xc8, 0x76, 0xc0, 0xd3, 0x8b, 0x62, 0x22, 0x91, 0x60,
+    0xa2, 0xe2, 0xb6, 0x06, 0xfa, 0xfe, 0x93, 0x53, 0x16, 0x7f, 0x6b, 0x54, 0x26, 0xeb, 0x45, 0x07,
+    0x6a, 0x9f, 0xc5, 0x3e, 0xf7, 0xf1, 0xe8, 0xa0, 0x81, 0xfb, 0x7e, 0x9e, 0x28, 0x20, 0x25, 0xff,
+    0xfd, 0xd2, 0x85, 0x25, 0x44, 0xd2, 0x62, 0x10, 0x64, 0x2a, 0x76, 0xf2, 0x0b, 0x2d, 0xea, 0xda,
+    0x10, 0x71, 0xbb, 0x03, 0x42, 0x51, 0x94, 0x9a, 0xb0, 0xcb, 0xd1, 0xe1, 0x45, 0x46, 0x69, 0x6f,
+    0xbb, 0x19, 0x69, 0xbc, 0x3b, 0x6c, 0x6d, 0x82, 0xfe, 0x10, 0x23, 0x4a, 0x6b, 0x77, 0x4c, 0x4f,
+    0xaf, 0xd0, 0x4f, 0x50, 0x70, 0x87, 0x8c, 0xb6, 0xdd, 0xe3, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x0c, 0x11, 0x14, 0x19,
+    0x1e, 0x22, 0x2a,
+];
+
+/// Offset of the hint indices in the signature: c~ (64 bytes) and z
+/// (7 * 640 bytes) come first, the 8 cumulative hint counts last
+const HINT_OFFSET: usize = SIGNATURE_1.len() - 83;
+
+struct Vector {
+    pub_key: &'static [u8; 2592],
+    message: &'static [u8; 48],
+    signature: &'static [u8; 4627],
+}
+
+const VECTORS: [Vector; 2] = [
+    Vector {
+        pub_key: &PUB_KEY_1,
+        message: &MESSAGE_1,
+        signature: &SIGNATURE_1,
+    },
+    Vector {
+        pub_key: &PUB_KEY_2,
+        message: &MESSAGE_2,
+        signature: &SIGNATURE_2,
+    },
+];
+
+/// Change applied to a known answer vector before it is verified
+#[derive(Clone, Copy)]
+enum Mutation {
+    None,
+    FlipMessageBit(usize),
+    TruncateMessage,
+    FlipSignatureBit(usize),
+    SetSignatureByte(usize, u8),
+    SwapSignatureBytes(usize),
+    FlipPubKeyBit(usize),
+}
+
+/// sigVer cases run against every vector, mirroring the failure categories
+/// in image/openssl/testdata/mldsa87_kat.toml
+const SIG_VER_CASES: [(Mutation, Mldsa87Result); 8] = [
+    (Mutation::None, Mldsa87Result::Success),
+    (Mutation::FlipMessageBit(0), Mldsa87Result::SigVerifyFailed),
+    (Mutation::TruncateMessage, Mldsa87Result::SigVerifyFailed),
+    // Commitment hash
+    (
+        Mutation::FlipSignatureBit(0),
+        Mldsa87Result::SigVerifyFailed,
+    ),
+    // Response vector z
+    (
+        Mutation::FlipSignatureBit(1000),
+        Mldsa87Result::SigVerifyFailed,
+    ),
+    // A cumulative hint count above OMEGA (75)
+    (
+        Mutation::SetSignatureByte(SIGNATURE_1.len() - 1, 76),
+        Mldsa87Result::SigVerifyFailed,
+    ),
+    // Hint indices of the first polynomial out of order; both vectors have
+    // at least two hints there
+    (
+        Mutation::SwapSignatureBytes(HINT_OFFSET),
+        Mldsa87Result::SigVerifyFailed,
+    ),
+    (Mutation::FlipPubKeyBit(100), Mldsa87Result::SigVerifyFailed),
+];
+
+fn verify(vector: &Vector, mutation: Mutation) -> Mldsa87Result {
+    let mut pub_key = *vector.pub_key;
+    let mut message = *vector.message;
+    let mut message_len = message.len();
+    let mut signature = *vector.signature;
+    match mutation {
+        Mutation::None => {}
+        Mutation::FlipMessageBit(i) => message[i] ^= 0x01,
+        Mutation::TruncateMessage => message_len -= 1,
+        Mutation::FlipSignatureBit(i) => signature[i] ^= 0x01,
+        Mutation::SetSignatureByte(i, value) => signature[i] = value,
+        Mutation::SwapSignatureBytes(i) => signature.swap(i, i + 1),
+        Mutation::FlipPubKeyBit(i) => pub_key[i] ^= 0x01,
+    }
+    Mldsa87::default()
+        .verify(&pub_key, &message[..message_len], &signature)
+        .unwrap()
+}
+
+fn test_mldsa87_sig_ver() {
+    for vector in VECTORS.iter() {
+        for (mutation, expected) in SIG_VER_CASES {
+            assert_eq!(verify(vector, mutation), expected);
+        }
+    }
+}
+
+test_suite! {
+    test_mldsa87_sig_ver,
+}
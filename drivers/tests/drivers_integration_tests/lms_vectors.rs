@@ -0,0 +1,263 @@
+// Licensed under the Apache-2.0 license
+
+//! Table-driven LMS verification tests. Each file in `test_data/lms` holds
+//! one vector: its source, a message, a public key and a signature,
+//! serialized as in RFC 8554. Every vector must verify through
+//! `Lms::verify_lms_signature_generic`, and must fail to verify once
+//! tampered with. `test_data/lms/README.md` lists where each vector comes
+//! from.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use caliptra_drivers::{get_lmots_parameters, CaliptraResult, Lms, LmsResult};
+use caliptra_drivers_sw::Sha256Sw;
+use caliptra_lms_types::{LmotsAlgorithmType, LmsAlgorithmType, LmsPublicKey, LmsSignature};
+use zerocopy::FromBytes;
+
+#[derive(Clone, Debug)]
+struct LmsVector {
+    name: String,
+    source: String,
+    message: Vec<u8>,
+    public_key: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+impl LmsVector {
+    fn parse(path: &Path) -> Self {
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let text = std::fs::read_to_string(path).unwrap();
+        let field = |key: &str| {
+            text.lines()
+                .filter(|line| !line.starts_with('#'))
+                .filter_map(|line| line.split_once('='))
+                .find(|(k, _)| k.trim() == key)
+                .map(|(_, v)| v.trim())
+                .unwrap_or_else(|| panic!("{name}: missing {key}"))
+        };
+        let hex_field = |key: &str| {
+            hex_decode(field(key)).unwrap_or_else(|| panic!("{name}: invalid hex in {key}"))
+        };
+        Self {
+            source: field("source").to_string(),
+            message: hex_field("message"),
+            public_key: hex_field("public_key"),
+            signature: hex_field("signature"),
+            name,
+        }
+    }
+
+    fn lms_type(&self) -> LmsAlgorithmType {
+        LmsAlgorithmType::read_from_prefix(self.public_key.as_slice()).unwrap()
+    }
+
+    fn ots_type(&self) -> LmotsAlgorithmType {
+        LmotsAlgorithmType::read_from_prefix(&self.public_key[4..]).unwrap()
+    }
+
+    /// The LMS and LM-OTS types of the signature, which select the
+    /// parameters it is verified with
+    fn sig_types(&self) -> (LmsAlgorithmType, LmotsAlgorithmType) {
+        let ots_type = LmotsAlgorithmType::read_from_prefix(&self.signature[4..]).unwrap();
+        let params = get_lmots_parameters(ots_type).unwrap();
+        let lms_type_offset = 8 + params.n as usize * (params.p as usize + 1);
+        let lms_type =
+            LmsAlgorithmType::read_from_prefix(&self.signature[lms_type_offset..]).unwrap();
+        (lms_type, ots_type)
+    }
+
+    fn verify(&self) -> CaliptraResult<LmsResult> {
+        use caliptra_lms_types::{LmotsAlgorithmType as Ots, LmsAlgorithmType as Tree};
+
+        match self.sig_types() {
+            (Tree::LmsSha256N24H5, Ots::LmotsSha256N24W1) => self.verify_generic::<6, 200, 5>(),
+            (Tree::LmsSha256N24H5, Ots::LmotsSha256N24W2) => self.verify_generic::<6, 101, 5>(),
+            (Tree::LmsSha256N24H5, Ots::LmotsSha256N24W4) => self.verify_generic::<6, 51, 5>(),
+            (Tree::LmsSha256N24H5, Ots::LmotsSha256N24W8) => self.verify_generic::<6, 26, 5>(),
+            (Tree::LmsSha256N24H10, Ots::LmotsSha256N24W1) => self.verify_generic::<6, 200, 10>(),
+            (Tree::LmsSha256N24H10, Ots::LmotsSha256N24W2) => self.verify_generic::<6, 101, 10>(),
+            (Tree::LmsSha256N24H10, Ots::LmotsSha256N24W4) => self.verify_generic::<6, 51, 10>(),
+            (Tree::LmsSha256N24H10, Ots::LmotsSha256N24W8) => self.verify_generic::<6, 26, 10>(),
+            (Tree::LmsSha256N24H15, Ots::LmotsSha256N24W1) => self.verify_generic::<6, 200, 15>(),
+            (Tree::LmsSha256N24H15, Ots::LmotsSha256N24W2) => self.verify_generic::<6, 101, 15>(),
+            (Tree::LmsSha256N24H15, Ots::LmotsSha256N24W4) => self.verify_generic::<6, 51, 15>(),
+            (Tree::LmsSha256N24H15, Ots::LmotsSha256N24W8) => self.verify_generic::<6, 26, 15>(),
+            (Tree::LmsSha256N24H20, Ots::LmotsSha256N24W1) => self.verify_generic::<6, 200, 20>(),
+            (Tree::LmsSha256N24H20, Ots::LmotsSha256N24W2) => self.verify_generic::<6, 101, 20>(),
+            (Tree::LmsSha256N24H20, Ots::LmotsSha256N24W4) => self.verify_generic::<6, 51, 20>(),
+            (Tree::LmsSha256N24H20, Ots::LmotsSha256N24W8) => self.verify_generic::<6, 26, 20>(),
+            (Tree::LmsSha256N24H25, Ots::LmotsSha256N24W1) => self.verify_generic::<6, 200, 25>(),
+            (Tree::LmsSha256N24H25, Ots::LmotsSha256N24W2) => self.verify_generic::<6, 101, 25>(),
+            (Tree::LmsSha256N24H25, Ots::LmotsSha256N24W4) => self.verify_generic::<6, 51, 25>(),
+            (Tree::LmsSha256N24H25, Ots::LmotsSha256N24W8) => self.verify_generic::<6, 26, 25>(),
+            (Tree::LmsSha256N32H5, Ots::LmotsSha256N32W1) => self.verify_generic::<8, 265, 5>(),
+            (Tree::LmsSha256N32H5, Ots::LmotsSha256N32W2) => self.verify_generic::<8, 133, 5>(),
+            (Tree::LmsSha256N32H5, Ots::LmotsSha256N32W4) => self.verify_generic::<8, 67, 5>(),
+            (Tree::LmsSha256N32H5, Ots::LmotsSha256N32W8) => self.verify_generic::<8, 34, 5>(),
+            (Tree::LmsSha256N32H10, Ots::LmotsSha256N32W1) => self.verify_generic::<8, 265, 10>(),
+            (Tree::LmsSha256N32H10, Ots::LmotsSha256N32W2) => self.verify_generic::<8, 133, 10>(),
+            (Tree::LmsSha256N32H10, Ots::LmotsSha256N32W4) => self.verify_generic::<8, 67, 10>(),
+            (Tree::LmsSha256N32H10, Ots::LmotsSha256N32W8) => self.verify_generic::<8, 34, 10>(),
+            (Tree::LmsSha256N32H15, Ots::LmotsSha256N32W1) => self.verify_generic::<8, 265, 15>(),
+            (Tree::LmsSha256N32H15, Ots::LmotsSha256N32W2) => self.verify_generic::<8, 133, 15>(),
+            (Tree::LmsSha256N32H15, Ots::LmotsSha256N32W4) => self.verify_generic::<8, 67, 15>(),
+            (Tree::LmsSha256N32H15, Ots::LmotsSha256N32W8) => self.verify_generic::<8, 34, 15>(),
+            (Tree::LmsSha256N32H20, Ots::LmotsSha256N32W1) => self.verify_generic::<8, 265, 20>(),
+            (Tree::LmsSha256N32H20, Ots::LmotsSha256N32W2) => self.verify_generic::<8, 133, 20>(),
+            (Tree::LmsSha256N32H20, Ots::LmotsSha256N32W4) => self.verify_generic::<8, 67, 20>(),
+            (Tree::LmsSha256N32H20, Ots::LmotsSha256N32W8) => self.verify_generic::<8, 34, 20>(),
+            (Tree::LmsSha256N32H25, Ots::LmotsSha256N32W1) => self.verify_generic::<8, 265, 25>(),
+            (Tree::LmsSha256N32H25, Ots::LmotsSha256N32W2) => self.verify_generic::<8, 133, 25>(),
+            (Tree::LmsSha256N32H25, Ots::LmotsSha256N32W4) => self.verify_generic::<8, 67, 25>(),
+            (Tree::LmsSha256N32H25, Ots::LmotsSha256N32W8) => self.verify_generic::<8, 34, 25>(),
+            (tree, ots) => panic!("{}: unsupported parameter set {tree:?} {ots:?}", self.name),
+        }
+    }
+
+    fn verify_generic<const N: usize, const P: usize, const H: usize>(
+        &self,
+    ) -> CaliptraResult<LmsResult> {
+        let public_key = LmsPublicKey::<N>::read_from(self.public_key.as_slice())
+            .unwrap_or_else(|| panic!("{}: invalid public key length", self.name));
+        let signature = LmsSignature::<N, P, H>::read_from(self.signature.as_slice())
+            .unwrap_or_else(|| panic!("{}: invalid signature length", self.name));
+        Lms::default().verify_lms_signature_generic(
+            &mut Sha256Sw::new(),
+            &self.message,
+            &public_key,
+            &signature,
+        )
+    }
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn lms_vectors() -> Vec<LmsVector> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/drivers_integration_tests/test_data/lms");
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| LmsVector::parse(path)).collect()
+}
+
+#[test]
+fn test_lms_vectors_verify() {
+    for vector in lms_vectors() {
+        assert_eq!(vector.verify(), Ok(LmsResult::Success), "{}", vector.name);
+    }
+}
+
+#[test]
+fn test_lms_vectors_record_source() {
+    let vectors = lms_vectors();
+    for vector in &vectors {
+        // Generated vectors are seeded with their name, so
+        // gen_lms_vectors.py reproduces them exactly
+        if let Some(seed) = vector.source.strip_prefix("gen_lms_vectors.py, seed ") {
+            assert_eq!(seed, format!("\"{}\"", vector.name), "{}", vector.name);
+        }
+    }
+
+    // Vectors produced independently of the generator
+    let external = vectors
+        .iter()
+        .filter(|vector| vector.source.starts_with("RFC 8554"))
+        .count();
+    assert!(external >= 2);
+}
+
+#[test]
+fn test_lms_vectors_cover_all_parameter_sets() {
+    let covered: HashSet<(u32, u32)> = lms_vectors()
+        .iter()
+        .map(|vector| (vector.lms_type().0.get(), vector.ots_type().0.get()))
+        .collect();
+
+    // LMS_SHA256_M32_H5..H25 with LMOTS_SHA256_N32_W1..W8, and
+    // LMS_SHA256_M24_H5..H25 with LMOTS_SHA256_N24_W1..W8
+    for lms_type in 5..=9 {
+        for ots_type in 1..=4 {
+            assert!(
+                covered.contains(&(lms_type, ots_type)),
+                "{lms_type} {ots_type}"
+            );
+        }
+    }
+    for lms_type in 10..=14 {
+        for ots_type in 5..=8 {
+            assert!(
+                covered.contains(&(lms_type, ots_type)),
+                "{lms_type} {ots_type}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lms_vectors_tampered() {
+    for vector in lms_vectors() {
+        let mut tampered = vector.clone();
+        tampered.message[0] ^= 1;
+        assert_eq!(
+            tampered.verify(),
+            Ok(LmsResult::SigVerifyFailed),
+            "{}: message",
+            vector.name
+        );
+
+        // The public key root
+        let mut tampered = vector.clone();
+        *tampered.public_key.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            tampered.verify(),
+            Ok(LmsResult::SigVerifyFailed),
+            "{}: public key",
+            vector.name
+        );
+
+        // The last authentication path node
+        let mut tampered = vector.clone();
+        *tampered.signature.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            tampered.verify(),
+            Ok(LmsResult::SigVerifyFailed),
+            "{}: signature",
+            vector.name
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "lms-tree-checks")]
+fn test_lms_vectors_tree_type_mismatch() {
+    use caliptra_drivers::CaliptraError;
+    use zerocopy::AsBytes;
+
+    for vector in lms_vectors() {
+        let mut tampered = vector.clone();
+        let other_type = match vector.lms_type() {
+            LmsAlgorithmType::LmsSha256N32H5 => LmsAlgorithmType::LmsSha256N32H10,
+            _ => LmsAlgorithmType::LmsSha256N32H5,
+        };
+        tampered.public_key[..4].copy_from_slice(other_type.as_bytes());
+        assert_eq!(
+            tampered.verify(),
+            Err(CaliptraError::DRIVER_LMS_SIGNATURE_LMS_DOESNT_MATCH_PUBKEY_LMS),
+            "{}",
+            vector.name
+        );
+    }
+}
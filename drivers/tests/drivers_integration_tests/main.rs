@@ -1,5 +1,7 @@
 // Licensed under the Apache-2.0 license
 
+mod lms_vectors;
+
 use std::error::Error;
 use std::iter;
 
@@ -764,6 +766,11 @@ fn test_negative_lms() {
     run_driver_test(&firmware::driver_tests::TEST_NEGATIVE_LMS);
 }
 
+#[test]
+fn test_mldsa87() {
+    run_driver_test(&firmware::driver_tests::TEST_MLDSA87);
+}
+
 // Return a series of nibbles that won't fail health tests.
 // Used for testing the CSRNG's "success paths".
 fn trng_nibbles() -> impl Iterator<Item = u8> + Clone {
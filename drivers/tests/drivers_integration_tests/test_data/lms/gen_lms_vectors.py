@@ -0,0 +1,128 @@
+#!/usr/bin/env python3
+
+# Licensed under the Apache-2.0 license
+
+"""Regenerate the generated LMS vectors in this directory.
+
+Each vector is signed with a one-time key at a random leaf of a tree whose
+other nodes are random, so the public key root is computed from the
+signature rather than from a complete tree. This keeps the H20 and H25
+parameter sets cheap to produce. The randomness is seeded with the vector
+name, so running this script reproduces the checked-in files byte for byte.
+
+Vectors from external sources (see README.md) are not touched.
+"""
+
+import hashlib
+import os
+import random
+import struct
+
+D_PBLC, D_MESG, D_LEAF, D_INTR = 0x8080, 0x8181, 0x8282, 0x8383
+
+# LM-OTS type: (n, w, p, ls), RFC 8554 Table 1 and SP 800-208 Table 3
+LMOTS = {
+    1: (32, 1, 265, 7), 2: (32, 2, 133, 6), 3: (32, 4, 67, 4), 4: (32, 8, 34, 0),
+    5: (24, 1, 200, 8), 6: (24, 2, 101, 6), 7: (24, 4, 51, 4), 8: (24, 8, 26, 0),
+}
+# LMS type: (m, h), RFC 8554 Table 2 and SP 800-208 Table 2
+LMS = {
+    5: (32, 5), 6: (32, 10), 7: (32, 15), 8: (32, 20), 9: (32, 25),
+    10: (24, 5), 11: (24, 10), 12: (24, 15), 13: (24, 20), 14: (24, 25),
+}
+
+
+def H(*parts, n):
+    return hashlib.sha256(b"".join(parts)).digest()[:n]
+
+
+def coef(s, i, w):
+    return (s[i * w // 8] >> (8 - (w * (i % (8 // w)) + w))) & ((1 << w) - 1)
+
+
+def cksm(q, n, w, ls):
+    s = sum((1 << w) - 1 - coef(q, i, w) for i in range(n * 8 // w))
+    return struct.pack(">H", (s << ls) & 0xffff)
+
+
+def chain(I, q, i, start, end, tmp, n):
+    for j in range(start, end):
+        tmp = H(I, struct.pack(">IHB", q, i, j), tmp, n=n)
+    return tmp
+
+
+def root(I, q, h, leaf, path, n):
+    node = (1 << h) + q
+    tmp = H(I, struct.pack(">IH", node, D_LEAF), leaf, n=n)
+    for sibling in path:
+        if node % 2 == 1:
+            tmp = H(I, struct.pack(">IH", node // 2, D_INTR), sibling, tmp, n=n)
+        else:
+            tmp = H(I, struct.pack(">IH", node // 2, D_INTR), tmp, sibling, n=n)
+        node //= 2
+    return tmp
+
+
+def verify(message, pub, sig):
+    lms_type, ots_type = struct.unpack(">II", pub[:8])
+    I, T1 = pub[8:24], pub[24:]
+    n, h = LMS[lms_type]
+    _, w, p, ls = LMOTS[ots_type]
+    q, sig_ots = struct.unpack(">II", sig[:8])
+    C = sig[8:8 + n]
+    off = 8 + n
+    y = [sig[off + i * n:off + (i + 1) * n] for i in range(p)]
+    off += p * n
+    (sig_lms,) = struct.unpack(">I", sig[off:off + 4])
+    off += 4
+    path = [sig[off + i * n:off + (i + 1) * n] for i in range(h)]
+    if sig_ots != ots_type or sig_lms != lms_type or off + h * n != len(sig):
+        return False
+    Q = H(I, struct.pack(">IH", q, D_MESG), C, message, n=n)
+    Qa = Q + cksm(Q, n, w, ls)
+    z = [chain(I, q, i, coef(Qa, i, w), (1 << w) - 1, y[i], n) for i in range(p)]
+    Kc = H(I, struct.pack(">IH", q, D_PBLC), *z, n=n)
+    return root(I, q, h, Kc, path, n) == T1
+
+
+def sign(rng, message, lms_type, ots_type):
+    n, h = LMS[lms_type]
+    _, w, p, ls = LMOTS[ots_type]
+    I = rng.randbytes(16)
+    q = rng.randrange(1 << h)
+    x = [rng.randbytes(n) for _ in range(p)]
+    C = rng.randbytes(n)
+    Q = H(I, struct.pack(">IH", q, D_MESG), C, message, n=n)
+    Qa = Q + cksm(Q, n, w, ls)
+    y = [chain(I, q, i, 0, coef(Qa, i, w), x[i], n) for i in range(p)]
+    K = H(I, struct.pack(">IH", q, D_PBLC),
+          *[chain(I, q, i, 0, (1 << w) - 1, x[i], n) for i in range(p)], n=n)
+    path = [rng.randbytes(n) for _ in range(h)]
+    pub = struct.pack(">II", lms_type, ots_type) + I + root(I, q, h, K, path, n)
+    sig = (struct.pack(">II", q, ots_type) + C + b"".join(y)
+           + struct.pack(">I", lms_type) + b"".join(path))
+    return pub, sig
+
+
+def main():
+    out = os.path.dirname(os.path.abspath(__file__))
+    for lms_type, (n, h) in sorted(LMS.items()):
+        for ots_type, (ots_n, w, _, _) in sorted(LMOTS.items()):
+            if ots_n != n:
+                continue
+            name = f"sha256_n{n}_h{h}_w{w}"
+            rng = random.Random(name)
+            message = f"LMS test vector {name}".encode()
+            pub, sig = sign(rng, message, lms_type, ots_type)
+            assert verify(message, pub, sig)
+            assert not verify(message + b"\0", pub, sig)
+            with open(os.path.join(out, name + ".txt"), "w") as f:
+                f.write(f"# LMS_SHA256_M{n}_H{h} / LMOTS_SHA256_N{n}_W{w}\n")
+                f.write(f"source = gen_lms_vectors.py, seed \"{name}\"\n")
+                f.write(f"message = {message.hex()}\n")
+                f.write(f"public_key = {pub.hex()}\n")
+                f.write(f"signature = {sig.hex()}\n")
+
+
+if __name__ == "__main__":
+    main()
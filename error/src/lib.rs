@@ -262,6 +262,52 @@ impl CaliptraError {
         CaliptraError::new_const(0x000b0040);
     pub const IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS: CaliptraError =
         CaliptraError::new_const(0x000b0041);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_COUNT_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000b0042);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_ID_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0043);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_TYPE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0044);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_SIZE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0045);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_OFFSET_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0046);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0047);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_OVERLAP: CaliptraError =
+        CaliptraError::new_const(0x000b0048);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_ENTRY_POINT_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0049);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b004a);
+    pub const IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000b004b);
+    pub const IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING: CaliptraError =
+        CaliptraError::new_const(0x000b004c);
+    pub const IMAGE_VERIFIER_ERR_MLDSA_PUB_KEY_DIGEST_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b004d);
+    pub const IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b0050);
+    pub const IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0051);
+    pub const IMAGE_VERIFIER_ERR_OWNER_MLDSA_VERIFY_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b0052);
+    pub const IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0053);
+    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_LEVELS_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0054);
+    pub const IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_LEVELS_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0055);
+    pub const IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING: CaliptraError =
+        CaliptraError::new_const(0x000b0056);
+    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_VERIFY_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b0057);
+    pub const IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b0058);
+    pub const IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_VERIFY_FAILURE: CaliptraError =
+        CaliptraError::new_const(0x000b0059);
+    pub const IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000b005a);
 
     /// Driver Error: LMS
     pub const DRIVER_LMS_INVALID_LMS_ALGO_TYPE: CaliptraError =
@@ -291,6 +337,8 @@ impl CaliptraError {
 
     pub const DRIVER_LMS_SIGNATURE_LMOTS_DOESNT_MATCH_PUBKEY_LMOTS: CaliptraError =
         CaliptraError::new_const(0x000c000d);
+    pub const DRIVER_LMS_SIGNATURE_LMS_DOESNT_MATCH_PUBKEY_LMS: CaliptraError =
+        CaliptraError::new_const(0x000c000e);
 
     /// CSRNG Errors
     pub const DRIVER_CSRNG_INSTANTIATE: CaliptraError = CaliptraError::new_const(0x000d0001);
@@ -414,6 +462,44 @@ impl CaliptraError {
     pub const RUNTIME_PCR_RESERVED: CaliptraError = CaliptraError::new_const(0x000E003D);
     pub const RUNTIME_PCR_INVALID_INDEX: CaliptraError = CaliptraError::new_const(0x000E003F);
 
+    /// Certificate chain Runtime Errors
+    pub const RUNTIME_GET_CERT_CHAIN_INVALID_OFFSET: CaliptraError =
+        CaliptraError::new_const(0x000E0040);
+    pub const RUNTIME_GET_CERT_CHAIN_MALFORMED_CERT: CaliptraError =
+        CaliptraError::new_const(0x000E0041);
+    pub const RUNTIME_GET_CERT_CHAIN_TOO_MANY_CERTS: CaliptraError =
+        CaliptraError::new_const(0x000E0042);
+    pub const RUNTIME_GET_CERT_CHAIN_DPE_LEAF_FAILED: CaliptraError =
+        CaliptraError::new_const(0x000E0043);
+
+    /// Attestation token Runtime Errors
+    pub const RUNTIME_CBOR_ENCODE_FAILED: CaliptraError = CaliptraError::new_const(0x000E0044);
+    pub const RUNTIME_EAT_DPE_SIGN_FAILED: CaliptraError = CaliptraError::new_const(0x000E0045);
+
+    /// Chunked mailbox transfer Runtime Errors
+    pub const RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE: CaliptraError =
+        CaliptraError::new_const(0x000E0046);
+    pub const RUNTIME_CHUNKED_INVALID_OFFSET: CaliptraError = CaliptraError::new_const(0x000E0047);
+    pub const RUNTIME_CHUNKED_NOT_STARTED: CaliptraError = CaliptraError::new_const(0x000E0048);
+    pub const RUNTIME_CHUNKED_INCOMPLETE: CaliptraError = CaliptraError::new_const(0x000E0049);
+    pub const RUNTIME_CHUNKED_INVALID_CHECKSUM: CaliptraError =
+        CaliptraError::new_const(0x000E004A);
+    pub const RUNTIME_IDEV_CERT_PARSE_FAILED: CaliptraError = CaliptraError::new_const(0x000E004B);
+    pub const RUNTIME_IDEV_CERT_UNSUPPORTED_ALGORITHM: CaliptraError =
+        CaliptraError::new_const(0x000E004C);
+    pub const RUNTIME_IDEV_CERT_PUB_KEY_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000E004D);
+    pub const RUNTIME_IDEV_CERT_ISSUER_MISMATCH: CaliptraError =
+        CaliptraError::new_const(0x000E004E);
+    pub const RUNTIME_IDEV_CSR_UNAVAILABLE: CaliptraError = CaliptraError::new_const(0x000E004F);
+    pub const RUNTIME_SET_TIME_INVALID_TIME: CaliptraError = CaliptraError::new_const(0x000E0050);
+    pub const RUNTIME_SET_TIME_SIGNATURE_INVALID: CaliptraError =
+        CaliptraError::new_const(0x000E0051);
+    pub const RUNTIME_SET_TIME_ROLLBACK: CaliptraError = CaliptraError::new_const(0x000E0052);
+    pub const RUNTIME_CERT_NOT_YET_VALID: CaliptraError = CaliptraError::new_const(0x000E0053);
+    pub const RUNTIME_CERT_EXPIRED: CaliptraError = CaliptraError::new_const(0x000E0054);
+    pub const RUNTIME_SET_TIME_NO_NONCE: CaliptraError = CaliptraError::new_const(0x000E0055);
+
     /// FMC Errors
     pub const FMC_GLOBAL_NMI: CaliptraError = CaliptraError::new_const(0x000F0001);
     pub const FMC_GLOBAL_EXCEPTION: CaliptraError = CaliptraError::new_const(0x000F0002);
@@ -428,6 +514,7 @@ impl CaliptraError {
     pub const ADDRESS_NOT_IN_ICCM: CaliptraError = CaliptraError::new_const(0x000F000B);
     pub const FMC_HANDOFF_NOT_READY_FOR_RT: CaliptraError = CaliptraError::new_const(0x000F000C);
     pub const FMC_GLOBAL_WDT_EXPIRED: CaliptraError = CaliptraError::new_const(0x000F000D);
+    pub const FMC_RT_ALIAS_CWT_ENCODE_FAILURE: CaliptraError = CaliptraError::new_const(0x000F000E);
 
     /// TRNG_EXT Errors
     pub const DRIVER_TRNG_EXT_TIMEOUT: CaliptraError = CaliptraError::new_const(0x00100001);
@@ -534,6 +621,7 @@ impl CaliptraError {
 
     pub const ROM_GLOBAL_MEASUREMENT_LOG_EXHAUSTED: CaliptraError =
         CaliptraError::new_const(0x0105000D);
+    pub const ROM_GLOBAL_CWT_ENCODE_FAILURE: CaliptraError = CaliptraError::new_const(0x0105000E);
 
     /// ROM KAT Errors
     pub const ROM_KAT_SHA256_DIGEST_FAILURE: CaliptraError = CaliptraError::new_const(0x90010001);
@@ -23,13 +23,14 @@ use crate::flow::x509::X509;
 use crate::fmc_env::FmcEnv;
 use crate::FmcBootStatus;
 use crate::HandOff;
+use caliptra_cbor_cert::{ConfigDescriptor, DiceCwtParams, DiceCwtTbs};
 use caliptra_common::cprintln;
 use caliptra_common::crypto::Ecc384KeyPair;
 use caliptra_common::keyids::{KEY_ID_RT_CDI, KEY_ID_RT_PRIV_KEY, KEY_ID_TMP};
 use caliptra_common::HexBytes;
 use caliptra_drivers::{
-    okref, report_boot_status, CaliptraError, CaliptraResult, Ecc384Result, KeyId, PersistentData,
-    ResetReason,
+    okref, report_boot_status, CaliptraError, CaliptraResult, DiceCertFormat, Ecc384Result, KeyId,
+    PersistentData, ResetReason,
 };
 use caliptra_x509::{NotAfter, NotBefore, RtAliasCertTbs, RtAliasCertTbsParams};
 
@@ -286,31 +287,57 @@ impl RtAliasLayer {
         let auth_pub_key = &input.auth_key_pair.pub_key;
         let pub_key = &output.subj_key_pair.pub_key;
 
-        let serial_number = &X509::cert_sn(env, pub_key)?;
-
         let rt_tci: [u8; 48] = HandOff::rt_tci(env).into();
         let rt_svn = HandOff::rt_svn(env) as u8;
 
-        // Certificate `To Be Signed` Parameters
-        let params = RtAliasCertTbsParams {
-            // Do we need the UEID here?
-            ueid: &X509::ueid(env)?,
-            subject_sn: &output.subj_sn,
-            subject_key_id: &output.subj_key_id,
-            issuer_sn: &input.auth_sn,
-            authority_key_id: &input.auth_key_id,
-            serial_number,
-            public_key: &pub_key.to_der(),
-            not_before,
-            not_after,
-            tcb_info_rt_svn: &rt_svn.to_be_bytes(),
-            tcb_info_rt_tci: &rt_tci,
-            // Are there any fields missing?
+        let x509_tbs;
+        let cwt_tbs;
+        let (tbs, signed_data): (&[u8], &[u8]) = match env.soc_ifc.fuse_bank().dice_cert_format() {
+            DiceCertFormat::X509 => {
+                let serial_number = &X509::cert_sn(env, pub_key)?;
+
+                // Certificate `To Be Signed` Parameters
+                let params = RtAliasCertTbsParams {
+                    // Do we need the UEID here?
+                    ueid: &X509::ueid(env)?,
+                    subject_sn: &output.subj_sn,
+                    subject_key_id: &output.subj_key_id,
+                    issuer_sn: &input.auth_sn,
+                    authority_key_id: &input.auth_key_id,
+                    serial_number,
+                    public_key: &pub_key.to_der(),
+                    not_before,
+                    not_after,
+                    tcb_info_rt_svn: &rt_svn.to_be_bytes(),
+                    tcb_info_rt_tci: &rt_tci,
+                    // Are there any fields missing?
+                };
+
+                // Generate the `To Be Signed` portion of the CSR
+                x509_tbs = RtAliasCertTbs::new(&params);
+                (x509_tbs.tbs(), x509_tbs.tbs())
+            }
+            DiceCertFormat::Cbor => {
+                // CWT claims, signed through their COSE Sig_structure
+                let params = DiceCwtParams {
+                    issuer: &input.auth_sn,
+                    subject: &output.subj_sn,
+                    public_key_x: &(&pub_key.x).into(),
+                    public_key_y: &(&pub_key.y).into(),
+                    mode: X509::dice_mode(env),
+                    code_hash: Some(&rt_tci),
+                    authority_hash: None,
+                    config_desc: Some(ConfigDescriptor {
+                        component_name: "caliptra-rt",
+                        security_version: rt_svn.into(),
+                    }),
+                };
+                cwt_tbs = DiceCwtTbs::new(&params)
+                    .ok_or(CaliptraError::FMC_RT_ALIAS_CWT_ENCODE_FAILURE)?;
+                (cwt_tbs.tbs(), cwt_tbs.sig_structure())
+            }
         };
 
-        // Generate the `To Be Signed` portion of the CSR
-        let tbs = RtAliasCertTbs::new(&params);
-
         // Sign the the `To Be Signed` portion
         cprintln!(
             "[alias rt] Signing Cert with AUTHO
@@ -321,7 +348,7 @@ impl RtAliasLayer {
         // Sign the AliasRt To Be Signed DER Blob with AliasFMC Private Key in Key Vault Slot 7
         // AliasRtTbsDigest = sha384_digest(AliasRtTbs) AliaRtTbsCertSig = ecc384_sign(KvSlot5, AliasFmcTbsDigest)
 
-        let sig = Crypto::ecdsa384_sign(env, auth_priv_key, auth_pub_key, tbs.tbs());
+        let sig = Crypto::ecdsa384_sign(env, auth_priv_key, auth_pub_key, signed_data);
         let sig = okref(&sig)?;
         // Clear the authority private key
         cprintln!(
@@ -343,15 +370,15 @@ impl RtAliasLayer {
         cprintln!("[alias rt] SIG.S = {}", HexBytes(&_sig_s));
 
         // Verify the signature of the `To Be Signed` portion
-        if Crypto::ecdsa384_verify(env, auth_pub_key, tbs.tbs(), sig)? != Ecc384Result::Success {
+        if Crypto::ecdsa384_verify(env, auth_pub_key, signed_data, sig)? != Ecc384Result::Success {
             return Err(CaliptraError::FMC_RT_ALIAS_CERT_VERIFY);
         }
 
         HandOff::set_rt_dice_signature(env, sig);
 
         //  Copy TBS to DCCM and set size in FHT.
-        Self::copy_tbs(tbs.tbs(), env.persistent_data.get_mut())?;
-        HandOff::set_rtalias_tbs_size(env, tbs.tbs().len());
+        Self::copy_tbs(tbs, env.persistent_data.get_mut())?;
+        HandOff::set_rtalias_tbs_size(env, tbs.len());
 
         report_boot_status(FmcBootStatus::RtAliasCertSigGenerationComplete as u32);
 
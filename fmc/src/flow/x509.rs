@@ -13,12 +13,29 @@ Abstract:
 --*/
 use super::crypto::Crypto;
 use crate::fmc_env::FmcEnv;
+use caliptra_cbor_cert::DiceMode;
 use caliptra_drivers::*;
 
 /// X509 API
 pub enum X509 {}
 
 impl X509 {
+    /// Get the Open Profile for DICE mode of the device
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - FMC Environment
+    ///
+    /// # Returns
+    ///
+    /// `DiceMode` - Mode recorded in CBOR certificates
+    pub fn dice_mode(env: &FmcEnv) -> DiceMode {
+        DiceMode::from_security_state(
+            env.soc_ifc.lifecycle() == Lifecycle::Production,
+            env.soc_ifc.debug_locked(),
+        )
+    }
+
     /// Get X509 Subject Serial Number
     ///
     /// # Arguments
@@ -130,4 +130,7 @@ impl<TBus: Bus> Bus for BusLogger<TBus> {
     fn update_reset(&mut self) {
         self.bus.update_reset();
     }
+    fn ext_int_pending(&self) -> bool {
+        self.bus.ext_int_pending()
+    }
 }
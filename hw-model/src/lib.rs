@@ -10,7 +10,10 @@ use std::{
 };
 
 use api::calc_checksum;
-use api::mailbox::{MailboxReqHeader, MailboxRespHeader, Response};
+use api::mailbox::{
+    ChunkedBeginReq, ChunkedDataReq, ChunkedReadReq, ChunkedResp, MailboxReq, MailboxReqHeader,
+    MailboxRespHeader, Response,
+};
 use caliptra_api as api;
 use caliptra_emu_bus::Bus;
 use caliptra_hw_model_types::{
@@ -264,6 +267,36 @@ impl<'a> Default for BootParams<'a> {
     }
 }
 
+/// Validates the length, checksum and FIPS status of the mailbox response
+/// `response_bytes` and converts it to `R`.
+fn parse_mailbox_resp<R: Response>(response_bytes: &[u8]) -> std::result::Result<R, ModelError> {
+    if response_bytes.len() < R::MIN_SIZE || response_bytes.len() > mem::size_of::<R>() {
+        return Err(ModelError::MailboxUnexpectedResponseLen {
+            expected_min: R::MIN_SIZE as u32,
+            expected_max: mem::size_of::<R>() as u32,
+            actual: response_bytes.len() as u32,
+        });
+    }
+
+    let mut response = R::new_zeroed();
+    response.as_bytes_mut()[..response_bytes.len()].copy_from_slice(response_bytes);
+
+    let response_header = MailboxRespHeader::read_from_prefix(response_bytes).unwrap();
+    let actual_checksum = calc_checksum(0, &response_bytes[4..]);
+    if actual_checksum != response_header.chksum {
+        return Err(ModelError::MailboxRespInvalidChecksum {
+            expected: response_header.chksum,
+            actual: actual_checksum,
+        });
+    }
+    if response_header.fips_status != MailboxRespHeader::FIPS_STATUS_APPROVED {
+        return Err(ModelError::MailboxRespInvalidFipsStatus(
+            response_header.fips_status,
+        ));
+    }
+    Ok(response)
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum ModelError {
     MailboxCmdFailed(u32),
@@ -286,6 +319,7 @@ pub enum ModelError {
     UnableToReadMailbox,
     MailboxNoResponseData,
     MailboxReqTypeTooSmall,
+    MailboxReqInvalid(u32),
     MailboxRespTypeTooSmall,
     MailboxUnexpectedResponseLen {
         expected_min: u32,
@@ -334,6 +368,9 @@ impl Display for ModelError {
             ModelError::MailboxReqTypeTooSmall => {
                 write!(f, "Mailbox request type too small to contain header")
             }
+            ModelError::MailboxReqInvalid(err) => {
+                write!(f, "Mailbox request could not be encoded. err=0x{err:x}")
+            }
             ModelError::MailboxRespTypeTooSmall => {
                 write!(f, "Mailbox response type too small to contain header")
             }
@@ -840,6 +877,12 @@ pub trait HwModel {
 
     fn ecc_error_injection(&mut self, _mode: ErrorInjectionMode) {}
 
+    /// Returns the number of clock cycles the CPU has spent halted in
+    /// low-power idle, or None if the model can't measure it.
+    fn halted_cycles(&self) -> Option<u64> {
+        None
+    }
+
     fn set_apb_pauser(&mut self, pauser: u32);
 
     /// Executes a typed request and (if success), returns the typed response.
@@ -869,34 +912,105 @@ pub trait HwModel {
         let Some(response_bytes) = self.mailbox_execute(R::ID.into(), req.as_bytes())? else {
             return Err(ModelError::MailboxNoResponseData);
         };
-        if response_bytes.len() < R::Resp::MIN_SIZE
-            || response_bytes.len() > mem::size_of::<R::Resp>()
-        {
+        parse_mailbox_resp(&response_bytes)
+    }
+
+    /// Executes `cmd` with request data `buf` using a chunked transfer
+    /// (CHUNKED_BEGIN, CHUNKED_CONTINUE, CHUNKED_END and CHUNKED_READ), for
+    /// requests or responses too large for a single mailbox transaction.
+    /// `buf` must start with the request header, including its checksum.
+    /// Returns the complete response, after validating the checksum over
+    /// the whole payload.
+    fn mailbox_execute_chunked(
+        &mut self,
+        cmd: u32,
+        buf: &[u8],
+    ) -> std::result::Result<Vec<u8>, ModelError> {
+        let mut chunks = buf.chunks(ChunkedDataReq::DATA_MAX_SIZE);
+
+        let first = chunks.next().unwrap_or_default();
+        let mut begin = ChunkedBeginReq {
+            cmd,
+            total_size: buf.len() as u32,
+            payload_chksum: calc_checksum(cmd, buf),
+            data_size: first.len() as u32,
+            ..Default::default()
+        };
+        begin.data[..first.len()].copy_from_slice(first);
+        self.mailbox_execute_chunked_req(MailboxReq::ChunkedBegin(begin))?;
+
+        let mut offset = first.len();
+        let mut chunks = chunks.peekable();
+        let end_resp = loop {
+            let chunk = chunks.next().unwrap_or_default();
+            let mut req = ChunkedDataReq {
+                offset: offset as u32,
+                data_size: chunk.len() as u32,
+                ..Default::default()
+            };
+            req.data[..chunk.len()].copy_from_slice(chunk);
+            offset += chunk.len();
+            if chunks.peek().is_some() {
+                self.mailbox_execute_chunked_req(MailboxReq::ChunkedContinue(req))?;
+            } else {
+                let Some(resp_bytes) =
+                    self.mailbox_execute_chunked_req(MailboxReq::ChunkedEnd(req))?
+                else {
+                    return Err(ModelError::MailboxNoResponseData);
+                };
+                break parse_mailbox_resp::<ChunkedResp>(&resp_bytes)?;
+            };
+        };
+
+        let total_size = end_resp.total_size as usize;
+        let payload_chksum = end_resp.payload_chksum;
+        let mut resp = Vec::with_capacity(total_size);
+        let mut chunk = end_resp;
+        loop {
+            let data = chunk
+                .data()
+                .ok_or(ModelError::MailboxUnexpectedResponseLen {
+                    expected_min: 0,
+                    expected_max: ChunkedResp::DATA_MAX_SIZE as u32,
+                    actual: chunk.data_size,
+                })?;
+            resp.extend_from_slice(data);
+            if resp.len() >= total_size || data.is_empty() {
+                break;
+            }
+            chunk = self.mailbox_execute_req(ChunkedReadReq {
+                offset: resp.len() as u32,
+                ..Default::default()
+            })?;
+        }
+        if resp.len() != total_size {
             return Err(ModelError::MailboxUnexpectedResponseLen {
-                expected_min: R::Resp::MIN_SIZE as u32,
-                expected_max: mem::size_of::<R::Resp>() as u32,
-                actual: response_bytes.len() as u32,
+                expected_min: total_size as u32,
+                expected_max: total_size as u32,
+                actual: resp.len() as u32,
             });
         }
-
-        let mut response = R::Resp::new_zeroed();
-        response.as_bytes_mut()[..response_bytes.len()].copy_from_slice(&response_bytes);
-
-        let response_header =
-            MailboxRespHeader::read_from_prefix(response_bytes.as_slice()).unwrap();
-        let actual_checksum = calc_checksum(0, &response_bytes[4..]);
-        if actual_checksum != response_header.chksum {
+        let actual_checksum = calc_checksum(0, &resp);
+        if actual_checksum != payload_chksum {
             return Err(ModelError::MailboxRespInvalidChecksum {
-                expected: response_header.chksum,
+                expected: payload_chksum,
                 actual: actual_checksum,
             });
         }
-        if response_header.fips_status != MailboxRespHeader::FIPS_STATUS_APPROVED {
-            return Err(ModelError::MailboxRespInvalidFipsStatus(
-                response_header.fips_status,
-            ));
-        }
-        Ok(response)
+        Ok(resp)
+    }
+
+    /// Populates the checksum of `req` and executes it
+    fn mailbox_execute_chunked_req(
+        &mut self,
+        mut req: MailboxReq,
+    ) -> std::result::Result<Option<Vec<u8>>, ModelError> {
+        req.populate_chksum()
+            .map_err(|e| ModelError::MailboxReqInvalid(e.into()))?;
+        let req_bytes = req
+            .as_bytes()
+            .map_err(|e| ModelError::MailboxReqInvalid(e.into()))?;
+        self.mailbox_execute(req.cmd_code().into(), req_bytes)
     }
 
     /// Executes `cmd` with request data `buf`. Returns `Ok(Some(_))` if
@@ -254,6 +254,10 @@ impl crate::HwModel for ModelEmulated {
         }
     }
 
+    fn halted_cycles(&self) -> Option<u64> {
+        Some(self.cpu.halted_cycles())
+    }
+
     fn set_apb_pauser(&mut self, _pauser: u32) {
         unimplemented!();
     }
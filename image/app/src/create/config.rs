@@ -27,6 +27,17 @@ pub(crate) struct VendorKeyConfig {
     pub ecc_priv_keys: Option<[String; VENDOR_ECC_KEY_COUNT as usize]>,
 
     pub lms_priv_keys: Option<[String; VENDOR_LMS_KEY_COUNT as usize]>,
+
+    /// Signed public keys of the HSS levels below the selected LMS key, top to
+    /// bottom, as written by the `hss-sign` command
+    pub lms_hss_signed_pub_keys: Option<Vec<String>>,
+
+    /// LMS private key of the bottom HSS level
+    pub lms_hss_priv_key: Option<String>,
+
+    pub mldsa_pub_key: Option<String>,
+
+    pub mldsa_priv_key: Option<String>,
 }
 
 /// Owner Key Configuration
@@ -39,11 +50,25 @@ pub(crate) struct OwnerKeyConfig {
     pub lms_pub_key: String,
 
     pub lms_priv_key: Option<String>,
+
+    /// Signed public keys of the HSS levels below the owner LMS key, top to
+    /// bottom, as written by the `hss-sign` command
+    pub lms_hss_signed_pub_keys: Option<Vec<String>>,
+
+    /// LMS private key of the bottom HSS level
+    pub lms_hss_priv_key: Option<String>,
+
+    pub mldsa_pub_key: Option<String>,
+
+    pub mldsa_priv_key: Option<String>,
 }
 
 //Key Configuration
 #[derive(Default, Serialize, Deserialize)]
 pub(crate) struct KeyConfig {
+    // Optional so that an owner can re-sign an image with an owner-only
+    // configuration.
+    #[serde(default)]
     pub vendor: VendorKeyConfig,
 
     pub owner: Option<OwnerKeyConfig>,
@@ -12,7 +12,7 @@ Abstract:
 
 --*/
 
-mod config;
+pub(crate) mod config;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -21,13 +21,18 @@ use caliptra_image_openssl::ecc_priv_key_from_pem;
 use caliptra_image_openssl::ecc_pub_key_from_pem;
 use caliptra_image_openssl::lms_priv_key_from_pem;
 use caliptra_image_openssl::lms_pub_key_from_pem;
+use caliptra_image_openssl::lms_signed_pub_key_from_file;
+use caliptra_image_openssl::mldsa_priv_key_from_file;
+use caliptra_image_openssl::mldsa_pub_key_from_file;
+use caliptra_image_openssl::LmsKeyState;
+use caliptra_image_openssl::OsslCrypto;
 use caliptra_image_serde::ImageBundleWriter;
 use caliptra_image_types::*;
 use clap::ArgMatches;
 use std::path::Path;
 use std::path::PathBuf;
 
-use caliptra_image_elf::ElfExecutable;
+use caliptra_image_elf::{ElfExecutable, ElfLayout};
 use config::{OwnerKeyConfig, VendorKeyConfig};
 
 use chrono::NaiveDate;
@@ -37,7 +42,7 @@ use chrono::NaiveDate;
 /// and extracts year, month and day from the string
 /// and then performs some basic validity checks
 ///
-fn check_date(from_date: &str, to_date: &str) -> anyhow::Result<bool> {
+pub(crate) fn check_date(from_date: &str, to_date: &str) -> anyhow::Result<bool> {
     let time_fmt = "YYYYMMDDHHMMSS";
 
     let current_date = chrono::Utc::now().date_naive();
@@ -149,19 +154,21 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
     let config = config::load_key_config(config_path)?;
 
     let fmc_rev = hex::decode(fmc_rev)?;
-    let fmc = ElfExecutable::open(
+    let fmc = ElfExecutable::open_with_layout(
         fmc_path,
         *fmc_version,
         *fmc_svn,
         fmc_rev[..IMAGE_REVISION_BYTE_SIZE].try_into()?,
+        &ElfLayout::fmc(),
     )?;
 
     let runtime_rev = hex::decode(runtime_rev)?;
-    let runtime = ElfExecutable::open(
+    let runtime = ElfExecutable::open_with_layout(
         runtime_path,
         *runtime_version,
         *runtime_svn,
         runtime_rev[..IMAGE_REVISION_BYTE_SIZE].try_into()?,
+        &ElfLayout::runtime(),
     )?;
 
     let config_dir = config_path
@@ -180,10 +187,28 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
         owner_config: owner_config(config_dir, &config.owner, own_from_date, own_to_date)?,
         fmc,
         runtime,
+        components: Vec::new(),
     };
 
-    let gen = ImageGenerator::new(caliptra_image_openssl::OsslCrypto::default());
-    let image = gen.generate(&gen_config).unwrap();
+    // LMS leaves must never be reused, so signing with LMS private keys
+    // requires a state file recording the leaves already used.
+    let signs_with_lms = config.vendor.lms_priv_keys.is_some()
+        || config.vendor.lms_hss_priv_key.is_some()
+        || config.owner.as_ref().map_or(false, |owner| {
+            owner.lms_priv_key.is_some() || owner.lms_hss_priv_key.is_some()
+        });
+    let crypto = match args.get_one::<PathBuf>("lms-state") {
+        Some(path) => OsslCrypto::with_lms_state(LmsKeyState::open(path)?),
+        None if signs_with_lms && !args.get_flag("insecure-fixed-lms-q") => {
+            return Err(anyhow!(
+                "--lms-state is required when signing with LMS private keys"
+            ))
+        }
+        None => OsslCrypto::default(),
+    };
+
+    let gen = ImageGenerator::new(crypto);
+    let image = gen.generate(&gen_config)?;
 
     let out_file = std::fs::OpenOptions::new()
         .create(true)
@@ -199,7 +224,7 @@ pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
 }
 
 /// Generate Vendor Config
-fn vendor_config(
+pub(crate) fn vendor_config(
     path: &Path,
     config: &VendorKeyConfig,
     ecc_key_idx: u32,
@@ -255,6 +280,20 @@ fn vendor_config(
         gen_config.priv_keys = Some(priv_keys);
     }
 
+    gen_config.lms_hss = hss_config(
+        path,
+        &config.lms_hss_signed_pub_keys,
+        &config.lms_hss_priv_key,
+    )?;
+
+    if let Some(key_file) = &config.mldsa_pub_key {
+        gen_config.mldsa_pub_key = Some(mldsa_pub_key_from_file(&path.join(key_file))?);
+    }
+
+    if let Some(key_file) = &config.mldsa_priv_key {
+        gen_config.mldsa_priv_key = Some(mldsa_priv_key_from_file(&path.join(key_file))?);
+    }
+
     gen_config.ecc_key_idx = ecc_key_idx;
     gen_config.lms_key_idx = lms_key_idx;
     gen_config.not_before = from_date;
@@ -264,7 +303,7 @@ fn vendor_config(
 }
 
 /// Generate owner config
-fn owner_config(
+pub(crate) fn owner_config(
     path: &Path,
     config: &Option<OwnerKeyConfig>,
     from_date: [u8; 15],
@@ -294,6 +333,20 @@ fn owner_config(
             priv_keys.lms_priv_key = lms_priv_key_from_pem(&priv_key_path)?;
             gen_config.priv_keys = Some(priv_keys);
         }
+
+        gen_config.lms_hss = hss_config(
+            path,
+            &config.lms_hss_signed_pub_keys,
+            &config.lms_hss_priv_key,
+        )?;
+
+        if let Some(key_file) = &config.mldsa_pub_key {
+            gen_config.mldsa_pub_key = Some(mldsa_pub_key_from_file(&path.join(key_file))?);
+        }
+
+        if let Some(key_file) = &config.mldsa_priv_key {
+            gen_config.mldsa_priv_key = Some(mldsa_priv_key_from_file(&path.join(key_file))?);
+        }
         gen_config.not_before = from_date;
         gen_config.not_after = to_date;
 
@@ -302,3 +355,29 @@ fn owner_config(
         Ok(None)
     }
 }
+
+/// Generate HSS config from the signed public keys of the lower HSS levels
+fn hss_config(
+    path: &Path,
+    signed_pub_keys: &Option<Vec<String>>,
+    priv_key: &Option<String>,
+) -> anyhow::Result<Option<ImageGeneratorHssConfig>> {
+    let Some(signed_pub_keys) = signed_pub_keys else {
+        if priv_key.is_some() {
+            return Err(anyhow!("An HSS private key requires HSS signed public keys"));
+        }
+        return Ok(None);
+    };
+
+    let mut gen_config = ImageGeneratorHssConfig::default();
+    for file in signed_pub_keys {
+        gen_config
+            .signed_pub_keys
+            .push(lms_signed_pub_key_from_file(&path.join(file))?);
+    }
+    if let Some(priv_key) = priv_key {
+        gen_config.priv_key = Some(lms_priv_key_from_pem(&path.join(priv_key))?);
+    }
+
+    Ok(Some(gen_config))
+}
@@ -0,0 +1,567 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra image diff command.
+
+--*/
+
+use anyhow::{bail, Context};
+use caliptra_image_elf::{elf_symbols, SymbolType};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::*;
+use clap::ArgMatches;
+use elf::endian::LittleEndian;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use zerocopy::AsBytes;
+
+/// A manifest or TOC field that differs between the images
+struct FieldChange {
+    name: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+/// Size of an ELF section or symbol in the old and new images
+struct SizeChange {
+    name: String,
+    old: Option<u64>,
+    new: Option<u64>,
+}
+
+impl SizeChange {
+    fn delta(&self) -> i64 {
+        self.new.unwrap_or(0) as i64 - self.old.unwrap_or(0) as i64
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "name": self.name,
+            "old": self.old,
+            "new": self.new,
+            "delta": self.delta(),
+        })
+    }
+}
+
+/// Section and symbol size differences of an executable
+#[derive(Default)]
+struct ElfDiff {
+    sections: Vec<SizeChange>,
+    symbols: Vec<SizeChange>,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let old_path: &PathBuf = args
+        .get_one::<PathBuf>("old")
+        .with_context(|| "old arg not specified")?;
+
+    let new_path: &PathBuf = args
+        .get_one::<PathBuf>("new")
+        .with_context(|| "new arg not specified")?;
+
+    let old = read_image(old_path)?;
+    let new = read_image(new_path)?;
+
+    let changes = diff_fields(&image_fields(&old)?, &image_fields(&new)?);
+
+    let mut elf_diffs = vec![];
+    for (name, old_arg, new_arg) in [
+        ("fmc", "old-fmc-elf", "new-fmc-elf"),
+        ("runtime", "old-rt-elf", "new-rt-elf"),
+    ] {
+        match (
+            args.get_one::<PathBuf>(old_arg),
+            args.get_one::<PathBuf>(new_arg),
+        ) {
+            (Some(old_elf), Some(new_elf)) => {
+                elf_diffs.push((name, diff_elfs(old_elf, new_elf)?));
+            }
+            (None, None) => {}
+            _ => bail!("--{old_arg} and --{new_arg} must be specified together"),
+        }
+    }
+
+    let output = match args.get_one::<String>("format").map(String::as_str) {
+        Some("json") => serde_json::to_string_pretty(&to_json(&changes, &elf_diffs))?,
+        _ => to_text(&changes, &elf_diffs),
+    };
+    match args.get_one::<PathBuf>("out") {
+        Some(path) => std::fs::write(path, output)
+            .with_context(|| format!("Failed to write {}", path.display()))?,
+        None => print!("{output}"),
+    }
+
+    if args.get_flag("fail-on-svn-decrease") && svn_decreased(&old, &new) {
+        bail!("FMC, runtime or component SVN decreased");
+    }
+    if args.get_flag("fail-on-change") && !changes.is_empty() {
+        bail!("Images differ in {} fields", changes.len());
+    }
+    Ok(())
+}
+
+fn read_image(path: &PathBuf) -> anyhow::Result<ImageBundle> {
+    let image =
+        std::fs::read(path).with_context(|| format!("Failed to read image {}", path.display()))?;
+    ImageBundle::from_bytes(&image)
+        .with_context(|| format!("Failed to parse image {}", path.display()))
+}
+
+/// Whether the FMC, runtime or a component present in both images has a
+/// lower SVN in `new`
+fn svn_decreased(old: &ImageBundle, new: &ImageBundle) -> bool {
+    old.manifest.fmc.svn > new.manifest.fmc.svn
+        || old.manifest.runtime.svn > new.manifest.runtime.svn
+        || old.components.iter().any(|o| {
+            new.components
+                .iter()
+                .any(|n| n.toc.id == o.toc.id && o.toc.svn > n.toc.svn)
+        })
+}
+
+/// Flatten the manifest and TOC fields of an image into (name, value) pairs
+fn image_fields(image: &ImageBundle) -> anyhow::Result<Vec<(String, String)>> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let manifest = &image.manifest;
+    let preamble = &manifest.preamble;
+    let header = &manifest.header;
+
+    let mut fields = vec![
+        (
+            "preamble.vendor_pub_keys_digest".into(),
+            digest_hex(&gen.vendor_pubkey_digest(preamble)?),
+        ),
+        (
+            "preamble.vendor_ecc_pub_key_idx".into(),
+            preamble.vendor_ecc_pub_key_idx.to_string(),
+        ),
+        (
+            "preamble.vendor_lms_pub_key_idx".into(),
+            preamble.vendor_lms_pub_key_idx.to_string(),
+        ),
+        (
+            "preamble.owner_pub_keys_digest".into(),
+            digest_hex(&gen.owner_pubkey_digest(preamble)?),
+        ),
+        (
+            "header.revision".into(),
+            hex::encode(header.revision.as_bytes()),
+        ),
+        (
+            "header.vendor_ecc_pub_key_idx".into(),
+            header.vendor_ecc_pub_key_idx.to_string(),
+        ),
+        (
+            "header.vendor_lms_pub_key_idx".into(),
+            header.vendor_lms_pub_key_idx.to_string(),
+        ),
+        ("header.flags".into(), format!("0x{:08x}", header.flags)),
+        ("header.toc_len".into(), header.toc_len.to_string()),
+        (
+            "header.pl0_pauser".into(),
+            format!("0x{:08x}", header.pl0_pauser),
+        ),
+        ("header.toc_digest".into(), digest_hex(&header.toc_digest)),
+        (
+            "header.vendor_not_before".into(),
+            date_str(&header.vendor_data.vendor_not_before),
+        ),
+        (
+            "header.vendor_not_after".into(),
+            date_str(&header.vendor_data.vendor_not_after),
+        ),
+        (
+            "header.owner_not_before".into(),
+            date_str(&header.owner_data.owner_not_before),
+        ),
+        (
+            "header.owner_not_after".into(),
+            date_str(&header.owner_data.owner_not_after),
+        ),
+        (
+            "header.owner_epoch".into(),
+            u16::from_le_bytes(header.owner_data.epoch).to_string(),
+        ),
+    ];
+
+    let tocs = [
+        ("fmc".to_string(), &manifest.fmc),
+        ("runtime".into(), &manifest.runtime),
+    ]
+    .into_iter()
+    .chain(
+        image
+            .components
+            .iter()
+            .map(|c| (format!("component[0x{:08x}]", c.toc.id), &c.toc)),
+    );
+    for (name, toc) in tocs {
+        fields.extend([
+            (format!("{name}.type"), toc.r#type.to_string()),
+            (format!("{name}.revision"), hex::encode(toc.revision)),
+            (format!("{name}.version"), toc.version.to_string()),
+            (format!("{name}.svn"), toc.svn.to_string()),
+            (
+                format!("{name}.load_addr"),
+                format!("0x{:08x}", toc.load_addr),
+            ),
+            (
+                format!("{name}.entry_point"),
+                format!("0x{:08x}", toc.entry_point),
+            ),
+            (format!("{name}.offset"), format!("0x{:08x}", toc.offset)),
+            (format!("{name}.size"), toc.size.to_string()),
+            (format!("{name}.digest"), digest_hex(&toc.digest)),
+        ]);
+    }
+    Ok(fields)
+}
+
+/// Fields that differ, in the order of the old image followed by fields
+/// only in the new image
+fn diff_fields(old: &[(String, String)], new: &[(String, String)]) -> Vec<FieldChange> {
+    let new_map: BTreeMap<&str, &str> = new.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    let old_map: BTreeMap<&str, &str> = old.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+
+    let mut changes = vec![];
+    for (name, old_value) in old {
+        let new_value = new_map.get(name.as_str()).copied();
+        if new_value != Some(old_value.as_str()) {
+            changes.push(FieldChange {
+                name: name.clone(),
+                old: Some(old_value.clone()),
+                new: new_value.map(Into::into),
+            });
+        }
+    }
+    for (name, new_value) in new {
+        if !old_map.contains_key(name.as_str()) {
+            changes.push(FieldChange {
+                name: name.clone(),
+                old: None,
+                new: Some(new_value.clone()),
+            });
+        }
+    }
+    changes
+}
+
+fn diff_elfs(old_path: &PathBuf, new_path: &PathBuf) -> anyhow::Result<ElfDiff> {
+    let old = std::fs::read(old_path)
+        .with_context(|| format!("Failed to read ELF {}", old_path.display()))?;
+    let new = std::fs::read(new_path)
+        .with_context(|| format!("Failed to read ELF {}", new_path.display()))?;
+    Ok(ElfDiff {
+        sections: diff_sizes(section_sizes(&old)?, section_sizes(&new)?),
+        symbols: diff_sizes(symbol_sizes(&old)?, symbol_sizes(&new)?),
+    })
+}
+
+/// Sizes of the sections loaded into memory
+fn section_sizes(elf_bytes: &[u8]) -> anyhow::Result<BTreeMap<String, u64>> {
+    let elf = elf::ElfBytes::<LittleEndian>::minimal_parse(elf_bytes)?;
+    let (Some(headers), Some(strings)) = elf.section_headers_with_strtab()? else {
+        bail!("ELF file has no section headers");
+    };
+    let mut sizes = BTreeMap::new();
+    for header in headers
+        .iter()
+        .filter(|h| h.sh_flags & u64::from(elf::abi::SHF_ALLOC) != 0)
+    {
+        let name = strings.get(header.sh_name as usize)?;
+        *sizes.entry(name.to_string()).or_default() += header.sh_size;
+    }
+    Ok(sizes)
+}
+
+/// Sizes of the function and object symbols
+fn symbol_sizes(elf_bytes: &[u8]) -> anyhow::Result<BTreeMap<String, u64>> {
+    let mut sizes = BTreeMap::new();
+    for symbol in elf_symbols(elf_bytes)?
+        .into_iter()
+        .filter(|s| matches!(s.ty, SymbolType::Func | SymbolType::Object) && s.size != 0)
+    {
+        *sizes.entry(symbol.name.to_string()).or_default() += symbol.size;
+    }
+    Ok(sizes)
+}
+
+/// Sizes that differ, largest change first
+fn diff_sizes(old: BTreeMap<String, u64>, new: BTreeMap<String, u64>) -> Vec<SizeChange> {
+    let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let mut changes: Vec<SizeChange> = names
+        .into_iter()
+        .map(|name| SizeChange {
+            name: name.clone(),
+            old: old.get(name).copied(),
+            new: new.get(name).copied(),
+        })
+        .filter(|c| c.old != c.new)
+        .collect();
+    changes.sort_by_key(|c| std::cmp::Reverse(c.delta().abs()));
+    changes
+}
+
+fn to_json(changes: &[FieldChange], elf_diffs: &[(&str, ElfDiff)]) -> serde_json::Value {
+    let fields: Vec<_> = changes
+        .iter()
+        .map(|c| json!({ "name": c.name, "old": c.old, "new": c.new }))
+        .collect();
+    let mut elfs = serde_json::Map::new();
+    for (name, diff) in elf_diffs {
+        elfs.insert(
+            name.to_string(),
+            json!({
+                "sections": diff.sections.iter().map(SizeChange::to_json).collect::<Vec<_>>(),
+                "symbols": diff.symbols.iter().map(SizeChange::to_json).collect::<Vec<_>>(),
+            }),
+        );
+    }
+    json!({ "fields": fields, "elfs": elfs })
+}
+
+fn to_text(changes: &[FieldChange], elf_diffs: &[(&str, ElfDiff)]) -> String {
+    let mut s = String::new();
+    if changes.is_empty() {
+        let _ = writeln!(s, "Manifests are identical");
+    } else {
+        let _ = writeln!(s, "Manifest changes:");
+        for c in changes {
+            let _ = writeln!(
+                s,
+                "  {}: {} -> {}",
+                c.name,
+                c.old.as_deref().unwrap_or("(none)"),
+                c.new.as_deref().unwrap_or("(none)")
+            );
+        }
+    }
+
+    for (name, diff) in elf_diffs {
+        for (kind, sizes) in [("section", &diff.sections), ("symbol", &diff.symbols)] {
+            let total: i64 = sizes.iter().map(SizeChange::delta).sum();
+            let _ = writeln!(s, "{name} {kind} size changes ({total:+} bytes):");
+            for c in sizes.iter() {
+                let _ = writeln!(
+                    s,
+                    "  {:+8}  {:>8} -> {:<8} {}",
+                    c.delta(),
+                    size_str(c.old),
+                    size_str(c.new),
+                    c.name
+                );
+            }
+        }
+    }
+    s
+}
+
+fn digest_hex(digest: &ImageDigest) -> String {
+    digest.iter().map(|w| format!("{w:08x}")).collect()
+}
+
+fn date_str(date: &[u8; 15]) -> String {
+    if date.iter().all(|&b| b == 0) {
+        return "(unset)".into();
+    }
+    String::from_utf8_lossy(date).into_owned()
+}
+
+fn size_str(size: Option<u64>) -> String {
+    size.map_or("-".into(), |s| s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(id: u32, svn: u32) -> ImageComponent {
+        ImageComponent {
+            toc: ImageTocEntry {
+                id,
+                svn,
+                ..Default::default()
+            },
+            content: vec![],
+        }
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn sizes(pairs: &[(&str, u64)]) -> BTreeMap<String, u64> {
+        pairs
+            .iter()
+            .map(|(name, size)| (name.to_string(), *size))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_images_have_no_changes() {
+        let image = ImageBundle {
+            components: vec![component(0x1000, 3)],
+            ..Default::default()
+        };
+        let fields = image_fields(&image).unwrap();
+        assert!(diff_fields(&fields, &fields).is_empty());
+        assert!(!svn_decreased(&image, &image));
+        assert_eq!(to_text(&[], &[]), "Manifests are identical\n");
+    }
+
+    #[test]
+    fn test_image_fields_changes() {
+        let old = ImageBundle {
+            components: vec![component(0x1000, 3)],
+            ..Default::default()
+        };
+        let mut new = ImageBundle {
+            components: vec![component(0x1000, 3), component(0x2000, 1)],
+            ..Default::default()
+        };
+        new.manifest.fmc.svn = 2;
+        new.manifest.header.vendor_ecc_pub_key_idx = 1;
+        new.manifest.runtime.load_addr = 0x4000_0080;
+
+        let changes = diff_fields(&image_fields(&old).unwrap(), &image_fields(&new).unwrap());
+        let find = |name: &str| {
+            let c = changes.iter().find(|c| c.name == name).unwrap();
+            (c.old.as_deref(), c.new.as_deref())
+        };
+        assert_eq!(find("fmc.svn"), (Some("0"), Some("2")));
+        assert_eq!(
+            find("header.vendor_ecc_pub_key_idx"),
+            (Some("0"), Some("1"))
+        );
+        assert_eq!(
+            find("runtime.load_addr"),
+            (Some("0x00000000"), Some("0x40000080"))
+        );
+        assert_eq!(find("component[0x00002000].svn"), (None, Some("1")));
+        assert!(!changes
+            .iter()
+            .any(|c| c.name.starts_with("component[0x00001000]")));
+        assert!(!svn_decreased(&old, &new));
+        assert!(svn_decreased(&new, &old));
+    }
+
+    #[test]
+    fn test_svn_decreased() {
+        let mut old = ImageBundle {
+            components: vec![component(0x1000, 3), component(0x2000, 1)],
+            ..Default::default()
+        };
+        old.manifest.fmc.svn = 1;
+        old.manifest.runtime.svn = 1;
+
+        let mut new = ImageBundle {
+            components: vec![component(0x1000, 3)],
+            ..Default::default()
+        };
+        new.manifest.fmc.svn = 1;
+        new.manifest.runtime.svn = 1;
+        // Removing a component is not an SVN decrease
+        assert!(!svn_decreased(&old, &new));
+
+        new.manifest.runtime.svn = 0;
+        assert!(svn_decreased(&old, &new));
+        new.manifest.runtime.svn = 1;
+
+        new.components[0].toc.svn = 2;
+        assert!(svn_decreased(&old, &new));
+    }
+
+    #[test]
+    fn test_diff_fields_order() {
+        let old = fields(&[("a", "1"), ("b", "2"), ("c", "3")]);
+        let new = fields(&[("d", "4"), ("c", "3"), ("b", "5")]);
+        let changes: Vec<_> = diff_fields(&old, &new)
+            .into_iter()
+            .map(|c| (c.name, c.old, c.new))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("a".to_string(), Some("1".to_string()), None),
+                ("b".into(), Some("2".into()), Some("5".into())),
+                ("d".into(), None, Some("4".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_sizes_largest_change_first() {
+        let old = sizes(&[(".text", 100), (".rodata", 50), ("gone", 8), ("same", 4)]);
+        let new = sizes(&[(".text", 110), (".rodata", 20), ("added", 16), ("same", 4)]);
+        let changes = diff_sizes(old, new);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.name.as_str(), c.old, c.new, c.delta()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (".rodata", Some(50), Some(20), -30),
+                ("added", None, Some(16), 16),
+                (".text", Some(100), Some(110), 10),
+                ("gone", Some(8), None, -8),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_text_and_json_output() {
+        let changes = [FieldChange {
+            name: "fmc.svn".into(),
+            old: Some("0".into()),
+            new: None,
+        }];
+        let elf_diffs = [(
+            "fmc",
+            ElfDiff {
+                sections: diff_sizes(sizes(&[(".text", 100)]), sizes(&[(".text", 96)])),
+                symbols: vec![],
+            },
+        )];
+
+        let text = to_text(&changes, &elf_diffs);
+        assert_eq!(
+            text,
+            "Manifest changes:\n  fmc.svn: 0 -> (none)\n\
+             fmc section size changes (-4 bytes):\n        -4       100 -> 96       .text\n\
+             fmc symbol size changes (+0 bytes):\n"
+        );
+
+        let json = to_json(&changes, &elf_diffs);
+        assert_eq!(
+            json,
+            json!({
+                "fields": [{ "name": "fmc.svn", "old": "0", "new": null }],
+                "elfs": {
+                    "fmc": {
+                        "sections": [{ "name": ".text", "old": 100, "new": 96, "delta": -4 }],
+                        "symbols": [],
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,234 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   env.rs
+
+Abstract:
+
+    File contains the host image verification environment used to check
+    fuse values against an image bundle.
+
+--*/
+
+use caliptra_drivers::memory_layout::ICCM_RANGE;
+use caliptra_drivers::*;
+use caliptra_drivers_sw::{Ecc384Sw, KeyVaultSw, Sha256Sw, Sha384Sw};
+use caliptra_image_types::*;
+use caliptra_image_verify::{ImageVerificationEnv, LmsHssPubKeys, Mldsa87PubKeyDigests};
+use core::ops::Range;
+use zerocopy::FromBytes;
+
+use super::FuseValues;
+
+/// Verification environment that presents the fuse values of a bundle to
+/// the image verifier, as the ROM would see them on a cold boot. Digests and
+/// signatures are computed through the driver traits with the software
+/// drivers, like the ROM does with the hardware ones.
+pub(crate) struct HostVerificationEnv<'a> {
+    pub image: &'a [u8],
+    pub fuses: &'a FuseValues,
+    pub sha256: Sha256Sw,
+    pub sha384: Sha384Sw,
+    pub ecc384: Ecc384Sw,
+}
+
+impl<'a> HostVerificationEnv<'a> {
+    pub fn new(image: &'a [u8], fuses: &'a FuseValues) -> Self {
+        Self {
+            image,
+            fuses,
+            sha256: Sha256Sw::new(),
+            sha384: Sha384Sw::new(),
+            ecc384: Ecc384Sw::new(KeyVaultSw::new()),
+        }
+    }
+}
+
+impl<'a> ImageVerificationEnv for &mut HostVerificationEnv<'a> {
+    fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        Ok(self.sha384.digest(data)?.0)
+    }
+
+    fn sha384_digest_with_suffix(
+        &mut self,
+        offset: u32,
+        len: u32,
+        suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        let mut digest = Array4x12::default();
+        let mut op = self.sha384.digest_init()?;
+        op.update(data)?;
+        op.update(suffix)?;
+        op.finalize(&mut digest)?;
+        Ok(digest.0)
+    }
+
+    fn ecc384_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> CaliptraResult<Array4xN<12, 48>> {
+        let pub_key = Ecc384PubKey {
+            x: pub_key.x.into(),
+            y: pub_key.y.into(),
+        };
+        let sig = Ecc384Signature {
+            r: sig.r.into(),
+            s: sig.s.into(),
+        };
+
+        // The verifier compares the result with r, so only return r for a
+        // valid signature
+        match self.ecc384.verify(&pub_key, &Array4x12::from(digest), &sig) {
+            Ok(Ecc384Result::Success) => Ok(sig.r),
+            _ => Ok(Array4x12::default()),
+        }
+    }
+
+    fn lms_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>> {
+        let mut message = [0u8; SHA384_DIGEST_BYTE_SIZE];
+        for i in 0..digest.len() {
+            message[i * 4..][..4].copy_from_slice(&digest[i].to_be_bytes());
+        }
+        Lms::default().verify_lms_signature_cfi_generic(&mut self.sha256, &message, pub_key, sig)
+    }
+
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key_offset: u32,
+        sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let pub_key = self
+            .image
+            .get(pub_key_offset as usize..)
+            .and_then(|b| b.get(..MLDSA87_PUB_KEY_BYTE_SIZE))
+            .and_then(|b| <&[u8; MLDSA87_PUB_KEY_BYTE_SIZE]>::try_from(b).ok())
+            .ok_or(err)?;
+        let sig = self
+            .image
+            .get(sig_offset as usize..)
+            .and_then(|b| b.get(..MLDSA87_SIGNATURE_BYTE_SIZE))
+            .and_then(|b| <&[u8; MLDSA87_SIGNATURE_BYTE_SIZE]>::try_from(b).ok())
+            .ok_or(err)?;
+
+        let mut message = [0u8; SHA384_DIGEST_BYTE_SIZE];
+        for i in 0..digest.len() {
+            message[i * 4..][..4].copy_from_slice(&digest[i].to_be_bytes());
+        }
+        Mldsa87::default().verify(pub_key, &message, sig)
+    }
+
+    fn lms_hss_signed_pub_key(&self, offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+        self.image
+            .get(offset as usize..)
+            .and_then(ImageLmsSignedPubKey::read_from_prefix)
+            .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS)
+    }
+
+    fn vendor_pub_key_digest(&self) -> ImageDigest {
+        self.fuses.key_manifest_pk_hash
+    }
+
+    fn vendor_ecc_pub_key_revocation(&self) -> VendorPubKeyRevocation {
+        VendorPubKeyRevocation::from_bits_truncate(self.fuses.key_manifest_pk_hash_mask)
+    }
+
+    fn vendor_lms_pub_key_revocation(&self) -> u32 {
+        self.fuses.fuse_lms_revocation
+    }
+
+    fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
+        self.fuses.owner_pk_hash
+    }
+
+    fn anti_rollback_disable(&self) -> bool {
+        self.fuses.anti_rollback_disable
+    }
+
+    fn dev_lifecycle(&self) -> Lifecycle {
+        self.fuses.life_cycle
+    }
+
+    // The data vault is only consulted on update resets
+    fn vendor_ecc_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn vendor_lms_pub_key_idx_dv(&self) -> u32 {
+        0
+    }
+
+    fn owner_pub_key_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    // ML-DSA public keys are checked against the PQC extension of the bundle
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+        None
+    }
+
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+        None
+    }
+
+    fn get_fmc_digest_dv(&self) -> ImageDigest {
+        ImageDigest::default()
+    }
+
+    fn fmc_fuse_svn(&self) -> u32 {
+        32 - self.fuses.fmc_key_manifest_svn.leading_zeros()
+    }
+
+    fn runtime_fuse_svn(&self) -> u32 {
+        let svn = self
+            .fuses
+            .runtime_svn
+            .iter()
+            .rev()
+            .fold(0u128, |acc, word| (acc << 32) | u128::from(*word));
+        128 - svn.leading_zeros()
+    }
+
+    fn iccm_range(&self) -> Range<u32> {
+        ICCM_RANGE
+    }
+
+    fn lms_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn lms_hss_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn mldsa87_verify_enabled(&self) -> bool {
+        self.fuses.mldsa_verify
+    }
+
+    fn set_fw_extended_error(&mut self, _err: u32) {}
+}
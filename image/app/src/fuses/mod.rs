@@ -0,0 +1,500 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra fuse provisioning bundle
+    command.
+
+--*/
+
+mod env;
+
+use anyhow::{anyhow, bail, Context};
+use caliptra_drivers::{Lifecycle, ResetReason};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::*;
+use caliptra_image_verify::ImageVerifier;
+use clap::ArgMatches;
+use serde_json::json;
+use std::fmt::Write;
+use std::mem::size_of;
+use std::path::PathBuf;
+use zerocopy::FromBytes;
+
+use env::HostVerificationEnv;
+
+/// Highest FMC SVN that fits in the FMC key manifest SVN fuses
+const MAX_FMC_FUSE_SVN: u32 = 32;
+
+/// Highest runtime SVN that fits in the runtime SVN fuses
+const MAX_RUNTIME_FUSE_SVN: u32 = 128;
+
+/// Fuse values Caliptra expects for an image
+pub(crate) struct FuseValues {
+    pub key_manifest_pk_hash: ImageDigest,
+    pub key_manifest_pk_hash_mask: u32,
+    pub owner_pk_hash: ImageDigest,
+    pub fmc_key_manifest_svn: u32,
+    pub runtime_svn: [u32; 4],
+    pub anti_rollback_disable: bool,
+    pub idevid_key_id_algo: &'static str,
+    pub idevid_cert_attr_flags: u32,
+    pub life_cycle: Lifecycle,
+    pub lms_verify: bool,
+    pub mldsa_verify: bool,
+    pub fuse_lms_revocation: u32,
+}
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let image = std::fs::read(image_path)
+        .with_context(|| format!("Failed to read image {}", image_path.display()))?;
+    let manifest = ImageManifest::read_from_prefix(&image[..])
+        .ok_or_else(|| anyhow!("Image is smaller than the manifest"))?;
+
+    let ecc_revocation = *args.get_one::<u32>("ecc-revocation").unwrap_or(&0);
+    if ecc_revocation >= 1 << VENDOR_ECC_KEY_COUNT {
+        bail!("ECC revocation mask 0x{ecc_revocation:x} has bits for nonexistent keys");
+    }
+
+    let fmc_svn = *args.get_one::<u32>("fmc-svn").unwrap_or(&manifest.fmc.svn);
+    if fmc_svn > MAX_FMC_FUSE_SVN {
+        bail!("FMC SVN {fmc_svn} is greater than {MAX_FMC_FUSE_SVN}");
+    }
+    let rt_svn = *args
+        .get_one::<u32>("rt-svn")
+        .unwrap_or(&manifest.runtime.svn);
+    if rt_svn > MAX_RUNTIME_FUSE_SVN {
+        bail!("Runtime SVN {rt_svn} is greater than {MAX_RUNTIME_FUSE_SVN}");
+    }
+
+    let (idevid_key_id_algo, mut idevid_cert_attr_flags) = match args
+        .get_one::<String>("idevid-key-id-algo")
+        .map(String::as_str)
+    {
+        Some("sha256") => ("sha256", 1),
+        Some("sha384") => ("sha384", 2),
+        Some("fuse") => ("fuse", 3),
+        _ => ("sha1", 0),
+    };
+
+    let mldsa_verify = args.get_flag("mldsa-verify");
+    if mldsa_verify {
+        idevid_cert_attr_flags |= 0x8;
+    }
+
+    let life_cycle = match args.get_one::<String>("lifecycle").map(String::as_str) {
+        Some("unprovisioned") => Lifecycle::Unprovisioned,
+        Some("manufacturing") => Lifecycle::Manufacturing,
+        _ => Lifecycle::Production,
+    };
+
+    let (key_manifest_pk_hash, owner_pk_hash) = pk_hashes(&image, &manifest, mldsa_verify)
+        .with_context(|| format!("Failed to hash the public keys of {}", image_path.display()))?;
+    let fuses = FuseValues {
+        key_manifest_pk_hash,
+        key_manifest_pk_hash_mask: ecc_revocation,
+        owner_pk_hash,
+        fmc_key_manifest_svn: fmc_svn_fuse(fmc_svn),
+        runtime_svn: runtime_svn_fuse(rt_svn),
+        anti_rollback_disable: args.get_flag("anti-rollback-disable"),
+        idevid_key_id_algo,
+        idevid_cert_attr_flags,
+        life_cycle,
+        lms_verify: args.get_flag("lms-verify"),
+        mldsa_verify,
+        fuse_lms_revocation: *args.get_one::<u32>("lms-revocation").unwrap_or(&0),
+    };
+
+    cross_check(&image, &manifest, &fuses)?;
+
+    let json = serde_json::to_string_pretty(&to_json(&fuses))?;
+    let outputs = [
+        ("out-json", json),
+        ("out-rust", to_rust(&fuses)),
+        ("out-c", to_c(&fuses)),
+    ];
+    let mut written = false;
+    for (arg, contents) in &outputs {
+        if let Some(path) = args.get_one::<PathBuf>(arg) {
+            std::fs::write(path, contents)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            written = true;
+        }
+    }
+    if !written {
+        println!("{}", outputs[0].1);
+    }
+    Ok(())
+}
+
+/// Vendor and owner public key hashes of an image, as the ROM computes them
+/// for comparison with the key hash fuses
+fn pk_hashes(
+    image: &[u8],
+    manifest: &ImageManifest,
+    mldsa_verify: bool,
+) -> anyhow::Result<(ImageDigest, ImageDigest)> {
+    // With ML-DSA verification the key hash fuses also bind the ML-DSA
+    // public keys of the PQC extension
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    if mldsa_verify {
+        let bundle = ImageBundle::from_bytes(image).with_context(|| "Failed to parse image")?;
+        let Some(pqc_extension) = &bundle.pqc_extension else {
+            bail!("--mldsa-verify requires an image with ML-DSA signatures");
+        };
+        Ok((
+            gen.vendor_pubkey_digest_mldsa(&manifest.preamble, pqc_extension)?,
+            gen.owner_pubkey_digest_mldsa(&manifest.preamble, pqc_extension)?,
+        ))
+    } else {
+        Ok((
+            gen.vendor_pubkey_digest(&manifest.preamble)?,
+            gen.owner_pubkey_digest(&manifest.preamble)?,
+        ))
+    }
+}
+
+/// Check that the image boots on a cold reset with `fuses` burned
+pub(crate) fn cross_check(
+    image: &[u8],
+    manifest: &ImageManifest,
+    fuses: &FuseValues,
+) -> anyhow::Result<()> {
+    if fuses.life_cycle == Lifecycle::Unprovisioned {
+        eprintln!(
+            "warning: the vendor key hash and SVN fuses are not checked by the ROM in the unprovisioned lifecycle"
+        );
+    }
+
+    let mut env = HostVerificationEnv::new(image, fuses);
+    let image_size =
+        u32::try_from(image.len()).map_err(|_| anyhow!("Image is too large to verify"))?;
+
+    // Component TOC entries follow the manifest
+    let component_count = manifest.header.toc_len.saturating_sub(FW_TOC_ENTRY_COUNT);
+    if component_count > MAX_COMPONENT_COUNT {
+        bail!(
+            "Image has {component_count} components; at most {MAX_COMPONENT_COUNT} are supported"
+        );
+    }
+    let components = (0..component_count as usize)
+        .map(|i| {
+            image
+                .get(size_of::<ImageManifest>() + i * size_of::<ImageTocEntry>()..)
+                .and_then(ImageTocEntry::read_from_prefix)
+                .ok_or_else(|| anyhow!("Image is smaller than its component TOC"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut verifier = ImageVerifier::new(&mut env);
+    if let Err(err) = verifier.verify(manifest, &components, image_size, ResetReason::ColdReset) {
+        let report =
+            verifier.verify_report(manifest, &components, image_size, ResetReason::ColdReset);
+        bail!(
+            "Image fails verification with these fuse values: error 0x{:08x}\n{report}",
+            u32::from(err)
+        );
+    }
+    Ok(())
+}
+
+/// Encode `svn` the way the ROM reads the FMC key manifest SVN fuses
+fn fmc_svn_fuse(svn: u32) -> u32 {
+    u32::MAX.checked_shr(MAX_FMC_FUSE_SVN - svn).unwrap_or(0)
+}
+
+/// Encode `svn` the way the ROM reads the runtime SVN fuses
+fn runtime_svn_fuse(svn: u32) -> [u32; 4] {
+    let bits = u128::MAX
+        .checked_shr(MAX_RUNTIME_FUSE_SVN - svn)
+        .unwrap_or(0);
+    core::array::from_fn(|i| (bits >> (32 * i)) as u32)
+}
+
+fn lifecycle_name(life_cycle: Lifecycle) -> &'static str {
+    match life_cycle {
+        Lifecycle::Unprovisioned => "Unprovisioned",
+        Lifecycle::Manufacturing => "Manufacturing",
+        Lifecycle::Reserved2 => "Reserved2",
+        Lifecycle::Production => "Production",
+    }
+}
+
+fn hex_words(words: &[u32]) -> Vec<String> {
+    words.iter().map(|w| format!("0x{w:08x}")).collect()
+}
+
+/// Describe `fuses` as JSON, with register words as hex strings
+fn to_json(fuses: &FuseValues) -> serde_json::Value {
+    json!({
+        "key_manifest_pk_hash": hex_words(&fuses.key_manifest_pk_hash),
+        "key_manifest_pk_hash_mask": format!("0x{:x}", fuses.key_manifest_pk_hash_mask),
+        "owner_pk_hash": hex_words(&fuses.owner_pk_hash),
+        "fmc_key_manifest_svn": format!("0x{:08x}", fuses.fmc_key_manifest_svn),
+        "runtime_svn": hex_words(&fuses.runtime_svn),
+        "anti_rollback_disable": fuses.anti_rollback_disable,
+        "idevid_key_id_algo": fuses.idevid_key_id_algo,
+        "idevid_cert_attr_flags": format!("0x{:x}", fuses.idevid_cert_attr_flags),
+        "life_cycle": lifecycle_name(fuses.life_cycle),
+        "lms_verify": fuses.lms_verify,
+        "mldsa_verify": fuses.mldsa_verify,
+        "lms_revocation": format!("0x{:08x}", fuses.fuse_lms_revocation),
+    })
+}
+
+fn join_words(words: &[u32]) -> String {
+    hex_words(words).join(", ")
+}
+
+/// Describe `fuses` as a `caliptra_hw_model::Fuses` initializer
+fn to_rust(fuses: &FuseValues) -> String {
+    let mut idevid_cert_attr = [0u32; 24];
+    idevid_cert_attr[0] = fuses.idevid_cert_attr_flags;
+
+    let mut s = String::new();
+    s.push_str("caliptra_hw_model::Fuses {\n");
+    let _ = writeln!(
+        s,
+        "    key_manifest_pk_hash: [{}],",
+        join_words(&fuses.key_manifest_pk_hash)
+    );
+    let _ = writeln!(
+        s,
+        "    key_manifest_pk_hash_mask: caliptra_hw_model::U4::X{:x},",
+        fuses.key_manifest_pk_hash_mask
+    );
+    let _ = writeln!(
+        s,
+        "    owner_pk_hash: [{}],",
+        join_words(&fuses.owner_pk_hash)
+    );
+    let _ = writeln!(
+        s,
+        "    fmc_key_manifest_svn: 0x{:08x},",
+        fuses.fmc_key_manifest_svn
+    );
+    let _ = writeln!(s, "    runtime_svn: [{}],", join_words(&fuses.runtime_svn));
+    let _ = writeln!(
+        s,
+        "    anti_rollback_disable: {},",
+        fuses.anti_rollback_disable
+    );
+    let _ = writeln!(
+        s,
+        "    idevid_cert_attr: [{}],",
+        join_words(&idevid_cert_attr)
+    );
+    let _ = writeln!(
+        s,
+        "    life_cycle: caliptra_hw_model::DeviceLifecycle::{},",
+        lifecycle_name(fuses.life_cycle)
+    );
+    let _ = writeln!(s, "    lms_verify: {},", fuses.lms_verify);
+    let _ = writeln!(
+        s,
+        "    fuse_lms_revocation: 0x{:08x},",
+        fuses.fuse_lms_revocation
+    );
+    s.push_str("    ..Default::default()\n}\n");
+    s
+}
+
+/// Describe `fuses` as a `struct caliptra_fuses` initializer for libcaliptra
+fn to_c(fuses: &FuseValues) -> String {
+    let mut s = String::new();
+    // libcaliptra has no fields for the LMS fuses
+    let _ = writeln!(
+        s,
+        "/* Not in struct caliptra_fuses: lms_verify = {}, lms_revocation = 0x{:08x} */",
+        fuses.lms_verify, fuses.fuse_lms_revocation
+    );
+    s.push_str("struct caliptra_fuses fuses = {\n");
+    let _ = writeln!(
+        s,
+        "    .key_manifest_pk_hash = {{{}}},",
+        join_words(&fuses.key_manifest_pk_hash)
+    );
+    let _ = writeln!(
+        s,
+        "    .key_manifest_pk_hash_mask = 0x{:x},",
+        fuses.key_manifest_pk_hash_mask
+    );
+    let _ = writeln!(
+        s,
+        "    .owner_pk_hash = {{{}}},",
+        join_words(&fuses.owner_pk_hash)
+    );
+    let _ = writeln!(
+        s,
+        "    .fmc_key_manifest_svn = 0x{:08x},",
+        fuses.fmc_key_manifest_svn
+    );
+    let _ = writeln!(
+        s,
+        "    .runtime_svn = {{{}}},",
+        join_words(&fuses.runtime_svn)
+    );
+    let _ = writeln!(
+        s,
+        "    .anti_rollback_disable = {},",
+        fuses.anti_rollback_disable
+    );
+    let _ = writeln!(
+        s,
+        "    .idevid_cert_attr = {{0x{:08x}}},",
+        fuses.idevid_cert_attr_flags
+    );
+    let _ = writeln!(s, "    .life_cycle = {},", lifecycle_name(fuses.life_cycle));
+    s.push_str("};\n");
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TestExecutable;
+    use caliptra_drivers::CaliptraError;
+    use caliptra_image_elf::ElfLayout;
+    use caliptra_image_fake_keys::*;
+    use caliptra_image_gen::{ImageGeneratorConfig, ImageGeneratorVendorConfig};
+
+    fn fake_key_image(vendor_config: ImageGeneratorVendorConfig) -> (Vec<u8>, ImageManifest) {
+        let gen_config = ImageGeneratorConfig {
+            vendor_config,
+            owner_config: Some(OWNER_CONFIG),
+            fmc: TestExecutable::new(ElfLayout::fmc().code.start),
+            runtime: TestExecutable::new(ElfLayout::runtime().code.start),
+            components: Vec::new(),
+        };
+        let image = ImageGenerator::new(OsslCrypto::default())
+            .generate(&gen_config)
+            .unwrap();
+        (image.to_bytes().unwrap(), image.manifest)
+    }
+
+    /// Production fuses for `image`, as the command generates them
+    fn fuse_values(image: &[u8], manifest: &ImageManifest) -> FuseValues {
+        let (key_manifest_pk_hash, owner_pk_hash) = pk_hashes(image, manifest, false).unwrap();
+        FuseValues {
+            key_manifest_pk_hash,
+            key_manifest_pk_hash_mask: 0,
+            owner_pk_hash,
+            fmc_key_manifest_svn: fmc_svn_fuse(manifest.fmc.svn),
+            runtime_svn: runtime_svn_fuse(manifest.runtime.svn),
+            anti_rollback_disable: false,
+            idevid_key_id_algo: "sha1",
+            idevid_cert_attr_flags: 0,
+            life_cycle: Lifecycle::Production,
+            lms_verify: true,
+            mldsa_verify: false,
+            fuse_lms_revocation: 0,
+        }
+    }
+
+    fn assert_fails_with(
+        image: &[u8],
+        manifest: &ImageManifest,
+        fuses: &FuseValues,
+        err: CaliptraError,
+    ) {
+        let msg = cross_check(image, manifest, fuses).unwrap_err().to_string();
+        let expected = format!("error 0x{:08x}", u32::from(err));
+        assert!(msg.contains(&expected), "{msg}");
+    }
+
+    #[test]
+    fn test_fake_keys_fuse_values_pass_verification() {
+        // The key hashes cover every vendor key, so they do not depend on the
+        // key the image is signed with
+        let preamble = ImagePreamble {
+            vendor_pub_keys: VENDOR_PUBLIC_KEYS,
+            owner_pub_keys: OWNER_PUBLIC_KEYS,
+            ..Default::default()
+        };
+        let gen = ImageGenerator::new(OsslCrypto::default());
+        let vendor_pk_hash = gen.vendor_pubkey_digest(&preamble).unwrap();
+        let owner_pk_hash = gen.owner_pubkey_digest(&preamble).unwrap();
+
+        for vendor_config in [
+            VENDOR_CONFIG_KEY_0,
+            VENDOR_CONFIG_KEY_1,
+            VENDOR_CONFIG_KEY_2,
+            VENDOR_CONFIG_KEY_3,
+        ] {
+            let (image, manifest) = fake_key_image(vendor_config);
+            let fuses = fuse_values(&image, &manifest);
+            assert_eq!(fuses.key_manifest_pk_hash, vendor_pk_hash);
+            assert_eq!(fuses.owner_pk_hash, owner_pk_hash);
+            cross_check(&image, &manifest, &fuses).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_fake_keys_wrong_fuse_values_fail_verification() {
+        let (image, manifest) = fake_key_image(VENDOR_CONFIG_KEY_0);
+        cross_check(&image, &manifest, &fuse_values(&image, &manifest)).unwrap();
+
+        let mut fuses = fuse_values(&image, &manifest);
+        fuses.key_manifest_pk_hash[11] ^= 1;
+        assert_fails_with(
+            &image,
+            &manifest,
+            &fuses,
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH,
+        );
+
+        let mut fuses = fuse_values(&image, &manifest);
+        fuses.owner_pk_hash[0] ^= 1;
+        assert_fails_with(
+            &image,
+            &manifest,
+            &fuses,
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH,
+        );
+
+        let mut fuses = fuse_values(&image, &manifest);
+        fuses.key_manifest_pk_hash_mask = 1;
+        assert_fails_with(
+            &image,
+            &manifest,
+            &fuses,
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_REVOKED,
+        );
+    }
+
+    #[test]
+    fn test_fake_keys_fuse_bundle_outputs() {
+        let (image, manifest) = fake_key_image(VENDOR_CONFIG_KEY_0);
+        let fuses = fuse_values(&image, &manifest);
+
+        let json = to_json(&fuses);
+        assert_eq!(
+            json["key_manifest_pk_hash"],
+            json!(hex_words(&fuses.key_manifest_pk_hash))
+        );
+        assert_eq!(
+            json["owner_pk_hash"],
+            json!(hex_words(&fuses.owner_pk_hash))
+        );
+
+        let rust = to_rust(&fuses);
+        let c = to_c(&fuses);
+        for digest in [&fuses.key_manifest_pk_hash, &fuses.owner_pk_hash] {
+            let joined = join_words(digest);
+            assert!(rust.contains(&joined), "{rust}");
+            assert!(c.contains(&joined), "{c}");
+        }
+    }
+}
@@ -0,0 +1,66 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra HSS provisioning command,
+    which signs the LMS public key of an HSS level with its parent key.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::{
+    lms_priv_key_from_pem, lms_pub_key_from_pem, LmsKeyState, OsslCrypto,
+};
+use clap::ArgMatches;
+use std::io::Write;
+use std::path::PathBuf;
+use zerocopy::AsBytes;
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let parent_priv_key_path: &PathBuf = args
+        .get_one::<PathBuf>("parent-priv-key")
+        .with_context(|| "parent-priv-key arg not specified")?;
+
+    let pub_key_path: &PathBuf = args
+        .get_one::<PathBuf>("pub-key")
+        .with_context(|| "pub-key arg not specified")?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    let parent_priv_key = lms_priv_key_from_pem(parent_priv_key_path)?;
+    let pub_key = lms_pub_key_from_pem(pub_key_path)?;
+
+    let crypto = match args.get_one::<PathBuf>("lms-state") {
+        Some(path) => OsslCrypto::with_lms_state(LmsKeyState::open(path)?),
+        None if !args.get_flag("insecure-fixed-lms-q") => {
+            return Err(anyhow!(
+                "--lms-state is required when signing with LMS private keys"
+            ))
+        }
+        None => OsslCrypto::default(),
+    };
+    let gen = ImageGenerator::new(crypto);
+    let signed_pub_key = gen.gen_hss_signed_pub_key(&pub_key, &parent_priv_key)?;
+
+    // Never overwrite a provisioned key; its parent leaf is already spent
+    let mut out_file = std::fs::OpenOptions::new()
+        .create_new(true)
+        .write(true)
+        .open(out_path)
+        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+    out_file
+        .write_all(signed_pub_key.as_bytes())
+        .with_context(|| format!("Failed to write file {}", out_path.display()))?;
+
+    Ok(())
+}
@@ -0,0 +1,423 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra key generation command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::{
+    ecc_pub_key_from_pem, generate_lms_key_pair, lms_pub_key_from_pem, OsslCrypto,
+};
+use caliptra_image_types::*;
+use caliptra_lms_types::{LmotsAlgorithmType, LmsAlgorithmType};
+use clap::ArgMatches;
+use openssl::ec::{EcGroup, EcKey};
+use openssl::nid::Nid;
+use std::io::Write;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use zerocopy::AsBytes;
+
+use crate::create::config::{KeyConfig, OwnerKeyConfig, VendorKeyConfig};
+
+/// Name of the key configuration file written to the output directory
+const KEY_CONFIG_FILE: &str = "keys.toml";
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let out_dir: &PathBuf = args
+        .get_one::<PathBuf>("out-dir")
+        .with_context(|| "out-dir arg not specified")?;
+
+    let with_owner = !args.get_flag("no-owner");
+
+    let config = generate_keys(out_dir, with_owner, IMAGE_LMS_TREE_TYPE, IMAGE_LMS_OTS_TYPE)?;
+    println!(
+        "Wrote key configuration {}",
+        out_dir.join(KEY_CONFIG_FILE).display()
+    );
+
+    print_fuse_values(out_dir, &config)
+}
+
+/// Generate the vendor keys, and the owner keys if `with_owner` is set, into
+/// `out_dir` along with a key configuration file referencing them
+fn generate_keys(
+    out_dir: &Path,
+    with_owner: bool,
+    lms_tree_type: LmsAlgorithmType,
+    lms_otstype: LmotsAlgorithmType,
+) -> anyhow::Result<KeyConfig> {
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Failed to create directory {}", out_dir.display()))?;
+
+    let mut vendor = VendorKeyConfig::default();
+    let mut ecc_priv_keys: [String; VENDOR_ECC_KEY_COUNT as usize] = Default::default();
+    for (i, (pub_key, priv_key)) in vendor
+        .ecc_pub_keys
+        .iter_mut()
+        .zip(ecc_priv_keys.iter_mut())
+        .enumerate()
+    {
+        *pub_key = format!("vnd-pub-key-{i}.pem");
+        *priv_key = format!("vnd-priv-key-{i}.pem");
+        write_ecc_key_pair(out_dir, pub_key, priv_key)?;
+    }
+    vendor.ecc_priv_keys = Some(ecc_priv_keys);
+
+    let lms_key_count = VENDOR_LMS_KEY_COUNT as usize + usize::from(with_owner);
+    let mut lms_key_pairs =
+        generate_lms_key_pairs(lms_key_count, lms_tree_type, lms_otstype)?.into_iter();
+
+    let mut lms_priv_keys: [String; VENDOR_LMS_KEY_COUNT as usize] = Default::default();
+    for (i, (pub_key, priv_key)) in vendor
+        .lms_pub_keys
+        .iter_mut()
+        .zip(lms_priv_keys.iter_mut())
+        .enumerate()
+    {
+        *pub_key = format!("vnd-lms-pub-key-{i}.pem");
+        *priv_key = format!("vnd-lms-priv-key-{i}.pem");
+        let (lms_priv, lms_pub) = lms_key_pairs.next().unwrap();
+        write_new_file(&out_dir.join(&*pub_key), lms_pub.as_bytes(), false)?;
+        write_new_file(&out_dir.join(&*priv_key), lms_priv.as_bytes(), true)?;
+    }
+    vendor.lms_priv_keys = Some(lms_priv_keys);
+
+    let owner = if with_owner {
+        let owner = OwnerKeyConfig {
+            ecc_pub_key: "own-pub-key.pem".into(),
+            ecc_priv_key: Some("own-priv-key.pem".into()),
+            lms_pub_key: "own-lms-pub-key.pem".into(),
+            lms_priv_key: Some("own-lms-priv-key.pem".into()),
+            lms_hss_signed_pub_keys: None,
+            lms_hss_priv_key: None,
+            mldsa_pub_key: None,
+            mldsa_priv_key: None,
+        };
+        write_ecc_key_pair(
+            out_dir,
+            &owner.ecc_pub_key,
+            owner.ecc_priv_key.as_ref().unwrap(),
+        )?;
+        let (lms_priv, lms_pub) = lms_key_pairs.next().unwrap();
+        write_new_file(&out_dir.join(&owner.lms_pub_key), lms_pub.as_bytes(), false)?;
+        write_new_file(
+            &out_dir.join(owner.lms_priv_key.as_ref().unwrap()),
+            lms_priv.as_bytes(),
+            true,
+        )?;
+        Some(owner)
+    } else {
+        None
+    };
+
+    let config = KeyConfig { vendor, owner };
+    write_new_file(
+        &out_dir.join(KEY_CONFIG_FILE),
+        toml::to_string(&config)?.as_bytes(),
+        false,
+    )?;
+    Ok(config)
+}
+
+/// Generate `count` LMS key pairs. Generating a key computes every leaf of
+/// its tree, so the keys are spread across one thread per available CPU.
+fn generate_lms_key_pairs(
+    count: usize,
+    tree_type: LmsAlgorithmType,
+    otstype: LmotsAlgorithmType,
+) -> anyhow::Result<Vec<(ImageLmsPrivKey, ImageLmsPublicKey)>> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let threads = std::thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(count);
+    let keys_per_thread = (count + threads - 1) / threads;
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..count)
+            .step_by(keys_per_thread)
+            .map(|first| {
+                let keys = keys_per_thread.min(count - first);
+                s.spawn(move || {
+                    (0..keys)
+                        .map(|_| generate_lms_key_pair(tree_type, otstype))
+                        .collect::<anyhow::Result<Vec<_>>>()
+                })
+            })
+            .collect();
+        let mut key_pairs = Vec::with_capacity(count);
+        for h in handles {
+            key_pairs.extend(
+                h.join()
+                    .map_err(|_| anyhow!("LMS key generation panicked"))??,
+            );
+        }
+        Ok(key_pairs)
+    })
+}
+
+/// Generate an ECC P-384 key pair and write it as PEM files
+fn write_ecc_key_pair(dir: &Path, pub_key_file: &str, priv_key_file: &str) -> anyhow::Result<()> {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+    let key = EcKey::generate(&group)?;
+    write_new_file(&dir.join(pub_key_file), &key.public_key_to_pem()?, false)?;
+    write_new_file(&dir.join(priv_key_file), &key.private_key_to_pem()?, true)
+}
+
+/// Write `contents` to `path`, refusing to overwrite an existing file
+fn write_new_file(path: &Path, contents: &[u8], private: bool) -> anyhow::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        if private {
+            options.mode(0o600);
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = private;
+
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("Failed to create file {}", path.display()))?;
+    file.write_all(contents)
+        .with_context(|| format!("Failed to write file {}", path.display()))
+}
+
+/// Print the public key hashes to burn into the fuses
+fn print_fuse_values(dir: &Path, config: &KeyConfig) -> anyhow::Result<()> {
+    let (vendor_digest, owner_digest) = fuse_values(dir, config)?;
+    print_digest(
+        "fuse_key_manifest_pk_hash (vendor public key hash)",
+        &vendor_digest,
+    );
+    if let Some(owner_digest) = owner_digest {
+        print_digest("fuse_owner_pk_hash (owner public key hash)", &owner_digest);
+    }
+    Ok(())
+}
+
+/// Compute the vendor and owner public key hashes from the written public
+/// keys the same way the image generator does
+fn fuse_values(
+    dir: &Path,
+    config: &KeyConfig,
+) -> anyhow::Result<(ImageDigest, Option<ImageDigest>)> {
+    let mut preamble = ImagePreamble::default();
+    for (i, pem_file) in config.vendor.ecc_pub_keys.iter().enumerate() {
+        preamble.vendor_pub_keys.ecc_pub_keys[i] = ecc_pub_key_from_pem(&dir.join(pem_file))?;
+    }
+    for (i, pem_file) in config.vendor.lms_pub_keys.iter().enumerate() {
+        preamble.vendor_pub_keys.lms_pub_keys[i] = lms_pub_key_from_pem(&dir.join(pem_file))?;
+    }
+
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let vendor_digest = gen.vendor_pubkey_digest(&preamble)?;
+
+    let owner_digest = match &config.owner {
+        Some(owner) => {
+            preamble.owner_pub_keys.ecc_pub_key =
+                ecc_pub_key_from_pem(&dir.join(&owner.ecc_pub_key))?;
+            preamble.owner_pub_keys.lms_pub_key =
+                lms_pub_key_from_pem(&dir.join(&owner.lms_pub_key))?;
+            Some(gen.owner_pubkey_digest(&preamble)?)
+        }
+        None => None,
+    };
+    Ok((vendor_digest, owner_digest))
+}
+
+/// Print `digest` in the order its words are written to the fuse registers
+fn print_digest(name: &str, digest: &ImageDigest) {
+    println!("{name}:");
+    for (i, word) in digest.iter().enumerate() {
+        println!("  [{i:2}] 0x{word:08x}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create::config::load_key_config;
+    use crate::create::{owner_config, vendor_config};
+    use crate::fuses::{cross_check, FuseValues};
+    use crate::test_util::TestExecutable;
+    use caliptra_drivers::Lifecycle;
+    use caliptra_image_elf::ElfLayout;
+    use caliptra_image_gen::ImageGeneratorConfig;
+    use caliptra_image_openssl::lms_verify;
+
+    // H5 trees have 32 leaves, so the keys are quick to generate. The fixed
+    // leaf `OsslCrypto::default()` signs with is within an H5 tree.
+    const LMS_TREE_TYPE: LmsAlgorithmType = LmsAlgorithmType::LmsSha256N24H5;
+    const LMS_OTS_TYPE: LmotsAlgorithmType = LmotsAlgorithmType::LmotsSha256N24W8;
+
+    fn out_dir(name: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("caliptra-keygen-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        path
+    }
+
+    #[test]
+    fn test_generate_keys_writes_config_and_key_files() {
+        let dir = out_dir("files");
+        let config = generate_keys(&dir, true, LMS_TREE_TYPE, LMS_OTS_TYPE).unwrap();
+        let owner = config.owner.as_ref().unwrap();
+
+        let mut public_files: Vec<&str> = config
+            .vendor
+            .ecc_pub_keys
+            .iter()
+            .chain(config.vendor.lms_pub_keys.iter())
+            .map(String::as_str)
+            .collect();
+        public_files.extend([
+            KEY_CONFIG_FILE,
+            owner.ecc_pub_key.as_str(),
+            owner.lms_pub_key.as_str(),
+        ]);
+        let mut private_files: Vec<&str> = config
+            .vendor
+            .ecc_priv_keys
+            .iter()
+            .flatten()
+            .chain(config.vendor.lms_priv_keys.iter().flatten())
+            .map(String::as_str)
+            .collect();
+        private_files.extend([
+            owner.ecc_priv_key.as_deref().unwrap(),
+            owner.lms_priv_key.as_deref().unwrap(),
+        ]);
+        assert_eq!(
+            private_files.len(),
+            (VENDOR_ECC_KEY_COUNT + VENDOR_LMS_KEY_COUNT + 2) as usize
+        );
+
+        let mut written: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        written.sort();
+        let mut expected: Vec<String> = public_files
+            .iter()
+            .chain(private_files.iter())
+            .map(|name| name.to_string())
+            .collect();
+        expected.sort();
+        assert_eq!(written, expected);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            for name in &private_files {
+                let mode = std::fs::metadata(dir.join(name))
+                    .unwrap()
+                    .permissions()
+                    .mode();
+                assert_eq!(mode & 0o777, 0o600, "{name}");
+            }
+        }
+
+        // The LMS keys are distinct keys of the requested types
+        for name in config.vendor.lms_pub_keys.iter() {
+            let pub_key = lms_pub_key_from_pem(&dir.join(name)).unwrap();
+            assert_eq!(pub_key.tree_type, LMS_TREE_TYPE);
+            assert_eq!(pub_key.otstype, LMS_OTS_TYPE);
+        }
+        let (pub0, pub1) = (
+            std::fs::read(dir.join(&config.vendor.lms_pub_keys[0])).unwrap(),
+            std::fs::read(dir.join(&config.vendor.lms_pub_keys[1])).unwrap(),
+        );
+        assert_ne!(pub0, pub1);
+
+        // Keys are never overwritten
+        assert!(generate_keys(&dir, true, LMS_TREE_TYPE, LMS_OTS_TYPE).is_err());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_generate_keys_without_owner() {
+        let dir = out_dir("no-owner");
+        let config = generate_keys(&dir, false, LMS_TREE_TYPE, LMS_OTS_TYPE).unwrap();
+        assert!(config.owner.is_none());
+        assert!(!dir.join("own-pub-key.pem").exists());
+        assert!(!dir.join("own-lms-pub-key.pem").exists());
+        assert_eq!(fuse_values(&dir, &config).unwrap().1, None);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_generated_keys_sign_image_matching_fuse_values() {
+        let dir = out_dir("create");
+        let keys = generate_keys(&dir, true, LMS_TREE_TYPE, LMS_OTS_TYPE).unwrap();
+        let (vendor_digest, owner_digest) = fuse_values(&dir, &keys).unwrap();
+
+        // Sign an image with the keys the way the create command does
+        let config = load_key_config(&dir.join(KEY_CONFIG_FILE)).unwrap();
+        let gen_config = ImageGeneratorConfig {
+            vendor_config: vendor_config(&dir, &config.vendor, 0, 0, [0; 15], [0; 15]).unwrap(),
+            owner_config: owner_config(&dir, &config.owner, [0; 15], [0; 15]).unwrap(),
+            fmc: TestExecutable::new(ElfLayout::fmc().code.start),
+            runtime: TestExecutable::new(ElfLayout::runtime().code.start),
+            components: Vec::new(),
+        };
+        let gen = ImageGenerator::new(OsslCrypto::default());
+        let image = gen.generate(&gen_config).unwrap();
+        let preamble = &image.manifest.preamble;
+        assert_eq!(gen.vendor_pubkey_digest(preamble).unwrap(), vendor_digest);
+        assert_eq!(
+            Some(gen.owner_pubkey_digest(preamble).unwrap()),
+            owner_digest
+        );
+
+        // The ROM verifier only supports H15 LMS signatures, so the H5 LMS
+        // signatures are checked here and the image is verified with ECC
+        let header = &image.manifest.header;
+        assert!(lms_verify(
+            &gen.header_digest_vendor(header).unwrap(),
+            &preamble.vendor_pub_keys.lms_pub_keys[0],
+            &preamble.vendor_sigs.lms_sig,
+        )
+        .unwrap());
+        assert!(lms_verify(
+            &gen.header_digest_owner(header).unwrap(),
+            &preamble.owner_pub_keys.lms_pub_key,
+            &preamble.owner_sigs.lms_sig,
+        )
+        .unwrap());
+
+        let fuses = FuseValues {
+            key_manifest_pk_hash: vendor_digest,
+            key_manifest_pk_hash_mask: 0,
+            owner_pk_hash: owner_digest.unwrap(),
+            fmc_key_manifest_svn: 0,
+            runtime_svn: [0; 4],
+            anti_rollback_disable: false,
+            idevid_key_id_algo: "sha1",
+            idevid_cert_attr_flags: 0,
+            life_cycle: Lifecycle::Production,
+            lms_verify: false,
+            mldsa_verify: false,
+            fuse_lms_revocation: 0,
+        };
+        cross_check(&image.to_bytes().unwrap(), &image.manifest, &fuses).unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -13,9 +13,16 @@ Abstract:
 --*/
 use std::path::PathBuf;
 
-use clap::{arg, value_parser, Command};
+use clap::{arg, value_parser, ArgAction, Command};
 
 mod create;
+mod diff;
+mod fuses;
+mod hss_sign;
+mod keygen;
+mod resign;
+#[cfg(test)]
+mod test_util;
 
 /// Entry point
 fn main() {
@@ -81,6 +88,16 @@ fn main() {
                 .required(true)
                 .value_parser(value_parser!(PathBuf)),
         )
+        .arg(
+            arg!(--"lms-state" <FILE> "LMS key state file tracking used leaves. Required when signing with LMS private keys")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"insecure-fixed-lms-q" "Sign with the fixed LMS leaf supported by the fake test keys instead of using --lms-state. Never use with real keys")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             arg!(--"own-from-date" <String> "Certificate Validity Start Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
                 .required(false)
@@ -100,6 +117,214 @@ fn main() {
             arg!(--"mfg-to-date" <String> "Certificate Validity End Date By Manufacturer [YYYYMMDDHHMMSS - Zulu Time]")
                 .required(false)
                 .value_parser(value_parser!(String)),
+        ),
+        Command::new("keygen")
+        .about("Generate vendor and owner keys and the key configuration file")
+        .arg(
+            arg!(--"out-dir" <DIR> "Output directory for the keys and keys.toml")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"no-owner" "Only generate vendor keys")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        ),
+        Command::new("resign")
+        .about("Replace the owner keys and signatures of a vendor signed firmware image bundle")
+        .arg(
+            arg!(--"image" <FILE> "Vendor signed firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"key-config" <FILE> "Key Configuration file with the owner keys")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output file")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"vendor-pk-hash" <HEX> "Expected vendor public key hash (fuse_key_manifest_pk_hash)")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"lms-state" <FILE> "LMS key state file tracking used leaves of the owner LMS key")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"insecure-fixed-lms-q" "Sign with the fixed LMS leaf supported by the fake test keys instead of using --lms-state. Never use with real keys")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"own-from-date" <String> "Certificate Validity Start Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"own-to-date" <String> "Certificate Validity End Date By Owner [YYYYMMDDHHMMSS - Zulu Time]")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"own-epoch" <U16> "Owner epoch")
+                .required(false)
+                .value_parser(value_parser!(u16)),
+        ),
+        Command::new("hss-sign")
+        .about("Sign the LMS public key of an HSS level with its parent key, once, when the level is provisioned")
+        .arg(
+            arg!(--"parent-priv-key" <FILE> "LMS private key of the parent level")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"pub-key" <FILE> "LMS public key of the level to sign")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output signed public key file, listed in lms_hss_signed_pub_keys")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"lms-state" <FILE> "LMS key state file tracking used leaves of the parent key")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"insecure-fixed-lms-q" "Sign with the fixed LMS leaf supported by the fake test keys instead of using --lms-state. Never use with real keys")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        ),
+        Command::new("fuses")
+        .about("Generate the fuse values for a firmware image bundle and check that the image verifies with them")
+        .arg(
+            arg!(--"image" <FILE> "Firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"ecc-revocation" <U32> "Vendor ECC public key revocation mask")
+                .required(false)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"lms-revocation" <U32> "Vendor LMS public key revocation mask")
+                .required(false)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"fmc-svn" <U32> "FMC SVN to burn. Defaults to the SVN of the image")
+                .required(false)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"rt-svn" <U32> "Runtime SVN to burn. Defaults to the SVN of the image")
+                .required(false)
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            arg!(--"anti-rollback-disable" "Disable the SVN anti-rollback checks")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"lms-verify" "Require LMS signatures in addition to ECC")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"mldsa-verify" "Require ML-DSA-87 signatures in addition to ECC")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"idevid-key-id-algo" <ALGO> "IDevID subject key identifier algorithm")
+                .required(false)
+                .value_parser(["sha1", "sha256", "sha384", "fuse"])
+                .default_value("sha1"),
+        )
+        .arg(
+            arg!(--"lifecycle" <STATE> "Device lifecycle state")
+                .required(false)
+                .value_parser(["unprovisioned", "manufacturing", "production"])
+                .default_value("production"),
+        )
+        .arg(
+            arg!(--"out-json" <FILE> "Output JSON file")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"out-rust" <FILE> "Output caliptra_hw_model::Fuses initializer")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"out-c" <FILE> "Output libcaliptra struct caliptra_fuses initializer")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        ),
+        Command::new("diff")
+        .about("Compare the manifests, TOC entries and executable sizes of two firmware image bundles")
+        .arg(
+            arg!(--"old" <FILE> "Old firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"new" <FILE> "New firmware image bundle")
+                .required(true)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"old-fmc-elf" <FILE> "FMC ELF binary of the old image")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"new-fmc-elf" <FILE> "FMC ELF binary of the new image")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"old-rt-elf" <FILE> "Runtime ELF binary of the old image")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"new-rt-elf" <FILE> "Runtime ELF binary of the new image")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"format" <FORMAT> "Output format")
+                .required(false)
+                .value_parser(["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            arg!(--"out" <FILE> "Output file. Defaults to stdout")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"fail-on-svn-decrease" "Fail if the FMC, runtime or a component SVN decreased")
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"fail-on-change" "Fail if any manifest or TOC field differs")
+                .required(false)
+                .action(ArgAction::SetTrue),
         )];
 
     let cmd = Command::new("caliptra-image-app")
@@ -110,6 +335,11 @@ fn main() {
 
     let result = match cmd.subcommand().unwrap() {
         ("create", args) => create::run_cmd(args),
+        ("keygen", args) => keygen::run_cmd(args),
+        ("resign", args) => resign::run_cmd(args),
+        ("hss-sign", args) => hss_sign::run_cmd(args),
+        ("fuses", args) => fuses::run_cmd(args),
+        ("diff", args) => diff::run_cmd(args),
         (_, _) => unreachable!(),
     };
 
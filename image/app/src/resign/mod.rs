@@ -0,0 +1,103 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mod.rs
+
+Abstract:
+
+    File contains implementation of the Caliptra owner re-signing command.
+
+--*/
+
+use anyhow::{anyhow, Context};
+use caliptra_image_gen::ImageGenerator;
+use caliptra_image_openssl::{LmsKeyState, OsslCrypto};
+use caliptra_image_serde::ImageBundleWriter;
+use caliptra_image_types::ImageBundle;
+use clap::ArgMatches;
+use std::path::PathBuf;
+
+use crate::create::{check_date, config, owner_config};
+
+/// Run the command
+pub(crate) fn run_cmd(args: &ArgMatches) -> anyhow::Result<()> {
+    let image_path: &PathBuf = args
+        .get_one::<PathBuf>("image")
+        .with_context(|| "image arg not specified")?;
+
+    let config_path: &PathBuf = args
+        .get_one::<PathBuf>("key-config")
+        .with_context(|| "key-config arg not specified")?;
+
+    let out_path: &PathBuf = args
+        .get_one::<PathBuf>("out")
+        .with_context(|| "out arg not specified")?;
+
+    //YYYYMMDDHHMMSS - Zulu Time
+    let mut own_from_date: [u8; 15] = [0u8; 15];
+    let mut own_to_date: [u8; 15] = [0u8; 15];
+    if let Some(from_date) = args.get_one::<String>("own-from-date") {
+        if let Some(to_date) = args.get_one::<String>("own-to-date") {
+            check_date(from_date, to_date)?;
+            own_from_date[0..14].copy_from_slice(&from_date.as_bytes()[0..14]);
+            own_from_date[14] = b'Z';
+            own_to_date[0..14].copy_from_slice(&to_date.as_bytes()[0..14]);
+            own_to_date[14] = b'Z';
+        }
+    }
+
+    let config = config::load_key_config(config_path)?;
+    let config_dir = config_path
+        .parent()
+        .with_context(|| "Invalid parent path")?;
+    let mut owner_config = owner_config(config_dir, &config.owner, own_from_date, own_to_date)?
+        .ok_or_else(|| anyhow!("Key configuration has no owner keys"))?;
+    if let Some(epoch) = args.get_one::<u16>("own-epoch") {
+        owner_config.epoch = epoch.to_le_bytes();
+    }
+
+    let image = std::fs::read(image_path)
+        .with_context(|| format!("Failed to read image {}", image_path.display()))?;
+    let image = ImageBundle::from_bytes(&image)
+        .with_context(|| format!("Failed to parse image {}", image_path.display()))?;
+
+    let crypto = match args.get_one::<PathBuf>("lms-state") {
+        Some(path) => OsslCrypto::with_lms_state(LmsKeyState::open(path)?),
+        None if !args.get_flag("insecure-fixed-lms-q") => {
+            return Err(anyhow!(
+                "--lms-state is required when signing with LMS private keys"
+            ))
+        }
+        None => OsslCrypto::default(),
+    };
+    let gen = ImageGenerator::new(crypto);
+
+    // The vendor signatures only prove the image matches the vendor keys it
+    // carries; check those keys against the fuse value when it is known.
+    if let Some(expected) = args.get_one::<String>("vendor-pk-hash") {
+        let digest = gen.vendor_pubkey_digest(&image.manifest.preamble)?;
+        let digest: String = digest.iter().map(|w| format!("{w:08x}")).collect();
+        if !digest.eq_ignore_ascii_case(expected.trim_start_matches("0x")) {
+            return Err(anyhow!(
+                "Vendor public key hash {digest} does not match --vendor-pk-hash"
+            ));
+        }
+    }
+
+    let image = gen.resign_owner(image, &owner_config)?;
+
+    let out_file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(out_path)
+        .with_context(|| format!("Failed to create file {}", out_path.display()))?;
+
+    let mut writer = ImageBundleWriter::new(out_file);
+    writer.write(&image)?;
+
+    Ok(())
+}
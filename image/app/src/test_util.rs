@@ -0,0 +1,63 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   test_util.rs
+
+Abstract:
+
+    File contains helpers shared by the unit tests of the commands.
+
+--*/
+
+use caliptra_image_gen::ImageGenratorExecutable;
+use caliptra_image_types::ImageRevision;
+
+/// Executable of `nop` instructions at `load_addr`
+pub(crate) struct TestExecutable {
+    load_addr: u32,
+    rev: ImageRevision,
+    content: Vec<u8>,
+}
+
+impl TestExecutable {
+    pub(crate) fn new(load_addr: u32) -> Self {
+        Self {
+            load_addr,
+            rev: Default::default(),
+            content: 0x0000_0013u32.to_le_bytes().repeat(64),
+        }
+    }
+}
+
+impl ImageGenratorExecutable for TestExecutable {
+    fn version(&self) -> u32 {
+        0
+    }
+
+    fn svn(&self) -> u32 {
+        0
+    }
+
+    fn rev(&self) -> &ImageRevision {
+        &self.rev
+    }
+
+    fn load_addr(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn entry_point(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn content(&self) -> &Vec<u8> {
+        &self.content
+    }
+
+    fn size(&self) -> u32 {
+        self.content.len() as u32
+    }
+}
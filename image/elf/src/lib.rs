@@ -13,12 +13,186 @@ Abstract:
 --*/
 
 use anyhow::{bail, Context};
+use caliptra_common::memory_layout::{
+    DATA_ORG, DATA_SIZE, DCCM_ORG, DCCM_SIZE, ESTACK_ORG, ESTACK_SIZE, ICCM_RANGE, NSTACK_ORG,
+    NSTACK_SIZE, RTALIAS_TBS_ORG, RTALIAS_TBS_SIZE, STACK_ORG, STACK_SIZE,
+};
+use caliptra_common::{FMC_ORG, FMC_SIZE, RUNTIME_ORG, RUNTIME_SIZE};
 use caliptra_image_gen::ImageGenratorExecutable;
 use caliptra_image_types::ImageRevision;
+use elf::abi::{PF_W, PF_X, PT_LOAD};
 use elf::endian::AnyEndian;
 use elf::ElfBytes;
+use std::ops::Range;
 use std::path::PathBuf;
 
+mod symbols;
+
+pub use symbols::{elf_symbols, Symbol, SymbolBind, SymbolType, SymbolVisibility};
+
+/// Memory regions an executable is permitted to occupy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElfLayout {
+    /// Region holding code and read-only data
+    pub code: Range<u32>,
+
+    /// Regions holding writable data, BSS and stacks
+    pub data: Vec<Range<u32>>,
+}
+
+impl ElfLayout {
+    /// Layout accepting code anywhere in ICCM and data anywhere in DCCM.
+    pub fn iccm() -> Self {
+        Self {
+            code: ICCM_RANGE,
+            data: vec![DCCM_ORG..DCCM_ORG + DCCM_SIZE],
+        }
+    }
+
+    /// Layout of the First Mutable Code, as generated by `gen_memory_x`.
+    pub fn fmc() -> Self {
+        Self::with_code(FMC_ORG..FMC_ORG + FMC_SIZE)
+    }
+
+    /// Layout of the Runtime firmware, as generated by `gen_memory_x`.
+    pub fn runtime() -> Self {
+        Self::with_code(RUNTIME_ORG..RUNTIME_ORG + RUNTIME_SIZE)
+    }
+
+    fn with_code(code: Range<u32>) -> Self {
+        Self {
+            code,
+            data: vec![
+                RTALIAS_TBS_ORG..RTALIAS_TBS_ORG + RTALIAS_TBS_SIZE,
+                DATA_ORG..DATA_ORG + DATA_SIZE,
+                STACK_ORG..STACK_ORG + STACK_SIZE,
+                ESTACK_ORG..ESTACK_ORG + ESTACK_SIZE,
+                NSTACK_ORG..NSTACK_ORG + NSTACK_SIZE,
+            ],
+        }
+    }
+}
+
+/// Loadable (PT_LOAD) segment of an ELF file
+#[derive(Clone, Debug)]
+struct LoadSegment {
+    range: Range<u32>,
+    writable: bool,
+    executable: bool,
+}
+
+fn fmt_range(range: &Range<u32>) -> String {
+    format!("0x{:08x}..0x{:08x}", range.start, range.end)
+}
+
+fn contains(outer: &Range<u32>, inner: &Range<u32>) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+fn intersects(a: &Range<u32>, b: &Range<u32>) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Read the non-empty loadable segments from the ELF program headers.
+fn load_segments(elf_file: &ElfBytes<AnyEndian>) -> anyhow::Result<Vec<LoadSegment>> {
+    let Some(segments) = elf_file.segments() else {
+        bail!("ELF file has no program headers");
+    };
+    let mut result = vec![];
+    for phdr in segments
+        .iter()
+        .filter(|p| p.p_type == PT_LOAD && p.p_memsz > 0)
+    {
+        let start = u32::try_from(phdr.p_vaddr);
+        let end = phdr.p_vaddr.checked_add(phdr.p_memsz).map(u32::try_from);
+        let (Ok(start), Some(Ok(end))) = (start, end) else {
+            bail!(
+                "Segment at 0x{:x} with size 0x{:x} exceeds the 32-bit address space",
+                phdr.p_vaddr,
+                phdr.p_memsz
+            );
+        };
+        result.push(LoadSegment {
+            range: start..end,
+            writable: phdr.p_flags & PF_W != 0,
+            executable: phdr.p_flags & PF_X != 0,
+        });
+    }
+    Ok(result)
+}
+
+/// Check the loadable segments and entry point of an executable against a
+/// memory layout.
+///
+/// # Arguments
+///
+/// * `segments` - Loadable segments of the executable
+/// * `layout` - Permitted memory regions
+/// * `image` - Address range covered by the generated image content
+/// * `entry_point` - Executable entry point
+fn validate_layout(
+    segments: &[LoadSegment],
+    layout: &ElfLayout,
+    image: &Range<u32>,
+    entry_point: u32,
+) -> anyhow::Result<()> {
+    let mut sorted: Vec<&LoadSegment> = segments.iter().collect();
+    sorted.sort_by_key(|s| s.range.start);
+    for pair in sorted.windows(2) {
+        if intersects(&pair[0].range, &pair[1].range) {
+            bail!(
+                "Segments {} and {} overlap",
+                fmt_range(&pair[0].range),
+                fmt_range(&pair[1].range)
+            );
+        }
+    }
+
+    for segment in sorted {
+        let range = &segment.range;
+        if segment.writable {
+            if intersects(range, &ICCM_RANGE) || intersects(range, &layout.code) {
+                bail!(
+                    "Writable segment {} is in ICCM; writable data must be placed in DCCM",
+                    fmt_range(range)
+                );
+            }
+            if !layout.data.iter().any(|region| contains(region, range)) {
+                bail!(
+                    "Writable segment {} is outside the permitted data regions {}",
+                    fmt_range(range),
+                    layout
+                        .data
+                        .iter()
+                        .map(fmt_range)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        } else if !contains(&layout.code, range) {
+            bail!(
+                "Segment {} is outside the code region {}",
+                fmt_range(range),
+                fmt_range(&layout.code)
+            );
+        }
+    }
+
+    if !image.contains(&entry_point) {
+        bail!(
+            "Entry point 0x{entry_point:08x} is outside the image {}",
+            fmt_range(image)
+        );
+    }
+    if !segments
+        .iter()
+        .any(|s| s.executable && s.range.contains(&entry_point))
+    {
+        bail!("Entry point 0x{entry_point:08x} is not in an executable segment");
+    }
+    Ok(())
+}
+
 /// ELF Executable
 #[derive(Default)]
 pub struct ElfExecutable {
@@ -58,12 +232,48 @@ impl ElfExecutable {
         let file_data = std::fs::read(path).with_context(|| "Failed to read file")?;
         ElfExecutable::new(&file_data, version, svn, rev)
     }
+
+    /// Open an ELF file and validate it against `layout`.
+    pub fn open_with_layout(
+        path: &PathBuf,
+        version: u32,
+        svn: u32,
+        rev: ImageRevision,
+        layout: &ElfLayout,
+    ) -> anyhow::Result<Self> {
+        let file_data = std::fs::read(path).with_context(|| "Failed to read file")?;
+        ElfExecutable::new_with_layout(&file_data, version, svn, rev, layout)
+            .with_context(|| format!("Invalid executable {}", path.display()))
+    }
+
     /// Create new instance of `ElfExecutable`.
+    ///
+    /// The executable is checked against `ElfLayout::iccm()`.
     pub fn new(
         elf_bytes: &[u8],
         version: u32,
         svn: u32,
         rev: ImageRevision,
+    ) -> anyhow::Result<Self> {
+        Self::new_with_layout(elf_bytes, version, svn, rev, &ElfLayout::iccm())
+    }
+
+    /// Create new instance of `ElfExecutable`, rejecting executables whose
+    /// segments or entry point do not fit `layout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `elf_bytes` - ELF file contents
+    /// * `version` - Executable version
+    /// * `svn` - Executable security version number
+    /// * `rev` - Executable revision
+    /// * `layout` - Permitted memory regions
+    pub fn new_with_layout(
+        elf_bytes: &[u8],
+        version: u32,
+        svn: u32,
+        rev: ImageRevision,
+        layout: &ElfLayout,
     ) -> anyhow::Result<Self> {
         let mut content = vec![];
 
@@ -89,6 +299,17 @@ impl ElfExecutable {
 
         let entry_point = elf_file.ehdr.e_entry as u32;
 
+        let image_end = u32::try_from(content.len())
+            .ok()
+            .and_then(|len| load_addr.checked_add(len))
+            .with_context(|| "Image exceeds the 32-bit address space")?;
+        validate_layout(
+            &load_segments(&elf_file)?,
+            layout,
+            &(load_addr..image_end),
+            entry_point,
+        )?;
+
         Ok(Self {
             version,
             svn,
@@ -164,7 +385,8 @@ impl ImageGenratorExecutable for ElfExecutable {
 
 #[cfg(test)]
 mod test {
-    use crate::load_into_image;
+    use crate::{load_into_image, validate_layout, ElfLayout, LoadSegment};
+    use caliptra_common::{FMC_ORG, RUNTIME_ORG};
 
     #[test]
     fn test_load_into_image() {
@@ -185,4 +407,103 @@ mod test {
             "Section address 0x3fffffff is below image base address 0x40000000"
         );
     }
+
+    fn text(start: u32, end: u32) -> LoadSegment {
+        LoadSegment {
+            range: start..end,
+            writable: false,
+            executable: true,
+        }
+    }
+
+    fn data(start: u32, end: u32) -> LoadSegment {
+        LoadSegment {
+            range: start..end,
+            writable: true,
+            executable: false,
+        }
+    }
+
+    fn validate(segments: &[LoadSegment], layout: &ElfLayout, entry_point: u32) -> String {
+        let image = segments[0].range.clone();
+        match validate_layout(segments, layout, &image, entry_point) {
+            Ok(()) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_layout() {
+        let segments = [
+            text(FMC_ORG, FMC_ORG + 0x1000),
+            data(0x5000_6a00, 0x5000_7000),
+        ];
+        assert_eq!(validate(&segments, &ElfLayout::fmc(), FMC_ORG), "");
+        assert_eq!(validate(&segments, &ElfLayout::iccm(), FMC_ORG + 0x10), "");
+    }
+
+    #[test]
+    fn test_validate_layout_overlap() {
+        let segments = [
+            text(FMC_ORG, FMC_ORG + 0x1000),
+            text(FMC_ORG + 0xff0, FMC_ORG + 0x1100),
+        ];
+        assert_eq!(
+            validate(&segments, &ElfLayout::fmc(), FMC_ORG),
+            "Segments 0x40000000..0x40001000 and 0x40000ff0..0x40001100 overlap"
+        );
+    }
+
+    #[test]
+    fn test_validate_layout_outside_code_region() {
+        let segments = [text(RUNTIME_ORG, RUNTIME_ORG + 0x100)];
+        assert_eq!(
+            validate(&segments, &ElfLayout::fmc(), RUNTIME_ORG),
+            "Segment 0x40005000..0x40005100 is outside the code region 0x40000000..0x40005000"
+        );
+    }
+
+    #[test]
+    fn test_validate_layout_writable_iccm() {
+        let segments = [
+            text(FMC_ORG, FMC_ORG + 0x100),
+            data(0x4001_0000, 0x4001_0100),
+        ];
+        assert_eq!(
+            validate(&segments, &ElfLayout::iccm(), FMC_ORG),
+            "Writable segment 0x40010000..0x40010100 is in ICCM; writable data must be placed in DCCM"
+        );
+    }
+
+    #[test]
+    fn test_validate_layout_writable_outside_data() {
+        let segments = [
+            text(FMC_ORG, FMC_ORG + 0x100),
+            data(0x5000_0000, 0x5000_0100),
+        ];
+        assert_eq!(
+            validate(&segments, &ElfLayout::fmc(), FMC_ORG),
+            "Writable segment 0x50000000..0x50000100 is outside the permitted data regions \
+             0x50004400..0x50004800, 0x50006e00..0x5001a000, 0x5001a000..0x5001f800, \
+             0x5001f800..0x5001fc00, 0x5001fc00..0x50020000"
+        );
+    }
+
+    #[test]
+    fn test_validate_layout_entry_point() {
+        let segments = [text(FMC_ORG, FMC_ORG + 0x100)];
+        assert_eq!(
+            validate(&segments, &ElfLayout::fmc(), FMC_ORG + 0x100),
+            "Entry point 0x40000100 is outside the image 0x40000000..0x40000100"
+        );
+
+        let segments = [LoadSegment {
+            executable: false,
+            ..text(FMC_ORG, FMC_ORG + 0x100)
+        }];
+        assert_eq!(
+            validate(&segments, &ElfLayout::fmc(), FMC_ORG),
+            "Entry point 0x40000000 is not in an executable segment"
+        );
+    }
 }
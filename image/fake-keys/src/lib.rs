@@ -2,9 +2,9 @@
 
 use caliptra_image_gen::{ImageGeneratorOwnerConfig, ImageGeneratorVendorConfig};
 use caliptra_image_types::{
-    ImageEccPrivKey, ImageEccPubKey, ImageLmsPrivKey, ImageLmsPublicKey, ImageOwnerPrivKeys,
-    ImageOwnerPubKeys, ImageVendorPrivKeys, ImageVendorPubKeys, IMAGE_LMS_OTS_TYPE,
-    IMAGE_LMS_TREE_TYPE,
+    ImageEccPrivKey, ImageEccPubKey, ImageLmsPrivKey, ImageLmsPublicKey, ImageMldsaPrivKey,
+    ImageOwnerPrivKeys, ImageOwnerPubKeys, ImageVendorPrivKeys, ImageVendorPubKeys,
+    IMAGE_LMS_OTS_TYPE, IMAGE_LMS_TREE_TYPE,
 };
 use caliptra_lms_types::bytes_to_words_6;
 
@@ -236,6 +236,16 @@ pub const OWNER_ECC_KEY_PRIVATE: ImageEccPrivKey = [
     0x59fdf849, 0xe39f4256, 0x19342ed2, 0x81d28d3d, 0x45ab3219, 0x5174582c, 0xecb4e9df, 0x9cc2e991,
     0xb75f88fd, 0xfa4bc6a4, 0x6b88340f, 0x05dd8890,
 ];
+/// ML-DSA-87 key generation seeds; the public keys are derived from them with
+/// `caliptra_image_openssl::mldsa87_pub_key`
+pub const VENDOR_MLDSA_KEY_PRIVATE: ImageMldsaPrivKey = [
+    0x3c, 0x2f, 0x8e, 0x51, 0xa7, 0x04, 0xd9, 0x6b, 0x15, 0xe2, 0x7a, 0xc8, 0x93, 0x0f, 0x46, 0xbd,
+    0x58, 0x21, 0xfa, 0x6e, 0x07, 0xc3, 0x9d, 0x32, 0xb4, 0x4a, 0x81, 0xe5, 0x1c, 0x76, 0x2d, 0x98,
+];
+pub const OWNER_MLDSA_KEY_PRIVATE: ImageMldsaPrivKey = [
+    0xd1, 0x64, 0x0b, 0x9f, 0x27, 0xe8, 0x5a, 0x33, 0xc6, 0x7d, 0x12, 0xab, 0x4e, 0xf0, 0x89, 0x35,
+    0x6a, 0xb7, 0x03, 0xde, 0x91, 0x48, 0x2c, 0xf5, 0x1e, 0x83, 0x60, 0xca, 0x57, 0x0d, 0xb2, 0x49,
+];
 pub const VENDOR_PUBLIC_KEYS: ImageVendorPubKeys = ImageVendorPubKeys {
     ecc_pub_keys: [
         VENDOR_ECC_KEY_0_PUBLIC,
@@ -336,6 +346,9 @@ pub const VENDOR_CONFIG_KEY_0: ImageGeneratorVendorConfig = ImageGeneratorVendor
     ecc_key_idx: 0,
     lms_key_idx: 0,
     priv_keys: Some(VENDOR_PRIVATE_KEYS),
+    lms_hss: None,
+    mldsa_pub_key: None,
+    mldsa_priv_key: None,
     not_before: [0u8; 15],
     not_after: [0u8; 15],
     pl0_pauser: Some(0x1),
@@ -368,6 +381,9 @@ pub const OWNER_CONFIG: ImageGeneratorOwnerConfig = ImageGeneratorOwnerConfig {
         ecc_priv_key: OWNER_ECC_KEY_PRIVATE,
         lms_priv_key: OWNER_LMS_KEY_PRIVATE,
     }),
+    lms_hss: None,
+    mldsa_pub_key: None,
+    mldsa_priv_key: None,
     not_before: [0u8; 15],
     not_after: [0u8; 15],
     epoch: [0u8; 2],
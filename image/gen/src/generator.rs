@@ -11,8 +11,9 @@ Abstract:
     Caliptra Image generator
 
 --*/
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use caliptra_image_types::*;
+use core::mem::size_of;
 use memoffset::offset_of;
 use zerocopy::AsBytes;
 
@@ -45,8 +46,33 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
     where
         E: ImageGenratorExecutable,
     {
-        let image_size =
-            IMAGE_MANIFEST_BYTE_SIZE as u32 + config.fmc.size() + config.runtime.size();
+        if config.components.len() > MAX_COMPONENT_COUNT as usize {
+            bail!(
+                "Image has {} components; at most {MAX_COMPONENT_COUNT} are supported",
+                config.components.len()
+            );
+        }
+
+        let toc_size = (config.components.len() * size_of::<ImageTocEntry>()) as u32;
+        let image_size = IMAGE_MANIFEST_BYTE_SIZE as u32
+            + toc_size
+            + config.fmc.size()
+            + config.runtime.size()
+            + config
+                .components
+                .iter()
+                .map(|c| c.content.len() as u32)
+                .sum::<u32>()
+            + if config.vendor_config.mldsa_pub_key.is_some() {
+                size_of::<ImagePqcExtension>() as u32
+            } else {
+                0
+            }
+            + if Self::has_hss_extension(config) {
+                size_of::<ImageHssExtension>() as u32
+            } else {
+                0
+            };
         if image_size > IMAGE_BYTE_SIZE as u32 {
             bail!(
                 "Image larger than {IMAGE_BYTE_SIZE} bytes; image size:{} bytes",
@@ -56,7 +82,7 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
 
         // Create FMC TOC & Content
         let id = ImageTocEntryId::Fmc;
-        let offset = IMAGE_MANIFEST_BYTE_SIZE as u32;
+        let offset = IMAGE_MANIFEST_BYTE_SIZE as u32 + toc_size;
         let (fmc_toc, fmc) = self.gen_image(&config.fmc, id, offset)?;
 
         // Create Runtime TOC & Content
@@ -75,11 +101,45 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             );
         }
 
+        // Create Component TOCs & Content
+        let mut offset = offset + runtime_toc.size;
+        let mut components: Vec<ImageComponent> = Vec::new();
+        for component in config.components.iter() {
+            let component = self.gen_component(component, offset)?;
+            if component.toc.id == u32::from(ImageTocEntryId::Fmc)
+                || component.toc.id == u32::from(ImageTocEntryId::Runtime)
+                || components.iter().any(|c| c.toc.id == component.toc.id)
+            {
+                bail!(
+                    "Component id {:#x?} is reserved or not unique",
+                    component.toc.id
+                );
+            }
+            let others = [&fmc_toc, &runtime_toc]
+                .into_iter()
+                .chain(components.iter().map(|c| &c.toc));
+            for other in others {
+                if component.toc.overlaps(other) {
+                    bail!(
+                        "Component {:#x?}:[{:#x?}:{:#x?}] load address range overlaps [{:#x?}:{:#x?}]",
+                        component.toc.id,
+                        component.toc.load_addr,
+                        component.toc.load_addr + component.toc.size - 1,
+                        other.load_addr,
+                        other.load_addr + other.size - 1
+                    );
+                }
+            }
+            offset += component.toc.size;
+            components.push(component);
+        }
+        let component_tocs: Vec<ImageTocEntry> = components.iter().map(|c| c.toc).collect();
+
         let ecc_key_idx = config.vendor_config.ecc_key_idx;
         let lms_key_idx = config.vendor_config.lms_key_idx;
 
         // Create Header
-        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc)?;
+        let toc_digest = self.toc_digest(&fmc_toc, &runtime_toc, &component_tocs)?;
         let header = self.gen_header(config, ecc_key_idx, lms_key_idx, toc_digest)?;
 
         // Create Preamable
@@ -93,6 +153,13 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             &header_digest_owner,
         )?;
 
+        // Create PQC Extension
+        let pqc_extension =
+            self.gen_pqc_extension(config, &header_digest_vendor, &header_digest_owner)?;
+
+        // Create HSS Extension
+        let hss_extension = Self::gen_hss_extension(config)?;
+
         // Create Manifest
         let manifest = ImageManifest {
             marker: MANIFEST_MARKER,
@@ -108,6 +175,9 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             manifest,
             fmc,
             runtime,
+            components,
+            pqc_extension,
+            hss_extension,
         };
 
         Ok(image)
@@ -126,7 +196,6 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         E: ImageGenratorExecutable,
     {
         let mut vendor_sigs = ImageSignatures::default();
-        let mut owner_sigs = ImageSignatures::default();
 
         if let Some(priv_keys) = config.vendor_config.priv_keys {
             let sig = self.crypto.ecdsa384_sign(
@@ -135,27 +204,18 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
                 &config.vendor_config.pub_keys.ecc_pub_keys[ecc_vendor_key_idx as usize],
             )?;
             vendor_sigs.ecc_sig = sig;
-            let lms_sig = self.crypto.lms_sign(
-                digest_vendor,
+            let lms_priv_key = Self::lms_signing_key(
                 &priv_keys.lms_priv_keys[lms_vendor_key_idx as usize],
+                config.vendor_config.lms_hss.as_ref(),
             )?;
+            let lms_sig = self.crypto.lms_sign(digest_vendor, lms_priv_key)?;
             vendor_sigs.lms_sig = lms_sig;
         }
 
-        if let Some(owner_config) = &config.owner_config {
-            if let Some(priv_keys) = &owner_config.priv_keys {
-                let sig = self.crypto.ecdsa384_sign(
-                    digest_owner,
-                    &priv_keys.ecc_priv_key,
-                    &owner_config.pub_keys.ecc_pub_key,
-                )?;
-                owner_sigs.ecc_sig = sig;
-                let lms_sig = self
-                    .crypto
-                    .lms_sign(digest_owner, &priv_keys.lms_priv_key)?;
-                owner_sigs.lms_sig = lms_sig;
-            }
-        }
+        let owner_sigs = match &config.owner_config {
+            Some(owner_config) => self.gen_owner_sigs(owner_config, digest_owner)?,
+            None => ImageSignatures::default(),
+        };
 
         let mut preamble = ImagePreamble {
             vendor_pub_keys: config.vendor_config.pub_keys,
@@ -165,14 +225,365 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             owner_sigs,
             ..Default::default()
         };
+        preamble.vendor_lms_hss_levels = Self::hss_levels(config.vendor_config.lms_hss.as_ref())?;
 
         if let Some(owner_config) = &config.owner_config {
             preamble.owner_pub_keys = owner_config.pub_keys;
+            preamble.owner_lms_hss_levels = Self::hss_levels(owner_config.lms_hss.as_ref())?;
         }
 
         Ok(preamble)
     }
 
+    /// Check whether the vendor or the owner signs with an HSS chain
+    fn has_hss_extension<E>(config: &ImageGeneratorConfig<E>) -> bool
+    where
+        E: ImageGenratorExecutable,
+    {
+        config.vendor_config.lms_hss.is_some()
+            || config
+                .owner_config
+                .as_ref()
+                .is_some_and(|owner_config| owner_config.lms_hss.is_some())
+    }
+
+    /// Number of HSS levels of a signer; 0 without an HSS chain
+    fn hss_levels(hss: Option<&ImageGeneratorHssConfig>) -> anyhow::Result<u32> {
+        let Some(hss) = hss else {
+            return Ok(0);
+        };
+        let levels = hss.signed_pub_keys.len() as u32 + 1;
+        if !(2..=LMS_HSS_MAX_LEVELS).contains(&levels) {
+            bail!("HSS chains must have 2 to {LMS_HSS_MAX_LEVELS} levels; levels:{levels}");
+        }
+        Ok(levels)
+    }
+
+    /// Select the LMS private key that signs the header: the key of the
+    /// bottom HSS level, or the top level key without an HSS chain
+    fn lms_signing_key<'a>(
+        top_priv_key: &'a ImageLmsPrivKey,
+        hss: Option<&'a ImageGeneratorHssConfig>,
+    ) -> anyhow::Result<&'a ImageLmsPrivKey> {
+        match hss {
+            Some(hss) => hss
+                .priv_key
+                .as_ref()
+                .ok_or_else(|| anyhow!("An HSS private key is required to sign with an HSS chain")),
+            None => Ok(top_priv_key),
+        }
+    }
+
+    /// Sign the public key of an HSS level with the private key of the level
+    /// above it. This is done once, when the key is provisioned; images copy
+    /// the result, so the parent's one-time signatures are not used per build.
+    ///
+    /// # Arguments
+    ///
+    /// * `pub_key` - LMS public key of the child level
+    /// * `parent_priv_key` - LMS private key of the parent level
+    ///
+    /// # Returns
+    ///
+    /// * `ImageLmsSignedPubKey` - Child public key and the parent's signature
+    pub fn gen_hss_signed_pub_key(
+        &self,
+        pub_key: &ImageLmsPublicKey,
+        parent_priv_key: &ImageLmsPrivKey,
+    ) -> anyhow::Result<ImageLmsSignedPubKey> {
+        Ok(ImageLmsSignedPubKey {
+            sig: self
+                .crypto
+                .lms_sign(&lms_hss_message(pub_key), parent_priv_key)?,
+            pub_key: *pub_key,
+        })
+    }
+
+    /// Copy the provisioned signed public keys of an HSS chain
+    fn gen_hss_signed_pub_keys(
+        hss: &ImageGeneratorHssConfig,
+    ) -> anyhow::Result<[ImageLmsSignedPubKey; LMS_HSS_MAX_LEVELS as usize - 1]> {
+        Self::hss_levels(Some(hss))?;
+        let mut signed_pub_keys =
+            <[ImageLmsSignedPubKey; LMS_HSS_MAX_LEVELS as usize - 1]>::default();
+        signed_pub_keys[..hss.signed_pub_keys.len()].copy_from_slice(&hss.signed_pub_keys);
+        Ok(signed_pub_keys)
+    }
+
+    /// Create the HSS extension when the vendor or the owner signs with an
+    /// HSS chain
+    fn gen_hss_extension<E>(
+        config: &ImageGeneratorConfig<E>,
+    ) -> anyhow::Result<Option<ImageHssExtension>>
+    where
+        E: ImageGenratorExecutable,
+    {
+        if !Self::has_hss_extension(config) {
+            return Ok(None);
+        }
+
+        let mut hss_extension = ImageHssExtension::default();
+        if let Some(hss) = &config.vendor_config.lms_hss {
+            hss_extension.vendor_signed_pub_keys = Self::gen_hss_signed_pub_keys(hss)?;
+        }
+        if let Some(owner_config) = &config.owner_config {
+            if let Some(hss) = &owner_config.lms_hss {
+                hss_extension.owner_signed_pub_keys = Self::gen_hss_signed_pub_keys(hss)?;
+            }
+        }
+        Ok(Some(hss_extension))
+    }
+
+    /// Create the PQC extension when the vendor has an ML-DSA key; the
+    /// signatures are left empty without the private keys
+    fn gen_pqc_extension<E>(
+        &self,
+        config: &ImageGeneratorConfig<E>,
+        digest_vendor: &ImageDigest,
+        digest_owner: &ImageDigest,
+    ) -> anyhow::Result<Option<ImagePqcExtension>>
+    where
+        E: ImageGenratorExecutable,
+    {
+        let Some(vendor_pub_key) = config.vendor_config.mldsa_pub_key else {
+            return Ok(None);
+        };
+        let Some(owner_config) = &config.owner_config else {
+            bail!("An owner ML-DSA public key is required with a vendor ML-DSA public key");
+        };
+
+        let mut pqc_extension = ImagePqcExtension {
+            vendor_mldsa_pub_key: vendor_pub_key,
+            ..Default::default()
+        };
+        if let Some(priv_key) = &config.vendor_config.mldsa_priv_key {
+            pqc_extension.vendor_mldsa_sig = self.crypto.mldsa87_sign(digest_vendor, priv_key)?;
+        }
+        self.gen_owner_mldsa_sig(&mut pqc_extension, owner_config, digest_owner)?;
+        Ok(Some(pqc_extension))
+    }
+
+    /// Set the owner ML-DSA public key and signature of a PQC extension
+    fn gen_owner_mldsa_sig(
+        &self,
+        pqc_extension: &mut ImagePqcExtension,
+        owner_config: &ImageGeneratorOwnerConfig,
+        digest_owner: &ImageDigest,
+    ) -> anyhow::Result<()> {
+        let Some(pub_key) = owner_config.mldsa_pub_key else {
+            bail!("An owner ML-DSA public key is required with a vendor ML-DSA public key");
+        };
+        pqc_extension.owner_mldsa_pub_key = pub_key;
+        pqc_extension.owner_mldsa_sig = match &owner_config.mldsa_priv_key {
+            Some(priv_key) => self.crypto.mldsa87_sign(digest_owner, priv_key)?,
+            None => [0; MLDSA87_SIGNATURE_BYTE_SIZE + 1],
+        };
+        Ok(())
+    }
+
+    /// Generate owner signatures; left empty without owner private keys
+    fn gen_owner_sigs(
+        &self,
+        owner_config: &ImageGeneratorOwnerConfig,
+        digest_owner: &ImageDigest,
+    ) -> anyhow::Result<ImageSignatures> {
+        let mut owner_sigs = ImageSignatures::default();
+        if let Some(priv_keys) = &owner_config.priv_keys {
+            owner_sigs.ecc_sig = self.crypto.ecdsa384_sign(
+                digest_owner,
+                &priv_keys.ecc_priv_key,
+                &owner_config.pub_keys.ecc_pub_key,
+            )?;
+            let lms_priv_key =
+                Self::lms_signing_key(&priv_keys.lms_priv_key, owner_config.lms_hss.as_ref())?;
+            owner_sigs.lms_sig = self.crypto.lms_sign(digest_owner, lms_priv_key)?;
+        }
+        Ok(owner_sigs)
+    }
+
+    /// Replace the owner public keys, owner data and owner signatures of a
+    /// vendor signed image. The vendor signatures and the image contents are
+    /// validated first; the vendor preamble and signatures are kept as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Vendor signed Caliptra Image Bundle
+    /// * `owner_config` - Owner configuration, including the private keys
+    ///
+    /// # Returns
+    ///
+    /// * `ImageBundle` - Caliptra Image Bundle signed by the owner
+    pub fn resign_owner(
+        &self,
+        mut image: ImageBundle,
+        owner_config: &ImageGeneratorOwnerConfig,
+    ) -> anyhow::Result<ImageBundle> {
+        if owner_config.priv_keys.is_none() {
+            bail!("Owner private keys are required to re-sign an image");
+        }
+        if image.pqc_extension.is_some() && owner_config.mldsa_priv_key.is_none() {
+            bail!("An owner ML-DSA private key is required to re-sign an image with ML-DSA");
+        }
+        self.verify_vendor_sigs(&image.manifest, image.hss_extension.as_ref())?;
+        if let Some(pqc_extension) = &image.pqc_extension {
+            self.verify_vendor_mldsa_sig(&image.manifest, pqc_extension)?;
+        }
+        self.verify_contents(&image)?;
+
+        let header = &mut image.manifest.header;
+        header.owner_data.owner_not_before = owner_config.not_before;
+        header.owner_data.owner_not_after = owner_config.not_after;
+        header.owner_data.epoch = owner_config.epoch;
+
+        let digest_owner = self.header_digest_owner(header)?;
+        let preamble = &mut image.manifest.preamble;
+        preamble.owner_pub_keys = owner_config.pub_keys;
+        preamble.owner_lms_hss_levels = Self::hss_levels(owner_config.lms_hss.as_ref())?;
+        preamble.owner_sigs = self.gen_owner_sigs(owner_config, &digest_owner)?;
+
+        // The HSS extension ends the image, so it can be added or dropped
+        // without affecting the vendor signatures
+        match &owner_config.lms_hss {
+            Some(hss) => {
+                image
+                    .hss_extension
+                    .get_or_insert_with(Default::default)
+                    .owner_signed_pub_keys = Self::gen_hss_signed_pub_keys(hss)?;
+            }
+            None if preamble.vendor_lms_hss_levels > 1 => {
+                if let Some(hss_extension) = &mut image.hss_extension {
+                    hss_extension.owner_signed_pub_keys = Default::default();
+                }
+            }
+            None => image.hss_extension = None,
+        }
+        if let Some(pqc_extension) = &mut image.pqc_extension {
+            self.gen_owner_mldsa_sig(pqc_extension, owner_config, &digest_owner)?;
+        }
+
+        Ok(image)
+    }
+
+    /// Verify the vendor ML-DSA signature of an image with the vendor ML-DSA
+    /// public key of its PQC extension
+    fn verify_vendor_mldsa_sig(
+        &self,
+        manifest: &ImageManifest,
+        pqc_extension: &ImagePqcExtension,
+    ) -> anyhow::Result<()> {
+        let pub_key = &pqc_extension.vendor_mldsa_pub_key;
+        let digest_vendor = self.header_digest_vendor(&manifest.header)?;
+        if !self
+            .crypto
+            .mldsa87_verify(&digest_vendor, pub_key, &pqc_extension.vendor_mldsa_sig)?
+        {
+            bail!("Vendor ML-DSA signature is invalid");
+        }
+        Ok(())
+    }
+
+    /// Verify the vendor ECC and LMS signatures of a manifest with the
+    /// vendor public keys it selects, walking the vendor HSS chain if any
+    fn verify_vendor_sigs(
+        &self,
+        manifest: &ImageManifest,
+        hss_extension: Option<&ImageHssExtension>,
+    ) -> anyhow::Result<()> {
+        let preamble = &manifest.preamble;
+        let ecc_key_idx = preamble.vendor_ecc_pub_key_idx;
+        let lms_key_idx = preamble.vendor_lms_pub_key_idx;
+        if ecc_key_idx != manifest.header.vendor_ecc_pub_key_idx
+            || lms_key_idx != manifest.header.vendor_lms_pub_key_idx
+        {
+            bail!("Vendor public key indices in the preamble and header do not match");
+        }
+        let ecc_pub_key = preamble
+            .vendor_pub_keys
+            .ecc_pub_keys
+            .get(ecc_key_idx as usize)
+            .ok_or_else(|| anyhow!("Invalid vendor ECC public key index {ecc_key_idx}"))?;
+        let lms_pub_key = preamble
+            .vendor_pub_keys
+            .lms_pub_keys
+            .get(lms_key_idx as usize)
+            .ok_or_else(|| anyhow!("Invalid vendor LMS public key index {lms_key_idx}"))?;
+        let lms_pub_key =
+            self.verify_hss_chain(lms_pub_key, preamble.vendor_lms_hss_levels, hss_extension)?;
+
+        let digest_vendor = self.header_digest_vendor(&manifest.header)?;
+        if !self.crypto.ecdsa384_verify(
+            &digest_vendor,
+            ecc_pub_key,
+            &preamble.vendor_sigs.ecc_sig,
+        )? {
+            bail!("Vendor ECC signature is invalid");
+        }
+        if !self
+            .crypto
+            .lms_verify(&digest_vendor, &lms_pub_key, &preamble.vendor_sigs.lms_sig)?
+        {
+            bail!("Vendor LMS signature is invalid");
+        }
+        Ok(())
+    }
+
+    /// Verify the vendor HSS chain below `pub_key` and return the public key
+    /// of its last level
+    fn verify_hss_chain(
+        &self,
+        pub_key: &ImageLmsPublicKey,
+        levels: u32,
+        hss_extension: Option<&ImageHssExtension>,
+    ) -> anyhow::Result<ImageLmsPublicKey> {
+        if levels <= 1 {
+            return Ok(*pub_key);
+        }
+        if levels > LMS_HSS_MAX_LEVELS {
+            bail!("Invalid vendor HSS levels {levels}");
+        }
+        let Some(hss_extension) = hss_extension else {
+            bail!("Vendor HSS extension is missing");
+        };
+        let mut pub_key = *pub_key;
+        for signed in &hss_extension.vendor_signed_pub_keys[..levels as usize - 1] {
+            if !self
+                .crypto
+                .lms_verify(&lms_hss_message(&signed.pub_key), &pub_key, &signed.sig)?
+            {
+                bail!("Vendor HSS signature is invalid");
+            }
+            pub_key = signed.pub_key;
+        }
+        Ok(pub_key)
+    }
+
+    /// Verify that the TOC and image contents match the digests in the
+    /// vendor signed header
+    fn verify_contents(&self, image: &ImageBundle) -> anyhow::Result<()> {
+        let manifest = &image.manifest;
+        let component_tocs: Vec<ImageTocEntry> = image.components.iter().map(|c| c.toc).collect();
+        let toc_digest = self.toc_digest(&manifest.fmc, &manifest.runtime, &component_tocs)?;
+        if toc_digest != manifest.header.toc_digest {
+            bail!("TOC digest does not match the header");
+        }
+
+        let sections = [
+            (&manifest.fmc, &image.fmc),
+            (&manifest.runtime, &image.runtime),
+        ]
+        .into_iter()
+        .chain(image.components.iter().map(|c| (&c.toc, &c.content)));
+        for (toc, content) in sections {
+            if self.crypto.sha384_digest(content)? != toc.digest {
+                bail!(
+                    "Digest of image {:#x?} does not match its TOC entry",
+                    toc.id
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Generate header
     fn gen_header<E>(
         &self,
@@ -188,7 +599,7 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
             vendor_ecc_pub_key_idx: ecc_key_idx,
             vendor_lms_pub_key_idx: lms_key_idx,
             flags: Self::DEFAULT_FLAGS,
-            toc_len: MAX_TOC_ENTRY_COUNT,
+            toc_len: FW_TOC_ENTRY_COUNT + config.components.len() as u32,
             toc_digest: digest,
             ..Default::default()
         };
@@ -223,16 +634,73 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         self.crypto.sha384_digest(header.as_bytes())
     }
 
-    /// Calculate owner public key(s) digest
+    /// Calculate owner public key(s) digest, bound to the owner LMS HSS
+    /// levels
     pub fn owner_pubkey_digest(&self, preamble: &ImagePreamble) -> anyhow::Result<ImageDigest> {
-        self.crypto
-            .sha384_digest(preamble.owner_pub_keys.as_bytes())
+        self.pubkey_digest(
+            preamble.owner_pub_keys.as_bytes(),
+            preamble.owner_lms_hss_levels,
+            None,
+        )
     }
 
-    /// Calculate vendor public key(s) digest
+    /// Calculate vendor public key(s) digest, bound to the vendor LMS HSS
+    /// levels
     pub fn vendor_pubkey_digest(&self, preamble: &ImagePreamble) -> anyhow::Result<ImageDigest> {
-        self.crypto
-            .sha384_digest(preamble.vendor_pub_keys.as_bytes())
+        self.pubkey_digest(
+            preamble.vendor_pub_keys.as_bytes(),
+            preamble.vendor_lms_hss_levels,
+            None,
+        )
+    }
+
+    /// Calculate owner public key(s) digest, bound to the owner ML-DSA-87
+    /// public key of the PQC extension
+    pub fn owner_pubkey_digest_mldsa(
+        &self,
+        preamble: &ImagePreamble,
+        pqc_extension: &ImagePqcExtension,
+    ) -> anyhow::Result<ImageDigest> {
+        self.pubkey_digest(
+            preamble.owner_pub_keys.as_bytes(),
+            preamble.owner_lms_hss_levels,
+            Some(&pqc_extension.owner_mldsa_pub_key),
+        )
+    }
+
+    /// Calculate vendor public key(s) digest, bound to the vendor ML-DSA-87
+    /// public key of the PQC extension
+    pub fn vendor_pubkey_digest_mldsa(
+        &self,
+        preamble: &ImagePreamble,
+        pqc_extension: &ImagePqcExtension,
+    ) -> anyhow::Result<ImageDigest> {
+        self.pubkey_digest(
+            preamble.vendor_pub_keys.as_bytes(),
+            preamble.vendor_lms_hss_levels,
+            Some(&pqc_extension.vendor_mldsa_pub_key),
+        )
+    }
+
+    /// Calculate the digest of `pub_keys`, followed by the little-endian
+    /// `lms_hss_levels` if there is more than one HSS level and by the
+    /// big-endian digest of `mldsa_pub_key`, if any
+    fn pubkey_digest(
+        &self,
+        pub_keys: &[u8],
+        lms_hss_levels: u32,
+        mldsa_pub_key: Option<&[u8]>,
+    ) -> anyhow::Result<ImageDigest> {
+        let mut data = pub_keys.to_vec();
+        if lms_hss_levels > 1 {
+            data.extend_from_slice(&lms_hss_levels.to_le_bytes());
+        }
+        if let Some(mldsa_pub_key) = mldsa_pub_key {
+            for word in self.crypto.sha384_digest(mldsa_pub_key)? {
+                data.extend_from_slice(&word.to_be_bytes());
+            }
+        }
+        self.crypto.sha384_digest(&data)
     }
 
     /// Generate image
@@ -265,15 +733,67 @@ impl<Crypto: ImageGeneratorCrypto> ImageGenerator<Crypto> {
         Ok((entry, image.content().clone()))
     }
 
+    /// Generate component
+    fn gen_component(
+        &self,
+        component: &ImageGeneratorComponent,
+        offset: u32,
+    ) -> anyhow::Result<ImageComponent> {
+        if component.content.is_empty() || component.content.len() % 4 != 0 {
+            bail!(
+                "Component {:#x?} size must be a non-zero multiple of 4 bytes",
+                component.id
+            );
+        }
+        if component
+            .load_addr
+            .checked_add(component.content.len() as u32)
+            .is_none()
+        {
+            bail!(
+                "Component {:#x?} load address range overflows",
+                component.id
+            );
+        }
+
+        let entry_point = match component.r#type {
+            ImageTocEntryType::Executable => component.entry_point,
+            ImageTocEntryType::Data => 0,
+        };
+
+        let toc = ImageTocEntry {
+            id: component.id,
+            r#type: component.r#type.into(),
+            revision: component.revision,
+            version: component.version,
+            svn: component.svn,
+            reserved: 0,
+            load_addr: component.load_addr,
+            entry_point,
+            offset,
+            size: component.content.len() as u32,
+            digest: self.crypto.sha384_digest(&component.content)?,
+        };
+
+        Ok(ImageComponent {
+            toc,
+            content: component.content.clone(),
+        })
+    }
+
     /// Calculate TOC digest
     pub fn toc_digest(
         &self,
         fmc_toc: &ImageTocEntry,
         rt_toc: &ImageTocEntry,
+        component_tocs: &[ImageTocEntry],
     ) -> anyhow::Result<ImageDigest> {
         let mut toc_content: Vec<u8> = Vec::new();
         toc_content.extend_from_slice(fmc_toc.as_bytes());
         toc_content.extend_from_slice(rt_toc.as_bytes());
+        for component_toc in component_tocs {
+            toc_content.extend_from_slice(component_toc.as_bytes());
+        }
         self.crypto.sha384_digest(&toc_content)
     }
 }
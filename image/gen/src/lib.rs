@@ -61,6 +61,37 @@ pub trait ImageGeneratorCrypto {
         digest: &ImageDigest,
         priv_key: &ImageLmsPrivKey,
     ) -> anyhow::Result<ImageLmsSignature>;
+
+    /// Calculate ML-DSA-87 Signature
+    fn mldsa87_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature>;
+
+    /// Verify ECDSA Signature
+    fn ecdsa384_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool>;
+
+    /// Verify LMS Signature
+    fn lms_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool>;
+
+    /// Verify ML-DSA-87 Signature
+    fn mldsa87_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool>;
 }
 
 /// Image Generator Vendor Configuration
@@ -74,6 +105,15 @@ pub struct ImageGeneratorVendorConfig {
 
     pub priv_keys: Option<ImageVendorPrivKeys>,
 
+    /// Lower HSS levels below the selected LMS key; the image carries an
+    /// HSS extension when set
+    pub lms_hss: Option<ImageGeneratorHssConfig>,
+
+    /// ML-DSA-87 public key; the image carries a PQC extension when set
+    pub mldsa_pub_key: Option<ImageMldsaPubKey>,
+
+    pub mldsa_priv_key: Option<ImageMldsaPrivKey>,
+
     pub not_before: [u8; 15],
 
     pub not_after: [u8; 15],
@@ -88,6 +128,15 @@ pub struct ImageGeneratorOwnerConfig {
 
     pub priv_keys: Option<ImageOwnerPrivKeys>,
 
+    /// Lower HSS levels below the owner LMS key; the image carries an HSS
+    /// extension when set
+    pub lms_hss: Option<ImageGeneratorHssConfig>,
+
+    /// ML-DSA-87 public key; required when the vendor has one
+    pub mldsa_pub_key: Option<ImageMldsaPubKey>,
+
+    pub mldsa_priv_key: Option<ImageMldsaPrivKey>,
+
     pub not_before: [u8; 15],
 
     pub not_after: [u8; 15],
@@ -95,6 +144,40 @@ pub struct ImageGeneratorOwnerConfig {
     pub epoch: [u8; 2],
 }
 
+/// Image Generator HSS Configuration
+#[derive(Default, Clone)]
+pub struct ImageGeneratorHssConfig {
+    /// Public keys of the levels below the top level key, top to bottom.
+    /// Each key is signed once by its parent when it is provisioned (see
+    /// `ImageGenerator::gen_hss_signed_pub_key`) and copied into every image.
+    pub signed_pub_keys: Vec<ImageLmsSignedPubKey>,
+
+    /// LMS private key of the bottom level, which signs the image
+    pub priv_key: Option<ImageLmsPrivKey>,
+}
+
+/// Image Generator Component Configuration
+#[derive(Clone)]
+pub struct ImageGeneratorComponent {
+    /// Vendor defined component id; must not be an FMC or Runtime id
+    pub id: u32,
+
+    pub r#type: ImageTocEntryType,
+
+    pub revision: ImageRevision,
+
+    pub version: u32,
+
+    pub svn: u32,
+
+    pub load_addr: u32,
+
+    /// Entry point of an executable component; ignored for data
+    pub entry_point: u32,
+
+    pub content: Vec<u8>,
+}
+
 /// Image Generator Configuration
 #[derive(Default)]
 pub struct ImageGeneratorConfig<T>
@@ -108,4 +191,7 @@ where
     pub fmc: T,
 
     pub runtime: T,
+
+    /// Additional components, placed after the runtime in the image
+    pub components: Vec<ImageGeneratorComponent>,
 }
@@ -12,7 +12,11 @@ Abstract:
 
 --*/
 
+mod lms_state;
+mod mldsa87;
+
 use std::path::PathBuf;
+use std::sync::Mutex;
 
 use anyhow::{anyhow, Context};
 
@@ -28,8 +32,24 @@ use openssl::sha::{Sha256, Sha384};
 
 use zerocopy::{AsBytes, FromBytes};
 
+pub use lms_state::{lms_tree_height, LmsKeyState, LMS_STATE_RESERVE_COUNT};
+pub use mldsa87::mldsa87_pub_key;
+
 #[derive(Default)]
-pub struct OsslCrypto {}
+pub struct OsslCrypto {
+    lms_state: Option<Mutex<LmsKeyState>>,
+}
+
+impl OsslCrypto {
+    /// Create a signer that takes LMS leaf indices from `lms_state`, so that
+    /// no leaf is used twice. Every leaf of the tree is computed on each
+    /// signature, which takes a while for the 2^15 leaf trees.
+    pub fn with_lms_state(lms_state: LmsKeyState) -> Self {
+        Self {
+            lms_state: Some(Mutex::new(lms_state)),
+        }
+    }
+}
 
 const LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT: u8 = 10u8;
 const SUPPORTED_LMS_Q_VALUE: u32 = 5u32;
@@ -87,10 +107,11 @@ impl ImageGeneratorCrypto for OsslCrypto {
         Ok(image_sig)
     }
 
-    // This function is here as a convenience for creating test images.
-    // This function uses the same Q value for each signature which is
-    // insecure, a Q value must be used at most one time.  In practice
-    // the digest should be passed to a FIPS approved HSM for signature.
+    // Without an LMS state file, this function uses the same Q value for
+    // each signature, which is insecure: a Q value must be used at most one
+    // time. That mode is only a convenience for creating test images. In
+    // practice the digest should be passed to a FIPS approved HSM for
+    // signature.
     fn lms_sign(
         &self,
         digest: &ImageDigest,
@@ -99,7 +120,61 @@ impl ImageGeneratorCrypto for OsslCrypto {
         let message: [u8; ECC384_SCALAR_BYTE_SIZE] = from_hw_format(digest);
         let mut nonce = [0u8; SHA192_DIGEST_BYTE_SIZE];
         rand_bytes(&mut nonce).unwrap();
-        sign_with_lms_key(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE)
+        match &self.lms_state {
+            Some(lms_state) => {
+                let q = lms_state
+                    .lock()
+                    .map_err(|_| anyhow!("LMS state lock poisoned"))?
+                    .next_q(priv_key)?;
+                sign_with_lms_key(priv_key, &message, &nonce, q, true)
+            }
+            None => sign_with_lms_key(priv_key, &message, &nonce, SUPPORTED_LMS_Q_VALUE, false),
+        }
+    }
+
+    /// Calculate ML-DSA-87 Signature over the big-endian digest bytes
+    fn mldsa87_sign(
+        &self,
+        digest: &ImageDigest,
+        priv_key: &ImageMldsaPrivKey,
+    ) -> anyhow::Result<ImageMldsaSignature> {
+        let message: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+        let mut sig = [0u8; MLDSA87_SIGNATURE_BYTE_SIZE + 1];
+        sig[..MLDSA87_SIGNATURE_BYTE_SIZE]
+            .copy_from_slice(&mldsa87::mldsa87_sign(priv_key, &message)?);
+        Ok(sig)
+    }
+
+    /// Verify ECDSA-384 Signature
+    fn ecdsa384_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> anyhow::Result<bool> {
+        ecdsa384_verify(digest, pub_key, sig)
+    }
+
+    /// Verify LMS Signature
+    fn lms_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> anyhow::Result<bool> {
+        lms_verify(digest, pub_key, sig)
+    }
+
+    /// Verify ML-DSA-87 Signature
+    fn mldsa87_verify(
+        &self,
+        digest: &ImageDigest,
+        pub_key: &ImageMldsaPubKey,
+        sig: &ImageMldsaSignature,
+    ) -> anyhow::Result<bool> {
+        let message: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+        let sig = <&mldsa87::Mldsa87Signature>::try_from(&sig[..MLDSA87_SIGNATURE_BYTE_SIZE])?;
+        mldsa87::mldsa87_verify(pub_key, &message, sig)
     }
 }
 
@@ -156,6 +231,176 @@ pub fn lms_priv_key_from_pem(path: &PathBuf) -> anyhow::Result<ImageLmsPrivKey>
     ImageLmsPrivKey::read_from(&key_bytes[..]).ok_or(anyhow!("Error parsing LMS priv key"))
 }
 
+/// Read an HSS level's LMS public key and its parent's signature from a
+/// raw file
+pub fn lms_signed_pub_key_from_file(path: &PathBuf) -> anyhow::Result<ImageLmsSignedPubKey> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read signed public key file {}", path.display()))?;
+
+    ImageLmsSignedPubKey::read_from(&bytes[..])
+        .ok_or(anyhow!("Error parsing LMS signed public key"))
+}
+
+/// Read ML-DSA-87 public key from a raw key file
+pub fn mldsa_pub_key_from_file(path: &PathBuf) -> anyhow::Result<ImageMldsaPubKey> {
+    let key_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read public key file {}", path.display()))?;
+
+    ImageMldsaPubKey::try_from(&key_bytes[..])
+        .map_err(|_| anyhow!("Error parsing ML-DSA public key"))
+}
+
+/// Read ML-DSA-87 private key seed from a raw key file
+pub fn mldsa_priv_key_from_file(path: &PathBuf) -> anyhow::Result<ImageMldsaPrivKey> {
+    let key_bytes = std::fs::read(path)
+        .with_context(|| format!("Failed to read private key file {}", path.display()))?;
+
+    ImageMldsaPrivKey::try_from(&key_bytes[..])
+        .map_err(|_| anyhow!("Error parsing ML-DSA priv key"))
+}
+
+/// Verify an ECDSA-384 signature
+///
+/// # Arguments
+///
+/// * `digest` - Digest that was signed, in hardware format
+/// * `pub_key` - Public key to verify with
+/// * `sig` - Signature to verify
+///
+/// # Returns
+///
+/// * `bool` - Whether the signature is valid
+pub fn ecdsa384_verify(
+    digest: &ImageDigest,
+    pub_key: &ImageEccPubKey,
+    sig: &ImageEccSignature,
+) -> anyhow::Result<bool> {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+    let mut ctx = BigNumContext::new()?;
+
+    let pub_key_x = BigNum::from_slice(&from_hw_format(&pub_key.x))?;
+    let pub_key_y = BigNum::from_slice(&from_hw_format(&pub_key.y))?;
+    let mut point = EcPoint::new(&group)?;
+    point.set_affine_coordinates_gfp(&group, &pub_key_x, &pub_key_y, &mut ctx)?;
+    let ec_key = EcKey::from_public_key(&group, &point)?;
+
+    let r = BigNum::from_slice(&from_hw_format(&sig.r))?;
+    let s = BigNum::from_slice(&from_hw_format(&sig.s))?;
+    let sig = EcdsaSig::from_private_components(r, s)?;
+
+    let digest: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+    Ok(sig.verify(&digest, &ec_key)?)
+}
+
+/// Verify an LMS signature
+///
+/// # Arguments
+///
+/// * `digest` - Digest that was signed, in hardware format
+/// * `pub_key` - Public key to verify with
+/// * `sig` - Signature to verify
+///
+/// # Returns
+///
+/// * `bool` - Whether the signature is valid
+pub fn lms_verify(
+    digest: &ImageDigest,
+    pub_key: &ImageLmsPublicKey,
+    sig: &ImageLmsSignature,
+) -> anyhow::Result<bool> {
+    if sig.tree_type != pub_key.tree_type || sig.ots.ots_type != pub_key.otstype {
+        return Ok(false);
+    }
+    let height = match pub_key.tree_type {
+        IMAGE_LMS_TREE_TYPE => 15,
+        IMAGE_LMS_TREE_TYPE_HT_5 => 5,
+        _ => return Ok(false),
+    };
+    let (alg_p, width, ls) = match pub_key.otstype {
+        IMAGE_LMS_OTS_TYPE => (51usize, 4usize, 4u8),
+        IMAGE_LMS_OTS_TYPE_8 => (26usize, 8usize, 0u8),
+        _ => return Ok(false),
+    };
+    let q = u32::from(sig.q);
+    if q >= (1 << height) {
+        return Ok(false);
+    }
+    let id = &pub_key.id;
+    let message: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(digest);
+
+    // Compute the LM-OTS public key candidate (RFC 8554 section 4.6)
+    let mut hasher = Sha256::new();
+    hasher.update(id);
+    hasher.update(&q.to_be_bytes());
+    hasher.update(&D_MESG.to_be_bytes());
+    hasher.update(sig.ots.nonce.as_bytes());
+    hasher.update(&message);
+    let q_arr = hasher.finish();
+    let q_arr = &q_arr[..SHA192_DIGEST_BYTE_SIZE];
+
+    let mut checksum: u16 = 0;
+    let data_coeff: usize = (SHA192_DIGEST_BYTE_SIZE * 8) / width;
+    let alg_chksum_max: u16 = (1 << width) - 1;
+    for i in 0..data_coeff {
+        checksum += alg_chksum_max - (coefficient(q_arr, i, width)? as u16);
+    }
+    checksum <<= ls;
+    let checksum_str: [u8; 2] = checksum.to_be_bytes();
+
+    let mut hasher_pblc = Sha256::new();
+    hasher_pblc.update(id);
+    hasher_pblc.update(&q.to_be_bytes());
+    hasher_pblc.update(&D_PBLC.to_be_bytes());
+    for i in 0..alg_p {
+        let a: u8 = if i < data_coeff {
+            coefficient(q_arr, i, width)?
+        } else {
+            coefficient(&checksum_str, i - data_coeff, width)?
+        };
+        let mut tmp = [0u8; SHA192_DIGEST_BYTE_SIZE];
+        tmp.copy_from_slice(sig.ots.y[i].as_bytes());
+        let i_str: [u8; 2] = (i as u16).to_be_bytes();
+        for j in a..alg_chksum_max as u8 {
+            let mut hasher = Sha256::new();
+            hasher.update(id);
+            hasher.update(&q.to_be_bytes());
+            hasher.update(&i_str);
+            hasher.update(&[j]);
+            hasher.update(&tmp);
+            tmp.copy_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+        }
+        hasher_pblc.update(&tmp);
+    }
+    let k = hasher_pblc.finish();
+
+    // Compute the LMS root candidate (RFC 8554 section 5.4.2)
+    let mut node: u32 = (1 << height) + q;
+    let mut hasher = Sha256::new();
+    hasher.update(id);
+    hasher.update(&node.to_be_bytes());
+    hasher.update(&D_LEAF.to_be_bytes());
+    hasher.update(&k[..SHA192_DIGEST_BYTE_SIZE]);
+    let mut tmp = [0u8; SHA192_DIGEST_BYTE_SIZE];
+    tmp.copy_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+    for path in sig.tree_path[..height].iter() {
+        let mut hasher = Sha256::new();
+        hasher.update(id);
+        hasher.update(&(node / 2).to_be_bytes());
+        hasher.update(&D_INTR.to_be_bytes());
+        if node % 2 == 1 {
+            hasher.update(path.as_bytes());
+            hasher.update(&tmp);
+        } else {
+            hasher.update(&tmp);
+            hasher.update(path.as_bytes());
+        }
+        tmp.copy_from_slice(&hasher.finish()[..SHA192_DIGEST_BYTE_SIZE]);
+        node /= 2;
+    }
+
+    Ok(tmp == pub_key.digest.as_bytes())
+}
+
 /// Convert the slice to hardware format
 fn to_hw_format(value: &[u8]) -> [u32; ECC384_SCALAR_WORD_SIZE] {
     let arr = TryInto::<[u8; ECC384_SCALAR_BYTE_SIZE]>::try_into(value).unwrap();
@@ -273,6 +518,7 @@ fn generate_lms_pubkey_helper(
     tree_height: u8,
     seed: &[u8],
     q: Option<u32>,
+    full_tree: bool,
     pub_key: &mut Option<ImageLmsPublicKey>,
     sig: &mut Option<ImageLmsSignature>,
 ) {
@@ -293,8 +539,12 @@ fn generate_lms_pubkey_helper(
         _ => (51usize, 4u8),
     };
     for i in 0..max_idx {
-        // TODO: We only support a fixed Q in larger trees
-        if tree_height <= LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT || i == SUPPORTED_LMS_Q_VALUE {
+        // TODO: We only support a fixed Q in larger trees, unless the full
+        // tree is requested
+        if tree_height <= LMS_TREE_GEN_SUPPORTED_FULL_HEIGHT
+            || full_tree
+            || i == SUPPORTED_LMS_Q_VALUE
+        {
             generate_lmots_pubkey_helper(id, i, p, w, seed, &mut k[..]);
         } else {
             k[..].copy_from_slice(&zero_k[..]);
@@ -435,8 +685,29 @@ fn generate_ots_signature_helper(
     sig
 }
 
-#[allow(unused)]
-fn generate_lms_pubkey(priv_key: &ImageLmsPrivKey) -> anyhow::Result<ImageLmsPublicKey> {
+/// Generate a new LMS key pair. Caliptra images use `IMAGE_LMS_TREE_TYPE`
+/// and `IMAGE_LMS_OTS_TYPE`; the smaller H5 tree is only for tests. Every
+/// leaf of the tree is computed, so that any leaf can be used for signing
+/// with an `LmsKeyState`.
+pub fn generate_lms_key_pair(
+    tree_type: LmsAlgorithmType,
+    otstype: LmotsAlgorithmType,
+) -> anyhow::Result<(ImageLmsPrivKey, ImageLmsPublicKey)> {
+    let mut priv_key = ImageLmsPrivKey {
+        tree_type,
+        otstype,
+        ..Default::default()
+    };
+    rand_bytes(&mut priv_key.id)?;
+    rand_bytes(priv_key.seed.as_bytes_mut())?;
+    let pub_key = generate_lms_pubkey(&priv_key, true)?;
+    Ok((priv_key, pub_key))
+}
+
+fn generate_lms_pubkey(
+    priv_key: &ImageLmsPrivKey,
+    full_tree: bool,
+) -> anyhow::Result<ImageLmsPublicKey> {
     match priv_key.tree_type {
         IMAGE_LMS_TREE_TYPE => {}
         IMAGE_LMS_TREE_TYPE_HT_5 => {}
@@ -464,6 +735,7 @@ fn generate_lms_pubkey(priv_key: &ImageLmsPrivKey) -> anyhow::Result<ImageLmsPub
         height,
         priv_key.seed.as_bytes(),
         None,
+        full_tree,
         &mut pub_key,
         &mut None,
     );
@@ -475,6 +747,7 @@ fn sign_with_lms_key(
     message: &[u8],
     nonce: &[u8],
     q: u32,
+    full_tree: bool,
 ) -> anyhow::Result<ImageLmsSignature> {
     match priv_key.tree_type {
         IMAGE_LMS_TREE_TYPE => {}
@@ -515,6 +788,7 @@ fn sign_with_lms_key(
         height,
         priv_key.seed.as_bytes(),
         Some(q),
+        full_tree,
         &mut None,
         &mut sig,
     );
@@ -538,14 +812,14 @@ fn test_print_lms_private_pub_key() {
     for i in 0..4 {
         rand_bytes(&mut priv_key.id).unwrap();
         rand_bytes(priv_key.seed.as_bytes_mut()).unwrap();
-        let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+        let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
         println!("pub const VENDOR_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
         println!("pub const VENDOR_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
     }
     for i in 0..1 {
         rand_bytes(&mut priv_key.id).unwrap();
         rand_bytes(priv_key.seed.as_bytes_mut()).unwrap();
-        let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+        let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
         println!("pub const OWNER_LMS_KEY{i}_PRIVATE: ImageLmsPrivKey = {priv_key:#04x?};");
         println!("pub const OWNER_LMS_KEY{i}_PUBLIC: ImageLmsPublicKey = {pub_key:#04x?};");
     }
@@ -574,7 +848,7 @@ fn test_lms() {
             0x88, 0x27, 0x96, 0x61, 0x83, 0x14, 0x50, 0x8b, 0x12, 0xd2,
         ]),
     };
-    let pub_key = generate_lms_pubkey(&priv_key).unwrap();
+    let pub_key = generate_lms_pubkey(&priv_key, false).unwrap();
     assert_eq!(expected_pub_key, pub_key);
 }
 
@@ -763,7 +1037,7 @@ fn test_lms_sig() {
         Default::default(),
         Default::default(),
     ];
-    let sig = sign_with_lms_key(&priv_key, &message, &nonce, 5).unwrap();
+    let sig = sign_with_lms_key(&priv_key, &message, &nonce, 5, false).unwrap();
 
     assert_eq!(
         sig,
@@ -1070,7 +1344,7 @@ fn test_lms_sig_h15() {
             0xa4, 0x9a, 0x35, 0x8f, 0xb5, 0x13, 0x71, 0x64, 0xa9, 0x5d,
         ]),
     ];
-    let sig = sign_with_lms_key(&priv_key, &message, nonce.as_bytes(), 5).unwrap();
+    let sig = sign_with_lms_key(&priv_key, &message, nonce.as_bytes(), 5, false).unwrap();
 
     assert_eq!(
         sig,
@@ -1086,3 +1360,63 @@ fn test_lms_sig_h15() {
         }
     );
 }
+
+#[test]
+fn test_ecdsa384_verify() {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let key = EcKey::generate(&group).unwrap();
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut x = BigNum::new().unwrap();
+    let mut y = BigNum::new().unwrap();
+    key.public_key()
+        .affine_coordinates_gfp(&group, &mut x, &mut y, &mut ctx)
+        .unwrap();
+    let pub_key = ImageEccPubKey {
+        x: to_hw_format(&x.to_vec_padded(ECC384_SCALAR_BYTE_SIZE as i32).unwrap()),
+        y: to_hw_format(&y.to_vec_padded(ECC384_SCALAR_BYTE_SIZE as i32).unwrap()),
+    };
+    let priv_key = to_hw_format(
+        &key.private_key()
+            .to_vec_padded(ECC384_SCALAR_BYTE_SIZE as i32)
+            .unwrap(),
+    );
+
+    let crypto = OsslCrypto::default();
+    let mut digest = crypto.sha384_digest(b"caliptra").unwrap();
+    let sig = crypto.ecdsa384_sign(&digest, &priv_key, &pub_key).unwrap();
+    assert!(ecdsa384_verify(&digest, &pub_key, &sig).unwrap());
+
+    digest[0] ^= 1;
+    assert!(!ecdsa384_verify(&digest, &pub_key, &sig).unwrap());
+}
+
+#[test]
+fn test_lms_verify() {
+    for otstype in [IMAGE_LMS_OTS_TYPE_8, IMAGE_LMS_OTS_TYPE] {
+        let mut priv_key = ImageLmsPrivKey {
+            tree_type: IMAGE_LMS_TREE_TYPE_HT_5,
+            otstype,
+            ..Default::default()
+        };
+        rand_bytes(&mut priv_key.id).unwrap();
+        rand_bytes(priv_key.seed.as_bytes_mut()).unwrap();
+        let pub_key = generate_lms_pubkey(&priv_key, true).unwrap();
+
+        let crypto = OsslCrypto::default();
+        let mut digest = crypto.sha384_digest(b"caliptra").unwrap();
+        let message: [u8; SHA384_DIGEST_BYTE_SIZE] = from_hw_format(&digest);
+        let mut sig = sign_with_lms_key(&priv_key, &message, &[0x5a; 24], 7, true).unwrap();
+        assert!(lms_verify(&digest, &pub_key, &sig).unwrap());
+
+        sig.tree_path[1][0] = (u32::from(sig.tree_path[1][0]) ^ 1).into();
+        assert!(!lms_verify(&digest, &pub_key, &sig).unwrap());
+        sig.tree_path[1][0] = (u32::from(sig.tree_path[1][0]) ^ 1).into();
+
+        sig.q = 8.into();
+        assert!(!lms_verify(&digest, &pub_key, &sig).unwrap());
+        sig.q = 7.into();
+
+        digest[0] ^= 1;
+        assert!(!lms_verify(&digest, &pub_key, &sig).unwrap());
+    }
+}
@@ -0,0 +1,339 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   lms_state.rs
+
+Abstract:
+
+    File contains the persistent record of LMS leaf indices used for signing.
+
+--*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::ops::Range;
+use std::os::fd::AsRawFd;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+use caliptra_image_types::{ImageLmsPrivKey, IMAGE_LMS_TREE_TYPE};
+use nix::fcntl::FlockArg;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::IMAGE_LMS_TREE_TYPE_HT_5;
+
+/// Number of leaf indices reserved each time the state file is written
+pub const LMS_STATE_RESERVE_COUNT: u32 = 16;
+
+/// A warning is printed once fewer than 1/LMS_STATE_WARN_DIVISOR of the
+/// leaves of a tree remain unused
+pub const LMS_STATE_WARN_DIVISOR: u32 = 10;
+
+/// Per-key entry of the state file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct LmsKeyEntry {
+    /// Height of the tree
+    tree_height: u32,
+
+    /// First leaf index that has not been handed out. Every index below it
+    /// must be considered used.
+    next_q: u32,
+}
+
+/// Contents of the state file, keyed by the hex-encoded LMS key identifier
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct LmsStateFile {
+    keys: BTreeMap<String, LmsKeyEntry>,
+}
+
+/// Tracks the LMS leaf indices (q values) used by each signing key.
+///
+/// Leaves are reserved in blocks of `LMS_STATE_RESERVE_COUNT`: the state file
+/// is updated before any leaf of a block is used, so a crash can only skip
+/// leaves, never hand one out twice. The file is replaced atomically on each
+/// update. Each reservation re-reads the file while holding an exclusive lock
+/// on `<path>.lock`, so concurrent signers may share a state file.
+pub struct LmsKeyState {
+    path: PathBuf,
+    file: LmsStateFile,
+    reserved: HashMap<String, Range<u32>>,
+}
+
+impl LmsKeyState {
+    /// Open the state file at `path`, starting empty if it does not exist
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: Self::read(path)?,
+            reserved: HashMap::new(),
+        })
+    }
+
+    /// Read the state file at `path`, or an empty state if it does not exist
+    fn read(path: &Path) -> anyhow::Result<LmsStateFile> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => toml::from_str(&s)
+                .with_context(|| format!("Failed to parse LMS state file {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(LmsStateFile::default()),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to read LMS state file {}", path.display()))
+            }
+        }
+    }
+
+    /// Number of leaves of `priv_key` that have not been reserved yet
+    pub fn remaining(&self, priv_key: &ImageLmsPrivKey) -> anyhow::Result<u32> {
+        let leaf_count = 1 << lms_tree_height(priv_key)?;
+        let next_q = self
+            .file
+            .keys
+            .get(&hex::encode(priv_key.id))
+            .map_or(0, |entry| entry.next_q);
+        Ok(leaf_count - next_q.min(leaf_count))
+    }
+
+    /// Hand out the next unused leaf index of `priv_key`. Fails if every
+    /// leaf of the tree has been used.
+    pub fn next_q(&mut self, priv_key: &ImageLmsPrivKey) -> anyhow::Result<u32> {
+        let height = lms_tree_height(priv_key)?;
+        let leaf_count = 1u32 << height;
+        let key_id = hex::encode(priv_key.id);
+
+        let reserved = self.reserved.entry(key_id.clone()).or_insert(0..0);
+        let q = if let Some(q) = reserved.next() {
+            q
+        } else {
+            let mut block = self.reserve_block(&key_id, height)?;
+            let q = block.next().unwrap();
+            self.reserved.insert(key_id.clone(), block);
+            q
+        };
+
+        let remaining = leaf_count - q - 1;
+        if remaining < leaf_count / LMS_STATE_WARN_DIVISOR {
+            eprintln!("warning: LMS key {key_id} has {remaining} of {leaf_count} leaves left");
+        }
+        Ok(q)
+    }
+
+    /// Reserve the next block of leaves of key `key_id` in the state file.
+    /// The file is re-read under an exclusive lock, so leaves reserved by
+    /// other signers sharing the file since it was opened are not reused.
+    fn reserve_block(&mut self, key_id: &str, height: u32) -> anyhow::Result<Range<u32>> {
+        let leaf_count = 1u32 << height;
+        let _lock = self.lock()?;
+        self.file = Self::read(&self.path)?;
+
+        let entry = self
+            .file
+            .keys
+            .entry(key_id.to_string())
+            .or_insert(LmsKeyEntry {
+                tree_height: height,
+                next_q: 0,
+            });
+        if entry.tree_height != height {
+            bail!(
+                "LMS key {key_id} has tree height {} in {}, expected {height}",
+                entry.tree_height,
+                self.path.display()
+            );
+        }
+        if entry.next_q >= leaf_count {
+            bail!("LMS key {key_id} is exhausted: all {leaf_count} leaves have been used");
+        }
+        let block = entry.next_q..(entry.next_q + LMS_STATE_RESERVE_COUNT).min(leaf_count);
+        entry.next_q = block.end;
+        self.save()?;
+        Ok(block)
+    }
+
+    /// Take an exclusive lock on `<path>.lock`, held until the returned file
+    /// is dropped. The state file itself is replaced on each update, so it
+    /// cannot carry the lock.
+    fn lock(&self) -> anyhow::Result<File> {
+        let mut lock_path = self.path.clone().into_os_string();
+        lock_path.push(".lock");
+        let lock_path = PathBuf::from(lock_path);
+
+        let lock = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&lock_path)
+            .with_context(|| format!("Failed to create {}", lock_path.display()))?;
+        nix::fcntl::flock(lock.as_raw_fd(), FlockArg::LockExclusive)
+            .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+        Ok(lock)
+    }
+
+    /// Atomically replace the state file with the current state
+    fn save(&self) -> anyhow::Result<()> {
+        let contents = toml::to_string(&self.file)?;
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let mut tmp = OpenOptions::new()
+            .create(true)
+            .truncate(true)
+            .write(true)
+            .open(&tmp_path)
+            .with_context(|| format!("Failed to create {}", tmp_path.display()))?;
+        tmp.write_all(contents.as_bytes())?;
+        tmp.sync_all()?;
+        drop(tmp);
+
+        std::fs::rename(&tmp_path, &self.path)
+            .with_context(|| format!("Failed to update LMS state file {}", self.path.display()))?;
+
+        // Persist the rename itself
+        if let Some(dir) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            if let Ok(dir) = File::open(dir) {
+                let _ = dir.sync_all();
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Height of the tree of `priv_key`
+pub fn lms_tree_height(priv_key: &ImageLmsPrivKey) -> anyhow::Result<u32> {
+    match priv_key.tree_type {
+        IMAGE_LMS_TREE_TYPE => Ok(15),
+        IMAGE_LMS_TREE_TYPE_HT_5 => Ok(5),
+        _ => Err(anyhow!("Error parsing lms parameters")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IMAGE_LMS_OTS_TYPE_8;
+
+    fn state_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "caliptra-lms-state-{}-{name}.toml",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn priv_key(id: u8) -> ImageLmsPrivKey {
+        ImageLmsPrivKey {
+            tree_type: IMAGE_LMS_TREE_TYPE_HT_5,
+            otstype: IMAGE_LMS_OTS_TYPE_8,
+            id: [id; 16],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_next_q_sequential() {
+        let path = state_path("sequential");
+        let mut state = LmsKeyState::open(&path).unwrap();
+        let (key0, key1) = (priv_key(0), priv_key(1));
+        assert_eq!(state.next_q(&key0).unwrap(), 0);
+        assert_eq!(state.next_q(&key0).unwrap(), 1);
+        assert_eq!(state.next_q(&key1).unwrap(), 0);
+        assert_eq!(state.next_q(&key0).unwrap(), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reserved_leaves_are_skipped_after_reopen() {
+        let path = state_path("reopen");
+        let key = priv_key(0);
+        {
+            let mut state = LmsKeyState::open(&path).unwrap();
+            assert_eq!(state.next_q(&key).unwrap(), 0);
+        }
+        // The rest of the first block may have been used before a crash
+        let mut state = LmsKeyState::open(&path).unwrap();
+        assert_eq!(state.next_q(&key).unwrap(), LMS_STATE_RESERVE_COUNT);
+        assert_eq!(
+            state.remaining(&key).unwrap(),
+            32 - 2 * LMS_STATE_RESERVE_COUNT
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_shared_state_file() {
+        let path = state_path("shared");
+        let key = priv_key(0);
+        // Both signers open the file before either reserves a block
+        let mut state1 = LmsKeyState::open(&path).unwrap();
+        let mut state2 = LmsKeyState::open(&path).unwrap();
+        assert_eq!(state1.next_q(&key).unwrap(), 0);
+        assert_eq!(state2.next_q(&key).unwrap(), LMS_STATE_RESERVE_COUNT);
+        assert_eq!(state1.next_q(&key).unwrap(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_signers_never_share_a_leaf() {
+        let path = state_path("concurrent");
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let key = ImageLmsPrivKey {
+                        tree_type: IMAGE_LMS_TREE_TYPE,
+                        ..priv_key(0)
+                    };
+                    // Each signer reserves a new block
+                    (0..4)
+                        .map(|_| LmsKeyState::open(&path).unwrap().next_q(&key).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let mut qs: Vec<u32> = threads
+            .into_iter()
+            .flat_map(|t| t.join().unwrap())
+            .collect();
+        let count = qs.len();
+        qs.sort();
+        qs.dedup();
+        assert_eq!(qs.len(), count);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_exhausted_key_is_refused() {
+        let path = state_path("exhausted");
+        let key = priv_key(0);
+        let mut state = LmsKeyState::open(&path).unwrap();
+        for i in 0..32 {
+            assert_eq!(state.next_q(&key).unwrap(), i);
+        }
+        assert!(state.next_q(&key).is_err());
+
+        let mut state = LmsKeyState::open(&path).unwrap();
+        assert_eq!(state.remaining(&key).unwrap(), 0);
+        assert!(state.next_q(&key).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_tree_height_mismatch() {
+        let path = state_path("height");
+        let key = priv_key(0);
+        std::fs::write(
+            &path,
+            format!(
+                "[keys.{}]\ntree_height = 15\nnext_q = 0\n",
+                hex::encode(key.id)
+            ),
+        )
+        .unwrap();
+        let mut state = LmsKeyState::open(&path).unwrap();
+        assert!(state.next_q(&key).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
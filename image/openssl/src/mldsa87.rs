@@ -0,0 +1,152 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+   mldsa87.rs
+
+Abstract:
+
+    File contains ML-DSA-87 key generation, signing and verification as
+    specified in FIPS 204, backed by the fips204 crate. The private key is
+    the 32 byte key generation seed; signatures use the deterministic
+    variant with an empty context.
+
+--*/
+
+use anyhow::anyhow;
+use caliptra_image_types::{ImageMldsaPrivKey, ImageMldsaPubKey, MLDSA87_SIGNATURE_BYTE_SIZE};
+use fips204::ml_dsa_87;
+use fips204::traits::{KeyGen, SerDes, Signer, Verifier};
+
+/// ML-DSA-87 signature
+pub type Mldsa87Signature = [u8; MLDSA87_SIGNATURE_BYTE_SIZE];
+
+/// Derive the ML-DSA-87 public key from the key generation seed
+pub fn mldsa87_pub_key(priv_key: &ImageMldsaPrivKey) -> anyhow::Result<ImageMldsaPubKey> {
+    let (pub_key, _) = ml_dsa_87::KG::keygen_from_seed(priv_key);
+    Ok(pub_key.into_bytes())
+}
+
+/// Sign `msg` with the deterministic variant of ML-DSA-87 (FIPS 204
+/// Algorithm 2) and an empty context
+pub fn mldsa87_sign(priv_key: &ImageMldsaPrivKey, msg: &[u8]) -> anyhow::Result<Mldsa87Signature> {
+    let (_, priv_key) = ml_dsa_87::KG::keygen_from_seed(priv_key);
+    priv_key
+        .try_sign_with_seed(&[0u8; 32], msg, &[])
+        .map_err(|e| anyhow!("ML-DSA-87 signing failed: {e}"))
+}
+
+/// Verify an ML-DSA-87 signature (FIPS 204 Algorithm 3) with an empty context
+pub fn mldsa87_verify(
+    pub_key: &ImageMldsaPubKey,
+    msg: &[u8],
+    sig: &Mldsa87Signature,
+) -> anyhow::Result<bool> {
+    let pub_key = ml_dsa_87::PublicKey::try_from_bytes(*pub_key)
+        .map_err(|e| anyhow!("Invalid ML-DSA-87 public key: {e}"))?;
+    Ok(pub_key.verify(msg, sig, &[]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_derive::Deserialize;
+
+    /// Known answer tests in the layout of the ACVP ML-DSA test files
+    #[derive(Deserialize)]
+    struct Kat {
+        key_gen: Vec<KeyGenCase>,
+        sig_gen: Vec<SigGenCase>,
+        sig_ver: Vec<SigVerGroup>,
+    }
+
+    #[derive(Deserialize)]
+    struct KeyGenCase {
+        tc_id: u32,
+        seed: String,
+        pk: String,
+        sk: String,
+    }
+
+    #[derive(Deserialize)]
+    struct SigGenCase {
+        tc_id: u32,
+        seed: String,
+        message: String,
+        signature: String,
+    }
+
+    #[derive(Deserialize)]
+    struct SigVerGroup {
+        pk: String,
+        tests: Vec<SigVerCase>,
+    }
+
+    #[derive(Deserialize)]
+    struct SigVerCase {
+        tc_id: u32,
+        reason: String,
+        message: String,
+        signature: String,
+        test_passed: bool,
+    }
+
+    fn kat() -> Kat {
+        toml::from_str(include_str!("../testdata/mldsa87_kat.toml")).unwrap()
+    }
+
+    fn decode<const N: usize>(s: &str) -> [u8; N] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_key_gen() {
+        for case in kat().key_gen {
+            let seed = decode(&case.seed);
+            assert_eq!(
+                mldsa87_pub_key(&seed).unwrap(),
+                decode(&case.pk),
+                "tcId {}",
+                case.tc_id
+            );
+
+            let (_, priv_key) = ml_dsa_87::KG::keygen_from_seed(&seed);
+            assert_eq!(
+                priv_key.into_bytes(),
+                decode(&case.sk),
+                "tcId {}",
+                case.tc_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_sig_gen() {
+        for case in kat().sig_gen {
+            let seed = decode(&case.seed);
+            let message = hex::decode(&case.message).unwrap();
+            let sig = mldsa87_sign(&seed, &message).unwrap();
+            assert_eq!(sig, decode(&case.signature), "tcId {}", case.tc_id);
+            assert!(mldsa87_verify(&mldsa87_pub_key(&seed).unwrap(), &message, &sig).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_sig_ver() {
+        for group in kat().sig_ver {
+            let pub_key = decode(&group.pk);
+            for case in group.tests {
+                let message = hex::decode(&case.message).unwrap();
+                assert_eq!(
+                    mldsa87_verify(&pub_key, &message, &decode(&case.signature)).unwrap(),
+                    case.test_passed,
+                    "tcId {}: {}",
+                    case.tc_id,
+                    case.reason
+                );
+            }
+        }
+    }
+}
@@ -29,8 +29,20 @@ impl<W: Write> ImageBundleWriter<W> {
     /// Write Image Bundle
     pub fn write(&mut self, image: &ImageBundle) -> anyhow::Result<()> {
         self.writer.write_all(image.manifest.as_bytes())?;
+        for component in &image.components {
+            self.writer.write_all(component.toc.as_bytes())?;
+        }
         self.writer.write_all(&image.fmc)?;
         self.writer.write_all(&image.runtime)?;
+        for component in &image.components {
+            self.writer.write_all(&component.content)?;
+        }
+        if let Some(hss_extension) = &image.hss_extension {
+            self.writer.write_all(hss_extension.as_bytes())?;
+        }
+        if let Some(pqc_extension) = &image.pqc_extension {
+            self.writer.write_all(pqc_extension.as_bytes())?;
+        }
         Ok(())
     }
 }
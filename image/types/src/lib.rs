@@ -23,12 +23,14 @@ use caliptra_lms_types::{
     LmotsAlgorithmType, LmotsSignature, LmsAlgorithmType, LmsPrivateKey, LmsPublicKey, LmsSignature,
 };
 use memoffset::{offset_of, span_of};
-use zerocopy::{AsBytes, FromBytes};
+use zerocopy::{AsBytes, BigEndian, FromBytes, U32};
 
 pub const MANIFEST_MARKER: u32 = 0x4E414D43;
 pub const VENDOR_ECC_KEY_COUNT: u32 = 4;
 pub const VENDOR_LMS_KEY_COUNT: u32 = 32;
-pub const MAX_TOC_ENTRY_COUNT: u32 = 2;
+pub const FW_TOC_ENTRY_COUNT: u32 = 2;
+pub const MAX_COMPONENT_COUNT: u32 = 4;
+pub const MAX_TOC_ENTRY_COUNT: u32 = FW_TOC_ENTRY_COUNT + MAX_COMPONENT_COUNT;
 pub const IMAGE_REVISION_BYTE_SIZE: usize = 20;
 pub const ECC384_SCALAR_WORD_SIZE: usize = 12;
 pub const ECC384_SCALAR_BYTE_SIZE: usize = 48;
@@ -44,6 +46,12 @@ pub const IMAGE_LMS_TREE_TYPE: LmsAlgorithmType = LmsAlgorithmType::LmsSha256N24
 // LMOTS-SHA192-W4
 pub const IMAGE_LMS_OTS_TYPE: LmotsAlgorithmType = LmotsAlgorithmType::LmotsSha256N24W4;
 pub const IMAGE_MANIFEST_BYTE_SIZE: usize = core::mem::size_of::<ImageManifest>();
+pub const PQC_EXTENSION_MARKER: u32 = 0x45435150;
+pub const HSS_EXTENSION_MARKER: u32 = 0x45535348;
+pub const LMS_HSS_MAX_LEVELS: u32 = 3;
+pub const MLDSA87_PRIV_KEY_SEED_BYTE_SIZE: usize = 32;
+pub const MLDSA87_PUB_KEY_BYTE_SIZE: usize = 2592;
+pub const MLDSA87_SIGNATURE_BYTE_SIZE: usize = 4627;
 
 pub type ImageScalar = [u32; ECC384_SCALAR_WORD_SIZE];
 pub type ImageDigest = [u32; SHA384_DIGEST_WORD_SIZE];
@@ -79,6 +87,24 @@ pub type ImageLmsSignature =
     LmsSignature<SHA192_DIGEST_WORD_SIZE, IMAGE_LMS_OTS_P_PARAM, IMAGE_LMS_KEY_HEIGHT>;
 pub type ImageLmOTSSignature = LmotsSignature<SHA192_DIGEST_WORD_SIZE, IMAGE_LMS_OTS_P_PARAM>;
 
+// The serialized LMS public key signed by its parent in an HSS chain is
+// exactly the size of an image digest.
+const _: () = assert!(size_of::<ImageLmsPublicKey>() == SHA384_DIGEST_BYTE_SIZE);
+
+/// Returns the message signed by the parent of `pub_key` in an HSS chain:
+/// the serialized public key, as a digest with big-endian words.
+pub fn lms_hss_message(pub_key: &ImageLmsPublicKey) -> ImageDigest {
+    <[U32<BigEndian>; SHA384_DIGEST_WORD_SIZE]>::read_from(pub_key.as_bytes())
+        .unwrap_or_default()
+        .map(|word| word.get())
+}
+
+pub type ImageMldsaPubKey = [u8; MLDSA87_PUB_KEY_BYTE_SIZE];
+/// ML-DSA-87 private key, stored as the 32-byte key generation seed
+pub type ImageMldsaPrivKey = [u8; MLDSA87_PRIV_KEY_SEED_BYTE_SIZE];
+/// ML-DSA-87 signature, padded by one byte to keep the extension word aligned
+pub type ImageMldsaSignature = [u8; MLDSA87_SIGNATURE_BYTE_SIZE + 1];
+
 /// Caliptra Image Bundle
 #[cfg(feature = "std")]
 #[derive(Debug, Default)]
@@ -91,6 +117,26 @@ pub struct ImageBundle {
 
     /// Runtime
     pub runtime: Vec<u8>,
+
+    /// Additional components, in TOC order
+    pub components: Vec<ImageComponent>,
+
+    /// ML-DSA-87 keys and signatures, appended after the last component
+    pub pqc_extension: Option<ImagePqcExtension>,
+
+    /// HSS signed public keys, appended after the PQC extension
+    pub hss_extension: Option<ImageHssExtension>,
+}
+
+/// Caliptra Image Component
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone)]
+pub struct ImageComponent {
+    /// TOC Entry
+    pub toc: ImageTocEntry,
+
+    /// Contents
+    pub content: Vec<u8>,
 }
 
 #[cfg(feature = "std")]
@@ -99,6 +145,9 @@ impl ImageBundle {
         use std::io::ErrorKind;
         let mut result = vec![];
         result.extend_from_slice(self.manifest.as_bytes());
+        for component in &self.components {
+            result.extend_from_slice(component.toc.as_bytes());
+        }
         if self.manifest.fmc.offset as usize != result.len() {
             return Err(std::io::Error::new(
                 ErrorKind::Other,
@@ -125,8 +174,94 @@ impl ImageBundle {
             ));
         }
         result.extend_from_slice(&self.runtime);
+        for component in &self.components {
+            if component.toc.offset as usize != result.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual component offset does not match TOC entry",
+                ));
+            }
+            if component.toc.size as usize != component.content.len() {
+                return Err(std::io::Error::new(
+                    ErrorKind::Other,
+                    "actual component size does not match TOC entry",
+                ));
+            }
+            result.extend_from_slice(&component.content);
+        }
+        if let Some(pqc_extension) = &self.pqc_extension {
+            result.extend_from_slice(pqc_extension.as_bytes());
+        }
+        if let Some(hss_extension) = &self.hss_extension {
+            result.extend_from_slice(hss_extension.as_bytes());
+        }
         Ok(result)
     }
+
+    /// Parse an image bundle; the inverse of `to_bytes`. Only the layout is
+    /// checked, not the digests or signatures.
+    pub fn from_bytes(bytes: &[u8]) -> std::io::Result<Self> {
+        use std::io::ErrorKind;
+        let err = |msg: &str| std::io::Error::new(ErrorKind::Other, msg);
+        let section = |offset: u32, size: u32| {
+            bytes
+                .get(offset as usize..)
+                .and_then(|b| b.get(..size as usize))
+                .map(|b| b.to_vec())
+        };
+
+        let manifest = ImageManifest::read_from_prefix(bytes)
+            .ok_or_else(|| err("image is smaller than the manifest"))?;
+        if manifest.marker != MANIFEST_MARKER {
+            return Err(err("invalid manifest marker"));
+        }
+        let count = manifest.header.toc_len.saturating_sub(FW_TOC_ENTRY_COUNT);
+        if count > MAX_COMPONENT_COUNT {
+            return Err(err("too many TOC entries"));
+        }
+
+        let mut end = manifest.fmc.offset.saturating_add(manifest.fmc.size);
+        end = end.max(
+            manifest
+                .runtime
+                .offset
+                .saturating_add(manifest.runtime.size),
+        );
+        let mut components = vec![];
+        for i in 0..count as usize {
+            let offset = size_of::<ImageManifest>() + i * size_of::<ImageTocEntry>();
+            let toc = bytes
+                .get(offset..)
+                .and_then(ImageTocEntry::read_from_prefix)
+                .ok_or_else(|| err("component TOC entry out of bounds"))?;
+            let content =
+                section(toc.offset, toc.size).ok_or_else(|| err("component out of bounds"))?;
+            end = end.max(toc.offset.saturating_add(toc.size));
+            components.push(ImageComponent { toc, content });
+        }
+
+        // The PQC extension, if present, follows the last section. The HSS
+        // extension, if present, is exactly the remaining data.
+        let mut rest = bytes.get(end as usize..).unwrap_or_default();
+        let pqc_extension = ImagePqcExtension::read_from_prefix(rest)
+            .filter(|ext| ext.marker == PQC_EXTENSION_MARKER);
+        if pqc_extension.is_some() {
+            rest = &rest[size_of::<ImagePqcExtension>()..];
+        }
+        let hss_extension =
+            ImageHssExtension::read_from(rest).filter(|ext| ext.marker == HSS_EXTENSION_MARKER);
+
+        Ok(Self {
+            fmc: section(manifest.fmc.offset, manifest.fmc.size)
+                .ok_or_else(|| err("fmc out of bounds"))?,
+            runtime: section(manifest.runtime.offset, manifest.runtime.size)
+                .ok_or_else(|| err("runtime out of bounds"))?,
+            manifest,
+            components,
+            pqc_extension,
+            hss_extension,
+        })
+    }
 }
 
 /// Calipatra Image Manifest
@@ -191,6 +326,14 @@ impl ImageManifest {
         let span = span_of!(ImageManifest, fmc..=runtime);
         span.start as u32..span.end as u32
     }
+
+    /// Returns `Range<u32>` containing the table of contents followed by
+    /// `component_count` component TOC entries, which are stored right after
+    /// the manifest
+    pub fn toc_range_with_components(component_count: u32) -> Range<u32> {
+        let range = Self::toc_range();
+        range.start..range.end + component_count * size_of::<ImageTocEntry>() as u32
+    }
 }
 
 #[repr(C)]
@@ -236,6 +379,86 @@ pub struct ImageSignatures {
     pub lms_sig: ImageLmsSignature,
 }
 
+/// LMS public key of one HSS level, signed by the level above it
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Default, Debug)]
+pub struct ImageLmsSignedPubKey {
+    /// Signature by the parent key over the serialized `pub_key`
+    pub sig: ImageLmsSignature,
+
+    /// LMS public key of this level
+    pub pub_key: ImageLmsPublicKey,
+}
+
+/// Caliptra Image HSS Extension
+///
+/// Carries the signed public keys of the lower levels of the vendor and owner
+/// HSS (RFC 8554) chains. Entry `i` holds the key of level `i + 2`, signed by
+/// the key of level `i + 1`; the first level is the LMS public key in the
+/// preamble and the `lms_sig` of the preamble is made with the key of the last
+/// level. Entries beyond the HSS levels of a signer are unused. The
+/// extension occupies the last bytes of the image bundle.
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Debug)]
+pub struct ImageHssExtension {
+    /// Marker
+    pub marker: u32,
+
+    /// Vendor HSS signed public keys
+    pub vendor_signed_pub_keys: [ImageLmsSignedPubKey; LMS_HSS_MAX_LEVELS as usize - 1],
+
+    /// Owner HSS signed public keys
+    pub owner_signed_pub_keys: [ImageLmsSignedPubKey; LMS_HSS_MAX_LEVELS as usize - 1],
+}
+
+impl Default for ImageHssExtension {
+    fn default() -> Self {
+        Self {
+            marker: HSS_EXTENSION_MARKER,
+            vendor_signed_pub_keys: Default::default(),
+            owner_signed_pub_keys: Default::default(),
+        }
+    }
+}
+
+/// Caliptra Image PQC Extension
+///
+/// Carries the ML-DSA-87 public keys and signatures, which are too large for
+/// the manifest. The extension follows the last image section and precedes
+/// the HSS extension, if any. When the fuses require ML-DSA verification, the
+/// digest of each public key is appended to the vendor or owner public keys
+/// in the preamble to form the key hash stored in fuses.
+#[repr(C)]
+#[derive(AsBytes, Clone, Copy, FromBytes, Debug)]
+pub struct ImagePqcExtension {
+    /// Marker
+    pub marker: u32,
+
+    /// Vendor ML-DSA-87 Public Key
+    pub vendor_mldsa_pub_key: ImageMldsaPubKey,
+
+    /// Vendor ML-DSA-87 signature over the vendor header digest
+    pub vendor_mldsa_sig: ImageMldsaSignature,
+
+    /// Owner ML-DSA-87 Public Key
+    pub owner_mldsa_pub_key: ImageMldsaPubKey,
+
+    /// Owner ML-DSA-87 signature over the header digest
+    pub owner_mldsa_sig: ImageMldsaSignature,
+}
+
+impl Default for ImagePqcExtension {
+    fn default() -> Self {
+        Self {
+            marker: PQC_EXTENSION_MARKER,
+            vendor_mldsa_pub_key: [0; MLDSA87_PUB_KEY_BYTE_SIZE],
+            vendor_mldsa_sig: [0; MLDSA87_SIGNATURE_BYTE_SIZE + 1],
+            owner_mldsa_pub_key: [0; MLDSA87_PUB_KEY_BYTE_SIZE],
+            owner_mldsa_sig: [0; MLDSA87_SIGNATURE_BYTE_SIZE + 1],
+        }
+    }
+}
+
 /// Calipatra Image Bundle Preamble
 #[repr(C)]
 #[derive(AsBytes, Clone, Copy, FromBytes, Default, Debug, Zeroize)]
@@ -259,7 +482,13 @@ pub struct ImagePreamble {
     /// Owner Signatures
     pub owner_sigs: ImageSignatures,
 
-    pub _rsvd: [u32; 2],
+    /// Number of HSS levels below and including the vendor LMS public keys.
+    /// 0 and 1 denote a single LMS tree.
+    pub vendor_lms_hss_levels: u32,
+
+    /// Number of HSS levels below and including the owner LMS public key.
+    /// 0 and 1 denote a single LMS tree.
+    pub owner_lms_hss_levels: u32,
 }
 
 #[repr(C)]
@@ -326,10 +555,14 @@ pub struct ImageHeader {
     pub owner_data: OwnerSignedData,
 }
 
-/// Caliptra table contents entry id
+/// Caliptra table contents entry type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImageTocEntryType {
     /// First mutable code
     Executable = 1,
+
+    /// Data blob
+    Data = 2,
 }
 
 impl From<ImageTocEntryType> for u32 {
@@ -431,6 +664,180 @@ mod tests {
         assert_eq!(std::mem::size_of::<ImageManifest>() % 4, 0);
     }
 
+    #[test]
+    fn test_preamble_layout() {
+        // The HSS levels take the place of the reserved words at the end of
+        // the preamble, so the key hash fuses and the manifest size are
+        // unchanged
+        assert_eq!(
+            offset_of!(ImagePreamble, vendor_lms_hss_levels),
+            offset_of!(ImagePreamble, owner_sigs) + size_of::<ImageSignatures>()
+        );
+        assert_eq!(
+            size_of::<ImagePreamble>(),
+            offset_of!(ImagePreamble, owner_lms_hss_levels) + 4
+        );
+        assert_eq!(
+            size_of::<ImageVendorPubKeys>(),
+            size_of::<[ImageEccPubKey; VENDOR_ECC_KEY_COUNT as usize]>()
+                + size_of::<[ImageLmsPublicKey; VENDOR_LMS_KEY_COUNT as usize]>()
+        );
+        assert_eq!(
+            size_of::<ImageOwnerPubKeys>(),
+            size_of::<ImageEccPubKey>() + size_of::<ImageLmsPublicKey>()
+        );
+    }
+
+    #[test]
+    fn test_pqc_extension_size() {
+        assert_eq!(std::mem::size_of::<ImagePqcExtension>() % 4, 0);
+        assert_eq!(
+            std::mem::size_of::<ImagePqcExtension>(),
+            4 + 2 * (MLDSA87_PUB_KEY_BYTE_SIZE + MLDSA87_SIGNATURE_BYTE_SIZE + 1)
+        );
+    }
+
+    #[test]
+    fn test_hss_extension_size() {
+        assert_eq!(std::mem::size_of::<ImageHssExtension>() % 4, 0);
+        assert_eq!(
+            std::mem::size_of::<ImageHssExtension>(),
+            4 + 4 * std::mem::size_of::<ImageLmsSignedPubKey>()
+        );
+    }
+
+    #[test]
+    fn test_lms_hss_message() {
+        let pub_key = ImageLmsPublicKey {
+            tree_type: IMAGE_LMS_TREE_TYPE,
+            otstype: IMAGE_LMS_OTS_TYPE,
+            id: [0xa5; 16],
+            digest: [0x01020304.into(); SHA192_DIGEST_WORD_SIZE],
+        };
+        let message = lms_hss_message(&pub_key);
+        let bytes: Vec<u8> = message.iter().flat_map(|w| w.to_be_bytes()).collect();
+        assert_eq!(bytes, pub_key.as_bytes());
+        assert_eq!(message[0], 12);
+        assert_eq!(message[1], 7);
+    }
+
+    #[test]
+    fn test_toc_range_with_components() {
+        // Component TOC entries are only contiguous with the firmware TOC
+        // entries if the runtime entry ends the manifest
+        assert_eq!(
+            ImageManifest::toc_range().end as usize,
+            std::mem::size_of::<ImageManifest>()
+        );
+        assert_eq!(
+            ImageManifest::toc_range_with_components(0),
+            ImageManifest::toc_range()
+        );
+        assert_eq!(
+            ImageManifest::toc_range_with_components(2).len(),
+            4 * std::mem::size_of::<ImageTocEntry>()
+        );
+    }
+
+    #[test]
+    fn test_image_bundle_from_bytes() {
+        let manifest_size = size_of::<ImageManifest>() as u32;
+        let toc_size = size_of::<ImageTocEntry>() as u32;
+        let mut image = ImageBundle {
+            fmc: vec![1; 8],
+            runtime: vec![2; 12],
+            components: vec![ImageComponent {
+                toc: ImageTocEntry {
+                    id: 0x10,
+                    offset: manifest_size + toc_size + 20,
+                    size: 4,
+                    ..Default::default()
+                },
+                content: vec![3; 4],
+            }],
+            ..Default::default()
+        };
+        image.manifest.marker = MANIFEST_MARKER;
+        image.manifest.header.toc_len = FW_TOC_ENTRY_COUNT + 1;
+        image.manifest.fmc.offset = manifest_size + toc_size;
+        image.manifest.fmc.size = 8;
+        image.manifest.runtime.offset = manifest_size + toc_size + 8;
+        image.manifest.runtime.size = 12;
+
+        let bytes = image.to_bytes().unwrap();
+        let parsed = ImageBundle::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.manifest.as_bytes(), image.manifest.as_bytes());
+        assert_eq!(parsed.fmc, image.fmc);
+        assert_eq!(parsed.runtime, image.runtime);
+        assert_eq!(parsed.components.len(), 1);
+        assert_eq!(
+            parsed.components[0].toc.as_bytes(),
+            image.components[0].toc.as_bytes()
+        );
+        assert_eq!(parsed.components[0].content, image.components[0].content);
+        assert!(parsed.hss_extension.is_none());
+        assert!(parsed.pqc_extension.is_none());
+        assert_eq!(parsed.to_bytes().unwrap(), bytes);
+
+        // PQC extension following the last component
+        let mut pqc_extension = ImagePqcExtension::default();
+        pqc_extension.vendor_mldsa_pub_key[0] = 0x5a;
+        pqc_extension.owner_mldsa_sig[1] = 0xa5;
+        image.pqc_extension = Some(pqc_extension);
+        let pqc_bytes = image.to_bytes().unwrap();
+        assert_eq!(
+            pqc_bytes.len(),
+            bytes.len() + size_of::<ImagePqcExtension>()
+        );
+        let parsed = ImageBundle::from_bytes(&pqc_bytes).unwrap();
+        assert_eq!(
+            parsed.pqc_extension.unwrap().as_bytes(),
+            pqc_extension.as_bytes()
+        );
+        assert!(parsed.hss_extension.is_none());
+        assert_eq!(parsed.to_bytes().unwrap(), pqc_bytes);
+        image.pqc_extension = None;
+
+        // HSS extension following the last component
+        let mut hss_extension = ImageHssExtension::default();
+        hss_extension.vendor_signed_pub_keys[0].pub_key.id = [0x5a; 16];
+        hss_extension.owner_signed_pub_keys[1].sig.q = 7.into();
+        image.hss_extension = Some(hss_extension);
+        let hss_bytes = image.to_bytes().unwrap();
+        assert_eq!(
+            hss_bytes.len(),
+            bytes.len() + size_of::<ImageHssExtension>()
+        );
+        let parsed = ImageBundle::from_bytes(&hss_bytes).unwrap();
+        assert_eq!(
+            parsed.hss_extension.unwrap().as_bytes(),
+            hss_extension.as_bytes()
+        );
+        assert!(parsed.pqc_extension.is_none());
+        assert_eq!(parsed.to_bytes().unwrap(), hss_bytes);
+
+        // Both extensions, with the HSS extension last
+        image.pqc_extension = Some(pqc_extension);
+        let ext_bytes = image.to_bytes().unwrap();
+        assert_eq!(
+            ext_bytes.len(),
+            pqc_bytes.len() + size_of::<ImageHssExtension>()
+        );
+        assert_eq!(&ext_bytes[pqc_bytes.len()..], hss_extension.as_bytes());
+        let parsed = ImageBundle::from_bytes(&ext_bytes).unwrap();
+        assert!(parsed.hss_extension.is_some());
+        assert!(parsed.pqc_extension.is_some());
+        assert_eq!(parsed.to_bytes().unwrap(), ext_bytes);
+
+        // Truncated component
+        assert!(ImageBundle::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        // Bad marker
+        let mut bad = bytes.clone();
+        bad[0] ^= 1;
+        assert!(ImageBundle::from_bytes(&bad).is_err());
+    }
+
     #[test]
     fn test_image_overlap() {
         let mut image1 = ImageTocEntry::default();
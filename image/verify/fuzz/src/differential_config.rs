@@ -0,0 +1,48 @@
+// Licensed under the Apache-2.0 license
+
+//! Fuse and reset configuration prefixed to the inputs of
+//! `fuzz_target_differential`, shared with `gen_differential_corpus`.
+
+/// Length of the configuration that precedes the image bundle in the input
+pub const CONFIG_LEN: usize = 16;
+
+pub const FLAG_UPDATE_RESET: u8 = 1 << 0;
+pub const FLAG_ANTI_ROLLBACK_DISABLE: u8 = 1 << 1;
+pub const FLAG_LMS_VERIFY: u8 = 1 << 2;
+pub const FLAG_VENDOR_PK_HASH: u8 = 1 << 3;
+pub const FLAG_OWNER_PK_HASH: u8 = 1 << 4;
+pub const FLAG_COLD_BOOT_FROM_IMAGE: u8 = 1 << 5;
+pub const LIFECYCLE_SHIFT: u8 = 6;
+
+/// Fuse and reset configuration of a fuzz input
+///
+/// Encoded as the first `CONFIG_LEN` bytes of the input:
+///
+/// | Offset | Field                                                        |
+/// |--------|--------------------------------------------------------------|
+/// | 0      | Flags (`FLAG_*`) and lifecycle in bits 6-7                   |
+/// | 1      | Vendor ECC key revocation fuses                              |
+/// | 2..6   | Vendor LMS key revocation fuses                              |
+/// | 6      | FMC fuse SVN                                                 |
+/// | 7      | Runtime fuse SVN                                             |
+/// | 8      | Cold boot vendor ECC key index, unless taken from the image  |
+/// | 9      | Cold boot vendor LMS key index, unless taken from the image  |
+#[derive(Clone, Copy, Default)]
+pub struct Config {
+    pub update_reset: bool,
+    pub anti_rollback_disable: bool,
+    pub lms_verify: bool,
+    /// Burn the vendor key hash of the image, otherwise leave it zero
+    pub vendor_pk_hash: bool,
+    /// Burn the owner key hash of the image, otherwise leave it zero
+    pub owner_pk_hash: bool,
+    /// Use the cold boot values of the image on an update reset
+    pub cold_boot_from_image: bool,
+    pub lifecycle: u8,
+    pub ecc_revocation: u8,
+    pub lms_revocation: u32,
+    pub fmc_svn: u8,
+    pub runtime_svn: u8,
+    pub cold_boot_ecc_idx: u8,
+    pub cold_boot_lms_idx: u8,
+}
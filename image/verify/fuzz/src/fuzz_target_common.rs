@@ -55,6 +55,15 @@ impl ImageVerificationEnv for TestEnv {
         Ok(self.digest)
     }
 
+    fn sha384_digest_with_suffix(
+        &mut self,
+        _offset: u32,
+        _len: u32,
+        _suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest> {
+        Ok(self.digest)
+    }
+
     fn ecc384_verify(
         &mut self,
         _digest: &ImageDigest,
@@ -81,6 +90,19 @@ impl ImageVerificationEnv for TestEnv {
         }
     }
 
+    fn mldsa87_verify(
+        &mut self,
+        _digest: &ImageDigest,
+        _pub_key_offset: u32,
+        _sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result> {
+        Ok(Mldsa87Result::Success)
+    }
+
+    fn lms_hss_signed_pub_key(&self, _offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+        Ok(ImageLmsSignedPubKey::default())
+    }
+
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.vendor_pub_key_digest
     }
@@ -117,6 +139,14 @@ impl ImageVerificationEnv for TestEnv {
         self.owner_pub_key_digest
     }
 
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+        None
+    }
+
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+        None
+    }
+
     fn get_fmc_digest_dv(&self) -> ImageDigest {
         self.fmc_digest
     }
@@ -140,6 +170,14 @@ impl ImageVerificationEnv for TestEnv {
         true
     }
 
+    fn lms_hss_verify_enabled(&self) -> bool {
+        true
+    }
+
+    fn mldsa87_verify_enabled(&self) -> bool {
+        false
+    }
+
     fn set_fw_extended_error(&mut self, _err: u32) {}
 }
 
@@ -149,7 +187,7 @@ pub fn harness_structured(reset_reason: ResetReason, manifest: ImageManifest) {
     let mut image_verifier = ImageVerifier::new(test_env);
 
     //println!("{:?}", fuzz_bundle);
-    let _result = image_verifier.verify(&manifest, IMAGE_BUNDLE_SIZE, reset_reason);
+    let _result = image_verifier.verify(&manifest, &[], IMAGE_BUNDLE_SIZE, reset_reason);
     //println!("{:?}", _result);
 }
 
@@ -167,12 +205,27 @@ pub fn harness_unstructured(reset_reason: ResetReason, data: &[u8]) {
         typed_fuzz_manifest = &*(data.as_ptr() as *const ImageManifest);
     }
 
+    // Component TOC entries follow the manifest
+    let component_count = (typed_fuzz_manifest
+        .header
+        .toc_len
+        .saturating_sub(FW_TOC_ENTRY_COUNT) as usize)
+        .min(MAX_COMPONENT_COUNT as usize)
+        .min((data.len() - size_of::<ImageManifest>()) / size_of::<ImageTocEntry>());
+    let components: &[ImageTocEntry] = unsafe {
+        core::slice::from_raw_parts(
+            data.as_ptr().add(size_of::<ImageManifest>()) as *const ImageTocEntry,
+            component_count,
+        )
+    };
+
     let test_env = TestEnv::default();
     let mut image_verifier = ImageVerifier::new(test_env);
 
     //println!("{:?}", fuzz_bundle);
     let _result = image_verifier.verify(
         typed_fuzz_manifest,
+        components,
         data.len().try_into().unwrap(),
         reset_reason,
     );
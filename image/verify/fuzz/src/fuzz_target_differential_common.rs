@@ -0,0 +1,375 @@
+// Licensed under the Apache-2.0 license
+
+use std::mem::size_of;
+
+use caliptra_drivers::memory_layout::ICCM_RANGE;
+use caliptra_drivers::*;
+use caliptra_drivers_sw::Sha256Sw;
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorCrypto};
+use caliptra_image_openssl::{ecdsa384_verify, OsslCrypto};
+use caliptra_image_types::*;
+use caliptra_image_verify::*;
+use core::ops::Range;
+use zerocopy::{AsBytes, FromBytes};
+
+use crate::differential_config::*;
+use crate::reference_verifier::{self, ColdBootState, Fuses};
+
+impl Config {
+    fn decode(bytes: &[u8; CONFIG_LEN]) -> Self {
+        let flags = bytes[0];
+        Self {
+            update_reset: flags & FLAG_UPDATE_RESET != 0,
+            anti_rollback_disable: flags & FLAG_ANTI_ROLLBACK_DISABLE != 0,
+            lms_verify: flags & FLAG_LMS_VERIFY != 0,
+            vendor_pk_hash: flags & FLAG_VENDOR_PK_HASH != 0,
+            owner_pk_hash: flags & FLAG_OWNER_PK_HASH != 0,
+            cold_boot_from_image: flags & FLAG_COLD_BOOT_FROM_IMAGE != 0,
+            lifecycle: flags >> LIFECYCLE_SHIFT,
+            ecc_revocation: bytes[1],
+            lms_revocation: u32::from_le_bytes(bytes[2..6].try_into().unwrap()),
+            fmc_svn: bytes[6],
+            runtime_svn: bytes[7],
+            cold_boot_ecc_idx: bytes[8],
+            cold_boot_lms_idx: bytes[9],
+        }
+    }
+
+    fn fuses(&self, image: &[u8]) -> Fuses {
+        let gen = ImageGenerator::new(OsslCrypto::default());
+        let (vendor_pk_hash, owner_pk_hash) = match ImageManifest::read_from_prefix(image) {
+            Some(manifest) => (
+                self.vendor_pk_hash
+                    .then(|| gen.vendor_pubkey_digest(&manifest.preamble).unwrap())
+                    .unwrap_or_default(),
+                self.owner_pk_hash
+                    .then(|| gen.owner_pubkey_digest(&manifest.preamble).unwrap())
+                    .unwrap_or_default(),
+            ),
+            None => Default::default(),
+        };
+        Fuses {
+            vendor_pk_hash,
+            owner_pk_hash,
+            ecc_revocation: self.ecc_revocation.into(),
+            lms_revocation: self.lms_revocation,
+            fmc_svn: self.fmc_svn.into(),
+            runtime_svn: self.runtime_svn.into(),
+            anti_rollback_disable: self.anti_rollback_disable,
+            lms_verify: self.lms_verify,
+            lifecycle: match self.lifecycle {
+                0 => Lifecycle::Unprovisioned,
+                1 => Lifecycle::Manufacturing,
+                2 => Lifecycle::Reserved2,
+                _ => Lifecycle::Production,
+            },
+        }
+    }
+
+    fn cold_boot_state(&self, image: &[u8]) -> Option<ColdBootState> {
+        if !self.update_reset {
+            return None;
+        }
+        match ImageManifest::read_from_prefix(image) {
+            Some(manifest) if self.cold_boot_from_image => Some(ColdBootState {
+                vendor_ecc_pub_key_idx: manifest.preamble.vendor_ecc_pub_key_idx,
+                vendor_lms_pub_key_idx: manifest.preamble.vendor_lms_pub_key_idx,
+                owner_pk_hash: ImageGenerator::new(OsslCrypto::default())
+                    .owner_pubkey_digest(&manifest.preamble)
+                    .unwrap(),
+                fmc_digest: manifest.fmc.digest,
+            }),
+            _ => Some(ColdBootState {
+                vendor_ecc_pub_key_idx: self.cold_boot_ecc_idx.into(),
+                vendor_lms_pub_key_idx: self.cold_boot_lms_idx.into(),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+/// Verification environment backed by software crypto, standing in for the
+/// ROM hardware
+struct SoftwareEnv<'a> {
+    image: &'a [u8],
+    fuses: &'a Fuses,
+    cold_boot: Option<&'a ColdBootState>,
+    crypto: OsslCrypto,
+}
+
+impl<'a> ImageVerificationEnv for SoftwareEnv<'a> {
+    fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        self.crypto.sha384_digest(data).map_err(|_| err)
+    }
+
+    fn sha384_digest_with_suffix(
+        &mut self,
+        offset: u32,
+        len: u32,
+        suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        self.crypto
+            .sha384_digest(&[data, suffix].concat())
+            .map_err(|_| err)
+    }
+
+    fn ecc384_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+    ) -> CaliptraResult<Array4xN<12, 48>> {
+        // The hardware returns r for a valid signature; return anything else
+        // for an invalid one
+        match ecdsa384_verify(digest, pub_key, sig) {
+            Ok(true) => Ok(sig.r.into()),
+            _ => Ok(sig.r.map(|w| !w).into()),
+        }
+    }
+
+    fn lms_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+    ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>> {
+        let mut message = [0u8; SHA384_DIGEST_BYTE_SIZE];
+        for i in 0..digest.len() {
+            message[i * 4..][..4].copy_from_slice(&digest[i].to_be_bytes());
+        }
+        Lms::default().verify_lms_signature_cfi_generic(
+            &mut Sha256Sw::new(),
+            &message,
+            pub_key,
+            sig,
+        )
+    }
+
+    // The reference verifier does not model the PQC extension
+    fn mldsa87_verify(
+        &mut self,
+        _digest: &ImageDigest,
+        _pub_key_offset: u32,
+        _sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result> {
+        Ok(Mldsa87Result::SigVerifyFailed)
+    }
+
+    fn lms_hss_signed_pub_key(&self, offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+        self.image
+            .get(offset as usize..)
+            .and_then(ImageLmsSignedPubKey::read_from_prefix)
+            .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS)
+    }
+
+    fn vendor_pub_key_digest(&self) -> ImageDigest {
+        self.fuses.vendor_pk_hash
+    }
+
+    fn vendor_ecc_pub_key_revocation(&self) -> VendorPubKeyRevocation {
+        VendorPubKeyRevocation::from_bits_truncate(self.fuses.ecc_revocation)
+    }
+
+    fn vendor_lms_pub_key_revocation(&self) -> u32 {
+        self.fuses.lms_revocation
+    }
+
+    fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
+        self.fuses.owner_pk_hash
+    }
+
+    fn anti_rollback_disable(&self) -> bool {
+        self.fuses.anti_rollback_disable
+    }
+
+    fn dev_lifecycle(&self) -> Lifecycle {
+        self.fuses.lifecycle
+    }
+
+    fn vendor_ecc_pub_key_idx_dv(&self) -> u32 {
+        self.cold_boot.map_or(0, |c| c.vendor_ecc_pub_key_idx)
+    }
+
+    fn vendor_lms_pub_key_idx_dv(&self) -> u32 {
+        self.cold_boot.map_or(0, |c| c.vendor_lms_pub_key_idx)
+    }
+
+    fn owner_pub_key_digest_dv(&self) -> ImageDigest {
+        self.cold_boot
+            .map_or(ImageDigest::default(), |c| c.owner_pk_hash)
+    }
+
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+        None
+    }
+
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+        None
+    }
+
+    fn get_fmc_digest_dv(&self) -> ImageDigest {
+        self.cold_boot
+            .map_or(ImageDigest::default(), |c| c.fmc_digest)
+    }
+
+    fn fmc_fuse_svn(&self) -> u32 {
+        self.fuses.fmc_svn
+    }
+
+    fn runtime_fuse_svn(&self) -> u32 {
+        self.fuses.runtime_svn
+    }
+
+    fn iccm_range(&self) -> Range<u32> {
+        ICCM_RANGE
+    }
+
+    fn lms_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn lms_hss_verify_enabled(&self) -> bool {
+        self.fuses.lms_verify
+    }
+
+    fn mldsa87_verify_enabled(&self) -> bool {
+        false
+    }
+
+    fn set_fw_extended_error(&mut self, _err: u32) {}
+}
+
+/// Load the manifest and component TOC entries the way the ROM does before
+/// running `ImageVerifier`
+fn load_manifest(image: &[u8]) -> CaliptraResult<(ImageManifest, Vec<ImageTocEntry>)> {
+    let err = CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID;
+    let manifest = ImageManifest::read_from_prefix(image).ok_or(err)?;
+    let component_count = manifest.header.toc_len.saturating_sub(FW_TOC_ENTRY_COUNT);
+    if component_count > MAX_COMPONENT_COUNT {
+        return Err(err);
+    }
+    let components = (0..component_count as usize)
+        .map(|i| {
+            image
+                .get(size_of::<ImageManifest>() + i * size_of::<ImageTocEntry>()..)
+                .and_then(ImageTocEntry::read_from_prefix)
+                .ok_or(err)
+        })
+        .collect::<CaliptraResult<Vec<_>>>()?;
+    Ok((manifest, components))
+}
+
+fn software_verifier<'a>(
+    image: &'a [u8],
+    fuses: &'a Fuses,
+    cold_boot: Option<&'a ColdBootState>,
+) -> ImageVerifier<SoftwareEnv<'a>> {
+    ImageVerifier::new(SoftwareEnv {
+        image,
+        fuses,
+        cold_boot,
+        crypto: OsslCrypto::default(),
+    })
+}
+
+fn reset_reason(cold_boot: Option<&ColdBootState>) -> ResetReason {
+    if cold_boot.is_some() {
+        ResetReason::UpdateReset
+    } else {
+        ResetReason::ColdReset
+    }
+}
+
+/// Run `ImageVerifier` the way the ROM does, including the component TOC
+/// load that precedes it
+fn firmware_verify(
+    image: &[u8],
+    fuses: &Fuses,
+    cold_boot: Option<&ColdBootState>,
+) -> CaliptraResult<()> {
+    let (manifest, components) = load_manifest(image)?;
+    software_verifier(image, fuses, cold_boot)
+        .verify(
+            &manifest,
+            &components,
+            image.len() as u32,
+            reset_reason(cold_boot),
+        )
+        .map(|_| ())
+}
+
+/// Run `ImageVerifier::verify_report` on the same inputs as
+/// `firmware_verify` and return the first failure it reports
+fn firmware_report(
+    image: &[u8],
+    fuses: &Fuses,
+    cold_boot: Option<&ColdBootState>,
+) -> CaliptraResult<()> {
+    let (manifest, components) = load_manifest(image)?;
+    let report = software_verifier(image, fuses, cold_boot).verify_report(
+        &manifest,
+        &components,
+        image.len() as u32,
+        reset_reason(cold_boot),
+    );
+    match report.failures.first() {
+        Some(failure) => Err(failure.error),
+        None => Ok(()),
+    }
+}
+
+/// Split a fuzz input into the image bundle and the fuses and cold boot
+/// state its configuration describes
+fn decode_input(data: &[u8]) -> Option<(&[u8], Fuses, Option<ColdBootState>)> {
+    if data.len() < CONFIG_LEN {
+        return None;
+    }
+    let (config, image) = data.split_at(CONFIG_LEN);
+    let config = Config::decode(config.try_into().unwrap());
+    Some((image, config.fuses(image), config.cold_boot_state(image)))
+}
+
+/// Check that the first failure `ImageVerifier::verify_report` lists is the
+/// error `ImageVerifier::verify` returns
+pub fn harness_report(data: &[u8]) {
+    let Some((image, fuses, cold_boot)) = decode_input(data) else {
+        return;
+    };
+    let firmware = firmware_verify(image, &fuses, cold_boot.as_ref());
+    let report = firmware_report(image, &fuses, cold_boot.as_ref());
+    if firmware != report {
+        panic!(
+            "ImageVerifier returned {firmware:x?} but its report starts with {report:x?} with {fuses:x?}, cold boot state {cold_boot:x?}"
+        );
+    }
+}
+
+pub fn harness_differential(data: &[u8]) {
+    let Some((image, fuses, cold_boot)) = decode_input(data) else {
+        return;
+    };
+
+    let firmware = firmware_verify(image, &fuses, cold_boot.as_ref());
+    let reference = reference_verifier::verify(image, &fuses, cold_boot.as_ref());
+    if firmware.is_ok() != reference.is_ok() {
+        panic!(
+            "ImageVerifier returned {firmware:x?} but the reference model returned {reference:?} with {fuses:x?}, cold boot state {cold_boot:x?}"
+        );
+    }
+
+    harness_report(data);
+}
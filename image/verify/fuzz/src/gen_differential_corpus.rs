@@ -0,0 +1,248 @@
+// Licensed under the Apache-2.0 license
+
+//! Generate seed inputs for `fuzz_target_differential`: image bundles signed
+//! with the fake keys, each prefixed with a fuse and reset configuration.
+//!
+//! Usage: gen_differential_corpus <OUT_DIR>
+
+mod differential_config;
+#[cfg(test)]
+mod fuzz_target_differential_common;
+#[cfg(test)]
+mod reference_verifier;
+
+use std::path::PathBuf;
+
+use caliptra_drivers::memory_layout::ICCM_ORG;
+use caliptra_image_fake_keys::*;
+use caliptra_image_gen::*;
+use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_types::*;
+use differential_config::*;
+
+/// Executable with generated contents
+struct SeedExecutable {
+    content: Vec<u8>,
+    rev: ImageRevision,
+    load_addr: u32,
+    svn: u32,
+}
+
+impl SeedExecutable {
+    fn new(load_addr: u32, size: usize, svn: u32) -> Self {
+        Self {
+            content: (0..size)
+                .map(|i| (i * 7 + load_addr as usize) as u8)
+                .collect(),
+            rev: Default::default(),
+            load_addr,
+            svn,
+        }
+    }
+}
+
+impl ImageGenratorExecutable for SeedExecutable {
+    fn version(&self) -> u32 {
+        1
+    }
+
+    fn svn(&self) -> u32 {
+        self.svn
+    }
+
+    fn rev(&self) -> &ImageRevision {
+        &self.rev
+    }
+
+    fn load_addr(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn entry_point(&self) -> u32 {
+        self.load_addr
+    }
+
+    fn content(&self) -> &Vec<u8> {
+        &self.content
+    }
+
+    fn size(&self) -> u32 {
+        self.content.len() as u32
+    }
+}
+
+fn component(
+    id: u32,
+    r#type: ImageTocEntryType,
+    load_addr: u32,
+    size: usize,
+) -> ImageGeneratorComponent {
+    ImageGeneratorComponent {
+        id,
+        r#type,
+        revision: Default::default(),
+        version: 1,
+        svn: 0,
+        load_addr,
+        entry_point: load_addr,
+        content: vec![0xa5; size],
+    }
+}
+
+/// Seed inputs, named by the image and configuration they combine
+fn seed_inputs() -> anyhow::Result<Vec<(String, Vec<u8>)>> {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let images = [
+        (VENDOR_CONFIG_KEY_0, 0, vec![]),
+        (VENDOR_CONFIG_KEY_1, 3, vec![]),
+        (
+            VENDOR_CONFIG_KEY_3,
+            5,
+            vec![
+                component(0x1000, ImageTocEntryType::Data, ICCM_ORG + 0x8000, 0x100),
+                component(
+                    0x1001,
+                    ImageTocEntryType::Executable,
+                    ICCM_ORG + 0x9000,
+                    0x200,
+                ),
+            ],
+        ),
+    ];
+
+    let production = Config {
+        lifecycle: 3,
+        lms_verify: true,
+        vendor_pk_hash: true,
+        owner_pk_hash: true,
+        ..Default::default()
+    };
+    let configs = [
+        Config::default(),
+        production,
+        Config {
+            anti_rollback_disable: true,
+            fmc_svn: 8,
+            ..production
+        },
+        Config {
+            ecc_revocation: 0b0111,
+            lms_revocation: 0b1111,
+            fmc_svn: 3,
+            runtime_svn: 3,
+            ..production
+        },
+        Config {
+            update_reset: true,
+            cold_boot_from_image: true,
+            ..production
+        },
+        Config {
+            update_reset: true,
+            cold_boot_ecc_idx: 1,
+            cold_boot_lms_idx: 1,
+            ..production
+        },
+    ];
+
+    let mut inputs = vec![];
+    for (i, (vendor_config, svn, components)) in images.into_iter().enumerate() {
+        let image = gen.generate(&ImageGeneratorConfig {
+            vendor_config,
+            owner_config: Some(OWNER_CONFIG),
+            fmc: SeedExecutable::new(ICCM_ORG, 0x400, svn),
+            runtime: SeedExecutable::new(ICCM_ORG + 0x4000, 0x800, svn),
+            components,
+        })?;
+        let image = image.to_bytes()?;
+        for (j, config) in configs.iter().enumerate() {
+            let mut input = encode_config(config).to_vec();
+            input.extend_from_slice(&image);
+            inputs.push((format!("differential_{i}_{j}"), input));
+        }
+    }
+    Ok(inputs)
+}
+
+/// Encode `config` as the prefix `fuzz_target_differential` decodes
+fn encode_config(config: &Config) -> [u8; CONFIG_LEN] {
+    let mut bytes = [0u8; CONFIG_LEN];
+    for (set, flag) in [
+        (config.update_reset, FLAG_UPDATE_RESET),
+        (config.anti_rollback_disable, FLAG_ANTI_ROLLBACK_DISABLE),
+        (config.lms_verify, FLAG_LMS_VERIFY),
+        (config.vendor_pk_hash, FLAG_VENDOR_PK_HASH),
+        (config.owner_pk_hash, FLAG_OWNER_PK_HASH),
+        (config.cold_boot_from_image, FLAG_COLD_BOOT_FROM_IMAGE),
+    ] {
+        if set {
+            bytes[0] |= flag;
+        }
+    }
+    bytes[0] |= config.lifecycle << LIFECYCLE_SHIFT;
+    bytes[1] = config.ecc_revocation;
+    bytes[2..6].copy_from_slice(&config.lms_revocation.to_le_bytes());
+    bytes[6] = config.fmc_svn;
+    bytes[7] = config.runtime_svn;
+    bytes[8] = config.cold_boot_ecc_idx;
+    bytes[9] = config.cold_boot_lms_idx;
+    bytes
+}
+
+fn main() -> anyhow::Result<()> {
+    let out_dir: PathBuf = std::env::args_os()
+        .nth(1)
+        .ok_or_else(|| anyhow::anyhow!("Usage: gen_differential_corpus <OUT_DIR>"))?
+        .into();
+    std::fs::create_dir_all(&out_dir)?;
+
+    for (name, input) in seed_inputs()? {
+        std::fs::write(out_dir.join(name), input)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuzz_target_differential_common::{harness_differential, harness_report};
+    use std::path::Path;
+
+    #[test]
+    fn test_replay_seed_corpus() {
+        for (name, input) in seed_inputs().unwrap() {
+            println!("{name}");
+            harness_differential(&input);
+        }
+
+        // Also replay the corpus the nightly fuzzing job builds, if present
+        let corpus_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("common_corpus");
+        let Ok(entries) = std::fs::read_dir(corpus_dir) else {
+            return;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            println!("{}", path.display());
+            harness_differential(&std::fs::read(path).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_report_matches_verify_on_seed_corpus() {
+        for (name, input) in seed_inputs().unwrap() {
+            println!("{name}");
+            harness_report(&input);
+
+            // Corrupt bytes throughout the image and truncate it, so the
+            // checks fail at different stages
+            for offset in (CONFIG_LEN..input.len()).step_by(509) {
+                let mut mutated = input.clone();
+                mutated[offset] ^= 0x01;
+                harness_report(&mutated);
+            }
+            for len in [CONFIG_LEN + 16, CONFIG_LEN + 0x1000, input.len() - 4] {
+                harness_report(&input[..len]);
+            }
+        }
+    }
+}
@@ -0,0 +1,492 @@
+// Licensed under the Apache-2.0 license
+
+//! Host reference model of the image verification rules in rom/dev/README.md.
+//!
+//! This is written against `caliptra-image-types` only and deliberately does
+//! not share code with `caliptra-image-verify`, so the differential fuzz
+//! target can compare the accept/reject decision of both.
+
+use std::mem::size_of;
+
+use caliptra_drivers::memory_layout::{ICCM_ORG, ICCM_SIZE};
+use caliptra_drivers::Lifecycle;
+use caliptra_image_gen::ImageGeneratorCrypto;
+use caliptra_image_openssl::{ecdsa384_verify, lms_verify, OsslCrypto};
+use caliptra_image_types::*;
+use zerocopy::{AsBytes, FromBytes};
+
+const ZERO_DIGEST: ImageDigest = [0u32; SHA384_DIGEST_WORD_SIZE];
+const MAX_FMC_SVN: u32 = 32;
+const MAX_RUNTIME_SVN: u32 = 128;
+
+/// Fuse values seen by the verifier
+#[derive(Debug)]
+pub struct Fuses {
+    pub vendor_pk_hash: ImageDigest,
+    pub owner_pk_hash: ImageDigest,
+    pub ecc_revocation: u32,
+    pub lms_revocation: u32,
+    pub fmc_svn: u32,
+    pub runtime_svn: u32,
+    pub anti_rollback_disable: bool,
+    pub lms_verify: bool,
+    pub lifecycle: Lifecycle,
+}
+
+/// Values the ROM saved in the data vault on cold boot
+#[derive(Debug, Default)]
+pub struct ColdBootState {
+    pub vendor_ecc_pub_key_idx: u32,
+    pub vendor_lms_pub_key_idx: u32,
+    pub owner_pk_hash: ImageDigest,
+    pub fmc_digest: ImageDigest,
+}
+
+/// Decide whether the ROM must accept `image`
+///
+/// # Arguments
+///
+/// * `image`     - Image bundle
+/// * `fuses`     - Fuse values
+/// * `cold_boot` - Data vault contents on an update reset, `None` on a cold reset
+///
+/// # Returns
+///
+/// * `Err` - Rule the image breaks
+pub fn verify(
+    image: &[u8],
+    fuses: &Fuses,
+    cold_boot: Option<&ColdBootState>,
+) -> Result<(), &'static str> {
+    let manifest = ImageManifest::read_from_prefix(image).ok_or("image smaller than manifest")?;
+    check(manifest.marker == MANIFEST_MARKER, "manifest marker")?;
+    check(
+        manifest.size as usize == size_of::<ImageManifest>(),
+        "manifest size",
+    )?;
+
+    verify_keys(&manifest, fuses, cold_boot)?;
+    let hss_offset = hss_extension_offset(image, &manifest, fuses)?;
+    verify_signatures(image, &manifest, fuses, hss_offset)?;
+    let components = verify_toc(&image[..hss_offset.unwrap_or(image.len())], &manifest)?;
+
+    let svn_check = fuses.lifecycle != Lifecycle::Unprovisioned && !fuses.anti_rollback_disable;
+    let fmc_digest = verify_exe(
+        image,
+        &manifest.fmc,
+        svn_check.then_some((MAX_FMC_SVN, fuses.fmc_svn)),
+    )?;
+    if let Some(cold_boot) = cold_boot {
+        check(
+            fmc_digest == cold_boot.fmc_digest,
+            "FMC digest of cold boot",
+        )?;
+    }
+    verify_exe(
+        image,
+        &manifest.runtime,
+        svn_check.then_some((MAX_RUNTIME_SVN, fuses.runtime_svn)),
+    )?;
+
+    verify_components(image, &manifest, &components)
+}
+
+fn check(ok: bool, rule: &'static str) -> Result<(), &'static str> {
+    if ok {
+        Ok(())
+    } else {
+        Err(rule)
+    }
+}
+
+fn sha384(data: &[u8]) -> ImageDigest {
+    OsslCrypto::default().sha384_digest(data).unwrap()
+}
+
+/// Bytes `offset..offset + size` of the image
+fn section(image: &[u8], offset: u32, size: u32) -> Option<&[u8]> {
+    let start = offset as usize;
+    let end = start.checked_add(size as usize)?;
+    if offset as u64 + size as u64 > u32::MAX as u64 {
+        return None;
+    }
+    image.get(start..end)
+}
+
+/// Whether the `size` bytes loaded at `addr` are in ICCM
+fn in_iccm(addr: u32, size: u32) -> bool {
+    let iccm = ICCM_ORG as u64..ICCM_ORG as u64 + ICCM_SIZE as u64;
+    iccm.start <= addr as u64 && addr as u64 + size as u64 <= iccm.end
+}
+
+/// Hash of the preamble `pub_keys`, followed by the little-endian HSS
+/// `levels` if the signer uses more than one level
+fn pub_keys_hash(pub_keys: &[u8], levels: u32) -> ImageDigest {
+    let mut data = pub_keys.to_vec();
+    if levels > 1 {
+        data.extend_from_slice(&levels.to_le_bytes());
+    }
+    sha384(&data)
+}
+
+fn verify_keys(
+    manifest: &ImageManifest,
+    fuses: &Fuses,
+    cold_boot: Option<&ColdBootState>,
+) -> Result<(), &'static str> {
+    let preamble = &manifest.preamble;
+    let header = &manifest.header;
+
+    // The vendor key hash fuses are only checked once provisioned
+    if fuses.lifecycle != Lifecycle::Unprovisioned {
+        check(fuses.vendor_pk_hash != ZERO_DIGEST, "vendor key hash fuses")?;
+        check(
+            pub_keys_hash(
+                preamble.vendor_pub_keys.as_bytes(),
+                preamble.vendor_lms_hss_levels,
+            ) == fuses.vendor_pk_hash,
+            "vendor key hash",
+        )?;
+    }
+
+    // Zero owner key hash fuses accept any owner
+    let owner_pk_hash = pub_keys_hash(
+        preamble.owner_pub_keys.as_bytes(),
+        preamble.owner_lms_hss_levels,
+    );
+    check(
+        fuses.owner_pk_hash == ZERO_DIGEST || fuses.owner_pk_hash == owner_pk_hash,
+        "owner key hash",
+    )?;
+
+    // The last vendor key can't be revoked
+    let ecc_idx = preamble.vendor_ecc_pub_key_idx;
+    check(ecc_idx < VENDOR_ECC_KEY_COUNT, "vendor ECC key index")?;
+    check(
+        ecc_idx == VENDOR_ECC_KEY_COUNT - 1 || fuses.ecc_revocation & (1 << ecc_idx) == 0,
+        "vendor ECC key revoked",
+    )?;
+    check(
+        header.vendor_ecc_pub_key_idx == ecc_idx,
+        "header vendor ECC key index",
+    )?;
+
+    let lms_idx = preamble.vendor_lms_pub_key_idx;
+    if fuses.lms_verify {
+        check(lms_idx < VENDOR_LMS_KEY_COUNT, "vendor LMS key index")?;
+        check(
+            lms_idx == VENDOR_LMS_KEY_COUNT - 1 || fuses.lms_revocation & (1 << lms_idx) == 0,
+            "vendor LMS key revoked",
+        )?;
+        check(
+            header.vendor_lms_pub_key_idx == lms_idx,
+            "header vendor LMS key index",
+        )?;
+        check(
+            preamble.vendor_lms_hss_levels <= LMS_HSS_MAX_LEVELS,
+            "vendor HSS levels",
+        )?;
+        check(
+            preamble.owner_lms_hss_levels <= LMS_HSS_MAX_LEVELS,
+            "owner HSS levels",
+        )?;
+    }
+
+    // An update reset must keep the keys of the cold boot
+    if let Some(cold_boot) = cold_boot {
+        check(
+            cold_boot.owner_pk_hash == owner_pk_hash,
+            "owner key hash of cold boot",
+        )?;
+        check(
+            cold_boot.vendor_ecc_pub_key_idx == ecc_idx,
+            "vendor ECC key index of cold boot",
+        )?;
+        if fuses.lms_verify {
+            check(
+                cold_boot.vendor_lms_pub_key_idx == lms_idx,
+                "vendor LMS key index of cold boot",
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn ecc_sig_valid(digest: &ImageDigest, pub_key: &ImageEccPubKey, sig: &ImageEccSignature) -> bool {
+    pub_key.x != ZERO_DIGEST
+        && pub_key.y != ZERO_DIGEST
+        && sig.r != ZERO_DIGEST
+        && sig.s != ZERO_DIGEST
+        && matches!(ecdsa384_verify(digest, pub_key, sig), Ok(true))
+}
+
+fn lms_sig_valid(
+    digest: &ImageDigest,
+    pub_key: &ImageLmsPublicKey,
+    sig: &ImageLmsSignature,
+) -> bool {
+    matches!(lms_verify(digest, pub_key, sig), Ok(true))
+}
+
+/// Offset of the HSS extension, which occupies the end of the image when the
+/// vendor or owner signs with more than one LMS level
+fn hss_extension_offset(
+    image: &[u8],
+    manifest: &ImageManifest,
+    fuses: &Fuses,
+) -> Result<Option<usize>, &'static str> {
+    let preamble = &manifest.preamble;
+    if !fuses.lms_verify
+        || (preamble.vendor_lms_hss_levels <= 1 && preamble.owner_lms_hss_levels <= 1)
+    {
+        return Ok(None);
+    }
+    let size = size_of::<ImageHssExtension>();
+    check(
+        image.len() >= size_of::<ImageManifest>() + size,
+        "HSS extension missing",
+    )?;
+    Ok(Some(image.len() - size))
+}
+
+/// Walk the HSS chain of `levels` levels below `pub_key`, whose signed
+/// public keys start at `offset`, and return the key of the last level
+fn hss_signing_key(
+    image: &[u8],
+    pub_key: &ImageLmsPublicKey,
+    levels: u32,
+    offset: usize,
+) -> Option<ImageLmsPublicKey> {
+    let mut pub_key = *pub_key;
+    for level in 1..levels as usize {
+        let signed = ImageLmsSignedPubKey::read_from_prefix(
+            image.get(offset + (level - 1) * size_of::<ImageLmsSignedPubKey>()..)?,
+        )?;
+        if !lms_sig_valid(&lms_hss_message(&signed.pub_key), &pub_key, &signed.sig) {
+            return None;
+        }
+        pub_key = signed.pub_key;
+    }
+    Some(pub_key)
+}
+
+fn verify_signatures(
+    image: &[u8],
+    manifest: &ImageManifest,
+    fuses: &Fuses,
+    hss_offset: Option<usize>,
+) -> Result<(), &'static str> {
+    let preamble = &manifest.preamble;
+
+    // The vendor signs the header up to the owner data, which is last
+    let header = manifest.header.as_bytes();
+    let vendor_digest = sha384(&header[..header.len() - size_of::<OwnerSignedData>()]);
+    let owner_digest = sha384(header);
+
+    let vendor_ecc_key =
+        &preamble.vendor_pub_keys.ecc_pub_keys[preamble.vendor_ecc_pub_key_idx as usize];
+    check(
+        ecc_sig_valid(
+            &vendor_digest,
+            vendor_ecc_key,
+            &preamble.vendor_sigs.ecc_sig,
+        ),
+        "vendor ECC signature",
+    )?;
+    check(
+        ecc_sig_valid(
+            &owner_digest,
+            &preamble.owner_pub_keys.ecc_pub_key,
+            &preamble.owner_sigs.ecc_sig,
+        ),
+        "owner ECC signature",
+    )?;
+
+    if fuses.lms_verify {
+        // The marker precedes the vendor and then the owner signed keys
+        let vendor_offset = hss_offset.unwrap_or_default() + size_of::<u32>();
+        let owner_offset =
+            vendor_offset + (LMS_HSS_MAX_LEVELS as usize - 1) * size_of::<ImageLmsSignedPubKey>();
+
+        let vendor_lms_key = hss_signing_key(
+            image,
+            &preamble.vendor_pub_keys.lms_pub_keys[preamble.vendor_lms_pub_key_idx as usize],
+            preamble.vendor_lms_hss_levels,
+            vendor_offset,
+        )
+        .ok_or("vendor HSS signature")?;
+        check(
+            lms_sig_valid(
+                &vendor_digest,
+                &vendor_lms_key,
+                &preamble.vendor_sigs.lms_sig,
+            ),
+            "vendor LMS signature",
+        )?;
+        let owner_lms_key = hss_signing_key(
+            image,
+            &preamble.owner_pub_keys.lms_pub_key,
+            preamble.owner_lms_hss_levels,
+            owner_offset,
+        )
+        .ok_or("owner HSS signature")?;
+        check(
+            lms_sig_valid(&owner_digest, &owner_lms_key, &preamble.owner_sigs.lms_sig),
+            "owner LMS signature",
+        )?;
+    }
+    Ok(())
+}
+
+/// Check the TOC and return the component TOC entries
+fn verify_toc(image: &[u8], manifest: &ImageManifest) -> Result<Vec<ImageTocEntry>, &'static str> {
+    let header = &manifest.header;
+    check(
+        (FW_TOC_ENTRY_COUNT..=MAX_TOC_ENTRY_COUNT).contains(&header.toc_len),
+        "TOC length",
+    )?;
+
+    // The FMC and runtime entries end the manifest and the component
+    // entries follow it; the TOC digest covers all of them.
+    let entry_size = size_of::<ImageTocEntry>();
+    let toc_start = size_of::<ImageManifest>() - FW_TOC_ENTRY_COUNT as usize * entry_size;
+    let toc_end = toc_start + header.toc_len as usize * entry_size;
+    let toc = image
+        .get(toc_start..toc_end)
+        .ok_or("image smaller than TOC")?;
+    check(sha384(toc) == header.toc_digest, "TOC digest")?;
+    let components: Vec<ImageTocEntry> = toc[FW_TOC_ENTRY_COUNT as usize * entry_size..]
+        .chunks(entry_size)
+        .map(|entry| ImageTocEntry::read_from(entry).unwrap())
+        .collect();
+
+    let fmc = &manifest.fmc;
+    let runtime = &manifest.runtime;
+    check(fmc.size != 0, "FMC size")?;
+    check(runtime.size != 0, "runtime size")?;
+
+    let image_len = toc_end as u64
+        + fmc.size as u64
+        + runtime.size as u64
+        + components.iter().map(|c| c.size as u64).sum::<u64>();
+    check(image_len <= image.len() as u64, "image length")?;
+
+    // The FMC precedes the runtime in the image
+    let fmc_end = fmc.offset as u64 + fmc.size as u64;
+    let runtime_end = runtime.offset as u64 + runtime.size as u64;
+    check(
+        fmc_end <= u32::MAX as u64 && runtime_end <= u32::MAX as u64,
+        "TOC offset overflow",
+    )?;
+    check(fmc_end <= runtime.offset as u64, "FMC before runtime")?;
+
+    // The FMC and runtime don't overlap in ICCM
+    let fmc_load_end = fmc.load_addr as u64 + fmc.size as u64;
+    let runtime_load_end = runtime.load_addr as u64 + runtime.size as u64;
+    check(fmc_load_end <= 1 << 32, "FMC load address overflow")?;
+    check(runtime_load_end <= 1 << 32, "runtime load address overflow")?;
+    check(
+        fmc_load_end <= runtime.load_addr as u64 || runtime_load_end <= fmc.load_addr as u64,
+        "FMC and runtime load addresses overlap",
+    )?;
+
+    Ok(components)
+}
+
+/// Check an executable and return its digest
+fn verify_exe(
+    image: &[u8],
+    toc: &ImageTocEntry,
+    svn_limits: Option<(u32, u32)>,
+) -> Result<ImageDigest, &'static str> {
+    let digest = sha384(section(image, toc.offset, toc.size).ok_or("executable out of bounds")?);
+    check(digest == toc.digest, "executable digest")?;
+    check(in_iccm(toc.load_addr, toc.size), "executable load address")?;
+    check(toc.load_addr % 4 == 0, "executable load address alignment")?;
+    check(in_iccm(toc.entry_point, 1), "executable entry point")?;
+    check(toc.entry_point % 4 == 0, "executable entry point alignment")?;
+    if let Some((max_svn, fuse_svn)) = svn_limits {
+        check(toc.svn <= max_svn, "SVN above maximum")?;
+        check(toc.svn >= fuse_svn, "SVN below fuses")?;
+    }
+    Ok(digest)
+}
+
+fn verify_components(
+    image: &[u8],
+    manifest: &ImageManifest,
+    components: &[ImageTocEntry],
+) -> Result<(), &'static str> {
+    if components.is_empty() {
+        return Ok(());
+    }
+
+    // Component TOC entries sit between the manifest and the FMC, and the
+    // contents follow the runtime in TOC order
+    let toc_end = size_of::<ImageManifest>() + components.len() * size_of::<ImageTocEntry>();
+    check(manifest.fmc.offset as usize == toc_end, "FMC follows TOC")?;
+    let mut offset = manifest.runtime.offset + manifest.runtime.size;
+
+    let mut loaded = vec![
+        (manifest.fmc.load_addr as u64, manifest.fmc.size as u64),
+        (
+            manifest.runtime.load_addr as u64,
+            manifest.runtime.size as u64,
+        ),
+    ];
+    for (i, component) in components.iter().enumerate() {
+        check(
+            component.id != ImageTocEntryId::Fmc as u32
+                && component.id != ImageTocEntryId::Runtime as u32,
+            "component id reserved",
+        )?;
+        check(
+            components[..i].iter().all(|c| c.id != component.id),
+            "component id unique",
+        )?;
+
+        let executable = match component.r#type {
+            t if t == ImageTocEntryType::Executable as u32 => true,
+            t if t == ImageTocEntryType::Data as u32 => false,
+            _ => return Err("component type"),
+        };
+        check(
+            component.size != 0 && component.size % 4 == 0,
+            "component size",
+        )?;
+
+        check(component.offset == offset, "component offset")?;
+        let contents =
+            section(image, component.offset, component.size).ok_or("component out of bounds")?;
+        offset = component.offset + component.size;
+
+        let load = component.load_addr as u64;
+        let load_end = load + component.size as u64;
+        check(
+            load_end <= u32::MAX as u64,
+            "component load address overflow",
+        )?;
+        check(
+            component.load_addr % 4 == 0 && in_iccm(component.load_addr, component.size),
+            "component load address",
+        )?;
+        check(
+            loaded
+                .iter()
+                .all(|&(addr, size)| load_end <= addr || addr + size <= load),
+            "component load address overlap",
+        )?;
+        loaded.push((load, component.size as u64));
+
+        if executable {
+            check(
+                (load..load_end).contains(&(component.entry_point as u64))
+                    && component.entry_point % 4 == 0,
+                "component entry point",
+            )?;
+        }
+
+        check(sha384(contents) == component.digest, "component digest")?;
+    }
+    Ok(())
+}
@@ -13,12 +13,16 @@ Abstract:
 --*/
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "std")]
+mod report;
 mod verifier;
 
 use caliptra_drivers::*;
 use caliptra_image_types::*;
 use core::ops::Range;
 
+#[cfg(feature = "std")]
+pub use report::{VerificationFailure, VerificationReport};
 pub use verifier::ImageVerifier;
 
 pub const MAX_RUNTIME_SVN: u32 = 128;
@@ -75,6 +79,27 @@ pub struct ImageVerificationLogInfo {
     pub rt_log_info: ImageSvnLogInfo,
 }
 
+/// Digests of the vendor and owner ML-DSA-87 public keys
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mldsa87PubKeyDigests {
+    /// Vendor ML-DSA-87 public key digest
+    pub vendor: ImageDigest,
+
+    /// Owner ML-DSA-87 public key digest
+    pub owner: ImageDigest,
+}
+
+/// LMS public keys that sign the image header, at the bottom of the vendor
+/// and owner HSS chains
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LmsHssPubKeys {
+    /// Vendor LMS public key
+    pub vendor: ImageLmsPublicKey,
+
+    /// Owner LMS public key
+    pub owner: ImageLmsPublicKey,
+}
+
 /// Verified image information
 #[derive(Default, Debug)]
 pub struct ImageVerificationInfo {
@@ -96,6 +121,19 @@ pub struct ImageVerificationInfo {
     /// Runtime
     pub runtime: ImageVerificationExeInfo,
 
+    /// Number of verified components
+    pub component_count: u32,
+
+    /// Digests of the ML-DSA-87 public keys bound to the public key digests,
+    /// if the fuses require ML-DSA verification
+    #[cfg(feature = "mldsa")]
+    pub mldsa_pub_key_digests: Option<Mldsa87PubKeyDigests>,
+
+    /// LMS public keys at the bottom of the HSS chains, if LMS verification
+    /// is enabled and the vendor or owner uses more than one HSS level
+    #[cfg(feature = "lms-hss")]
+    pub lms_hss_pub_keys: Option<LmsHssPubKeys>,
+
     /// Information Returned To Be Logged
     pub log_info: ImageVerificationLogInfo,
 }
@@ -105,6 +143,14 @@ pub trait ImageVerificationEnv {
     /// Calculate SHA-384 Digest
     fn sha384_digest(&mut self, offset: u32, len: u32) -> CaliptraResult<ImageDigest>;
 
+    /// Calculate SHA-384 Digest of the image data followed by `suffix`
+    fn sha384_digest_with_suffix(
+        &mut self,
+        offset: u32,
+        len: u32,
+        suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest>;
+
     /// Perform ECC-384 Verification
     fn ecc384_verify(
         &mut self,
@@ -121,6 +167,18 @@ pub trait ImageVerificationEnv {
         sig: &ImageLmsSignature,
     ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>>;
 
+    /// Perform ML-DSA-87 Verification of the public key and signature stored
+    /// in the image at the given offsets
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key_offset: u32,
+        sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result>;
+
+    /// Read the HSS signed public key stored in the image at the given offset
+    fn lms_hss_signed_pub_key(&self, offset: u32) -> CaliptraResult<ImageLmsSignedPubKey>;
+
     /// Get Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest;
 
@@ -148,6 +206,14 @@ pub trait ImageVerificationEnv {
     // Get the owner key digest saved on cold boot in data vault
     fn owner_pub_key_digest_dv(&self) -> ImageDigest;
 
+    // Get the ML-DSA-87 public key digests verified on cold boot, if the
+    // fuses require ML-DSA verification
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests>;
+
+    // Get the LMS public keys at the bottom of the HSS chains verified on
+    // cold boot, for images without the HSS extension
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys>;
+
     // Save the fmc digest in the data vault on cold boot
     fn get_fmc_digest_dv(&self) -> ImageDigest;
 
@@ -163,6 +229,12 @@ pub trait ImageVerificationEnv {
     // LMS Verification enabled
     fn lms_verify_enabled(&self) -> bool;
 
+    // LMS HSS Verification enabled
+    fn lms_hss_verify_enabled(&self) -> bool;
+
+    // ML-DSA Verification enabled
+    fn mldsa87_verify_enabled(&self) -> bool;
+
     // Set the extended error code
     fn set_fw_extended_error(&mut self, err: u32);
 }
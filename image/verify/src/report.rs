@@ -0,0 +1,1596 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    report.rs
+
+Abstract:
+
+    This file implements the host side verification report, which runs all
+    independent image checks and records every failure.
+
+--*/
+
+use core::fmt;
+
+use crate::*;
+use caliptra_drivers::*;
+use caliptra_image_types::*;
+use memoffset::offset_of;
+
+const ZERO_DIGEST: ImageDigest = [0u32; SHA384_DIGEST_WORD_SIZE];
+
+/// A failed verification check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationFailure {
+    /// Name of the check
+    pub check: &'static str,
+
+    /// Error `ImageVerifier::verify` returns when this check fails first
+    pub error: CaliptraError,
+
+    /// Expected value
+    pub expected: String,
+
+    /// Actual value
+    pub actual: String,
+}
+
+/// Result of running all verification checks on an image
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Failed checks, in the order `ImageVerifier::verify` runs them, so the
+    /// first failure is the error `verify` returns
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl VerificationReport {
+    /// Whether all checks passed
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Whether `error` is among the failures
+    pub fn contains(&self, error: CaliptraError) -> bool {
+        self.failures.iter().any(|f| f.error == error)
+    }
+
+    /// Record a failure of `check` unless `ok`
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `ok`
+    fn expect(
+        &mut self,
+        ok: bool,
+        check: &'static str,
+        error: CaliptraError,
+        expected: impl fmt::Display,
+        actual: impl fmt::Display,
+    ) -> bool {
+        if !ok {
+            self.failures.push(VerificationFailure {
+                check,
+                error,
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+        ok
+    }
+
+    /// Record the failure of an environment operation needed by `check`
+    fn env_failure(&mut self, check: &'static str, error: CaliptraError, env_err: CaliptraError) {
+        self.failures.push(VerificationFailure {
+            check,
+            error,
+            expected: "success".into(),
+            actual: format!("error 0x{:08x}", u32::from(env_err)),
+        });
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return writeln!(f, "All checks passed");
+        }
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "{} failed (error 0x{:08x}): expected {}, actual {}",
+                failure.check,
+                u32::from(failure.error),
+                failure.expected,
+                failure.actual
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
+    /// Run all independent checks of `verify` and report every failure
+    ///
+    /// Checks that depend on a failed check, such as a signature made with an
+    /// out of range key index, are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest`      - Image Manifest
+    /// * `components`    - Component TOC entries, as stored after the manifest
+    /// * `img_bundle_sz` - Image bundle size
+    /// * `reason`        - Reset Reason
+    ///
+    /// # Returns
+    ///
+    /// * `VerificationReport` - Failed checks
+    pub fn verify_report(
+        &mut self,
+        manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &[ImageTocEntry],
+        img_bundle_sz: u32,
+        reason: ResetReason,
+    ) -> VerificationReport {
+        let mut report = VerificationReport::default();
+
+        report.expect(
+            manifest.marker == MANIFEST_MARKER,
+            "manifest marker",
+            CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH,
+            format_args!("0x{MANIFEST_MARKER:08x}"),
+            format_args!("0x{:08x}", manifest.marker),
+        );
+        report.expect(
+            manifest.size as usize == core::mem::size_of::<ImageManifest>(),
+            "manifest size",
+            CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_SIZE_MISMATCH,
+            core::mem::size_of::<ImageManifest>(),
+            manifest.size,
+        );
+
+        #[cfg(feature = "mldsa")]
+        let mldsa_pub_keys = self.report_mldsa_pub_keys(&mut report, manifest, img_bundle_sz);
+        #[cfg(not(feature = "mldsa"))]
+        let mldsa_pub_keys: Option<(Option<u32>, Option<Mldsa87PubKeyDigests>)> =
+            Some((None, None));
+        if let Some((_, mldsa_pub_key_digests)) = mldsa_pub_keys {
+            self.report_pub_key_digests(
+                &mut report,
+                reason,
+                &manifest.preamble,
+                mldsa_pub_key_digests.as_ref(),
+            );
+        }
+        self.report_key_indices(&mut report, manifest, reason);
+        #[cfg(feature = "lms-hss")]
+        let hss_ext_offset = self.report_hss_extension(&mut report, manifest, img_bundle_sz);
+        #[cfg(not(feature = "lms-hss"))]
+        let hss_ext_offset = None;
+        self.report_signatures(&mut report, manifest, hss_ext_offset);
+        let pqc_ext_offset = mldsa_pub_keys.and_then(|(offset, _)| offset);
+        #[cfg(feature = "mldsa")]
+        if let Some(ext_offset) = pqc_ext_offset {
+            self.report_mldsa_sigs(&mut report, ext_offset);
+        }
+        // The firmware sections must end before the PQC and HSS extensions
+        let img_bundle_sz = pqc_ext_offset.or(hss_ext_offset).unwrap_or(img_bundle_sz);
+        #[cfg(not(feature = "components"))]
+        let components: &[ImageTocEntry] = &[];
+        self.report_toc(&mut report, manifest, components, img_bundle_sz);
+        self.report_exe(&mut report, &manifest.fmc, true, reason);
+        self.report_exe(&mut report, &manifest.runtime, false, reason);
+        #[cfg(feature = "components")]
+        self.report_components(&mut report, manifest, components);
+
+        report
+    }
+
+    fn digest(
+        &mut self,
+        report: &mut VerificationReport,
+        range: core::ops::Range<u32>,
+        check: &'static str,
+        error: CaliptraError,
+    ) -> Option<ImageDigest> {
+        match self.env.sha384_digest(range.start, range.len() as u32) {
+            Ok(digest) => Some(digest),
+            Err(err) => {
+                report.env_failure(check, error, err);
+                None
+            }
+        }
+    }
+
+    fn pub_keys_digest_or_report(
+        &mut self,
+        report: &mut VerificationReport,
+        range: core::ops::Range<u32>,
+        lms_hss_levels: u32,
+        mldsa_pub_key_digest: Option<&ImageDigest>,
+        check: &'static str,
+        error: CaliptraError,
+    ) -> Option<ImageDigest> {
+        match self.pub_keys_digest(range, lms_hss_levels, mldsa_pub_key_digest) {
+            Ok(digest) => Some(digest),
+            Err(err) => {
+                report.env_failure(check, error, err);
+                None
+            }
+        }
+    }
+
+    /// Check the vendor and owner public key digests; `mldsa_pub_key_digests`
+    /// are the digests of the ML-DSA public keys bound to them, if any.
+    fn report_pub_key_digests(
+        &mut self,
+        report: &mut VerificationReport,
+        reason: ResetReason,
+        preamble: &ImagePreamble,
+        mldsa_pub_key_digests: Option<&Mldsa87PubKeyDigests>,
+    ) {
+        // The vendor public key digest is not checked in the unprovisioned state
+        if self.env.dev_lifecycle() != Lifecycle::Unprovisioned {
+            let expected = self.env.vendor_pub_key_digest();
+            if report.expect(
+                expected != ZERO_DIGEST,
+                "vendor public key digest fuses",
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID,
+                "non-zero digest",
+                hex(&expected),
+            ) {
+                if let Some(actual) = self.pub_keys_digest_or_report(
+                    report,
+                    ImageManifest::vendor_pub_keys_range(),
+                    preamble.vendor_lms_hss_levels,
+                    mldsa_pub_key_digests.map(|d| &d.vendor),
+                    "vendor public key digest",
+                    CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_FAILURE,
+                ) {
+                    report.expect(
+                        expected == actual,
+                        "vendor public key digest",
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH,
+                        hex(&expected),
+                        hex(&actual),
+                    );
+                }
+            }
+        }
+
+        let Some(actual) = self.pub_keys_digest_or_report(
+            report,
+            ImageManifest::owner_pub_key_range(),
+            preamble.owner_lms_hss_levels,
+            mldsa_pub_key_digests.map(|d| &d.owner),
+            "owner public key digest",
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_FAILURE,
+        ) else {
+            return;
+        };
+        let fuses = self.env.owner_pub_key_digest_fuses();
+        report.expect(
+            fuses == ZERO_DIGEST || fuses == actual,
+            "owner public key digest",
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH,
+            hex(&fuses),
+            hex(&actual),
+        );
+        if reason == ResetReason::UpdateReset {
+            let cold_boot = self.env.owner_pub_key_digest_dv();
+            report.expect(
+                cold_boot == actual,
+                "owner public key digest of cold boot",
+                CaliptraError::IMAGE_VERIFIER_ERR_UPDATE_RESET_OWNER_DIGEST_FAILURE,
+                hex(&cold_boot),
+                hex(&actual),
+            );
+        }
+    }
+
+    fn report_key_indices(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        reason: ResetReason,
+    ) {
+        let preamble = &manifest.preamble;
+
+        let idx = preamble.vendor_ecc_pub_key_idx;
+        if report.expect(
+            idx < VENDOR_ECC_KEY_COUNT,
+            "vendor ECC public key index",
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_OUT_OF_BOUNDS,
+            format_args!("< {VENDOR_ECC_KEY_COUNT}"),
+            idx,
+        ) {
+            // The last key is never revoked
+            let revocation = self.env.vendor_ecc_pub_key_revocation();
+            report.expect(
+                idx == VENDOR_ECC_KEY_COUNT - 1
+                    || !revocation
+                        .contains(VendorPubKeyRevocation::from_bits_truncate(0x01u32 << idx)),
+                "vendor ECC public key revocation",
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_REVOKED,
+                format_args!("key {idx} not revoked"),
+                format_args!("revocation fuses 0x{:08x}", revocation.bits()),
+            );
+        }
+        if reason == ResetReason::UpdateReset {
+            let cold_boot = self.env.vendor_ecc_pub_key_idx_dv();
+            report.expect(
+                cold_boot == idx,
+                "vendor ECC public key index of cold boot",
+                CaliptraError::IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_ECC_PUB_KEY_IDX_MISMATCH,
+                cold_boot,
+                idx,
+            );
+        }
+
+        if !self.env.lms_verify_enabled() {
+            return;
+        }
+        let idx = preamble.vendor_lms_pub_key_idx;
+        if report.expect(
+            idx < VENDOR_LMS_KEY_COUNT,
+            "vendor LMS public key index",
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_OUT_OF_BOUNDS,
+            format_args!("< {VENDOR_LMS_KEY_COUNT}"),
+            idx,
+        ) {
+            let revocation = self.env.vendor_lms_pub_key_revocation();
+            report.expect(
+                idx == VENDOR_LMS_KEY_COUNT - 1 || revocation & (0x01u32 << idx) == 0,
+                "vendor LMS public key revocation",
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_REVOKED,
+                format_args!("key {idx} not revoked"),
+                format_args!("revocation fuses 0x{revocation:08x}"),
+            );
+        }
+        if reason == ResetReason::UpdateReset {
+            let cold_boot = self.env.vendor_lms_pub_key_idx_dv();
+            report.expect(
+                cold_boot == idx,
+                "vendor LMS public key index of cold boot",
+                CaliptraError::IMAGE_VERIFIER_ERR_UPDATE_RESET_VENDOR_LMS_PUB_KEY_IDX_MISMATCH,
+                cold_boot,
+                idx,
+            );
+        }
+    }
+
+    /// Check the LMS HSS levels and locate the HSS extension.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<u32>` - Offset of the HSS extension, if a signer uses more
+    ///   than one level and the extension is available for verification
+    #[cfg(feature = "lms-hss")]
+    fn report_hss_extension(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        img_bundle_sz: u32,
+    ) -> Option<u32> {
+        if !self.env.lms_verify_enabled() {
+            return None;
+        }
+        let preamble = &manifest.preamble;
+        let vendor_levels = preamble.vendor_lms_hss_levels;
+        let vendor_ok = report.expect(
+            vendor_levels <= LMS_HSS_MAX_LEVELS,
+            "vendor LMS HSS levels",
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_LEVELS_INVALID,
+            format_args!("<= {LMS_HSS_MAX_LEVELS}"),
+            vendor_levels,
+        );
+        let owner_levels = preamble.owner_lms_hss_levels;
+        let owner_ok = report.expect(
+            owner_levels <= LMS_HSS_MAX_LEVELS,
+            "owner LMS HSS levels",
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_LEVELS_INVALID,
+            format_args!("<= {LMS_HSS_MAX_LEVELS}"),
+            owner_levels,
+        );
+        if !vendor_ok || !owner_ok || (vendor_levels <= 1 && owner_levels <= 1) {
+            return None;
+        }
+        if !self.env.lms_hss_verify_enabled() {
+            // Images without the extension use the keys verified on cold boot
+            report.expect(
+                self.env.lms_hss_pub_keys_cold_boot().is_some(),
+                "HSS extension",
+                CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING,
+                "HSS keys verified on cold boot",
+                "none",
+            );
+            return None;
+        }
+
+        // The HSS extension occupies the end of the image bundle
+        let ext_size = core::mem::size_of::<ImageHssExtension>() as u32;
+        let min_size = core::mem::size_of::<ImageManifest>() as u32 + ext_size;
+        if !report.expect(
+            img_bundle_sz >= min_size,
+            "HSS extension",
+            CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING,
+            format_args!(">= {min_size}"),
+            img_bundle_sz,
+        ) {
+            return None;
+        }
+        Some(img_bundle_sz - ext_size)
+    }
+
+    /// Check the header signatures and the key indices encoded in the
+    /// header, in the order `verify_header` checks them
+    fn report_signatures(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        hss_ext_offset: Option<u32>,
+    ) {
+        let preamble = &manifest.preamble;
+        let range = ImageManifest::header_range();
+        let vendor_header_len = offset_of!(ImageHeader, owner_data) as u32;
+        let check = "header digest";
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_HEADER_DIGEST_FAILURE;
+        let digest_vendor = self.digest(
+            report,
+            range.start..range.start + vendor_header_len,
+            check,
+            err,
+        );
+        let digest_owner = self.digest(report, range, check, err);
+
+        // Images without the HSS extension are verified with the keys at the
+        // bottom of the HSS chains verified on cold boot
+        let lms_verify = self.env.lms_verify_enabled();
+        #[cfg(feature = "lms-hss")]
+        let cold_boot_keys = if lms_verify
+            && hss_ext_offset.is_none()
+            && (preamble.vendor_lms_hss_levels > 1 || preamble.owner_lms_hss_levels > 1)
+        {
+            self.env.lms_hss_pub_keys_cold_boot()
+        } else {
+            None
+        };
+        #[cfg(not(feature = "lms-hss"))]
+        let cold_boot_keys: Option<LmsHssPubKeys> = None;
+
+        // Otherwise walk the HSS chains down to the LMS keys that signed the
+        // header
+        let vendor_lms_pub_key = preamble
+            .vendor_pub_keys
+            .lms_pub_keys
+            .get(preamble.vendor_lms_pub_key_idx as usize)
+            .filter(|_| lms_verify && cold_boot_keys.is_none())
+            .and_then(|pub_key| {
+                self.report_lms_signing_key(
+                    report,
+                    pub_key,
+                    preamble.vendor_lms_hss_levels,
+                    hss_ext_offset.map(|offset| {
+                        offset + offset_of!(ImageHssExtension, vendor_signed_pub_keys) as u32
+                    }),
+                    "vendor LMS HSS signature",
+                    [
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_VERIFY_FAILURE,
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_SIGNATURE_INVALID,
+                    ],
+                )
+            })
+            .or(cold_boot_keys.map(|keys| keys.vendor));
+        let owner_lms_pub_key = if let Some(keys) = cold_boot_keys {
+            Some(keys.owner)
+        } else if lms_verify {
+            self.report_lms_signing_key(
+                report,
+                &preamble.owner_pub_keys.lms_pub_key,
+                preamble.owner_lms_hss_levels,
+                hss_ext_offset.map(|offset| {
+                    offset + offset_of!(ImageHssExtension, owner_signed_pub_keys) as u32
+                }),
+                "owner LMS HSS signature",
+                [
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_VERIFY_FAILURE,
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_SIGNATURE_INVALID,
+                ],
+            )
+        } else {
+            None
+        };
+
+        if let Some(digest_vendor) = digest_vendor {
+            if let Some(pub_key) = preamble
+                .vendor_pub_keys
+                .ecc_pub_keys
+                .get(preamble.vendor_ecc_pub_key_idx as usize)
+            {
+                self.report_ecc_sig(
+                    report,
+                    &digest_vendor,
+                    pub_key,
+                    &preamble.vendor_sigs.ecc_sig,
+                    "vendor ECC signature",
+                    [
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_INVALID_ARG,
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID_ARG,
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_VERIFY_FAILURE,
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_SIGNATURE_INVALID,
+                    ],
+                );
+            }
+            if let Some(pub_key) = vendor_lms_pub_key {
+                self.report_lms_sig(
+                    report,
+                    &digest_vendor,
+                    &pub_key,
+                    &preamble.vendor_sigs.lms_sig,
+                    "vendor LMS signature",
+                    [
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_VERIFY_FAILURE,
+                        CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_SIGNATURE_INVALID,
+                    ],
+                );
+            }
+        }
+
+        // The key indices used to verify the vendor signature are encoded in
+        // the header
+        let header = &manifest.header;
+        report.expect(
+            header.vendor_ecc_pub_key_idx == preamble.vendor_ecc_pub_key_idx,
+            "header vendor ECC public key index",
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_MISMATCH,
+            preamble.vendor_ecc_pub_key_idx,
+            header.vendor_ecc_pub_key_idx,
+        );
+        if lms_verify {
+            report.expect(
+                header.vendor_lms_pub_key_idx == preamble.vendor_lms_pub_key_idx,
+                "header vendor LMS public key index",
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_PUB_KEY_INDEX_MISMATCH,
+                preamble.vendor_lms_pub_key_idx,
+                header.vendor_lms_pub_key_idx,
+            );
+        }
+
+        let Some(digest_owner) = digest_owner else {
+            return;
+        };
+        self.report_ecc_sig(
+            report,
+            &digest_owner,
+            &preamble.owner_pub_keys.ecc_pub_key,
+            &preamble.owner_sigs.ecc_sig,
+            "owner ECC signature",
+            [
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_ECC_PUB_KEY_INVALID_ARG,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID_ARG,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_ECC_VERIFY_FAILURE,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID,
+            ],
+        );
+        if let Some(pub_key) = owner_lms_pub_key {
+            self.report_lms_sig(
+                report,
+                &digest_owner,
+                &pub_key,
+                &preamble.owner_sigs.lms_sig,
+                "owner LMS signature",
+                [
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_VERIFY_FAILURE,
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_SIGNATURE_INVALID,
+                ],
+            );
+        }
+    }
+
+    /// Find the LMS public key that signed the header: `pub_key` for a single
+    /// tree, otherwise the last level of the HSS chain stored at
+    /// `chain_offset`. `errors` are the verify failure and signature mismatch
+    /// errors of the chain.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<ImageLmsPublicKey>` - Signing key, if the chain can be checked
+    ///   and is valid
+    fn report_lms_signing_key(
+        &mut self,
+        report: &mut VerificationReport,
+        pub_key: &ImageLmsPublicKey,
+        levels: u32,
+        chain_offset: Option<u32>,
+        check: &'static str,
+        errors: [CaliptraError; 2],
+    ) -> Option<ImageLmsPublicKey> {
+        // Without HSS support the preamble key signs the header
+        if levels <= 1 || cfg!(not(feature = "lms-hss")) {
+            return Some(*pub_key);
+        }
+        let chain_offset = chain_offset?;
+        let mut pub_key = *pub_key;
+        for level in 1..levels {
+            let offset =
+                chain_offset + (level - 1) * core::mem::size_of::<ImageLmsSignedPubKey>() as u32;
+            let signed = match self.env.lms_hss_signed_pub_key(offset) {
+                Ok(signed) => signed,
+                Err(err) => {
+                    report.env_failure(check, errors[0], err);
+                    return None;
+                }
+            };
+            let message = lms_hss_message(&signed.pub_key);
+            if !self.report_lms_sig(report, &message, &pub_key, &signed.sig, check, errors) {
+                return None;
+            }
+            pub_key = signed.pub_key;
+        }
+        Some(pub_key)
+    }
+
+    /// Check an ECC signature; `errors` are the invalid public key, invalid
+    /// signature, verify failure and signature mismatch errors.
+    fn report_ecc_sig(
+        &mut self,
+        report: &mut VerificationReport,
+        digest: &ImageDigest,
+        pub_key: &ImageEccPubKey,
+        sig: &ImageEccSignature,
+        check: &'static str,
+        errors: [CaliptraError; 4],
+    ) {
+        let key_ok = report.expect(
+            pub_key.x != ZERO_DIGEST && pub_key.y != ZERO_DIGEST,
+            check,
+            errors[0],
+            "non-zero public key",
+            format_args!("x {} y {}", hex(&pub_key.x), hex(&pub_key.y)),
+        );
+        let sig_ok = report.expect(
+            sig.r != ZERO_DIGEST && sig.s != ZERO_DIGEST,
+            check,
+            errors[1],
+            "non-zero signature",
+            format_args!("r {} s {}", hex(&sig.r), hex(&sig.s)),
+        );
+        if !key_ok || !sig_ok {
+            return;
+        }
+        match self.env.ecc384_verify(digest, pub_key, sig) {
+            Ok(verify_r) => {
+                report.expect(
+                    verify_r == Array4xN(sig.r),
+                    check,
+                    errors[3],
+                    format_args!("r {}", hex(&sig.r)),
+                    format_args!("r {}", hex(&verify_r.0)),
+                );
+            }
+            Err(err) => report.env_failure(check, errors[2], err),
+        }
+    }
+
+    /// Check an LMS signature; `errors` are the verify failure and signature
+    /// mismatch errors. Returns whether the signature is valid.
+    fn report_lms_sig(
+        &mut self,
+        report: &mut VerificationReport,
+        digest: &ImageDigest,
+        pub_key: &ImageLmsPublicKey,
+        sig: &ImageLmsSignature,
+        check: &'static str,
+        errors: [CaliptraError; 2],
+    ) -> bool {
+        match self.env.lms_verify(digest, pub_key, sig) {
+            Ok(candidate_key) => {
+                let pub_key_digest = HashValue::from(pub_key.digest);
+                report.expect(
+                    candidate_key == pub_key_digest,
+                    check,
+                    errors[1],
+                    format_args!("public key {}", hex(&pub_key_digest.0)),
+                    format_args!("public key {}", hex(&candidate_key.0)),
+                )
+            }
+            Err(err) => {
+                report.env_failure(check, errors[0], err);
+                false
+            }
+        }
+    }
+
+    /// Locate the PQC extension and calculate the digests of its ML-DSA
+    /// public keys. Images without the extension use the digests verified on
+    /// cold boot.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<(Option<u32>, Option<Mldsa87PubKeyDigests>)>` - Offset of the
+    ///   PQC extension, if it is verified, and the ML-DSA public key digests,
+    ///   if the fuses require ML-DSA verification. `None` if the digests are
+    ///   required but unavailable.
+    #[cfg(feature = "mldsa")]
+    fn report_mldsa_pub_keys(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        img_bundle_sz: u32,
+    ) -> Option<(Option<u32>, Option<Mldsa87PubKeyDigests>)> {
+        if !self.env.mldsa87_verify_enabled() {
+            return Some((None, self.env.mldsa87_pub_key_digests_cold_boot()));
+        }
+
+        // The PQC extension precedes the HSS extension, if any
+        let preamble = &manifest.preamble;
+        let mut ext_end = img_bundle_sz;
+        if preamble.vendor_lms_hss_levels > 1 || preamble.owner_lms_hss_levels > 1 {
+            ext_end = ext_end.saturating_sub(core::mem::size_of::<ImageHssExtension>() as u32);
+        }
+        let ext_size = core::mem::size_of::<ImagePqcExtension>() as u32;
+        let min_size = core::mem::size_of::<ImageManifest>() as u32 + ext_size;
+        if !report.expect(
+            ext_end >= min_size,
+            "PQC extension",
+            CaliptraError::IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING,
+            format_args!(">= {min_size}"),
+            ext_end,
+        ) {
+            return None;
+        }
+        let ext_offset = ext_end - ext_size;
+
+        let mut digests = [ZERO_DIGEST; 2];
+        for (digest, pub_key_offset) in digests.iter_mut().zip([
+            offset_of!(ImagePqcExtension, vendor_mldsa_pub_key),
+            offset_of!(ImagePqcExtension, owner_mldsa_pub_key),
+        ]) {
+            let pub_key_offset = ext_offset + pub_key_offset as u32;
+            *digest = self.digest(
+                report,
+                pub_key_offset..pub_key_offset + MLDSA87_PUB_KEY_BYTE_SIZE as u32,
+                "ML-DSA public key digest",
+                CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PUB_KEY_DIGEST_FAILURE,
+            )?;
+        }
+        let [vendor, owner] = digests;
+
+        Some((
+            Some(ext_offset),
+            Some(Mldsa87PubKeyDigests { vendor, owner }),
+        ))
+    }
+
+    /// Check the ML-DSA signatures of the PQC extension at `ext_offset`
+    #[cfg(feature = "mldsa")]
+    fn report_mldsa_sigs(&mut self, report: &mut VerificationReport, ext_offset: u32) {
+        let range = ImageManifest::header_range();
+        let vendor_header_len = offset_of!(ImageHeader, owner_data) as u32;
+        let check = "header digest";
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_HEADER_DIGEST_FAILURE;
+        let digest_vendor = self.digest(
+            report,
+            range.start..range.start + vendor_header_len,
+            check,
+            err,
+        );
+        let digest_owner = self.digest(report, range, check, err);
+
+        for (digest, pub_key_offset, sig_offset, check, errors) in [
+            (
+                digest_vendor,
+                offset_of!(ImagePqcExtension, vendor_mldsa_pub_key),
+                offset_of!(ImagePqcExtension, vendor_mldsa_sig),
+                "vendor ML-DSA signature",
+                [
+                    CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE,
+                    CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID,
+                ],
+            ),
+            (
+                digest_owner,
+                offset_of!(ImagePqcExtension, owner_mldsa_pub_key),
+                offset_of!(ImagePqcExtension, owner_mldsa_sig),
+                "owner ML-DSA signature",
+                [
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_VERIFY_FAILURE,
+                    CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID,
+                ],
+            ),
+        ] {
+            let Some(digest) = digest else {
+                continue;
+            };
+            let pub_key_offset = ext_offset + pub_key_offset as u32;
+            let sig_offset = ext_offset + sig_offset as u32;
+            match self.env.mldsa87_verify(&digest, pub_key_offset, sig_offset) {
+                Ok(result) => {
+                    report.expect(
+                        result == Mldsa87Result::Success,
+                        check,
+                        errors[1],
+                        "valid signature",
+                        "invalid signature",
+                    );
+                }
+                Err(err) => report.env_failure(check, errors[0], err),
+            }
+        }
+    }
+
+    fn report_toc(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        components: &[ImageTocEntry],
+        img_bundle_sz: u32,
+    ) {
+        let toc_len = manifest.header.toc_len;
+        let component_count = components.len() as u32;
+        #[cfg(feature = "components")]
+        let max_toc_len = MAX_TOC_ENTRY_COUNT;
+        #[cfg(not(feature = "components"))]
+        let max_toc_len = FW_TOC_ENTRY_COUNT;
+        if report.expect(
+            (FW_TOC_ENTRY_COUNT..=max_toc_len).contains(&toc_len),
+            "TOC entry count",
+            CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID,
+            format_args!("{FW_TOC_ENTRY_COUNT}..={max_toc_len}"),
+            toc_len,
+        ) {
+            report.expect(
+                toc_len - FW_TOC_ENTRY_COUNT == component_count,
+                "component count",
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_COUNT_MISMATCH,
+                toc_len - FW_TOC_ENTRY_COUNT,
+                component_count,
+            );
+        }
+
+        let range = ImageManifest::toc_range_with_components(component_count);
+        if let Some(actual) = self.digest(
+            report,
+            range.clone(),
+            "TOC digest",
+            CaliptraError::IMAGE_VERIFIER_ERR_TOC_DIGEST_FAILURE,
+        ) {
+            report.expect(
+                manifest.header.toc_digest == actual,
+                "TOC digest",
+                CaliptraError::IMAGE_VERIFIER_ERR_TOC_DIGEST_MISMATCH,
+                hex(&manifest.header.toc_digest),
+                hex(&actual),
+            );
+        }
+
+        let fmc = &manifest.fmc;
+        let runtime = &manifest.runtime;
+        let fmc_ok = report.expect(
+            fmc.image_size() != 0,
+            "FMC size",
+            CaliptraError::IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO,
+            "> 0",
+            fmc.image_size(),
+        );
+        let runtime_ok = report.expect(
+            runtime.image_size() != 0,
+            "runtime size",
+            CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO,
+            "> 0",
+            runtime.image_size(),
+        );
+
+        let img_len: u64 = range.end as u64
+            + fmc.image_size() as u64
+            + runtime.image_size() as u64
+            + components
+                .iter()
+                .map(|c| c.image_size() as u64)
+                .sum::<u64>();
+        report.expect(
+            img_len <= img_bundle_sz.into(),
+            "image length",
+            CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE,
+            format_args!("<= {img_bundle_sz}"),
+            img_len,
+        );
+
+        let mut image_range = |toc: &ImageTocEntry, check: &'static str| match toc.image_range() {
+            Ok(range) => Some(range),
+            Err(err) => {
+                report.expect(
+                    false,
+                    check,
+                    err,
+                    "no overflow",
+                    format_args!("0x{:08x} + {}", toc.offset, toc.size),
+                );
+                None
+            }
+        };
+        let fmc_range = image_range(fmc, "FMC offset");
+        let runtime_range = image_range(runtime, "runtime offset");
+        if let (Some(fmc_range), Some(runtime_range)) = (fmc_range, runtime_range) {
+            let disjoint = report.expect(
+                fmc_range.start >= runtime_range.end || fmc_range.end <= runtime_range.start,
+                "FMC and runtime offsets",
+                CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_OVERLAP,
+                "non-overlapping",
+                format_args!("FMC {fmc_range:x?} runtime {runtime_range:x?}"),
+            );
+            if disjoint {
+                report.expect(
+                    fmc_range.end <= runtime_range.start,
+                    "FMC and runtime order",
+                    CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_INCORRECT_ORDER,
+                    "FMC before runtime",
+                    format_args!("FMC {fmc_range:x?} runtime {runtime_range:x?}"),
+                );
+            }
+        }
+
+        if !fmc_ok || !runtime_ok {
+            return;
+        }
+        let fmc_end = fmc.load_addr.checked_add(fmc.image_size() - 1);
+        report.expect(
+            fmc_end.is_some(),
+            "FMC load address",
+            CaliptraError::IMAGE_VERIFIER_ERR_FMC_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW,
+            "no overflow",
+            format_args!("0x{:08x} + {}", fmc.load_addr, fmc.image_size()),
+        );
+        let runtime_end = runtime.load_addr.checked_add(runtime.image_size() - 1);
+        report.expect(
+            runtime_end.is_some(),
+            "runtime load address",
+            CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDRESS_IMAGE_SIZE_ARITHMETIC_OVERFLOW,
+            "no overflow",
+            format_args!("0x{:08x} + {}", runtime.load_addr, runtime.image_size()),
+        );
+        if let (Some(fmc_end), Some(runtime_end)) = (fmc_end, runtime_end) {
+            report.expect(
+                fmc.load_addr > runtime_end || fmc_end < runtime.load_addr,
+                "FMC and runtime load addresses",
+                CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP,
+                "non-overlapping",
+                format_args!(
+                    "FMC 0x{:08x}..=0x{fmc_end:08x} runtime 0x{:08x}..=0x{runtime_end:08x}",
+                    fmc.load_addr, runtime.load_addr
+                ),
+            );
+        }
+    }
+
+    fn report_exe(
+        &mut self,
+        report: &mut VerificationReport,
+        toc: &ImageTocEntry,
+        is_fmc: bool,
+        reason: ResetReason,
+    ) {
+        use CaliptraError as E;
+        let (name, errors) = if is_fmc {
+            (
+                [
+                    "FMC digest",
+                    "FMC load address",
+                    "FMC entry point",
+                    "FMC SVN",
+                ],
+                [
+                    E::IMAGE_VERIFIER_ERR_FMC_DIGEST_FAILURE,
+                    E::IMAGE_VERIFIER_ERR_FMC_DIGEST_MISMATCH,
+                    E::IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_INVALID,
+                    E::IMAGE_VERIFIER_ERR_FMC_LOAD_ADDR_UNALIGNED,
+                    E::IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_INVALID,
+                    E::IMAGE_VERIFIER_ERR_FMC_ENTRY_POINT_UNALIGNED,
+                    E::IMAGE_VERIFIER_ERR_FMC_SVN_GREATER_THAN_MAX_SUPPORTED,
+                    E::IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_FUSE,
+                ],
+            )
+        } else {
+            (
+                [
+                    "runtime digest",
+                    "runtime load address",
+                    "runtime entry point",
+                    "runtime SVN",
+                ],
+                [
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_FAILURE,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_DIGEST_MISMATCH,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_INVALID,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_LOAD_ADDR_UNALIGNED,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_INVALID,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_ENTRY_POINT_UNALIGNED,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_SVN_GREATER_THAN_MAX_SUPPORTED,
+                    E::IMAGE_VERIFIER_ERR_RUNTIME_SVN_LESS_THAN_FUSE,
+                ],
+            )
+        };
+
+        // The TOC checks report an offset range that overflows
+        let actual = match toc.image_range() {
+            Ok(range) => self.digest(report, range, name[0], errors[0]),
+            Err(_) => None,
+        };
+        if let Some(actual) = actual {
+            report.expect(
+                toc.digest == actual,
+                name[0],
+                errors[1],
+                hex(&toc.digest),
+                hex(&actual),
+            );
+        }
+
+        let iccm = self.env.iccm_range();
+        let load_end = toc.load_addr.checked_add(toc.size.saturating_sub(1));
+        report.expect(
+            iccm.contains(&toc.load_addr) && load_end.map_or(false, |end| iccm.contains(&end)),
+            name[1],
+            errors[2],
+            format_args!("within ICCM {iccm:x?}"),
+            format_args!("0x{:08x} size {}", toc.load_addr, toc.size),
+        );
+        report.expect(
+            toc.load_addr % 4 == 0,
+            name[1],
+            errors[3],
+            "4-byte aligned",
+            format_args!("0x{:08x}", toc.load_addr),
+        );
+        report.expect(
+            iccm.contains(&toc.entry_point),
+            name[2],
+            errors[4],
+            format_args!("within ICCM {iccm:x?}"),
+            format_args!("0x{:08x}", toc.entry_point),
+        );
+        report.expect(
+            toc.entry_point % 4 == 0,
+            name[2],
+            errors[5],
+            "4-byte aligned",
+            format_args!("0x{:08x}", toc.entry_point),
+        );
+
+        // SVNs are not checked when unprovisioned or when anti-rollback is disabled
+        if self.env.dev_lifecycle() != Lifecycle::Unprovisioned && !self.env.anti_rollback_disable()
+        {
+            let (max_svn, fuse_svn) = if is_fmc {
+                (32, self.env.fmc_fuse_svn())
+            } else {
+                (MAX_RUNTIME_SVN, self.env.runtime_fuse_svn())
+            };
+            report.expect(
+                toc.svn <= max_svn,
+                name[3],
+                errors[6],
+                format_args!("<= {max_svn}"),
+                toc.svn,
+            );
+            report.expect(
+                toc.svn >= fuse_svn,
+                name[3],
+                errors[7],
+                format_args!(">= fuse SVN {fuse_svn}"),
+                toc.svn,
+            );
+        }
+
+        if let Some(actual) = actual.filter(|_| is_fmc && reason == ResetReason::UpdateReset) {
+            let cold_boot = self.env.get_fmc_digest_dv();
+            report.expect(
+                cold_boot == actual,
+                "FMC digest of cold boot",
+                E::IMAGE_VERIFIER_ERR_UPDATE_RESET_FMC_DIGEST_MISMATCH,
+                hex(&cold_boot),
+                hex(&actual),
+            );
+        }
+    }
+
+    #[cfg(feature = "components")]
+    fn report_components(
+        &mut self,
+        report: &mut VerificationReport,
+        manifest: &ImageManifest,
+        components: &[ImageTocEntry],
+    ) {
+        // The layout checks depend on each other, so only the first layout
+        // failure is reported; the digests are checked per component below.
+        if let Err(err) = self.verify_components(manifest, components) {
+            report.expect(
+                err == CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_MISMATCH,
+                "component layout",
+                err,
+                "valid component TOC",
+                format_args!("error 0x{:08x}", u32::from(err)),
+            );
+        }
+
+        for component in components {
+            let Ok(range) = component.image_range() else {
+                continue;
+            };
+            if let Some(actual) = self.digest(
+                report,
+                range,
+                "component digest",
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_FAILURE,
+            ) {
+                report.expect(
+                    component.digest == actual,
+                    "component digest",
+                    CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_MISMATCH,
+                    format_args!(
+                        "component 0x{:08x} {}",
+                        component.id,
+                        hex(&component.digest)
+                    ),
+                    format_args!("component 0x{:08x} {}", component.id, hex(&actual)),
+                );
+            }
+        }
+    }
+}
+
+fn hex(words: &[u32]) -> String {
+    words.iter().map(|w| format!("{w:08x}")).collect()
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use super::*;
+    use caliptra_common::memory_layout::*;
+
+    const DUMMY_DATA: [u32; 12] = [0xdeadbeef; 12];
+
+    struct TestEnv {
+        digest: ImageDigest,
+        lifecycle: Lifecycle,
+        vendor_pub_key_digest: ImageDigest,
+        fmc_fuse_svn: u32,
+        lms_verify_enabled: bool,
+        image_extensions: bool,
+        lms_hss_pub_keys_cold_boot: Option<LmsHssPubKeys>,
+        owner_pub_key_digest_dv: ImageDigest,
+        fmc_digest_dv: ImageDigest,
+    }
+
+    impl ImageVerificationEnv for TestEnv {
+        fn sha384_digest(&mut self, _offset: u32, _len: u32) -> CaliptraResult<ImageDigest> {
+            Ok(self.digest)
+        }
+
+        fn sha384_digest_with_suffix(
+            &mut self,
+            _offset: u32,
+            _len: u32,
+            _suffix: &[u8],
+        ) -> CaliptraResult<ImageDigest> {
+            Ok(self.digest)
+        }
+
+        fn ecc384_verify(
+            &mut self,
+            _digest: &ImageDigest,
+            _pub_key: &ImageEccPubKey,
+            sig: &ImageEccSignature,
+        ) -> CaliptraResult<Array4xN<12, 48>> {
+            Ok(Array4x12::from(sig.r))
+        }
+
+        fn lms_verify(
+            &mut self,
+            _digest: &ImageDigest,
+            pub_key: &ImageLmsPublicKey,
+            _sig: &ImageLmsSignature,
+        ) -> CaliptraResult<HashValue<SHA192_DIGEST_WORD_SIZE>> {
+            Ok(HashValue::from(pub_key.digest))
+        }
+
+        fn mldsa87_verify(
+            &mut self,
+            _digest: &ImageDigest,
+            _pub_key_offset: u32,
+            _sig_offset: u32,
+        ) -> CaliptraResult<Mldsa87Result> {
+            Ok(Mldsa87Result::Success)
+        }
+
+        fn lms_hss_signed_pub_key(&self, _offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+            Ok(ImageLmsSignedPubKey::default())
+        }
+
+        fn vendor_pub_key_digest(&self) -> ImageDigest {
+            self.vendor_pub_key_digest
+        }
+
+        fn vendor_ecc_pub_key_revocation(&self) -> VendorPubKeyRevocation {
+            VendorPubKeyRevocation::empty()
+        }
+
+        fn vendor_lms_pub_key_revocation(&self) -> u32 {
+            0
+        }
+
+        fn owner_pub_key_digest_fuses(&self) -> ImageDigest {
+            ZERO_DIGEST
+        }
+
+        fn anti_rollback_disable(&self) -> bool {
+            false
+        }
+
+        fn dev_lifecycle(&self) -> Lifecycle {
+            self.lifecycle
+        }
+
+        fn vendor_ecc_pub_key_idx_dv(&self) -> u32 {
+            0
+        }
+
+        fn vendor_lms_pub_key_idx_dv(&self) -> u32 {
+            0
+        }
+
+        fn owner_pub_key_digest_dv(&self) -> ImageDigest {
+            self.owner_pub_key_digest_dv
+        }
+
+        fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+            None
+        }
+
+        fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+            self.lms_hss_pub_keys_cold_boot
+        }
+
+        fn get_fmc_digest_dv(&self) -> ImageDigest {
+            self.fmc_digest_dv
+        }
+
+        fn fmc_fuse_svn(&self) -> u32 {
+            self.fmc_fuse_svn
+        }
+
+        fn runtime_fuse_svn(&self) -> u32 {
+            0
+        }
+
+        fn iccm_range(&self) -> Range<u32> {
+            ICCM_ORG..ICCM_ORG + ICCM_SIZE
+        }
+
+        fn lms_verify_enabled(&self) -> bool {
+            self.lms_verify_enabled
+        }
+
+        fn lms_hss_verify_enabled(&self) -> bool {
+            self.image_extensions && self.lms_verify_enabled
+        }
+
+        fn mldsa87_verify_enabled(&self) -> bool {
+            false
+        }
+
+        fn set_fw_extended_error(&mut self, _err: u32) {}
+    }
+
+    /// Manifest that passes every check with `TestEnv`
+    fn valid_manifest() -> ImageManifest {
+        let mut manifest = ImageManifest {
+            marker: MANIFEST_MARKER,
+            size: core::mem::size_of::<ImageManifest>() as u32,
+            ..Default::default()
+        };
+        manifest.preamble.vendor_pub_keys.ecc_pub_keys[0] = ImageEccPubKey {
+            x: DUMMY_DATA,
+            y: DUMMY_DATA,
+        };
+        manifest.preamble.vendor_sigs.ecc_sig = ImageEccSignature {
+            r: DUMMY_DATA,
+            s: DUMMY_DATA,
+        };
+        manifest.preamble.owner_pub_keys.ecc_pub_key =
+            manifest.preamble.vendor_pub_keys.ecc_pub_keys[0];
+        manifest.preamble.owner_sigs.ecc_sig = manifest.preamble.vendor_sigs.ecc_sig;
+        manifest.header.toc_len = FW_TOC_ENTRY_COUNT;
+        manifest.header.toc_digest = DUMMY_DATA;
+        for (toc, offset, load_addr) in [
+            (&mut manifest.fmc, manifest.size, ICCM_ORG),
+            (
+                &mut manifest.runtime,
+                manifest.size + 0x100,
+                ICCM_ORG + 0x1000,
+            ),
+        ] {
+            *toc = ImageTocEntry {
+                offset,
+                size: 0x100,
+                load_addr,
+                entry_point: load_addr,
+                digest: DUMMY_DATA,
+                ..Default::default()
+            };
+        }
+        manifest
+    }
+
+    fn env() -> TestEnv {
+        TestEnv {
+            digest: DUMMY_DATA,
+            lifecycle: Lifecycle::Production,
+            vendor_pub_key_digest: DUMMY_DATA,
+            fmc_fuse_svn: 0,
+            lms_verify_enabled: false,
+            image_extensions: true,
+            lms_hss_pub_keys_cold_boot: None,
+            owner_pub_key_digest_dv: ZERO_DIGEST,
+            fmc_digest_dv: ZERO_DIGEST,
+        }
+    }
+
+    #[test]
+    fn test_report_success() {
+        let manifest = valid_manifest();
+        let mut verifier = ImageVerifier::new(env());
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        assert!(report.is_ok(), "{report}");
+        assert!(verifier
+            .verify(&manifest, &[], 0x100000, ResetReason::ColdReset)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_report_lists_all_failures() {
+        let mut manifest = valid_manifest();
+        manifest.marker = 0;
+        manifest.header.vendor_ecc_pub_key_idx = 1;
+        manifest.preamble.owner_sigs.ecc_sig.s = ZERO_DIGEST;
+        manifest.fmc.svn = 1;
+        manifest.runtime.load_addr = ICCM_ORG + 0x80;
+        manifest.runtime.entry_point = ICCM_ORG + 0x80;
+        let mut verifier = ImageVerifier::new(TestEnv {
+            fmc_fuse_svn: 2,
+            vendor_pub_key_digest: [1; 12],
+            ..env()
+        });
+
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        let errors: Vec<_> = report.failures.iter().map(|f| f.error).collect();
+        assert_eq!(
+            errors,
+            [
+                CaliptraError::IMAGE_VERIFIER_ERR_MANIFEST_MARKER_MISMATCH,
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH,
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_MISMATCH,
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_ECC_SIGNATURE_INVALID_ARG,
+                CaliptraError::IMAGE_VERIFIER_ERR_FMC_RUNTIME_LOAD_ADDR_OVERLAP,
+                CaliptraError::IMAGE_VERIFIER_ERR_FMC_SVN_LESS_THAN_FUSE,
+            ]
+        );
+        assert_eq!(report.failures[2].expected, "0");
+        assert_eq!(report.failures[2].actual, "1");
+        assert_eq!(report.failures[5].expected, ">= fuse SVN 2");
+
+        // verify() stops at the first failure the report lists
+        assert_eq!(
+            verifier
+                .verify(&manifest, &[], 0x100000, ResetReason::ColdReset)
+                .err(),
+            Some(errors[0])
+        );
+    }
+
+    #[test]
+    fn test_report_first_failure_matches_verify() {
+        type Case = (
+            &'static str,
+            ResetReason,
+            fn(&mut ImageManifest, &mut TestEnv),
+        );
+        let cases: &[Case] = &[
+            ("valid", ResetReason::ColdReset, |_, _| {}),
+            ("marker", ResetReason::ColdReset, |m, _| m.marker = 0),
+            ("manifest size", ResetReason::ColdReset, |m, _| m.size = 0),
+            (
+                "zero vendor key digest",
+                ResetReason::ColdReset,
+                |_, env| env.vendor_pub_key_digest = ZERO_DIGEST,
+            ),
+            ("vendor key digest", ResetReason::ColdReset, |_, env| {
+                env.vendor_pub_key_digest = [1; 12]
+            }),
+            ("vendor ECC key index", ResetReason::ColdReset, |m, _| {
+                m.preamble.vendor_ecc_pub_key_idx = VENDOR_ECC_KEY_COUNT
+            }),
+            ("header ECC key index", ResetReason::ColdReset, |m, _| {
+                m.header.vendor_ecc_pub_key_idx = 1
+            }),
+            (
+                "header ECC key index and vendor signature",
+                ResetReason::ColdReset,
+                |m, _| {
+                    m.header.vendor_ecc_pub_key_idx = 1;
+                    m.preamble.vendor_sigs.ecc_sig.r = ZERO_DIGEST;
+                },
+            ),
+            (
+                "header ECC key index and owner signature",
+                ResetReason::ColdReset,
+                |m, _| {
+                    m.header.vendor_ecc_pub_key_idx = 1;
+                    m.preamble.owner_sigs.ecc_sig.s = ZERO_DIGEST;
+                },
+            ),
+            ("vendor ECC public key", ResetReason::ColdReset, |m, _| {
+                m.preamble.vendor_pub_keys.ecc_pub_keys[0].x = ZERO_DIGEST
+            }),
+            ("vendor LMS key index", ResetReason::ColdReset, |m, env| {
+                env.lms_verify_enabled = true;
+                m.preamble.vendor_lms_pub_key_idx = VENDOR_LMS_KEY_COUNT;
+            }),
+            ("header LMS key index", ResetReason::ColdReset, |m, env| {
+                env.lms_verify_enabled = true;
+                m.header.vendor_lms_pub_key_idx = 1;
+            }),
+            ("TOC entry count", ResetReason::ColdReset, |m, _| {
+                m.header.toc_len = 0
+            }),
+            ("component count", ResetReason::ColdReset, |m, _| {
+                m.header.toc_len = FW_TOC_ENTRY_COUNT + 1
+            }),
+            ("TOC digest", ResetReason::ColdReset, |m, _| {
+                m.header.toc_digest = ZERO_DIGEST
+            }),
+            ("FMC size", ResetReason::ColdReset, |m, _| m.fmc.size = 0),
+            ("FMC offset overflow", ResetReason::ColdReset, |m, _| {
+                m.fmc.offset = u32::MAX
+            }),
+            ("FMC and runtime offsets", ResetReason::ColdReset, |m, _| {
+                m.runtime.offset = m.fmc.offset
+            }),
+            ("FMC digest", ResetReason::ColdReset, |m, _| {
+                m.fmc.digest = ZERO_DIGEST
+            }),
+            ("FMC load address", ResetReason::ColdReset, |m, _| {
+                m.fmc.load_addr += 2;
+                m.fmc.entry_point += 2;
+            }),
+            ("runtime entry point", ResetReason::ColdReset, |m, _| {
+                m.runtime.entry_point = ICCM_ORG + ICCM_SIZE
+            }),
+            ("FMC SVN", ResetReason::ColdReset, |m, _| m.fmc.svn = 33),
+            (
+                "owner key digest of cold boot",
+                ResetReason::UpdateReset,
+                |_, _| {},
+            ),
+            (
+                "FMC SVN and FMC digest of cold boot",
+                ResetReason::UpdateReset,
+                |m, env| {
+                    env.owner_pub_key_digest_dv = DUMMY_DATA;
+                    m.fmc.svn = 33;
+                },
+            ),
+        ];
+
+        for (name, reason, mutate) in cases {
+            let mut manifest = valid_manifest();
+            let mut env = env();
+            mutate(&mut manifest, &mut env);
+            let mut verifier = ImageVerifier::new(env);
+            let report = verifier.verify_report(&manifest, &[], 0x100000, *reason);
+            let result = verifier.verify(&manifest, &[], 0x100000, *reason);
+            assert_eq!(
+                result.err(),
+                report.failures.first().map(|f| f.error),
+                "{name}: {report}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_report_skips_dependent_checks() {
+        let mut manifest = valid_manifest();
+        manifest.preamble.vendor_ecc_pub_key_idx = VENDOR_ECC_KEY_COUNT;
+        manifest.header.vendor_ecc_pub_key_idx = VENDOR_ECC_KEY_COUNT;
+        let mut verifier = ImageVerifier::new(env());
+
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        assert_eq!(report.failures.len(), 1, "{report}");
+        assert!(report
+            .contains(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_ECC_PUB_KEY_INDEX_OUT_OF_BOUNDS));
+    }
+
+    #[test]
+    #[cfg(feature = "lms-hss")]
+    fn test_report_lms_hss() {
+        let mut manifest = valid_manifest();
+        manifest.preamble.vendor_lms_hss_levels = LMS_HSS_MAX_LEVELS + 1;
+        manifest.preamble.owner_lms_hss_levels = 2;
+        let mut verifier = ImageVerifier::new(TestEnv {
+            lms_verify_enabled: true,
+            ..env()
+        });
+
+        // Signers using HSS are not LMS verified without a valid extension
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        let errors: Vec<_> = report.failures.iter().map(|f| f.error).collect();
+        assert_eq!(
+            errors,
+            [CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_LEVELS_INVALID],
+            "{report}"
+        );
+
+        manifest.preamble.vendor_lms_hss_levels = LMS_HSS_MAX_LEVELS;
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        assert!(report.is_ok(), "{report}");
+        assert!(verifier
+            .verify(&manifest, &[], 0x100000, ResetReason::ColdReset)
+            .is_ok());
+
+        let hss_size = core::mem::size_of::<ImageHssExtension>() as u32;
+        let report = verifier.verify_report(
+            &manifest,
+            &[],
+            manifest.size + hss_size - 1,
+            ResetReason::ColdReset,
+        );
+        assert!(report.contains(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING));
+
+        // The firmware sections must end before the extension
+        let report = verifier.verify_report(
+            &manifest,
+            &[],
+            manifest.size + 0x200 + hss_size - 1,
+            ResetReason::ColdReset,
+        );
+        let errors: Vec<_> = report.failures.iter().map(|f| f.error).collect();
+        assert_eq!(
+            errors,
+            [CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE],
+            "{report}"
+        );
+
+        // Without the extension, the keys verified on cold boot are required
+        let mut verifier = ImageVerifier::new(TestEnv {
+            lms_verify_enabled: true,
+            image_extensions: false,
+            ..env()
+        });
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        let errors: Vec<_> = report.failures.iter().map(|f| f.error).collect();
+        assert_eq!(
+            errors,
+            [CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING],
+            "{report}"
+        );
+        assert_eq!(
+            verifier
+                .verify(&manifest, &[], 0x100000, ResetReason::ColdReset)
+                .err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING)
+        );
+
+        let mut verifier = ImageVerifier::new(TestEnv {
+            lms_verify_enabled: true,
+            image_extensions: false,
+            lms_hss_pub_keys_cold_boot: Some(LmsHssPubKeys::default()),
+            ..env()
+        });
+        let report = verifier.verify_report(&manifest, &[], 0x100000, ResetReason::ColdReset);
+        assert!(report.is_ok(), "{report}");
+        assert!(verifier
+            .verify(&manifest, &[], 0x100000, ResetReason::ColdReset)
+            .is_ok());
+    }
+}
@@ -13,6 +13,7 @@ Abstract:
 --*/
 
 use core::num::NonZeroU32;
+use core::ops::Range;
 
 use crate::*;
 #[cfg(all(not(test), not(feature = "no-cfi")))]
@@ -38,12 +39,24 @@ struct HeaderInfo<'a> {
     owner_lms_info: Option<(&'a ImageLmsPublicKey, &'a ImageLmsSignature)>,
     owner_pub_keys_digest: ImageDigest,
     owner_pub_keys_digest_in_fuses: bool,
+    #[cfg(feature = "lms-hss")]
+    vendor_lms_hss_levels: u32,
+    #[cfg(feature = "lms-hss")]
+    owner_lms_hss_levels: u32,
+    #[cfg(feature = "lms-hss")]
+    hss_ext_offset: Option<u32>,
+    #[cfg(feature = "mldsa")]
+    pqc_ext_offset: Option<u32>,
+    #[cfg(feature = "mldsa")]
+    mldsa_pub_key_digests: Option<Mldsa87PubKeyDigests>,
 }
 
 /// TOC Info
 struct TocInfo<'a> {
     len: u32,
     digest: &'a ImageDigest,
+    #[cfg(feature = "lms-hss")]
+    lms_hss_pub_keys: Option<LmsHssPubKeys>,
 }
 
 /// Image Info
@@ -55,7 +68,7 @@ struct ImageInfo<'a> {
 /// Image Verifier
 pub struct ImageVerifier<Env: ImageVerificationEnv> {
     /// Verification Environment
-    env: Env,
+    pub(crate) env: Env,
 }
 
 impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
@@ -72,9 +85,10 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
     ///
     /// # Arguments
     ///
-    /// * `manifest` - Image Manifest
-    /// * `image`    - Image to verify
-    /// * `reason`   - Reset Reason
+    /// * `manifest`      - Image Manifest
+    /// * `components`    - Component TOC entries, as stored after the manifest
+    /// * `img_bundle_sz` - Image bundle size
+    /// * `reason`        - Reset Reason
     ///
     /// # Returns
     ///
@@ -84,6 +98,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
     pub fn verify(
         &mut self,
         manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &[ImageTocEntry],
         img_bundle_sz: u32,
         reason: ResetReason,
     ) -> CaliptraResult<ImageVerificationInfo> {
@@ -99,7 +114,7 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
 
         // Verify the preamble
         let preamble = &manifest.preamble;
-        let header_info = self.verify_preamble(preamble, reason);
+        let header_info = self.verify_preamble(preamble, reason, img_bundle_sz);
         let header_info = okref(&header_info)?;
 
         // Verify Header
@@ -107,7 +122,16 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         let toc_info = self.verify_header(header, header_info);
         let toc_info = okref(&toc_info)?;
 
+        // The firmware sections must end before the PQC and HSS extensions
+        #[cfg(feature = "lms-hss")]
+        let img_bundle_sz = header_info.hss_ext_offset.unwrap_or(img_bundle_sz);
+        #[cfg(feature = "mldsa")]
+        let img_bundle_sz = header_info.pqc_ext_offset.unwrap_or(img_bundle_sz);
+
         // Verify TOC
+        #[cfg(feature = "components")]
+        let image_info = self.verify_toc(manifest, components, toc_info, img_bundle_sz);
+        #[cfg(not(feature = "components"))]
         let image_info = self.verify_toc(manifest, toc_info, img_bundle_sz);
         let image_info = okref(&image_info)?;
 
@@ -117,6 +141,10 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         // Verify Runtime
         let (runtime_info, rt_log_info) = self.verify_runtime(image_info.runtime)?;
 
+        // Verify Components
+        #[cfg(feature = "components")]
+        self.verify_components(manifest, components)?;
+
         let info = ImageVerificationInfo {
             vendor_ecc_pub_key_idx: header_info.vendor_ecc_pub_key_idx,
             vendor_lms_pub_key_idx: header_info.vendor_lms_pub_key_idx,
@@ -124,6 +152,12 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             owner_pub_keys_digest_in_fuses: header_info.owner_pub_keys_digest_in_fuses,
             fmc: fmc_info,
             runtime: runtime_info,
+            #[cfg(feature = "components")]
+            component_count: components.len() as u32,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: header_info.mldsa_pub_key_digests,
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: toc_info.lms_hss_pub_keys,
             log_info: ImageVerificationLogInfo {
                 vendor_ecc_pub_key_idx: header_info.vendor_ecc_pub_key_idx,
                 fuse_vendor_ecc_pub_key_revocation: header_info.vendor_ecc_pub_key_revocation,
@@ -143,11 +177,35 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         &mut self,
         preamble: &'a ImagePreamble,
         reason: ResetReason,
+        img_bundle_sz: u32,
     ) -> CaliptraResult<HeaderInfo<'a>> {
+        #[cfg(not(any(feature = "mldsa", feature = "lms-hss")))]
+        let _ = img_bundle_sz;
+
+        // Verify ML-DSA Public Keys
+        #[cfg(feature = "mldsa")]
+        let (pqc_ext_offset, mldsa_pub_key_digests) =
+            self.verify_mldsa_pub_keys(preamble, img_bundle_sz)?;
+        #[cfg(all(feature = "lms-hss", not(feature = "mldsa")))]
+        let mldsa_pub_key_digests: Option<Mldsa87PubKeyDigests> = None;
+
         // Verify Vendor Public Key Digest
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))]
+        self.verify_vendor_pk_digest(
+            preamble.vendor_lms_hss_levels,
+            mldsa_pub_key_digests.as_ref().map(|d| &d.vendor),
+        )?;
+        #[cfg(not(any(feature = "mldsa", feature = "lms-hss")))]
         self.verify_vendor_pk_digest()?;
 
         // Verify Owner Public Key Digest
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))]
+        let (owner_pub_keys_digest, owner_pub_keys_digest_in_fuses) = self.verify_owner_pk_digest(
+            reason,
+            preamble.owner_lms_hss_levels,
+            mldsa_pub_key_digests.as_ref().map(|d| &d.owner),
+        )?;
+        #[cfg(not(any(feature = "mldsa", feature = "lms-hss")))]
         let (owner_pub_keys_digest, owner_pub_keys_digest_in_fuses) =
             self.verify_owner_pk_digest(reason)?;
 
@@ -165,11 +223,19 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         let mut vendor_lms_pub_key_idx: Option<u32> = None;
         let mut vendor_lms_info: Option<(&ImageLmsPublicKey, &'a ImageLmsSignature)> = None;
         let mut vendor_lms_pub_key_revocation: Option<u32> = None;
+        #[cfg(feature = "lms-hss")]
+        let mut hss_ext_offset: Option<u32> = None;
 
         if cfi_launder(self.env.lms_verify_enabled()) {
             (vendor_lms_pub_key_idx, vendor_lms_pub_key_revocation) =
                 self.verify_vendor_lms_pk_idx(preamble, reason)?;
 
+            // Verify LMS HSS Levels
+            #[cfg(feature = "lms-hss")]
+            {
+                hss_ext_offset = self.verify_lms_hss_levels(preamble, img_bundle_sz)?;
+            }
+
             if let Some(idx) = vendor_lms_pub_key_idx {
                 vendor_lms_info = Some((
                     &preamble.vendor_pub_keys.lms_pub_keys[idx as usize],
@@ -180,6 +246,9 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             cfi_assert!(!self.env.lms_verify_enabled());
         }
 
+        #[cfg(feature = "lms-hss")]
+        let vendor_lms_hss_levels = preamble.vendor_lms_hss_levels;
+
         // Owner Information
         let owner_ecc_info = (
             &preamble.owner_pub_keys.ecc_pub_key,
@@ -195,6 +264,8 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             cfi_assert!(!self.env.lms_verify_enabled());
             None
         };
+        #[cfg(feature = "lms-hss")]
+        let owner_lms_hss_levels = preamble.owner_lms_hss_levels;
 
         let info = HeaderInfo {
             vendor_ecc_pub_key_idx,
@@ -207,6 +278,16 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
             owner_ecc_info,
             vendor_ecc_pub_key_revocation,
             vendor_lms_pub_key_revocation,
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests,
         };
 
         Ok(info)
@@ -301,8 +382,15 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok((Some(key_idx), Some(revocation)))
     }
 
-    /// Verify vendor public key digest
-    fn verify_vendor_pk_digest(&mut self) -> Result<(), NonZeroU32> {
+    /// Verify vendor public key digest. `lms_hss_levels` are the HSS levels
+    /// of the vendor LMS keys and `mldsa_digest` is the digest of the
+    /// vendor ML-DSA public key, if it is bound to the vendor public key
+    /// digest.
+    fn verify_vendor_pk_digest(
+        &mut self,
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))] lms_hss_levels: u32,
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))] mldsa_digest: Option<&ImageDigest>,
+    ) -> Result<(), NonZeroU32> {
         // We skip vendor public key check in unprovisioned state
         if cfi_launder(self.env.dev_lifecycle()) == Lifecycle::Unprovisioned {
             cfi_assert_eq(self.env.dev_lifecycle(), Lifecycle::Unprovisioned);
@@ -323,6 +411,14 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
 
         let range = ImageManifest::vendor_pub_keys_range();
 
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))]
+        let actual = self
+            .pub_keys_digest(range, lms_hss_levels, mldsa_digest)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_FAILURE
+            })?;
+        #[cfg(not(any(feature = "mldsa", feature = "lms-hss")))]
         let actual = self
             .env
             .sha384_digest(range.start, range.len() as u32)
@@ -340,14 +436,26 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok(())
     }
 
-    /// Verify owner public key digest.
+    /// Verify owner public key digest. `lms_hss_levels` are the HSS levels of
+    /// the owner LMS key and `mldsa_digest` is the digest of the owner
+    /// ML-DSA public key, if it is bound to the owner public key digest.
     /// Returns a bool indicating whether the digest was in fuses.
     fn verify_owner_pk_digest(
         &mut self,
         reason: ResetReason,
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))] lms_hss_levels: u32,
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))] mldsa_digest: Option<&ImageDigest>,
     ) -> CaliptraResult<(ImageDigest, bool)> {
         let range = ImageManifest::owner_pub_key_range();
 
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))]
+        let actual = self
+            .pub_keys_digest(range, lms_hss_levels, mldsa_digest)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_FAILURE
+            })?;
+        #[cfg(not(any(feature = "mldsa", feature = "lms-hss")))]
         let actual = self
             .env
             .sha384_digest(range.start, range.len() as u32)
@@ -380,6 +488,138 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok((actual, fuses_digest != ZERO_DIGEST))
     }
 
+    /// Verify the LMS HSS levels of the vendor and owner keys. Returns the
+    /// offset of the HSS extension if either signer uses more than one level
+    /// and the extension is available for verification.
+    #[cfg(feature = "lms-hss")]
+    fn verify_lms_hss_levels(
+        &mut self,
+        preamble: &ImagePreamble,
+        img_bundle_sz: u32,
+    ) -> CaliptraResult<Option<u32>> {
+        let vendor_levels = preamble.vendor_lms_hss_levels;
+        if cfi_launder(vendor_levels) > LMS_HSS_MAX_LEVELS {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_LEVELS_INVALID);
+        } else {
+            cfi_assert_le(vendor_levels, LMS_HSS_MAX_LEVELS);
+        }
+
+        let owner_levels = preamble.owner_lms_hss_levels;
+        if cfi_launder(owner_levels) > LMS_HSS_MAX_LEVELS {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_LEVELS_INVALID);
+        } else {
+            cfi_assert_le(owner_levels, LMS_HSS_MAX_LEVELS);
+        }
+
+        if (vendor_levels <= 1 && owner_levels <= 1) || !self.env.lms_hss_verify_enabled() {
+            return Ok(None);
+        }
+
+        // The HSS extension occupies the end of the image bundle
+        let ext_size = core::mem::size_of::<ImageHssExtension>() as u32;
+        let manifest_size = core::mem::size_of::<ImageManifest>() as u32;
+        if img_bundle_sz < manifest_size + ext_size {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING);
+        }
+
+        Ok(Some(img_bundle_sz - ext_size))
+    }
+
+    /// Calculate the digest of the public keys in `range`, followed by the
+    /// little-endian `lms_hss_levels` if the signer uses more than one HSS
+    /// level and by `mldsa_pub_key_digest` if an ML-DSA public key is bound
+    /// to them
+    pub(crate) fn pub_keys_digest(
+        &mut self,
+        range: Range<u32>,
+        lms_hss_levels: u32,
+        mldsa_pub_key_digest: Option<&ImageDigest>,
+    ) -> CaliptraResult<ImageDigest> {
+        let mut suffix = [0u8; core::mem::size_of::<u32>() + SHA384_DIGEST_BYTE_SIZE];
+        let mut suffix_len = 0;
+
+        #[cfg(feature = "lms-hss")]
+        if lms_hss_levels > 1 {
+            suffix[..core::mem::size_of::<u32>()].copy_from_slice(&lms_hss_levels.to_le_bytes());
+            suffix_len += core::mem::size_of::<u32>();
+        }
+        #[cfg(not(feature = "lms-hss"))]
+        let _ = lms_hss_levels;
+
+        if let Some(digest) = mldsa_pub_key_digest {
+            let digest = <[u8; SHA384_DIGEST_BYTE_SIZE]>::from(Array4x12::from(digest));
+            suffix[suffix_len..suffix_len + SHA384_DIGEST_BYTE_SIZE].copy_from_slice(&digest);
+            suffix_len += SHA384_DIGEST_BYTE_SIZE;
+        }
+
+        if suffix_len == 0 {
+            self.env.sha384_digest(range.start, range.len() as u32)
+        } else {
+            self.env.sha384_digest_with_suffix(
+                range.start,
+                range.len() as u32,
+                &suffix[..suffix_len],
+            )
+        }
+    }
+
+    /// Calculate the digests of the ML-DSA public keys in the PQC extension,
+    /// which are bound to the vendor and owner public key digests. Images
+    /// without the extension use the digests verified on cold boot.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<u32>` - Offset of the PQC extension, if it is verified
+    /// * `Option<Mldsa87PubKeyDigests>` - ML-DSA public key digests, if the
+    ///   fuses require ML-DSA verification
+    #[cfg(feature = "mldsa")]
+    fn verify_mldsa_pub_keys(
+        &mut self,
+        preamble: &ImagePreamble,
+        img_bundle_sz: u32,
+    ) -> CaliptraResult<(Option<u32>, Option<Mldsa87PubKeyDigests>)> {
+        if !cfi_launder(self.env.mldsa87_verify_enabled()) {
+            cfi_assert!(!self.env.mldsa87_verify_enabled());
+            return Ok((None, self.env.mldsa87_pub_key_digests_cold_boot()));
+        }
+
+        // The PQC extension precedes the HSS extension, if any, at the end of
+        // the image bundle
+        let mut ext_end = img_bundle_sz;
+        if preamble.vendor_lms_hss_levels > 1 || preamble.owner_lms_hss_levels > 1 {
+            ext_end = ext_end.saturating_sub(core::mem::size_of::<ImageHssExtension>() as u32);
+        }
+        let ext_size = core::mem::size_of::<ImagePqcExtension>() as u32;
+        let manifest_size = core::mem::size_of::<ImageManifest>() as u32;
+        if ext_end < manifest_size + ext_size {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING);
+        }
+        let ext_offset = ext_end - ext_size;
+
+        let vendor = self.mldsa_pub_key_digest(
+            ext_offset + offset_of!(ImagePqcExtension, vendor_mldsa_pub_key) as u32,
+        )?;
+        let owner = self.mldsa_pub_key_digest(
+            ext_offset + offset_of!(ImagePqcExtension, owner_mldsa_pub_key) as u32,
+        )?;
+
+        Ok((
+            Some(ext_offset),
+            Some(Mldsa87PubKeyDigests { vendor, owner }),
+        ))
+    }
+
+    /// Calculate the digest of the ML-DSA public key at `offset`
+    #[cfg(feature = "mldsa")]
+    fn mldsa_pub_key_digest(&mut self, offset: u32) -> CaliptraResult<ImageDigest> {
+        self.env
+            .sha384_digest(offset, MLDSA87_PUB_KEY_BYTE_SIZE as u32)
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_MLDSA_PUB_KEY_DIGEST_FAILURE
+            })
+    }
+
     /// Verify Header
     #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
     fn verify_header<'a>(
@@ -408,8 +648,23 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
                 CaliptraError::IMAGE_VERIFIER_ERR_HEADER_DIGEST_FAILURE
             })?;
 
+        // Signers using more than one HSS level sign the header with the LMS
+        // keys at the bottom of their HSS chains
+        #[cfg(feature = "lms-hss")]
+        let lms_hss_pub_keys = self.verify_lms_hss_pub_keys(info)?;
+        #[cfg(feature = "lms-hss")]
+        let (vendor_lms_info, owner_lms_info) = match &lms_hss_pub_keys {
+            Some(keys) => (
+                info.vendor_lms_info.map(|(_, sig)| (&keys.vendor, sig)),
+                info.owner_lms_info.map(|(_, sig)| (&keys.owner, sig)),
+            ),
+            None => (info.vendor_lms_info, info.owner_lms_info),
+        };
+        #[cfg(not(feature = "lms-hss"))]
+        let (vendor_lms_info, owner_lms_info) = (info.vendor_lms_info, info.owner_lms_info);
+
         // Verify vendor signature
-        self.verify_vendor_sig(&digest_vendor, info.vendor_ecc_info, info.vendor_lms_info)?;
+        self.verify_vendor_sig(&digest_vendor, info.vendor_ecc_info, vendor_lms_info)?;
 
         // Verify the ECC public key index used to verify header signature is encoded
         // in the header
@@ -436,15 +691,25 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         self.verify_owner_ecc_sig(&digest_owner, owner_ecc_pub_key, owner_ecc_sig)?;
 
         // Verify owner LMS signature
-        if let Some((owner_lms_pub_key, owner_lms_sig)) = cfi_launder(info.owner_lms_info) {
+        if let Some((owner_lms_pub_key, owner_lms_sig)) = cfi_launder(owner_lms_info) {
             self.verify_owner_lms_sig(&digest_owner, owner_lms_pub_key, owner_lms_sig)?;
         } else {
-            cfi_assert!(info.owner_lms_info.is_none());
+            cfi_assert!(owner_lms_info.is_none());
+        }
+
+        // Verify vendor and owner ML-DSA signatures
+        #[cfg(feature = "mldsa")]
+        if let Some(ext_offset) = cfi_launder(info.pqc_ext_offset) {
+            self.verify_mldsa_sigs(&digest_vendor, &digest_owner, ext_offset)?;
+        } else {
+            cfi_assert!(info.pqc_ext_offset.is_none());
         }
 
         let verif_info = TocInfo {
             len: header.toc_len,
             digest: &header.toc_digest,
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys,
         };
 
         Ok(verif_info)
@@ -561,21 +826,182 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok(())
     }
 
+    /// Verify the signed public keys of the lower levels of an HSS chain,
+    /// stored in the image at `offset`. Returns the public key of the last
+    /// level.
+    #[cfg(feature = "lms-hss")]
+    fn verify_lms_hss_chain(
+        &mut self,
+        pub_key: &ImageLmsPublicKey,
+        levels: u32,
+        offset: u32,
+        verify_err: CaliptraError,
+        sig_err: CaliptraError,
+    ) -> CaliptraResult<ImageLmsPublicKey> {
+        let mut pub_key = *pub_key;
+        for level in 1..levels {
+            let signed_offset =
+                offset + (level - 1) * core::mem::size_of::<ImageLmsSignedPubKey>() as u32;
+            let signed = self
+                .env
+                .lms_hss_signed_pub_key(signed_offset)
+                .map_err(|err| {
+                    self.env.set_fw_extended_error(err.into());
+                    verify_err
+                })?;
+
+            let candidate_key = self
+                .env
+                .lms_verify(&lms_hss_message(&signed.pub_key), &pub_key, &signed.sig)
+                .map_err(|err| {
+                    self.env.set_fw_extended_error(err.into());
+                    verify_err
+                })?;
+
+            let pub_key_digest = HashValue::from(pub_key.digest);
+            if candidate_key != pub_key_digest {
+                return Err(sig_err);
+            } else {
+                caliptra_cfi_lib::cfi_assert_eq_6_words(&candidate_key.0, &pub_key_digest.0);
+            }
+
+            pub_key = signed.pub_key;
+        }
+
+        Ok(pub_key)
+    }
+
+    /// Get the LMS public keys that sign the header if LMS verification is
+    /// enabled and the vendor or owner uses more than one HSS level. These
+    /// are the keys at the bottom of the HSS chains of the extension or, for
+    /// images without the extension, the keys verified on cold boot.
+    #[cfg(feature = "lms-hss")]
+    fn verify_lms_hss_pub_keys(
+        &mut self,
+        info: &HeaderInfo,
+    ) -> CaliptraResult<Option<LmsHssPubKeys>> {
+        let (Some((vendor_pub_key, _)), Some((owner_pub_key, _))) =
+            (info.vendor_lms_info, info.owner_lms_info)
+        else {
+            return Ok(None);
+        };
+        if info.vendor_lms_hss_levels <= 1 && info.owner_lms_hss_levels <= 1 {
+            return Ok(None);
+        }
+
+        let Some(ext_offset) = cfi_launder(info.hss_ext_offset) else {
+            // Never skip LMS verification of an image without the extension
+            return match self.env.lms_hss_pub_keys_cold_boot() {
+                Some(keys) => Ok(Some(keys)),
+                None => Err(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING),
+            };
+        };
+
+        let vendor = self.verify_lms_hss_chain(
+            vendor_pub_key,
+            info.vendor_lms_hss_levels,
+            ext_offset + offset_of!(ImageHssExtension, vendor_signed_pub_keys) as u32,
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_VERIFY_FAILURE,
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_SIGNATURE_INVALID,
+        )?;
+        let owner = self.verify_lms_hss_chain(
+            owner_pub_key,
+            info.owner_lms_hss_levels,
+            ext_offset + offset_of!(ImageHssExtension, owner_signed_pub_keys) as u32,
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_VERIFY_FAILURE,
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_SIGNATURE_INVALID,
+        )?;
+
+        Ok(Some(LmsHssPubKeys { vendor, owner }))
+    }
+
+    /// Verify vendor and owner ML-DSA Signatures
+    #[cfg(feature = "mldsa")]
+    fn verify_mldsa_sigs(
+        &mut self,
+        digest_vendor: &ImageDigest,
+        digest_owner: &ImageDigest,
+        ext_offset: u32,
+    ) -> CaliptraResult<()> {
+        let result = self
+            .env
+            .mldsa87_verify(
+                digest_vendor,
+                ext_offset + offset_of!(ImagePqcExtension, vendor_mldsa_pub_key) as u32,
+                ext_offset + offset_of!(ImagePqcExtension, vendor_mldsa_sig) as u32,
+            )
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_VERIFY_FAILURE
+            })?;
+        if cfi_launder(result) != Mldsa87Result::Success {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID);
+        } else {
+            cfi_assert_eq(result, Mldsa87Result::Success);
+        }
+
+        let result = self
+            .env
+            .mldsa87_verify(
+                digest_owner,
+                ext_offset + offset_of!(ImagePqcExtension, owner_mldsa_pub_key) as u32,
+                ext_offset + offset_of!(ImagePqcExtension, owner_mldsa_sig) as u32,
+            )
+            .map_err(|err| {
+                self.env.set_fw_extended_error(err.into());
+                CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_VERIFY_FAILURE
+            })?;
+        if cfi_launder(result) != Mldsa87Result::Success {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID);
+        } else {
+            cfi_assert_eq(result, Mldsa87Result::Success);
+        }
+
+        Ok(())
+    }
+
     /// Verify Table of Contents
     #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
     fn verify_toc<'a>(
         &mut self,
         manifest: &'a ImageManifest,
+        #[cfg(feature = "components")] components: &[ImageTocEntry],
         verify_info: &TocInfo,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageInfo<'a>> {
-        if cfi_launder(verify_info.len) != MAX_TOC_ENTRY_COUNT {
-            Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)?;
-        } else {
-            cfi_assert_eq(verify_info.len, MAX_TOC_ENTRY_COUNT);
-        }
+        #[cfg(feature = "components")]
+        let range = {
+            if cfi_launder(verify_info.len) < FW_TOC_ENTRY_COUNT
+                || verify_info.len > MAX_TOC_ENTRY_COUNT
+            {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)?;
+            } else {
+                cfi_assert_ge(verify_info.len, FW_TOC_ENTRY_COUNT);
+                cfi_assert_le(verify_info.len, MAX_TOC_ENTRY_COUNT);
+            }
 
-        let range = ImageManifest::toc_range();
+            let component_count = components.len() as u32;
+            if cfi_launder(verify_info.len - FW_TOC_ENTRY_COUNT) != component_count {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_COUNT_MISMATCH)?;
+            } else {
+                cfi_assert_eq(verify_info.len - FW_TOC_ENTRY_COUNT, component_count);
+            }
+
+            // The component TOC entries follow the manifest, so the digest
+            // covers them together with the FMC and Runtime entries.
+            ImageManifest::toc_range_with_components(component_count)
+        };
+
+        #[cfg(not(feature = "components"))]
+        let range = {
+            if cfi_launder(verify_info.len) != FW_TOC_ENTRY_COUNT {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)?;
+            } else {
+                cfi_assert_eq(verify_info.len, FW_TOC_ENTRY_COUNT);
+            }
+
+            ImageManifest::toc_range()
+        };
 
         let actual = self
             .env
@@ -602,6 +1028,15 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         }
 
         // Image length does not exceed the Image Bundle size
+        #[cfg(feature = "components")]
+        let img_len: u64 = range.end as u64
+            + manifest.fmc.image_size() as u64
+            + manifest.runtime.image_size() as u64
+            + components
+                .iter()
+                .map(|c| c.image_size() as u64)
+                .sum::<u64>();
+        #[cfg(not(feature = "components"))]
         let img_len: u64 = manifest.size as u64
             + manifest.fmc.image_size() as u64
             + manifest.runtime.image_size() as u64;
@@ -651,6 +1086,95 @@ impl<Env: ImageVerificationEnv> ImageVerifier<Env> {
         Ok(info)
     }
 
+    /// Verify Components
+    #[cfg(feature = "components")]
+    #[cfg_attr(all(not(test), not(feature = "no-cfi")), cfi_impl_fn)]
+    pub(crate) fn verify_components(
+        &mut self,
+        manifest: &ImageManifest,
+        components: &[ImageTocEntry],
+    ) -> CaliptraResult<()> {
+        if components.is_empty() {
+            return Ok(());
+        }
+
+        // The component TOC entries sit between the manifest and the FMC,
+        // and the component contents follow the Runtime in TOC order.
+        let toc_end = ImageManifest::toc_range_with_components(components.len() as u32).end;
+        if manifest.fmc.offset != toc_end {
+            Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_OFFSET_INVALID)?;
+        }
+        let mut offset = manifest.runtime.image_range()?.end;
+
+        for (i, component) in components.iter().enumerate() {
+            if component.id == ImageTocEntryId::Fmc as u32
+                || component.id == ImageTocEntryId::Runtime as u32
+                || components[..i].iter().any(|c| c.id == component.id)
+            {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_ID_INVALID)?;
+            }
+
+            let executable = if component.r#type == ImageTocEntryType::Executable as u32 {
+                true
+            } else if component.r#type == ImageTocEntryType::Data as u32 {
+                false
+            } else {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_TYPE_INVALID)?
+            };
+
+            if component.size == 0 || component.size % 4 != 0 {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_SIZE_INVALID)?;
+            }
+
+            let range = component.image_range()?;
+            if range.start != offset {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_OFFSET_INVALID)?;
+            }
+            offset = range.end;
+
+            let load_end = component
+                .load_addr
+                .checked_add(component.size)
+                .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_INVALID)?;
+            if component.load_addr % 4 != 0
+                || !self.env.iccm_range().contains(&component.load_addr)
+                || !self.env.iccm_range().contains(&(load_end - 1))
+            {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_INVALID)?;
+            }
+
+            if component.overlaps(&manifest.fmc)
+                || component.overlaps(&manifest.runtime)
+                || components[..i].iter().any(|c| component.overlaps(c))
+            {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_OVERLAP)?;
+            }
+
+            if executable
+                && (!(component.load_addr..load_end).contains(&component.entry_point)
+                    || component.entry_point % 4 != 0)
+            {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_ENTRY_POINT_INVALID)?;
+            }
+
+            let actual = self
+                .env
+                .sha384_digest(range.start, range.len() as u32)
+                .map_err(|err| {
+                    self.env.set_fw_extended_error(err.into());
+                    CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_FAILURE
+                })?;
+
+            if cfi_launder(component.digest) != actual {
+                Err(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_MISMATCH)?;
+            } else {
+                caliptra_cfi_lib::cfi_assert_eq_12_words(&component.digest, &actual);
+            }
+        }
+
+        Ok(())
+    }
+
     // Check if SVN check is required
     #[inline(always)]
     fn svn_check_required(&mut self) -> bool {
@@ -852,6 +1376,10 @@ mod tests {
         0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef,
         0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef, 0xdeadbeef,
     ];
+    const SUFFIXED_DUMMY_DATA: [u32; 12] = [
+        0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d,
+        0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d, 0xfeedf00d,
+    ];
     const VENDOR_ECC_PUBKEY: ImageEccPubKey = ImageEccPubKey {
         x: DUMMY_DATA,
         y: DUMMY_DATA,
@@ -921,7 +1449,7 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset);
+        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset, 0);
         assert!(result.is_ok());
     }
 
@@ -990,7 +1518,7 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset);
+        let result = verifier.verify_preamble(&preamble, ResetReason::UpdateReset, 0);
         assert!(result.is_ok());
     }
 
@@ -998,7 +1526,7 @@ mod tests {
     fn test_manifest_marker() {
         let manifest = ImageManifest::default();
         let mut verifier = ImageVerifier::new(TestEnv::default());
-        let result = verifier.verify(&manifest, manifest.size, ResetReason::ColdReset);
+        let result = verifier.verify(&manifest, &[], manifest.size, ResetReason::ColdReset);
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -1014,7 +1542,7 @@ mod tests {
             ..Default::default()
         };
         let mut verifier = ImageVerifier::new(TestEnv::default());
-        let result = verifier.verify(&manifest, manifest.size, ResetReason::ColdReset);
+        let result = verifier.verify(&manifest, &[], manifest.size, ResetReason::ColdReset);
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -1030,7 +1558,7 @@ mod tests {
             ..Default::default()
         };
         let mut verifier = ImageVerifier::new(test_env);
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, 0);
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -1050,7 +1578,7 @@ mod tests {
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
 
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, 0);
         assert!(result.is_ok());
     }
 
@@ -1064,11 +1592,361 @@ mod tests {
         };
         let mut verifier = ImageVerifier::new(test_env);
         let preamble = ImagePreamble::default();
-        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset);
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, 0);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mldsa")]
+    fn test_preamble_pqc_extension_missing() {
+        let test_env = TestEnv {
+            mldsa_verify_enabled: true,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+        let pqc_size = core::mem::size_of::<ImagePqcExtension>() as u32;
+
+        // Image bundle too small to hold the extension
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32 + pqc_size - 1,
+        );
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING)
+        );
+
+        // The PQC extension precedes the HSS extension
+        preamble.vendor_lms_hss_levels = 2;
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32 + pqc_size,
+        );
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "mldsa")]
+    fn test_preamble_mldsa_pub_key_binding() {
+        let test_env = TestEnv {
+            lifecycle: Lifecycle::Production,
+            mldsa_verify_enabled: true,
+            digest: DUMMY_DATA,
+            vendor_pub_key_digest: DUMMY_DATA,
+            owner_pub_key_digest: DUMMY_DATA,
+            suffixed_digest: SUFFIXED_DUMMY_DATA,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let preamble = ImagePreamble::default();
+        let img_bundle_sz =
+            (IMAGE_MANIFEST_BYTE_SIZE + core::mem::size_of::<ImagePqcExtension>()) as u32;
+
+        // The key hash fuses must include the ML-DSA public key digests
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        verifier.env.vendor_pub_key_digest = SUFFIXED_DUMMY_DATA;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        verifier.env.owner_pub_key_digest = SUFFIXED_DUMMY_DATA;
+        let header_info = verifier
+            .verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz)
+            .unwrap();
+        assert_eq!(
+            header_info.pqc_ext_offset,
+            Some(IMAGE_MANIFEST_BYTE_SIZE as u32)
+        );
+        assert_eq!(
+            header_info.mldsa_pub_key_digests,
+            Some(Mldsa87PubKeyDigests {
+                vendor: DUMMY_DATA,
+                owner: DUMMY_DATA,
+            })
+        );
+        assert_eq!(header_info.owner_pub_keys_digest, SUFFIXED_DUMMY_DATA);
+    }
+
+    #[test]
+    #[cfg(feature = "mldsa")]
+    fn test_preamble_mldsa_cold_boot_digests() {
+        let test_env = TestEnv {
+            lifecycle: Lifecycle::Production,
+            digest: DUMMY_DATA,
+            vendor_pub_key_digest: SUFFIXED_DUMMY_DATA,
+            owner_pub_key_digest: SUFFIXED_DUMMY_DATA,
+            suffixed_digest: SUFFIXED_DUMMY_DATA,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let preamble = ImagePreamble::default();
+
+        // Without ML-DSA keys, the key hash fuses cover the preamble keys only
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::UpdateReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32,
+        );
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
         );
+
+        // Images without the PQC extension are bound to the ML-DSA keys
+        // verified on cold boot
+        verifier.env.mldsa_pub_key_digests_cold_boot = Some(Mldsa87PubKeyDigests {
+            vendor: DUMMY_DATA,
+            owner: DUMMY_DATA,
+        });
+        let header_info = verifier
+            .verify_preamble(
+                &preamble,
+                ResetReason::UpdateReset,
+                IMAGE_MANIFEST_BYTE_SIZE as u32,
+            )
+            .unwrap();
+        assert_eq!(header_info.pqc_ext_offset, None);
+        assert_eq!(
+            header_info.mldsa_pub_key_digests,
+            verifier.env.mldsa_pub_key_digests_cold_boot
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lms-hss")]
+    fn test_preamble_lms_hss_levels() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+        let img_bundle_sz =
+            (IMAGE_MANIFEST_BYTE_SIZE + core::mem::size_of::<ImageHssExtension>()) as u32;
+
+        preamble.vendor_lms_hss_levels = LMS_HSS_MAX_LEVELS + 1;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_LEVELS_INVALID)
+        );
+
+        preamble.vendor_lms_hss_levels = LMS_HSS_MAX_LEVELS;
+        preamble.owner_lms_hss_levels = LMS_HSS_MAX_LEVELS + 1;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_LEVELS_INVALID)
+        );
+
+        preamble.owner_lms_hss_levels = 1;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        let header_info = result.unwrap();
+        assert_eq!(header_info.vendor_lms_hss_levels, LMS_HSS_MAX_LEVELS);
+        assert_eq!(header_info.owner_lms_hss_levels, 1);
+        assert_eq!(
+            header_info.hss_ext_offset,
+            Some(IMAGE_MANIFEST_BYTE_SIZE as u32)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lms-hss")]
+    fn test_preamble_hss_extension() {
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+        let hss_size = core::mem::size_of::<ImageHssExtension>() as u32;
+        let pqc_size = core::mem::size_of::<ImagePqcExtension>() as u32;
+
+        // A single level needs no extension
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32,
+        );
+        assert_eq!(result.unwrap().hss_ext_offset, None);
+
+        // Image bundle too small to hold the extension
+        preamble.owner_lms_hss_levels = 2;
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32 + hss_size - 1,
+        );
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING)
+        );
+
+        // The HSS extension occupies the end of the image bundle
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32 + pqc_size + hss_size,
+        );
+        let header_info = result.unwrap();
+        assert_eq!(
+            header_info.hss_ext_offset,
+            Some(IMAGE_MANIFEST_BYTE_SIZE as u32 + pqc_size)
+        );
+        assert!(header_info.owner_lms_info.is_some());
+
+        // Without the extension, signers using HSS are still LMS verified
+        verifier.env.lms_hss_verify_enabled = false;
+        let result = verifier.verify_preamble(
+            &preamble,
+            ResetReason::ColdReset,
+            IMAGE_MANIFEST_BYTE_SIZE as u32,
+        );
+        let header_info = result.unwrap();
+        assert_eq!(header_info.hss_ext_offset, None);
+        assert!(header_info.vendor_lms_info.is_some());
+        assert!(header_info.owner_lms_info.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "lms-hss")]
+    fn test_preamble_lms_hss_levels_binding() {
+        let test_env = TestEnv {
+            lifecycle: Lifecycle::Production,
+            digest: DUMMY_DATA,
+            vendor_pub_key_digest: DUMMY_DATA,
+            owner_pub_key_digest: DUMMY_DATA,
+            suffixed_digest: SUFFIXED_DUMMY_DATA,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let mut preamble = ImagePreamble::default();
+        let img_bundle_sz =
+            (IMAGE_MANIFEST_BYTE_SIZE + core::mem::size_of::<ImageHssExtension>()) as u32;
+
+        // A single level is not bound to the key hashes
+        preamble.vendor_lms_hss_levels = 1;
+        assert!(verifier
+            .verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz)
+            .is_ok());
+
+        // The key hash fuses must include the HSS levels
+        preamble.vendor_lms_hss_levels = 2;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        verifier.env.vendor_pub_key_digest = SUFFIXED_DUMMY_DATA;
+        preamble.owner_lms_hss_levels = 2;
+        let result = verifier.verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH)
+        );
+
+        verifier.env.owner_pub_key_digest = SUFFIXED_DUMMY_DATA;
+        let header_info = verifier
+            .verify_preamble(&preamble, ResetReason::ColdReset, img_bundle_sz)
+            .unwrap();
+        assert_eq!(header_info.owner_pub_keys_digest, SUFFIXED_DUMMY_DATA);
+    }
+
+    #[test]
+    #[cfg(feature = "lms-hss")]
+    fn test_header_lms_hss_chain() {
+        let test_env = TestEnv {
+            verify_result: true,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let header = ImageHeader::default();
+        let owner_lms_pubkey = ImageLmsPublicKey::default();
+        let owner_lms_sig = ImageLmsSignature::default();
+        let binding_vendor_lms_pubkey = vendor_lms_pubkey();
+        let binding_vendor_lms_sig = vendor_lms_sig();
+        let mut header_info: HeaderInfo = HeaderInfo {
+            vendor_ecc_pub_key_idx: 0,
+            vendor_lms_pub_key_idx: Some(0),
+            vendor_ecc_info: (&VENDOR_ECC_PUBKEY, &VENDOR_ECC_SIG),
+            vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
+            owner_ecc_info: (&OWNER_ECC_PUBKEY, &OWNER_ECC_SIG),
+            owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_pub_keys_digest: ImageDigest::default(),
+            owner_pub_keys_digest_in_fuses: false,
+            vendor_ecc_pub_key_revocation: Default::default(),
+            vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 2,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: Some(IMAGE_MANIFEST_BYTE_SIZE as u32),
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
+        };
+        let result = verifier.verify_header(&header, &header_info);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_SIGNATURE_INVALID)
+        );
+
+        header_info.vendor_lms_hss_levels = 1;
+        header_info.owner_lms_hss_levels = 3;
+        let result = verifier.verify_header(&header, &header_info);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_SIGNATURE_INVALID)
+        );
+
+        verifier.env.verify_lms_result = true;
+        header_info.vendor_lms_hss_levels = 3;
+        let toc_info = verifier.verify_header(&header, &header_info).unwrap();
+        assert_eq!(
+            toc_info.lms_hss_pub_keys,
+            Some(LmsHssPubKeys {
+                vendor: ImageLmsPublicKey::default(),
+                owner: ImageLmsPublicKey::default(),
+            })
+        );
+
+        // Images without the extension are never verified with the preamble
+        // keys
+        header_info.hss_ext_offset = None;
+        let result = verifier.verify_header(&header, &header_info);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_HSS_EXTENSION_MISSING)
+        );
+
+        // but with the keys verified on cold boot
+        let cold_boot_keys = LmsHssPubKeys {
+            vendor: binding_vendor_lms_pubkey,
+            owner: ImageLmsPublicKey::default(),
+        };
+        verifier.env.lms_hss_pub_keys_cold_boot = Some(cold_boot_keys);
+        let toc_info = verifier.verify_header(&header, &header_info).unwrap();
+        assert_eq!(toc_info.lms_hss_pub_keys, Some(cold_boot_keys));
+
+        verifier.env.verify_lms_result = false;
+        let result = verifier.verify_header(&header, &header_info);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_SIGNATURE_INVALID)
+        );
     }
 
     #[test]
@@ -1093,6 +1971,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1123,6 +2011,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1159,6 +2057,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1195,6 +2103,16 @@ mod tests {
             owner_pub_keys_digest: ImageDigest::default(),
             owner_pub_keys_digest_in_fuses: false,
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1229,6 +2147,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1263,6 +2191,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1297,6 +2235,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1330,6 +2278,16 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let result = verifier.verify_header(&header, &header_info);
         assert_eq!(
@@ -1366,38 +2324,127 @@ mod tests {
             owner_pub_keys_digest_in_fuses: false,
             vendor_ecc_pub_key_revocation: Default::default(),
             vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            pqc_ext_offset: None,
+            #[cfg(feature = "mldsa")]
+            mldsa_pub_key_digests: None,
         };
         let toc_info = verifier.verify_header(&header, &header_info).unwrap();
         assert_eq!(toc_info.len, 100);
         assert_eq!(toc_info.digest, &DUMMY_DATA);
     }
 
+    #[test]
+    #[cfg(feature = "mldsa")]
+    fn test_header_mldsa_signature() {
+        let test_env = TestEnv {
+            verify_result: true,
+            verify_lms_result: true,
+            mldsa_verify_enabled: true,
+            ..Default::default()
+        };
+        let mut verifier = ImageVerifier::new(test_env);
+        let header = ImageHeader::default();
+        let owner_lms_pubkey = ImageLmsPublicKey::default();
+        let owner_lms_sig = ImageLmsSignature::default();
+        let binding_vendor_lms_pubkey = vendor_lms_pubkey();
+        let binding_vendor_lms_sig = vendor_lms_sig();
+        let header_info: HeaderInfo = HeaderInfo {
+            vendor_ecc_pub_key_idx: 0,
+            vendor_lms_pub_key_idx: Some(0),
+            vendor_ecc_info: (&VENDOR_ECC_PUBKEY, &VENDOR_ECC_SIG),
+            vendor_lms_info: Some((&binding_vendor_lms_pubkey, &binding_vendor_lms_sig)),
+            owner_ecc_info: (&OWNER_ECC_PUBKEY, &OWNER_ECC_SIG),
+            owner_lms_info: Some((&owner_lms_pubkey, &owner_lms_sig)),
+            owner_pub_keys_digest: ImageDigest::default(),
+            owner_pub_keys_digest_in_fuses: false,
+            vendor_ecc_pub_key_revocation: Default::default(),
+            vendor_lms_pub_key_revocation: Default::default(),
+            #[cfg(feature = "lms-hss")]
+            vendor_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            owner_lms_hss_levels: 0,
+            #[cfg(feature = "lms-hss")]
+            hss_ext_offset: None,
+            pqc_ext_offset: Some(IMAGE_MANIFEST_BYTE_SIZE as u32),
+            mldsa_pub_key_digests: None,
+        };
+        let result = verifier.verify_header(&header, &header_info);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID)
+        );
+
+        verifier.env.verify_mldsa_result = true;
+        assert!(verifier.verify_header(&header, &header_info).is_ok());
+    }
+
     #[test]
     fn test_toc_incorrect_length() {
         let manifest = ImageManifest::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT / 2,
+            len: MAX_TOC_ENTRY_COUNT + 1,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
-        let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, manifest.size);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
+        );
+
+        let toc_info = TocInfo {
+            len: FW_TOC_ENTRY_COUNT - 1,
+            digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
+        };
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, manifest.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID)
         );
     }
 
+    #[test]
+    fn test_toc_component_count_mismatch() {
+        let manifest = ImageManifest::default();
+        let test_env = TestEnv::default();
+        let mut verifier = ImageVerifier::new(test_env);
+        let toc_info = TocInfo {
+            len: FW_TOC_ENTRY_COUNT + 1,
+            digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
+        };
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, manifest.size);
+        assert_eq!(
+            result.err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_COUNT_MISMATCH)
+        );
+    }
+
     #[test]
     fn test_toc_digest_mismatch() {
         let manifest = ImageManifest::default();
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &DUMMY_DATA,
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
-        let result = verifier.verify_toc(&manifest, &toc_info, manifest.size);
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, manifest.size);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_TOC_DIGEST_MISMATCH)
@@ -1410,8 +2457,10 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         // Case 0:
@@ -1423,6 +2472,7 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1440,6 +2490,7 @@ mod tests {
         manifest.runtime.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1457,6 +2508,7 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1474,6 +2526,7 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1491,6 +2544,7 @@ mod tests {
         manifest.fmc.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1508,6 +2562,7 @@ mod tests {
         manifest.runtime.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1525,6 +2580,7 @@ mod tests {
         manifest.fmc.size = 30;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1540,8 +2596,10 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         // FMC size == 0
@@ -1549,7 +2607,7 @@ mod tests {
         manifest.fmc.size = 0;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 200;
-        let result = verifier.verify_toc(&manifest, &toc_info, 500);
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, 500);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_FMC_SIZE_ZERO)
@@ -1560,7 +2618,7 @@ mod tests {
         manifest.fmc.size = 100;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 0;
-        let result = verifier.verify_toc(&manifest, &toc_info, 500);
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, 500);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_RUNTIME_SIZE_ZERO)
@@ -1572,7 +2630,7 @@ mod tests {
         manifest.fmc.size = 100;
         manifest.runtime.offset = 100;
         manifest.runtime.size = 200;
-        let result = verifier.verify_toc(&manifest, &toc_info, 100);
+        let result = verifier.verify_toc(&manifest, &[], &toc_info, 100);
         assert_eq!(
             result.err(),
             Some(CaliptraError::IMAGE_VERIFIER_ERR_IMAGE_LEN_MORE_THAN_BUNDLE_SIZE)
@@ -1585,8 +2643,10 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         // [-FMC--]
@@ -1599,6 +2659,7 @@ mod tests {
         manifest.runtime.load_addr = 0x2000;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1612,8 +2673,10 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         // [-FMC--]
@@ -1624,6 +2687,7 @@ mod tests {
         manifest.fmc.size = 200;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1639,8 +2703,10 @@ mod tests {
         let test_env = TestEnv::default();
         let mut verifier = ImageVerifier::new(test_env);
         let toc_info = TocInfo {
-            len: MAX_TOC_ENTRY_COUNT,
+            len: FW_TOC_ENTRY_COUNT,
             digest: &ImageDigest::default(),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         manifest.fmc.offset = 0;
@@ -1657,6 +2723,7 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1674,6 +2741,7 @@ mod tests {
         manifest.runtime.size = 100;
         let result = verifier.verify_toc(
             &manifest,
+            &[],
             &toc_info,
             manifest.size + manifest.fmc.image_size() + manifest.runtime.image_size(),
         );
@@ -1811,11 +2879,116 @@ mod tests {
         assert_eq!(info.size, 100);
     }
 
+    /// Manifest and two components laid out as the image generator does
+    fn component_manifest() -> (ImageManifest, [ImageTocEntry; 2]) {
+        let mut manifest = ImageManifest::default();
+        manifest.fmc.offset = ImageManifest::toc_range_with_components(2).end;
+        manifest.fmc.size = 100;
+        manifest.fmc.load_addr = ICCM_ORG;
+        manifest.runtime.offset = manifest.fmc.offset + manifest.fmc.size;
+        manifest.runtime.size = 200;
+        manifest.runtime.load_addr = ICCM_ORG + 0x1000;
+
+        let data = ImageTocEntry {
+            id: 0x10,
+            r#type: ImageTocEntryType::Data.into(),
+            offset: manifest.runtime.offset + manifest.runtime.size,
+            size: 64,
+            load_addr: ICCM_ORG + 0x2000,
+            ..Default::default()
+        };
+        let exe = ImageTocEntry {
+            id: 0x11,
+            r#type: ImageTocEntryType::Executable.into(),
+            offset: data.offset + data.size,
+            size: 128,
+            load_addr: ICCM_ORG + 0x3000,
+            entry_point: ICCM_ORG + 0x3040,
+            ..Default::default()
+        };
+        (manifest, [data, exe])
+    }
+
+    #[test]
+    fn test_components_success() {
+        let (manifest, components) = component_manifest();
+        let mut verifier = ImageVerifier::new(TestEnv::default());
+        assert_eq!(
+            verifier.verify_components(&manifest, &components).err(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_components_invalid() {
+        type Modify = fn(&mut ImageManifest, &mut [ImageTocEntry; 2]);
+        let cases: [(Modify, CaliptraError); 8] = [
+            (
+                |m, _| m.fmc.offset = m.size,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_OFFSET_INVALID,
+            ),
+            (
+                |_, c| c[1].id = ImageTocEntryId::Runtime.into(),
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_ID_INVALID,
+            ),
+            (
+                |_, c| c[1].id = c[0].id,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_ID_INVALID,
+            ),
+            (
+                |_, c| c[0].r#type = 3,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_TYPE_INVALID,
+            ),
+            (
+                |_, c| c[0].size = 62,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_SIZE_INVALID,
+            ),
+            (
+                |_, c| c[1].load_addr = ICCM_ORG + ICCM_SIZE - 64,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_INVALID,
+            ),
+            (
+                |m, c| c[0].load_addr = m.runtime.load_addr + 0x40,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_LOAD_ADDR_OVERLAP,
+            ),
+            (
+                |_, c| c[1].entry_point = c[1].load_addr + c[1].size,
+                CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_ENTRY_POINT_INVALID,
+            ),
+        ];
+        for (modify, err) in cases {
+            let (mut manifest, mut components) = component_manifest();
+            modify(&mut manifest, &mut components);
+            let mut verifier = ImageVerifier::new(TestEnv::default());
+            assert_eq!(
+                verifier.verify_components(&manifest, &components).err(),
+                Some(err)
+            );
+        }
+    }
+
+    #[test]
+    fn test_components_digest_mismatch() {
+        let (manifest, mut components) = component_manifest();
+        components[1].digest = DUMMY_DATA;
+        let mut verifier = ImageVerifier::new(TestEnv::default());
+        assert_eq!(
+            verifier.verify_components(&manifest, &components).err(),
+            Some(CaliptraError::IMAGE_VERIFIER_ERR_COMPONENT_DIGEST_MISMATCH)
+        );
+    }
+
     struct TestEnv {
         digest: ImageDigest,
         fmc_digest: ImageDigest,
         verify_result: bool,
         verify_lms_result: bool,
+        verify_mldsa_result: bool,
+        mldsa_verify_enabled: bool,
+        lms_hss_verify_enabled: bool,
+        suffixed_digest: ImageDigest,
+        mldsa_pub_key_digests_cold_boot: Option<Mldsa87PubKeyDigests>,
+        lms_hss_pub_keys_cold_boot: Option<LmsHssPubKeys>,
         vendor_pub_key_digest: ImageDigest,
         vendor_ecc_pub_key_revocation: VendorPubKeyRevocation,
         vendor_lms_pub_key_revocation: u32,
@@ -1830,6 +3003,12 @@ mod tests {
                 fmc_digest: ImageDigest::default(),
                 verify_result: false,
                 verify_lms_result: false,
+                verify_mldsa_result: false,
+                mldsa_verify_enabled: false,
+                lms_hss_verify_enabled: true,
+                suffixed_digest: ImageDigest::default(),
+                mldsa_pub_key_digests_cold_boot: None,
+                lms_hss_pub_keys_cold_boot: None,
                 vendor_pub_key_digest: ImageDigest::default(),
                 vendor_ecc_pub_key_revocation: VendorPubKeyRevocation::default(),
                 vendor_lms_pub_key_revocation: 0,
@@ -1844,6 +3023,15 @@ mod tests {
             Ok(self.digest)
         }
 
+        fn sha384_digest_with_suffix(
+            &mut self,
+            _offset: u32,
+            _len: u32,
+            _suffix: &[u8],
+        ) -> CaliptraResult<ImageDigest> {
+            Ok(self.suffixed_digest)
+        }
+
         fn ecc384_verify(
             &mut self,
             _digest: &ImageDigest,
@@ -1870,6 +3058,23 @@ mod tests {
             }
         }
 
+        fn mldsa87_verify(
+            &mut self,
+            _digest: &ImageDigest,
+            _pub_key_offset: u32,
+            _sig_offset: u32,
+        ) -> CaliptraResult<Mldsa87Result> {
+            if self.verify_mldsa_result {
+                Ok(Mldsa87Result::Success)
+            } else {
+                Ok(Mldsa87Result::SigVerifyFailed)
+            }
+        }
+
+        fn lms_hss_signed_pub_key(&self, _offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+            Ok(ImageLmsSignedPubKey::default())
+        }
+
         fn vendor_pub_key_digest(&self) -> ImageDigest {
             self.vendor_pub_key_digest
         }
@@ -1906,6 +3111,14 @@ mod tests {
             self.owner_pub_key_digest
         }
 
+        fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+            self.mldsa_pub_key_digests_cold_boot
+        }
+
+        fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+            self.lms_hss_pub_keys_cold_boot
+        }
+
         fn get_fmc_digest_dv(&self) -> ImageDigest {
             self.fmc_digest
         }
@@ -1929,6 +3142,14 @@ mod tests {
             true
         }
 
+        fn lms_hss_verify_enabled(&self) -> bool {
+            self.lms_hss_verify_enabled
+        }
+
+        fn mldsa87_verify_enabled(&self) -> bool {
+            self.mldsa_verify_enabled
+        }
+
         fn set_fw_extended_error(&mut self, _err: u32) {}
     }
 }
@@ -12,6 +12,9 @@ Abstract:
 
 --*/
 
+#[cfg(feature = "crash-record")]
+use caliptra_drivers::CrashRecord;
+
 /// Exception Record
 #[repr(C)]
 pub(crate) struct ExceptionRecord {
@@ -38,3 +41,26 @@ pub(crate) struct ExceptionRecord {
     pub mstatus: u32,
     pub mtval: u32,
 }
+
+#[cfg(feature = "crash-record")]
+impl ExceptionRecord {
+    /// Start a crash record from the exception state. The crash record is
+    /// completed when the fatal error is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `err_interrupt_status` - error_internal_intr_r, only available on NMI
+    pub(crate) fn record_crash(&self, err_interrupt_status: Option<u32>) {
+        let crash_record = unsafe { CrashRecord::get_mut() };
+        crash_record.begin_trap(&[
+            self.ra, self.sp, self.a0, self.a1, self.a2, self.a3, self.a4, self.a5, self.a6,
+            self.a7, self.t0, self.t1, self.t2, self.t3, self.t4, self.t5, self.t6,
+        ]);
+        crash_record.mcause = self.mcause;
+        crash_record.mscause = self.mscause;
+        crash_record.mepc = self.mepc;
+        crash_record.mtval = self.mtval;
+        crash_record.mstatus = self.mstatus;
+        crash_record.err_interrupt_status = err_interrupt_status.unwrap_or_default();
+    }
+}
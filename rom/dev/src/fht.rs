@@ -24,7 +24,11 @@ use caliptra_drivers::{
 };
 
 const FHT_MAJOR_VERSION: u16 = 1;
+#[cfg(not(feature = "components"))]
 const FHT_MINOR_VERSION: u16 = 0;
+// The component entries are only filled in with the components feature
+#[cfg(feature = "components")]
+const FHT_MINOR_VERSION: u16 = 1;
 
 #[derive(Debug, Default)]
 pub struct FhtDataStore {}
@@ -21,6 +21,8 @@ use crate::cprintln;
 use crate::flow::cold_reset::{copy_tbs, TbsType};
 use crate::print::HexBytes;
 use crate::rom_env::RomEnv;
+#[cfg(feature = "cbor-certs")]
+use caliptra_cbor_cert::{ConfigDescriptor, DiceCwtParams, DiceCwtTbs};
 use caliptra_cfi_derive::cfi_impl_fn;
 use caliptra_cfi_lib::{cfi_assert, cfi_assert_eq, cfi_launder};
 use caliptra_common::dice;
@@ -28,6 +30,8 @@ use caliptra_common::keyids::{KEY_ID_FMC_PRIV_KEY, KEY_ID_ROM_FMC_CDI};
 use caliptra_common::pcr::PCR_ID_FMC_CURRENT;
 use caliptra_common::RomBootStatus::*;
 use caliptra_drivers::{okmutref, report_boot_status, Array4x12, CaliptraResult, KeyId, Lifecycle};
+#[cfg(feature = "cbor-certs")]
+use caliptra_drivers::{CaliptraError, DiceCertFormat};
 use caliptra_x509::{FmcAliasCertTbs, FmcAliasCertTbsParams};
 use zeroize::Zeroize;
 
@@ -173,33 +177,67 @@ impl FmcAliasLayer {
         hasher.update(&<[u8; 48]>::from(env.data_vault.owner_pk_hash()))?;
         hasher.finalize(&mut fuse_info_digest)?;
 
-        // Certificate `To Be Signed` Parameters
-        let params = FmcAliasCertTbsParams {
-            ueid: &X509::ueid(env)?,
-            subject_sn: &output.subj_sn,
-            subject_key_id: &output.subj_key_id,
-            issuer_sn: input.auth_sn,
-            authority_key_id: input.auth_key_id,
-            serial_number: &X509::cert_sn(env, pub_key)?,
-            public_key: &pub_key.to_der(),
-            tcb_info_fmc_tci: &(&env.data_vault.fmc_tci()).into(),
-            tcb_info_device_info_hash: &fuse_info_digest.into(),
-            tcb_info_flags: &flags,
-            tcb_info_fmc_svn: &svn.to_be_bytes(),
-            tcb_info_fmc_svn_fuses: &fuse_svn.to_be_bytes(),
-            not_before: &fw_proc_info.fmc_cert_valid_not_before.value,
-            not_after: &fw_proc_info.fmc_cert_valid_not_after.value,
+        let fmc_tci: [u8; 48] = (&env.data_vault.fmc_tci()).into();
+        let fuse_info_digest: [u8; 48] = fuse_info_digest.into();
+
+        let x509_tbs;
+        #[cfg(feature = "cbor-certs")]
+        let cwt_tbs;
+        #[cfg_attr(not(feature = "cbor-certs"), allow(clippy::match_single_binding))]
+        let (tbs, signed_data): (&[u8], &[u8]) = match X509::dice_cert_format(env) {
+            #[cfg(feature = "cbor-certs")]
+            DiceCertFormat::Cbor => {
+                // CWT claims, signed through their COSE Sig_structure
+                let params = DiceCwtParams {
+                    issuer: input.auth_sn,
+                    subject: &output.subj_sn,
+                    public_key_x: &(&pub_key.x).into(),
+                    public_key_y: &(&pub_key.y).into(),
+                    mode: X509::dice_mode(env),
+                    code_hash: Some(&fmc_tci),
+                    authority_hash: Some(&fuse_info_digest),
+                    config_desc: Some(ConfigDescriptor {
+                        component_name: "caliptra-fmc",
+                        security_version: svn.into(),
+                    }),
+                };
+                cwt_tbs =
+                    DiceCwtTbs::new(&params).ok_or(CaliptraError::ROM_GLOBAL_CWT_ENCODE_FAILURE)?;
+                (cwt_tbs.tbs(), cwt_tbs.sig_structure())
+            }
+            // X.509, the only format issued without the cbor-certs feature
+            _ => {
+                // Certificate `To Be Signed` Parameters
+                let params = FmcAliasCertTbsParams {
+                    ueid: &X509::ueid(env)?,
+                    subject_sn: &output.subj_sn,
+                    subject_key_id: &output.subj_key_id,
+                    issuer_sn: input.auth_sn,
+                    authority_key_id: input.auth_key_id,
+                    serial_number: &X509::cert_sn(env, pub_key)?,
+                    public_key: &pub_key.to_der(),
+                    tcb_info_fmc_tci: &fmc_tci,
+                    tcb_info_device_info_hash: &fuse_info_digest,
+                    tcb_info_flags: &flags,
+                    tcb_info_fmc_svn: &svn.to_be_bytes(),
+                    tcb_info_fmc_svn_fuses: &fuse_svn.to_be_bytes(),
+                    not_before: &fw_proc_info.fmc_cert_valid_not_before.value,
+                    not_after: &fw_proc_info.fmc_cert_valid_not_after.value,
+                };
+
+                // Generate the `To Be Signed` portion of the CSR
+                x509_tbs = FmcAliasCertTbs::new(&params);
+                (x509_tbs.tbs(), x509_tbs.tbs())
+            }
         };
 
-        // Generate the `To Be Signed` portion of the CSR
-        let tbs = FmcAliasCertTbs::new(&params);
-
         // Sign the the `To Be Signed` portion
         cprintln!(
             "[afmc] Signing Cert with AUTHORITY.KEYID = {}",
             auth_priv_key as u8
         );
-        let mut sig = Crypto::ecdsa384_sign_and_verify(env, auth_priv_key, auth_pub_key, tbs.tbs());
+        let mut sig =
+            Crypto::ecdsa384_sign_and_verify(env, auth_priv_key, auth_pub_key, signed_data);
         let sig = okmutref(&mut sig)?;
 
         // Clear the authority private key
@@ -227,7 +265,7 @@ impl FmcAliasLayer {
         env.data_vault.set_fmc_pub_key(pub_key);
 
         //  Copy TBS to DCCM.
-        copy_tbs(tbs.tbs(), TbsType::FmcaliasTbs, env)?;
+        copy_tbs(tbs, TbsType::FmcaliasTbs, env)?;
 
         report_boot_status(FmcAliasCertSigGenerationComplete.into());
         Ok(())
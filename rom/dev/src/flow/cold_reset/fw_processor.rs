@@ -13,6 +13,8 @@ Abstract:
 --*/
 #[cfg(feature = "fake-rom")]
 use crate::flow::fake::FakeRomImageVerificationEnv;
+#[cfg(feature = "components")]
+use crate::flow::ImageComponents;
 use crate::fuse::log_fuse_data;
 use crate::pcr;
 use crate::rom_env::RomEnv;
@@ -20,7 +22,11 @@ use crate::run_fips_tests;
 use caliptra_cfi_derive::cfi_impl_fn;
 use caliptra_cfi_lib::CfiCounter;
 use caliptra_common::capabilities::Capabilities;
+#[cfg(feature = "crash-record")]
+use caliptra_common::crash_info::GetCrashInfoCmd;
 use caliptra_common::fips::FipsVersionCmd;
+#[cfg(feature = "idevid-csr")]
+use caliptra_common::mailbox_api::SetIdevCsrNonceReq;
 use caliptra_common::mailbox_api::{
     CapabilitiesResp, CommandId, MailboxReqHeader, MailboxRespHeader, Response,
     StashMeasurementReq, StashMeasurementResp,
@@ -49,12 +55,14 @@ pub struct FwProcInfo {
     pub fmc_effective_fuse_svn: u32,
 
     pub owner_pub_keys_digest_in_fuses: bool,
+
+    pub idevid_csr_nonce: Option<[u8; 32]>,
 }
 
 pub struct FirmwareProcessor {}
 
 impl FirmwareProcessor {
-    pub fn process(env: &mut RomEnv) -> CaliptraResult<FwProcInfo> {
+    pub fn process(env: &mut RomEnv, store_idev_csr: bool) -> CaliptraResult<FwProcInfo> {
         let mut kats_env = caliptra_kat::KatsEnv {
             // SHA1 Engine
             sha1: &mut env.sha1,
@@ -84,18 +92,27 @@ impl FirmwareProcessor {
             sha_acc_lock_state: ShaAccLockState::NotAcquired,
         };
         // Process mailbox commands.
+        let mut idevid_csr_nonce = None;
         let mut txn = Self::process_mailbox_commands(
             &mut env.soc_ifc,
             &mut env.mbox,
             &mut env.pcr_bank,
             &mut kats_env,
             env.persistent_data.get_mut(),
+            store_idev_csr,
+            &mut idevid_csr_nonce,
         )?;
 
         // Load the manifest
         let manifest = Self::load_manifest(&mut env.persistent_data, &mut txn);
         let manifest = okref(&manifest)?;
 
+        // Load the component TOC entries
+        #[cfg(feature = "components")]
+        let components = ImageComponents::load_toc(manifest, &mut txn);
+        #[cfg(feature = "components")]
+        let components = okref(&components)?;
+
         let mut venv = FirmwareImageVerificationEnv {
             sha256: &mut env.sha256,
             sha384: &mut env.sha384,
@@ -104,9 +121,17 @@ impl FirmwareProcessor {
             data_vault: &mut env.data_vault,
             pcr_bank: &mut env.pcr_bank,
             image: txn.raw_mailbox_contents(),
+            image_extensions: true,
+            #[cfg(feature = "mldsa")]
+            mldsa87_pub_key_digests: None,
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: None,
         };
 
         // Verify the image
+        #[cfg(feature = "components")]
+        let info = Self::verify_image(&mut venv, manifest, components, txn.dlen());
+        #[cfg(not(feature = "components"))]
         let info = Self::verify_image(&mut venv, manifest, txn.dlen());
         let info = okref(&info)?;
 
@@ -116,12 +141,39 @@ impl FirmwareProcessor {
         Self::populate_data_vault(venv.data_vault, info, &env.persistent_data);
 
         // Extend PCR0 and PCR1
+        #[cfg(feature = "components")]
+        pcr::extend_pcrs(&mut venv, info, components, &mut env.persistent_data)?;
+        #[cfg(not(feature = "components"))]
         pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
         report_boot_status(FwProcessorExtendPcrComplete.into());
 
         // Load the image
+        #[cfg(feature = "components")]
+        {
+            Self::load_image(manifest, components, &mut txn)?;
+            components.store(&mut env.persistent_data.get_mut().fht);
+        }
+        #[cfg(not(feature = "components"))]
         Self::load_image(manifest, &mut txn)?;
 
+        // Runtime re-verifies the image, which it rebuilds without the PQC
+        // extension, against the ML-DSA public keys verified here
+        #[cfg(feature = "mldsa")]
+        if let Some(digests) = info.mldsa_pub_key_digests {
+            let fht = &mut env.persistent_data.get_mut().fht;
+            fht.vendor_mldsa_pub_key_digest = digests.vendor;
+            fht.owner_mldsa_pub_key_digest = digests.owner;
+        }
+
+        // The rebuilt image has no HSS extension either, so Runtime also
+        // re-verifies it against the LMS keys at the bottom of the HSS chains
+        #[cfg(feature = "lms-hss")]
+        if let Some(keys) = info.lms_hss_pub_keys {
+            let fht = &mut env.persistent_data.get_mut().fht;
+            fht.vendor_lms_hss_pub_key = zerocopy::transmute!(keys.vendor);
+            fht.owner_lms_hss_pub_key = zerocopy::transmute!(keys.owner);
+        }
+
         // Complete the mailbox transaction indicating success.
         txn.complete(true)?;
         report_boot_status(FwProcessorFirmwareDownloadTxComplete.into());
@@ -139,6 +191,7 @@ impl FirmwareProcessor {
             fmc_cert_valid_not_after: nf,
             fmc_effective_fuse_svn: info.fmc.effective_fuse_svn,
             owner_pub_keys_digest_in_fuses: info.owner_pub_keys_digest_in_fuses,
+            idevid_csr_nonce,
         })
     }
 
@@ -151,6 +204,8 @@ impl FirmwareProcessor {
     /// * `pcr_bank` - PCR Bank
     /// * `sha384` - SHA384
     /// * `persistent_data` - Persistent data
+    /// * `store_idev_csr` - Whether the nonce-bearing IDevID CSR was requested
+    /// * `idevid_csr_nonce` - Nonce received for the IDevID CSR, if any
     ///
     /// # Returns
     /// * `MailboxRecvTxn` - Mailbox Receive Transaction
@@ -167,7 +222,11 @@ impl FirmwareProcessor {
         pcr_bank: &mut PcrBank,
         env: &mut KatsEnv,
         persistent_data: &mut PersistentData,
+        store_idev_csr: bool,
+        idevid_csr_nonce: &mut Option<[u8; 32]>,
     ) -> CaliptraResult<ManuallyDrop<MailboxRecvTxn<'a>>> {
+        #[cfg(not(feature = "idevid-csr"))]
+        let _ = (store_idev_csr, idevid_csr_nonce);
         let mut self_test_in_progress = false;
 
         cprintln!("[fwproc] Waiting for Commands...");
@@ -267,6 +326,15 @@ impl FirmwareProcessor {
                         txn.send_response(resp.as_bytes())?;
                         continue;
                     }
+                    #[cfg(feature = "crash-record")]
+                    CommandId::GET_CRASH_INFO => {
+                        let mut request = MailboxReqHeader::default();
+                        Self::copy_req_verify_chksum(&mut txn, request.as_bytes_mut())?;
+
+                        let mut resp = GetCrashInfoCmd::execute(&persistent_data.crash_record);
+                        resp.populate_chksum();
+                        txn.send_response(resp.as_bytes())?;
+                    }
                     CommandId::STASH_MEASUREMENT => {
                         if persistent_data.fht.meas_log_index == MEASUREMENT_MAX_COUNT as u32 {
                             cprintln!(
@@ -286,6 +354,17 @@ impl FirmwareProcessor {
                         resp.populate_chksum();
                         txn.send_response(resp.as_bytes())?;
                     }
+                    #[cfg(feature = "idevid-csr")]
+                    CommandId::SET_IDEV_CSR_NONCE if store_idev_csr => {
+                        let mut request = SetIdevCsrNonceReq::default();
+                        Self::copy_req_verify_chksum(&mut txn, request.as_bytes_mut())?;
+
+                        *idevid_csr_nonce = Some(request.nonce);
+
+                        let mut resp = MailboxRespHeader::default();
+                        resp.populate_chksum();
+                        txn.send_response(resp.as_bytes())?;
+                    }
                     _ => {
                         cprintln!("[fwproc] Invalid command received");
                         // Don't complete the transaction here; let the fatal
@@ -323,6 +402,7 @@ impl FirmwareProcessor {
     fn verify_image(
         venv: &mut FirmwareImageVerificationEnv,
         manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &ImageComponents,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageVerificationInfo> {
         #[cfg(feature = "fake-rom")]
@@ -342,6 +422,14 @@ impl FirmwareProcessor {
         CfiCounter::delay();
 
         let mut verifier = ImageVerifier::new(venv);
+        #[cfg(feature = "components")]
+        let info = verifier.verify(
+            manifest,
+            components.entries(),
+            img_bundle_sz,
+            ResetReason::ColdReset,
+        )?;
+        #[cfg(not(feature = "components"))]
         let info = verifier.verify(manifest, img_bundle_sz, ResetReason::ColdReset)?;
 
         cprintln!(
@@ -450,13 +538,18 @@ impl FirmwareProcessor {
     ///
     /// # Arguments
     ///
-    /// * `env`      - ROM Environment
-    /// * `manifest` - Manifest
-    /// * `txn`      - Mailbox Receive Transaction
+    /// * `env`        - ROM Environment
+    /// * `manifest`   - Manifest
+    /// * `components` - Component TOC entries
+    /// * `txn`        - Mailbox Receive Transaction
     // Inlined to reduce ROM size
     #[inline(always)]
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
-    fn load_image(manifest: &ImageManifest, txn: &mut MailboxRecvTxn) -> CaliptraResult<()> {
+    fn load_image(
+        manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &ImageComponents,
+        txn: &mut MailboxRecvTxn,
+    ) -> CaliptraResult<()> {
         cprintln!(
             "[fwproc] Loading FMC at address 0x{:08x} len {}",
             manifest.fmc.load_addr,
@@ -483,6 +576,9 @@ impl FirmwareProcessor {
 
         txn.copy_request(runtime_dest.as_bytes_mut())?;
 
+        #[cfg(feature = "components")]
+        components.load(txn)?;
+
         report_boot_status(FwProcessorLoadImageComplete.into());
         Ok(())
     }
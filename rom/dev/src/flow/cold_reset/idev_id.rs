@@ -288,6 +288,108 @@ impl InitDevIdLayer {
         result
     }
 
+    /// Store the nonce-bearing Initial Device ID CSR in persistent data, if
+    /// requested, and erase the Initial Device ID private key
+    ///
+    /// # Arguments
+    ///
+    /// * `env`            - ROM Environment
+    /// * `output`         - DICE Output
+    /// * `store_idev_csr` - Whether the nonce-bearing CSR was requested
+    /// * `nonce`          - Nonce received from the SOC, if any
+    #[cfg(feature = "idevid-csr")]
+    pub fn store_csr(
+        env: &mut RomEnv,
+        output: &DiceOutput,
+        store_idev_csr: bool,
+        nonce: Option<&[u8; 32]>,
+    ) -> CaliptraResult<()> {
+        env.persistent_data.get_mut().idevid_csr.zeroize();
+
+        let result = match (store_idev_csr, nonce) {
+            (true, Some(nonce)) => Self::make_nonce_csr(env, output, nonce),
+            (true, None) => {
+                cprintln!("[idev] No CSR nonce received");
+                Ok(())
+            }
+            (false, _) => Ok(()),
+        };
+
+        let priv_key = output.subj_key_pair.priv_key;
+        cprintln!("[idev] Erasing SUBJECT.KEYID = {}", priv_key as u8);
+        env.key_vault.erase_key(priv_key)?;
+
+        result
+    }
+
+    /// Create Initial Device ID CSR carrying a nonce in its challengePassword
+    /// attribute
+    ///
+    /// # Arguments
+    ///
+    /// * `env`    - ROM Environment
+    /// * `output` - DICE Output
+    /// * `nonce`  - Nonce received from the SOC
+    #[cfg(feature = "idevid-csr")]
+    fn make_nonce_csr(
+        env: &mut RomEnv,
+        output: &DiceOutput,
+        nonce: &[u8; 32],
+    ) -> CaliptraResult<()> {
+        let key_pair = &output.subj_key_pair;
+
+        // CSR `To Be Signed` Parameters
+        let params = InitDevIdNonceCsrTbsParams {
+            // Unique Endpoint Identifier
+            ueid: &X509::ueid(env)?,
+
+            // Subject Name
+            subject_sn: &output.subj_sn,
+
+            // Public Key
+            public_key: &key_pair.pub_key.to_der(),
+
+            // Nonce
+            nonce: &X509::hex(nonce),
+        };
+
+        // Generate the `To Be Signed` portion of the CSR
+        let tbs = InitDevIdNonceCsrTbs::new(&params);
+
+        cprintln!(
+            "[idev] Signing nonce CSR with SUBJECT.KEYID = {}",
+            key_pair.priv_key as u8
+        );
+
+        // Sign the `To Be Signed` portion
+        let mut sig =
+            Crypto::ecdsa384_sign_and_verify(env, key_pair.priv_key, &key_pair.pub_key, tbs.tbs());
+        let sig = okmutref(&mut sig)?;
+
+        // Build the CSR with `To Be Signed` & `Signature`
+        let mut csr = [0u8; IDEVID_CSR_MAX_SIZE];
+        let result = Ecdsa384CsrBuilder::new(tbs.tbs(), &sig.to_ecdsa())
+            .ok_or(CaliptraError::ROM_IDEVID_CSR_BUILDER_INIT_FAILURE);
+        sig.zeroize();
+
+        let csr_bldr = result?;
+        let csr_len = csr_bldr
+            .build(&mut csr)
+            .ok_or(CaliptraError::ROM_IDEVID_CSR_BUILDER_BUILD_FAILURE)?;
+
+        let csr = csr
+            .get(..csr_len)
+            .ok_or(CaliptraError::ROM_IDEVID_CSR_OVERFLOW)?;
+        env.persistent_data
+            .get_mut()
+            .idevid_csr
+            .set(csr)
+            .ok_or(CaliptraError::ROM_IDEVID_CSR_OVERFLOW)?;
+
+        cprintln!("[idev] Stored nonce CSR of size {} bytes", csr_len);
+        Ok(())
+    }
+
     /// Send Initial Device ID CSR to SOC
     ///
     /// # Argument
@@ -20,6 +20,8 @@ use crate::cprintln;
 use crate::flow::cold_reset::{copy_tbs, TbsType};
 use crate::print::HexBytes;
 use crate::rom_env::RomEnv;
+#[cfg(feature = "cbor-certs")]
+use caliptra_cbor_cert::{DiceCwtParams, DiceCwtTbs};
 use caliptra_cfi_derive::cfi_impl_fn;
 use caliptra_cfi_lib::{cfi_assert, cfi_assert_eq, cfi_launder};
 use caliptra_common::keyids::{KEY_ID_FE, KEY_ID_LDEVID_PRIV_KEY, KEY_ID_ROM_FMC_CDI};
@@ -37,14 +39,19 @@ impl LocalDevIdLayer {
     ///
     /// # Arguments
     ///
-    /// * `env`   - ROM Environment
-    /// * `input` - Dice input
+    /// * `env`            - ROM Environment
+    /// * `input`          - Dice input
+    /// * `store_idev_csr` - Retain the authority key for the nonce-bearing IDevID CSR
     ///
     /// # Returns
     ///
     /// * `DiceOutput` - key pair, subject identifier serial number, subject key identifier
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
-    pub fn derive(env: &mut RomEnv, input: &DiceInput) -> CaliptraResult<DiceOutput> {
+    pub fn derive(
+        env: &mut RomEnv,
+        input: &DiceInput,
+        store_idev_csr: bool,
+    ) -> CaliptraResult<DiceOutput> {
         cprintln!("[ldev] ++");
         cprintln!("[ldev] CDI.KEYID = {}", KEY_ID_ROM_FMC_CDI as u8);
         cprintln!("[ldev] SUBJECT.KEYID = {}", KEY_ID_LDEVID_PRIV_KEY as u8);
@@ -81,7 +88,7 @@ impl LocalDevIdLayer {
         };
 
         // Generate Local Device ID Certificate
-        Self::generate_cert_sig(env, input, &output)?;
+        Self::generate_cert_sig(env, input, &output, store_idev_csr)?;
 
         cprintln!("[ldev] --");
         report_boot_status(LDevIdDerivationComplete.into());
@@ -138,50 +145,83 @@ impl LocalDevIdLayer {
     ///
     /// # Arguments
     ///
-    /// * `env`    - ROM Environment
-    /// * `input`  - DICE Input
-    /// * `output` - DICE Output
+    /// * `env`            - ROM Environment
+    /// * `input`          - DICE Input
+    /// * `output`         - DICE Output
+    /// * `store_idev_csr` - Retain the authority key for the nonce-bearing IDevID CSR
     fn generate_cert_sig(
         env: &mut RomEnv,
         input: &DiceInput,
         output: &DiceOutput,
+        store_idev_csr: bool,
     ) -> CaliptraResult<()> {
         let auth_priv_key = input.auth_key_pair.priv_key;
         let auth_pub_key = &input.auth_key_pair.pub_key;
         let pub_key = &output.subj_key_pair.pub_key;
 
-        let serial_number = X509::cert_sn(env, pub_key);
-        let serial_number = okref(&serial_number)?;
-
-        // CSR `To Be Signed` Parameters
-        let params = LocalDevIdCertTbsParams {
-            ueid: &X509::ueid(env)?,
-            subject_sn: &output.subj_sn,
-            subject_key_id: &output.subj_key_id,
-            issuer_sn: input.auth_sn,
-            authority_key_id: input.auth_key_id,
-            serial_number,
-            public_key: &pub_key.to_der(),
-            not_before: &NotBefore::default().value,
-            not_after: &NotAfter::default().value,
+        let x509_tbs;
+        #[cfg(feature = "cbor-certs")]
+        let cwt_tbs;
+        #[cfg_attr(not(feature = "cbor-certs"), allow(clippy::match_single_binding))]
+        let (tbs, signed_data): (&[u8], &[u8]) = match X509::dice_cert_format(env) {
+            #[cfg(feature = "cbor-certs")]
+            DiceCertFormat::Cbor => {
+                // CWT claims, signed through their COSE Sig_structure
+                let params = DiceCwtParams {
+                    issuer: input.auth_sn,
+                    subject: &output.subj_sn,
+                    public_key_x: &(&pub_key.x).into(),
+                    public_key_y: &(&pub_key.y).into(),
+                    mode: X509::dice_mode(env),
+                    code_hash: None,
+                    authority_hash: None,
+                    config_desc: None,
+                };
+                cwt_tbs =
+                    DiceCwtTbs::new(&params).ok_or(CaliptraError::ROM_GLOBAL_CWT_ENCODE_FAILURE)?;
+                (cwt_tbs.tbs(), cwt_tbs.sig_structure())
+            }
+            // X.509, the only format issued without the cbor-certs feature
+            _ => {
+                let serial_number = X509::cert_sn(env, pub_key);
+                let serial_number = okref(&serial_number)?;
+
+                // CSR `To Be Signed` Parameters
+                let params = LocalDevIdCertTbsParams {
+                    ueid: &X509::ueid(env)?,
+                    subject_sn: &output.subj_sn,
+                    subject_key_id: &output.subj_key_id,
+                    issuer_sn: input.auth_sn,
+                    authority_key_id: input.auth_key_id,
+                    serial_number,
+                    public_key: &pub_key.to_der(),
+                    not_before: &NotBefore::default().value,
+                    not_after: &NotAfter::default().value,
+                };
+
+                // Generate the `To Be Signed` portion of the CSR
+                x509_tbs = LocalDevIdCertTbs::new(&params);
+                (x509_tbs.tbs(), x509_tbs.tbs())
+            }
         };
 
-        // Generate the `To Be Signed` portion of the CSR
-        let tbs = LocalDevIdCertTbs::new(&params);
-
         // Sign the `To Be Signed` portion
         cprintln!(
             "[ldev] Signing Cert with AUTHORITY.KEYID = {}",
             auth_priv_key as u8
         );
-        let mut sig = Crypto::ecdsa384_sign_and_verify(env, auth_priv_key, auth_pub_key, tbs.tbs());
+        let mut sig =
+            Crypto::ecdsa384_sign_and_verify(env, auth_priv_key, auth_pub_key, signed_data);
         let sig = okmutref(&mut sig)?;
 
-        // Clear the authority private key
-        env.key_vault.erase_key(auth_priv_key).map_err(|err| {
-            sig.zeroize();
-            err
-        })?;
+        // Clear the authority private key, unless it is retained to sign the
+        // nonce-bearing IDevID CSR once firmware processing completes
+        if !store_idev_csr {
+            env.key_vault.erase_key(auth_priv_key).map_err(|err| {
+                sig.zeroize();
+                err
+            })?;
+        }
 
         let _pub_x: [u8; 48] = (&pub_key.x).into();
         let _pub_y: [u8; 48] = (&pub_key.y).into();
@@ -203,7 +243,7 @@ impl LocalDevIdLayer {
         env.data_vault.set_ldev_dice_pub_key(pub_key);
 
         //  Copy TBS to DCCM.
-        copy_tbs(tbs.tbs(), TbsType::LdevidTbs, env)?;
+        copy_tbs(tbs, TbsType::LdevidTbs, env)?;
 
         report_boot_status(LDevIdCertSigGenerationComplete.into());
         Ok(())
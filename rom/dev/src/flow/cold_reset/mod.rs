@@ -60,18 +60,45 @@ impl ColdResetFlow {
         // Initialize FHT
         fht::initialize_fht(env);
 
+        // Sample the SOC-writable request to store the nonce-bearing IDEVID
+        // CSR once, so every layer acts on the same value
+        #[cfg(feature = "idevid-csr")]
+        let store_idev_csr = env.soc_ifc.mfg_flag_store_idev_id_csr();
+        #[cfg(not(feature = "idevid-csr"))]
+        let store_idev_csr = false;
+
         // Execute IDEVID layer
         let mut idevid_layer_output = InitDevIdLayer::derive(env)?;
         let ldevid_layer_input = dice_input_from_output(&idevid_layer_output);
 
         // Execute LDEVID layer
-        let result = LocalDevIdLayer::derive(env, &ldevid_layer_input);
-        idevid_layer_output.zeroize();
-        let mut ldevid_layer_output = result?;
+        let result = LocalDevIdLayer::derive(env, &ldevid_layer_input, store_idev_csr);
+        let mut ldevid_layer_output = result.map_err(|err| {
+            idevid_layer_output.zeroize();
+            err
+        })?;
         let fmc_layer_input = dice_input_from_output(&ldevid_layer_output);
 
         // Download and validate firmware.
-        let mut fw_proc_info = FirmwareProcessor::process(env)?;
+        let mut fw_proc_info = FirmwareProcessor::process(env, store_idev_csr).map_err(|err| {
+            idevid_layer_output.zeroize();
+            err
+        })?;
+
+        // Store the nonce-bearing IDEVID CSR, if requested
+        #[cfg(feature = "idevid-csr")]
+        {
+            let result = InitDevIdLayer::store_csr(
+                env,
+                &idevid_layer_output,
+                store_idev_csr,
+                fw_proc_info.idevid_csr_nonce.as_ref(),
+            );
+            idevid_layer_output.zeroize();
+            result?;
+        }
+        #[cfg(not(feature = "idevid-csr"))]
+        idevid_layer_output.zeroize();
 
         // Execute FMCALIAS layer
         let result = FmcAliasLayer::derive(env, &fmc_layer_input, &fw_proc_info);
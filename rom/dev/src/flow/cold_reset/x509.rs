@@ -14,6 +14,8 @@ Abstract:
 use super::crypto::Crypto;
 use crate::cprintln;
 use crate::rom_env::RomEnv;
+#[cfg(feature = "cbor-certs")]
+use caliptra_cbor_cert::DiceMode;
 use caliptra_drivers::*;
 
 /// Wrapper to hold certificate buffer and length
@@ -57,6 +59,41 @@ impl X509 {
         Ok(ueid)
     }
 
+    /// Get the DICE certificate format selected by the fuses. ROMs built
+    /// without the `cbor-certs` feature always issue X.509 certificates.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - ROM Environment
+    ///
+    /// # Returns
+    ///
+    /// `DiceCertFormat` - Format of the Local Device ID and FMC Alias certificates
+    pub fn dice_cert_format(env: &RomEnv) -> DiceCertFormat {
+        if cfg!(feature = "cbor-certs") {
+            env.soc_ifc.fuse_bank().dice_cert_format()
+        } else {
+            DiceCertFormat::X509
+        }
+    }
+
+    /// Get the Open Profile for DICE mode of the device
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - ROM Environment
+    ///
+    /// # Returns
+    ///
+    /// `DiceMode` - Mode recorded in CBOR certificates
+    #[cfg(feature = "cbor-certs")]
+    pub fn dice_mode(env: &RomEnv) -> DiceMode {
+        DiceMode::from_security_state(
+            env.soc_ifc.lifecycle() == Lifecycle::Production,
+            env.soc_ifc.debug_locked(),
+        )
+    }
+
     /// Get X509 Subject Serial Number
     ///
     /// # Arguments
@@ -164,7 +201,7 @@ impl X509 {
     /// # Returns
     ///
     /// `[u8; 64]` - Hex representation of the buffer
-    fn hex(buf: &[u8; 32]) -> [u8; 64] {
+    pub fn hex(buf: &[u8; 32]) -> [u8; 64] {
         fn ch(byte: u8) -> u8 {
             match byte & 0x0F {
                 b @ 0..=9 => 48 + b,
@@ -0,0 +1,99 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    components.rs
+
+Abstract:
+
+    File contains the loading of the additional image components that follow
+    the FMC and Runtime in the image bundle.
+
+--*/
+
+use crate::cprintln;
+use caliptra_cfi_derive::cfi_impl_fn;
+use caliptra_common::FirmwareHandoffTable;
+use caliptra_drivers::MailboxRecvTxn;
+use caliptra_error::{CaliptraError, CaliptraResult};
+use caliptra_image_types::{ImageManifest, ImageTocEntry, FW_TOC_ENTRY_COUNT, MAX_COMPONENT_COUNT};
+use zerocopy::AsBytes;
+
+/// Component TOC entries of an image bundle
+#[derive(Default)]
+pub struct ImageComponents {
+    entries: [ImageTocEntry; MAX_COMPONENT_COUNT as usize],
+    count: usize,
+}
+
+impl ImageComponents {
+    /// Read the component TOC entries that follow the manifest
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - Manifest
+    /// * `txn`      - Mailbox Receive Transaction
+    ///
+    /// # Returns
+    ///
+    /// * `ImageComponents` - Component TOC entries, not yet verified
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn load_toc(manifest: &ImageManifest, txn: &mut MailboxRecvTxn) -> CaliptraResult<Self> {
+        // The header is not verified yet, so only bound the count here; the
+        // image verifier checks it against the signed TOC length.
+        let count = manifest.header.toc_len.saturating_sub(FW_TOC_ENTRY_COUNT);
+        if count > MAX_COMPONENT_COUNT {
+            return Err(CaliptraError::IMAGE_VERIFIER_ERR_TOC_ENTRY_COUNT_INVALID);
+        }
+
+        let mut components = Self {
+            count: count as usize,
+            ..Default::default()
+        };
+        txn.copy_request(components.entries[..components.count].as_bytes_mut())?;
+        Ok(components)
+    }
+
+    /// Component TOC entries
+    pub fn entries(&self) -> &[ImageTocEntry] {
+        &self.entries[..self.count]
+    }
+
+    /// Load the component contents, which follow the Runtime in the image
+    /// bundle, to ICCM
+    ///
+    /// # Arguments
+    ///
+    /// * `txn` - Mailbox Receive Transaction
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub fn load(&self, txn: &mut MailboxRecvTxn) -> CaliptraResult<()> {
+        for component in self.entries() {
+            cprintln!(
+                "[components] Loading component 0x{:08x} at address 0x{:08x} len {}",
+                component.id,
+                component.load_addr,
+                component.size
+            );
+
+            let dest = unsafe {
+                let addr = (component.load_addr) as *mut u32;
+                core::slice::from_raw_parts_mut(addr, component.size as usize / 4)
+            };
+
+            txn.copy_request(dest.as_bytes_mut())?;
+        }
+        Ok(())
+    }
+
+    /// Record the component TOC entries in the Firmware Handoff Table
+    ///
+    /// # Arguments
+    ///
+    /// * `fht` - Firmware Handoff Table
+    pub fn store(&self, fht: &mut FirmwareHandoffTable) {
+        fht.components = self.entries;
+        fht.component_count = self.count as u32;
+    }
+}
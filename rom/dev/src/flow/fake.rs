@@ -36,9 +36,10 @@ use caliptra_drivers::VendorPubKeyRevocation;
 use caliptra_drivers::*;
 use caliptra_error::CaliptraError;
 use caliptra_image_types::*;
-use caliptra_image_verify::ImageVerificationEnv;
+use caliptra_image_verify::{ImageVerificationEnv, LmsHssPubKeys, Mldsa87PubKeyDigests};
 use core::ops::Range;
 use fw_processor::FirmwareProcessor;
+use zerocopy::FromBytes;
 
 const FAKE_LDEV_TBS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/ldev_tbs.der"));
 const FAKE_LDEV_PUB_KEY: Ecc384PubKey = Ecc384PubKey {
@@ -164,7 +165,7 @@ impl FakeRomFlow {
                 drop(sha_op);
 
                 // Download and validate firmware.
-                _ = FirmwareProcessor::process(env)?;
+                _ = FirmwareProcessor::process(env, false)?;
 
                 // FMC Alias Cert
                 copy_canned_fmc_alias_cert(env)?;
@@ -245,6 +246,27 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         Ok(self.sha384.digest(data)?.0)
     }
 
+    fn sha384_digest_with_suffix(
+        &mut self,
+        offset: u32,
+        len: u32,
+        suffix: &[u8],
+    ) -> CaliptraResult<ImageDigest> {
+        let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+        let data = self
+            .image
+            .get(offset as usize..)
+            .ok_or(err)?
+            .get(..len as usize)
+            .ok_or(err)?;
+        let mut digest = Array4x12::default();
+        let mut op = self.sha384.digest_init()?;
+        op.update(data)?;
+        op.update(suffix)?;
+        op.finalize(&mut digest)?;
+        Ok(digest.0)
+    }
+
     /// ECC-384 Verification routine
     fn ecc384_verify(
         &mut self,
@@ -290,6 +312,45 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         }
     }
 
+    fn mldsa87_verify(
+        &mut self,
+        digest: &ImageDigest,
+        pub_key_offset: u32,
+        sig_offset: u32,
+    ) -> CaliptraResult<Mldsa87Result> {
+        if self.soc_ifc.verify_in_fake_mode() {
+            let err = CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS;
+            let pub_key = self
+                .image
+                .get(pub_key_offset as usize..)
+                .and_then(|b| b.get(..MLDSA87_PUB_KEY_BYTE_SIZE))
+                .and_then(|b| <&[u8; MLDSA87_PUB_KEY_BYTE_SIZE]>::try_from(b).ok())
+                .ok_or(err)?;
+            let sig = self
+                .image
+                .get(sig_offset as usize..)
+                .and_then(|b| b.get(..MLDSA87_SIGNATURE_BYTE_SIZE))
+                .and_then(|b| <&[u8; MLDSA87_SIGNATURE_BYTE_SIZE]>::try_from(b).ok())
+                .ok_or(err)?;
+
+            let mut message = [0u8; SHA384_DIGEST_BYTE_SIZE];
+            for i in 0..digest.len() {
+                message[i * 4..][..4].copy_from_slice(&digest[i].to_be_bytes());
+            }
+            Mldsa87::default().verify(pub_key, &message, sig)
+        } else {
+            // Mock verify, just always return success
+            Ok(Mldsa87Result::Success)
+        }
+    }
+
+    fn lms_hss_signed_pub_key(&self, offset: u32) -> CaliptraResult<ImageLmsSignedPubKey> {
+        self.image
+            .get(offset as usize..)
+            .and_then(ImageLmsSignedPubKey::read_from_prefix)
+            .ok_or(CaliptraError::IMAGE_VERIFIER_ERR_DIGEST_OUT_OF_BOUNDS)
+    }
+
     /// Retrieve Vendor Public Key Digest
     fn vendor_pub_key_digest(&self) -> ImageDigest {
         self.soc_ifc.fuse_bank().vendor_pub_key_hash().into()
@@ -335,6 +396,16 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         self.data_vault.owner_pk_hash().into()
     }
 
+    // The fake ROM always verifies images with the PQC extension
+    fn mldsa87_pub_key_digests_cold_boot(&self) -> Option<Mldsa87PubKeyDigests> {
+        None
+    }
+
+    // The fake ROM always verifies images with the HSS extension
+    fn lms_hss_pub_keys_cold_boot(&self) -> Option<LmsHssPubKeys> {
+        None
+    }
+
     // Get the fmc digest from the data vault on cold boot
     fn get_fmc_digest_dv(&self) -> ImageDigest {
         self.data_vault.fmc_tci().into()
@@ -358,6 +429,14 @@ impl<'a, 'b> ImageVerificationEnv for &mut FakeRomImageVerificationEnv<'a, 'b> {
         self.soc_ifc.fuse_bank().lms_verify() == RomVerifyConfig::EcdsaAndLms
     }
 
+    fn lms_hss_verify_enabled(&self) -> bool {
+        self.lms_verify_enabled()
+    }
+
+    fn mldsa87_verify_enabled(&self) -> bool {
+        self.soc_ifc.fuse_bank().mldsa_verify() == MldsaVerifyConfig::Enabled
+    }
+
     fn set_fw_extended_error(&mut self, err: u32) {
         self.soc_ifc.set_fw_extended_error(err);
     }
@@ -13,11 +13,16 @@ Abstract:
 --*/
 
 mod cold_reset;
+#[cfg(feature = "components")]
+mod components;
 #[cfg(feature = "fake-rom")]
 mod fake;
 mod update_reset;
 mod warm_reset;
 
+#[cfg(feature = "components")]
+pub(crate) use components::ImageComponents;
+
 use crate::cprintln;
 use crate::{handle_fatal_error, rom_env::RomEnv};
 use caliptra_cfi_derive::cfi_mod_fn;
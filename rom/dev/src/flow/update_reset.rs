@@ -13,6 +13,8 @@ Abstract:
 --*/
 #[cfg(feature = "fake-rom")]
 use crate::flow::fake::FakeRomImageVerificationEnv;
+#[cfg(feature = "components")]
+use crate::flow::ImageComponents;
 use crate::{cprintln, pcr, rom_env::RomEnv};
 use caliptra_common::verifier::FirmwareImageVerificationEnv;
 
@@ -65,6 +67,9 @@ impl UpdateResetFlow {
             let manifest = Self::load_manifest(env.persistent_data.get_mut(), &mut recv_txn)?;
             report_boot_status(UpdateResetLoadManifestComplete.into());
 
+            #[cfg(feature = "components")]
+            let components = ImageComponents::load_toc(&manifest, &mut recv_txn)?;
+
             let mut venv = FirmwareImageVerificationEnv {
                 sha256: &mut env.sha256,
                 sha384: &mut env.sha384,
@@ -73,8 +78,16 @@ impl UpdateResetFlow {
                 data_vault: &mut env.data_vault,
                 pcr_bank: &mut env.pcr_bank,
                 image: recv_txn.raw_mailbox_contents(),
+                image_extensions: true,
+                #[cfg(feature = "mldsa")]
+                mldsa87_pub_key_digests: None,
+                #[cfg(feature = "lms-hss")]
+                lms_hss_pub_keys: None,
             };
 
+            #[cfg(feature = "components")]
+            let info = Self::verify_image(&mut venv, &manifest, &components, recv_txn.dlen());
+            #[cfg(not(feature = "components"))]
             let info = Self::verify_image(&mut venv, &manifest, recv_txn.dlen());
             let info = okref(&info)?;
             report_boot_status(UpdateResetImageVerificationComplete.into());
@@ -83,6 +96,9 @@ impl UpdateResetFlow {
             Self::populate_data_vault(venv.data_vault, info);
 
             // Extend PCR0 and PCR1
+            #[cfg(feature = "components")]
+            pcr::extend_pcrs(&mut venv, info, &components, &mut env.persistent_data)?;
+            #[cfg(not(feature = "components"))]
             pcr::extend_pcrs(&mut venv, info, &mut env.persistent_data)?;
             report_boot_status(UpdateResetExtendPcrComplete.into());
 
@@ -91,7 +107,22 @@ impl UpdateResetFlow {
                 info.vendor_ecc_pub_key_idx
             );
 
+            #[cfg(feature = "components")]
+            {
+                Self::load_image(&manifest, &components, &mut recv_txn)?;
+                components.store(&mut env.persistent_data.get_mut().fht);
+            }
+            #[cfg(not(feature = "components"))]
             Self::load_image(&manifest, &mut recv_txn)?;
+
+            // Runtime re-verifies the image, which it rebuilds without the
+            // HSS extension, against the LMS keys verified here
+            #[cfg(feature = "lms-hss")]
+            if let Some(keys) = info.lms_hss_pub_keys {
+                let fht = &mut env.persistent_data.get_mut().fht;
+                fht.vendor_lms_hss_pub_key = zerocopy::transmute!(keys.vendor);
+                fht.owner_lms_hss_pub_key = zerocopy::transmute!(keys.owner);
+            }
             Ok(())
         };
         if let Err(e) = process_txn() {
@@ -133,11 +164,13 @@ impl UpdateResetFlow {
     ///
     /// * `env` - ROM Environment
     /// * 'manifest'- Manifest
+    /// * `components` - Component TOC entries
     ///
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
     fn verify_image(
         env: &mut FirmwareImageVerificationEnv,
         manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &ImageComponents,
         img_bundle_sz: u32,
     ) -> CaliptraResult<ImageVerificationInfo> {
         #[cfg(feature = "fake-rom")]
@@ -152,6 +185,14 @@ impl UpdateResetFlow {
 
         let mut verifier = ImageVerifier::new(env);
 
+        #[cfg(feature = "components")]
+        let info = verifier.verify(
+            manifest,
+            components.entries(),
+            img_bundle_sz,
+            ResetReason::UpdateReset,
+        )?;
+        #[cfg(not(feature = "components"))]
         let info = verifier.verify(manifest, img_bundle_sz, ResetReason::UpdateReset)?;
 
         Ok(info)
@@ -161,11 +202,16 @@ impl UpdateResetFlow {
     ///
     /// # Arguments
     ///
-    /// * `env`      - ROM Environment
-    /// * `manifest` - Manifest
-    /// * `txn`      - Mailbox Receive Transaction
+    /// * `env`        - ROM Environment
+    /// * `manifest`   - Manifest
+    /// * `components` - Component TOC entries
+    /// * `txn`        - Mailbox Receive Transaction
     #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
-    fn load_image(manifest: &ImageManifest, txn: &mut MailboxRecvTxn) -> CaliptraResult<()> {
+    fn load_image(
+        manifest: &ImageManifest,
+        #[cfg(feature = "components")] components: &ImageComponents,
+        txn: &mut MailboxRecvTxn,
+    ) -> CaliptraResult<()> {
         cprintln!(
             "[update-reset] Loading Runtime at address 0x{:08x} len {}",
             manifest.runtime.load_addr,
@@ -182,6 +228,9 @@ impl UpdateResetFlow {
 
         txn.copy_request(runtime_dest.as_bytes_mut())?;
 
+        #[cfg(feature = "components")]
+        components.load(txn)?;
+
         //Call the complete here to reset the execute bit
         txn.complete(true)?;
 
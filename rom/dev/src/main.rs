@@ -18,8 +18,19 @@ Abstract:
 use crate::{lock::lock_registers, print::HexBytes};
 use caliptra_cfi_lib::{cfi_assert_eq, CfiCounter};
 use caliptra_common::RomBootStatus;
+#[cfg(feature = "crash-record")]
+use caliptra_common::{
+    crash_info::GetCrashInfoCmd,
+    mailbox_api::{CommandId, Response},
+};
+#[cfg(feature = "crash-record")]
+use caliptra_drivers::CrashRecord;
+#[cfg(feature = "crash-record")]
+use caliptra_registers::mbox::MboxCsr;
 use caliptra_registers::soc_ifc::SocIfcReg;
 use core::hint::black_box;
+#[cfg(feature = "crash-record")]
+use zerocopy::AsBytes;
 
 use caliptra_drivers::{
     cprintln, report_boot_status, report_fw_error_fatal, report_fw_error_non_fatal, CaliptraError,
@@ -242,6 +253,8 @@ extern "C" fn exception_handler(exception: &exception::ExceptionRecord) {
         ext_info.at(2).write(|_| exception.mepc);
         ext_info.at(3).write(|_| exception.ra);
     }
+    #[cfg(feature = "crash-record")]
+    exception.record_crash(None);
 
     handle_fatal_error(CaliptraError::ROM_GLOBAL_EXCEPTION.into());
 }
@@ -279,6 +292,8 @@ extern "C" fn nmi_handler(exception: &exception::ExceptionRecord) {
         ext_info.at(3).write(|_| exception.ra);
         ext_info.at(4).write(|_| err_interrupt_status);
     }
+    #[cfg(feature = "crash-record")]
+    exception.record_crash(Some(err_interrupt_status));
 
     // Check if the NMI was due to WDT expiry.
     let mut error = CaliptraError::ROM_GLOBAL_NMI;
@@ -342,6 +357,10 @@ fn handle_fatal_error(code: u32) -> ! {
 
     loop {
         unsafe {
+            // Let the SoC retrieve the crash record.
+            #[cfg(feature = "crash-record")]
+            send_crash_info();
+
             // SoC firmware might be stuck waiting for Caliptra to finish
             // executing this pending mailbox transaction. Notify them that
             // we've failed.
@@ -357,6 +376,29 @@ fn handle_fatal_error(code: u32) -> ! {
     }
 }
 
+/// Respond to a pending GET_CRASH_INFO command. Any other pending command is
+/// left for `Mailbox::abort_pending_soc_to_uc_transactions()`.
+///
+/// # Safety
+///
+/// Only to be called from the fatal error handler, after which no other
+/// mailbox or persistent data accessor is used.
+#[cfg(feature = "crash-record")]
+unsafe fn send_crash_info() {
+    let mut mbox = Mailbox::new(MboxCsr::new());
+    let Some(txn) = mbox.peek_recv() else {
+        return;
+    };
+    if txn.cmd() != CommandId::GET_CRASH_INFO.into() {
+        return;
+    }
+    let mut txn = txn.start_txn();
+    let mut resp = GetCrashInfoCmd::execute(CrashRecord::get_mut());
+    resp.populate_chksum();
+    // If this fails the transaction is completed with failure when dropped.
+    let _ = txn.send_response(resp.as_bytes());
+}
+
 #[no_mangle]
 #[inline(never)]
 fn panic_is_possible() {
@@ -21,12 +21,16 @@ Note:
 
 --*/
 
+#[cfg(feature = "components")]
+use crate::flow::ImageComponents;
 use caliptra_cfi_derive::{cfi_impl_fn, cfi_mod_fn};
 use caliptra_common::verifier::FirmwareImageVerificationEnv;
 use caliptra_common::{
     pcr::{PCR_ID_FMC_CURRENT, PCR_ID_FMC_JOURNEY},
     PcrLogEntry, PcrLogEntryId,
 };
+#[cfg(feature = "components")]
+use caliptra_drivers::Array4x12;
 use caliptra_drivers::{
     CaliptraError, CaliptraResult, PcrBank, PersistentData, PersistentDataAccessor, Sha384,
 };
@@ -57,12 +61,15 @@ impl PcrExtender<'_> {
 ///
 /// # Arguments
 ///
-/// * `env` - ROM Environment
+/// * `env`        - ROM Environment
+/// * `info`       - Image Verification Info
+/// * `components` - Verified component TOC entries
 #[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
 #[inline(never)]
 pub(crate) fn extend_pcrs(
     env: &mut FirmwareImageVerificationEnv,
     info: &ImageVerificationInfo,
+    #[cfg(feature = "components")] components: &ImageComponents,
     persistent_data: &mut PersistentDataAccessor,
 ) -> CaliptraResult<()> {
     // Reset the PCR log size to zero.
@@ -104,6 +111,14 @@ pub(crate) fn extend_pcrs(
         PcrLogEntryId::FmcTci,
     )?;
 
+    #[cfg(feature = "components")]
+    for component in components.entries() {
+        pcr.extend(
+            &<[u8; 48]>::from(&Array4x12::from(component.digest)),
+            PcrLogEntryId::ComponentDigest,
+        )?;
+    }
+
     Ok(())
 }
 
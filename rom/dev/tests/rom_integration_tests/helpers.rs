@@ -2,7 +2,7 @@
 
 use std::mem;
 
-use caliptra_builder::{firmware, ImageOptions};
+use caliptra_builder::{firmware, FwId, ImageOptions};
 use caliptra_hw_model::{BootParams, Fuses, HwModel, InitParams, SecurityState};
 use caliptra_hw_model::{DefaultHwModel, ModelError};
 use caliptra_image_types::ImageBundle;
@@ -15,8 +15,26 @@ pub fn build_hw_model_and_image_bundle(
     (build_hw_model(fuses), image)
 }
 
+/// Like `build_hw_model_and_image_bundle`, with a ROM built with the optional
+/// features that are left out of the frozen ROM
+pub fn build_extended_hw_model_and_image_bundle(
+    fuses: Fuses,
+    image_options: ImageOptions,
+) -> (DefaultHwModel, ImageBundle) {
+    let image = build_image_bundle(image_options);
+    (build_extended_hw_model(fuses), image)
+}
+
 pub fn build_hw_model(fuses: Fuses) -> DefaultHwModel {
-    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    build_hw_model_with_rom(fuses, firmware::rom_from_env())
+}
+
+pub fn build_extended_hw_model(fuses: Fuses) -> DefaultHwModel {
+    build_hw_model_with_rom(fuses, &firmware::ROM_WITH_UART_EXTENDED)
+}
+
+fn build_hw_model_with_rom(fuses: Fuses, rom: &FwId) -> DefaultHwModel {
+    let rom = caliptra_builder::build_firmware_rom(rom).unwrap();
     caliptra_hw_model::new(BootParams {
         init_params: InitParams {
             rom: &rom,
@@ -1,15 +1,16 @@
 // Licensed under the Apache-2.0 license
 
-use caliptra_builder::firmware;
-use caliptra_hw_model::{BootParams, HwModel, InitParams};
+use caliptra_builder::{firmware, FwId};
+use caliptra_common::mailbox_api::GetCrashInfoReq;
+use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams};
 use elf::{endian::LittleEndian, ElfBytes};
 
-#[test]
-fn test_cpu_fault() {
-    const GLOBAL_EXCEPTION: u32 = 0x01050002;
-
-    let rom_fwid = firmware::rom_from_env();
+const GLOBAL_EXCEPTION: u32 = 0x01050002;
 
+/// Boot a ROM with an illegal instruction at rom_entry and wait for the
+/// exception handler to report the fatal error. Returns the model and the
+/// offset of rom_entry.
+fn boot_faulting_rom(rom_fwid: &FwId<'static>) -> (DefaultHwModel, usize) {
     let elf_bytes = caliptra_builder::build_firmware_elf(rom_fwid).unwrap();
     let mut rom = caliptra_builder::elf2rom(&elf_bytes).unwrap();
     let elf = ElfBytes::<LittleEndian>::minimal_parse(&elf_bytes).unwrap();
@@ -38,6 +39,13 @@ fn test_cpu_fault() {
 
     hw.step_until(|m| m.soc_ifc().cptra_fw_error_fatal().read() == GLOBAL_EXCEPTION);
 
+    (hw, rom_entry_offset)
+}
+
+#[test]
+fn test_cpu_fault() {
+    let (mut hw, rom_entry_offset) = boot_faulting_rom(firmware::rom_from_env());
+
     let mcause = hw.soc_ifc().cptra_fw_extended_error_info().at(0).read();
     let mscause = hw.soc_ifc().cptra_fw_extended_error_info().at(1).read();
     let mepc = hw.soc_ifc().cptra_fw_extended_error_info().at(2).read();
@@ -60,3 +68,24 @@ fn test_cpu_fault() {
     #[cfg(feature = "verilator")]
     assert!(hw.v.output.cptra_error_fatal);
 }
+
+#[test]
+fn test_cpu_fault_crash_info() {
+    // The crash record is only kept by ROMs built with the crash-record feature
+    let (mut hw, _) = boot_faulting_rom(&firmware::ROM_WITH_UART_EXTENDED);
+
+    let mcause = hw.soc_ifc().cptra_fw_extended_error_info().at(0).read();
+    let mscause = hw.soc_ifc().cptra_fw_extended_error_info().at(1).read();
+    let mepc = hw.soc_ifc().cptra_fw_extended_error_info().at(2).read();
+    let ra = hw.soc_ifc().cptra_fw_extended_error_info().at(3).read();
+
+    // The crash record holds the same trap state
+    let crash_info = hw.mailbox_execute_req(GetCrashInfoReq::default()).unwrap();
+    assert_eq!(crash_info.valid, 1);
+    assert_eq!(crash_info.fw_error_fatal, GLOBAL_EXCEPTION);
+    assert_eq!(crash_info.mcause, mcause);
+    assert_eq!(crash_info.mscause, mscause);
+    assert_eq!(crash_info.mepc, mepc);
+    assert_eq!(crash_info.mtval, 0xFFFF_FFFF);
+    assert_eq!(crash_info.regs[0], ra);
+}
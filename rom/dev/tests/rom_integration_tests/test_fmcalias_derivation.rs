@@ -19,9 +19,9 @@ use caliptra_drivers::{ColdResetEntry4, PcrId, RomVerifyConfig};
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{BootParams, Fuses, HwModel, InitParams, ModelError, SecurityState};
 use caliptra_image_fake_keys::{OWNER_CONFIG, VENDOR_CONFIG_KEY_1};
-use caliptra_image_gen::ImageGenerator;
+use caliptra_image_gen::{ImageGenerator, ImageGeneratorComponent};
 use caliptra_image_openssl::OsslCrypto;
-use caliptra_image_types::IMAGE_BYTE_SIZE;
+use caliptra_image_types::{ImageTocEntryType, IMAGE_BYTE_SIZE};
 use caliptra_test::swap_word_bytes;
 use openssl::hash::{Hasher, MessageDigest};
 use zerocopy::{AsBytes, FromBytes};
@@ -593,6 +593,7 @@ fn test_fuse_log() {
         fmc_version: 0,
         app_svn: FMC_SVN,
         app_version: 0,
+        ..Default::default()
     };
     let image_bundle =
         caliptra_builder::build_and_sign_image(&TEST_FMC_WITH_UART, &APP_WITH_UART, image_options)
@@ -1022,3 +1023,60 @@ fn test_upload_no_measurement() {
     let fht = FirmwareHandoffTable::read_from_prefix(data.as_bytes()).unwrap();
     assert_eq!(fht.meas_log_index, 0);
 }
+
+#[test]
+fn test_components_measured_and_in_fht() {
+    // Only ROMs built with the components feature load additional components
+    let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART_EXTENDED).unwrap();
+    let mut hw = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        fuses: Fuses::default(),
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Load the component in the ICCM space left after the FMC and Runtime
+    let component = ImageGeneratorComponent {
+        id: 0x1000,
+        r#type: ImageTocEntryType::Data,
+        revision: Default::default(),
+        version: 1,
+        svn: 0,
+        load_addr: ICCM_ORG + ICCM_SIZE - 0x1000,
+        entry_point: 0,
+        content: (0..256u32).flat_map(u32::to_le_bytes).collect(),
+    };
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &TEST_FMC_INTERACTIVE,
+        &APP_WITH_UART,
+        ImageOptions {
+            components: vec![component],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(image_bundle.manifest.header.toc_len, 3);
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+
+    let component_toc = &image_bundle.components[0].toc;
+    let pcr_entry_arr = hw.mailbox_execute(0x1000_0000, &[]).unwrap().unwrap();
+    check_pcr_log_entry(
+        &pcr_entry_arr,
+        4,
+        PcrLogEntryId::ComponentDigest,
+        PCR0_AND_PCR1_EXTENDED_ID,
+        swap_word_bytes(&component_toc.digest).as_bytes(),
+    );
+
+    let data = hw.mailbox_execute(0x1000_0003, &[]).unwrap().unwrap();
+    let fht = FirmwareHandoffTable::read_from_prefix(data.as_bytes()).unwrap();
+    assert_eq!(fht.components().len(), 1);
+    assert_eq!(fht.components()[0].as_bytes(), component_toc.as_bytes());
+}
@@ -1,9 +1,11 @@
 // Licensed under the Apache-2.0 license
 
 use caliptra_builder::{firmware, ImageOptions};
-use caliptra_common::mailbox_api::{CommandId, GetLdevCertResp, MailboxReqHeader};
-use caliptra_drivers::{IdevidCertAttr, MfgFlags, X509KeyIdAlgo};
-use caliptra_hw_model::{DefaultHwModel, Fuses, HwModel};
+use caliptra_common::mailbox_api::{
+    CommandId, GetIdevCsrReq, GetLdevCertResp, MailboxReqHeader, SetIdevCsrNonceReq,
+};
+use caliptra_drivers::{CaliptraError, IdevidCertAttr, MfgFlags, X509KeyIdAlgo};
+use caliptra_hw_model::{DefaultHwModel, Fuses, HwModel, ModelError};
 use caliptra_image_types::ImageBundle;
 use openssl::pkey::{PKey, Public};
 use openssl::x509::X509;
@@ -62,6 +64,81 @@ fn test_idev_subj_key_id_algo() {
     }
 }
 
+#[test]
+fn test_store_csr_with_nonce() {
+    let (mut hw, image_bundle) = helpers::build_extended_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions::default(),
+    );
+    hw.soc_ifc()
+        .cptra_dbg_manuf_service_reg()
+        .write(|_| MfgFlags::STORE_IDEVID_CSR.bits());
+
+    let nonce = [0xA5; 32];
+    hw.mailbox_execute_req(SetIdevCsrNonceReq {
+        nonce,
+        ..Default::default()
+    })
+    .unwrap();
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(RT_READY_FOR_COMMANDS, true);
+
+    let resp = hw.mailbox_execute_req(GetIdevCsrReq::default()).unwrap();
+    let csr_bytes = &resp.data[..resp.data_size as usize];
+
+    // The CSR is signed by the IDevID key and carries the nonce
+    let req = X509Req::from_der(csr_bytes).unwrap();
+    let idevid_pubkey = req.public_key().unwrap();
+    assert!(req.verify(&idevid_pubkey).unwrap());
+    let nonce_hex = hex::encode_upper(nonce);
+    assert!(csr_bytes
+        .windows(nonce_hex.len())
+        .any(|w| w == nonce_hex.as_bytes()));
+
+    // The IDevID key still endorses the LDevID certificate
+    verify_key(
+        &mut hw,
+        u32::from(CommandId::GET_LDEV_CERT),
+        &idevid_pubkey,
+        &Fuses::default().uds_seed,
+    );
+}
+
+#[test]
+fn test_store_csr_without_nonce() {
+    let (mut hw, image_bundle) = helpers::build_extended_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions::default(),
+    );
+    hw.soc_ifc()
+        .cptra_dbg_manuf_service_reg()
+        .write(|_| MfgFlags::STORE_IDEVID_CSR.bits());
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(RT_READY_FOR_COMMANDS, true);
+
+    assert_eq!(
+        hw.mailbox_execute_req(GetIdevCsrReq::default()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_IDEV_CSR_UNAVAILABLE.into()
+        ))
+    );
+}
+
+#[test]
+fn test_csr_nonce_rejected_without_flag() {
+    let mut hw = helpers::build_hw_model(Fuses::default());
+
+    assert_eq!(
+        hw.mailbox_execute_req(SetIdevCsrNonceReq::default()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::FW_PROC_MAILBOX_INVALID_COMMAND.into()
+        ))
+    );
+}
+
 fn fuses_with_random_uds() -> Fuses {
     const UDS_LEN: usize = core::mem::size_of::<u32>() * 12;
     let mut uds_bytes = [0; UDS_LEN];
@@ -18,10 +18,15 @@ use caliptra_hw_model::{
 };
 use caliptra_image_elf::ElfExecutable;
 use caliptra_image_fake_keys::{
-    VENDOR_CONFIG_KEY_0, VENDOR_CONFIG_KEY_1, VENDOR_CONFIG_KEY_2, VENDOR_CONFIG_KEY_3,
+    OWNER_CONFIG, OWNER_MLDSA_KEY_PRIVATE, VENDOR_CONFIG_KEY_0, VENDOR_CONFIG_KEY_1,
+    VENDOR_CONFIG_KEY_2, VENDOR_CONFIG_KEY_3, VENDOR_LMS_KEY_1_PRIVATE, VENDOR_LMS_KEY_1_PUBLIC,
+    VENDOR_LMS_KEY_2_PRIVATE, VENDOR_LMS_KEY_2_PUBLIC, VENDOR_LMS_KEY_3_PRIVATE,
+    VENDOR_LMS_KEY_3_PUBLIC, VENDOR_MLDSA_KEY_PRIVATE,
 };
-use caliptra_image_gen::{ImageGenerator, ImageGeneratorConfig, ImageGeneratorVendorConfig};
-use caliptra_image_openssl::OsslCrypto;
+use caliptra_image_gen::{
+    ImageGenerator, ImageGeneratorConfig, ImageGeneratorHssConfig, ImageGeneratorVendorConfig,
+};
+use caliptra_image_openssl::{lms_tree_height, mldsa87_pub_key, LmsKeyState, OsslCrypto};
 use caliptra_image_types::{
     ImageBundle, ImageManifest, VENDOR_ECC_KEY_COUNT, VENDOR_LMS_KEY_COUNT,
 };
@@ -1993,6 +1998,7 @@ fn update_header(image_bundle: &mut ImageBundle) {
         runtime: ElfExecutable::default(),
         vendor_config: opts.vendor_config,
         owner_config: opts.owner_config,
+        components: Vec::new(),
     };
 
     let gen = ImageGenerator::new(OsslCrypto::default());
@@ -2030,7 +2036,11 @@ fn update_fmc_runtime_ranges(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &[],
+        )
         .unwrap();
 
     // Update Header.
@@ -2051,7 +2061,11 @@ fn update_load_addr(image_bundle: &mut ImageBundle, is_fmc: bool, new_load_addr:
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &[],
+        )
         .unwrap();
 
     // Update Header.
@@ -2076,7 +2090,11 @@ fn update_entry_point(
 
     // Update TOC digest.
     image_bundle.manifest.header.toc_digest = gen
-        .toc_digest(&image_bundle.manifest.fmc, &image_bundle.manifest.runtime)
+        .toc_digest(
+            &image_bundle.manifest.fmc,
+            &image_bundle.manifest.runtime,
+            &[],
+        )
         .unwrap();
 
     // Update Header.
@@ -2312,3 +2330,473 @@ fn test_max_fw_image() {
     assert_eq!(iccm_cmp.len(), 1);
     assert_eq!(iccm_cmp[0], 0);
 }
+
+#[test]
+fn test_owner_resign_vendor_signed_image() {
+    let image_bundle = caliptra_builder::build_and_sign_image(
+        &FMC_WITH_UART,
+        &APP_WITH_UART,
+        ImageOptions {
+            owner_config: None,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let vendor_sigs = image_bundle.manifest.preamble.vendor_sigs;
+
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let mut owner_config = OWNER_CONFIG;
+    owner_config.epoch = [0x12, 0x34];
+    let image_bundle = gen.resign_owner(image_bundle, &owner_config).unwrap();
+    assert_eq!(
+        image_bundle.manifest.preamble.vendor_sigs.as_bytes(),
+        vendor_sigs.as_bytes()
+    );
+    assert_eq!(image_bundle.manifest.header.owner_data.epoch, [0x12, 0x34]);
+
+    let fuses = caliptra_hw_model::Fuses {
+        owner_pk_hash: gen
+            .owner_pubkey_digest(&image_bundle.manifest.preamble)
+            .unwrap(),
+        ..Default::default()
+    };
+    let rom = caliptra_builder::build_firmware_rom(firmware::rom_from_env()).unwrap();
+    let mut hw = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            security_state: SecurityState::from(fuses.life_cycle as u32),
+            ..Default::default()
+        },
+        fuses,
+        ..Default::default()
+    })
+    .unwrap();
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+#[test]
+fn test_owner_resign_invalid_vendor_signature() {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+
+    let mut image_bundle = helpers::build_image_bundle(ImageOptions::default());
+    image_bundle.manifest.preamble.vendor_sigs.ecc_sig.r[0] ^= 1;
+    assert!(gen.resign_owner(image_bundle, &OWNER_CONFIG).is_err());
+
+    let mut image_bundle = helpers::build_image_bundle(ImageOptions::default());
+    image_bundle.manifest.preamble.vendor_sigs.lms_sig.tree_path[0][0] = 0.into();
+    assert!(gen.resign_owner(image_bundle, &OWNER_CONFIG).is_err());
+
+    // The vendor signature covers the TOC digest, but not the contents
+    let mut image_bundle = helpers::build_image_bundle(ImageOptions::default());
+    image_bundle.runtime[0] ^= 1;
+    assert!(gen.resign_owner(image_bundle, &OWNER_CONFIG).is_err());
+}
+
+fn mldsa_image_options() -> ImageOptions {
+    let mut vendor_config = VENDOR_CONFIG_KEY_0;
+    vendor_config.mldsa_pub_key = Some(mldsa87_pub_key(&VENDOR_MLDSA_KEY_PRIVATE).unwrap());
+    vendor_config.mldsa_priv_key = Some(VENDOR_MLDSA_KEY_PRIVATE);
+    let mut owner_config = OWNER_CONFIG;
+    owner_config.mldsa_pub_key = Some(mldsa87_pub_key(&OWNER_MLDSA_KEY_PRIVATE).unwrap());
+    owner_config.mldsa_priv_key = Some(OWNER_MLDSA_KEY_PRIVATE);
+    ImageOptions {
+        vendor_config,
+        owner_config: Some(owner_config),
+        ..Default::default()
+    }
+}
+
+fn mldsa_verify_fuses() -> caliptra_hw_model::Fuses {
+    let mut fuses = caliptra_hw_model::Fuses::default();
+    fuses.idevid_cert_attr[IdevidCertAttr::Flags as usize] |= 0x8;
+    fuses
+}
+
+/// ML-DSA verification fuses with the key hashes that bind the ML-DSA public
+/// keys of `image_bundle`
+fn mldsa_verify_fuses_for(image_bundle: &ImageBundle) -> caliptra_hw_model::Fuses {
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let preamble = &image_bundle.manifest.preamble;
+    let pqc_extension = image_bundle.pqc_extension.as_ref().unwrap();
+    caliptra_hw_model::Fuses {
+        life_cycle: DeviceLifecycle::Manufacturing,
+        key_manifest_pk_hash: gen
+            .vendor_pubkey_digest_mldsa(preamble, pqc_extension)
+            .unwrap(),
+        owner_pk_hash: gen
+            .owner_pubkey_digest_mldsa(preamble, pqc_extension)
+            .unwrap(),
+        ..mldsa_verify_fuses()
+    }
+}
+
+#[test]
+fn test_mldsa_verify() {
+    let image_bundle = helpers::build_image_bundle(mldsa_image_options());
+    let mut hw = helpers::build_extended_hw_model(mldsa_verify_fuses_for(&image_bundle));
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+#[test]
+fn test_mldsa_verify_key_hash_without_mldsa_keys() {
+    // Key hash fuses that do not bind the ML-DSA public keys are rejected
+    let image_bundle = helpers::build_image_bundle(mldsa_image_options());
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let preamble = &image_bundle.manifest.preamble;
+    let fuses = mldsa_verify_fuses_for(&image_bundle);
+
+    let mut hw = helpers::build_extended_hw_model(caliptra_hw_model::Fuses {
+        key_manifest_pk_hash: gen.vendor_pubkey_digest(preamble).unwrap(),
+        ..fuses
+    });
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    drop(hw);
+
+    let mut hw = helpers::build_extended_hw_model(caliptra_hw_model::Fuses {
+        owner_pk_hash: gen.owner_pubkey_digest(preamble).unwrap(),
+        ..fuses
+    });
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_mldsa_verify_pqc_extension_missing() {
+    let fuses = mldsa_verify_fuses();
+    let (mut hw, image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, ImageOptions::default());
+    assert!(image_bundle.pqc_extension.is_none());
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_PQC_EXTENSION_MISSING.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+fn test_mldsa_verify_pub_key_digest_mismatch() {
+    let mut image_bundle = helpers::build_image_bundle(mldsa_image_options());
+    let fuses = mldsa_verify_fuses_for(&image_bundle);
+    image_bundle
+        .pqc_extension
+        .as_mut()
+        .unwrap()
+        .vendor_mldsa_pub_key[0] ^= 1;
+
+    let mut hw = helpers::build_extended_hw_model(fuses);
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    drop(hw);
+
+    let mut image_bundle = helpers::build_image_bundle(mldsa_image_options());
+    image_bundle
+        .pqc_extension
+        .as_mut()
+        .unwrap()
+        .owner_mldsa_pub_key[0] ^= 1;
+
+    let mut hw = helpers::build_extended_hw_model(fuses);
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+fn test_mldsa_verify_sig_mismatch() {
+    let fuses = mldsa_verify_fuses();
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, mldsa_image_options());
+    image_bundle
+        .pqc_extension
+        .as_mut()
+        .unwrap()
+        .vendor_mldsa_sig[0] ^= 1;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_MLDSA_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    drop(hw);
+
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, mldsa_image_options());
+    image_bundle.pqc_extension.as_mut().unwrap().owner_mldsa_sig[0] ^= 1;
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_MLDSA_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+fn test_mldsa_verify_optional_no_sig_mismatch_check() {
+    let fuses = caliptra_hw_model::Fuses::default();
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, mldsa_image_options());
+    image_bundle
+        .pqc_extension
+        .as_mut()
+        .unwrap()
+        .vendor_mldsa_sig[0] ^= 1;
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+fn lms_hss_image_options() -> ImageOptions {
+    // The lower levels are signed once, as when they are provisioned
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let vendor_top_priv_key = &VENDOR_CONFIG_KEY_0.priv_keys.unwrap().lms_priv_keys[0];
+    let owner_top_priv_key = &OWNER_CONFIG.priv_keys.unwrap().lms_priv_key;
+
+    let mut vendor_config = VENDOR_CONFIG_KEY_0;
+    vendor_config.lms_hss = Some(ImageGeneratorHssConfig {
+        signed_pub_keys: vec![gen
+            .gen_hss_signed_pub_key(&VENDOR_LMS_KEY_1_PUBLIC, vendor_top_priv_key)
+            .unwrap()],
+        priv_key: Some(VENDOR_LMS_KEY_1_PRIVATE),
+    });
+    let mut owner_config = OWNER_CONFIG;
+    owner_config.lms_hss = Some(ImageGeneratorHssConfig {
+        signed_pub_keys: vec![
+            gen.gen_hss_signed_pub_key(&VENDOR_LMS_KEY_2_PUBLIC, owner_top_priv_key)
+                .unwrap(),
+            gen.gen_hss_signed_pub_key(&VENDOR_LMS_KEY_3_PUBLIC, &VENDOR_LMS_KEY_2_PRIVATE)
+                .unwrap(),
+        ],
+        priv_key: Some(VENDOR_LMS_KEY_3_PRIVATE),
+    });
+    ImageOptions {
+        vendor_config,
+        owner_config: Some(owner_config),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_lms_hss_verify() {
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, lms_hss_image_options());
+    let preamble = &image_bundle.manifest.preamble;
+    assert_eq!(preamble.vendor_lms_hss_levels, 2);
+    assert_eq!(preamble.owner_lms_hss_levels, 3);
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+#[test]
+fn test_lms_hss_levels_bound_to_key_hash() {
+    let image_bundle = helpers::build_image_bundle(lms_hss_image_options());
+    let gen = ImageGenerator::new(OsslCrypto::default());
+    let preamble = &image_bundle.manifest.preamble;
+
+    // The owner key hash fuses must include the HSS levels
+    let mut unbound_preamble = *preamble;
+    unbound_preamble.owner_lms_hss_levels = 1;
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: true,
+        owner_pk_hash: gen.owner_pubkey_digest(&unbound_preamble).unwrap(),
+        ..Default::default()
+    };
+    let mut hw = helpers::build_extended_hw_model(fuses);
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_PUB_KEY_DIGEST_MISMATCH.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: true,
+        owner_pk_hash: gen.owner_pubkey_digest(preamble).unwrap(),
+        ..Default::default()
+    };
+    let mut hw = helpers::build_extended_hw_model(fuses);
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
+
+#[test]
+fn test_lms_hss_builds_only_sign_with_bottom_level() {
+    let state_path = std::env::temp_dir().join(format!(
+        "caliptra-rom-hss-lms-state-{}.toml",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&state_path);
+    let image_options = lms_hss_image_options();
+    let build = || {
+        helpers::build_image_bundle(ImageOptions {
+            lms_key_state: Some(state_path.clone()),
+            ..image_options.clone()
+        })
+    };
+    let image1 = build();
+    let image2 = build();
+
+    // The provisioned signatures of the upper levels are copied as is
+    let hss1 = image1.hss_extension.unwrap();
+    let hss2 = image2.hss_extension.unwrap();
+    assert_eq!(hss1.as_bytes(), hss2.as_bytes());
+    assert_eq!(
+        hss1.vendor_signed_pub_keys[0].sig.q,
+        hss2.vendor_signed_pub_keys[0].sig.q
+    );
+    assert_eq!(
+        hss1.owner_signed_pub_keys[0].sig.q,
+        hss2.owner_signed_pub_keys[0].sig.q
+    );
+
+    // Each build signs with a new leaf of the bottom level keys only
+    let (preamble1, preamble2) = (&image1.manifest.preamble, &image2.manifest.preamble);
+    assert_ne!(
+        preamble1.vendor_sigs.lms_sig.q,
+        preamble2.vendor_sigs.lms_sig.q
+    );
+    assert_ne!(
+        preamble1.owner_sigs.lms_sig.q,
+        preamble2.owner_sigs.lms_sig.q
+    );
+    let state = LmsKeyState::open(&state_path).unwrap();
+    for priv_key in [
+        &VENDOR_CONFIG_KEY_0.priv_keys.unwrap().lms_priv_keys[0],
+        &OWNER_CONFIG.priv_keys.unwrap().lms_priv_key,
+        &VENDOR_LMS_KEY_2_PRIVATE,
+    ] {
+        assert_eq!(
+            state.remaining(priv_key).unwrap(),
+            1 << lms_tree_height(priv_key).unwrap()
+        );
+    }
+    std::fs::remove_file(&state_path).unwrap();
+}
+
+#[test]
+fn test_lms_hss_verify_vendor_sig_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, lms_hss_image_options());
+    image_bundle
+        .hss_extension
+        .as_mut()
+        .unwrap()
+        .vendor_signed_pub_keys[0]
+        .sig
+        .tree_path[0][0] = 0.into();
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_VENDOR_LMS_HSS_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+    assert_eq!(
+        hw.soc_ifc().cptra_boot_status().read(),
+        u32::from(FwProcessorManifestLoadComplete)
+    );
+}
+
+#[test]
+fn test_lms_hss_verify_owner_sig_mismatch() {
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: true,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, lms_hss_image_options());
+    image_bundle
+        .hss_extension
+        .as_mut()
+        .unwrap()
+        .owner_signed_pub_keys[1]
+        .sig
+        .tree_path[0][0] = 0.into();
+
+    assert_eq!(
+        ModelError::MailboxCmdFailed(
+            CaliptraError::IMAGE_VERIFIER_ERR_OWNER_LMS_HSS_SIGNATURE_INVALID.into()
+        ),
+        hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_lms_hss_verify_optional_no_sig_mismatch_check() {
+    let fuses = caliptra_hw_model::Fuses {
+        lms_verify: false,
+        ..Default::default()
+    };
+    let (mut hw, mut image_bundle) =
+        helpers::build_extended_hw_model_and_image_bundle(fuses, lms_hss_image_options());
+    image_bundle
+        .hss_extension
+        .as_mut()
+        .unwrap()
+        .vendor_signed_pub_keys[0]
+        .sig
+        .tree_path[0][0] = 0.into();
+
+    hw.upload_firmware(&image_bundle.to_bytes().unwrap())
+        .unwrap();
+    hw.step_until_boot_status(u32::from(ColdResetComplete), true);
+}
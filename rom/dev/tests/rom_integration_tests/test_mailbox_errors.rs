@@ -1,7 +1,9 @@
 // Licensed under the Apache-2.0 license
 
 use caliptra_builder::ImageOptions;
-use caliptra_common::mailbox_api::{CommandId, MailboxReqHeader, StashMeasurementReq};
+use caliptra_common::mailbox_api::{
+    CommandId, GetCrashInfoReq, MailboxReqHeader, StashMeasurementReq,
+};
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{Fuses, HwModel, ModelError};
 use zerocopy::AsBytes;
@@ -31,6 +33,44 @@ fn test_unknown_command_is_fatal() {
     );
 }
 
+#[test]
+fn test_crash_info_after_fatal_error() {
+    let (mut hw, _image_bundle) = helpers::build_extended_hw_model_and_image_bundle(
+        Fuses::default(),
+        ImageOptions::default(),
+    );
+
+    // DCCM is cleared on cold reset
+    let crash_info = hw.mailbox_execute_req(GetCrashInfoReq::default()).unwrap();
+    assert_eq!(crash_info.valid, 0);
+
+    // This command does not exist
+    assert!(hw.mailbox_execute(0xabcd_1234, &[]).is_err());
+    hw.step_until_fatal_error(
+        CaliptraError::FW_PROC_MAILBOX_INVALID_COMMAND.into(),
+        MAX_WAIT_CYCLES,
+    );
+
+    let crash_info = hw.mailbox_execute_req(GetCrashInfoReq::default()).unwrap();
+    assert_eq!(crash_info.valid, 1);
+    assert_eq!(
+        crash_info.fw_error_fatal,
+        u32::from(CaliptraError::FW_PROC_MAILBOX_INVALID_COMMAND)
+    );
+    assert_eq!(crash_info.last_mbox_cmd, 0xabcd_1234);
+    // Not caused by a trap
+    assert_eq!(crash_info.mcause, 0);
+    assert_eq!(crash_info.regs, [0; 17]);
+
+    // Other commands are still rejected
+    assert_eq!(
+        hw.mailbox_execute(u32::from(CommandId::CAPABILITIES), &[]),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::FW_PROC_MAILBOX_INVALID_COMMAND.into()
+        ))
+    );
+}
+
 #[test]
 fn test_mailbox_command_aborted_after_handle_fatal_error() {
     let (mut hw, image_bundle) =
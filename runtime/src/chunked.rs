@@ -0,0 +1,175 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    chunked.rs
+
+Abstract:
+
+    File contains the mailbox commands that carry requests and responses
+    too large for a single mailbox transaction.
+
+--*/
+
+use caliptra_common::mailbox_api::{
+    ChunkedBeginReq, ChunkedDataReq, ChunkedReadReq, CommandId, GetCertChainReq, MailboxReqHeader,
+    MailboxResp,
+};
+use caliptra_error::{CaliptraError, CaliptraResult};
+use core::mem::size_of;
+use zerocopy::{AsBytes, FromBytes};
+
+use crate::mailbox::ChunkedTransfer;
+use crate::{execute_command, Drivers, GetCertChainCmd};
+
+/// Copy the chunk carried by a CHUNKED_CONTINUE or CHUNKED_END request into
+/// the staging buffer
+fn append_chunk(chunked: &mut ChunkedTransfer, cmd_args: &[u8]) -> CaliptraResult<()> {
+    if cmd_args.len() > size_of::<ChunkedDataReq>() {
+        return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+    }
+    let mut cmd = ChunkedDataReq::default();
+    cmd.as_bytes_mut()[..cmd_args.len()].copy_from_slice(cmd_args);
+
+    let data_size = cmd.data_size as usize;
+    if data_size > ChunkedDataReq::DATA_MAX_SIZE
+        || cmd_args.len() < size_of::<ChunkedDataReq>() - ChunkedDataReq::DATA_MAX_SIZE + data_size
+    {
+        return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+    }
+    chunked.append(cmd.offset, &cmd.data[..data_size])
+}
+
+pub struct ChunkedBeginCmd;
+impl ChunkedBeginCmd {
+    pub(crate) fn execute(
+        chunked: &mut ChunkedTransfer,
+        cmd_args: &[u8],
+    ) -> CaliptraResult<MailboxResp> {
+        if cmd_args.len() > size_of::<ChunkedBeginReq>() {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        }
+        let mut cmd = ChunkedBeginReq::default();
+        cmd.as_bytes_mut()[..cmd_args.len()].copy_from_slice(cmd_args);
+
+        let data_size = cmd.data_size as usize;
+        if data_size > ChunkedBeginReq::DATA_MAX_SIZE
+            || cmd_args.len()
+                < size_of::<ChunkedBeginReq>() - ChunkedBeginReq::DATA_MAX_SIZE + data_size
+        {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+
+        // Chunked transfers cannot be nested, and firmware updates are read
+        // directly from the mailbox
+        match CommandId::from(cmd.cmd) {
+            CommandId::CHUNKED_BEGIN
+            | CommandId::CHUNKED_CONTINUE
+            | CommandId::CHUNKED_END
+            | CommandId::CHUNKED_READ
+            | CommandId::FIRMWARE_LOAD => {
+                return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)
+            }
+            _ => (),
+        }
+
+        chunked.begin(cmd.cmd, cmd.total_size, cmd.payload_chksum)?;
+        chunked.append(0, &cmd.data[..data_size])?;
+        Ok(MailboxResp::default())
+    }
+}
+
+pub struct ChunkedContinueCmd;
+impl ChunkedContinueCmd {
+    pub(crate) fn execute(
+        chunked: &mut ChunkedTransfer,
+        cmd_args: &[u8],
+    ) -> CaliptraResult<MailboxResp> {
+        append_chunk(chunked, cmd_args)?;
+        Ok(MailboxResp::default())
+    }
+}
+
+pub struct ChunkedEndCmd;
+impl ChunkedEndCmd {
+    pub(crate) fn execute(
+        drivers: &mut Drivers,
+        chunked: &mut ChunkedTransfer,
+        cmd_args: &[u8],
+    ) -> CaliptraResult<MailboxResp> {
+        #[cfg(all(feature = "riscv", feature = "test_only_commands"))]
+        crate::stack_usage::paint();
+
+        let result = Self::execute_request(drivers, chunked, cmd_args);
+
+        #[cfg(all(feature = "riscv", feature = "test_only_commands"))]
+        caliptra_common::cprintln!(
+            "[rt] CHUNKED_END stack usage={}",
+            crate::stack_usage::high_water_mark()
+        );
+
+        result
+    }
+
+    fn execute_request(
+        drivers: &mut Drivers,
+        chunked: &mut ChunkedTransfer,
+        cmd_args: &[u8],
+    ) -> CaliptraResult<MailboxResp> {
+        if let Err(e) = append_chunk(chunked, cmd_args) {
+            chunked.reset();
+            return Err(e);
+        }
+
+        // The request is executed in place, without copying it out of the
+        // staging buffer
+        let (cmd, req) = chunked.end_request()?;
+
+        // The assembled request carries its own header, verified the same
+        // way as a request read directly from the mailbox
+        let Some(req_hdr) = MailboxReqHeader::read_from_prefix(req) else {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        };
+        if !caliptra_common::checksum::verify_checksum(
+            req_hdr.chksum,
+            cmd,
+            &req[size_of::<MailboxReqHeader>()..],
+        ) {
+            return Err(CaliptraError::RUNTIME_INVALID_CHECKSUM);
+        }
+
+        // Variable-length responses are streamed straight into the staging
+        // buffer so they are not capped by the size of their response struct
+        match CommandId::from(cmd) {
+            CommandId::GET_CERT_CHAIN => {
+                // Parse the request before the response overwrites it
+                let req = GetCertChainReq::read_from(req)
+                    .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+                GetCertChainCmd::execute_chunked(drivers, chunked, &req)?
+            }
+            _ => {
+                let mut resp = execute_command(drivers, cmd, req)?;
+                resp.populate_chksum()?;
+                chunked.set_response(resp.as_bytes()?)?;
+            }
+        }
+
+        Ok(MailboxResp::Chunked(chunked.read_response(0)?))
+    }
+}
+
+pub struct ChunkedReadCmd;
+impl ChunkedReadCmd {
+    pub(crate) fn execute(
+        chunked: &ChunkedTransfer,
+        cmd_args: &[u8],
+    ) -> CaliptraResult<MailboxResp> {
+        if let Some(cmd) = ChunkedReadReq::read_from(cmd_args) {
+            Ok(MailboxResp::Chunked(chunked.read_response(cmd.offset)?))
+        } else {
+            Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)
+        }
+    }
+}
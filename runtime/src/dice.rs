@@ -1,18 +1,25 @@
 // Licensed under the Apache-2.0 license
 
 use caliptra_common::mailbox_api::{
-    GetFmcAliasCertResp, GetIdevCertReq, GetIdevCertResp, GetLdevCertResp, GetRtAliasCertResp,
-    MailboxResp, MailboxRespHeader,
+    GetCertChainReq, GetCertChainResp, GetFmcAliasCertResp, GetIdevCertReq, GetIdevCertResp,
+    GetIdevCsrResp, GetLdevCertResp, GetRtAliasCertResp, MailboxResp, MailboxRespHeader,
 };
 
-use crate::Drivers;
+use crate::mailbox::ChunkedTransfer;
+use crate::{CptraDpeTypes, DpeCrypto, DpeEnv, DpePlatform, Drivers};
 
+use caliptra_cbor_cert::CoseSign1Builder;
 use caliptra_drivers::{
-    hand_off::DataStore, CaliptraError, CaliptraResult, DataVault, Ecc384Scalar, Ecc384Signature,
-    PersistentData,
+    hand_off::DataStore, CaliptraError, CaliptraResult, DataVault, DiceCertFormat, Ecc384Scalar,
+    Ecc384Signature, PersistentData,
 };
 use caliptra_x509::{Ecdsa384CertBuilder, Ecdsa384Signature};
-use zerocopy::AsBytes;
+use dpe::{
+    commands::{CertifyKeyCmd, CertifyKeyFlags, CommandExecution},
+    context::ContextHandle,
+    response::{CertifyKeyResp, Response},
+};
+use zerocopy::{AsBytes, FromBytes};
 
 pub struct IDevIdCertCmd;
 impl IDevIdCertCmd {
@@ -51,6 +58,27 @@ impl IDevIdCertCmd {
     }
 }
 
+pub struct GetIdevCsrCmd;
+impl GetIdevCsrCmd {
+    pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
+        let csr = drivers
+            .persistent_data
+            .get()
+            .idevid_csr
+            .get()
+            .ok_or(CaliptraError::RUNTIME_IDEV_CSR_UNAVAILABLE)?;
+
+        let mut resp = GetIdevCsrResp::default();
+        resp.data
+            .get_mut(..csr.len())
+            .ok_or(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)?
+            .copy_from_slice(csr);
+        resp.data_size = csr.len() as u32;
+
+        Ok(MailboxResp::GetIdevCsr(resp))
+    }
+}
+
 pub struct GetLdevCertCmd;
 impl GetLdevCertCmd {
     pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
@@ -59,6 +87,7 @@ impl GetLdevCertCmd {
         resp.data_size = copy_ldevid_cert(
             &drivers.data_vault,
             drivers.persistent_data.get(),
+            drivers.soc_ifc.fuse_bank().dice_cert_format(),
             &mut resp.data,
         )? as u32;
 
@@ -74,6 +103,7 @@ impl GetFmcAliasCertCmd {
         resp.data_size = copy_fmc_alias_cert(
             &drivers.data_vault,
             drivers.persistent_data.get(),
+            drivers.soc_ifc.fuse_bank().dice_cert_format(),
             &mut resp.data,
         )? as u32;
 
@@ -86,12 +116,256 @@ impl GetRtAliasCertCmd {
     pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
         let mut resp = GetRtAliasCertResp::default();
 
-        resp.data_size = copy_rt_alias_cert(drivers.persistent_data.get(), &mut resp.data)? as u32;
+        resp.data_size = copy_rt_alias_cert(
+            drivers.persistent_data.get(),
+            drivers.soc_ifc.fuse_bank().dice_cert_format(),
+            &mut resp.data,
+        )? as u32;
 
         Ok(MailboxResp::GetRtAliasCert(resp))
     }
 }
 
+pub struct GetCertChainCmd;
+impl GetCertChainCmd {
+    /// Label used to derive the DPE leaf key returned as part of the chain
+    pub(crate) const DPE_LEAF_LABEL: [u8; 48] = [0u8; 48];
+
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd = GetCertChainReq::read_from(cmd_args)
+            .ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+        let (mut resp, leaf) = Self::prepare(drivers, &cmd)?;
+        let leaf_cert = Self::leaf_cert(&leaf)?;
+        let chain = drivers.cert_chain.as_slice();
+
+        let total_size = resp.total_size as usize;
+        let start = cmd.offset as usize;
+        let end = core::cmp::min(total_size, start + GetCertChainResp::DATA_MAX_SIZE);
+
+        // Copy the requested window, which may straddle the stored chain and
+        // the DPE leaf cert.
+        for (i, byte) in (start..end).zip(resp.data.iter_mut()) {
+            *byte = match chain.get(i) {
+                Some(b) => *b,
+                None => *leaf_cert
+                    .get(i - chain.len())
+                    .ok_or(CaliptraError::RUNTIME_INTERNAL)?,
+            };
+        }
+
+        resp.next_offset = end as u32;
+        resp.data_size = (end - start) as u32;
+
+        Ok(MailboxResp::GetCertChain(resp))
+    }
+
+    /// Execute a GET_CERT_CHAIN request carried by a chunked transfer,
+    /// writing everything from the requested offset to the end of the chain
+    /// into the chunked staging buffer. The response has the same layout as
+    /// `GetCertChainResp`, but `data` is not limited to `DATA_MAX_SIZE`.
+    pub(crate) fn execute_chunked(
+        drivers: &mut Drivers,
+        chunked: &mut ChunkedTransfer,
+        cmd: &GetCertChainReq,
+    ) -> CaliptraResult<()> {
+        let (mut resp, leaf) = Self::prepare(drivers, cmd)?;
+        let leaf_cert = Self::leaf_cert(&leaf)?;
+        let chain = drivers.cert_chain.as_slice();
+
+        let start = cmd.offset as usize;
+        resp.next_offset = resp.total_size;
+        resp.data_size = resp.total_size - cmd.offset;
+
+        chunked.begin_response();
+        let fixed_size = core::mem::size_of::<GetCertChainResp>() - GetCertChainResp::DATA_MAX_SIZE;
+        chunked.push_response(&resp.as_bytes()[..fixed_size])?;
+        if let Some(chain) = chain.get(start..) {
+            chunked.push_response(chain)?;
+        }
+        let leaf_start = start.saturating_sub(chain.len());
+        chunked.push_response(
+            leaf_cert
+                .get(leaf_start..)
+                .ok_or(CaliptraError::RUNTIME_INTERNAL)?,
+        )?;
+        chunked.end_response()
+    }
+
+    /// Check the request, certify the DPE leaf if requested and fill in the
+    /// response fields shared by every chunk
+    fn prepare(
+        drivers: &mut Drivers,
+        cmd: &GetCertChainReq,
+    ) -> CaliptraResult<(GetCertChainResp, Option<CertifyKeyResp>)> {
+        if cmd.flags & !GetCertChainReq::FLAG_INCLUDE_DPE_LEAF != 0 {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+
+        let mut resp = GetCertChainResp::default();
+
+        // The stored chain holds the IDevID (if populated), LDevID, FMC alias
+        // and RT alias certs back to back. Walk it to find each cert's offset.
+        let format = drivers.soc_ifc.fuse_bank().dice_cert_format();
+        let chain = drivers.cert_chain.as_slice();
+        let mut offset = 0;
+        while offset < chain.len() {
+            Self::push_cert_offset(&mut resp, offset)?;
+            offset += cert_len(format, &chain[offset..])?;
+        }
+
+        let leaf = if cmd.flags & GetCertChainReq::FLAG_INCLUDE_DPE_LEAF != 0 {
+            Some(Self::certify_dpe_leaf(drivers)?)
+        } else {
+            None
+        };
+        let leaf_len = Self::leaf_cert(&leaf)?.len();
+        let chain_len = drivers.cert_chain.as_slice().len();
+        if leaf_len != 0 {
+            Self::push_cert_offset(&mut resp, chain_len)?;
+        }
+
+        resp.total_size = (chain_len + leaf_len) as u32;
+        if cmd.offset > resp.total_size {
+            return Err(CaliptraError::RUNTIME_GET_CERT_CHAIN_INVALID_OFFSET);
+        }
+
+        Ok((resp, leaf))
+    }
+
+    fn leaf_cert(leaf: &Option<CertifyKeyResp>) -> CaliptraResult<&[u8]> {
+        match leaf {
+            Some(leaf) => leaf
+                .cert
+                .get(..leaf.cert_size as usize)
+                .ok_or(CaliptraError::RUNTIME_GET_CERT_CHAIN_DPE_LEAF_FAILED),
+            None => Ok(&[]),
+        }
+    }
+
+    fn push_cert_offset(resp: &mut GetCertChainResp, offset: usize) -> CaliptraResult<()> {
+        let slot = resp
+            .cert_offsets
+            .get_mut(resp.cert_count as usize)
+            .ok_or(CaliptraError::RUNTIME_GET_CERT_CHAIN_TOO_MANY_CERTS)?;
+        *slot = offset as u32;
+        resp.cert_count += 1;
+        Ok(())
+    }
+
+    /// Certify the caller's default DPE context with a fixed label. DPE
+    /// signing is deterministic, so every chunk sees the same leaf cert.
+    fn certify_dpe_leaf(drivers: &mut Drivers) -> CaliptraResult<CertifyKeyResp> {
+        let locality = drivers.mbox.user();
+        let pdata = drivers.persistent_data.get();
+        let pl0_pauser = pdata.manifest1.header.pl0_pauser;
+        let flags = pdata.manifest1.header.flags;
+
+        // PL1 cannot request X509
+        if Drivers::is_caller_pl1(pl0_pauser, flags, locality) {
+            return Err(CaliptraError::RUNTIME_INCORRECT_PAUSER_PRIVILEGE_LEVEL);
+        }
+
+        let hashed_rt_pub_key = drivers.compute_rt_alias_sn()?;
+        let key_id_rt_cdi = Drivers::get_key_id_rt_cdi(drivers)?;
+        let key_id_rt_priv_key = Drivers::get_key_id_rt_priv_key(drivers)?;
+        let pdata = drivers.persistent_data.get();
+        let crypto = DpeCrypto::new(
+            &mut drivers.sha384,
+            &mut drivers.trng,
+            &mut drivers.ecc384,
+            &mut drivers.hmac384,
+            &mut drivers.key_vault,
+            pdata.fht.rt_dice_pub_key,
+            key_id_rt_cdi,
+            key_id_rt_priv_key,
+        );
+        let mut env = DpeEnv::<CptraDpeTypes> {
+            crypto,
+            platform: DpePlatform::new(pl0_pauser, hashed_rt_pub_key, &mut drivers.cert_chain),
+        };
+
+        let resp = CertifyKeyCmd {
+            handle: ContextHandle::default(),
+            label: Self::DPE_LEAF_LABEL,
+            flags: CertifyKeyFlags::empty(),
+            format: CertifyKeyCmd::FORMAT_X509,
+        }
+        .execute(
+            &mut drivers.persistent_data.get_mut().dpe,
+            &mut env,
+            locality,
+        );
+
+        match resp {
+            Ok(Response::CertifyKey(resp)) => Ok(resp),
+            _ => Err(CaliptraError::RUNTIME_GET_CERT_CHAIN_DPE_LEAF_FAILED),
+        }
+    }
+}
+
+/// Compute the encoded length of the certificate at the start of `cert`
+///
+/// # Arguments
+///
+/// * `format` - DICE certificate format selected by fuses
+/// * `cert` - Buffer starting with a certificate
+///
+/// # Returns
+///
+/// * `usize` - The length of the certificate
+fn cert_len(format: DiceCertFormat, cert: &[u8]) -> CaliptraResult<usize> {
+    // Untagged COSE_Sign1: an array of four items
+    const COSE_SIGN1_HEADER: u8 = 0x84;
+
+    // The IDevID cert populated by the SoC is X.509 in either format.
+    match format {
+        DiceCertFormat::Cbor if cert.first() == Some(&COSE_SIGN1_HEADER) => {
+            caliptra_cbor_cert::cbor_item_len(cert)
+                .ok_or(CaliptraError::RUNTIME_GET_CERT_CHAIN_MALFORMED_CERT)
+        }
+        _ => der_cert_len(cert),
+    }
+}
+
+/// Compute the encoded length of the DER certificate at the start of `der`
+///
+/// # Arguments
+///
+/// * `der` - Buffer starting with a DER-encoded certificate
+///
+/// # Returns
+///
+/// * `usize` - The length of the certificate including its SEQUENCE header
+fn der_cert_len(der: &[u8]) -> CaliptraResult<usize> {
+    const SEQUENCE_TAG: u8 = 0x30;
+    let err = CaliptraError::RUNTIME_GET_CERT_CHAIN_MALFORMED_CERT;
+
+    if der.first() != Some(&SEQUENCE_TAG) {
+        return Err(err);
+    }
+    let first_len_byte = *der.get(1).ok_or(err)?;
+    let (hdr_len, content_len) = if first_len_byte < 0x80 {
+        (2, first_len_byte as usize)
+    } else {
+        // Long form; certs in the chain never need more than two length bytes.
+        let num_len_bytes = (first_len_byte & 0x7f) as usize;
+        if num_len_bytes == 0 || num_len_bytes > 2 {
+            return Err(err);
+        }
+        let len_bytes = der.get(2..2 + num_len_bytes).ok_or(err)?;
+        let content_len = len_bytes
+            .iter()
+            .fold(0usize, |acc, b| (acc << 8) | *b as usize);
+        (2 + num_len_bytes, content_len)
+    };
+
+    let total_len = hdr_len + content_len;
+    if total_len > der.len() {
+        return Err(err);
+    }
+    Ok(total_len)
+}
+
 /// Retrieve the r portion of the LDevId cert signature
 ///
 /// # Arguments
@@ -174,6 +448,7 @@ pub fn ldevid_dice_sign(
 ///
 /// * `dv` - DataVault
 /// * `persistent_data` - PersistentData
+/// * `format` - DICE certificate format selected by fuses
 /// * `cert` - Buffer to copy LDevID certificate to
 ///
 /// # Returns
@@ -183,13 +458,14 @@ pub fn ldevid_dice_sign(
 pub fn copy_ldevid_cert(
     dv: &DataVault,
     persistent_data: &PersistentData,
+    format: DiceCertFormat,
     cert: &mut [u8],
 ) -> CaliptraResult<usize> {
     let tbs = persistent_data
         .ldevid_tbs
         .get(..persistent_data.fht.ldevid_tbs_size.into());
     let sig = ldevid_dice_sign(persistent_data, dv)?;
-    cert_from_tbs_and_sig(tbs, &sig, cert)
+    cert_from_tbs_and_sig(tbs, &sig, format, cert)
         .map_err(|_| CaliptraError::RUNTIME_GET_LDEVID_CERT_FAILED)
 }
 
@@ -275,6 +551,7 @@ pub fn fmc_dice_sign(
 ///
 /// * `dv` - DataVault
 /// * `persistent_data` - PersistentData
+/// * `format` - DICE certificate format selected by fuses
 /// * `cert` - Buffer to copy LDevID certificate to
 ///
 /// # Returns
@@ -284,13 +561,14 @@ pub fn fmc_dice_sign(
 pub fn copy_fmc_alias_cert(
     dv: &DataVault,
     persistent_data: &PersistentData,
+    format: DiceCertFormat,
     cert: &mut [u8],
 ) -> CaliptraResult<usize> {
     let tbs = persistent_data
         .fmcalias_tbs
         .get(..persistent_data.fht.fmcalias_tbs_size.into());
     let sig = fmc_dice_sign(persistent_data, dv)?;
-    cert_from_tbs_and_sig(tbs, &sig, cert)
+    cert_from_tbs_and_sig(tbs, &sig, format, cert)
         .map_err(|_| CaliptraError::RUNTIME_GET_FMC_ALIAS_CERT_FAILED)
 }
 
@@ -299,6 +577,7 @@ pub fn copy_fmc_alias_cert(
 /// # Arguments
 ///
 /// * `persistent_data` - PersistentData
+/// * `format` - DICE certificate format selected by fuses
 /// * `cert` - Buffer to copy LDevID certificate to
 ///
 /// # Returns
@@ -307,12 +586,13 @@ pub fn copy_fmc_alias_cert(
 #[inline(never)]
 pub fn copy_rt_alias_cert(
     persistent_data: &PersistentData,
+    format: DiceCertFormat,
     cert: &mut [u8],
 ) -> CaliptraResult<usize> {
     let tbs = persistent_data
         .rtalias_tbs
         .get(..persistent_data.fht.rtalias_tbs_size.into());
-    cert_from_tbs_and_sig(tbs, &persistent_data.fht.rt_dice_sign, cert)
+    cert_from_tbs_and_sig(tbs, &persistent_data.fht.rt_dice_sign, format, cert)
         .map_err(|_| CaliptraError::RUNTIME_GET_RT_ALIAS_CERT_FAILED)
 }
 
@@ -320,8 +600,9 @@ pub fn copy_rt_alias_cert(
 ///
 /// # Arguments
 ///
-/// * `tbs` - ToBeSigned portion
+/// * `tbs` - ToBeSigned portion, the CWT claims of a CBOR certificate
 /// * `sig` - Ecc384Signature
+/// * `format` - Format of the certificate
 /// * `cert` - Buffer to copy LDevID certificate to
 ///
 /// # Returns
@@ -330,6 +611,7 @@ pub fn copy_rt_alias_cert(
 fn cert_from_tbs_and_sig(
     tbs: Option<&[u8]>,
     sig: &Ecc384Signature,
+    format: DiceCertFormat,
     cert: &mut [u8],
 ) -> CaliptraResult<usize> {
     let Some(tbs) = tbs else {
@@ -341,11 +623,16 @@ fn cert_from_tbs_and_sig(
         r: sig.r.into(),
         s: sig.s.into(),
     };
-    let Some(builder) = Ecdsa384CertBuilder::new(tbs, &bldr_sig) else {
-        return Err(CaliptraError::RUNTIME_INTERNAL);
-    };
 
-    let Some(size) = builder.build(cert) else {
+    let size = match format {
+        DiceCertFormat::X509 => {
+            Ecdsa384CertBuilder::new(tbs, &bldr_sig).and_then(|builder| builder.build(cert))
+        }
+        DiceCertFormat::Cbor => {
+            CoseSign1Builder::new(tbs, &bldr_sig).and_then(|builder| builder.build(cert))
+        }
+    };
+    let Some(size) = size else {
         return Err(CaliptraError::RUNTIME_INTERNAL);
     };
 
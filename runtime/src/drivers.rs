@@ -17,6 +17,7 @@ Abstract:
 #[cfg(feature = "fips_self_test")]
 pub use crate::fips::{fips_self_test_cmd, fips_self_test_cmd::SelfTestStatus};
 
+use crate::time::GeneralizedTime;
 use crate::{
     dice, CptraDpeTypes, DisableAttestationCmd, DpeCrypto, DpePlatform, Mailbox, DPE_SUPPORT,
     MAX_CERT_CHAIN_SIZE, PL0_DPE_ACTIVE_CONTEXT_THRESHOLD, PL0_PAUSER_FLAG,
@@ -93,6 +94,12 @@ pub struct Drivers {
 
     pub cert_chain: ArrayVec<u8, MAX_CERT_CHAIN_SIZE>,
 
+    /// Time most recently set by an owner-signed SET_TIME token
+    pub trusted_time: Option<GeneralizedTime>,
+
+    /// Nonce issued by GET_TIME_NONCE for the next SET_TIME token
+    pub time_nonce: Option<[u8; 32]>,
+
     #[cfg(feature = "fips_self_test")]
     pub self_test_status: SelfTestStatus,
 
@@ -170,6 +177,8 @@ impl Drivers {
             #[cfg(feature = "fips_self_test")]
             self_test_status: SelfTestStatus::Idle,
             cert_chain: ArrayVec::new(),
+            trusted_time: None,
+            time_nonce: None,
             is_shutdown: false,
         })
     }
@@ -481,11 +490,12 @@ impl Drivers {
     fn create_cert_chain(drivers: &mut Drivers) -> CaliptraResult<()> {
         let data_vault = &drivers.data_vault;
         let persistent_data = &drivers.persistent_data;
+        let format = drivers.soc_ifc.fuse_bank().dice_cert_format();
         let mut cert = [0u8; MAX_CERT_CHAIN_SIZE];
 
         // Write ldev_id cert to cert chain.
         let ldevid_cert_size =
-            dice::copy_ldevid_cert(data_vault, persistent_data.get(), &mut cert)?;
+            dice::copy_ldevid_cert(data_vault, persistent_data.get(), format, &mut cert)?;
         if ldevid_cert_size > cert.len() {
             return Err(CaliptraError::RUNTIME_LDEV_ID_CERT_TOO_BIG);
         }
@@ -494,6 +504,7 @@ impl Drivers {
         let fmcalias_cert_size = dice::copy_fmc_alias_cert(
             data_vault,
             persistent_data.get(),
+            format,
             &mut cert[ldevid_cert_size..],
         )?;
         if ldevid_cert_size + fmcalias_cert_size > cert.len() {
@@ -503,6 +514,7 @@ impl Drivers {
         // Write rt alias cert to cert chain.
         let rtalias_cert_size = dice::copy_rt_alias_cert(
             persistent_data.get(),
+            format,
             &mut cert[ldevid_cert_size + fmcalias_cert_size..],
         )?;
         let cert_chain_size = ldevid_cert_size + fmcalias_cert_size + rtalias_cert_size;
@@ -0,0 +1,258 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    eat.rs
+
+Abstract:
+
+    File contains the GetEat mailbox command, which emits an Entity
+    Attestation Token (RFC 9711) as a COSE_Sign1-signed CBOR claims set.
+
+--*/
+
+use crate::{
+    handoff::RtHandoff, CptraDpeTypes, DpeCrypto, DpeEnv, DpePlatform, Drivers, GetCertChainCmd,
+};
+use caliptra_cbor_cert::{CborEncoder, CoseSign1Builder};
+use caliptra_common::mailbox_api::{EatClaim, GetEatReq, GetEatResp, MailboxResp};
+use caliptra_drivers::{
+    Array4x12, CaliptraError, CaliptraResult, Ecc384PrivKeyIn, Ecc384Signature, KeyReadArgs, Sha384,
+};
+use caliptra_x509::Ecdsa384Signature;
+use dpe::{
+    commands::{CommandExecution, SignCmd, SignFlags},
+    context::ContextHandle,
+    response::Response,
+};
+use zerocopy::FromBytes;
+
+pub struct GetEatCmd;
+impl GetEatCmd {
+    const COSE_SIGN1_TAG: u64 = 18;
+
+    /// The payload follows the one byte tag and the COSE_Sign1 header, which
+    /// ends with a byte string header with a two byte length.
+    const PAYLOAD_OFFSET: usize = 1 + CoseSign1Builder::HEADER_LEN;
+
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let cmd =
+            GetEatReq::read_from(cmd_args).ok_or(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS)?;
+        if cmd.flags & !GetEatReq::FLAG_SIGN_WITH_DPE_KEY != 0 {
+            return Err(CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS);
+        }
+
+        // Encode the claims in place so the token doesn't need a second buffer.
+        let mut resp = GetEatResp::default();
+        let payload_len = Self::encode_claims(
+            drivers,
+            &cmd.nonce,
+            resp.data
+                .get_mut(Self::PAYLOAD_OFFSET..)
+                .ok_or(CaliptraError::RUNTIME_INTERNAL)?,
+        )?;
+        if payload_len <= u8::MAX as usize || payload_len > u16::MAX as usize {
+            return Err(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED);
+        }
+        let payload_end = Self::PAYLOAD_OFFSET + payload_len;
+
+        let payload = resp
+            .data
+            .get(Self::PAYLOAD_OFFSET..payload_end)
+            .ok_or(CaliptraError::RUNTIME_INTERNAL)?;
+        let digest = Self::sig_structure_digest(&mut drivers.sha384, payload)?;
+        let sig = if cmd.flags & GetEatReq::FLAG_SIGN_WITH_DPE_KEY != 0 {
+            Self::sign_with_dpe_key(drivers, &digest)?
+        } else {
+            Self::sign_with_rt_alias(drivers, &digest)?
+        };
+
+        let mut enc = CborEncoder::new(
+            resp.data
+                .get_mut(..Self::PAYLOAD_OFFSET)
+                .ok_or(CaliptraError::RUNTIME_INTERNAL)?,
+        );
+        enc.tag(Self::COSE_SIGN1_TAG)
+            .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?;
+        CoseSign1Builder::encode_header(&mut enc, payload_len)
+            .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?;
+
+        let sig = Ecdsa384Signature {
+            r: sig.r.into(),
+            s: sig.s.into(),
+        };
+        let mut enc = CborEncoder::new(
+            resp.data
+                .get_mut(payload_end..)
+                .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?,
+        );
+        CoseSign1Builder::encode_signature(&mut enc, &sig)
+            .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?;
+
+        resp.data_size = (payload_end + enc.encoded_len()) as u32;
+        Ok(MailboxResp::GetEat(resp))
+    }
+
+    /// Encode the EAT claims set into `buf`
+    ///
+    /// # Arguments
+    ///
+    /// * `drivers` - Drivers
+    /// * `nonce` - Caller-supplied freshness nonce
+    /// * `buf` - Buffer to encode the claims into
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of bytes written to `buf`
+    fn encode_claims(drivers: &Drivers, nonce: &[u8; 32], buf: &mut [u8]) -> CaliptraResult<usize> {
+        let pdata = drivers.persistent_data.get();
+        let handoff = RtHandoff {
+            data_vault: &drivers.data_vault,
+            fht: &pdata.fht,
+        };
+        let fmc_svn = handoff.fmc_svn()?;
+        let rt_svn = handoff.rt_svn()?;
+        let rt_min_svn = handoff.rt_min_svn()?;
+
+        let mut enc = CborEncoder::new(buf);
+        Self::encode_claims_to(drivers, nonce, fmc_svn, rt_svn, rt_min_svn, &mut enc)
+            .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?;
+        Ok(enc.encoded_len())
+    }
+
+    /// Encode the EAT claims set with `enc`, once the claims that can fail to
+    /// be read have been gathered
+    fn encode_claims_to(
+        drivers: &Drivers,
+        nonce: &[u8; 32],
+        fmc_svn: u32,
+        rt_svn: u32,
+        rt_min_svn: u32,
+        enc: &mut CborEncoder,
+    ) -> Option<()> {
+        let pdata = drivers.persistent_data.get();
+        enc.map(9)?;
+
+        enc.int(EatClaim::NONCE)?;
+        enc.bytes(nonce)?;
+
+        enc.int(EatClaim::UEID)?;
+        enc.bytes(&drivers.soc_ifc.fuse_bank().ueid())?;
+
+        let pcrs = drivers.pcr_bank.read_all_pcrs();
+        enc.int(EatClaim::PCRS)?;
+        enc.array(pcrs.len())?;
+        for pcr in pcrs {
+            enc.bytes(&<[u8; 48]>::from(pcr))?;
+        }
+
+        let reset_ctrs = pdata.pcr_reset.all_counters();
+        enc.int(EatClaim::PCR_RESET_COUNTERS)?;
+        enc.array(reset_ctrs.len())?;
+        for ctr in reset_ctrs {
+            enc.uint(ctr.into())?;
+        }
+
+        enc.int(EatClaim::FMC_SVN)?;
+        enc.uint(fmc_svn.into())?;
+
+        enc.int(EatClaim::RT_SVN)?;
+        enc.uint(rt_svn.into())?;
+
+        enc.int(EatClaim::RT_MIN_SVN)?;
+        enc.uint(rt_min_svn.into())?;
+
+        // These are the FWIDs in the FMC alias and RT alias TcbInfo extensions.
+        enc.int(EatClaim::FMC_FWID)?;
+        enc.bytes(&<[u8; 48]>::from(Array4x12::from(
+            pdata.manifest1.fmc.digest,
+        )))?;
+
+        enc.int(EatClaim::RT_FWID)?;
+        enc.bytes(&<[u8; 48]>::from(Array4x12::from(
+            pdata.manifest1.runtime.digest,
+        )))
+    }
+
+    /// Compute the SHA-384 digest of the COSE_Sign1 Sig_structure
+    /// `["Signature1", protected, external_aad, payload]`
+    fn sig_structure_digest(sha384: &mut Sha384, payload: &[u8]) -> CaliptraResult<Array4x12> {
+        let mut hdr = [0u8; 32];
+        let mut enc = CborEncoder::new(&mut hdr);
+        CoseSign1Builder::encode_sig_structure_header(&mut enc, payload.len())
+            .ok_or(CaliptraError::RUNTIME_CBOR_ENCODE_FAILED)?;
+        let hdr_len = enc.encoded_len();
+
+        let mut digest = Array4x12::default();
+        let mut op = sha384.digest_init()?;
+        op.update(hdr.get(..hdr_len).ok_or(CaliptraError::RUNTIME_INTERNAL)?)?;
+        op.update(payload)?;
+        op.finalize(&mut digest)?;
+        Ok(digest)
+    }
+
+    fn sign_with_rt_alias(
+        drivers: &mut Drivers,
+        digest: &Array4x12,
+    ) -> CaliptraResult<Ecc384Signature> {
+        let key_id_rt_priv_key = Drivers::get_key_id_rt_priv_key(drivers)?;
+        let pub_key = drivers.persistent_data.get().fht.rt_dice_pub_key;
+        drivers.ecc384.sign(
+            &Ecc384PrivKeyIn::Key(KeyReadArgs::new(key_id_rt_priv_key)),
+            &pub_key,
+            digest,
+            &mut drivers.trng,
+        )
+    }
+
+    /// Sign with the key derived for the caller's default DPE context, using
+    /// the same label as the GET_CERT_CHAIN leaf cert so that cert certifies
+    /// the signing key.
+    fn sign_with_dpe_key(
+        drivers: &mut Drivers,
+        digest: &Array4x12,
+    ) -> CaliptraResult<Ecc384Signature> {
+        let hashed_rt_pub_key = drivers.compute_rt_alias_sn()?;
+        let key_id_rt_cdi = Drivers::get_key_id_rt_cdi(drivers)?;
+        let key_id_rt_priv_key = Drivers::get_key_id_rt_priv_key(drivers)?;
+        let pdata = drivers.persistent_data.get();
+        let crypto = DpeCrypto::new(
+            &mut drivers.sha384,
+            &mut drivers.trng,
+            &mut drivers.ecc384,
+            &mut drivers.hmac384,
+            &mut drivers.key_vault,
+            pdata.fht.rt_dice_pub_key,
+            key_id_rt_cdi,
+            key_id_rt_priv_key,
+        );
+        let pl0_pauser = pdata.manifest1.header.pl0_pauser;
+        let mut env = DpeEnv::<CptraDpeTypes> {
+            crypto,
+            platform: DpePlatform::new(pl0_pauser, hashed_rt_pub_key, &mut drivers.cert_chain),
+        };
+
+        let locality = drivers.mbox.user();
+        let resp = SignCmd {
+            handle: ContextHandle::default(),
+            label: GetCertChainCmd::DPE_LEAF_LABEL,
+            flags: SignFlags::empty(),
+            digest: digest.into(),
+        }
+        .execute(
+            &mut drivers.persistent_data.get_mut().dpe,
+            &mut env,
+            locality,
+        );
+
+        match resp {
+            Ok(Response::Sign(resp)) => Ok(Ecc384Signature {
+                r: resp.sig_r_or_hmac.into(),
+                s: resp.sig_s.into(),
+            }),
+            _ => Err(CaliptraError::RUNTIME_EAT_DPE_SIGN_FAILED),
+        }
+    }
+}
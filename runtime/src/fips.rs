@@ -63,8 +63,13 @@ pub mod fips_self_test_cmd {
         verifier::FirmwareImageVerificationEnv, FMC_ORG, FMC_SIZE, RUNTIME_ORG, RUNTIME_SIZE,
     };
     use caliptra_drivers::{ResetReason, ShaAccLockState};
-    use caliptra_image_types::RomInfo;
+    use caliptra_image_types::{ImageTocEntry, RomInfo};
     use caliptra_image_verify::ImageVerifier;
+    #[cfg(feature = "lms-hss")]
+    use caliptra_image_verify::LmsHssPubKeys;
+    #[cfg(feature = "mldsa")]
+    use caliptra_image_verify::Mldsa87PubKeyDigests;
+    use core::mem::size_of;
     use zerocopy::AsBytes;
 
     // Helper function to create a slice from a memory region
@@ -80,14 +85,23 @@ pub mod fips_self_test_cmd {
 
     #[cfg_attr(not(feature = "no-cfi"), cfi_mod_fn)]
     fn copy_and_verify_image(env: &mut Drivers) -> CaliptraResult<()> {
+        // The components follow the Runtime in the image bundle and their
+        // TOC entries follow the manifest.
+        let components = env.persistent_data.get().fht.components();
+        let components_size: u32 = components
+            .iter()
+            .map(|c| size_of::<ImageTocEntry>() as u32 + c.size)
+            .sum();
+        let image_size = env.persistent_data.get().manifest1.size
+            + env.persistent_data.get().manifest1.fmc.size
+            + env.persistent_data.get().manifest1.runtime.size
+            + components_size;
+
         env.mbox.write_cmd(0)?;
-        env.mbox.set_dlen(
-            env.persistent_data.get().manifest1.size
-                + env.persistent_data.get().manifest1.fmc.size
-                + env.persistent_data.get().manifest1.runtime.size,
-        );
+        env.mbox.set_dlen(image_size);
         env.mbox
             .copy_bytes_to_mbox(env.persistent_data.get().manifest1.as_bytes())?;
+        env.mbox.copy_bytes_to_mbox(components.as_bytes())?;
 
         let fmc_size = env.persistent_data.get().manifest1.fmc.size;
         if fmc_size > FMC_SIZE {
@@ -103,6 +117,23 @@ pub mod fips_self_test_cmd {
         let rt = unsafe { create_slice(RUNTIME_ORG, runtime_size as usize) };
         env.mbox.copy_bytes_to_mbox(rt.as_bytes())?;
 
+        for component in components {
+            let content = unsafe { create_slice(component.load_addr, component.size as usize) };
+            env.mbox.copy_bytes_to_mbox(content)?;
+        }
+
+        #[cfg(any(feature = "mldsa", feature = "lms-hss"))]
+        let fht = &env.persistent_data.get().fht;
+        #[cfg(feature = "mldsa")]
+        let mldsa87_pub_key_digests = Mldsa87PubKeyDigests {
+            vendor: fht.vendor_mldsa_pub_key_digest,
+            owner: fht.owner_mldsa_pub_key_digest,
+        };
+        #[cfg(feature = "lms-hss")]
+        let lms_hss_pub_keys = LmsHssPubKeys {
+            vendor: zerocopy::transmute!(fht.vendor_lms_hss_pub_key),
+            owner: zerocopy::transmute!(fht.owner_lms_hss_pub_key),
+        };
         let mut venv = FirmwareImageVerificationEnv {
             sha256: &mut env.sha256,
             sha384: &mut env.sha384,
@@ -111,14 +142,21 @@ pub mod fips_self_test_cmd {
             data_vault: &mut env.data_vault,
             pcr_bank: &mut env.pcr_bank,
             image: env.mbox.raw_mailbox_contents(),
+            // The image is rebuilt from ICCM without the HSS and PQC
+            // extensions; HSS and ML-DSA signatures were checked by ROM, which
+            // recorded the keys they verified.
+            image_extensions: false,
+            #[cfg(feature = "mldsa")]
+            mldsa87_pub_key_digests: Some(mldsa87_pub_key_digests),
+            #[cfg(feature = "lms-hss")]
+            lms_hss_pub_keys: Some(lms_hss_pub_keys),
         };
 
         let mut verifier = ImageVerifier::new(&mut venv);
         let _info = verifier.verify(
             &env.persistent_data.get().manifest1,
-            env.persistent_data.get().manifest1.size
-                + env.persistent_data.get().manifest1.fmc.size
-                + env.persistent_data.get().manifest1.runtime.size,
+            components,
+            image_size,
             ResetReason::UpdateReset,
         )?;
         env.mbox.unlock();
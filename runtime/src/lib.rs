@@ -13,11 +13,13 @@ Abstract:
 --*/
 #![cfg_attr(not(feature = "fip-self-test"), allow(unused))]
 #![no_std]
+mod chunked;
 pub mod dice;
 mod disable;
 mod dpe_crypto;
 mod dpe_platform;
 mod drivers;
+mod eat;
 pub mod fips;
 pub mod handoff;
 mod hmac;
@@ -25,7 +27,10 @@ pub mod info;
 mod invoke_dpe;
 mod pcr;
 mod populate_idev;
+#[cfg(all(feature = "riscv", feature = "test_only_commands"))]
+mod stack_usage;
 mod stash_measurement;
+mod time;
 mod update;
 mod verify;
 
@@ -33,23 +38,27 @@ mod verify;
 pub mod mailbox;
 use caliptra_cfi_lib::{cfi_assert, cfi_assert_eq, cfi_assert_ne, cfi_launder, CfiCounter};
 pub use drivers::Drivers;
-use mailbox::Mailbox;
+use mailbox::{ChunkedTransfer, Mailbox};
 
 pub use crate::hmac::Hmac;
+pub use caliptra_common::crash_info::GetCrashInfoCmd;
 pub use caliptra_common::fips::FipsVersionCmd;
-pub use dice::{GetFmcAliasCertCmd, GetLdevCertCmd, IDevIdCertCmd};
+pub use chunked::{ChunkedBeginCmd, ChunkedContinueCmd, ChunkedEndCmd, ChunkedReadCmd};
+pub use dice::{GetCertChainCmd, GetFmcAliasCertCmd, GetIdevCsrCmd, GetLdevCertCmd, IDevIdCertCmd};
 pub use disable::DisableAttestationCmd;
 use dpe_crypto::DpeCrypto;
 pub use dpe_platform::{DpePlatform, VENDOR_ID, VENDOR_SKU};
+pub use eat::GetEatCmd;
 pub use fips::FipsShutdownCmd;
 #[cfg(feature = "fips_self_test")]
 pub use fips::{fips_self_test_cmd, fips_self_test_cmd::SelfTestStatus};
-pub use populate_idev::PopulateIDevIdCertCmd;
+pub use populate_idev::{PopulateIDevIdCertCmd, TEST_IDEVID_ISSUER_NAME};
 
 pub use info::{FwInfoCmd, IDevIdInfoCmd};
 pub use invoke_dpe::InvokeDpeCmd;
 pub use pcr::IncrementPcrResetCounterCmd;
 pub use stash_measurement::StashMeasurementCmd;
+pub use time::{GetTimeNonceCmd, SetTimeCmd};
 pub use verify::EcdsaVerifyCmd;
 pub mod packet;
 use caliptra_common::mailbox_api::{CommandId, MailboxResp};
@@ -128,18 +137,30 @@ fn enter_idle(drivers: &mut Drivers) {
     }
 
     #[cfg(feature = "riscv")]
-    if cfg!(feature = "fpga_realtime") {
-        // TODO implement in emulator
-        caliptra_cpu::csr::mpmc_halt();
+    {
+        // Clear the notification before checking for a command, so that a
+        // command arriving in between still wakes the core.
+        drivers.soc_ifc.clear_mbox_notif_status();
+        if !drivers.mbox.is_cmd_ready() {
+            caliptra_cpu::csr::mpmc_halt();
+        }
     }
 }
 
 /// Handles the pending mailbox command and writes the repsonse back to the mailbox
 ///
+/// # Arguments
+///
+/// * `drivers` - Drivers
+/// * `chunked` - Staging buffer of the current chunked transfer
+///
 /// # Returns
 ///
 /// * `MboxStatusE` - the mailbox status (DataReady when we send a response)
-fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
+fn handle_command(
+    drivers: &mut Drivers,
+    chunked: &mut ChunkedTransfer,
+) -> CaliptraResult<MboxStatusE> {
     // For firmware update, don't read data from the mailbox
     if drivers.mbox.cmd() == CommandId::FIRMWARE_LOAD {
         update::handle_impactless_update(drivers)?;
@@ -163,9 +184,41 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
 
     // Handle the request and generate the response
     let mut resp = match CommandId::from(req_packet.cmd) {
+        CommandId::CHUNKED_BEGIN => ChunkedBeginCmd::execute(chunked, cmd_bytes),
+        CommandId::CHUNKED_CONTINUE => ChunkedContinueCmd::execute(chunked, cmd_bytes),
+        CommandId::CHUNKED_END => ChunkedEndCmd::execute(drivers, chunked, cmd_bytes),
+        CommandId::CHUNKED_READ => ChunkedReadCmd::execute(chunked, cmd_bytes),
+        _ => execute_command(drivers, req_packet.cmd, cmd_bytes),
+    }?;
+
+    // Send the response
+    Packet::copy_to_mbox(drivers, &mut resp)?;
+
+    Ok(MboxStatusE::DataReady)
+}
+
+/// Executes the command `cmd` carried in `cmd_bytes`, either received
+/// directly in the mailbox or assembled from a chunked transfer
+///
+/// # Arguments
+///
+/// * `drivers` - Drivers
+/// * `cmd` - Command code
+/// * `cmd_bytes` - Request, including its header
+///
+/// # Returns
+///
+/// * `MailboxResp` - Response to the command
+pub(crate) fn execute_command(
+    drivers: &mut Drivers,
+    cmd: u32,
+    cmd_bytes: &[u8],
+) -> CaliptraResult<MailboxResp> {
+    match CommandId::from(cmd) {
         CommandId::FIRMWARE_LOAD => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
         CommandId::GET_IDEV_CERT => IDevIdCertCmd::execute(cmd_bytes),
         CommandId::GET_IDEV_INFO => IDevIdInfoCmd::execute(drivers),
+        CommandId::GET_IDEV_CSR => GetIdevCsrCmd::execute(drivers),
         CommandId::GET_LDEV_CERT => GetLdevCertCmd::execute(drivers),
         CommandId::INVOKE_DPE => InvokeDpeCmd::execute(drivers, cmd_bytes),
         CommandId::ECDSA384_VERIFY => EcdsaVerifyCmd::execute(drivers, cmd_bytes),
@@ -178,10 +231,17 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         CommandId::POPULATE_IDEV_CERT => PopulateIDevIdCertCmd::execute(drivers, cmd_bytes),
         CommandId::GET_FMC_ALIAS_CERT => GetFmcAliasCertCmd::execute(drivers),
         CommandId::GET_RT_ALIAS_CERT => GetRtAliasCertCmd::execute(drivers),
+        CommandId::GET_CERT_CHAIN => GetCertChainCmd::execute(drivers, cmd_bytes),
         CommandId::INCREMENT_PCR_RESET_COUNTER => {
             IncrementPcrResetCounterCmd::execute(drivers, cmd_bytes)
         }
         CommandId::QUOTE_PCRS => GetPcrQuoteCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_EAT => GetEatCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_TIME_NONCE => GetTimeNonceCmd::execute(drivers),
+        CommandId::SET_TIME => SetTimeCmd::execute(drivers, cmd_bytes),
+        CommandId::GET_CRASH_INFO => Ok(MailboxResp::GetCrashInfo(GetCrashInfoCmd::execute(
+            &drivers.persistent_data.get().crash_record,
+        ))),
         #[cfg(feature = "test_only_commands")]
         CommandId::TEST_ONLY_HMAC384_VERIFY => HmacVerifyCmd::execute(drivers, cmd_bytes),
         CommandId::VERSION => {
@@ -205,16 +265,10 @@ fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
         },
         CommandId::SHUTDOWN => FipsShutdownCmd::execute(drivers),
         _ => Err(CaliptraError::RUNTIME_UNIMPLEMENTED_COMMAND),
-    }?;
-
-    // Send the response
-    Packet::copy_to_mbox(drivers, &mut resp)?;
-
-    Ok(MboxStatusE::DataReady)
+    }
 }
 
 #[cfg(feature = "riscv")]
-// TODO implement in emulator
 fn setup_mailbox_wfi(drivers: &mut Drivers) {
     use caliptra_drivers::IntSource;
 
@@ -261,9 +315,11 @@ pub fn handle_mailbox_commands(drivers: &mut Drivers) -> CaliptraResult<()> {
         cfi_assert!(!cmd_busy);
     }
     #[cfg(feature = "riscv")]
-    if cfg!(feature = "fpga_realtime") {
-        setup_mailbox_wfi(drivers);
-    }
+    setup_mailbox_wfi(drivers);
+
+    // Requests and responses of chunked transfers are staged here, outside
+    // of `drivers`, so an assembled request can be executed in place
+    let mut chunked = ChunkedTransfer::default();
 
     loop {
         enter_idle(drivers);
@@ -283,7 +339,7 @@ pub fn handle_mailbox_commands(drivers: &mut Drivers) -> CaliptraResult<()> {
                 caliptra_common::WdtTimeout::default(),
             );
             caliptra_drivers::report_fw_error_non_fatal(0);
-            let commmand_result = handle_command(drivers);
+            let commmand_result = handle_command(drivers, &mut chunked);
             if cfi_launder(commmand_result.is_ok()) {
                 cfi_assert!(commmand_result.is_ok());
             } else {
@@ -8,12 +8,16 @@ File Name:
 
 Abstract:
 
-    File contains mailbox interface.
+    File contains mailbox interface and the staging buffer for chunked
+    transfers.
 
 --*/
 
-use core::slice;
+use core::{mem::size_of, slice};
 
+use arrayvec::ArrayVec;
+use caliptra_common::checksum::{calc_checksum, verify_checksum};
+use caliptra_common::mailbox_api::{ChunkedResp, MailboxRespHeader};
 use caliptra_drivers::{memory_layout, CaliptraResult};
 use caliptra_error::CaliptraError;
 use caliptra_registers::mbox::{
@@ -162,3 +166,184 @@ impl Mailbox {
         }
     }
 }
+
+/// Maximum size in bytes of a request or response carried by a chunked
+/// transfer
+pub const MAX_CHUNKED_PAYLOAD_SIZE: usize = 4096;
+
+#[derive(Default)]
+enum ChunkedState {
+    #[default]
+    Idle,
+    Receiving {
+        cmd: u32,
+        total_size: usize,
+        chksum: u32,
+    },
+    Responding,
+    Sending {
+        chksum: u32,
+    },
+}
+
+/// Staging buffer for requests and responses that do not fit in a single
+/// mailbox transaction.
+///
+/// The request is assembled from CHUNKED_BEGIN, CHUNKED_CONTINUE and
+/// CHUNKED_END. Once executed, the buffer holds the response until it is
+/// replaced by the next transfer. Commands with variable-length responses
+/// may write them straight into the buffer with `begin_response()`,
+/// `push_response()` and `end_response()`, so they are not capped by the
+/// size of their response struct.
+#[derive(Default)]
+pub struct ChunkedTransfer {
+    state: ChunkedState,
+    buf: ArrayVec<u8, MAX_CHUNKED_PAYLOAD_SIZE>,
+}
+
+impl ChunkedTransfer {
+    /// Start receiving a new request, discarding any previous transfer
+    ///
+    /// # Arguments
+    ///
+    /// * `cmd` - Command carried by the transfer
+    /// * `total_size` - Size in bytes of the complete request
+    /// * `chksum` - Checksum over the complete request
+    pub fn begin(&mut self, cmd: u32, total_size: u32, chksum: u32) -> CaliptraResult<()> {
+        self.reset();
+        let total_size = total_size as usize;
+        if total_size > MAX_CHUNKED_PAYLOAD_SIZE {
+            return Err(CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE);
+        }
+        self.state = ChunkedState::Receiving {
+            cmd,
+            total_size,
+            chksum,
+        };
+        Ok(())
+    }
+
+    /// Append a chunk of the request. Chunks must be sent in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Offset of the chunk within the complete request
+    /// * `data` - Chunk data
+    pub fn append(&mut self, offset: u32, data: &[u8]) -> CaliptraResult<()> {
+        let ChunkedState::Receiving { total_size, .. } = self.state else {
+            return Err(CaliptraError::RUNTIME_CHUNKED_NOT_STARTED);
+        };
+        if offset as usize != self.buf.len() {
+            return Err(CaliptraError::RUNTIME_CHUNKED_INVALID_OFFSET);
+        }
+        if self.buf.len() + data.len() > total_size {
+            return Err(CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE);
+        }
+        self.buf
+            .try_extend_from_slice(data)
+            .map_err(|_| CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE)
+    }
+
+    /// Finish receiving the request. The request is left in the staging
+    /// buffer until the response replaces it.
+    ///
+    /// # Returns
+    ///
+    /// * `(u32, &[u8])` - Command and request bytes
+    pub fn end_request(&mut self) -> CaliptraResult<(u32, &[u8])> {
+        let ChunkedState::Receiving {
+            cmd,
+            total_size,
+            chksum,
+        } = self.state
+        else {
+            return Err(CaliptraError::RUNTIME_CHUNKED_NOT_STARTED);
+        };
+        if self.buf.len() != total_size {
+            self.reset();
+            return Err(CaliptraError::RUNTIME_CHUNKED_INCOMPLETE);
+        }
+        self.state = ChunkedState::Idle;
+        if !verify_checksum(chksum, cmd, &self.buf) {
+            self.buf.clear();
+            return Err(CaliptraError::RUNTIME_CHUNKED_INVALID_CHECKSUM);
+        }
+        Ok((cmd, &self.buf))
+    }
+
+    /// Stage `resp` to be read back with `read_response()`
+    pub fn set_response(&mut self, resp: &[u8]) -> CaliptraResult<()> {
+        self.reset();
+        self.buf
+            .try_extend_from_slice(resp)
+            .map_err(|_| CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE)?;
+        self.state = ChunkedState::Sending {
+            chksum: calc_checksum(0, resp),
+        };
+        Ok(())
+    }
+
+    /// Start writing a response in pieces with `push_response()`,
+    /// discarding any previous transfer
+    pub fn begin_response(&mut self) {
+        self.reset();
+        self.state = ChunkedState::Responding;
+    }
+
+    /// Append `data` to the response started by `begin_response()`
+    pub fn push_response(&mut self, data: &[u8]) -> CaliptraResult<()> {
+        if !matches!(self.state, ChunkedState::Responding) {
+            return Err(CaliptraError::RUNTIME_CHUNKED_NOT_STARTED);
+        }
+        self.buf
+            .try_extend_from_slice(data)
+            .map_err(|_| CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE)
+    }
+
+    /// Populate the checksum of the response started by `begin_response()`,
+    /// which must start with a `MailboxRespHeader`, and stage it to be read
+    /// back with `read_response()`
+    pub fn end_response(&mut self) -> CaliptraResult<()> {
+        if !matches!(self.state, ChunkedState::Responding) {
+            return Err(CaliptraError::RUNTIME_CHUNKED_NOT_STARTED);
+        }
+        if self.buf.len() < size_of::<MailboxRespHeader>() {
+            self.reset();
+            return Err(CaliptraError::RUNTIME_MAILBOX_API_RESPONSE_DATA_LEN_TOO_LARGE);
+        }
+        let (chksum, payload) = self.buf.split_at_mut(size_of::<u32>());
+        chksum.copy_from_slice(&calc_checksum(0, payload).to_le_bytes());
+        self.state = ChunkedState::Sending {
+            chksum: calc_checksum(0, &self.buf),
+        };
+        Ok(())
+    }
+
+    /// Read the chunk of the staged response starting at `offset`
+    pub fn read_response(&self, offset: u32) -> CaliptraResult<ChunkedResp> {
+        let ChunkedState::Sending { chksum } = self.state else {
+            return Err(CaliptraError::RUNTIME_CHUNKED_NOT_STARTED);
+        };
+        let data = self
+            .buf
+            .get(offset as usize..)
+            .ok_or(CaliptraError::RUNTIME_CHUNKED_INVALID_OFFSET)?;
+        let data = &data[..data.len().min(ChunkedResp::DATA_MAX_SIZE)];
+
+        let mut resp = ChunkedResp {
+            total_size: self.buf.len() as u32,
+            payload_chksum: chksum,
+            offset,
+            data_size: data.len() as u32,
+            ..Default::default()
+        };
+        resp.data[..data.len()].copy_from_slice(data);
+        Ok(resp)
+    }
+
+    /// Discard any transfer in progress
+    pub fn reset(&mut self) {
+        self.state = ChunkedState::Idle;
+        self.buf.clear();
+    }
+}
@@ -15,10 +15,25 @@ Abstract:
 use arrayvec::ArrayVec;
 use caliptra_common::mailbox_api::{MailboxResp, PopulateIdevCertReq};
 use caliptra_error::{CaliptraError, CaliptraResult};
+use caliptra_x509::X509Cert;
 use zerocopy::AsBytes;
 
 use crate::{Drivers, MAX_CERT_CHAIN_SIZE, PL0_PAUSER_FLAG};
 
+/// Issuer name "CN=Caliptra Test IDevID CA" expected by runtime builds with
+/// the `test_idevid_issuer` feature, used to test the issuer check without a
+/// build time X509 template configuration.
+pub const TEST_IDEVID_ISSUER_NAME: &[u8] = &[
+    0x30, 0x22, 0x31, 0x20, 0x30, 0x1e, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x17, 0x43, 0x61, 0x6c,
+    0x69, 0x70, 0x74, 0x72, 0x61, 0x20, 0x54, 0x65, 0x73, 0x74, 0x20, 0x49, 0x44, 0x65, 0x76, 0x49,
+    0x44, 0x20, 0x43, 0x41,
+];
+
+#[cfg(not(feature = "test_idevid_issuer"))]
+const EXPECTED_IDEVID_ISSUER_NAME: &[u8] = caliptra_x509::IDEVID_ISSUER_NAME;
+#[cfg(feature = "test_idevid_issuer")]
+const EXPECTED_IDEVID_ISSUER_NAME: &[u8] = TEST_IDEVID_ISSUER_NAME;
+
 pub struct PopulateIDevIdCertCmd;
 impl PopulateIDevIdCertCmd {
     pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
@@ -37,6 +52,8 @@ impl PopulateIDevIdCertCmd {
                 return Err(CaliptraError::RUNTIME_INCORRECT_PAUSER_PRIVILEGE_LEVEL);
             }
 
+            Self::validate_cert(drivers, &cmd.cert[..cert_size])?;
+
             let mut tmp_chain = ArrayVec::<u8, MAX_CERT_CHAIN_SIZE>::new();
             tmp_chain
                 .try_extend_from_slice(&cmd.cert[..cert_size])
@@ -51,4 +68,35 @@ impl PopulateIDevIdCertCmd {
             Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY)
         }
     }
+
+    /// Check that the certificate certifies this device's IDevID key with
+    /// ECDSA P-384 / SHA-384, and was issued by the configured CA (if any)
+    ///
+    /// # Arguments
+    ///
+    /// * `drivers` - Drivers
+    /// * `cert`    - DER encoded IDevID certificate
+    fn validate_cert(drivers: &Drivers, cert: &[u8]) -> CaliptraResult<()> {
+        let cert = X509Cert::parse(cert).ok_or(CaliptraError::RUNTIME_IDEV_CERT_PARSE_FAILED)?;
+
+        if !cert.is_ecdsa_p384_sha384() {
+            return Err(CaliptraError::RUNTIME_IDEV_CERT_UNSUPPORTED_ALGORITHM);
+        }
+        let (x, y) = cert
+            .ecc_p384_pub_key()
+            .ok_or(CaliptraError::RUNTIME_IDEV_CERT_UNSUPPORTED_ALGORITHM)?;
+
+        let idev_pub_key = drivers.persistent_data.get().fht.idev_dice_pub_key;
+        let idev_x: [u8; 48] = idev_pub_key.x.into();
+        let idev_y: [u8; 48] = idev_pub_key.y.into();
+        if *x != idev_x || *y != idev_y {
+            return Err(CaliptraError::RUNTIME_IDEV_CERT_PUB_KEY_MISMATCH);
+        }
+
+        if !EXPECTED_IDEVID_ISSUER_NAME.is_empty() && cert.issuer != EXPECTED_IDEVID_ISSUER_NAME {
+            return Err(CaliptraError::RUNTIME_IDEV_CERT_ISSUER_MISMATCH);
+        }
+
+        Ok(())
+    }
 }
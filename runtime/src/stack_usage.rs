@@ -0,0 +1,52 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    stack_usage.rs
+
+Abstract:
+
+    File contains test-only helpers measuring how much of the runtime stack
+    a command uses.
+
+--*/
+
+use caliptra_drivers::memory_layout::{STACK_ORG, STACK_SIZE};
+
+/// Pattern written to the unused part of the stack
+const STACK_PAINT: u32 = 0x5354_4b50;
+
+/// Space left unpainted below the caller's stack pointer, which covers the
+/// frame of this function
+const PAINT_GUARD_SIZE: u32 = 256;
+
+/// Fill the unused part of the stack with `STACK_PAINT`
+///
+/// Traps switch to the exception stack, so nothing else uses the memory
+/// below the current stack pointer while it is painted.
+#[inline(never)]
+pub fn paint() {
+    let sp: u32;
+    unsafe { core::arch::asm!("mv {}, sp", out(reg) sp) };
+
+    let end = sp.saturating_sub(PAINT_GUARD_SIZE);
+    let mut addr = STACK_ORG;
+    while addr < end {
+        unsafe { core::ptr::write_volatile(addr as *mut u32, STACK_PAINT) };
+        addr += 4;
+    }
+}
+
+/// Maximum number of bytes of the stack used since the last call to
+/// `paint()`
+pub fn high_water_mark() -> u32 {
+    let mut addr = STACK_ORG;
+    while addr < STACK_ORG + STACK_SIZE
+        && unsafe { core::ptr::read_volatile(addr as *const u32) } == STACK_PAINT
+    {
+        addr += 4;
+    }
+    STACK_ORG + STACK_SIZE - addr
+}
@@ -0,0 +1,144 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    time.rs
+
+Abstract:
+
+    File contains the GetTimeNonce and SetTime mailbox commands and the
+    validity window check of the firmware certificates.
+
+--*/
+
+use crate::Drivers;
+use caliptra_cfi_derive::cfi_impl_fn;
+use caliptra_common::mailbox_api::{
+    GetTimeNonceResp, MailboxResp, MailboxRespHeader, SetTimeReq, SetTimeResp,
+};
+use caliptra_drivers::{
+    Array4x12, CaliptraError, CaliptraResult, Ecc384PubKey, Ecc384Result, Ecc384Scalar,
+    Ecc384Signature,
+};
+use caliptra_image_types::ImageManifest;
+use caliptra_x509::{NotAfter, NotBefore};
+use zerocopy::FromBytes;
+
+/// ASN.1 GeneralizedTime, "YYYYMMDDHHMMSSZ"
+pub type GeneralizedTime = [u8; 15];
+
+pub struct GetTimeNonceCmd;
+impl GetTimeNonceCmd {
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub(crate) fn execute(drivers: &mut Drivers) -> CaliptraResult<MailboxResp> {
+        let mut resp = GetTimeNonceResp::default();
+        let rand = <[u8; 48]>::from(drivers.trng.generate()?);
+        resp.nonce.copy_from_slice(&rand[..resp.nonce.len()]);
+
+        // Replaces any outstanding nonce, so only the latest token is usable
+        drivers.time_nonce = Some(resp.nonce);
+
+        Ok(MailboxResp::GetTimeNonce(resp))
+    }
+}
+
+pub struct SetTimeCmd;
+impl SetTimeCmd {
+    #[cfg_attr(not(feature = "no-cfi"), cfi_impl_fn)]
+    pub(crate) fn execute(drivers: &mut Drivers, cmd_args: &[u8]) -> CaliptraResult<MailboxResp> {
+        let Some(cmd) = SetTimeReq::read_from(cmd_args) else {
+            return Err(CaliptraError::RUNTIME_INSUFFICIENT_MEMORY);
+        };
+
+        // Each nonce authorizes a single attempt, so a token cannot be
+        // replayed after it is used, after a reset or on another device.
+        let Some(nonce) = drivers.time_nonce.take() else {
+            return Err(CaliptraError::RUNTIME_SET_TIME_NO_NONCE);
+        };
+
+        if !Self::is_valid_time(&cmd.time) {
+            return Err(CaliptraError::RUNTIME_SET_TIME_INVALID_TIME);
+        }
+
+        // The token is signed by the owner key of the running image
+        let manifest = &drivers.persistent_data.get().manifest1;
+        let owner_key = &manifest.preamble.owner_pub_keys.ecc_pub_key;
+        let pub_key = Ecc384PubKey {
+            x: Array4x12::from(owner_key.x),
+            y: Array4x12::from(owner_key.y),
+        };
+        let sig = Ecc384Signature {
+            r: Ecc384Scalar::from(cmd.signature_r),
+            s: Ecc384Scalar::from(cmd.signature_s),
+        };
+        let mut digest = Array4x12::default();
+        let mut op = drivers.sha384.digest_init()?;
+        op.update(&nonce)?;
+        op.update(&cmd.time)?;
+        op.finalize(&mut digest)?;
+        if drivers.ecc384.verify(&pub_key, &digest, &sig)? != Ecc384Result::Success {
+            return Err(CaliptraError::RUNTIME_SET_TIME_SIGNATURE_INVALID);
+        }
+
+        // Older tokens must not be replayed to move the clock backwards
+        if let Some(time) = drivers.trusted_time {
+            if cmd.time < time {
+                return Err(CaliptraError::RUNTIME_SET_TIME_ROLLBACK);
+            }
+        }
+        drivers.trusted_time = Some(cmd.time);
+
+        let (not_before, not_after) = cert_validity_window(manifest);
+        let cert_validity = if cmd.time < not_before {
+            caliptra_drivers::report_fw_error_non_fatal(
+                CaliptraError::RUNTIME_CERT_NOT_YET_VALID.into(),
+            );
+            SetTimeResp::CERT_NOT_YET_VALID
+        } else if cmd.time > not_after {
+            caliptra_drivers::report_fw_error_non_fatal(CaliptraError::RUNTIME_CERT_EXPIRED.into());
+            SetTimeResp::CERT_EXPIRED
+        } else {
+            SetTimeResp::CERT_VALID
+        };
+
+        Ok(MailboxResp::SetTime(SetTimeResp {
+            hdr: MailboxRespHeader::default(),
+            cert_validity,
+        }))
+    }
+
+    /// Check that `time` is 14 decimal digits followed by 'Z'
+    fn is_valid_time(time: &GeneralizedTime) -> bool {
+        let (digits, zone) = time.split_at(time.len() - 1);
+        zone == b"Z" && digits.iter().all(u8::is_ascii_digit)
+    }
+}
+
+/// Validity window of the FMC and RT alias certificates of the current image.
+/// Mirrors the selection made by ROM and FMC: owner dates take preference
+/// over vendor dates, which take preference over the defaults.
+///
+/// # Arguments
+///
+/// * `manifest` - Image manifest
+///
+/// # Returns
+///
+/// * `(GeneralizedTime, GeneralizedTime)` - Not before and not after times
+pub fn cert_validity_window(manifest: &ImageManifest) -> (GeneralizedTime, GeneralizedTime) {
+    let null_time = [0u8; 15];
+    let vendor_data = &manifest.header.vendor_data;
+    let owner_data = &manifest.header.owner_data;
+
+    if owner_data.owner_not_before != null_time && owner_data.owner_not_after != null_time {
+        (owner_data.owner_not_before, owner_data.owner_not_after)
+    } else if vendor_data.vendor_not_before != null_time
+        && vendor_data.vendor_not_after != null_time
+    {
+        (vendor_data.vendor_not_before, vendor_data.vendor_not_after)
+    } else {
+        (NotBefore::default().value, NotAfter::default().value)
+    }
+}
@@ -31,6 +31,8 @@ const OPCODE_READ_DPE_INSTANCE: u32 = 0xA000_0000;
 const OPCODE_CORRUPT_DPE_INSTANCE: u32 = 0xB000_0000;
 const OPCODE_READ_PCR_RESET_COUNTER: u32 = 0xC000_0000;
 const OPCODE_CORRUPT_DPE_ROOT_TCI: u32 = 0xD000_0000;
+const OPCODE_FATAL_ERROR: u32 = 0xE000_0000;
+const OPCODE_READ_CRASH_RECORD: u32 = 0xF000_0000;
 const OPCODE_FW_LOAD: u32 = CommandId::FIRMWARE_LOAD.0;
 
 fn read_request(mbox: &Mailbox) -> &[u8] {
@@ -210,6 +212,17 @@ pub fn handle_command(drivers: &mut Drivers) -> CaliptraResult<MboxStatusE> {
                     .tci_current = TciMeasurement(input_bytes.try_into().unwrap());
                 write_response(&mut drivers.mbox, &[]);
             }
+            CommandId(OPCODE_FATAL_ERROR) => {
+                let input_bytes = read_request(&drivers.mbox);
+
+                handle_fatal_error(u32::read_from(input_bytes).unwrap());
+            }
+            CommandId(OPCODE_READ_CRASH_RECORD) => {
+                write_response(
+                    &mut drivers.mbox,
+                    drivers.persistent_data.get().crash_record.as_bytes(),
+                );
+            }
             CommandId(OPCODE_FW_LOAD) => {
                 unsafe { SocIfcReg::new() }
                     .regs_mut()
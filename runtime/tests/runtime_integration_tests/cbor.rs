@@ -0,0 +1,118 @@
+// Licensed under the Apache-2.0 license
+
+// Minimal CBOR decoder for checking tokens produced by the runtime. Only
+// supports the definite-length items the runtime emits.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CborValue {
+    Int(i128),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<CborValue>),
+    Map(Vec<(CborValue, CborValue)>),
+    Tag(u64, Box<CborValue>),
+}
+
+impl CborValue {
+    pub fn as_int(&self) -> i128 {
+        match self {
+            CborValue::Int(i) => *i,
+            _ => panic!("Expected int, found {self:?}"),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            CborValue::Bytes(b) => b,
+            _ => panic!("Expected byte string, found {self:?}"),
+        }
+    }
+
+    pub fn as_array(&self) -> &[CborValue] {
+        match self {
+            CborValue::Array(a) => a,
+            _ => panic!("Expected array, found {self:?}"),
+        }
+    }
+
+    /// Look up the value for integer `key` in a map
+    pub fn get(&self, key: i64) -> &CborValue {
+        let CborValue::Map(entries) = self else {
+            panic!("Expected map, found {self:?}");
+        };
+        entries
+            .iter()
+            .find(|(k, _)| *k == CborValue::Int(key.into()))
+            .map(|(_, v)| v)
+            .unwrap_or_else(|| panic!("Key {key} not found"))
+    }
+}
+
+/// Decode a single CBOR item that must span all of `data`
+pub fn decode(data: &[u8]) -> CborValue {
+    let (value, rest) = decode_item(data);
+    assert!(rest.is_empty(), "{} trailing bytes", rest.len());
+    value
+}
+
+fn decode_item(data: &[u8]) -> (CborValue, &[u8]) {
+    let (major, arg, mut rest) = decode_head(data);
+    let value = match major {
+        0 => CborValue::Int(arg.into()),
+        1 => CborValue::Int(-1 - i128::from(arg)),
+        2 => {
+            let (bytes, r) = rest.split_at(arg as usize);
+            rest = r;
+            CborValue::Bytes(bytes.to_vec())
+        }
+        3 => {
+            let (bytes, r) = rest.split_at(arg as usize);
+            rest = r;
+            CborValue::Text(String::from_utf8(bytes.to_vec()).unwrap())
+        }
+        4 => {
+            let mut items = vec![];
+            for _ in 0..arg {
+                let (item, r) = decode_item(rest);
+                rest = r;
+                items.push(item);
+            }
+            CborValue::Array(items)
+        }
+        5 => {
+            let mut entries = vec![];
+            for _ in 0..arg {
+                let (key, r) = decode_item(rest);
+                let (value, r) = decode_item(r);
+                rest = r;
+                entries.push((key, value));
+            }
+            CborValue::Map(entries)
+        }
+        6 => {
+            let (item, r) = decode_item(rest);
+            rest = r;
+            CborValue::Tag(arg, Box::new(item))
+        }
+        _ => panic!("Unsupported major type {major}"),
+    };
+    (value, rest)
+}
+
+fn decode_head(data: &[u8]) -> (u8, u64, &[u8]) {
+    let major = data[0] >> 5;
+    let info = data[0] & 0x1f;
+    let rest = &data[1..];
+    let arg_len = match info {
+        0..=23 => return (major, info.into(), rest),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => panic!("Unsupported additional info {info}"),
+    };
+    let arg = rest[..arg_len]
+        .iter()
+        .fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+    (major, arg, &rest[arg_len..])
+}
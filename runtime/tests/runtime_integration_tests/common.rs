@@ -5,8 +5,8 @@ use caliptra_builder::{
     FwId, ImageOptions,
 };
 use caliptra_common::mailbox_api::{
-    CommandId, GetFmcAliasCertResp, GetRtAliasCertResp, InvokeDpeReq, InvokeDpeResp, MailboxReq,
-    MailboxReqHeader,
+    CommandId, GetFmcAliasCertResp, GetIdevInfoResp, GetRtAliasCertResp, InvokeDpeReq,
+    InvokeDpeResp, MailboxReq, MailboxReqHeader,
 };
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams, ModelError};
@@ -20,10 +20,12 @@ use dpe::{
 use openssl::{
     asn1::{Asn1Integer, Asn1Time},
     bn::BigNum,
+    ec::{EcGroup, EcKey},
     hash::MessageDigest,
-    pkey::{PKey, Private},
+    nid::Nid,
+    pkey::{HasPublic, PKey, PKeyRef, Private, Public},
     x509::{X509Builder, X509},
-    x509::{X509Name, X509NameBuilder},
+    x509::{X509Name, X509NameBuilder, X509NameRef},
 };
 use zerocopy::{AsBytes, FromBytes};
 
@@ -83,6 +85,26 @@ pub fn run_rt_test(
 }
 
 pub fn generate_test_x509_cert(ec_key: PKey<Private>) -> X509 {
+    generate_test_x509_cert_for(&ec_key, &ec_key, MessageDigest::sha384())
+}
+
+// Generate a certificate for `subject_key`, signed by `issuer_key`
+pub fn generate_test_x509_cert_for<T: HasPublic>(
+    subject_key: &PKeyRef<T>,
+    issuer_key: &PKeyRef<Private>,
+    digest: MessageDigest,
+) -> X509 {
+    generate_test_x509_cert_issued_by(subject_key, issuer_key, None, digest)
+}
+
+// Generate a certificate for `subject_key`, signed by `issuer_key`. If
+// `issuer_name` is None, the certificate's issuer is its own subject name.
+pub fn generate_test_x509_cert_issued_by<T: HasPublic>(
+    subject_key: &PKeyRef<T>,
+    issuer_key: &PKeyRef<Private>,
+    issuer_name: Option<&X509NameRef>,
+    digest: MessageDigest,
+) -> X509 {
     let mut cert_builder = X509Builder::new().unwrap();
     cert_builder.set_version(2).unwrap();
     cert_builder
@@ -94,18 +116,46 @@ pub fn generate_test_x509_cert(ec_key: PKey<Private>) -> X509 {
         .unwrap();
     let subject_name = X509NameBuilder::build(subj_name_builder);
     cert_builder.set_subject_name(&subject_name).unwrap();
-    cert_builder.set_issuer_name(&subject_name).unwrap();
-    cert_builder.set_pubkey(&ec_key).unwrap();
+    cert_builder
+        .set_issuer_name(issuer_name.unwrap_or(&subject_name))
+        .unwrap();
+    cert_builder.set_pubkey(subject_key).unwrap();
     cert_builder
         .set_not_before(&Asn1Time::days_from_now(0).unwrap())
         .unwrap();
     cert_builder
         .set_not_after(&Asn1Time::days_from_now(365).unwrap())
         .unwrap();
-    cert_builder.sign(&ec_key, MessageDigest::sha384()).unwrap();
+    cert_builder.sign(issuer_key, digest).unwrap();
     cert_builder.build()
 }
 
+// Retrieve the IDevID public key of the device
+pub fn get_idev_pub_key(model: &mut DefaultHwModel) -> PKey<Public> {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_IDEV_INFO), &[]),
+    };
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_IDEV_INFO), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    let idev_resp = GetIdevInfoResp::read_from(resp.as_slice()).unwrap();
+
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let x = BigNum::from_slice(&idev_resp.idev_pub_x).unwrap();
+    let y = BigNum::from_slice(&idev_resp.idev_pub_y).unwrap();
+    let ec_key = EcKey::from_public_key_affine_coordinates(&group, &x, &y).unwrap();
+    PKey::from_ec_key(ec_key).unwrap()
+}
+
+// Generate an IDevID certificate for the device, issued by a random CA key
+pub fn generate_test_idev_cert(model: &mut DefaultHwModel) -> X509 {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let ca_key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+    let idev_key = get_idev_pub_key(model);
+    generate_test_x509_cert_for(&idev_key, &ca_key, MessageDigest::sha384())
+}
+
 fn get_cmd_id(dpe_cmd: &mut Command) -> u32 {
     match dpe_cmd {
         Command::GetProfile => Command::GET_PROFILE,
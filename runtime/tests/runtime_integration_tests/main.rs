@@ -1,11 +1,16 @@
 // Licensed under the Apache-2.0 license
 
+mod cbor;
 mod common;
 mod test_boot;
 mod test_certs;
+mod test_chunked;
+mod test_crash_info;
 mod test_disable;
+mod test_eat;
 mod test_ecdsa;
 mod test_fips;
+mod test_get_cert_chain;
 mod test_hmac;
 mod test_info;
 mod test_invoke_dpe;
@@ -14,6 +19,7 @@ mod test_panic_missing;
 mod test_pauser_privilege_levels;
 mod test_pcr;
 mod test_populate_idev;
+mod test_set_time;
 mod test_stash_measurement;
 mod test_tagging;
 mod test_update_reset;
@@ -1,16 +1,21 @@
 // Licensed under the Apache-2.0 license
 
+use crate::cbor::{self, CborValue};
 use crate::common::{
     execute_dpe_cmd, generate_test_x509_cert, get_fmc_alias_cert, get_rt_alias_cert, run_rt_test,
     DpeResult, TEST_LABEL,
 };
-use caliptra_builder::ImageOptions;
+use caliptra_builder::{
+    firmware::{self, APP_WITH_UART, FMC_WITH_UART},
+    ImageOptions,
+};
 use caliptra_common::mailbox_api::{
     CommandId, GetIdevCertReq, GetIdevCertResp, GetIdevInfoResp, GetLdevCertResp,
     GetRtAliasCertResp, MailboxReq, MailboxReqHeader,
 };
+use caliptra_drivers::IdevidCertAttr;
 use caliptra_error::CaliptraError;
-use caliptra_hw_model::{DefaultHwModel, HwModel};
+use caliptra_hw_model::{BootParams, DefaultHwModel, Fuses, HwModel, InitParams};
 use dpe::{
     commands::{CertifyKeyCmd, CertifyKeyFlags, Command},
     context::ContextHandle,
@@ -22,7 +27,8 @@ use openssl::{
     ec::{EcGroup, EcKey},
     ecdsa::EcdsaSig,
     nid::Nid,
-    pkey::PKey,
+    pkey::{PKey, Public},
+    sha::sha384,
     stack::Stack,
     x509::{
         store::X509StoreBuilder, verify::X509VerifyFlags, X509StoreContext, X509VerifyResult, X509,
@@ -309,3 +315,116 @@ fn test_full_cert_chain() {
         })
         .unwrap();
 }
+
+/// Verify an untagged COSE_Sign1 certificate with `key`, returning its claims
+fn verify_cbor_cert(cert: &[u8], key: &EcKey<Public>) -> CborValue {
+    let cose_sign1 = cbor::decode(cert);
+    let [protected, unprotected, payload, signature] = cose_sign1.as_array() else {
+        panic!("COSE_Sign1 must have 4 elements");
+    };
+
+    // alg: ES384
+    assert_eq!(cbor::decode(protected.as_bytes()).get(1).as_int(), -35);
+    assert_eq!(*unprotected, CborValue::Map(vec![]));
+
+    // Sig_structure = ["Signature1", protected, external_aad, payload]
+    let payload = payload.as_bytes();
+    let mut sig_structure = vec![0x84, 0x6a];
+    sig_structure.extend_from_slice(b"Signature1");
+    sig_structure.push(0x40 | protected.as_bytes().len() as u8);
+    sig_structure.extend_from_slice(protected.as_bytes());
+    sig_structure.push(0x40);
+    sig_structure.push(0x59);
+    sig_structure.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    sig_structure.extend_from_slice(payload);
+
+    let signature = signature.as_bytes();
+    assert_eq!(signature.len(), 96);
+    let sig = EcdsaSig::from_private_components(
+        BigNum::from_slice(&signature[..48]).unwrap(),
+        BigNum::from_slice(&signature[48..]).unwrap(),
+    )
+    .unwrap();
+    assert!(sig.verify(&sha384(&sig_structure), key).unwrap());
+
+    cbor::decode(payload)
+}
+
+/// Subject public key of CBOR certificate claims
+fn cbor_cert_pub_key(claims: &CborValue) -> EcKey<Public> {
+    let cose_key = cbor::decode(claims.get(-4670552).as_bytes());
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    EcKey::from_public_key_affine_coordinates(
+        &group,
+        &BigNum::from_slice(cose_key.get(-2).as_bytes()).unwrap(),
+        &BigNum::from_slice(cose_key.get(-3).as_bytes()).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_cbor_cert_chain() {
+    let mut fuses = Fuses::default();
+    fuses.idevid_cert_attr[IdevidCertAttr::Flags as usize] |= 0x4;
+
+    // CBOR certificates are only issued by ROMs built with the cbor-certs feature
+    let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART_EXTENDED).unwrap();
+    let mut opts = ImageOptions::default();
+    opts.vendor_config.pl0_pauser = Some(0x1);
+    let image =
+        caliptra_builder::build_and_sign_image(&FMC_WITH_UART, &APP_WITH_UART, opts).unwrap();
+    let mut model = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        fuses,
+        fw_image: Some(&image.to_bytes().unwrap()),
+        ..Default::default()
+    })
+    .unwrap();
+    model.step_until(|m| m.soc_ifc().cptra_flow_status().read().ready_for_fw());
+
+    // Get IDev public key
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_IDEV_INFO), &[]),
+    };
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_IDEV_INFO), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    let idev_resp = GetIdevInfoResp::read_from(resp.as_slice()).unwrap();
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let idev_key = EcKey::from_public_key_affine_coordinates(
+        &group,
+        &BigNum::from_slice(&idev_resp.idev_pub_x).unwrap(),
+        &BigNum::from_slice(&idev_resp.idev_pub_y).unwrap(),
+    )
+    .unwrap();
+
+    // Each certificate is signed by the subject key of the previous one
+    let ldev_resp = get_ldev_cert(&mut model);
+    let ldev_claims = verify_cbor_cert(&ldev_resp.data[..ldev_resp.data_size as usize], &idev_key);
+
+    let fmc_resp = get_fmc_alias_cert(&mut model);
+    let fmc_claims = verify_cbor_cert(
+        &fmc_resp.data[..fmc_resp.data_size as usize],
+        &cbor_cert_pub_key(&ldev_claims),
+    );
+    assert_eq!(fmc_claims.get(1), ldev_claims.get(2));
+    assert_eq!(fmc_claims.get(-4670545).as_bytes().len(), 48);
+
+    let rt_resp = get_rt_alias_cert(&mut model);
+    let rt_claims = verify_cbor_cert(
+        &rt_resp.data[..rt_resp.data_size as usize],
+        &cbor_cert_pub_key(&fmc_claims),
+    );
+    assert_eq!(rt_claims.get(1), fmc_claims.get(2));
+
+    // The configuration descriptor names the certified component
+    let config_desc = cbor::decode(rt_claims.get(-4670548).as_bytes());
+    assert_eq!(
+        *config_desc.get(-70002),
+        CborValue::Text("caliptra-rt".into())
+    );
+}
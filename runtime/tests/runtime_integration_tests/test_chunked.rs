@@ -0,0 +1,251 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::{assert_error, generate_test_idev_cert, run_rt_test};
+use caliptra_api::cert_chain::CertChain;
+use caliptra_common::checksum::calc_checksum;
+use caliptra_common::mailbox_api::{
+    ChunkedBeginReq, ChunkedDataReq, ChunkedReadReq, ChunkedResp, CommandId, GetCertChainReq,
+    GetCertChainResp, MailboxReq, MailboxReqHeader, PopulateIdevCertReq,
+};
+use caliptra_drivers::memory_layout::STACK_SIZE;
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use zerocopy::AsBytes;
+
+/// Stack that must be left unused while a CHUNKED_END request executes
+const MIN_STACK_HEADROOM: u32 = 1024;
+
+/// Request header and body for FW_INFO, with a valid checksum
+fn fw_info_req() -> Vec<u8> {
+    let hdr = MailboxReqHeader {
+        chksum: calc_checksum(CommandId::FW_INFO.into(), &[]),
+    };
+    hdr.as_bytes().to_vec()
+}
+
+fn execute(model: &mut DefaultHwModel, mut req: MailboxReq) -> Result<Option<Vec<u8>>, ModelError> {
+    req.populate_chksum().unwrap();
+    model.mailbox_execute(req.cmd_code().into(), req.as_bytes().unwrap())
+}
+
+/// Stack usage printed by the runtime for the last CHUNKED_END request
+fn chunked_end_stack_usage(model: &mut DefaultHwModel) -> u32 {
+    let output = model.output().take(usize::MAX);
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix("[rt] CHUNKED_END stack usage="))
+        .last()
+        .expect("CHUNKED_END stack usage not reported")
+        .trim()
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn test_chunked_matches_direct_command() {
+    let mut model = run_rt_test(None, None, None);
+
+    let req = fw_info_req();
+    let direct = model
+        .mailbox_execute(CommandId::FW_INFO.into(), &req)
+        .unwrap()
+        .unwrap();
+    let chunked = model
+        .mailbox_execute_chunked(CommandId::FW_INFO.into(), &req)
+        .unwrap();
+    assert_eq!(direct, chunked);
+}
+
+#[test]
+fn test_chunked_multi_chunk_response() {
+    let mut model = run_rt_test(None, None, None);
+
+    // The direct GET_CERT_CHAIN response is capped at its struct size, so
+    // the chain takes several requests. Carried by a chunked transfer, the
+    // whole chain is streamed back in one response spanning several chunks.
+    let mut direct_calls = 0;
+    let direct = CertChain::fetch::<ModelError>(0, |cmd, req| {
+        direct_calls += 1;
+        model.mailbox_execute(cmd, req)
+    })
+    .unwrap();
+    let mut chunked_calls = 0;
+    let mut chunked_size = 0;
+    let chunked = CertChain::fetch::<ModelError>(0, |cmd, req| {
+        chunked_calls += 1;
+        let resp = model.mailbox_execute_chunked(cmd, req)?;
+        chunked_size = resp.len();
+        Ok(Some(resp))
+    })
+    .unwrap();
+    assert_eq!(direct, chunked);
+    assert!(direct_calls > 1);
+    assert_eq!(chunked_calls, 1);
+    assert!(chunked_size > GetCertChainResp::DATA_MAX_SIZE);
+    assert!(chunked_size > ChunkedResp::DATA_MAX_SIZE);
+}
+
+#[test]
+fn test_chunked_multi_chunk_request() {
+    let mut model = run_rt_test(None, None, None);
+
+    let cert_bytes = generate_test_idev_cert(&mut model).to_der().unwrap();
+
+    // Send the full-size request so it spans two chunks
+    let mut req = PopulateIdevCertReq {
+        cert_size: cert_bytes.len() as u32,
+        ..Default::default()
+    };
+    req.cert[..cert_bytes.len()].copy_from_slice(&cert_bytes);
+    req.hdr.chksum = calc_checksum(
+        CommandId::POPULATE_IDEV_CERT.into(),
+        &req.as_bytes()[core::mem::size_of::<MailboxReqHeader>()..],
+    );
+    assert!(req.as_bytes().len() > ChunkedBeginReq::DATA_MAX_SIZE);
+
+    model
+        .mailbox_execute_chunked(CommandId::POPULATE_IDEV_CERT.into(), req.as_bytes())
+        .unwrap();
+
+    let chain =
+        CertChain::fetch::<ModelError>(0, |cmd, req| model.mailbox_execute(cmd, req)).unwrap();
+    assert_eq!(&chain.der[..cert_bytes.len()], cert_bytes.as_slice());
+}
+
+#[test]
+fn test_chunked_read_without_transfer() {
+    let mut model = run_rt_test(None, None, None);
+
+    let resp = model
+        .mailbox_execute_req(ChunkedReadReq::default())
+        .unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_CHUNKED_NOT_STARTED, resp);
+}
+
+#[test]
+fn test_chunked_out_of_order() {
+    let mut model = run_rt_test(None, None, None);
+
+    let req = fw_info_req();
+    let begin = ChunkedBeginReq {
+        cmd: CommandId::FW_INFO.into(),
+        total_size: req.len() as u32,
+        payload_chksum: calc_checksum(CommandId::FW_INFO.into(), &req),
+        ..Default::default()
+    };
+    execute(&mut model, MailboxReq::ChunkedBegin(begin)).unwrap();
+
+    // Skip the first bytes of the request
+    let mut data = ChunkedDataReq {
+        offset: 1,
+        data_size: 1,
+        ..Default::default()
+    };
+    data.data[0] = req[1];
+    let resp = execute(&mut model, MailboxReq::ChunkedContinue(data)).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_CHUNKED_INVALID_OFFSET,
+        resp,
+    );
+
+    // Ending before the whole request was sent discards the transfer
+    let resp = execute(
+        &mut model,
+        MailboxReq::ChunkedEnd(ChunkedDataReq::default()),
+    )
+    .unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_CHUNKED_INCOMPLETE, resp);
+
+    let resp = execute(
+        &mut model,
+        MailboxReq::ChunkedEnd(ChunkedDataReq::default()),
+    )
+    .unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_CHUNKED_NOT_STARTED, resp);
+}
+
+#[test]
+fn test_chunked_bad_payload_checksum() {
+    let mut model = run_rt_test(None, None, None);
+
+    let req = fw_info_req();
+    let mut begin = ChunkedBeginReq {
+        cmd: CommandId::FW_INFO.into(),
+        total_size: req.len() as u32,
+        payload_chksum: calc_checksum(CommandId::FW_INFO.into(), &req) ^ 1,
+        data_size: req.len() as u32,
+        ..Default::default()
+    };
+    begin.data[..req.len()].copy_from_slice(&req);
+    execute(&mut model, MailboxReq::ChunkedBegin(begin)).unwrap();
+
+    let resp = execute(
+        &mut model,
+        MailboxReq::ChunkedEnd(ChunkedDataReq {
+            offset: req.len() as u32,
+            ..Default::default()
+        }),
+    )
+    .unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_CHUNKED_INVALID_CHECKSUM,
+        resp,
+    );
+}
+
+#[test]
+fn test_chunked_payload_too_large() {
+    let mut model = run_rt_test(None, None, None);
+
+    let resp = execute(
+        &mut model,
+        MailboxReq::ChunkedBegin(ChunkedBeginReq {
+            cmd: CommandId::FW_INFO.into(),
+            total_size: 0x10000,
+            ..Default::default()
+        }),
+    )
+    .unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_CHUNKED_PAYLOAD_TOO_LARGE,
+        resp,
+    );
+}
+
+#[test]
+fn test_chunked_end_stack_usage() {
+    let mut model = run_rt_test(None, None, None);
+
+    let cert_bytes = generate_test_idev_cert(&mut model).to_der().unwrap();
+    let mut req = PopulateIdevCertReq {
+        cert_size: cert_bytes.len() as u32,
+        ..Default::default()
+    };
+    req.cert[..cert_bytes.len()].copy_from_slice(&cert_bytes);
+    req.hdr.chksum = calc_checksum(
+        CommandId::POPULATE_IDEV_CERT.into(),
+        &req.as_bytes()[core::mem::size_of::<MailboxReqHeader>()..],
+    );
+    model
+        .mailbox_execute_chunked(CommandId::POPULATE_IDEV_CERT.into(), req.as_bytes())
+        .unwrap();
+    let populate_usage = chunked_end_stack_usage(&mut model);
+
+    // Certifying the DPE leaf and streaming the whole chain into the staging
+    // buffer is the deepest path through CHUNKED_END
+    CertChain::fetch::<ModelError>(GetCertChainReq::FLAG_INCLUDE_DPE_LEAF, |cmd, req| {
+        model.mailbox_execute_chunked(cmd, req).map(Some)
+    })
+    .unwrap();
+    let cert_chain_usage = chunked_end_stack_usage(&mut model);
+
+    for usage in [populate_usage, cert_chain_usage] {
+        assert!(
+            usage + MIN_STACK_HEADROOM <= STACK_SIZE,
+            "CHUNKED_END used {usage} of {STACK_SIZE} stack bytes"
+        );
+    }
+}
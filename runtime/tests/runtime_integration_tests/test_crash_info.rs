@@ -0,0 +1,23 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::run_rt_test;
+use caliptra_common::mailbox_api::{GetCrashInfoReq, GetCrashInfoResp};
+use caliptra_hw_model::HwModel;
+
+#[test]
+fn test_crash_info_empty_after_cold_reset() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| m.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+
+    let resp = model
+        .mailbox_execute_req(GetCrashInfoReq::default())
+        .unwrap();
+    assert_eq!(
+        resp,
+        GetCrashInfoResp {
+            hdr: resp.hdr,
+            ..Default::default()
+        }
+    );
+}
@@ -0,0 +1,189 @@
+// Licensed under the Apache-2.0 license
+
+use crate::cbor::{self, CborValue};
+use crate::common::{assert_error, get_rt_alias_cert, run_rt_test};
+use caliptra_api::cert_chain::CertChain;
+use caliptra_common::mailbox_api::{
+    CommandId, EatClaim, FwInfoResp, GetCertChainReq, GetEatReq, GetEatResp, MailboxReq,
+    MailboxReqHeader, QuotePcrsReq, QuotePcrsResp,
+};
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use openssl::{bn::BigNum, ec::EcKeyRef, ecdsa::EcdsaSig, pkey::Public, sha::sha384, x509::X509};
+use zerocopy::{AsBytes, FromBytes};
+
+const NONCE: [u8; 32] = [0xc3; 32];
+
+fn get_eat(model: &mut DefaultHwModel, flags: u32) -> Vec<u8> {
+    let mut cmd = MailboxReq::GetEat(GetEatReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        nonce: NONCE,
+        flags,
+    });
+    cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_EAT), cmd.as_bytes().unwrap())
+        .unwrap()
+        .unwrap();
+    assert!(resp.len() <= std::mem::size_of::<GetEatResp>());
+    let mut eat_resp = GetEatResp::default();
+    eat_resp.as_bytes_mut()[..resp.len()].copy_from_slice(&resp);
+    assert!(caliptra_common::checksum::verify_checksum(
+        eat_resp.hdr.chksum,
+        0x0,
+        &resp[core::mem::size_of_val(&eat_resp.hdr.chksum)..],
+    ));
+    eat_resp.data[..eat_resp.data_size as usize].to_vec()
+}
+
+/// Check the COSE_Sign1 envelope and signature, returning the decoded claims
+fn verify_cose_sign1(token: &[u8], key: &EcKeyRef<Public>) -> CborValue {
+    let CborValue::Tag(18, cose_sign1) = cbor::decode(token) else {
+        panic!("Token is not a tagged COSE_Sign1");
+    };
+    let [protected, unprotected, payload, signature] = cose_sign1.as_array() else {
+        panic!("COSE_Sign1 must have 4 elements");
+    };
+
+    // alg: ES384
+    let protected_hdr = cbor::decode(protected.as_bytes());
+    assert_eq!(protected_hdr.get(1).as_int(), -35);
+    assert_eq!(*unprotected, CborValue::Map(vec![]));
+
+    // Sig_structure = ["Signature1", protected, external_aad, payload]
+    let mut sig_structure = vec![0x84, 0x6a];
+    sig_structure.extend_from_slice(b"Signature1");
+    sig_structure.extend_from_slice(&[0x40 | protected.as_bytes().len() as u8]);
+    sig_structure.extend_from_slice(protected.as_bytes());
+    sig_structure.push(0x40);
+    let payload = payload.as_bytes();
+    sig_structure.push(0x59);
+    sig_structure.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    sig_structure.extend_from_slice(payload);
+
+    let signature = signature.as_bytes();
+    assert_eq!(signature.len(), 96);
+    let sig = EcdsaSig::from_private_components(
+        BigNum::from_slice(&signature[..48]).unwrap(),
+        BigNum::from_slice(&signature[48..]).unwrap(),
+    )
+    .unwrap();
+    assert!(sig.verify(&sha384(&sig_structure), key).unwrap());
+
+    cbor::decode(payload)
+}
+
+fn get_fw_info(model: &mut DefaultHwModel) -> FwInfoResp {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::FW_INFO), &[]),
+    };
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::FW_INFO), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    FwInfoResp::read_from(resp.as_slice()).unwrap()
+}
+
+fn digest_bytes(digest: &[u32; 12]) -> Vec<u8> {
+    digest.iter().flat_map(|w| w.to_be_bytes()).collect()
+}
+
+#[test]
+fn test_eat_claims() {
+    let mut model = run_rt_test(None, None, None);
+
+    let token = get_eat(&mut model, 0);
+
+    let rt_resp = get_rt_alias_cert(&mut model);
+    let rt_cert = X509::from_der(rt_resp.data().unwrap()).unwrap();
+    let claims = verify_cose_sign1(&token, &rt_cert.public_key().unwrap().ec_key().unwrap());
+
+    assert_eq!(claims.get(EatClaim::NONCE).as_bytes(), NONCE);
+    assert_eq!(claims.get(EatClaim::UEID).as_bytes().len(), 17);
+
+    // PCRs and reset counters match QUOTE_PCRS
+    let mut cmd = MailboxReq::QuotePcrs(QuotePcrsReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        nonce: NONCE,
+    });
+    cmd.populate_chksum().unwrap();
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::QUOTE_PCRS), cmd.as_bytes().unwrap())
+        .unwrap()
+        .unwrap();
+    let quote = QuotePcrsResp::read_from(resp.as_slice()).unwrap();
+
+    let pcrs = claims.get(EatClaim::PCRS).as_array();
+    assert_eq!(pcrs.len(), quote.pcrs.len());
+    for (claim, pcr) in pcrs.iter().zip(quote.pcrs.iter()) {
+        assert_eq!(claim.as_bytes(), &pcr[..]);
+    }
+    let reset_ctrs = claims.get(EatClaim::PCR_RESET_COUNTERS).as_array();
+    for (claim, ctr) in reset_ctrs.iter().zip(quote.reset_ctrs.iter()) {
+        assert_eq!(claim.as_int(), i128::from(*ctr));
+    }
+
+    // SVNs and FWIDs match FW_INFO
+    let info = get_fw_info(&mut model);
+    assert_eq!(
+        claims.get(EatClaim::FMC_SVN).as_int(),
+        i128::from(info.fmc_manifest_svn)
+    );
+    assert_eq!(
+        claims.get(EatClaim::RT_SVN).as_int(),
+        i128::from(info.runtime_svn)
+    );
+    assert_eq!(
+        claims.get(EatClaim::RT_MIN_SVN).as_int(),
+        i128::from(info.min_runtime_svn)
+    );
+    assert_eq!(
+        claims.get(EatClaim::FMC_FWID).as_bytes(),
+        digest_bytes(&info.fmc_sha384_digest)
+    );
+    assert_eq!(
+        claims.get(EatClaim::RT_FWID).as_bytes(),
+        digest_bytes(&info.runtime_sha384_digest)
+    );
+}
+
+#[test]
+fn test_eat_signed_with_dpe_key() {
+    let mut model = run_rt_test(None, None, None);
+
+    let token = get_eat(&mut model, GetEatReq::FLAG_SIGN_WITH_DPE_KEY);
+
+    // The DPE leaf in the cert chain certifies the signing key
+    let chain =
+        CertChain::fetch::<ModelError>(GetCertChainReq::FLAG_INCLUDE_DPE_LEAF, |cmd, req| {
+            model.mailbox_execute(cmd, req)
+        })
+        .unwrap();
+    let certs = chain.validate::<()>().unwrap();
+    let leaf_key = certs.last().unwrap().public_key().unwrap();
+
+    let claims = verify_cose_sign1(&token, &leaf_key.ec_key().unwrap());
+    assert_eq!(claims.get(EatClaim::NONCE).as_bytes(), NONCE);
+}
+
+#[test]
+fn test_eat_invalid_flags() {
+    let mut model = run_rt_test(None, None, None);
+
+    let mut cmd = MailboxReq::GetEat(GetEatReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        nonce: NONCE,
+        flags: 0x2,
+    });
+    cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_EAT), cmd.as_bytes().unwrap())
+        .unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS,
+        resp,
+    );
+}
@@ -0,0 +1,164 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::{
+    assert_error, execute_dpe_cmd, get_fmc_alias_cert, get_rt_alias_cert, run_rt_test, DpeResult,
+};
+use caliptra_api::cert_chain::CertChain;
+use caliptra_builder::{
+    firmware::{self, APP_WITH_UART, FMC_WITH_UART},
+    ImageOptions,
+};
+use caliptra_common::mailbox_api::{CommandId, GetCertChainReq, MailboxReq, MailboxReqHeader};
+use caliptra_drivers::IdevidCertAttr;
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{BootParams, DefaultHwModel, Fuses, HwModel, InitParams, ModelError};
+use dpe::{
+    commands::{CertifyKeyCmd, CertifyKeyFlags, Command},
+    context::ContextHandle,
+    response::Response,
+};
+use openssl::x509::X509;
+
+fn fetch_cert_chain(model: &mut DefaultHwModel, flags: u32) -> CertChain {
+    CertChain::fetch::<ModelError>(flags, |cmd, req| model.mailbox_execute(cmd, req)).unwrap()
+}
+
+#[test]
+fn test_get_cert_chain() {
+    let mut model = run_rt_test(None, None, None);
+
+    let chain = fetch_cert_chain(&mut model, 0);
+    let certs = chain.validate::<()>().unwrap();
+
+    // Expect ldevid, fmc alias and rt alias
+    assert_eq!(certs.len(), 3);
+
+    let fmc_resp = get_fmc_alias_cert(&mut model);
+    let rt_resp = get_rt_alias_cert(&mut model);
+    assert_eq!(
+        certs[1].to_der().unwrap(),
+        &fmc_resp.data[..fmc_resp.data_size as usize]
+    );
+    assert_eq!(certs[2].to_der().unwrap(), rt_resp.data().unwrap());
+}
+
+#[test]
+fn test_get_cert_chain_cbor() {
+    let mut fuses = Fuses::default();
+    fuses.idevid_cert_attr[IdevidCertAttr::Flags as usize] |= 0x4;
+
+    // CBOR certificates are only issued by ROMs built with the cbor-certs feature
+    let rom = caliptra_builder::build_firmware_rom(&firmware::ROM_WITH_UART_EXTENDED).unwrap();
+    let mut opts = ImageOptions::default();
+    opts.vendor_config.pl0_pauser = Some(0x1);
+    let image =
+        caliptra_builder::build_and_sign_image(&FMC_WITH_UART, &APP_WITH_UART, opts).unwrap();
+    let mut model = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        fuses,
+        fw_image: Some(&image.to_bytes().unwrap()),
+        ..Default::default()
+    })
+    .unwrap();
+    model.step_until(|m| m.soc_ifc().cptra_flow_status().read().ready_for_fw());
+
+    let chain = fetch_cert_chain(&mut model, 0);
+    let certs = chain.validate_cbor::<()>().unwrap();
+
+    // Expect ldevid, fmc alias and rt alias
+    assert_eq!(certs.len(), 3);
+
+    let entries: Vec<&[u8]> = chain.certs().collect();
+    let fmc_resp = get_fmc_alias_cert(&mut model);
+    let rt_resp = get_rt_alias_cert(&mut model);
+    assert_eq!(entries[1], &fmc_resp.data[..fmc_resp.data_size as usize]);
+    assert_eq!(entries[2], rt_resp.data().unwrap());
+}
+
+#[test]
+fn test_get_cert_chain_with_dpe_leaf() {
+    let mut model = run_rt_test(None, None, None);
+
+    let chain = fetch_cert_chain(&mut model, GetCertChainReq::FLAG_INCLUDE_DPE_LEAF);
+    let certs = chain.validate::<()>().unwrap();
+    assert_eq!(certs.len(), 4);
+
+    // The leaf matches what DPE returns when certifying the default context
+    // with the same label.
+    let certify_key_cmd = CertifyKeyCmd {
+        handle: ContextHandle::default(),
+        label: [0u8; 48],
+        flags: CertifyKeyFlags::empty(),
+        format: CertifyKeyCmd::FORMAT_X509,
+    };
+    let resp = execute_dpe_cmd(
+        &mut model,
+        &mut Command::CertifyKey(certify_key_cmd),
+        DpeResult::Success,
+    );
+    let Some(Response::CertifyKey(certify_key_resp)) = resp else {
+        panic!("Wrong response type!");
+    };
+    let dpe_leaf_cert =
+        X509::from_der(&certify_key_resp.cert[..certify_key_resp.cert_size as usize]).unwrap();
+    assert_eq!(certs[3], dpe_leaf_cert);
+
+    // Fetching again yields the same chain
+    assert_eq!(
+        fetch_cert_chain(&mut model, GetCertChainReq::FLAG_INCLUDE_DPE_LEAF),
+        chain
+    );
+}
+
+#[test]
+fn test_get_cert_chain_invalid_offset() {
+    let mut model = run_rt_test(None, None, None);
+
+    let chain = fetch_cert_chain(&mut model, 0);
+
+    let mut cmd = MailboxReq::GetCertChain(GetCertChainReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        offset: chain.der.len() as u32 + 1,
+        flags: 0,
+    });
+    cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(
+            u32::from(CommandId::GET_CERT_CHAIN),
+            cmd.as_bytes().unwrap(),
+        )
+        .unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_GET_CERT_CHAIN_INVALID_OFFSET,
+        resp,
+    );
+}
+
+#[test]
+fn test_get_cert_chain_invalid_flags() {
+    let mut model = run_rt_test(None, None, None);
+
+    let mut cmd = MailboxReq::GetCertChain(GetCertChainReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        offset: 0,
+        flags: 0x8000_0000,
+    });
+    cmd.populate_chksum().unwrap();
+
+    let resp = model
+        .mailbox_execute(
+            u32::from(CommandId::GET_CERT_CHAIN),
+            cmd.as_bytes().unwrap(),
+        )
+        .unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_MAILBOX_INVALID_PARAMS,
+        resp,
+    );
+}
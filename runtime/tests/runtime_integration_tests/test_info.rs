@@ -6,9 +6,10 @@ use caliptra_builder::{
     ImageOptions,
 };
 use caliptra_common::mailbox_api::{
-    CommandId, FwInfoResp, GetIdevInfoResp, MailboxReqHeader, MailboxRespHeader,
+    CommandId, FwInfoResp, GetIdevCsrReq, GetIdevInfoResp, MailboxReqHeader, MailboxRespHeader,
 };
-use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams};
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams, ModelError};
 use caliptra_image_types::RomInfo;
 use core::mem::size_of;
 use zerocopy::{AsBytes, FromBytes};
@@ -163,3 +164,18 @@ fn test_idev_id_info() {
         .unwrap();
     GetIdevInfoResp::read_from(resp.as_slice()).unwrap();
 }
+
+#[test]
+fn test_idev_csr_unavailable() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| m.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+
+    // No CSR is stored unless requested via the manufacturing flags
+    assert_eq!(
+        model.mailbox_execute_req(GetIdevCsrReq::default()),
+        Err(ModelError::MailboxCmdFailed(
+            CaliptraError::RUNTIME_IDEV_CSR_UNAVAILABLE.into()
+        ))
+    );
+}
@@ -69,3 +69,38 @@ fn test_unimplemented_cmds() {
         resp,
     );
 }
+
+/// The runtime halts the CPU while waiting for commands, and a mailbox
+/// command wakes it.
+#[test]
+fn test_idle_halts_until_command() {
+    let mut model = run_rt_test(None, None, None);
+
+    model.step_until(|m| m.soc_mbox().status().read().mbox_fsm_ps().mbox_idle());
+
+    // Not every model can measure halted time
+    let Some(halted_before) = model.halted_cycles() else {
+        return;
+    };
+    for _ in 0..1000 {
+        model.step();
+    }
+    let halted_idle = model.halted_cycles().unwrap();
+    assert!(halted_idle > halted_before);
+
+    for _ in 0..2 {
+        let payload = MailboxReqHeader {
+            chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::VERSION), &[]),
+        };
+        model
+            .mailbox_execute(u32::from(CommandId::VERSION), payload.as_bytes())
+            .unwrap()
+            .unwrap();
+    }
+
+    // The runtime halts again once the commands complete
+    for _ in 0..1000 {
+        model.step();
+    }
+    assert!(model.halted_cycles().unwrap() > halted_idle);
+}
@@ -1,19 +1,25 @@
 // Licensed under the Apache-2.0 license
 
-use crate::common::{execute_dpe_cmd, generate_test_x509_cert, run_rt_test, DpeResult};
+use crate::common::{
+    assert_error, execute_dpe_cmd, generate_test_idev_cert, generate_test_x509_cert,
+    generate_test_x509_cert_for, generate_test_x509_cert_issued_by, get_idev_pub_key, run_rt_test,
+    DpeResult,
+};
+use caliptra_builder::firmware::APP_WITH_UART_IDEVID_ISSUER;
 use caliptra_common::mailbox_api::{CommandId, MailboxReq, MailboxReqHeader, PopulateIdevCertReq};
 use caliptra_error::CaliptraError;
-use caliptra_hw_model::{DefaultHwModel, HwModel};
-use caliptra_runtime::RtBootStatus;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use caliptra_runtime::{RtBootStatus, TEST_IDEVID_ISSUER_NAME};
 use dpe::{
     commands::{Command, GetCertificateChainCmd},
     response::Response,
 };
 use openssl::{
     ec::{EcGroup, EcKey},
+    hash::MessageDigest,
     nid::Nid,
     pkey::PKey,
-    x509::X509,
+    x509::{X509Name, X509},
 };
 
 fn get_full_cert_chain(model: &mut DefaultHwModel, out: &mut [u8; 4096]) -> usize {
@@ -53,6 +59,27 @@ fn get_full_cert_chain(model: &mut DefaultHwModel, out: &mut [u8; 4096]) -> usiz
     cert_chunk_1.certificate_size as usize + cert_chunk_2.certificate_size as usize
 }
 
+fn populate_idev_cert(model: &mut DefaultHwModel, cert_bytes: &[u8]) -> Result<(), ModelError> {
+    let mut cert_slice = [0u8; PopulateIdevCertReq::MAX_CERT_SIZE];
+    cert_slice[..cert_bytes.len()].copy_from_slice(cert_bytes);
+
+    let mut pop_idev_cmd = MailboxReq::PopulateIdevCert(PopulateIdevCertReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        cert_size: cert_bytes.len() as u32,
+        cert: cert_slice,
+    });
+    pop_idev_cmd.populate_chksum().unwrap();
+
+    model
+        .mailbox_execute(
+            u32::from(CommandId::POPULATE_IDEV_CERT),
+            pop_idev_cmd.as_bytes().unwrap(),
+        )
+        .map(|resp| {
+            resp.expect("We should have received a response");
+        })
+}
+
 // Will panic if any of the cert chain chunks is not a valid X.509 cert
 fn parse_cert_chain(cert_chain: &[u8], cert_chain_size: usize, expected_num_certs: u32) {
     let mut i = 0;
@@ -77,32 +104,11 @@ fn test_populate_idev_cert_cmd() {
     let cert_chain_len_without_idev_cert =
         get_full_cert_chain(&mut model, &mut cert_chain_without_idev_cert);
 
-    // generate test idev cert
-    let ec_group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
-    let ec_key = PKey::from_ec_key(EcKey::generate(&ec_group).unwrap()).unwrap();
-
-    let cert = generate_test_x509_cert(ec_key);
-
-    // copy der encoded idev cert
-    let cert_bytes = cert.to_der().unwrap();
-    let mut cert_slice = [0u8; PopulateIdevCertReq::MAX_CERT_SIZE];
-    cert_slice[..cert_bytes.len()].copy_from_slice(&cert_bytes);
-
-    let mut pop_idev_cmd = MailboxReq::PopulateIdevCert(PopulateIdevCertReq {
-        hdr: MailboxReqHeader { chksum: 0 },
-        cert_size: cert_bytes.len() as u32,
-        cert: cert_slice,
-    });
-    pop_idev_cmd.populate_chksum().unwrap();
+    // generate test idev cert for the device's IDevID key
+    let cert = generate_test_idev_cert(&mut model);
 
     // call populate idev cert so that the idev cert is added to the certificate chain
-    model
-        .mailbox_execute(
-            u32::from(CommandId::POPULATE_IDEV_CERT),
-            pop_idev_cmd.as_bytes().unwrap(),
-        )
-        .unwrap()
-        .expect("We should have received a response");
+    populate_idev_cert(&mut model, &cert.to_der().unwrap()).unwrap();
 
     let mut cert_chain_with_idev_cert = [0u8; 4096];
     let cert_chain_len_with_idev_cert =
@@ -141,3 +147,79 @@ fn test_populate_idev_cert_size_too_big() {
         Err(CaliptraError::RUNTIME_MAILBOX_API_REQUEST_DATA_LEN_TOO_LARGE)
     );
 }
+
+#[test]
+fn test_populate_idev_cert_wrong_key() {
+    let mut model = run_rt_test(None, None, None);
+
+    // A certificate for a key other than the IDevID key is rejected
+    let ec_group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let ec_key = PKey::from_ec_key(EcKey::generate(&ec_group).unwrap()).unwrap();
+    let cert = generate_test_x509_cert(ec_key);
+
+    let resp = populate_idev_cert(&mut model, &cert.to_der().unwrap()).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_IDEV_CERT_PUB_KEY_MISMATCH,
+        resp,
+    );
+}
+
+#[test]
+fn test_populate_idev_cert_wrong_algorithm() {
+    let mut model = run_rt_test(None, None, None);
+
+    // A certificate signed with SHA-256 is rejected
+    let ec_group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let ca_key = PKey::from_ec_key(EcKey::generate(&ec_group).unwrap()).unwrap();
+    let idev_key = get_idev_pub_key(&mut model);
+    let cert = generate_test_x509_cert_for(&idev_key, &ca_key, MessageDigest::sha256());
+
+    let resp = populate_idev_cert(&mut model, &cert.to_der().unwrap()).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_IDEV_CERT_UNSUPPORTED_ALGORITHM,
+        resp,
+    );
+}
+
+#[test]
+fn test_populate_idev_cert_malformed() {
+    let mut model = run_rt_test(None, None, None);
+
+    let cert = generate_test_idev_cert(&mut model).to_der().unwrap();
+    let resp = populate_idev_cert(&mut model, &cert[..cert.len() - 1]).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_IDEV_CERT_PARSE_FAILED,
+        resp,
+    );
+}
+
+#[test]
+fn test_populate_idev_cert_issuer_mismatch() {
+    let mut model = run_rt_test(Some(&APP_WITH_UART_IDEVID_ISSUER), None, None);
+
+    let ec_group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let ca_key = PKey::from_ec_key(EcKey::generate(&ec_group).unwrap()).unwrap();
+    let idev_key = get_idev_pub_key(&mut model);
+
+    // A certificate from a CA other than the configured IDevID CA is rejected
+    let cert = generate_test_x509_cert_for(&idev_key, &ca_key, MessageDigest::sha384());
+    let resp = populate_idev_cert(&mut model, &cert.to_der().unwrap()).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_IDEV_CERT_ISSUER_MISMATCH,
+        resp,
+    );
+
+    // A certificate from the configured IDevID CA is accepted
+    let issuer_name = X509Name::from_der(TEST_IDEVID_ISSUER_NAME).unwrap();
+    let cert = generate_test_x509_cert_issued_by(
+        &idev_key,
+        &ca_key,
+        Some(&issuer_name),
+        MessageDigest::sha384(),
+    );
+    populate_idev_cert(&mut model, &cert.to_der().unwrap()).unwrap();
+}
@@ -0,0 +1,227 @@
+// Licensed under the Apache-2.0 license
+
+use crate::common::{assert_error, run_rt_test};
+use caliptra_builder::{
+    firmware::{APP_WITH_UART, FMC_WITH_UART},
+    ImageOptions,
+};
+use caliptra_common::mailbox_api::{
+    CommandId, GetTimeNonceResp, MailboxReq, MailboxReqHeader, SetTimeReq, SetTimeResp,
+};
+use caliptra_error::CaliptraError;
+use caliptra_hw_model::{DefaultHwModel, HwModel, ModelError};
+use caliptra_image_fake_keys::{OWNER_ECC_KEY_PRIVATE, OWNER_ECC_KEY_PUBLIC};
+use openssl::{
+    bn::{BigNum, BigNumContext},
+    ec::{EcGroup, EcKey, EcPoint},
+    ecdsa::EcdsaSig,
+    nid::Nid,
+    sha::sha384,
+};
+use zerocopy::{AsBytes, FromBytes};
+
+fn to_bytes(words: &[u32; 12]) -> Vec<u8> {
+    words.iter().flat_map(|w| w.to_be_bytes()).collect()
+}
+
+fn get_time_nonce(model: &mut DefaultHwModel) -> [u8; 32] {
+    let payload = MailboxReqHeader {
+        chksum: caliptra_common::checksum::calc_checksum(u32::from(CommandId::GET_TIME_NONCE), &[]),
+    };
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::GET_TIME_NONCE), payload.as_bytes())
+        .unwrap()
+        .unwrap();
+    GetTimeNonceResp::read_from(resp.as_slice()).unwrap().nonce
+}
+
+/// Timestamp token bound to `nonce`, signed with the test owner key
+fn set_time_req(nonce: &[u8; 32], time: &[u8; 15]) -> MailboxReq {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1).unwrap();
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut pub_key = EcPoint::new(&group).unwrap();
+    pub_key
+        .set_affine_coordinates_gfp(
+            &group,
+            &BigNum::from_slice(&to_bytes(&OWNER_ECC_KEY_PUBLIC.x)).unwrap(),
+            &BigNum::from_slice(&to_bytes(&OWNER_ECC_KEY_PUBLIC.y)).unwrap(),
+            &mut ctx,
+        )
+        .unwrap();
+    let key = EcKey::from_private_components(
+        &group,
+        &BigNum::from_slice(&to_bytes(&OWNER_ECC_KEY_PRIVATE)).unwrap(),
+        &pub_key,
+    )
+    .unwrap();
+    let sig = EcdsaSig::sign(&sha384(&[&nonce[..], &time[..]].concat()), &key).unwrap();
+
+    let mut cmd = MailboxReq::SetTime(SetTimeReq {
+        hdr: MailboxReqHeader { chksum: 0 },
+        time: *time,
+        signature_r: sig.r().to_vec_padded(48).unwrap().try_into().unwrap(),
+        signature_s: sig.s().to_vec_padded(48).unwrap().try_into().unwrap(),
+        ..Default::default()
+    });
+    cmd.populate_chksum().unwrap();
+    cmd
+}
+
+fn set_time(model: &mut DefaultHwModel, cmd: &MailboxReq) -> Result<SetTimeResp, ModelError> {
+    let resp = model
+        .mailbox_execute(u32::from(CommandId::SET_TIME), cmd.as_bytes().unwrap())?
+        .unwrap();
+    Ok(SetTimeResp::read_from(resp.as_slice()).unwrap())
+}
+
+fn run_with_owner_window(not_before: &[u8; 15], not_after: &[u8; 15]) -> DefaultHwModel {
+    let mut opts = ImageOptions::default();
+    opts.vendor_config.pl0_pauser = Some(0x1);
+    let mut owner_config = opts.owner_config.unwrap();
+    owner_config.not_before = *not_before;
+    owner_config.not_after = *not_after;
+    opts.owner_config = Some(owner_config);
+    run_rt_test(None, Some(opts), None)
+}
+
+#[test]
+fn test_set_time_within_window() {
+    let mut model = run_with_owner_window(b"20250101000000Z", b"20300101000000Z");
+
+    let nonce = get_time_nonce(&mut model);
+    let resp = set_time(&mut model, &set_time_req(&nonce, b"20270615120000Z")).unwrap();
+    assert_eq!(resp.cert_validity, SetTimeResp::CERT_VALID);
+    assert_eq!(model.soc_ifc().cptra_fw_error_non_fatal().read(), 0);
+}
+
+#[test]
+fn test_set_time_outside_window() {
+    let mut model = run_with_owner_window(b"20250101000000Z", b"20300101000000Z");
+
+    let nonce = get_time_nonce(&mut model);
+    let resp = set_time(&mut model, &set_time_req(&nonce, b"20240101000000Z")).unwrap();
+    assert_eq!(resp.cert_validity, SetTimeResp::CERT_NOT_YET_VALID);
+    assert_eq!(
+        model.soc_ifc().cptra_fw_error_non_fatal().read(),
+        u32::from(CaliptraError::RUNTIME_CERT_NOT_YET_VALID)
+    );
+
+    let nonce = get_time_nonce(&mut model);
+    let resp = set_time(&mut model, &set_time_req(&nonce, b"20310101000000Z")).unwrap();
+    assert_eq!(resp.cert_validity, SetTimeResp::CERT_EXPIRED);
+    assert_eq!(
+        model.soc_ifc().cptra_fw_error_non_fatal().read(),
+        u32::from(CaliptraError::RUNTIME_CERT_EXPIRED)
+    );
+}
+
+#[test]
+fn test_set_time_rollback() {
+    let mut model = run_rt_test(None, None, None);
+
+    let nonce = get_time_nonce(&mut model);
+    set_time(&mut model, &set_time_req(&nonce, b"20270101000000Z")).unwrap();
+
+    // An older time is rejected, even with a fresh nonce
+    let nonce = get_time_nonce(&mut model);
+    let err = set_time(&mut model, &set_time_req(&nonce, b"20260101000000Z")).unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_SET_TIME_ROLLBACK, err);
+}
+
+#[test]
+fn test_set_time_invalid_token() {
+    let mut model = run_rt_test(None, None, None);
+
+    // Signature does not cover the time
+    let nonce = get_time_nonce(&mut model);
+    let MailboxReq::SetTime(mut req) = set_time_req(&nonce, b"20270101000000Z") else {
+        unreachable!();
+    };
+    req.time = *b"20280101000000Z";
+    let mut cmd = MailboxReq::SetTime(req);
+    cmd.populate_chksum().unwrap();
+    let err = set_time(&mut model, &cmd).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_SET_TIME_SIGNATURE_INVALID,
+        err,
+    );
+
+    // Signature does not cover the nonce
+    get_time_nonce(&mut model);
+    let err = set_time(&mut model, &set_time_req(&[0u8; 32], b"20270101000000Z")).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_SET_TIME_SIGNATURE_INVALID,
+        err,
+    );
+
+    // Malformed time
+    let nonce = get_time_nonce(&mut model);
+    let err = set_time(&mut model, &set_time_req(&nonce, b"2027-01-01T0000")).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_SET_TIME_INVALID_TIME,
+        err,
+    );
+}
+
+#[test]
+fn test_set_time_replay() {
+    let mut model = run_rt_test(None, None, None);
+
+    // A token without a nonce is rejected
+    let err = set_time(&mut model, &set_time_req(&[0u8; 32], b"20270101000000Z")).unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_SET_TIME_NO_NONCE, err);
+
+    let nonce = get_time_nonce(&mut model);
+    let req = set_time_req(&nonce, b"20270101000000Z");
+    set_time(&mut model, &req).unwrap();
+
+    // The nonce is consumed by the first attempt
+    let err = set_time(&mut model, &req).unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_SET_TIME_NO_NONCE, err);
+
+    // The token is not valid for a new nonce
+    get_time_nonce(&mut model);
+    let err = set_time(&mut model, &req).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_SET_TIME_SIGNATURE_INVALID,
+        err,
+    );
+}
+
+#[test]
+fn test_set_time_replay_after_reset() {
+    let mut model = run_rt_test(None, None, None);
+
+    let nonce = get_time_nonce(&mut model);
+    let req = set_time_req(&nonce, b"20270101000000Z");
+    set_time(&mut model, &req).unwrap();
+
+    // Trigger an update reset to the same firmware
+    let updated_fw_image = caliptra_builder::build_and_sign_image(
+        &FMC_WITH_UART,
+        &APP_WITH_UART,
+        ImageOptions::default(),
+    )
+    .unwrap()
+    .to_bytes()
+    .unwrap();
+    model
+        .mailbox_execute(u32::from(CommandId::FIRMWARE_LOAD), &updated_fw_image)
+        .unwrap();
+
+    // The token is not accepted after the reset, with or without a new nonce
+    let err = set_time(&mut model, &req).unwrap_err();
+    assert_error(&mut model, CaliptraError::RUNTIME_SET_TIME_NO_NONCE, err);
+
+    get_time_nonce(&mut model);
+    let err = set_time(&mut model, &req).unwrap_err();
+    assert_error(
+        &mut model,
+        CaliptraError::RUNTIME_SET_TIME_SIGNATURE_INVALID,
+        err,
+    );
+}
@@ -6,12 +6,13 @@ use caliptra_builder::{
     firmware::{self, APP_WITH_UART, FMC_WITH_UART, ROM_WITH_UART},
     ImageOptions,
 };
+use caliptra_drivers::CrashRecord;
 use caliptra_error::CaliptraError;
 use caliptra_hw_model::{BootParams, DeviceLifecycle, Fuses, HwModel, InitParams, SecurityState};
 use caliptra_registers::mbox::enums::MboxStatusE;
 use dpe::DPE_PROFILE;
 use openssl::sha::sha384;
-use zerocopy::AsBytes;
+use zerocopy::{AsBytes, FromBytes};
 
 fn swap_word_bytes_inplace(words: &mut [u32]) {
     for word in words.iter_mut() {
@@ -150,3 +151,65 @@ fn test_mbox_busy_during_warm_reset() {
     // Wait for boot
     model.step_until(|m| m.soc_ifc().cptra_flow_status().read().ready_for_runtime());
 }
+
+#[test]
+fn test_crash_record_survives_warm_reset() {
+    const OPCODE_FATAL_ERROR: u32 = 0xE000_0000;
+    const OPCODE_READ_CRASH_RECORD: u32 = 0xF000_0000;
+    const FATAL_ERROR: u32 = 0xdead_0001;
+
+    let rom = caliptra_builder::build_firmware_rom(&ROM_WITH_UART).unwrap();
+    let image = caliptra_builder::build_and_sign_image(
+        &FMC_WITH_UART,
+        &firmware::runtime_tests::MBOX,
+        ImageOptions::default(),
+    )
+    .unwrap();
+    let vendor_pk_hash =
+        bytes_to_be_words_48(&sha384(image.manifest.preamble.vendor_pub_keys.as_bytes()));
+    let owner_pk_hash =
+        bytes_to_be_words_48(&sha384(image.manifest.preamble.owner_pub_keys.as_bytes()));
+    let fuses = Fuses {
+        key_manifest_pk_hash: vendor_pk_hash,
+        owner_pk_hash,
+        ..Default::default()
+    };
+
+    let mut model = caliptra_hw_model::new(BootParams {
+        init_params: InitParams {
+            rom: &rom,
+            ..Default::default()
+        },
+        fuses,
+        fw_image: Some(&image.to_bytes().unwrap()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Wait for boot
+    model.step_until(|m| m.soc_ifc().cptra_flow_status().read().ready_for_runtime());
+
+    // The fatal error handler aborts the command
+    assert!(model
+        .mailbox_execute(OPCODE_FATAL_ERROR, FATAL_ERROR.as_bytes())
+        .is_err());
+    model.step_until(|m| m.soc_ifc().cptra_fw_error_fatal().read() == FATAL_ERROR);
+
+    // Perform warm reset
+    model.warm_reset_flow(&fuses);
+
+    // Wait for boot
+    model.step_until(|m| m.soc_ifc().cptra_flow_status().read().ready_for_runtime());
+
+    let resp = model
+        .mailbox_execute(OPCODE_READ_CRASH_RECORD, &[])
+        .unwrap()
+        .unwrap();
+    let crash_record = CrashRecord::read_from(resp.as_slice()).unwrap();
+    assert!(crash_record.is_valid());
+    assert_eq!(crash_record.fw_error_fatal, FATAL_ERROR);
+    assert_eq!(crash_record.last_mbox_cmd, OPCODE_FATAL_ERROR);
+    // Not caused by a trap
+    assert_eq!(crash_record.mcause, 0);
+    assert_eq!(crash_record.regs, [0; 17]);
+}
@@ -71,6 +71,14 @@ pub trait Bus {
         // By default, do nothing
     }
 
+    /// Returns true if an enabled external interrupt is pending at the
+    /// interrupt controller. The CPU uses this to wake from a low-power halt
+    /// and to take external interrupts.
+    fn ext_int_pending(&self) -> bool {
+        // By default, no interrupts
+        false
+    }
+
     fn update_reset(&mut self) {
         // By default, do nothing
     }
@@ -153,6 +153,18 @@ impl Clock {
         self.clock.now()
     }
 
+    /// Returns the number of clock cycles until the next scheduled timer
+    /// action fires, or None if no actions are scheduled.
+    pub fn cycles_until_next_action(&self) -> Option<u64> {
+        self.clock.next_action_time.get().map(|time| {
+            if self.clock.has_fired(time) {
+                0
+            } else {
+                time.wrapping_sub(self.now())
+            }
+        })
+    }
+
     /// Increments the clock by `delta`, and returns a list of timer
     /// actions fired.
     #[inline]
@@ -362,6 +374,24 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_cycles_until_next_action() {
+        let clock = Clock::new();
+        let timer = clock.timer();
+        assert_eq!(clock.cycles_until_next_action(), None);
+
+        let mut action0 = Some(timer.schedule_poll_in(40));
+        let _action1 = timer.schedule_poll_in(25);
+        assert_eq!(clock.cycles_until_next_action(), Some(25));
+
+        clock.increment(25);
+        assert_eq!(clock.cycles_until_next_action(), Some(15));
+
+        clock.increment(15);
+        assert!(timer.fired(&mut action0));
+        assert_eq!(clock.cycles_until_next_action(), None);
+    }
+
     #[test]
     fn test_clock() {
         let clock = Clock::new();
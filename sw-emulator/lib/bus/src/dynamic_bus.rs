@@ -94,6 +94,10 @@ impl Bus for DynamicBus {
             dev.bus.poll();
         }
     }
+
+    fn ext_int_pending(&self) -> bool {
+        self.devs.iter().any(|dev| dev.bus.ext_int_pending())
+    }
 }
 
 #[cfg(test)]
@@ -14,11 +14,13 @@ Abstract:
 
 use crate::csr_file::{Csr, CsrFile};
 use crate::instr::Instr;
+use crate::internal_timers::InternalTimers;
 use crate::types::{RvInstr, RvMStatus};
 use crate::xreg_file::{XReg, XRegFile};
 use bit_vec::BitVec;
 use caliptra_emu_bus::{Bus, BusError, Clock, TimerAction};
 use caliptra_emu_types::{RvAddr, RvData, RvException, RvSize};
+use std::collections::HashSet;
 
 pub type InstrTracer<'a> = dyn FnMut(u32, RvInstr) + 'a;
 
@@ -147,6 +149,15 @@ pub struct Cpu<TBus: Bus> {
     pub(crate) watch_ptr_cfg: WatchPtrCfg,
 
     pub code_coverage: CodeCoverage,
+
+    /// The core is halted by MPMC and waiting for an interrupt.
+    halted: bool,
+
+    /// Number of clock cycles spent halted
+    halted_cycles: u64,
+
+    /// VeeR internal timers
+    internal_timers: InternalTimers,
 }
 
 /// Cpu instruction step action
@@ -166,6 +177,19 @@ impl<TBus: Bus> Cpu<TBus> {
     /// Default Program counter reset value
     const PC_RESET_VAL: RvData = 0;
 
+    /// Machine external interrupt number (mip / mie bit)
+    const MEI: u32 = 11;
+
+    /// MPMC bit that halts the core
+    const MPMC_HALT: u32 = 1 << 0;
+
+    /// MPMC bit that sets mstatus.MIE when halting
+    const MPMC_HALTIE: u32 = 1 << 1;
+
+    /// The most clock cycles a halted core skips in a single step, so the SoC
+    /// still gets regular opportunities to interact with the model.
+    const MAX_HALTED_STEP: u64 = 1024;
+
     /// Create a new RISCV CPU
     pub fn new(bus: TBus, clock: Clock) -> Self {
         Self {
@@ -181,9 +205,22 @@ impl<TBus: Bus> Cpu<TBus> {
             // TODO: Pass in code_coverage from the outside (as caliptra-emu-cpu
             // isn't supposed to know anything about the caliptra memory map)
             code_coverage: CodeCoverage::new(ROM_SIZE, ICCM_SIZE),
+            halted: false,
+            halted_cycles: 0,
+            internal_timers: InternalTimers::new(),
         }
     }
 
+    /// Returns true if the core is halted waiting for an interrupt
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Returns the number of clock cycles the core has spent halted
+    pub fn halted_cycles(&self) -> u64 {
+        self.halted_cycles
+    }
+
     /// Read the RISCV CPU Program counter
     ///
     ///  # Return
@@ -263,7 +300,16 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
     pub fn read_csr(&self, csr: RvAddr) -> Result<RvData, RvException> {
-        self.csrs.read(csr)
+        if csr == Csr::MIP {
+            return Ok(self.csrs.read(csr)? | self.pending_interrupts());
+        }
+        match self
+            .internal_timers
+            .read(csr, self.clock.now(), self.halted)
+        {
+            Some(val) => Ok(val),
+            None => self.csrs.read(csr),
+        }
     }
 
     /// Write the specified Configuration status register
@@ -277,9 +323,57 @@ impl<TBus: Bus> Cpu<TBus> {
     ///
     /// * `RvException` - Exception with cause `RvExceptionCause::IllegalRegister`
     pub fn write_csr(&mut self, csr: RvAddr, val: RvData) -> Result<(), RvException> {
+        if csr == Csr::MPMC {
+            return self.write_mpmc(val);
+        }
+        if self
+            .internal_timers
+            .write(csr, val, self.clock.now(), self.halted)
+        {
+            return Ok(());
+        }
         self.csrs.write(csr, val)
     }
 
+    /// Write the VeeR power management control register. Halting takes effect
+    /// after the current instruction retires.
+    fn write_mpmc(&mut self, val: RvData) -> Result<(), RvException> {
+        self.csrs.write(Csr::MPMC, val)?;
+        if val & Self::MPMC_HALT == 0 {
+            return Ok(());
+        }
+        if val & Self::MPMC_HALTIE != 0 {
+            let mut status = RvMStatus(self.read_csr(Csr::MSTATUS)?);
+            status.set_mie(1);
+            self.write_csr(Csr::MSTATUS, status.0)?;
+        }
+        self.internal_timers.sync(self.clock.now(), false);
+        self.halted = true;
+        Ok(())
+    }
+
+    fn wake(&mut self) {
+        if self.halted {
+            self.internal_timers.sync(self.clock.now(), true);
+            self.halted = false;
+        }
+    }
+
+    /// Returns the pending interrupts as mip bits
+    fn pending_interrupts(&self) -> u32 {
+        let mut mip = self.internal_timers.pending(self.clock.now(), self.halted);
+        if self.bus.ext_int_pending() {
+            mip |= 1 << Self::MEI;
+        }
+        mip
+    }
+
+    /// Returns the pending interrupts that are enabled in mie
+    fn enabled_interrupts(&self) -> u32 {
+        // Cannot panic; mie is a valid CSR
+        self.pending_interrupts() & self.read_csr(Csr::MIE).unwrap()
+    }
+
     /// Read from bus
     ///
     /// # Arguments
@@ -384,28 +478,113 @@ impl<TBus: Bus> Cpu<TBus> {
 
     /// Step a single instruction
     pub fn step(&mut self, instr_tracer: Option<&mut InstrTracer>) -> StepAction {
+        if self.halted {
+            // An enabled interrupt wakes the core even if mstatus.MIE is
+            // clear; in that case execution resumes after the halting
+            // instruction without taking the interrupt.
+            if self.enabled_interrupts() == 0 {
+                return self.step_halted();
+            }
+            self.wake();
+        }
+
         let fired_action_types = self
             .clock
             .increment_and_process_timer_actions(1, &mut self.bus);
+        if let Some(action) = self.process_timer_actions(fired_action_types) {
+            return action;
+        }
+
+        if let Some(action) = self.handle_interrupt() {
+            return action;
+        }
+
+        match self.exec_instr(instr_tracer) {
+            Ok(result) => result,
+            Err(exception) => self.handle_exception(exception),
+        }
+    }
+
+    /// Advance the clock while halted. Skips ahead to the next scheduled
+    /// timer action or internal timer interrupt, whichever comes first.
+    fn step_halted(&mut self) -> StepAction {
+        let now = self.clock.now();
+        let delta = [
+            Some(Self::MAX_HALTED_STEP),
+            self.clock.cycles_until_next_action(),
+            self.internal_timers.cycles_until_pending(now, true),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(Self::MAX_HALTED_STEP)
+        .max(1);
+
+        let fired_action_types = self
+            .clock
+            .increment_and_process_timer_actions(delta, &mut self.bus);
+        self.halted_cycles += delta;
+        self.process_timer_actions(fired_action_types)
+            .unwrap_or(StepAction::Continue)
+    }
+
+    /// Handle timer actions that affect the core. Resets and NMIs also wake
+    /// a halted core.
+    fn process_timer_actions(
+        &mut self,
+        fired_action_types: HashSet<TimerAction>,
+    ) -> Option<StepAction> {
         for action_type in fired_action_types.iter() {
             match action_type {
                 TimerAction::WarmReset => {
+                    self.wake();
                     self.reset_pc();
                     break;
                 }
                 TimerAction::UpdateReset => {
+                    self.wake();
                     self.reset_pc();
                     break;
                 }
-                TimerAction::Nmi { mcause } => return self.handle_nmi(*mcause, 0),
+                TimerAction::Nmi { mcause } => {
+                    self.wake();
+                    return Some(self.handle_nmi(*mcause, 0));
+                }
                 TimerAction::SetNmiVec { addr } => self.nmivec = *addr,
                 _ => {}
             }
         }
+        None
+    }
 
-        match self.exec_instr(instr_tracer) {
-            Ok(result) => result,
-            Err(exception) => self.handle_exception(exception),
+    /// Take the highest priority pending interrupt, if interrupts are
+    /// globally enabled.
+    fn handle_interrupt(&mut self) -> Option<StepAction> {
+        // Cannot panic; mstatus is a valid CSR
+        if RvMStatus(self.read_csr(Csr::MSTATUS).unwrap()).mie() == 0 {
+            return None;
+        }
+        let enabled = self.enabled_interrupts();
+        let irq = [Self::MEI]
+            .into_iter()
+            .chain(InternalTimers::INTERRUPTS)
+            .find(|irq| enabled & (1 << irq) != 0)?;
+        if irq != Self::MEI {
+            self.internal_timers
+                .clear_pending(irq, self.clock.now(), self.halted);
+        }
+
+        // Cannot panic; mtvec is a valid CSR
+        let mtvec = self.read_csr(Csr::MTVEC).unwrap();
+        let mut next_pc = mtvec & !0b11;
+        if mtvec & 0b11 == 1 {
+            // Vectored mode
+            next_pc = next_pc.wrapping_add(4 * irq);
+        }
+        let ret = self.handle_trap(true, self.read_pc(), 0x8000_0000 | irq, 0, next_pc);
+        match ret {
+            Ok(_) => Some(StepAction::Continue),
+            Err(_) => Some(StepAction::Fatal),
         }
     }
 
@@ -447,10 +626,7 @@ impl<TBus: Bus> Cpu<TBus> {
         info: u32,
         next_pc: u32,
     ) -> Result<(), RvException> {
-        // TODO: Implement Interrupt Handling
-        // 1. Support for vectored asynchronous interrupts
-        // 2. Veer fast external interrupt support
-        assert!(!_intr);
+        // TODO: Veer fast external interrupt support
 
         self.write_csr(Csr::MEPC, pc)?;
         self.write_csr(Csr::MCAUSE, cause)?;
@@ -498,6 +674,7 @@ impl<TBus: Bus> Cpu<TBus> {
 mod tests {
     use super::*;
     use caliptra_emu_bus::{testing::FakeBus, DynamicBus, Rom, Timer};
+    use std::{cell::Cell, rc::Rc};
 
     #[test]
     fn test_new() {
@@ -559,6 +736,125 @@ mod tests {
         assert_eq!(cpu.read_pc(), 31 * 4);
     }
 
+    struct IrqBus {
+        pending: Rc<Cell<bool>>,
+    }
+    impl Bus for IrqBus {
+        fn read(&mut self, _size: RvSize, _addr: RvAddr) -> Result<RvData, BusError> {
+            Err(BusError::LoadAccessFault)
+        }
+        fn write(&mut self, _size: RvSize, _addr: RvAddr, _val: RvData) -> Result<(), BusError> {
+            Err(BusError::StoreAccessFault)
+        }
+        fn ext_int_pending(&self) -> bool {
+            self.pending.get()
+        }
+    }
+
+    /// Returns a CPU running `addi t0, zero, 1; csrrs zero, mpmc, t0; nop...`
+    fn halting_cpu() -> (Cpu<DynamicBus>, Rc<Cell<bool>>) {
+        const RV32_NO_OP: u32 = 0x00000013;
+        const ADDI_T0_1: u32 = 0x00100293;
+        const CSRRS_MPMC_T0: u32 = 0x7c62a073;
+
+        let mut bus = DynamicBus::new();
+        let rom = Rom::new(
+            [ADDI_T0_1, CSRRS_MPMC_T0]
+                .into_iter()
+                .chain(std::iter::repeat(RV32_NO_OP).take(254))
+                .flat_map(u32::to_le_bytes)
+                .collect(),
+        );
+        bus.attach_dev("ROM", 0..=0x3ff, Box::new(rom)).unwrap();
+
+        let pending = Rc::new(Cell::new(false));
+        let irq_bus = IrqBus {
+            pending: pending.clone(),
+        };
+        bus.attach_dev("IRQ", 0x2000..=0x3000, Box::new(irq_bus))
+            .unwrap();
+
+        (Cpu::new(bus, Clock::new()), pending)
+    }
+
+    #[test]
+    fn test_halt_wake_on_ext_int() {
+        let (mut cpu, pending) = halting_cpu();
+        cpu.write_csr(Csr::MIE, 1 << 11).unwrap();
+
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.read_pc(), 8);
+
+        for _ in 0..3 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.read_pc(), 8);
+        assert_eq!(cpu.halted_cycles(), 3 * 1024);
+        assert_eq!(cpu.clock.now(), 2 + 3 * 1024);
+
+        // mstatus.MIE is clear, so the core resumes without taking the interrupt
+        pending.set(true);
+        assert_eq!(cpu.read_csr(Csr::MIP).unwrap(), 1 << 11);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(!cpu.is_halted());
+        assert_eq!(cpu.read_pc(), 12);
+        assert_eq!(cpu.read_csr(Csr::MCAUSE).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_halt_ignores_disabled_ext_int() {
+        let (mut cpu, pending) = halting_cpu();
+        pending.set(true);
+
+        for _ in 0..4 {
+            assert_eq!(cpu.step(None), StepAction::Continue);
+        }
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.read_pc(), 8);
+    }
+
+    #[test]
+    fn test_halt_wake_on_internal_timer() {
+        let (mut cpu, _) = halting_cpu();
+        cpu.write_csr(Csr::MIE, 1 << 29).unwrap();
+        cpu.write_csr(Csr::MITB0, 100).unwrap();
+        // Enable, and keep counting while halted
+        cpu.write_csr(Csr::MITCTL0, 0b11).unwrap();
+
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(cpu.is_halted());
+
+        // The halted core skips straight to the timer interrupt
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.halted_cycles(), 98);
+        assert_eq!(cpu.read_csr(Csr::MITCNT0).unwrap(), 100);
+
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert!(!cpu.is_halted());
+        assert_eq!(cpu.read_pc(), 12);
+    }
+
+    #[test]
+    fn test_ext_int_trap() {
+        let (mut cpu, pending) = halting_cpu();
+        cpu.write_csr(Csr::MTVEC, 0x100).unwrap();
+        cpu.write_csr(Csr::MIE, 1 << 11).unwrap();
+        cpu.write_csr(Csr::MSTATUS, 1 << 3).unwrap();
+
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        pending.set(true);
+        assert_eq!(cpu.step(None), StepAction::Continue);
+        assert_eq!(cpu.read_pc(), 0x100);
+        assert_eq!(cpu.read_csr(Csr::MEPC).unwrap(), 4);
+        assert_eq!(cpu.read_csr(Csr::MCAUSE).unwrap(), 0x8000_000b);
+        assert_eq!(RvMStatus(cpu.read_csr(Csr::MSTATUS).unwrap()).mie(), 0);
+    }
+
     pub fn count_executed(coverage: &CodeCoverage) -> usize {
         coverage
             .rom_bit_vec
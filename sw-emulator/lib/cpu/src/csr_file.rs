@@ -76,6 +76,27 @@ impl Csr {
     /// Instruction Retired High Counter CSR
     pub const MINSTRETH: RvAddr = 0xB82;
 
+    /// Power Management Control CSR (VeeR-specific)
+    pub const MPMC: RvAddr = 0x7C6;
+
+    /// Internal Timer Counter 0 CSR (VeeR-specific)
+    pub const MITCNT0: RvAddr = 0x7D2;
+
+    /// Internal Timer Bound 0 CSR (VeeR-specific)
+    pub const MITB0: RvAddr = 0x7D3;
+
+    /// Internal Timer Control 0 CSR (VeeR-specific)
+    pub const MITCTL0: RvAddr = 0x7D4;
+
+    /// Internal Timer Counter 1 CSR (VeeR-specific)
+    pub const MITCNT1: RvAddr = 0x7D5;
+
+    /// Internal Timer Bound 1 CSR (VeeR-specific)
+    pub const MITB1: RvAddr = 0x7D6;
+
+    /// Internal Timer Control 1 CSR (VeeR-specific)
+    pub const MITCTL1: RvAddr = 0x7D7;
+
     /// Create a new Configurations and Status register
     ///
     /// # Arguments
@@ -128,6 +149,7 @@ impl CsrFile {
         self.csrs[Csr::MCYCLEH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRET as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
         self.csrs[Csr::MINSTRETH as usize] = Csr::new(0x0000_0000, 0xFFFF_FFFF);
+        self.csrs[Csr::MPMC as usize] = Csr::new(0x0000_0000, 0x0000_0002);
     }
 
     /// Read the specified configuration status register
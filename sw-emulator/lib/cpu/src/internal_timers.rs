@@ -0,0 +1,221 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    internal_timers.rs
+
+Abstract:
+
+    File contains implementation of the VeeR EL2 internal timers.
+
+--*/
+
+use crate::csr_file::Csr;
+use caliptra_emu_types::{RvAddr, RvData};
+
+/// Control register bits
+const MITCTL_ENABLE: u32 = 1 << 0;
+const MITCTL_HALT_EN: u32 = 1 << 1;
+const MITCTL_MASK: u32 = 0x7;
+
+/// VeeR internal timer. The counter increments every cycle while enabled
+/// and restarts from zero after reaching the bound, raising an interrupt.
+///
+/// The counter is evaluated lazily: `count` holds the value at
+/// `last_update`, and later values are computed from the elapsed cycles.
+#[derive(Clone, Copy)]
+struct InternalTimer {
+    count: u32,
+    bound: u32,
+    ctl: u32,
+    last_update: u64,
+    pending: bool,
+}
+
+impl InternalTimer {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            bound: u32::MAX,
+            ctl: MITCTL_ENABLE,
+            last_update: 0,
+            pending: false,
+        }
+    }
+
+    fn counting(&self, halted: bool) -> bool {
+        self.ctl & MITCTL_ENABLE != 0 && (!halted || self.ctl & MITCTL_HALT_EN != 0)
+    }
+
+    /// Returns the counter value and pending state at `now`
+    fn state_at(&self, now: u64, halted: bool) -> (u32, bool) {
+        if !self.counting(halted) {
+            return (self.count, self.pending);
+        }
+        let bound = u64::from(self.bound);
+        let total = u64::from(self.count) + now.wrapping_sub(self.last_update);
+        if total <= bound {
+            (total as u32, self.pending || total == bound)
+        } else {
+            (((total - bound - 1) % (bound + 1)) as u32, true)
+        }
+    }
+
+    fn sync(&mut self, now: u64, halted: bool) {
+        (self.count, self.pending) = self.state_at(now, halted);
+        self.last_update = now;
+    }
+
+    fn cycles_until_pending(&self, now: u64, halted: bool) -> Option<u64> {
+        let (count, pending) = self.state_at(now, halted);
+        if pending {
+            Some(0)
+        } else if self.counting(halted) {
+            Some(u64::from(self.bound - count))
+        } else {
+            None
+        }
+    }
+}
+
+/// The two VeeR internal timers
+pub(crate) struct InternalTimers {
+    timers: [InternalTimer; 2],
+}
+
+impl InternalTimers {
+    /// Interrupt number (mip / mie bit) of each timer
+    pub const INTERRUPTS: [u32; 2] = [29, 28];
+
+    pub fn new() -> Self {
+        Self {
+            timers: [InternalTimer::new(); 2],
+        }
+    }
+
+    /// Map a CSR address to a timer index and register offset
+    fn decode(csr: RvAddr) -> Option<(usize, RvAddr)> {
+        match csr {
+            Csr::MITCNT0..=Csr::MITCTL0 => Some((0, csr - Csr::MITCNT0)),
+            Csr::MITCNT1..=Csr::MITCTL1 => Some((1, csr - Csr::MITCNT1)),
+            _ => None,
+        }
+    }
+
+    /// Read a timer CSR. Returns `None` if `csr` is not a timer CSR.
+    pub fn read(&self, csr: RvAddr, now: u64, halted: bool) -> Option<RvData> {
+        let (index, reg) = Self::decode(csr)?;
+        let timer = &self.timers[index];
+        match reg {
+            0 => Some(timer.state_at(now, halted).0),
+            1 => Some(timer.bound),
+            _ => Some(timer.ctl),
+        }
+    }
+
+    /// Write a timer CSR. Returns false if `csr` is not a timer CSR.
+    pub fn write(&mut self, csr: RvAddr, val: RvData, now: u64, halted: bool) -> bool {
+        let Some((index, reg)) = Self::decode(csr) else {
+            return false;
+        };
+        let timer = &mut self.timers[index];
+        timer.sync(now, halted);
+        match reg {
+            0 => {
+                timer.count = val;
+                timer.pending = false;
+            }
+            1 => timer.bound = val,
+            _ => timer.ctl = val & MITCTL_MASK,
+        }
+        true
+    }
+
+    /// Latch the counters before the core halts or wakes, as that changes
+    /// whether timers without halt_en are counting.
+    pub fn sync(&mut self, now: u64, halted: bool) {
+        for timer in self.timers.iter_mut() {
+            timer.sync(now, halted);
+        }
+    }
+
+    /// Returns the pending timer interrupts as mip bits
+    pub fn pending(&self, now: u64, halted: bool) -> u32 {
+        self.timers
+            .iter()
+            .zip(Self::INTERRUPTS)
+            .filter(|(timer, _)| timer.state_at(now, halted).1)
+            .fold(0, |mip, (_, irq)| mip | (1 << irq))
+    }
+
+    /// Clear the pending interrupt of the timer raising `irq`
+    pub fn clear_pending(&mut self, irq: u32, now: u64, halted: bool) {
+        for (timer, timer_irq) in self.timers.iter_mut().zip(Self::INTERRUPTS) {
+            if timer_irq == irq {
+                timer.sync(now, halted);
+                timer.pending = false;
+            }
+        }
+    }
+
+    /// Returns the number of cycles until the next timer interrupt becomes
+    /// pending, if any timer is counting.
+    pub fn cycles_until_pending(&self, now: u64, halted: bool) -> Option<u64> {
+        self.timers
+            .iter()
+            .filter_map(|timer| timer.cycles_until_pending(now, halted))
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_and_wrap() {
+        let mut timers = InternalTimers::new();
+        assert!(timers.write(Csr::MITB0, 9, 0, false));
+        assert_eq!(timers.read(Csr::MITCNT0, 5, false), Some(5));
+        assert_eq!(timers.pending(5, false), 0);
+        assert_eq!(timers.cycles_until_pending(5, false), Some(4));
+
+        assert_eq!(timers.read(Csr::MITCNT0, 9, false), Some(9));
+        assert_eq!(timers.pending(9, false), 1 << 29);
+
+        // The counter restarts from zero, the interrupt stays pending
+        assert_eq!(timers.read(Csr::MITCNT0, 10, false), Some(0));
+        assert_eq!(timers.read(Csr::MITCNT0, 23, false), Some(3));
+        assert_eq!(timers.pending(23, false), 1 << 29);
+
+        timers.clear_pending(29, 23, false);
+        assert_eq!(timers.pending(23, false), 0);
+        assert_eq!(timers.cycles_until_pending(23, false), Some(6));
+    }
+
+    #[test]
+    fn test_halt_en() {
+        let mut timers = InternalTimers::new();
+        timers.write(Csr::MITB0, 100, 0, false);
+        timers.write(Csr::MITB1, 100, 0, false);
+        timers.write(Csr::MITCTL1, MITCTL_ENABLE | MITCTL_HALT_EN, 0, false);
+
+        timers.sync(10, false);
+        assert_eq!(timers.read(Csr::MITCNT0, 50, true), Some(10));
+        assert_eq!(timers.read(Csr::MITCNT1, 50, true), Some(50));
+        assert_eq!(timers.cycles_until_pending(50, true), Some(50));
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut timers = InternalTimers::new();
+        timers.write(Csr::MITCTL0, 0, 0, false);
+        timers.write(Csr::MITCTL1, 0, 0, false);
+        assert_eq!(timers.read(Csr::MITCNT0, 1000, false), Some(0));
+        assert_eq!(timers.cycles_until_pending(1000, false), None);
+        assert!(!timers.write(Csr::MEPC, 0, 0, false));
+        assert_eq!(timers.read(Csr::MEPC, 0, false), None);
+    }
+}
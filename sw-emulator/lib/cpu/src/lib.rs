@@ -15,6 +15,7 @@ Abstract:
 pub mod cpu;
 mod csr_file;
 mod instr;
+mod internal_timers;
 mod types;
 pub mod xreg_file;
 
@@ -98,6 +98,9 @@ pub fn derive_bus(input: TokenStream) -> TokenStream {
                 #(self.#field_idents.update_reset();)*
                 #self_update_reset_tokens
             }
+            fn ext_int_pending(&self) -> bool {
+                false #(|| self.#field_idents.ext_int_pending())*
+            }
 
         }
     }
@@ -795,6 +798,18 @@ mod tests {
                         self.i2c2.update_reset();
                         self.spi0.update_reset();
                     }
+                    fn ext_int_pending(&self) -> bool {
+                        false
+                            || self.rom.ext_int_pending()
+                            || self.sram.ext_int_pending()
+                            || self.dram.ext_int_pending()
+                            || self.uart0.ext_int_pending()
+                            || self.uart1.ext_int_pending()
+                            || self.i2c0.ext_int_pending()
+                            || self.i2c1.ext_int_pending()
+                            || self.i2c2.ext_int_pending()
+                            || self.spi0.ext_int_pending()
+                    }
                 }
             }.to_string()
         );
@@ -821,6 +836,9 @@ mod tests {
                     }
                     fn update_reset(&mut self) {
                     }
+                    fn ext_int_pending(&self) -> bool {
+                        false
+                    }
                 }
             }.to_string()
         );
@@ -25,6 +25,7 @@ mod hmac_sha384;
 mod iccm;
 mod key_vault;
 mod mailbox;
+mod pic;
 mod root_bus;
 mod sha512_acc;
 pub mod soc_reg;
@@ -41,6 +42,7 @@ pub use iccm::Iccm;
 pub use key_vault::KeyUsage;
 pub use key_vault::KeyVault;
 pub use mailbox::{MailboxExternal, MailboxInternal, MailboxRam};
+pub use pic::{IrqLine, Pic};
 pub use root_bus::{
     ActionCb, CaliptraRootBus, CaliptraRootBusArgs, DownloadIdevidCsrCb, ReadyForFwCb,
     SocToCaliptraBus, TbServicesCb, UploadUpdateFwCb,
@@ -151,6 +151,18 @@ impl MailboxInternal {
             regs: self.regs.clone(),
         }
     }
+
+    /// Returns true if the SoC has submitted a command since the last call to
+    /// `clear_cmd_avail_sts()`. This drives the soc_ifc command-available
+    /// notification interrupt.
+    pub fn cmd_avail_sts(&self) -> bool {
+        self.regs.borrow().state_machine.context.cmd_avail
+    }
+
+    /// Clear the command-available notification status.
+    pub fn clear_cmd_avail_sts(&mut self) {
+        self.regs.borrow_mut().state_machine.context.cmd_avail = false;
+    }
 }
 
 impl Bus for MailboxInternal {
@@ -427,7 +439,7 @@ statemachine! {
         RdyForData + WrUnlock  / unlock_and_reset = Idle,
 
         //move from rdy for data to execute uc  when soc sets execute bit.
-        RdyForData + SocExecSet = ExecUc,
+        RdyForData + SocExecSet / set_cmd_avail = ExecUc,
 
         //move from rdy for data to execute soc when soc sets execute bit.
         RdyForData + UcExecSet = ExecSoc,
@@ -474,6 +486,8 @@ pub struct Context {
     data_out: u32,
     // unlock
     pub unlock: u32,
+    /// Command available notification status
+    pub cmd_avail: bool,
 }
 
 impl Context {
@@ -488,6 +502,7 @@ impl Context {
             cmd: 0,
             data_out: 0,
             unlock: 0,
+            cmd_avail: false,
         }
     }
 }
@@ -534,6 +549,10 @@ impl StateMachineContext for Context {
         self.cmd = cmd.0;
     }
 
+    fn set_cmd_avail(&mut self) {
+        self.cmd_avail = true;
+    }
+
     fn lock(&mut self, user: &MailboxRequester) {
         self.fifo.reset();
         self.locked = 1;
@@ -0,0 +1,248 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    pic.rs
+
+Abstract:
+
+    File contains the VeeR EL2 Programmable Interrupt Controller (PIC)
+    implementation.
+
+--*/
+
+use caliptra_emu_bus::{Bus, BusError};
+use caliptra_emu_types::{RvAddr, RvData, RvSize};
+
+/// An interrupt request line. Returns the current level of the line.
+pub type IrqLine = Box<dyn Fn() -> bool>;
+
+pub struct Pic {
+    /// Interrupt priority level per source
+    meipl: [u32; Pic::SOURCE_COUNT],
+
+    /// Interrupt enable per source
+    meie: [u32; Pic::SOURCE_COUNT],
+
+    /// Priority order configuration
+    mpiccfg: u32,
+
+    /// Gateway configuration per source
+    meigwctrl: [u32; Pic::SOURCE_COUNT],
+
+    /// Interrupt request lines, indexed by source id
+    lines: Vec<(usize, IrqLine)>,
+}
+
+impl Pic {
+    /// Number of interrupt sources. Source 0 is reserved.
+    pub const SOURCE_COUNT: usize = 32;
+
+    /// soc_ifc notification interrupt source id
+    pub const SOC_IFC_NOTIF_IRQ: usize = 20;
+
+    const MEIPL_START: RvAddr = 0x0000;
+    const MEIP_START: RvAddr = 0x1000;
+    const MEIE_START: RvAddr = 0x2000;
+    const MPICCFG: RvAddr = 0x3000;
+    const MEIGWCTRL_START: RvAddr = 0x4000;
+    const MEIGWCLR_START: RvAddr = 0x5000;
+
+    const ARRAY_SIZE: RvAddr = (Pic::SOURCE_COUNT * 4) as RvAddr;
+
+    const PRIORITY_MASK: u32 = 0xf;
+    const INTEN_MASK: u32 = 0x1;
+    const PRIORD_MASK: u32 = 0x1;
+    const GWCTRL_POLARITY: u32 = 1 << 0;
+    const GWCTRL_MASK: u32 = 0x3;
+
+    pub fn new() -> Self {
+        Self {
+            meipl: [0; Pic::SOURCE_COUNT],
+            meie: [0; Pic::SOURCE_COUNT],
+            mpiccfg: 0,
+            meigwctrl: [0; Pic::SOURCE_COUNT],
+            lines: Vec::new(),
+        }
+    }
+
+    /// Connect an interrupt request line to the specified source
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Interrupt source id
+    /// * `line` - Interrupt request line
+    pub fn connect_irq(&mut self, source: usize, line: IrqLine) {
+        assert!(
+            source > 0 && source < Self::SOURCE_COUNT,
+            "Invalid interrupt source {source}"
+        );
+        self.lines.push((source, line));
+    }
+
+    /// Returns true if the gateway for `source` is signalling a pending
+    /// interrupt. Gateways are always level-triggered; edge-triggered mode is
+    /// not modeled.
+    fn gateway_pending(&self, source: usize, line: &IrqLine) -> bool {
+        let active_low = self.meigwctrl[source] & Self::GWCTRL_POLARITY != 0;
+        line() != active_low
+    }
+
+    /// Returns the MEIP pending bitmap
+    fn meip(&self) -> u32 {
+        self.lines
+            .iter()
+            .filter(|(source, line)| self.gateway_pending(*source, line))
+            .fold(0, |meip, (source, _)| meip | (1 << *source))
+    }
+
+    /// A source with the lowest priority never interrupts the core.
+    fn priority_above_threshold(&self, source: usize) -> bool {
+        let priority = self.meipl[source];
+        if self.mpiccfg & Self::PRIORD_MASK == 0 {
+            priority > 0
+        } else {
+            priority < Self::PRIORITY_MASK
+        }
+    }
+
+    fn index(addr: RvAddr, start: RvAddr) -> Option<usize> {
+        (start..start + Self::ARRAY_SIZE)
+            .contains(&addr)
+            .then_some(((addr - start) / 4) as usize)
+    }
+}
+
+impl Default for Pic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bus for Pic {
+    /// Read data of specified size from given address
+    fn read(&mut self, size: RvSize, addr: RvAddr) -> Result<RvData, BusError> {
+        if size != RvSize::Word || addr & 0x3 != 0 {
+            return Err(BusError::LoadAccessFault);
+        }
+        if let Some(i) = Self::index(addr, Self::MEIPL_START) {
+            return Ok(self.meipl[i]);
+        }
+        if addr == Self::MEIP_START {
+            return Ok(self.meip());
+        }
+        if let Some(i) = Self::index(addr, Self::MEIE_START) {
+            return Ok(self.meie[i]);
+        }
+        if addr == Self::MPICCFG {
+            return Ok(self.mpiccfg);
+        }
+        if let Some(i) = Self::index(addr, Self::MEIGWCTRL_START) {
+            return Ok(self.meigwctrl[i]);
+        }
+        if Self::index(addr, Self::MEIGWCLR_START).is_some() {
+            return Ok(0);
+        }
+        Err(BusError::LoadAccessFault)
+    }
+
+    /// Write data of specified size to given address
+    fn write(&mut self, size: RvSize, addr: RvAddr, val: RvData) -> Result<(), BusError> {
+        if size != RvSize::Word || addr & 0x3 != 0 {
+            return Err(BusError::StoreAccessFault);
+        }
+        if let Some(i) = Self::index(addr, Self::MEIPL_START) {
+            self.meipl[i] = val & Self::PRIORITY_MASK;
+        } else if let Some(i) = Self::index(addr, Self::MEIE_START) {
+            self.meie[i] = val & Self::INTEN_MASK;
+        } else if addr == Self::MPICCFG {
+            self.mpiccfg = val & Self::PRIORD_MASK;
+        } else if let Some(i) = Self::index(addr, Self::MEIGWCTRL_START) {
+            self.meigwctrl[i] = val & Self::GWCTRL_MASK;
+        } else if Self::index(addr, Self::MEIGWCLR_START).is_some() {
+            // Only edge-triggered gateways latch, and they aren't modeled.
+        } else {
+            return Err(BusError::StoreAccessFault);
+        }
+        Ok(())
+    }
+
+    fn ext_int_pending(&self) -> bool {
+        self.lines.iter().any(|(source, line)| {
+            self.meie[*source] & Self::INTEN_MASK != 0
+                && self.priority_above_threshold(*source)
+                && self.gateway_pending(*source, line)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+
+    const SOURCE: usize = 5;
+
+    fn pic_with_line() -> (Pic, Rc<Cell<bool>>) {
+        let level = Rc::new(Cell::new(false));
+        let mut pic = Pic::new();
+        let line_level = level.clone();
+        pic.connect_irq(SOURCE, Box::new(move || line_level.get()));
+        (pic, level)
+    }
+
+    #[test]
+    fn test_meip() {
+        let (mut pic, level) = pic_with_line();
+        assert_eq!(pic.read(RvSize::Word, Pic::MEIP_START).unwrap(), 0);
+        level.set(true);
+        assert_eq!(
+            pic.read(RvSize::Word, Pic::MEIP_START).unwrap(),
+            1 << SOURCE
+        );
+
+        // Active-low polarity inverts the line
+        pic.write(RvSize::Word, Pic::MEIGWCTRL_START + 4 * SOURCE as u32, 1)
+            .unwrap();
+        assert_eq!(pic.read(RvSize::Word, Pic::MEIP_START).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_ext_int_pending() {
+        let (mut pic, level) = pic_with_line();
+        level.set(true);
+        assert!(!pic.ext_int_pending());
+
+        pic.write(RvSize::Word, Pic::MEIE_START + 4 * SOURCE as u32, 1)
+            .unwrap();
+        // Priority 0 never interrupts
+        assert!(!pic.ext_int_pending());
+
+        pic.write(RvSize::Word, Pic::MEIPL_START + 4 * SOURCE as u32, 15)
+            .unwrap();
+        assert!(pic.ext_int_pending());
+
+        level.set(false);
+        assert!(!pic.ext_int_pending());
+
+        // With reversed priority order, 15 is the lowest priority
+        level.set(true);
+        pic.write(RvSize::Word, Pic::MPICCFG, 1).unwrap();
+        assert!(!pic.ext_int_pending());
+    }
+
+    #[test]
+    fn test_invalid_access() {
+        let mut pic = Pic::new();
+        assert_eq!(
+            pic.read(RvSize::Byte, Pic::MEIPL_START),
+            Err(BusError::LoadAccessFault)
+        );
+        assert_eq!(
+            pic.write(RvSize::Word, 0x6000, 0),
+            Err(BusError::StoreAccessFault)
+        );
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
     iccm::Iccm,
     soc_reg::{DebugManufService, SocRegistersExternal},
     AsymEcc384, Csrng, Doe, EmuCtrl, HashSha256, HashSha512, HmacSha384, KeyVault, MailboxExternal,
-    MailboxInternal, MailboxRam, Sha512Accelerator, SocRegistersInternal, Uart,
+    MailboxInternal, MailboxRam, Pic, Sha512Accelerator, SocRegistersInternal, Uart,
 };
 use caliptra_emu_bus::{Clock, Ram, Rom};
 use caliptra_emu_derive::Bus;
@@ -290,6 +290,9 @@ pub struct CaliptraRootBus {
 
     #[peripheral(offset = 0x5000_0000, mask = 0x0fff_ffff)]
     pub dccm: Ram,
+
+    #[peripheral(offset = 0x6000_0000, mask = 0x0000_7fff)]
+    pub pic: Pic,
 }
 
 impl CaliptraRootBus {
@@ -313,6 +316,13 @@ impl CaliptraRootBus {
 
         let sha512 = HashSha512::new(clock, key_vault.clone());
 
+        let mut pic = Pic::new();
+        let notif_soc_reg = soc_reg.clone();
+        pic.connect_irq(
+            Pic::SOC_IFC_NOTIF_IRQ,
+            Box::new(move || notif_soc_reg.notif_irq()),
+        );
+
         Self {
             rom,
             doe: Doe::new(clock, key_vault.clone(), soc_reg.clone()),
@@ -330,6 +340,7 @@ impl CaliptraRootBus {
             mailbox,
             sha512_acc: Sha512Accelerator::new(clock, mailbox_ram),
             csrng: Csrng::new(itrng_nibbles.unwrap()),
+            pic,
         }
     }
 
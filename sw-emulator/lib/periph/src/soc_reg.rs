@@ -213,6 +213,19 @@ register_bitfields! [
         ERROR_WDT_TIMER1_TIMEOUT_STS OFFSET(6) NUMBITS(1) [],
         ERROR_WDT_TIMER2_TIMEOUT_STS OFFSET(7) NUMBITS(1) [],
         RSVD OFFSET(8) NUMBITS(24) [],
+    ],
+
+    /// GlobalIntrEn
+    GlobalIntrEn [
+        ERROR_EN OFFSET(0) NUMBITS(1) [],
+        NOTIF_EN OFFSET(1) NUMBITS(1) [],
+        RSVD OFFSET(2) NUMBITS(30) [],
+    ],
+
+    /// NotifIntrT
+    NotifIntrT [
+        NOTIF_CMD_AVAIL_STS OFFSET(0) NUMBITS(1) [],
+        RSVD OFFSET(1) NUMBITS(31) [],
     ]
 ];
 
@@ -247,6 +260,12 @@ impl SocRegistersInternal {
             ))),
         }
     }
+    /// Returns true if the soc_ifc notification interrupt line to the PIC
+    /// is asserted.
+    pub fn notif_irq(&self) -> bool {
+        self.regs.borrow().notif_irq()
+    }
+
     pub fn is_debug_locked(&self) -> bool {
         let reg = &self.regs.borrow().cptra_security_state.reg;
         reg.read(SecurityState::DEBUG_LOCKED) != 0
@@ -567,7 +586,7 @@ struct SocRegistersImpl {
 
     /// GLOBAL_INTR_EN_R Register
     #[register(offset = 0x0800)]
-    global_intr_en_r: ReadWriteRegister<u32>,
+    global_intr_en_r: ReadWriteRegister<u32, GlobalIntrEn::Register>,
 
     /// ERROR_INTR_EN_R Register
     #[register(offset = 0x0804)]
@@ -575,7 +594,7 @@ struct SocRegistersImpl {
 
     /// NOTIF_INTR_EN_R Register
     #[register(offset = 0x0808)]
-    notif_intr_en_r: ReadWriteRegister<u32>,
+    notif_intr_en_r: ReadWriteRegister<u32, NotifIntrT::Register>,
 
     /// ERROR_GLOBAL_INTR_R Register
     #[register(offset = 0x080c)]
@@ -589,6 +608,14 @@ struct SocRegistersImpl {
     #[register(offset = 0x0814)]
     error_internal_intr_r: ReadWriteRegister<u32, ErrorIntrT::Register>,
 
+    /// NOTIF_INTERNAL_INTR_R Register
+    #[register(
+        offset = 0x0818,
+        read_fn = on_read_notif_internal_intr_r,
+        write_fn = on_write_notif_internal_intr_r
+    )]
+    notif_internal_intr_r: ReadWriteRegister<u32, NotifIntrT::Register>,
+
     /// Mailbox
     mailbox: MailboxInternal,
 
@@ -713,6 +740,7 @@ impl SocRegistersImpl {
             error_global_intr_r: ReadWriteRegister::new(0),
             notif_global_intr_r: ReadWriteRegister::new(0),
             error_internal_intr_r: ReadWriteRegister::new(0),
+            notif_internal_intr_r: ReadWriteRegister::new(0),
             mailbox,
             iccm,
             timer: Timer::new(clock),
@@ -916,6 +944,41 @@ impl SocRegistersImpl {
         Ok(())
     }
 
+    fn on_read_notif_internal_intr_r(&mut self, _size: RvSize) -> Result<u32, BusError> {
+        Ok(self.notif_internal_intr_sts())
+    }
+
+    /// Status bits are write-1-to-clear
+    fn on_write_notif_internal_intr_r(
+        &mut self,
+        _size: RvSize,
+        val: RvData,
+    ) -> Result<(), BusError> {
+        let clear = InMemoryRegister::<u32, NotifIntrT::Register>::new(val);
+        if clear.is_set(NotifIntrT::NOTIF_CMD_AVAIL_STS) {
+            self.mailbox.clear_cmd_avail_sts();
+        }
+        self.notif_internal_intr_r
+            .reg
+            .set(self.notif_internal_intr_r.reg.get() & !val);
+        Ok(())
+    }
+
+    /// The command-available status is latched by the mailbox when the SoC
+    /// sets the execute bit.
+    fn notif_internal_intr_sts(&self) -> u32 {
+        let mut sts = self.notif_internal_intr_r.reg.get();
+        if self.mailbox.cmd_avail_sts() {
+            sts |= NotifIntrT::NOTIF_CMD_AVAIL_STS::SET.value;
+        }
+        sts
+    }
+
+    fn notif_irq(&self) -> bool {
+        self.global_intr_en_r.reg.is_set(GlobalIntrEn::NOTIF_EN)
+            && (self.notif_internal_intr_sts() & self.notif_intr_en_r.reg.get()) != 0
+    }
+
     fn on_write_wdt_timer1_en(&mut self, _size: RvSize, val: RvData) -> Result<(), BusError> {
         self.cptra_wdt_timer1_en.reg.set(val);
 
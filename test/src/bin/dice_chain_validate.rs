@@ -0,0 +1,160 @@
+// Licensed under the Apache-2.0 license
+
+//! Validate a Caliptra DICE certificate chain against the TCG DICE profile.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::{bail, Context};
+use caliptra_image_types::ImageBundle;
+use caliptra_test::derive::DoeInput;
+use caliptra_test::dice_chain::{
+    validate_dice_chain, DiceChain, DiceChainExpectations, ExpectedFwids,
+};
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command};
+
+fn read(args: &ArgMatches, name: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    args.get_one::<PathBuf>(name)
+        .map(|path| {
+            std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))
+        })
+        .transpose()
+}
+
+fn hex_arg(args: &ArgMatches, name: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    args.get_one::<String>(name)
+        .map(|s| hex::decode(s).with_context(|| format!("--{name} is not valid hex")))
+        .transpose()
+}
+
+/// Parse a hex argument as big-endian 32-bit words, as written to the fuse
+/// registers.
+fn words_arg<const N: usize>(args: &ArgMatches, name: &str) -> anyhow::Result<Option<[u32; N]>> {
+    let Some(bytes) = hex_arg(args, name)? else {
+        return Ok(None);
+    };
+    if bytes.len() != N * 4 {
+        bail!("--{name} must be {} bytes", N * 4);
+    }
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Ok(Some(words))
+}
+
+fn run(args: &ArgMatches) -> anyhow::Result<bool> {
+    let idevid_csr = read(args, "idevid-csr")?;
+    let idevid_cert = read(args, "idevid-cert")?;
+    let ldevid_cert = read(args, "ldevid-cert")?;
+    let fmc_alias_cert = read(args, "fmc-alias-cert")?;
+    let rt_alias_cert = read(args, "rt-alias-cert")?;
+    let dpe_leaf_cert = read(args, "dpe-leaf-cert")?;
+
+    let fwids = read(args, "image")?
+        .map(|image| -> anyhow::Result<_> {
+            let bundle = ImageBundle::from_bytes(&image).context("Failed to parse image")?;
+            Ok(ExpectedFwids::from_manifest(&bundle.manifest))
+        })
+        .transpose()?;
+
+    let uds_seed = words_arg(args, "uds-seed")?;
+    let field_entropy = words_arg(args, "field-entropy")?;
+    let obf_key = words_arg(args, "obf-key")?;
+    let doe_input = (args.get_flag("derive-keys")
+        || uds_seed.is_some()
+        || field_entropy.is_some()
+        || obf_key.is_some())
+    .then(|| {
+        let default = DoeInput::default();
+        DoeInput {
+            uds_seed: uds_seed.unwrap_or(default.uds_seed),
+            field_entropy_seed: field_entropy.unwrap_or(default.field_entropy_seed),
+            doe_obf_key: obf_key.unwrap_or(default.doe_obf_key),
+            ..default
+        }
+    });
+
+    let report = validate_dice_chain(
+        &DiceChain {
+            idevid_csr: idevid_csr.as_deref(),
+            idevid_cert: idevid_cert.as_deref(),
+            ldevid_cert: ldevid_cert.as_deref(),
+            fmc_alias_cert: fmc_alias_cert.as_deref(),
+            rt_alias_cert: rt_alias_cert.as_deref(),
+            dpe_leaf_cert: dpe_leaf_cert.as_deref(),
+        },
+        &DiceChainExpectations {
+            fwids,
+            ueid: hex_arg(args, "ueid")?,
+            idevid_key_id: hex_arg(args, "idevid-key-id")?,
+            doe_input,
+            pcr0_input: None,
+        },
+    );
+    print!("{report}");
+    Ok(report.is_ok())
+}
+
+fn main() -> ExitCode {
+    let cert = |name: &'static str, help: &'static str| {
+        clap::Arg::new(name)
+            .long(name)
+            .value_name("FILE")
+            .help(help)
+            .required(false)
+            .value_parser(value_parser!(PathBuf))
+    };
+    let args = Command::new("dice-chain-validate")
+        .about("Validate a Caliptra DICE certificate chain")
+        .arg(cert("idevid-csr", "DER encoded IDevID CSR"))
+        .arg(cert("idevid-cert", "DER encoded IDevID certificate"))
+        .arg(cert("ldevid-cert", "DER encoded LDevID certificate"))
+        .arg(cert("fmc-alias-cert", "DER encoded FMC alias certificate"))
+        .arg(cert("rt-alias-cert", "DER encoded RT alias certificate"))
+        .arg(cert("dpe-leaf-cert", "DER encoded DPE leaf certificate"))
+        .arg(
+            arg!(--"image" <FILE> "Firmware image bundle the alias certificates must measure")
+                .required(false)
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"ueid" <HEX> "Expected UEID")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"idevid-key-id" <HEX> "IDevID key identifier programmed in the fuses")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"derive-keys" "Check the IDevID and LDevID keys derived from the fuse inputs")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            arg!(--"uds-seed" <HEX> "UDS seed fuses, as big-endian words")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"field-entropy" <HEX> "Field entropy fuses, as big-endian words")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .arg(
+            arg!(--"obf-key" <HEX> "DOE obfuscation key, as big-endian words")
+                .required(false)
+                .value_parser(value_parser!(String)),
+        )
+        .get_matches();
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,720 @@
+// Licensed under the Apache-2.0 license
+
+//! Host-side validation of a Caliptra DICE certificate chain against the TCG
+//! DICE profile.
+//!
+//! Every check is run independently and all failures are reported, so a
+//! single invocation explains everything that is wrong with a chain.
+
+use std::fmt;
+
+use caliptra_image_types::ImageManifest;
+use openssl::{
+    pkey::{PKey, Public},
+    sha::{sha1, sha256, sha384},
+    x509::{X509Req, X509},
+};
+use x509_parser::{
+    certificate::X509Certificate,
+    certification_request::X509CertificationRequest,
+    cri_attributes::ParsedCriAttribute,
+    extensions::{ParsedExtension, X509Extension},
+    oid_registry::asn1_rs::oid,
+    prelude::FromDer,
+};
+use zerocopy::AsBytes;
+
+use crate::{
+    crypto::pubkey_ecdsa_der,
+    derive::{self, DoeInput, DoeOutput, FmcAliasKey, IDevId, LDevId, Pcr0, Pcr0Input},
+    swap_word_bytes,
+    x509::{DiceFwid, DiceTcbInfo},
+};
+
+const SHA384_OID: asn1::ObjectIdentifier = asn1::oid!(2, 16, 840, 1, 101, 3, 4, 2, 2);
+
+/// A layer of the DICE chain
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiceLayer {
+    IDevIdCsr,
+    IDevId,
+    LDevId,
+    FmcAlias,
+    RtAlias,
+    DpeLeaf,
+}
+
+impl DiceLayer {
+    fn name(self) -> &'static str {
+        match self {
+            Self::IDevIdCsr => "IDevID CSR",
+            Self::IDevId => "IDevID",
+            Self::LDevId => "LDevID",
+            Self::FmcAlias => "FMC alias",
+            Self::RtAlias => "RT alias",
+            Self::DpeLeaf => "DPE leaf",
+        }
+    }
+
+    /// Path length the Caliptra certificate templates use for this layer
+    fn template_path_len(self) -> Option<u32> {
+        match self {
+            Self::IDevIdCsr => Some(5),
+            Self::LDevId => Some(4),
+            Self::FmcAlias => Some(3),
+            Self::RtAlias => Some(2),
+            Self::IDevId | Self::DpeLeaf => None,
+        }
+    }
+
+    /// Whether the certificate is generated by Caliptra firmware (and so
+    /// carries a UEID and a subject key identifier derived from its key)
+    fn generated_by_caliptra(self) -> bool {
+        matches!(
+            self,
+            Self::IDevIdCsr | Self::LDevId | Self::FmcAlias | Self::RtAlias
+        )
+    }
+}
+
+impl fmt::Display for DiceLayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// DER encoded certificates (and CSR) of a DICE chain. Absent entries are
+/// skipped, along with the checks linking them to their neighbours.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DiceChain<'a> {
+    pub idevid_csr: Option<&'a [u8]>,
+    pub idevid_cert: Option<&'a [u8]>,
+    pub ldevid_cert: Option<&'a [u8]>,
+    pub fmc_alias_cert: Option<&'a [u8]>,
+    pub rt_alias_cert: Option<&'a [u8]>,
+    pub dpe_leaf_cert: Option<&'a [u8]>,
+}
+
+/// FWIDs and SVNs the alias certificates are expected to report
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExpectedFwids {
+    pub fmc_digest: [u8; 48],
+    pub fmc_svn: u32,
+    pub rt_digest: [u8; 48],
+    pub rt_svn: u32,
+}
+
+impl ExpectedFwids {
+    pub fn from_manifest(manifest: &ImageManifest) -> Self {
+        Self {
+            fmc_digest: swap_word_bytes(&manifest.fmc.digest)
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+            fmc_svn: manifest.fmc.svn,
+            rt_digest: swap_word_bytes(&manifest.runtime.digest)
+                .as_bytes()
+                .try_into()
+                .unwrap(),
+            rt_svn: manifest.runtime.svn,
+        }
+    }
+}
+
+/// Optional expectations the chain is checked against
+#[derive(Clone, Debug, Default)]
+pub struct DiceChainExpectations {
+    /// Image the alias certificates must measure
+    pub fwids: Option<ExpectedFwids>,
+
+    /// UEID every Caliptra generated certificate must carry
+    pub ueid: Option<Vec<u8>>,
+
+    /// IDevID key identifier, when the fuses select `X509KeyIdAlgo::Fuse`.
+    /// Otherwise any of the SHA based key identifiers is accepted.
+    pub idevid_key_id: Option<Vec<u8>>,
+
+    /// Fuse and silicon secrets used to recompute the IDevID and LDevID keys
+    pub doe_input: Option<DoeInput>,
+
+    /// PCR0 inputs used (together with `doe_input`) to recompute the FMC
+    /// alias key
+    pub pcr0_input: Option<Pcr0Input>,
+}
+
+/// A failed chain check
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiceChainFailure {
+    pub layer: DiceLayer,
+    pub check: &'static str,
+    pub detail: String,
+}
+
+/// Result of validating a DICE chain
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DiceChainReport {
+    pub failures: Vec<DiceChainFailure>,
+}
+
+impl DiceChainReport {
+    /// Whether all checks passed
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Whether `check` failed for `layer`
+    pub fn contains(&self, layer: DiceLayer, check: &str) -> bool {
+        self.failures
+            .iter()
+            .any(|f| f.layer == layer && f.check == check)
+    }
+
+    fn fail(&mut self, layer: DiceLayer, check: &'static str, detail: impl Into<String>) {
+        self.failures.push(DiceChainFailure {
+            layer,
+            check,
+            detail: detail.into(),
+        });
+    }
+
+    fn expect(
+        &mut self,
+        ok: bool,
+        layer: DiceLayer,
+        check: &'static str,
+        detail: impl Into<String>,
+    ) {
+        if !ok {
+            self.fail(layer, check, detail);
+        }
+    }
+}
+
+impl fmt::Display for DiceChainReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return writeln!(f, "All checks passed");
+        }
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "{}: {} check failed: {}",
+                failure.layer, failure.check, failure.detail
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct BasicConstraints {
+    critical: bool,
+    ca: bool,
+    path_len: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct Extensions {
+    authority_key_id: Option<Vec<u8>>,
+    subject_key_id: Option<Vec<u8>>,
+    basic_constraints: Option<BasicConstraints>,
+    key_cert_sign: bool,
+    digital_signature: bool,
+    ueid: Option<Vec<u8>>,
+}
+
+impl Extensions {
+    fn parse(exts: &[X509Extension]) -> Result<Self, String> {
+        let mut result = Self::default();
+        for ext in exts {
+            match ext.parsed_extension() {
+                ParsedExtension::AuthorityKeyIdentifier(aki) => {
+                    result.authority_key_id = aki.key_identifier.as_ref().map(|k| k.0.to_vec());
+                }
+                ParsedExtension::SubjectKeyIdentifier(ski) => {
+                    result.subject_key_id = Some(ski.0.to_vec());
+                }
+                ParsedExtension::BasicConstraints(bc) => {
+                    result.basic_constraints = Some(BasicConstraints {
+                        critical: ext.critical,
+                        ca: bc.ca,
+                        path_len: bc.path_len_constraint,
+                    });
+                }
+                ParsedExtension::KeyUsage(ku) => {
+                    result.key_cert_sign = ku.key_cert_sign();
+                    result.digital_signature = ku.digital_signature();
+                }
+                ParsedExtension::UnsupportedExtension { oid }
+                    if oid == &oid!(2.23.133 .5 .4 .4) =>
+                {
+                    let ueid = asn1::parse(ext.value, |d| {
+                        d.read_element::<asn1::Sequence>()?
+                            .parse(|d| d.read_element::<&[u8]>())
+                    })
+                    .map_err(|e| format!("malformed UEID extension: {e:?}"))?;
+                    result.ueid = Some(ueid.to_vec());
+                }
+                ParsedExtension::ParseError { error } => {
+                    return Err(format!("malformed extension {}: {error:?}", ext.oid));
+                }
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// A parsed certificate or CSR
+struct Link<'a> {
+    layer: DiceLayer,
+    der: &'a [u8],
+    pub_key: PKey<Public>,
+    cert: Option<X509>,
+    exts: Extensions,
+}
+
+fn parse_csr(der: &[u8]) -> Result<(PKey<Public>, Extensions), String> {
+    let req = X509Req::from_der(der).map_err(|e| e.to_string())?;
+    let pub_key = req.public_key().map_err(|e| e.to_string())?;
+    if !req.verify(&pub_key).map_err(|e| e.to_string())? {
+        return Err("CSR self-signature is invalid".into());
+    }
+    let (_, parsed) = X509CertificationRequest::from_der(der).map_err(|e| e.to_string())?;
+    let exts = parsed
+        .certification_request_info
+        .iter_attributes()
+        .find_map(|attr| match attr.parsed_attribute() {
+            ParsedCriAttribute::ExtensionRequest(requested) => Some(&requested.extensions[..]),
+            _ => None,
+        })
+        .unwrap_or_default();
+    Ok((pub_key, Extensions::parse(exts)?))
+}
+
+fn parse_cert(der: &[u8]) -> Result<(PKey<Public>, X509, Extensions), String> {
+    let cert = X509::from_der(der).map_err(|e| e.to_string())?;
+    let pub_key = cert.public_key().map_err(|e| e.to_string())?;
+    let (_, parsed) = X509Certificate::from_der(der).map_err(|e| e.to_string())?;
+    Ok((pub_key, cert, Extensions::parse(parsed.extensions())?))
+}
+
+/// Validate `chain` against the Caliptra DICE profile and `expected`.
+///
+/// # Arguments
+///
+/// * `chain` - Certificates to validate
+/// * `expected` - Optional expected image measurements, UEID and fuse inputs
+///
+/// # Returns
+///
+/// * `DiceChainReport` - Every failed check
+pub fn validate_dice_chain(chain: &DiceChain, expected: &DiceChainExpectations) -> DiceChainReport {
+    let mut report = DiceChainReport::default();
+
+    let entries = [
+        (DiceLayer::IDevIdCsr, chain.idevid_csr),
+        (DiceLayer::IDevId, chain.idevid_cert),
+        (DiceLayer::LDevId, chain.ldevid_cert),
+        (DiceLayer::FmcAlias, chain.fmc_alias_cert),
+        (DiceLayer::RtAlias, chain.rt_alias_cert),
+        (DiceLayer::DpeLeaf, chain.dpe_leaf_cert),
+    ];
+    let mut links = vec![];
+    for (layer, der) in entries {
+        let Some(der) = der else {
+            continue;
+        };
+        let parsed = if layer == DiceLayer::IDevIdCsr {
+            parse_csr(der).map(|(pub_key, exts)| (pub_key, None, exts))
+        } else {
+            parse_cert(der).map(|(pub_key, cert, exts)| (pub_key, Some(cert), exts))
+        };
+        match parsed {
+            Ok((pub_key, cert, exts)) => links.push(Link {
+                layer,
+                der,
+                pub_key,
+                cert,
+                exts,
+            }),
+            Err(e) => report.fail(layer, "parse", e),
+        }
+    }
+
+    check_linkage(&mut report, &links, expected.idevid_key_id.as_deref());
+    check_constraints(&mut report, &links);
+    check_ueid(&mut report, &links, expected.ueid.as_deref());
+    if let Some(fwids) = &expected.fwids {
+        check_tcb_info(&mut report, &links, fwids);
+    }
+    if let Some(doe_input) = &expected.doe_input {
+        check_derived_keys(&mut report, &links, doe_input, expected.pcr0_input.as_ref());
+    }
+    report
+}
+
+fn find<'a, 'b>(links: &'b [Link<'a>], layer: DiceLayer) -> Option<&'b Link<'a>> {
+    links.iter().find(|l| l.layer == layer)
+}
+
+/// The link holding the key that signs `layer`
+fn issuer<'a, 'b>(links: &'b [Link<'a>], layer: DiceLayer) -> Option<&'b Link<'a>> {
+    match layer {
+        DiceLayer::IDevIdCsr | DiceLayer::IDevId => None,
+        DiceLayer::LDevId => {
+            find(links, DiceLayer::IDevId).or_else(|| find(links, DiceLayer::IDevIdCsr))
+        }
+        DiceLayer::FmcAlias => find(links, DiceLayer::LDevId),
+        DiceLayer::RtAlias => find(links, DiceLayer::FmcAlias),
+        DiceLayer::DpeLeaf => find(links, DiceLayer::RtAlias),
+    }
+}
+
+/// Key identifiers the ROM may use for the IDevID key, depending on the
+/// `X509KeyIdAlgo` fuse.
+fn idevid_key_ids(pub_key: &PKey<Public>, fuse_key_id: Option<&[u8]>) -> Vec<Vec<u8>> {
+    if let Some(key_id) = fuse_key_id {
+        return vec![key_id.to_vec()];
+    }
+    let der = pubkey_ecdsa_der(pub_key);
+    vec![
+        sha1(&der).to_vec(),
+        sha256(&der)[..20].to_vec(),
+        sha384(&der)[..20].to_vec(),
+    ]
+}
+
+/// Check signatures and AKI/SKI linkage between adjacent layers.
+fn check_linkage(report: &mut DiceChainReport, links: &[Link], idevid_key_id: Option<&[u8]>) {
+    if let (Some(csr), Some(cert)) = (
+        find(links, DiceLayer::IDevIdCsr),
+        find(links, DiceLayer::IDevId),
+    ) {
+        report.expect(
+            csr.pub_key.public_eq(&cert.pub_key),
+            DiceLayer::IDevId,
+            "public key",
+            "IDevID certificate key does not match the IDevID CSR key",
+        );
+    }
+
+    for link in links {
+        if link.layer.generated_by_caliptra() && link.cert.is_some() {
+            let expected = derive::key_id(&link.pub_key);
+            report.expect(
+                link.exts.subject_key_id.as_deref() == Some(&expected[..]),
+                link.layer,
+                "subject key identifier",
+                format!(
+                    "expected {:02x?}, found {:02x?}",
+                    expected, link.exts.subject_key_id
+                ),
+            );
+        }
+
+        let (Some(issuer), Some(cert)) = (issuer(links, link.layer), &link.cert) else {
+            continue;
+        };
+        if !cert.verify(&issuer.pub_key).unwrap_or(false) {
+            report.fail(
+                link.layer,
+                "signature",
+                format!("not signed by the {} key", issuer.layer),
+            );
+        }
+
+        // A CSR carries no subject key identifier; the ROM derives the
+        // IDevID key identifier as selected by the fuses instead.
+        let issuer_key_ids: Vec<Vec<u8>> = match issuer.cert {
+            Some(_) => issuer.exts.subject_key_id.iter().cloned().collect(),
+            None => idevid_key_ids(&issuer.pub_key, idevid_key_id),
+        };
+        let aki = &link.exts.authority_key_id;
+        if !aki
+            .as_ref()
+            .map_or(false, |aki| issuer_key_ids.contains(aki))
+        {
+            report.fail(
+                link.layer,
+                "authority key identifier",
+                format!(
+                    "{aki:02x?} does not match the {} key identifier {issuer_key_ids:02x?}",
+                    issuer.layer
+                ),
+            );
+        }
+    }
+}
+
+/// Check basicConstraints and key usages.
+fn check_constraints(report: &mut DiceChainReport, links: &[Link]) {
+    // The IDevID CSR and certificate certify the same key, so only one of
+    // them counts towards path lengths.
+    let path: Vec<&Link> = links
+        .iter()
+        .filter(|l| l.layer != DiceLayer::IDevIdCsr || find(links, DiceLayer::IDevId).is_none())
+        .collect();
+
+    for link in links {
+        let layer = link.layer;
+        let bc = link.exts.basic_constraints.clone().unwrap_or_default();
+
+        if layer == DiceLayer::DpeLeaf {
+            report.expect(
+                link.exts.digital_signature,
+                layer,
+                "key usage",
+                "digitalSignature is not set",
+            );
+            continue;
+        }
+
+        report.expect(
+            link.exts.basic_constraints.is_some() && bc.critical && bc.ca,
+            layer,
+            "basic constraints",
+            format!("expected a critical CA constraint, found {bc:?}"),
+        );
+        if let Some(path_len) = layer.template_path_len() {
+            report.expect(
+                bc.path_len == Some(path_len),
+                layer,
+                "path length",
+                format!("expected {path_len}, found {:?}", bc.path_len),
+            );
+        }
+        if let (Some(path_len), Some(pos)) =
+            (bc.path_len, path.iter().position(|l| l.layer == layer))
+        {
+            // pathLenConstraint limits the number of intermediate CAs below
+            // this certificate; the final certificate is not counted.
+            let intermediates = (path.len() - pos).saturating_sub(2) as u32;
+            report.expect(
+                path_len >= intermediates,
+                layer,
+                "path length",
+                format!("{path_len} does not allow the {intermediates} certificates below it"),
+            );
+        }
+
+        report.expect(
+            link.exts.key_cert_sign,
+            layer,
+            "key usage",
+            "keyCertSign is not set",
+        );
+        if layer == DiceLayer::RtAlias {
+            report.expect(
+                link.exts.digital_signature,
+                layer,
+                "key usage",
+                "digitalSignature is not set",
+            );
+        }
+    }
+}
+
+/// Check every Caliptra generated certificate carries the same UEID.
+fn check_ueid(report: &mut DiceChainReport, links: &[Link], expected: Option<&[u8]>) {
+    let mut first: Option<(DiceLayer, &[u8])> = None;
+    for link in links.iter().filter(|l| l.layer.generated_by_caliptra()) {
+        let Some(ueid) = link.exts.ueid.as_deref() else {
+            report.fail(link.layer, "UEID", "tcg-dice-Ueid extension is missing");
+            continue;
+        };
+        if let Some(expected) = expected {
+            report.expect(
+                ueid == expected,
+                link.layer,
+                "UEID",
+                format!("expected {expected:02x?}, found {ueid:02x?}"),
+            );
+        } else if let Some((first_layer, first_ueid)) = first {
+            report.expect(
+                ueid == first_ueid,
+                link.layer,
+                "UEID",
+                format!("{ueid:02x?} differs from the {first_layer} UEID {first_ueid:02x?}"),
+            );
+        }
+        first.get_or_insert((link.layer, ueid));
+    }
+}
+
+fn check_fwid(
+    report: &mut DiceChainReport,
+    layer: DiceLayer,
+    tcb_info: &DiceTcbInfo,
+    digest: &[u8; 48],
+    svn: u32,
+) {
+    let expected_fwids = [DiceFwid {
+        hash_alg: SHA384_OID,
+        digest: digest.to_vec(),
+    }];
+    report.expect(
+        tcb_info.fwids == expected_fwids,
+        layer,
+        "FWID",
+        format!("expected {expected_fwids:?}, found {:?}", tcb_info.fwids),
+    );
+    let expected_svn = 0x100 | (svn & 0xff);
+    report.expect(
+        tcb_info.svn == Some(expected_svn),
+        layer,
+        "SVN",
+        format!("expected 0x{expected_svn:x}, found {:x?}", tcb_info.svn),
+    );
+}
+
+/// Check the TcbInfo FWIDs and SVNs of the alias certificates.
+fn check_tcb_info(report: &mut DiceChainReport, links: &[Link], expected: &ExpectedFwids) {
+    if let Some(fmc) = find(links, DiceLayer::FmcAlias) {
+        match DiceTcbInfo::find_multiple_in_cert(fmc.der) {
+            Ok(tcb_infos) => {
+                report.expect(
+                    tcb_infos
+                        .iter()
+                        .any(|t| t.model.as_deref() == Some("Device")),
+                    fmc.layer,
+                    "MultiTcbInfo",
+                    "device TcbInfo is missing",
+                );
+                match tcb_infos.iter().find(|t| t.model.as_deref() == Some("FMC")) {
+                    Some(t) => {
+                        check_fwid(report, fmc.layer, t, &expected.fmc_digest, expected.fmc_svn)
+                    }
+                    None => report.fail(fmc.layer, "MultiTcbInfo", "FMC TcbInfo is missing"),
+                }
+            }
+            Err(e) => report.fail(fmc.layer, "MultiTcbInfo", format!("{e:?}")),
+        }
+    }
+    if let Some(rt) = find(links, DiceLayer::RtAlias) {
+        match DiceTcbInfo::find_single_in_cert(rt.der) {
+            Ok(Some(t)) => check_fwid(report, rt.layer, &t, &expected.rt_digest, expected.rt_svn),
+            Ok(None) => report.fail(rt.layer, "TcbInfo", "tcg-dice-TcbInfo extension is missing"),
+            Err(e) => report.fail(rt.layer, "TcbInfo", format!("{e:?}")),
+        }
+    }
+}
+
+/// Check the certified keys match the keys recomputed from fuse inputs.
+fn check_derived_keys(
+    report: &mut DiceChainReport,
+    links: &[Link],
+    doe_input: &DoeInput,
+    pcr0_input: Option<&Pcr0Input>,
+) {
+    let doe_output = DoeOutput::generate(doe_input);
+    let idevid_key = IDevId::derive(&doe_output).derive_public_key();
+    let ldevid = LDevId::derive(&doe_output);
+
+    let mut expected = vec![
+        (DiceLayer::IDevIdCsr, idevid_key.clone()),
+        (DiceLayer::IDevId, idevid_key),
+        (DiceLayer::LDevId, ldevid.derive_public_key()),
+    ];
+    if let Some(pcr0_input) = pcr0_input {
+        expected.push((
+            DiceLayer::FmcAlias,
+            FmcAliasKey::derive(&Pcr0::derive(pcr0_input), &ldevid).derive_public_key(),
+        ));
+    }
+
+    for (layer, key) in expected {
+        if let Some(link) = find(links, layer) {
+            report.expect(
+                link.pub_key.public_eq(&key),
+                layer,
+                "derived key",
+                "public key does not match the key derived from the fuse inputs",
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+const IDEVID_CSR: &[u8] =
+    include_bytes!("../tests/caliptra_integration_tests/smoke_testdata/idevid_csr.der");
+#[cfg(test)]
+const LDEVID_CERT: &[u8] =
+    include_bytes!("../tests/caliptra_integration_tests/smoke_testdata/ldevid_cert.der");
+
+#[test]
+fn test_validate_dice_chain() {
+    let report = validate_dice_chain(
+        &DiceChain {
+            idevid_csr: Some(IDEVID_CSR),
+            ldevid_cert: Some(LDEVID_CERT),
+            ..Default::default()
+        },
+        &DiceChainExpectations {
+            ueid: Some(vec![0; 17]),
+            doe_input: Some(DoeInput::default()),
+            ..Default::default()
+        },
+    );
+    assert!(report.is_ok(), "{report}");
+}
+
+#[test]
+fn test_validate_dice_chain_wrong_keys() {
+    let report = validate_dice_chain(
+        &DiceChain {
+            idevid_csr: Some(IDEVID_CSR),
+            ldevid_cert: Some(LDEVID_CERT),
+            ..Default::default()
+        },
+        &DiceChainExpectations {
+            doe_input: Some(DoeInput {
+                uds_seed: [0x1234_5678; 12],
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+    assert!(report.contains(DiceLayer::IDevIdCsr, "derived key"));
+    assert!(report.contains(DiceLayer::LDevId, "derived key"));
+    assert_eq!(report.failures.len(), 2, "{report}");
+}
+
+#[test]
+fn test_validate_dice_chain_bad_signature() {
+    let mut ldevid_cert = LDEVID_CERT.to_vec();
+    let len = ldevid_cert.len();
+    ldevid_cert[len - 4] ^= 0x01;
+    let report = validate_dice_chain(
+        &DiceChain {
+            idevid_csr: Some(IDEVID_CSR),
+            ldevid_cert: Some(&ldevid_cert),
+            ..Default::default()
+        },
+        &DiceChainExpectations::default(),
+    );
+    assert_eq!(
+        report.failures,
+        vec![DiceChainFailure {
+            layer: DiceLayer::LDevId,
+            check: "signature",
+            detail: "not signed by the IDevID CSR key".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_validate_dice_chain_missing_issuer() {
+    // The LDevID certificate is not signed by its own key
+    let report = validate_dice_chain(
+        &DiceChain {
+            ldevid_cert: Some(LDEVID_CERT),
+            fmc_alias_cert: Some(LDEVID_CERT),
+            ..Default::default()
+        },
+        &DiceChainExpectations::default(),
+    );
+    assert!(report.contains(DiceLayer::FmcAlias, "signature"));
+    assert!(report.contains(DiceLayer::FmcAlias, "authority key identifier"));
+    assert!(report.contains(DiceLayer::FmcAlias, "path length"));
+}
@@ -8,6 +8,7 @@ use caliptra_hw_model::{BootParams, DefaultHwModel, HwModel, InitParams};
 
 pub mod crypto;
 pub mod derive;
+pub mod dice_chain;
 mod redact;
 mod unwrap_single;
 pub mod x509;
@@ -15,51 +15,154 @@ Abstract:
 
 mod cert;
 mod code_gen;
+mod config;
 mod csr;
 mod tbs;
 mod x509;
 
 use code_gen::CodeGen;
+use config::{CertConfig, TemplateConfig};
 use x509::{EcdsaSha384Algo, Fwid, FwidParam, KeyUsage};
 
 use std::env;
 
+const IDEVID_CN: &str = "Caliptra 1.0 IDevID";
+const LDEVID_CN: &str = "Caliptra 1.0 LDevID";
+const FMC_ALIAS_CN: &str = "Caliptra 1.0 FMC Alias";
+const RT_ALIAS_CN: &str = "Caliptra 1.0 Rt Alias";
+
+/// Environment variable holding the path of the template configuration file
+const TEMPLATE_CONFIG_ENV: &str = "CALIPTRA_X509_TEMPLATE_CONFIG";
+
 // Main Entry point
 fn main() {
     let out_dir_os_str = env::var_os("OUT_DIR").unwrap();
     let out_dir = out_dir_os_str.to_str().unwrap();
 
-    gen_init_devid_csr(out_dir);
-    gen_local_devid_cert(out_dir);
-    gen_fmc_alias_cert(out_dir);
-    gen_rt_alias_cert(out_dir);
+    let config = load_template_config();
+
+    gen_init_devid_csr(out_dir, &config);
+    gen_init_devid_nonce_csr(out_dir, &config);
+    gen_local_devid_cert(out_dir, &config);
+    gen_fmc_alias_cert(out_dir, &config);
+    gen_rt_alias_cert(out_dir, &config);
+    gen_subject_names(out_dir, &config);
+    gen_idevid_issuer_name(out_dir, &config);
+}
+
+/// Load the template configuration from the file named by
+/// `TEMPLATE_CONFIG_ENV`, or the default configuration if the variable is not
+/// set.
+fn load_template_config() -> TemplateConfig {
+    println!("cargo:rerun-if-env-changed={TEMPLATE_CONFIG_ENV}");
+    let Some(path) = env::var_os(TEMPLATE_CONFIG_ENV) else {
+        return TemplateConfig::default();
+    };
+    println!("cargo:rerun-if-changed={}", path.to_string_lossy());
+
+    let config_str = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!("Failed to read {}: {e}", path.to_string_lossy());
+    });
+    let config: TemplateConfig = toml::from_str(&config_str).unwrap_or_else(|e| {
+        panic!("Failed to parse {}: {e}", path.to_string_lossy());
+    });
+    if let Err(e) = config.validate() {
+        panic!("Invalid X509 template configuration: {e}");
+    }
+    config
+}
+
+/// Apply the configured subject attributes and custom extensions of a
+/// certificate, and the subject attributes of its issuer
+fn apply_cert_config(
+    mut bldr: cert::CertTemplateBuilder<EcdsaSha384Algo>,
+    subject: &CertConfig,
+    issuer: &CertConfig,
+) -> cert::CertTemplateBuilder<EcdsaSha384Algo> {
+    for (oid, critical, value) in subject.custom_exts() {
+        bldr = bldr.add_custom_ext(&oid, critical, &value);
+    }
+    bldr.add_subject_name_entries(&subject.subject_entries())
+        .add_issuer_name_entries(&issuer.subject_entries())
+}
+
+/// Generate the subject common names, which firmware needs to construct the
+/// issuer names of certificates it does not generate from a template
+fn gen_subject_names(out_dir: &str, config: &TemplateConfig) {
+    let code = format!(
+        "pub const IDEVID_SUBJECT_CN: &str = {:?};\n\
+         pub const LDEVID_SUBJECT_CN: &str = {:?};\n\
+         pub const FMC_ALIAS_SUBJECT_CN: &str = {:?};\n\
+         pub const RT_ALIAS_SUBJECT_CN: &str = {:?};\n",
+        config.idevid.cn(IDEVID_CN),
+        config.ldevid.cn(LDEVID_CN),
+        config.fmc_alias.cn(FMC_ALIAS_CN),
+        config.rt_alias.cn(RT_ALIAS_CN),
+    );
+    std::fs::write(std::path::Path::new(out_dir).join("subject_names.rs"), code).unwrap();
+}
+
+/// Generate the expected issuer name of the IDevID certificate populated by
+/// the SoC. An empty name disables the issuer check.
+fn gen_idevid_issuer_name(out_dir: &str, config: &TemplateConfig) {
+    let code = format!(
+        "pub const IDEVID_ISSUER_NAME: &[u8] = &{:?};\n",
+        config.idevid_ca.issuer_der()
+    );
+    std::fs::write(
+        std::path::Path::new(out_dir).join("idevid_issuer_name.rs"),
+        code,
+    )
+    .unwrap();
 }
 
 /// Generated Initial DeviceId Cert Signing request Template
-fn gen_init_devid_csr(out_dir: &str) {
+fn gen_init_devid_csr(out_dir: &str, config: &TemplateConfig) {
     let mut usage = KeyUsage::default();
     usage.set_key_cert_sign(true);
-    let bldr = csr::CsrTemplateBuilder::<EcdsaSha384Algo>::new()
+    let mut bldr = csr::CsrTemplateBuilder::<EcdsaSha384Algo>::new()
         .add_basic_constraints_ext(true, 5)
         .add_key_usage_ext(usage)
         .add_ueid_ext(&[0xFF; 17]);
-    let template = bldr.tbs_template("Caliptra 1.0 IDevID");
+    for (oid, critical, value) in config.idevid.custom_exts() {
+        bldr = bldr.add_custom_ext(&oid, critical, &value);
+    }
+    let bldr = bldr.add_subject_name_entries(&config.idevid.subject_entries());
+    let template = bldr.tbs_template(config.idevid.cn(IDEVID_CN));
     CodeGen::gen_code("InitDevIdCsrTbs", template, out_dir);
 }
 
+/// Generate Initial DeviceId Cert Signing request Template carrying a nonce
+fn gen_init_devid_nonce_csr(out_dir: &str, config: &TemplateConfig) {
+    let mut usage = KeyUsage::default();
+    usage.set_key_cert_sign(true);
+    let mut bldr = csr::CsrTemplateBuilder::<EcdsaSha384Algo>::new()
+        .add_basic_constraints_ext(true, 5)
+        .add_key_usage_ext(usage)
+        .add_ueid_ext(&[0xFF; 17])
+        .add_nonce_attr(64);
+    for (oid, critical, value) in config.idevid.custom_exts() {
+        bldr = bldr.add_custom_ext(&oid, critical, &value);
+    }
+    let bldr = bldr.add_subject_name_entries(&config.idevid.subject_entries());
+    let template = bldr.tbs_template(config.idevid.cn(IDEVID_CN));
+    CodeGen::gen_code("InitDevIdNonceCsrTbs", template, out_dir);
+}
+
 /// Generate Local DeviceId Certificate Template
-fn gen_local_devid_cert(out_dir: &str) {
+fn gen_local_devid_cert(out_dir: &str, config: &TemplateConfig) {
     let mut usage = KeyUsage::default();
     usage.set_key_cert_sign(true);
     let bldr = cert::CertTemplateBuilder::<EcdsaSha384Algo>::new()
         .add_basic_constraints_ext(true, 4)
         .add_key_usage_ext(usage)
         .add_ueid_ext(&[0xFF; 17]);
-    let template = bldr.tbs_template("Caliptra 1.0 LDevID", "Caliptra 1.0 IDevID");
+    let bldr = apply_cert_config(bldr, &config.ldevid, &config.idevid);
+    let template = bldr.tbs_template(config.ldevid.cn(LDEVID_CN), config.idevid.cn(IDEVID_CN));
     CodeGen::gen_code("LocalDevIdCertTbs", template, out_dir);
 }
 
-fn gen_fmc_alias_cert(out_dir: &str) {
+fn gen_fmc_alias_cert(out_dir: &str, config: &TemplateConfig) {
     let mut usage = KeyUsage::default();
     usage.set_key_cert_sign(true);
     let bldr = cert::CertTemplateBuilder::<EcdsaSha384Algo>::new()
@@ -84,11 +187,15 @@ fn gen_fmc_alias_cert(out_dir: &str) {
                 },
             }],
         );
-    let template = bldr.tbs_template("Caliptra 1.0 FMC Alias", "Caliptra 1.0 LDevID");
+    let bldr = apply_cert_config(bldr, &config.fmc_alias, &config.ldevid);
+    let template = bldr.tbs_template(
+        config.fmc_alias.cn(FMC_ALIAS_CN),
+        config.ldevid.cn(LDEVID_CN),
+    );
     CodeGen::gen_code("FmcAliasCertTbs", template, out_dir);
 }
 
-fn gen_rt_alias_cert(out_dir: &str) {
+fn gen_rt_alias_cert(out_dir: &str, config: &TemplateConfig) {
     let mut usage = KeyUsage::default();
     // Add KeyCertSign to allow signing of other certs
     usage.set_key_cert_sign(true);
@@ -106,6 +213,10 @@ fn gen_rt_alias_cert(out_dir: &str) {
                 digest: &[0xCD; 48],
             },
         }]);
-    let template = bldr.tbs_template("Caliptra 1.0 Rt Alias", "Caliptra 1.0 FMC Alias");
+    let bldr = apply_cert_config(bldr, &config.rt_alias, &config.fmc_alias);
+    let template = bldr.tbs_template(
+        config.rt_alias.cn(RT_ALIAS_CN),
+        config.fmc_alias.cn(FMC_ALIAS_CN),
+    );
     CodeGen::gen_code("RtAliasCertTbs", template, out_dir);
 }
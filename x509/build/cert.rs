@@ -32,6 +32,8 @@ pub struct CertTemplateBuilder<Algo: SigningAlgorithm> {
     builder: X509Builder,
     exts: Stack<X509Extension>,
     params: Vec<CertTemplateParam>,
+    subject_entries: Vec<(String, String)>,
+    issuer_entries: Vec<(String, String)>,
 }
 
 impl<Algo: SigningAlgorithm> CertTemplateBuilder<Algo> {
@@ -42,9 +44,34 @@ impl<Algo: SigningAlgorithm> CertTemplateBuilder<Algo> {
             builder: X509Builder::new().unwrap(),
             exts: Stack::new().unwrap(),
             params: vec![],
+            subject_entries: vec![],
+            issuer_entries: vec![],
         }
     }
 
+    /// Add attributes to the subject name, following the common name and
+    /// serial number
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Attribute (field, value) pairs
+    pub fn add_subject_name_entries(mut self, entries: &[(String, String)]) -> Self {
+        self.subject_entries.extend_from_slice(entries);
+        self
+    }
+
+    /// Add attributes to the issuer name, following the common name and
+    /// serial number. These must match the subject name entries of the
+    /// issuer certificate.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Attribute (field, value) pairs
+    pub fn add_issuer_name_entries(mut self, entries: &[(String, String)]) -> Self {
+        self.issuer_entries.extend_from_slice(entries);
+        self
+    }
+
     /// Add X509 Basic Constraints Extension
     ///
     /// # Arguments
@@ -85,6 +112,20 @@ impl<Algo: SigningAlgorithm> CertTemplateBuilder<Algo> {
         self
     }
 
+    /// Add an extension with a fixed value
+    ///
+    /// # Arguments
+    ///
+    /// * `oid`      - Extension OID in dotted notation
+    /// * `critical` - Criticality of the extension
+    /// * `value`    - DER encoded extension value
+    pub fn add_custom_ext(mut self, oid: &str, critical: bool, value: &[u8]) -> Self {
+        self.exts
+            .push(x509::make_custom_ext(oid, critical, value))
+            .unwrap();
+        self
+    }
+
     pub fn add_fmc_dice_tcb_info_ext(
         mut self,
         device_fwids: &[FwidParam],
@@ -244,6 +285,7 @@ impl<Algo: SigningAlgorithm> CertTemplateBuilder<Algo> {
         subject_name
             .append_entry_by_text("serialNumber", &subject_key.hex_str())
             .unwrap();
+        x509::append_name_entries(&mut subject_name, &self.subject_entries);
         let subject_name = subject_name.build();
         self.builder.set_subject_name(&subject_name).unwrap();
         let param = CertTemplateParam {
@@ -258,6 +300,7 @@ impl<Algo: SigningAlgorithm> CertTemplateBuilder<Algo> {
         issuer_name
             .append_entry_by_text("serialNumber", &issuer_key.hex_str())
             .unwrap();
+        x509::append_name_entries(&mut issuer_name, &self.issuer_entries);
         let issuer_name = issuer_name.build();
         self.builder.set_issuer_name(&issuer_name).unwrap();
         let param = CertTemplateParam {
@@ -0,0 +1,324 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    config.rs
+
+Abstract:
+
+    File contains the optional build time configuration of the X509 templates.
+
+--*/
+
+use openssl::asn1::Asn1Object;
+use serde_derive::Deserialize;
+
+/// Extension OIDs generated by the templates themselves
+const RESERVED_EXT_OIDS: &[&str] = &[
+    "2.5.29.14",      // Subject Key Identifier
+    "2.5.29.15",      // Key Usage
+    "2.5.29.19",      // Basic Constraints
+    "2.5.29.35",      // Authority Key Identifier
+    "2.23.133.5.4.1", // tcg-dice-TcbInfo
+    "2.23.133.5.4.4", // tcg-dice-Ueid
+    "2.23.133.5.4.5", // tcg-dice-MultiTcbInfo
+];
+
+/// Subject name attributes generated by the templates themselves
+const RESERVED_NAME_FIELDS: &[&str] = &["CN", "commonName", "serialNumber"];
+
+/// Additional subject name attribute
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NameEntry {
+    /// Attribute short name or OID, e.g. "O" or "2.5.4.10"
+    pub field: String,
+
+    /// Attribute value
+    pub value: String,
+}
+
+/// Custom extension with a fixed value
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomExt {
+    /// Extension OID in dotted notation
+    pub oid: String,
+
+    /// Criticality of the extension
+    #[serde(default)]
+    pub critical: bool,
+
+    /// Hex encoded DER of the extension value (the contents of extnValue)
+    pub value: String,
+}
+
+/// Configuration of a single certificate (or CSR) template
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CertConfig {
+    /// Subject common name replacing the default
+    pub subject_cn: Option<String>,
+
+    /// Subject name attributes following the common name and serial number
+    pub subject: Vec<NameEntry>,
+
+    /// Custom extensions following the standard extensions
+    pub extensions: Vec<CustomExt>,
+}
+
+impl CertConfig {
+    /// Subject common name
+    pub fn cn<'a>(&'a self, default: &'a str) -> &'a str {
+        self.subject_cn.as_deref().unwrap_or(default)
+    }
+
+    /// Subject name attributes as (field, value) pairs
+    pub fn subject_entries(&self) -> Vec<(String, String)> {
+        self.subject
+            .iter()
+            .map(|e| (e.field.clone(), e.value.clone()))
+            .collect()
+    }
+
+    /// Custom extensions as (oid, critical, value) tuples
+    pub fn custom_exts(&self) -> Vec<(String, bool, Vec<u8>)> {
+        self.extensions
+            .iter()
+            .map(|e| (e.oid.clone(), e.critical, hex::decode(&e.value).unwrap()))
+            .collect()
+    }
+
+    fn validate(&self, name: &str) -> Result<(), String> {
+        for entry in &self.subject {
+            if RESERVED_NAME_FIELDS.contains(&entry.field.as_str()) {
+                return Err(format!(
+                    "[{name}] subject field {} is generated by the template",
+                    entry.field
+                ));
+            }
+        }
+        for ext in &self.extensions {
+            let oid = ext.oid.as_str();
+            let dotted = oid
+                .split('.')
+                .all(|arc| !arc.is_empty() && arc.bytes().all(|b| b.is_ascii_digit()));
+            if !dotted || Asn1Object::from_str(oid).is_err() {
+                return Err(format!("[{name}] invalid extension OID {oid}"));
+            }
+            if RESERVED_EXT_OIDS.contains(&oid) {
+                return Err(format!(
+                    "[{name}] extension {oid} is generated by the template"
+                ));
+            }
+            let value = hex::decode(&ext.value)
+                .map_err(|_| format!("[{name}] extension {oid} value is not valid hex"))?;
+            asn1::parse_single::<asn1::Tlv>(&value)
+                .map_err(|_| format!("[{name}] extension {oid} value is not a DER element"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Build time configuration of the X509 templates
+///
+/// ```toml
+/// [idevid]
+/// subject_cn = "Acme IDevID"
+/// subject = [{ field = "O", value = "Acme" }]
+///
+/// [[ldevid.extensions]]
+/// oid = "1.3.6.1.4.1.99999.1"
+/// critical = false
+/// value = "0c0461636d65"
+///
+/// [idevid_ca]
+/// issuer = "30123110300e06035504030c0741636d65204341"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TemplateConfig {
+    /// Initial DeviceId CSR
+    pub idevid: CertConfig,
+
+    /// Local DeviceId certificate
+    pub ldevid: CertConfig,
+
+    /// FMC Alias certificate
+    pub fmc_alias: CertConfig,
+
+    /// Runtime Alias certificate
+    pub rt_alias: CertConfig,
+
+    /// CA issuing the IDevID certificate populated by the SoC
+    pub idevid_ca: CaConfig,
+}
+
+/// Configuration of the CA issuing the IDevID certificate
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CaConfig {
+    /// Hex encoded DER of the CA name, which must match the issuer name of
+    /// the IDevID certificate byte for byte
+    pub issuer: Option<String>,
+}
+
+impl CaConfig {
+    /// DER encoded issuer name, empty if not configured
+    pub fn issuer_der(&self) -> Vec<u8> {
+        self.issuer
+            .as_deref()
+            .map(|issuer| hex::decode(issuer).unwrap())
+            .unwrap_or_default()
+    }
+
+    fn validate(&self, name: &str) -> Result<(), String> {
+        let Some(issuer) = &self.issuer else {
+            return Ok(());
+        };
+        let issuer =
+            hex::decode(issuer).map_err(|_| format!("[{name}] issuer is not valid hex"))?;
+        // A Name is a SEQUENCE of relative distinguished names
+        match asn1::parse_single::<asn1::Tlv>(&issuer) {
+            Ok(_) if issuer[0] == 0x30 => Ok(()),
+            _ => Err(format!("[{name}] issuer is not a DER encoded name")),
+        }
+    }
+}
+
+impl TemplateConfig {
+    /// Check that the configuration does not clash with what the templates
+    /// generate themselves
+    pub fn validate(&self) -> Result<(), String> {
+        self.idevid.validate("idevid")?;
+        self.ldevid.validate("ldevid")?;
+        self.fmc_alias.validate("fmc_alias")?;
+        self.rt_alias.validate("rt_alias")?;
+        self.idevid_ca.validate("idevid_ca")?;
+
+        // DPE encodes the issuer name of its leaf certificates from the RT
+        // alias common name and serial number only.
+        if !self.rt_alias.subject.is_empty() {
+            return Err(
+                "[rt_alias] subject attributes are not supported; DPE leaf issuer names \
+                 carry only the common name and serial number"
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(config: &str) -> Result<TemplateConfig, String> {
+        let config: TemplateConfig = toml::from_str(config).map_err(|e| e.to_string())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    #[test]
+    fn test_default_config() {
+        let config = parse("").unwrap();
+        assert_eq!(
+            config.idevid.cn("Caliptra 1.0 IDevID"),
+            "Caliptra 1.0 IDevID"
+        );
+        assert!(config.ldevid.subject_entries().is_empty());
+        assert!(config.ldevid.custom_exts().is_empty());
+        assert!(config.idevid_ca.issuer_der().is_empty());
+    }
+
+    #[test]
+    fn test_example_config() {
+        let config = parse(include_str!("../template-config.example.toml")).unwrap();
+        assert_eq!(config.idevid.cn("Caliptra 1.0 IDevID"), "Acme IDevID");
+        assert_eq!(
+            config.idevid.subject_entries(),
+            [
+                ("O".to_string(), "Acme".to_string()),
+                ("C".into(), "US".into())
+            ]
+        );
+        assert_eq!(
+            config.ldevid.custom_exts(),
+            [(
+                "1.3.6.1.4.1.99999.1".to_string(),
+                false,
+                b"\x0c\x04acme".to_vec()
+            )]
+        );
+        assert!(config.rt_alias.subject_entries().is_empty());
+        assert_eq!(
+            config.idevid_ca.issuer_der(),
+            hex::decode("30123110300e06035504030c0741636d65204341").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_rejected() {
+        assert!(parse("[idevid]\nsubject_name = \"Acme\"").is_err());
+        assert!(parse("[devid]\nsubject_cn = \"Acme\"").is_err());
+        assert!(
+            parse("[[idevid.extensions]]\noid = \"1.2.3\"\nvalue = \"0500\"\nflags = 1").is_err()
+        );
+    }
+
+    #[test]
+    fn test_invalid_config_rejected() {
+        let cases = [
+            (
+                "[idevid]\nsubject = [{ field = \"CN\", value = \"Acme\" }]",
+                "[idevid] subject field CN is generated by the template",
+            ),
+            (
+                "[fmc_alias]\nsubject = [{ field = \"serialNumber\", value = \"1\" }]",
+                "[fmc_alias] subject field serialNumber is generated by the template",
+            ),
+            (
+                "[[ldevid.extensions]]\noid = \"1.3.x\"\nvalue = \"0500\"",
+                "[ldevid] invalid extension OID 1.3.x",
+            ),
+            (
+                "[[ldevid.extensions]]\noid = \"1..3\"\nvalue = \"0500\"",
+                "[ldevid] invalid extension OID 1..3",
+            ),
+            (
+                "[[rt_alias.extensions]]\noid = \"2.5.29.15\"\nvalue = \"030204b0\"",
+                "[rt_alias] extension 2.5.29.15 is generated by the template",
+            ),
+            (
+                "[[idevid.extensions]]\noid = \"1.2.3\"\nvalue = \"0g\"",
+                "[idevid] extension 1.2.3 value is not valid hex",
+            ),
+            (
+                "[[idevid.extensions]]\noid = \"1.2.3\"\nvalue = \"0c05aa\"",
+                "[idevid] extension 1.2.3 value is not a DER element",
+            ),
+            (
+                "[[idevid.extensions]]\noid = \"1.2.3\"\nvalue = \"05000500\"",
+                "[idevid] extension 1.2.3 value is not a DER element",
+            ),
+            (
+                "[idevid_ca]\nissuer = \"30zz\"",
+                "[idevid_ca] issuer is not valid hex",
+            ),
+            (
+                "[idevid_ca]\nissuer = \"0c0141\"",
+                "[idevid_ca] issuer is not a DER encoded name",
+            ),
+            (
+                "[rt_alias]\nsubject = [{ field = \"O\", value = \"Acme\" }]",
+                "[rt_alias] subject attributes are not supported",
+            ),
+        ];
+        for (config, error) in cases {
+            let err = parse(config).unwrap_err();
+            assert!(err.starts_with(error), "{config}: {err}");
+        }
+    }
+}
@@ -30,6 +30,8 @@ pub struct CsrTemplateBuilder<Algo: SigningAlgorithm> {
     builder: X509ReqBuilder,
     exts: Stack<X509Extension>,
     params: Vec<CsrTemplateParam>,
+    subject_entries: Vec<(String, String)>,
+    nonce_len: Option<usize>,
 }
 
 impl<Algo: SigningAlgorithm> CsrTemplateBuilder<Algo> {
@@ -40,8 +42,22 @@ impl<Algo: SigningAlgorithm> CsrTemplateBuilder<Algo> {
             builder: X509ReqBuilder::new().unwrap(),
             exts: Stack::new().unwrap(),
             params: vec![],
+            subject_entries: vec![],
+            nonce_len: None,
         }
     }
+
+    /// Add attributes to the subject name, following the common name and
+    /// serial number
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Attribute (field, value) pairs
+    pub fn add_subject_name_entries(mut self, entries: &[(String, String)]) -> Self {
+        self.subject_entries.extend_from_slice(entries);
+        self
+    }
+
     /// Add X509 Basic Constraints Extension
     ///
     /// # Arguments
@@ -83,6 +99,30 @@ impl<Algo: SigningAlgorithm> CsrTemplateBuilder<Algo> {
         self
     }
 
+    /// Add an extension with a fixed value
+    ///
+    /// # Arguments
+    ///
+    /// * `oid`      - Extension OID in dotted notation
+    /// * `critical` - Criticality of the extension
+    /// * `value`    - DER encoded extension value
+    pub fn add_custom_ext(mut self, oid: &str, critical: bool, value: &[u8]) -> Self {
+        self.exts
+            .push(x509::make_custom_ext(oid, critical, value))
+            .unwrap();
+        self
+    }
+
+    /// Add a PKCS#9 challengePassword attribute carrying a nonce
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - Length of the nonce, in characters
+    pub fn add_nonce_attr(mut self, len: usize) -> Self {
+        self.nonce_len = Some(len);
+        self
+    }
+
     /// Generate To Be Signed (TBS) Template
     pub fn tbs_template(mut self, subject_cn: &str) -> TbsTemplate {
         // Generate key pair
@@ -105,6 +145,7 @@ impl<Algo: SigningAlgorithm> CsrTemplateBuilder<Algo> {
         subject_name
             .append_entry_by_text("serialNumber", &key.hex_str())
             .unwrap();
+        x509::append_name_entries(&mut subject_name, &self.subject_entries);
         let subject_name = subject_name.build();
         self.builder.set_subject_name(&subject_name).unwrap();
         let param = CsrTemplateParam {
@@ -130,6 +171,18 @@ impl<Algo: SigningAlgorithm> CsrTemplateBuilder<Algo> {
         // Retrieve the To be signed portion from the CSR
         let mut tbs = x509::get_tbs(der);
 
+        // OpenSSL can only add extension attributes, so the nonce attribute is
+        // added to the TBS directly. The signature of the CSR is discarded.
+        if let Some(len) = self.nonce_len {
+            // 'Z' is not a hex digit, so the needle is unique
+            let nonce = "Z".repeat(len);
+            tbs = x509::add_csr_attr(tbs, &x509::make_challenge_password_attr(&nonce));
+            self.params.push(CsrTemplateParam {
+                tbs_param: TbsParam::new("NONCE", 0, len),
+                needle: nonce.into_bytes(),
+            });
+        }
+
         // Calculate the offset of parameters and sanitize the TBS section
         let params = self
             .params
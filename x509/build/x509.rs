@@ -31,6 +31,7 @@ use openssl::x509::extension::BasicConstraints;
 use openssl::x509::extension::KeyUsage as Usage;
 use openssl::x509::extension::SubjectKeyIdentifier;
 use openssl::x509::X509Extension;
+use openssl::x509::X509NameBuilder;
 use openssl::x509::X509v3Context;
 
 use crate::tbs::TbsParam;
@@ -44,6 +45,7 @@ const AUTH_KEY_ID_OID: &str = "2.5.29.35";
 const TCG_UEID_OID: &str = "2.23.133.5.4.4";
 const TCG_TCB_INFO_OID: &str = "2.23.133.5.4.1";
 const TCG_MULTI_TCB_INFO_OID: &str = "2.23.133.5.4.5";
+const CHALLENGE_PASSWORD_OID: &str = "1.2.840.113549.1.9.7";
 
 #[derive(asn1::Asn1Write)]
 struct TcbInfo<'a> {
@@ -266,6 +268,105 @@ pub fn make_tcg_ueid_ext(ueid: &[u8]) -> X509Extension {
     X509Extension::new_from_der(&oid, false, &der).unwrap()
 }
 
+/// Make an extension with a fixed DER encoded value
+pub fn make_custom_ext(oid: &str, critical: bool, value: &[u8]) -> X509Extension {
+    let der = Asn1OctetString::new_from_bytes(value).unwrap();
+    let oid = Asn1Object::from_str(oid).unwrap();
+    X509Extension::new_from_der(&oid, critical, &der).unwrap()
+}
+
+/// Append (field, value) attributes to an X509 name
+pub fn append_name_entries(name: &mut X509NameBuilder, entries: &[(String, String)]) {
+    for (field, value) in entries {
+        name.append_entry_by_text(field, value)
+            .unwrap_or_else(|e| panic!("Invalid name attribute {field}={value}: {e}"));
+    }
+}
+
+/// Make a PKCS#9 challengePassword CSR attribute
+pub fn make_challenge_password_attr(password: &str) -> Vec<u8> {
+    #[derive(asn1::Asn1Write)]
+    struct Attribute<'a> {
+        attr_type: asn1::ObjectIdentifier,
+        values: asn1::SetOfWriter<'a, asn1::Utf8String<'a>>,
+    }
+
+    let values = [asn1::Utf8String::new(password)];
+    let attr = Attribute {
+        attr_type: asn1::ObjectIdentifier::from_string(CHALLENGE_PASSWORD_OID).unwrap(),
+        values: asn1::SetOfWriter::new(&values),
+    };
+    asn1::write_single(&attr).unwrap()
+}
+
+/// Add an attribute to the attributes of a CSR `To Be Signed` portion
+///
+/// # Arguments
+///
+/// * `tbs`  - DER encoded CertificationRequestInfo
+/// * `attr` - DER encoded Attribute
+pub fn add_csr_attr(tbs: Vec<u8>, attr: &[u8]) -> Vec<u8> {
+    let mut fields = der_elements(der_contents(&tbs, 0x30));
+
+    // The attributes are the last field of the CertificationRequestInfo
+    let attrs = fields
+        .pop()
+        .filter(|attrs| attrs[0] == 0xA0)
+        .expect("CSR has no attributes");
+    let mut attrs = der_elements(der_contents(attrs, 0xA0));
+    attrs.push(attr);
+
+    // DER orders the elements of a SET OF by their encoding
+    attrs.sort();
+
+    let mut contents = fields.concat();
+    contents.extend(der_encode(0xA0, &attrs.concat()));
+    der_encode(0x30, &contents)
+}
+
+/// Split a DER element into its header length and contents length
+fn der_header(der: &[u8]) -> (usize, usize) {
+    match der[1] {
+        len @ 0..=0x7F => (2, len as usize),
+        0x81 => (3, der[2] as usize),
+        0x82 => (4, u16::from_be_bytes([der[2], der[3]]) as usize),
+        _ => panic!("Unsupported DER Length"),
+    }
+}
+
+/// Contents of a DER element with the given tag
+fn der_contents(der: &[u8], tag: u8) -> &[u8] {
+    if der[0] != tag {
+        panic!("Unexpected DER tag {:#x}", der[0]);
+    }
+    let (hdr_len, len) = der_header(der);
+    &der[hdr_len..hdr_len + len]
+}
+
+/// Split a sequence of DER elements
+fn der_elements(mut der: &[u8]) -> Vec<&[u8]> {
+    let mut elements = vec![];
+    while !der.is_empty() {
+        let (hdr_len, len) = der_header(der);
+        let (element, rest) = der.split_at(hdr_len + len);
+        elements.push(element);
+        der = rest;
+    }
+    elements
+}
+
+/// Encode a DER element
+fn der_encode(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut der = vec![tag];
+    match contents.len() {
+        len @ 0..=0x7F => der.push(len as u8),
+        len @ 0x80..=0xFF => der.extend([0x81, len as u8]),
+        len => der.extend([0x82].into_iter().chain((len as u16).to_be_bytes())),
+    }
+    der.extend_from_slice(contents);
+    der
+}
+
 /// Make Subject Key ID extension
 pub fn make_subj_key_id_ext(ctx: &X509v3Context) -> X509Extension {
     SubjectKeyIdentifier::new().build(ctx).unwrap()
@@ -0,0 +1,518 @@
+/*++
+
+Licensed under the Apache-2.0 license.
+
+File Name:
+
+    cert_parser.rs
+
+Abstract:
+
+    Minimal DER parser for X509 certificates, sufficient to inspect the
+    names, keys and algorithms of certificates provided to the firmware.
+
+--*/
+
+use crate::Ecdsa384Signature;
+
+/// DER Integer Tag
+const DER_INTEGER_TAG: u8 = 0x02;
+
+/// DER Bit String Tag
+const DER_BIT_STR_TAG: u8 = 0x03;
+
+/// DER Object Identifier Tag
+const DER_OID_TAG: u8 = 0x06;
+
+/// DER Sequence Tag
+const DER_SEQ_TAG: u8 = 0x30;
+
+/// Certificate Version Tag ([0] EXPLICIT)
+const DER_VERSION_TAG: u8 = 0xA0;
+
+/// Issuer Unique Identifier Tag ([1] IMPLICIT)
+const DER_ISSUER_UID_TAG: u8 = 0x81;
+
+/// Subject Unique Identifier Tag ([2] IMPLICIT)
+const DER_SUBJECT_UID_TAG: u8 = 0x82;
+
+/// Extensions Tag ([3] EXPLICIT)
+const DER_EXTENSIONS_TAG: u8 = 0xA3;
+
+/// ecdsa-with-SHA384 (1.2.840.10045.4.3.3)
+const OID_ECDSA_WITH_SHA384: &[u8] = &[0x06, 0x08, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x03];
+
+/// id-ecPublicKey (1.2.840.10045.2.1)
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+
+/// secp384r1 (1.3.132.0.34)
+const OID_SECP384R1: &[u8] = &[0x06, 0x05, 0x2B, 0x81, 0x04, 0x00, 0x22];
+
+/// Uncompressed EC point marker
+const EC_POINT_UNCOMPRESSED: u8 = 0x04;
+
+/// DER Tag-Length-Value element
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DerTlv<'a> {
+    /// Tag
+    pub tag: u8,
+
+    /// Complete encoding of the element
+    pub raw: &'a [u8],
+
+    /// Contents of the element
+    pub value: &'a [u8],
+}
+
+/// Reader over a sequence of DER elements
+#[derive(Clone, Copy, Debug)]
+pub struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    /// Create an instance of `DerReader`
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - DER encoded elements
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Check if all the elements have been read
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Tag of the next element
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.data.first().copied()
+    }
+
+    /// Read the next element
+    ///
+    /// # Returns
+    ///
+    /// * `DerTlv` - Element, or `None` if the encoding is malformed or not
+    ///   in the definite, minimal length form required by DER
+    pub fn read_any(&mut self) -> Option<DerTlv<'a>> {
+        let (&tag, rest) = self.data.split_first()?;
+
+        // High tag numbers are not used by X509
+        if tag & 0x1F == 0x1F {
+            return None;
+        }
+
+        let (&first, rest) = rest.split_first()?;
+        let (len, rest) = if first < 0x80 {
+            (first as usize, rest)
+        } else {
+            let num_bytes = (first & 0x7F) as usize;
+            if num_bytes == 0 || num_bytes > core::mem::size_of::<u32>() {
+                return None;
+            }
+            let len_bytes = rest.get(..num_bytes)?;
+            if len_bytes[0] == 0 {
+                return None;
+            }
+            let len = len_bytes
+                .iter()
+                .fold(0usize, |len, byte| (len << 8) | *byte as usize);
+            if len < 0x80 {
+                return None;
+            }
+            (len, &rest[num_bytes..])
+        };
+
+        let value = rest.get(..len)?;
+        let header_len = self.data.len() - rest.len();
+        let raw = &self.data[..header_len + len];
+        self.data = &rest[len..];
+
+        Some(DerTlv { tag, raw, value })
+    }
+
+    /// Read the next element, which must have the given tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Expected tag
+    pub fn read(&mut self, tag: u8) -> Option<DerTlv<'a>> {
+        if self.peek_tag()? != tag {
+            return None;
+        }
+        self.read_any()
+    }
+
+    /// Read the next element if it has the given tag
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Expected tag
+    ///
+    /// # Returns
+    ///
+    /// * `Option<DerTlv>` - Element, or `None` if the next element has a
+    ///   different tag. `None` is returned by the outer option if the element
+    ///   is malformed.
+    pub fn read_optional(&mut self, tag: u8) -> Option<Option<DerTlv<'a>>> {
+        if self.peek_tag() != Some(tag) {
+            return Some(None);
+        }
+        self.read_any().map(Some)
+    }
+}
+
+/// Parsed X509 Certificate
+///
+/// The fields borrow from the certificate encoding. Names and algorithm
+/// identifiers are kept in their DER form so they can be compared directly.
+#[derive(Clone, Copy, Debug)]
+pub struct X509Cert<'a> {
+    /// Complete encoding of the To Be Signed (TBS) portion
+    pub tbs: &'a [u8],
+
+    /// Certificate version (0 for v1, 2 for v3)
+    pub version: u8,
+
+    /// Serial number contents
+    pub serial_number: &'a [u8],
+
+    /// Contents of the signature algorithm identifier within the TBS
+    pub tbs_sig_algo: &'a [u8],
+
+    /// Complete encoding of the issuer name
+    pub issuer: &'a [u8],
+
+    /// Contents of the validity period
+    pub validity: &'a [u8],
+
+    /// Complete encoding of the subject name
+    pub subject: &'a [u8],
+
+    /// Contents of the subject public key algorithm identifier
+    pub pub_key_algo: &'a [u8],
+
+    /// Subject public key, excluding the unused bits octet
+    pub pub_key: &'a [u8],
+
+    /// Contents of the extensions sequence
+    pub extensions: Option<&'a [u8]>,
+
+    /// Contents of the outer signature algorithm identifier
+    pub sig_algo: &'a [u8],
+
+    /// Signature, excluding the unused bits octet
+    pub signature: &'a [u8],
+}
+
+impl<'a> X509Cert<'a> {
+    /// Parse a DER encoded certificate
+    ///
+    /// # Arguments
+    ///
+    /// * `der` - DER encoded certificate, without trailing data
+    ///
+    /// # Returns
+    ///
+    /// * `X509Cert` - Parsed certificate, or `None` if the encoding is malformed
+    pub fn parse(der: &'a [u8]) -> Option<Self> {
+        let mut outer = DerReader::new(der);
+        let cert = outer.read(DER_SEQ_TAG)?;
+        if !outer.is_empty() {
+            return None;
+        }
+
+        let mut cert_reader = DerReader::new(cert.value);
+        let tbs = cert_reader.read(DER_SEQ_TAG)?;
+        let sig_algo = cert_reader.read(DER_SEQ_TAG)?.value;
+        let signature = Self::bit_string(cert_reader.read(DER_BIT_STR_TAG)?)?;
+        if !cert_reader.is_empty() {
+            return None;
+        }
+
+        let mut tbs_reader = DerReader::new(tbs.value);
+        let version = match tbs_reader.read_optional(DER_VERSION_TAG)? {
+            Some(version) => {
+                let mut reader = DerReader::new(version.value);
+                let version = reader.read(DER_INTEGER_TAG)?;
+                match (reader.is_empty(), version.value) {
+                    (true, [v @ 0..=2]) => *v,
+                    _ => return None,
+                }
+            }
+            None => 0,
+        };
+        let serial_number = tbs_reader.read(DER_INTEGER_TAG)?.value;
+        let tbs_sig_algo = tbs_reader.read(DER_SEQ_TAG)?.value;
+        let issuer = tbs_reader.read(DER_SEQ_TAG)?.raw;
+        let validity = tbs_reader.read(DER_SEQ_TAG)?.value;
+        let subject = tbs_reader.read(DER_SEQ_TAG)?.raw;
+
+        let mut spki_reader = DerReader::new(tbs_reader.read(DER_SEQ_TAG)?.value);
+        let pub_key_algo = spki_reader.read(DER_SEQ_TAG)?.value;
+        let pub_key = Self::bit_string(spki_reader.read(DER_BIT_STR_TAG)?)?;
+        if !spki_reader.is_empty() {
+            return None;
+        }
+
+        tbs_reader.read_optional(DER_ISSUER_UID_TAG)?;
+        tbs_reader.read_optional(DER_SUBJECT_UID_TAG)?;
+        let extensions = match tbs_reader.read_optional(DER_EXTENSIONS_TAG)? {
+            Some(exts) => {
+                let mut reader = DerReader::new(exts.value);
+                let exts = reader.read(DER_SEQ_TAG)?;
+                if !reader.is_empty() {
+                    return None;
+                }
+                Some(exts.value)
+            }
+            None => None,
+        };
+        if !tbs_reader.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            tbs: tbs.raw,
+            version,
+            serial_number,
+            tbs_sig_algo,
+            issuer,
+            validity,
+            subject,
+            pub_key_algo,
+            pub_key,
+            extensions,
+            sig_algo,
+            signature,
+        })
+    }
+
+    /// Check if the certificate is signed with ECDSA P-384 and SHA-384
+    pub fn is_ecdsa_p384_sha384(&self) -> bool {
+        self.tbs_sig_algo == OID_ECDSA_WITH_SHA384 && self.sig_algo == OID_ECDSA_WITH_SHA384
+    }
+
+    /// Subject public key, if it is an ECC P-384 key
+    ///
+    /// # Returns
+    ///
+    /// * `(x, y)` - Affine coordinates of the public key
+    pub fn ecc_p384_pub_key(&self) -> Option<(&'a [u8; 48], &'a [u8; 48])> {
+        let mut reader = DerReader::new(self.pub_key_algo);
+        if reader.read(DER_OID_TAG)?.raw != OID_EC_PUBLIC_KEY
+            || reader.read(DER_OID_TAG)?.raw != OID_SECP384R1
+            || !reader.is_empty()
+        {
+            return None;
+        }
+
+        let (&format, coords) = self.pub_key.split_first()?;
+        if format != EC_POINT_UNCOMPRESSED || coords.len() != 96 {
+            return None;
+        }
+        let (x, y) = coords.split_at(48);
+        Some((x.try_into().ok()?, y.try_into().ok()?))
+    }
+
+    /// Certificate signature, if it is an ECDSA P-384 signature
+    pub fn ecdsa_p384_signature(&self) -> Option<Ecdsa384Signature> {
+        let mut outer = DerReader::new(self.signature);
+        let sig = outer.read(DER_SEQ_TAG)?;
+        if !outer.is_empty() {
+            return None;
+        }
+
+        let mut reader = DerReader::new(sig.value);
+        let mut result = Ecdsa384Signature::default();
+        Self::uint(reader.read(DER_INTEGER_TAG)?, &mut result.r)?;
+        Self::uint(reader.read(DER_INTEGER_TAG)?, &mut result.s)?;
+        if !reader.is_empty() {
+            return None;
+        }
+        Some(result)
+    }
+
+    /// Contents of a bit string without unused bits
+    fn bit_string(tlv: DerTlv<'a>) -> Option<&'a [u8]> {
+        match tlv.value.split_first()? {
+            (0, bits) => Some(bits),
+            _ => None,
+        }
+    }
+
+    /// Decode a non-negative integer into a fixed size big-endian buffer
+    fn uint(tlv: DerTlv<'a>, out: &mut [u8]) -> Option<()> {
+        let value = match tlv.value {
+            [0, rest @ ..] if !rest.is_empty() => rest,
+            [first, ..] if *first & 0x80 == 0 => tlv.value,
+            _ => return None,
+        };
+        let offset = out.len().checked_sub(value.len())?;
+        out[offset..].copy_from_slice(value);
+        Some(())
+    }
+}
+
+#[cfg(all(test, target_family = "unix"))]
+mod tests {
+    use openssl::asn1::{Asn1Integer, Asn1Time};
+    use openssl::bn::BigNum;
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::ecdsa::EcdsaSig;
+    use openssl::hash::MessageDigest;
+    use openssl::nid::Nid;
+    use openssl::pkey::{PKey, Private};
+    use openssl::x509::{X509Builder, X509NameBuilder};
+
+    use super::*;
+    use crate::test_util::tests::*;
+
+    fn make_test_cert(
+        subject_key: &PKey<Private>,
+        issuer_key: &PKey<Private>,
+        digest: MessageDigest,
+    ) -> Vec<u8> {
+        let mut builder = X509Builder::new().unwrap();
+        builder.set_version(2).unwrap();
+        let serial = BigNum::from_u32(0x1234).unwrap();
+        builder
+            .set_serial_number(&Asn1Integer::from_bn(&serial).unwrap())
+            .unwrap();
+        let mut subject = X509NameBuilder::new().unwrap();
+        subject.append_entry_by_text("CN", "Test IDevID").unwrap();
+        builder.set_subject_name(&subject.build()).unwrap();
+        let mut issuer = X509NameBuilder::new().unwrap();
+        issuer.append_entry_by_text("CN", "Test CA").unwrap();
+        issuer.append_entry_by_text("O", "Test Org").unwrap();
+        builder.set_issuer_name(&issuer.build()).unwrap();
+        builder.set_pubkey(subject_key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(365).unwrap())
+            .unwrap();
+        builder.sign(issuer_key, digest).unwrap();
+        builder.build().to_der().unwrap()
+    }
+
+    #[test]
+    fn test_parse_cert() {
+        let subject_key = Ecc384AsymKey::default();
+        let issuer_key = Ecc384AsymKey::default();
+        let der = make_test_cert(
+            subject_key.priv_key(),
+            issuer_key.priv_key(),
+            MessageDigest::sha384(),
+        );
+        let openssl_cert = openssl::x509::X509::from_der(&der).unwrap();
+
+        let cert = X509Cert::parse(&der).unwrap();
+        assert_eq!(cert.version, 2);
+        assert_eq!(cert.serial_number, &[0x12, 0x34]);
+        assert_eq!(cert.issuer, openssl_cert.issuer_name().to_der().unwrap());
+        assert_eq!(cert.subject, openssl_cert.subject_name().to_der().unwrap());
+        assert!(cert.is_ecdsa_p384_sha384());
+        assert!(cert.extensions.is_none());
+
+        let (x, y) = cert.ecc_p384_pub_key().unwrap();
+        assert_eq!(&subject_key.pub_key()[1..49], x);
+        assert_eq!(&subject_key.pub_key()[49..], y);
+
+        // The parsed signature must verify over the parsed TBS
+        let sig = cert.ecdsa_p384_signature().unwrap();
+        let sig = EcdsaSig::from_private_components(
+            BigNum::from_slice(&sig.r).unwrap(),
+            BigNum::from_slice(&sig.s).unwrap(),
+        )
+        .unwrap();
+        let digest = openssl::sha::sha384(cert.tbs);
+        let ec_key = issuer_key.priv_key().ec_key().unwrap();
+        assert!(sig.verify(&digest, &ec_key).unwrap());
+    }
+
+    #[test]
+    fn test_parse_non_p384_cert() {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let p256_key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+        let issuer_key = Ecc384AsymKey::default();
+
+        let der = make_test_cert(&p256_key, issuer_key.priv_key(), MessageDigest::sha384());
+        let cert = X509Cert::parse(&der).unwrap();
+        assert!(cert.is_ecdsa_p384_sha384());
+        assert!(cert.ecc_p384_pub_key().is_none());
+
+        let der = make_test_cert(
+            issuer_key.priv_key(),
+            issuer_key.priv_key(),
+            MessageDigest::sha256(),
+        );
+        let cert = X509Cert::parse(&der).unwrap();
+        assert!(!cert.is_ecdsa_p384_sha384());
+        assert!(cert.ecc_p384_pub_key().is_some());
+    }
+
+    #[test]
+    fn test_parse_malformed_cert() {
+        let key = Ecc384AsymKey::default();
+        let der = make_test_cert(key.priv_key(), key.priv_key(), MessageDigest::sha384());
+        assert!(X509Cert::parse(&der).is_some());
+
+        // Truncated
+        assert!(X509Cert::parse(&der[..der.len() - 1]).is_none());
+        assert!(X509Cert::parse(&[]).is_none());
+
+        // Trailing data
+        let mut trailing = der.clone();
+        trailing.push(0);
+        assert!(X509Cert::parse(&trailing).is_none());
+
+        // Not a sequence
+        let mut bad_tag = der.clone();
+        bad_tag[0] = 0x31;
+        assert!(X509Cert::parse(&bad_tag).is_none());
+    }
+
+    #[test]
+    fn test_der_reader_lengths() {
+        // Short form
+        let mut reader = DerReader::new(&[0x04, 0x02, 0xAA, 0xBB]);
+        let tlv = reader.read(0x04).unwrap();
+        assert_eq!(tlv.value, &[0xAA, 0xBB]);
+        assert!(reader.is_empty());
+
+        // Long form
+        let mut data = vec![0x04, 0x81, 0x80];
+        data.extend_from_slice(&[0x55; 0x80]);
+        let mut reader = DerReader::new(&data);
+        let tlv = reader.read_any().unwrap();
+        assert_eq!(tlv.raw.len(), data.len());
+        assert_eq!(tlv.value.len(), 0x80);
+
+        // Indefinite length
+        assert!(DerReader::new(&[0x30, 0x80, 0x00, 0x00])
+            .read_any()
+            .is_none());
+
+        // Non-minimal lengths
+        assert!(DerReader::new(&[0x04, 0x81, 0x01, 0xAA])
+            .read_any()
+            .is_none());
+        assert!(DerReader::new(&[0x04, 0x82, 0x00, 0x01, 0xAA])
+            .read_any()
+            .is_none());
+
+        // Length exceeds the data
+        assert!(DerReader::new(&[0x04, 0x03, 0xAA]).read_any().is_none());
+
+        // Unexpected tag
+        assert!(DerReader::new(&[0x04, 0x00]).read(0x02).is_none());
+        assert_eq!(
+            DerReader::new(&[0x04, 0x00]).read_optional(0x02),
+            Some(None)
+        );
+    }
+}
@@ -14,6 +14,7 @@ Abstract:
 
 // Note: All the necessary code is auto generated
 include!(concat!(env!("OUT_DIR"), "/init_dev_id_csr_tbs.rs"));
+include!(concat!(env!("OUT_DIR"), "/init_dev_id_nonce_csr_tbs.rs"));
 
 #[cfg(all(test, target_family = "unix"))]
 mod tests {
@@ -156,4 +157,58 @@ mod tests {
             .unwrap();
         assert!(!ueid_ext.critical);
     }
+
+    #[test]
+    fn test_nonce_csr() {
+        let key = Ecc384AsymKey::default();
+        let ec_key = key.priv_key().ec_key().unwrap();
+        let nonce = hex::encode_upper([0x5A; 32]);
+
+        let params = InitDevIdNonceCsrTbsParams {
+            public_key: &key.pub_key().try_into().unwrap(),
+            subject_sn: &key.hex_str().into_bytes().try_into().unwrap(),
+            ueid: &TEST_UEID.try_into().unwrap(),
+            nonce: &nonce.as_bytes().try_into().unwrap(),
+        };
+        let csr = InitDevIdNonceCsrTbs::new(&params);
+
+        assert_eq!(
+            &csr.tbs()[InitDevIdNonceCsrTbs::NONCE_OFFSET
+                ..InitDevIdNonceCsrTbs::NONCE_OFFSET + InitDevIdNonceCsrTbs::NONCE_LEN],
+            nonce.as_bytes(),
+        );
+
+        let sig: EcdsaSig = csr
+            .sign(|b| {
+                let mut sha = Sha384::new();
+                sha.update(b);
+                EcdsaSig::sign(&sha.finish(), &ec_key)
+            })
+            .unwrap();
+        let ecdsa_sig = Ecdsa384Signature {
+            r: sig.r().to_vec_padded(48).unwrap().try_into().unwrap(),
+            s: sig.s().to_vec_padded(48).unwrap().try_into().unwrap(),
+        };
+
+        let builder = Ecdsa384CsrBuilder::new(csr.tbs(), &ecdsa_sig).unwrap();
+        let mut buf = vec![0u8; builder.len()];
+        builder.build(&mut buf).unwrap();
+
+        let req: X509Req = X509Req::from_der(&buf).unwrap();
+        assert!(req.verify(key.priv_key()).unwrap());
+
+        let (_, parsed_csr) = X509CertificationRequest::from_der(&buf).unwrap();
+        let attrs: Vec<_> = parsed_csr
+            .certification_request_info
+            .iter_attributes()
+            .collect();
+        assert_eq!(attrs.len(), 2);
+
+        // challengePassword
+        let challenge = attrs
+            .iter()
+            .find(|attr| attr.oid == oid!(1.2.840 .113549 .1 .9 .7))
+            .unwrap();
+        assert!(challenge.value.ends_with(nonce.as_bytes()));
+    }
 }
@@ -15,6 +15,7 @@ Abstract:
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod cert_bldr;
+mod cert_parser;
 mod fmc_alias_cert;
 mod idevid_csr;
 mod ldevid_cert;
@@ -22,12 +23,19 @@ mod rt_alias_cert;
 mod test_util;
 
 pub use cert_bldr::{Ecdsa384CertBuilder, Ecdsa384CsrBuilder, Ecdsa384Signature};
+pub use cert_parser::{DerReader, DerTlv, X509Cert};
 pub use fmc_alias_cert::{FmcAliasCertTbs, FmcAliasCertTbsParams};
 pub use idevid_csr::{InitDevIdCsrTbs, InitDevIdCsrTbsParams};
 pub use ldevid_cert::{LocalDevIdCertTbs, LocalDevIdCertTbsParams};
 pub use rt_alias_cert::{RtAliasCertTbs, RtAliasCertTbsParams};
 use zeroize::Zeroize;
 
+// Subject common names, including any build time overrides
+include!(concat!(env!("OUT_DIR"), "/subject_names.rs"));
+
+// Issuer name required of the IDevID certificate, empty if any issuer is accepted
+include!(concat!(env!("OUT_DIR"), "/idevid_issuer_name.rs"));
+
 pub const NOT_BEFORE: &str = "20230101000000Z";
 pub const NOT_AFTER: &str = "99991231235959Z";
 
@@ -0,0 +1,6 @@
+// Licensed under the Apache-2.0 license
+
+// The template configuration is only compiled into the build script, which
+// has no test harness, so its tests are built here.
+#[path = "../build/config.rs"]
+mod config;